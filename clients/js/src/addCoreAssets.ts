@@ -0,0 +1,24 @@
+import {
+  PublicKey,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { baseAddCoreAssets } from './generated';
+
+export type AddCoreAssetsInput = Parameters<typeof baseAddCoreAssets>[1] & {
+  assets: PublicKey[];
+};
+
+export const addCoreAssets = (
+  context: Parameters<typeof baseAddCoreAssets>[0],
+  input: AddCoreAssetsInput
+): TransactionBuilder =>
+  transactionBuilder().add(
+    baseAddCoreAssets(context, input).addRemainingAccounts(
+      input.assets.map((asset) => ({
+        pubkey: asset,
+        isSigner: false,
+        isWritable: true,
+      }))
+    )
+  );
@@ -106,9 +106,20 @@ export type GumballGuard = {
           docs: ['SlotHashes sysvar cluster data.', ''];
         },
         {
-          name: 'gumballEventAuthority';
+          name: 'pendingDraw';
+          isMut: true;
+          isSigner: false;
+          docs: ['Pending draw account.', ''];
+        },
+        {
+          name: 'randomness';
           isMut: false;
           isSigner: false;
+          isOptional: true;
+          docs: [
+            "Randomness account of the gumball machine's randomness provider.",
+            '',
+          ];
         },
       ];
       args: [
@@ -124,6 +135,106 @@ export type GumballGuard = {
         },
       ];
     },
+    {
+      name: 'drawMany';
+      docs: [
+        'Draw multiple prizes from a gumball machine wrapped in the gumball guard.',
+      ];
+      accounts: [
+        {
+          name: 'gumballGuard';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Guard account.'];
+        },
+        {
+          name: 'gumballMachineProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Machine program account.', ''];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Payer for the mint (SOL) fees.'];
+        },
+        {
+          name: 'buyer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Minter account for validation and non-SOL fees.'];
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['Token Metadata program.', ''];
+        },
+        {
+          name: 'splTokenProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['SPL Token program.'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['System program.'];
+        },
+        {
+          name: 'sysvarInstructions';
+          isMut: false;
+          isSigner: false;
+          docs: ['Instructions sysvar account.', ''];
+        },
+        {
+          name: 'recentSlothashes';
+          isMut: false;
+          isSigner: false;
+          docs: ['SlotHashes sysvar cluster data.', ''];
+        },
+        {
+          name: 'pendingDraw';
+          isMut: true;
+          isSigner: false;
+          docs: ['Pending draw account.', ''];
+        },
+        {
+          name: 'randomness';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Randomness account of the gumball machine's randomness provider.",
+            '',
+          ];
+        },
+      ];
+      args: [
+        {
+          name: 'quantity';
+          type: 'u16';
+        },
+        {
+          name: 'mintArgs';
+          type: 'bytes';
+        },
+        {
+          name: 'label';
+          type: {
+            option: 'string';
+          };
+        },
+      ];
+    },
     {
       name: 'drawJellybean';
       docs: [
@@ -228,6 +339,259 @@ export type GumballGuard = {
         },
       ];
     },
+    {
+      name: 'buySpecific';
+      docs: [
+        'Buy a specific prize from a gumball machine wrapped in the gumball guard, at the',
+        'price set by the premium price guard of the group. Groups with the nft payment or',
+        'nft burn guard cannot be used to buy specific prizes.',
+      ];
+      accounts: [
+        {
+          name: 'gumballGuard';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Guard account.'];
+        },
+        {
+          name: 'gumballMachineProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Machine program account.', ''];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Payer for the mint (SOL) fees.'];
+        },
+        {
+          name: 'buyer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Minter account for validation and non-SOL fees.'];
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['Token Metadata program.', ''];
+        },
+        {
+          name: 'splTokenProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['SPL Token program.'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['System program.'];
+        },
+        {
+          name: 'sysvarInstructions';
+          isMut: false;
+          isSigner: false;
+          docs: ['Instructions sysvar account.', ''];
+        },
+        {
+          name: 'recentSlothashes';
+          isMut: false;
+          isSigner: false;
+          docs: ['SlotHashes sysvar cluster data.', ''];
+        },
+        {
+          name: 'gumballEventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'mintArgs';
+          type: 'bytes';
+        },
+        {
+          name: 'label';
+          type: {
+            option: 'string';
+          };
+        },
+      ];
+    },
+    {
+      name: 'reroll';
+      docs: [
+        'Give back an unclaimed prize and draw a new one from a gumball machine wrapped',
+        'in the gumball guard. The group must have the reroll fee guard, which sets the',
+        'price charged by the payment guards; groups with it can only be used to reroll.',
+      ];
+      accounts: [
+        {
+          name: 'gumballGuard';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Guard account.'];
+        },
+        {
+          name: 'gumballMachineProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Machine program account.', ''];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Payer for the reroll (SOL) fees.'];
+        },
+        {
+          name: 'buyer';
+          isMut: true;
+          isSigner: true;
+          docs: [
+            'Buyer of the rerolled prize, used for validation and non-SOL fees.',
+          ];
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['Token Metadata program.', ''];
+        },
+        {
+          name: 'splTokenProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['SPL Token program.'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['System program.'];
+        },
+        {
+          name: 'sysvarInstructions';
+          isMut: false;
+          isSigner: false;
+          docs: ['Instructions sysvar account.', ''];
+        },
+        {
+          name: 'recentSlothashes';
+          isMut: false;
+          isSigner: false;
+          docs: ['SlotHashes sysvar cluster data.', ''];
+        },
+        {
+          name: 'pendingDraw';
+          isMut: true;
+          isSigner: false;
+          docs: ['Pending draw account.', ''];
+        },
+        {
+          name: 'randomness';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Randomness account of the gumball machine's randomness provider.",
+            '',
+          ];
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'mintArgs';
+          type: 'bytes';
+        },
+        {
+          name: 'label';
+          type: {
+            option: 'string';
+          };
+        },
+      ];
+    },
+    {
+      name: 'pauseSale';
+      docs: [
+        'Pause the sale of a gumball machine wrapped in the gumball guard.',
+      ];
+      accounts: [
+        {
+          name: 'gumballGuard';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'gumballMachineProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'resumeSale';
+      docs: [
+        'Resume the sale of a gumball machine wrapped in the gumball guard.',
+      ];
+      accounts: [
+        {
+          name: 'gumballGuard';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'gumballMachineProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
     {
       name: 'route';
       docs: ['Route the transaction to a guard instruction.'];
@@ -737,6 +1101,33 @@ export type GumballGuard = {
         ];
       };
     },
+    {
+      name: 'PremiumPrice';
+      docs: [
+        'Guard that sets the price to buy a specific item instead of drawing one. Buying a',
+        'specific item is only allowed when this guard is enabled.',
+        '',
+        'The premium price replaces the price of the payment guards (sol payment, token',
+        'payment and token2022 payment) and the amount of the token burn guard. A flat price',
+        'is a single amount, so guard sets with a flat premium price can only have one of',
+        'those guards.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'priceType';
+            type: {
+              defined: 'PremiumPriceType';
+            };
+          },
+          {
+            name: 'value';
+            type: 'u64';
+          },
+        ];
+      };
+    },
     {
       name: 'ProgramGate';
       docs: [
@@ -771,6 +1162,26 @@ export type GumballGuard = {
         ];
       };
     },
+    {
+      name: 'RerollFee';
+      docs: [
+        'Guard that sets the fee to reroll an unclaimed prize. Rerolling is only allowed when',
+        'this guard is enabled, and groups with this guard can only be used to reroll.',
+        '',
+        'The fee replaces the price of the payment guards (sol payment, token payment and',
+        'token2022 payment) and the amount of the token burn guard. Since the fee is a single',
+        'amount, guard sets with a reroll fee can only have one of those guards.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+        ];
+      };
+    },
     {
       name: 'SolPayment';
       docs: [
@@ -1180,15 +1591,52 @@ export type GumballGuard = {
             };
           },
           {
-            name: 'token2022Payment';
-            docs: [
-              'Token2022 payment guard (set the price for the mint in spl-token-2022 amount).',
-            ];
-            type: {
-              option: {
-                defined: 'Token2022Payment';
-              };
-            };
+            name: 'token2022Payment';
+            docs: [
+              'Token2022 payment guard (set the price for the mint in spl-token-2022 amount).',
+            ];
+            type: {
+              option: {
+                defined: 'Token2022Payment';
+              };
+            };
+          },
+          {
+            name: 'premiumPrice';
+            docs: [
+              'Premium price guard (set the price to buy a specific item).',
+            ];
+            type: {
+              option: {
+                defined: 'PremiumPrice';
+              };
+            };
+          },
+          {
+            name: 'rerollFee';
+            docs: [
+              'Reroll fee guard (set the fee to reroll an unclaimed prize).',
+            ];
+            type: {
+              option: {
+                defined: 'RerollFee';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'PremiumPriceType';
+      docs: ['How the price of a specific item is calculated.'];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Flat';
+          },
+          {
+            name: 'Multiplier';
           },
         ];
       };
@@ -1270,6 +1718,12 @@ export type GumballGuard = {
           {
             name: 'Token2022Payment';
           },
+          {
+            name: 'PremiumPrice';
+          },
+          {
+            name: 'RerollFee';
+          },
         ];
       };
     },
@@ -1577,6 +2031,41 @@ export type GumballGuard = {
       name: 'InvalidMachine';
       msg: 'Invalid machine';
     },
+    {
+      code: 6057;
+      name: 'InvalidQuantity';
+      msg: 'Invalid quantity';
+    },
+    {
+      code: 6058;
+      name: 'RerollFeeNotEnabled';
+      msg: 'Reroll fee guard must be enabled to reroll a prize';
+    },
+    {
+      code: 6059;
+      name: 'RerollOnlyGroup';
+      msg: 'Guard group can only be used to reroll a prize';
+    },
+    {
+      code: 6060;
+      name: 'PremiumPriceNotEnabled';
+      msg: 'Premium price guard must be enabled to buy a specific item';
+    },
+    {
+      code: 6061;
+      name: 'InvalidPremiumPrice';
+      msg: 'Invalid premium price';
+    },
+    {
+      code: 6062;
+      name: 'PremiumPriceNotSupported';
+      msg: 'Premium price cannot be charged with an NFT payment or NFT burn guard';
+    },
+    {
+      code: 6063;
+      name: 'MultiplePaymentGuards';
+      msg: 'Reroll fee and flat premium price guards require a single payment guard';
+    },
   ];
 };
 
@@ -1589,42 +2078,344 @@ export const IDL: GumballGuard = {
       docs: ['Create a new gumball guard account.'],
       accounts: [
         {
-          name: 'gumballGuard',
+          name: 'gumballGuard',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'base',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'data',
+          type: 'bytes',
+        },
+      ],
+    },
+    {
+      name: 'draw',
+      docs: [
+        'Draw a prize from a gumball machine wrapped in the gumball guard.',
+      ],
+      accounts: [
+        {
+          name: 'gumballGuard',
+          isMut: false,
+          isSigner: false,
+          docs: ['Gumball Guard account.'],
+        },
+        {
+          name: 'gumballMachineProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['Gumball Machine program account.', ''],
+        },
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Payer for the mint (SOL) fees.'],
+        },
+        {
+          name: 'buyer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Minter account for validation and non-SOL fees.'],
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['Token Metadata program.', ''],
+        },
+        {
+          name: 'splTokenProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['SPL Token program.'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['System program.'],
+        },
+        {
+          name: 'sysvarInstructions',
+          isMut: false,
+          isSigner: false,
+          docs: ['Instructions sysvar account.', ''],
+        },
+        {
+          name: 'recentSlothashes',
+          isMut: false,
+          isSigner: false,
+          docs: ['SlotHashes sysvar cluster data.', ''],
+        },
+        {
+          name: 'pendingDraw',
+          isMut: true,
+          isSigner: false,
+          docs: ['Pending draw account.', ''],
+        },
+        {
+          name: 'randomness',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Randomness account of the gumball machine's randomness provider.",
+            '',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'mintArgs',
+          type: 'bytes',
+        },
+        {
+          name: 'label',
+          type: {
+            option: 'string',
+          },
+        },
+      ],
+    },
+    {
+      name: 'drawMany',
+      docs: [
+        'Draw multiple prizes from a gumball machine wrapped in the gumball guard.',
+      ],
+      accounts: [
+        {
+          name: 'gumballGuard',
+          isMut: false,
+          isSigner: false,
+          docs: ['Gumball Guard account.'],
+        },
+        {
+          name: 'gumballMachineProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['Gumball Machine program account.', ''],
+        },
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Payer for the mint (SOL) fees.'],
+        },
+        {
+          name: 'buyer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Minter account for validation and non-SOL fees.'],
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['Token Metadata program.', ''],
+        },
+        {
+          name: 'splTokenProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['SPL Token program.'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['System program.'],
+        },
+        {
+          name: 'sysvarInstructions',
+          isMut: false,
+          isSigner: false,
+          docs: ['Instructions sysvar account.', ''],
+        },
+        {
+          name: 'recentSlothashes',
+          isMut: false,
+          isSigner: false,
+          docs: ['SlotHashes sysvar cluster data.', ''],
+        },
+        {
+          name: 'pendingDraw',
+          isMut: true,
+          isSigner: false,
+          docs: ['Pending draw account.', ''],
+        },
+        {
+          name: 'randomness',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Randomness account of the gumball machine's randomness provider.",
+            '',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'quantity',
+          type: 'u16',
+        },
+        {
+          name: 'mintArgs',
+          type: 'bytes',
+        },
+        {
+          name: 'label',
+          type: {
+            option: 'string',
+          },
+        },
+      ],
+    },
+    {
+      name: 'drawJellybean',
+      docs: [
+        'Draw a prize from a gumball machine wrapped in the gumball guard.',
+      ],
+      accounts: [
+        {
+          name: 'gumballGuard',
+          isMut: false,
+          isSigner: false,
+          docs: ['Gumball Guard account.'],
+        },
+        {
+          name: 'jellybeanMachineProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['Jellybean Machine program account.', ''],
+        },
+        {
+          name: 'jellybeanMachine',
           isMut: true,
           isSigner: false,
+          docs: ['Jellybean machine account.'],
         },
         {
-          name: 'base',
-          isMut: false,
+          name: 'jellybeanMachineAuthorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
           isSigner: true,
+          docs: ['Payer for the mint (SOL) fees.'],
         },
         {
-          name: 'authority',
-          isMut: false,
+          name: 'buyer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Minter account for validation and non-SOL fees.'],
+        },
+        {
+          name: 'unclaimedPrizes',
+          isMut: true,
           isSigner: false,
         },
         {
-          name: 'payer',
+          name: 'printFeeAccount',
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'Print fee account. Required if the jellybean machine has a print fee config.',
+          ],
+        },
+        {
+          name: 'splTokenProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['SPL Token program.'],
         },
         {
           name: 'systemProgram',
           isMut: false,
           isSigner: false,
+          docs: ['System program.'],
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+          docs: ['Rent.'],
+        },
+        {
+          name: 'sysvarInstructions',
+          isMut: false,
+          isSigner: false,
+          docs: ['Instructions sysvar account.', ''],
+        },
+        {
+          name: 'recentSlothashes',
+          isMut: false,
+          isSigner: false,
+          docs: ['SlotHashes sysvar cluster data.', ''],
+        },
+        {
+          name: 'jellybeanEventAuthority',
+          isMut: false,
+          isSigner: false,
         },
       ],
       args: [
         {
-          name: 'data',
+          name: 'mintArgs',
           type: 'bytes',
         },
+        {
+          name: 'label',
+          type: {
+            option: 'string',
+          },
+        },
       ],
     },
     {
-      name: 'draw',
+      name: 'buySpecific',
       docs: [
-        'Draw a prize from a gumball machine wrapped in the gumball guard.',
+        'Buy a specific prize from a gumball machine wrapped in the gumball guard, at the',
+        'price set by the premium price guard of the group. Groups with the nft payment or',
+        'nft burn guard cannot be used to buy specific prizes.',
       ],
       accounts: [
         {
@@ -1694,6 +2485,10 @@ export const IDL: GumballGuard = {
         },
       ],
       args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
         {
           name: 'mintArgs',
           type: 'bytes',
@@ -1707,9 +2502,11 @@ export const IDL: GumballGuard = {
       ],
     },
     {
-      name: 'drawJellybean',
+      name: 'reroll',
       docs: [
-        'Draw a prize from a gumball machine wrapped in the gumball guard.',
+        'Give back an unclaimed prize and draw a new one from a gumball machine wrapped',
+        'in the gumball guard. The group must have the reroll fee guard, which sets the',
+        'price charged by the payment guards; groups with it can only be used to reroll.',
       ],
       accounts: [
         {
@@ -1719,47 +2516,36 @@ export const IDL: GumballGuard = {
           docs: ['Gumball Guard account.'],
         },
         {
-          name: 'jellybeanMachineProgram',
+          name: 'gumballMachineProgram',
           isMut: false,
           isSigner: false,
-          docs: ['Jellybean Machine program account.', ''],
-        },
-        {
-          name: 'jellybeanMachine',
-          isMut: true,
-          isSigner: false,
-          docs: ['Jellybean machine account.'],
+          docs: ['Gumball Machine program account.', ''],
         },
         {
-          name: 'jellybeanMachineAuthorityPda',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
+          docs: ['Gumball machine account.'],
         },
         {
           name: 'payer',
           isMut: true,
           isSigner: true,
-          docs: ['Payer for the mint (SOL) fees.'],
+          docs: ['Payer for the reroll (SOL) fees.'],
         },
         {
           name: 'buyer',
           isMut: true,
           isSigner: true,
-          docs: ['Minter account for validation and non-SOL fees.'],
-        },
-        {
-          name: 'unclaimedPrizes',
-          isMut: true,
-          isSigner: false,
+          docs: [
+            'Buyer of the rerolled prize, used for validation and non-SOL fees.',
+          ],
         },
         {
-          name: 'printFeeAccount',
-          isMut: true,
+          name: 'tokenMetadataProgram',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
-          docs: [
-            'Print fee account. Required if the jellybean machine has a print fee config.',
-          ],
+          docs: ['Token Metadata program.', ''],
         },
         {
           name: 'splTokenProgram',
@@ -1773,12 +2559,6 @@ export const IDL: GumballGuard = {
           isSigner: false,
           docs: ['System program.'],
         },
-        {
-          name: 'rent',
-          isMut: false,
-          isSigner: false,
-          docs: ['Rent.'],
-        },
         {
           name: 'sysvarInstructions',
           isMut: false,
@@ -1792,12 +2572,27 @@ export const IDL: GumballGuard = {
           docs: ['SlotHashes sysvar cluster data.', ''],
         },
         {
-          name: 'jellybeanEventAuthority',
+          name: 'pendingDraw',
+          isMut: true,
+          isSigner: false,
+          docs: ['Pending draw account.', ''],
+        },
+        {
+          name: 'randomness',
           isMut: false,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            "Randomness account of the gumball machine's randomness provider.",
+            '',
+          ],
         },
       ],
       args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
         {
           name: 'mintArgs',
           type: 'bytes',
@@ -1810,6 +2605,64 @@ export const IDL: GumballGuard = {
         },
       ],
     },
+    {
+      name: 'pauseSale',
+      docs: [
+        'Pause the sale of a gumball machine wrapped in the gumball guard.',
+      ],
+      accounts: [
+        {
+          name: 'gumballGuard',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'gumballMachineProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'resumeSale',
+      docs: [
+        'Resume the sale of a gumball machine wrapped in the gumball guard.',
+      ],
+      accounts: [
+        {
+          name: 'gumballGuard',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'gumballMachineProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
     {
       name: 'route',
       docs: ['Route the transaction to a guard instruction.'],
@@ -2319,6 +3172,33 @@ export const IDL: GumballGuard = {
         ],
       },
     },
+    {
+      name: 'PremiumPrice',
+      docs: [
+        'Guard that sets the price to buy a specific item instead of drawing one. Buying a',
+        'specific item is only allowed when this guard is enabled.',
+        '',
+        'The premium price replaces the price of the payment guards (sol payment, token',
+        'payment and token2022 payment) and the amount of the token burn guard. A flat price',
+        'is a single amount, so guard sets with a flat premium price can only have one of',
+        'those guards.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'priceType',
+            type: {
+              defined: 'PremiumPriceType',
+            },
+          },
+          {
+            name: 'value',
+            type: 'u64',
+          },
+        ],
+      },
+    },
     {
       name: 'ProgramGate',
       docs: [
@@ -2353,6 +3233,26 @@ export const IDL: GumballGuard = {
         ],
       },
     },
+    {
+      name: 'RerollFee',
+      docs: [
+        'Guard that sets the fee to reroll an unclaimed prize. Rerolling is only allowed when',
+        'this guard is enabled, and groups with this guard can only be used to reroll.',
+        '',
+        'The fee replaces the price of the payment guards (sol payment, token payment and',
+        'token2022 payment) and the amount of the token burn guard. Since the fee is a single',
+        'amount, guard sets with a reroll fee can only have one of those guards.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'amount',
+            type: 'u64',
+          },
+        ],
+      },
+    },
     {
       name: 'SolPayment',
       docs: [
@@ -2772,6 +3672,43 @@ export const IDL: GumballGuard = {
               },
             },
           },
+          {
+            name: 'premiumPrice',
+            docs: [
+              'Premium price guard (set the price to buy a specific item).',
+            ],
+            type: {
+              option: {
+                defined: 'PremiumPrice',
+              },
+            },
+          },
+          {
+            name: 'rerollFee',
+            docs: [
+              'Reroll fee guard (set the fee to reroll an unclaimed prize).',
+            ],
+            type: {
+              option: {
+                defined: 'RerollFee',
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'PremiumPriceType',
+      docs: ['How the price of a specific item is calculated.'],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Flat',
+          },
+          {
+            name: 'Multiplier',
+          },
         ],
       },
     },
@@ -2852,6 +3789,12 @@ export const IDL: GumballGuard = {
           {
             name: 'Token2022Payment',
           },
+          {
+            name: 'PremiumPrice',
+          },
+          {
+            name: 'RerollFee',
+          },
         ],
       },
     },
@@ -3159,5 +4102,40 @@ export const IDL: GumballGuard = {
       name: 'InvalidMachine',
       msg: 'Invalid machine',
     },
+    {
+      code: 6057,
+      name: 'InvalidQuantity',
+      msg: 'Invalid quantity',
+    },
+    {
+      code: 6058,
+      name: 'RerollFeeNotEnabled',
+      msg: 'Reroll fee guard must be enabled to reroll a prize',
+    },
+    {
+      code: 6059,
+      name: 'RerollOnlyGroup',
+      msg: 'Guard group can only be used to reroll a prize',
+    },
+    {
+      code: 6060,
+      name: 'PremiumPriceNotEnabled',
+      msg: 'Premium price guard must be enabled to buy a specific item',
+    },
+    {
+      code: 6061,
+      name: 'InvalidPremiumPrice',
+      msg: 'Invalid premium price',
+    },
+    {
+      code: 6062,
+      name: 'PremiumPriceNotSupported',
+      msg: 'Premium price cannot be charged with an NFT payment or NFT burn guard',
+    },
+    {
+      code: 6063,
+      name: 'MultiplePaymentGuards',
+      msg: 'Reroll fee and flat premium price guards require a single payment guard',
+    },
   ],
 };
@@ -97,314 +97,495 @@ export type MallowGumball = {
       ];
     },
     {
-      name: 'addNft';
+      name: 'setItemTiers';
       docs: [
-        'Add legacy NFTs to the gumball machine.',
+        'Sets the tier of the items in the range `[start_index, end_index]`.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Seller',
-        '4. `[]` Mint account',
-        '5. `[writable]` Token account',
-        '6. `[writable]` Metadata account',
-        '7. `[]` Edition account',
-        '8. `[]` Token program',
-        '9. `[]` Token Metadata program',
-        '10. `[]` System program',
-        '11. `[writable, optional]` Seller token record (pNFT)',
-        '12. `[optional]` Auth rules account (pNFT)',
-        '13. `[optional]` Instructions sysvar (pNFT)',
-        '14. `[optional]` Auth rules program (pNFT)',
+        '1. `[signer]` Gumball Machine authority',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball Machine account.'];
-        },
-        {
-          name: 'sellerHistory';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller history account.'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authorityPda';
-          isMut: true;
-          isSigner: false;
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [
         {
-          name: 'seller';
-          isMut: true;
-          isSigner: true;
-          docs: ['Seller of the nft'];
+          name: 'startIndex';
+          type: 'u32';
         },
         {
-          name: 'mint';
-          isMut: false;
-          isSigner: false;
+          name: 'endIndex';
+          type: 'u32';
         },
         {
-          name: 'tokenAccount';
-          isMut: true;
-          isSigner: false;
+          name: 'tier';
+          type: 'u8';
         },
+      ];
+    },
+    {
+      name: 'setItemWeights';
+      docs: [
+        'Sets the proceeds weight of the items in the range `[start_index, end_index]`.',
+        'Sale proceeds are split between sellers proportionally to the weight of their items.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ];
+      accounts: [
         {
-          name: 'metadata';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'edition';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [
         {
-          name: 'tokenProgram';
-          isMut: false;
-          isSigner: false;
+          name: 'startIndex';
+          type: 'u32';
         },
         {
-          name: 'tokenMetadataProgram';
-          isMut: false;
-          isSigner: false;
+          name: 'endIndex';
+          type: 'u32';
         },
         {
-          name: 'systemProgram';
-          isMut: false;
-          isSigner: false;
+          name: 'weight';
+          type: 'u32';
         },
+      ];
+    },
+    {
+      name: 'setTierWeights';
+      docs: [
+        'Sets the draw weight of each tier. The odds of drawing an item are proportional',
+        'to the weight of its tier.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ];
+      accounts: [
         {
-          name: 'sellerTokenRecord';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['OPTIONAL PNFT ACCOUNTS'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authRules';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
-          isOptional: true;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [
         {
-          name: 'instructions';
-          isMut: false;
+          name: 'weights';
+          type: {
+            array: ['u16', 8];
+          };
+        },
+      ];
+    },
+    {
+      name: 'setRandomnessConfig';
+      docs: [
+        'Sets the randomness provider used to draw items. When no provider is set, draws',
+        'are revealed using slot hashes.',
+        '',
+        'Every draw uses the configured randomness account, which must have an open request',
+        'when the draw is made. The draw is bound to that request and is only revealed with',
+        'its value; if the request is replaced, the draw cannot be revealed.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
           isSigner: false;
-          isOptional: true;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authRulesProgram';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
-          isOptional: true;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
       ];
       args: [
         {
-          name: 'args';
+          name: 'randomnessConfig';
           type: {
-            defined: 'AddItemArgs';
+            defined: 'RandomnessConfig';
+          };
+        },
+        {
+          name: 'randomnessRequestConfig';
+          type: {
+            defined: 'RandomnessRequestConfig';
           };
         },
       ];
     },
     {
-      name: 'addCoreAsset';
+      name: 'setAddItemRequestExpiry';
       docs: [
-        'Add Core assets to the gumball machine.',
+        'Sets how many seconds add item requests stay open before anyone can close them.',
+        'An expiry of 0 means requests never expire.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Seller',
-        '4. `[writable]` Asset account',
-        '5. `[writable, optional]` Collection account',
-        '6. `[]` MPL Core program',
-        '7. `[]` System program',
+        '1. `[signer]` Gumball Machine authority',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball Machine account.'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'sellerHistory';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller history account.'];
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [
         {
-          name: 'authorityPda';
+          name: 'expiry';
+          type: 'i64';
+        },
+      ];
+    },
+    {
+      name: 'setForceCloseGracePeriod';
+      docs: [
+        'Sets how many seconds after the sale ends the gumball machine can be force closed',
+        'without the mint authority. A grace period of 0 disables force closing. Once the sale',
+        'has started the grace period can only be increased.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'seller';
-          isMut: true;
+          name: 'authority';
+          isMut: false;
           isSigner: true;
-          docs: ['Seller of the asset.'];
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [
         {
-          name: 'asset';
-          isMut: true;
-          isSigner: false;
+          name: 'gracePeriod';
+          type: 'i64';
         },
+      ];
+    },
+    {
+      name: 'setClaimDeadline';
+      docs: [
+        'Sets the deadline to claim drawn items, counted from the end of the sale. Once it has',
+        'passed, buyers can no longer claim their items and settling an unclaimed item sends it',
+        'to the configured destination instead, while the seller is paid as usual. Items held',
+        'by a receipt do not expire.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ];
+      accounts: [
         {
-          name: 'collection';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ["Core asset's collection if it's part of one."];
-        },
-        {
-          name: 'mplCoreProgram';
-          isMut: false;
-          isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'systemProgram';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
       ];
       args: [
         {
-          name: 'args';
+          name: 'claimDeadlineConfig';
           type: {
-            defined: 'AddItemArgs';
+            defined: 'ClaimDeadlineConfig';
           };
         },
       ];
     },
     {
-      name: 'addTokens';
+      name: 'setInstantDelivery';
       docs: [
-        'Add fungible tokens to the gumball machine.',
+        'Sets whether drawn items are transferred to the buyer when the draw is revealed,',
+        'instead of being claimed in a separate transaction. Items are only selected on',
+        "reveal, so the reveal transaction (sent by the buyer's client or a reveal crank)",
+        'passes the delivery accounts of the drawn items. Compressed NFTs cannot be delivered',
+        'this way, so instant delivery cannot be enabled on a machine that holds them, or on a',
+        'machine with receipts.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Seller',
-        '4. `[]` Mint account',
-        "5. `[writable]` Seller's token account",
-        "6. `[writable]` Gumball machine's token account",
-        '7. `[]` Token program',
-        '8. `[]` Associated Token program',
-        '9. `[]` System program',
-        '10. `[]` Rent sysvar',
+        '1. `[signer]` Gumball Machine authority',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball Machine account.'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'sellerHistory';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller history account.'];
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [
         {
-          name: 'authorityPda';
-          isMut: true;
-          isSigner: false;
+          name: 'instantDelivery';
+          type: 'bool';
         },
+      ];
+    },
+    {
+      name: 'createReceiptCollection';
+      docs: [
+        'Creates the Core collection of the draw receipts, with the authority PDA as its',
+        'update authority. Receipts use the name and URI of the collection.',
+        '',
+        'Once the collection exists, every revealed draw mints a receipt for each drawn item,',
+        'with the gumball machine, index and draw slot as attributes. The receipt becomes the',
+        'only way to claim the item, so it can be traded until it is burnt with',
+        '`claim_with_receipt`. Items held by a receipt cannot be settled until it is burnt.',
+        'Receipts cannot be used with instant delivery.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '2. `[signer, writable]` Gumball Machine authority (payer of the collection)',
+        '3. `[signer, writable]` Receipt collection account',
+        '4. `[]` MPL Core program',
+        '5. `[]` System program',
+      ];
+      accounts: [
         {
-          name: 'seller';
+          name: 'gumballMachine';
           isMut: true;
-          isSigner: true;
-          docs: ['Seller of the tokens'];
+          isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'mint';
+          name: 'authorityPda';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'tokenAccount';
+          name: 'authority';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Pays for the collection account.',
+          ];
         },
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'receiptCollection';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Receipt collection account.'];
         },
         {
-          name: 'tokenProgram';
+          name: 'mplCoreProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'associatedTokenProgram';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
+          docs: ['System program.'];
         },
+      ];
+      args: [
         {
-          name: 'systemProgram';
-          isMut: false;
+          name: 'name';
+          type: 'string';
+        },
+        {
+          name: 'uri';
+          type: 'string';
+        },
+      ];
+    },
+    {
+      name: 'resizeCapacity';
+      docs: [
+        'Resizes the item capacity of the gumball machine, moving the data stored after',
+        'the config lines to its new position. The capacity can be decreased down to the',
+        'number of items loaded.',
+        '',
+        'The account can only grow by 10KiB per instruction, so a larger increase is done',
+        'over several calls with the same capacity: each call grows the account and the',
+        'data is moved by the last one.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[]` System program',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
           isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'rent';
+          name: 'authority';
+          isMut: true;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Pays for (or receives) the rent difference.',
+          ];
+        },
+        {
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
+          docs: ['System program.'];
         },
       ];
       args: [
         {
-          name: 'amount';
+          name: 'itemCapacity';
           type: 'u64';
         },
+      ];
+    },
+    {
+      name: 'migrate';
+      docs: [
+        'Migrates the gumball machine account to the current version, upgrading config',
+        'lines and adding the fields of newer versions with default values.',
+        '',
+        'Accounts that need to grow by more than 10kb are resized over multiple calls,',
+        'the data is only migrated once the account reaches its final size.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[]` System program',
+      ];
+      accounts: [
         {
-          name: 'quantity';
-          type: 'u16';
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'args';
-          type: {
-            defined: 'AddItemArgs';
-          };
+          name: 'authority';
+          isMut: true;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Pays for the additional rent.',
+          ];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+          docs: ['System program.'];
         },
       ];
+      args: [];
     },
     {
-      name: 'requestAddNft';
+      name: 'addNft';
       docs: [
-        'Request to add a NFT to the gumball machine.',
-        "Freezes the seller's NFT and creates a request account.",
+        'Add legacy NFTs to the gumball machine.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]).',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '4. `[signer, writable]` Seller of the nft.',
-        '5. `[]` Mint account of the NFT.',
-        "6. `[writable]` Seller's token account for the NFT.",
-        '7. `[writable]` Metadata account of the NFT.',
-        '8. `[]` Edition account of the NFT.',
-        '9. `[]` Token program.',
-        '10. `[]` Token Metadata program.',
-        '11. `[]` System program.',
-        '12. `[writable, optional]` Seller token record (pNFT).',
-        '13. `[optional]` Auth rules account (pNFT).',
-        '14. `[optional]` Instructions sysvar (pNFT).',
-        '15. `[optional]` Auth rules program (pNFT).',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[]` Mint account',
+        '5. `[writable]` Token account',
+        '6. `[writable]` Metadata account',
+        '7. `[]` Edition account',
+        '8. `[]` Token program',
+        '9. `[]` Token Metadata program',
+        '10. `[]` System program',
+        '11. `[writable, optional]` Seller token record (pNFT)',
+        '12. `[optional]` Auth rules account (pNFT)',
+        '13. `[optional]` Instructions sysvar (pNFT)',
+        '14. `[optional]` Auth rules program (pNFT)',
       ];
       accounts: [
         {
@@ -419,12 +600,6 @@ export type MallowGumball = {
           isSigner: false;
           docs: ['Seller history account.'];
         },
-        {
-          name: 'addItemRequest';
-          isMut: true;
-          isSigner: false;
-          docs: ['Add item request account.'];
-        },
         {
           name: 'authorityPda';
           isMut: true;
@@ -497,25 +672,30 @@ export type MallowGumball = {
           isOptional: true;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'AddItemArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'requestAddCoreAsset';
+      name: 'addCoreAsset';
       docs: [
-        'Request to add a core asset to the gumball machine.',
-        "Freezes the seller's asset and creates a request account.",
+        'Add Core assets to the gumball machine.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]).',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '4. `[signer, writable]` Seller of the asset.',
-        '5. `[writable]` Asset account.',
-        '6. `[writable, optional]` Collection account if asset is part of one.',
-        '7. `[]` MPL Core program.',
-        '8. `[]` System program.',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[writable]` Asset account',
+        '5. `[writable, optional]` Collection account',
+        '6. `[]` MPL Core program',
+        '7. `[]` System program',
       ];
       accounts: [
         {
@@ -530,12 +710,6 @@ export type MallowGumball = {
           isSigner: false;
           docs: ['Seller history account.'];
         },
-        {
-          name: 'addItemRequest';
-          isMut: true;
-          isSigner: false;
-          docs: ['Add item request account.'];
-        },
         {
           name: 'authorityPda';
           isMut: true;
@@ -570,47 +744,44 @@ export type MallowGumball = {
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'AddItemArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'cancelAddNftRequest';
+      name: 'addCoreAssets';
       docs: [
-        'Cancel a request to add a NFT to the gumball machine.',
-        "Thaws and revokes delegate from the seller's NFT and closes the request account.",
+        'Add multiple core assets to the gumball machine.',
+        'Validates the seller once and freezes each asset with the authority PDA as delegate.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
-        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]). Will be closed.',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
-        '3. `[signer, writable]` Seller of the NFT.',
-        '4. `[]` Mint account of the NFT.',
-        "5. `[writable]` Seller's token account for the NFT.",
-        "6. `[writable]` Authority PDA's token account.",
-        '7. `[]` Edition account of the NFT.',
-        '8. `[]` Token program.',
-        '9. `[]` Associated Token program.',
-        '10. `[]` Token Metadata program.',
-        '11. `[]` System program.',
-        '12. `[]` Rent sysvar.',
-        '13. `[writable, optional]` Metadata account (pNFT).',
-        '14. `[writable, optional]` Seller token record (pNFT).',
-        '15. `[optional]` Auth rules account (pNFT).',
-        '16. `[optional]` Instructions sysvar (pNFT).',
-        '17. `[optional]` Auth rules program (pNFT).',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[writable, optional]` Collection account shared by the assets',
+        '5. `[]` MPL Core program',
+        '6. `[]` System program',
+        'Remaining accounts: Asset accounts (writable)',
       ];
       accounts: [
         {
-          name: 'sellerHistory';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Seller history account.'];
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'addItemRequest';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Add item request account.'];
+          docs: ['Seller history account.'];
         },
         {
           name: 'authorityPda';
@@ -621,119 +792,166 @@ export type MallowGumball = {
           name: 'seller';
           isMut: true;
           isSigner: true;
-          docs: ['Seller of the NFT.'];
-        },
-        {
-          name: 'mint';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'tokenAccount';
-          isMut: true;
-          isSigner: false;
+          docs: ['Seller of the assets.'];
         },
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'collection';
           isMut: true;
           isSigner: false;
+          isOptional: true;
+          docs: ['Collection shared by the assets if they are part of one.'];
         },
         {
-          name: 'edition';
+          name: 'mplCoreProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'associatedTokenProgram';
-          isMut: false;
-          isSigner: false;
-        },
+          name: 'args';
+          type: {
+            defined: 'AddItemArgs';
+          };
+        },
+      ];
+    },
+    {
+      name: 'addTokens';
+      docs: [
+        'Add fungible tokens to the gumball machine.',
+        'Token-2022 mints are supported, the seller pays any transfer fee so the gumball machine',
+        'receives the full amount. Non-transferable mints are rejected.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[]` Mint account',
+        "5. `[writable]` Seller's token account",
+        "6. `[writable]` Gumball machine's token account",
+        '7. `[]` Token program (SPL Token or Token-2022)',
+        '8. `[]` Associated Token program',
+        '9. `[]` System program',
+        '10. `[]` Rent sysvar',
+      ];
+      accounts: [
         {
-          name: 'tokenMetadataProgram';
-          isMut: false;
+          name: 'gumballMachine';
+          isMut: true;
           isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'systemProgram';
-          isMut: false;
+          name: 'sellerHistory';
+          isMut: true;
           isSigner: false;
+          docs: ['Seller history account.'];
         },
         {
-          name: 'rent';
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: true;
+          docs: ['Seller of the tokens'];
+        },
+        {
+          name: 'mint';
           isMut: false;
           isSigner: false;
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'];
         },
         {
-          name: 'metadata';
+          name: 'tokenAccount';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: [
-            'OPTIONAL PNFT ACCOUNTS',
-            '/// CHECK: Safe due to token metadata program check',
-          ];
         },
         {
-          name: 'sellerTokenRecord';
+          name: 'authorityPdaTokenAccount';
           isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'authRules';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'instructions';
+          name: 'associatedTokenProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'authRulesProgram';
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'quantity';
+          type: 'u16';
+        },
+        {
+          name: 'args';
+          type: {
+            defined: 'AddItemArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'cancelAddCoreAssetRequest';
+      name: 'addCompressedNft';
       docs: [
-        'Cancel a request to add a core asset to the gumball machine.',
-        "Thaws and revokes delegate from the seller's asset and closes the request account.",
+        'Add compressed nft to the gumball machine.',
+        'Transfers the leaf to the authority PDA, the config line records the asset id.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
-        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
-        '3. `[signer, writable]` Seller of the asset.',
-        '4. `[writable]` Asset account.',
-        '5. `[writable, optional]` Collection account if asset is part of one.',
-        '6. `[]` MPL Core program.',
-        '7. `[]` System program.',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[writable]` Merkle tree account',
+        '5. `[]` Tree config account',
+        '6. `[]` Bubblegum program',
+        '7. `[]` SPL Account Compression program',
+        '8. `[]` SPL Noop program',
+        '9. `[]` System program',
+        'Remaining accounts: Merkle proof nodes',
       ];
       accounts: [
         {
-          name: 'sellerHistory';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Seller history account.'];
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'addItemRequest';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Add item request account.'];
+          docs: ['Seller history account.'];
         },
         {
           name: 'authorityPda';
@@ -744,22 +962,30 @@ export type MallowGumball = {
           name: 'seller';
           isMut: true;
           isSigner: true;
-          docs: ['Seller of the asset.'];
+          docs: ['Seller of the nft'];
         },
         {
-          name: 'asset';
+          name: 'merkleTree';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'collection';
-          isMut: true;
+          name: 'treeConfig';
+          isMut: false;
           isSigner: false;
-          isOptional: true;
-          docs: ["Core asset's collection if it's part of one."];
         },
         {
-          name: 'mplCoreProgram';
+          name: 'bubblegumProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'compressionProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'logWrapper';
           isMut: false;
           isSigner: false;
         },
@@ -769,23 +995,36 @@ export type MallowGumball = {
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'AddItemArgs';
+          };
+        },
+        {
+          name: 'compressedNft';
+          type: {
+            defined: 'CompressedNftArgs';
+          };
+        },
+      ];
     },
     {
-      name: 'approveAddItem';
+      name: 'addSolPrize';
       docs: [
-        'Approve adding an item to the gumball machine.',
-        "Moves the item from the request to the gumball machine's config lines.",
+        'Add native SOL prizes to the gumball machine.',
+        'Transfers `amount * quantity` lamports to the authority PDA, the config lines record the native mint.',
+        'Each prize must be at least the rent-exempt minimum, and the lamports of SOL prizes are',
+        'tracked apart from the sale revenue so payouts of revenue cannot use them.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '3. `[signer, writable]` Authority of the gumball machine.',
-        '4. `[writable]` Seller account (receiver of closed request account rent).',
-        '5. `[]` Asset/Mint account (checked via add_item_request constraint).',
-        '6. `[]` System program.',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[]` System program',
       ];
       accounts: [
         {
@@ -795,10 +1034,10 @@ export type MallowGumball = {
           docs: ['Gumball Machine account.'];
         },
         {
-          name: 'addItemRequest';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Add item request account.'];
+          docs: ['Seller history account.'];
         },
         {
           name: 'authorityPda';
@@ -806,57 +1045,58 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'authority';
+          name: 'seller';
           isMut: true;
           isSigner: true;
-          docs: ['Authority of the gumball machine.'];
+          docs: ['Seller of the prize'];
         },
         {
-          name: 'seller';
-          isMut: true;
+          name: 'systemProgram';
+          isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'asset';
-          isMut: false;
-          isSigner: false;
+          name: 'amount';
+          type: 'u64';
         },
         {
-          name: 'systemProgram';
-          isMut: false;
-          isSigner: false;
+          name: 'quantity';
+          type: 'u16';
+        },
+        {
+          name: 'args';
+          type: {
+            defined: 'AddItemArgs';
+          };
         },
       ];
-      args: [];
     },
     {
-      name: 'removeNft';
+      name: 'requestAddNft';
       docs: [
-        'Remove legacy NFT from the gumball machine.',
-        "Thaws and revokes delegate from the seller's NFT and removes it from the config lines.",
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        'Request to add a NFT to the gumball machine.',
+        "Freezes the seller's NFT and creates a request account.",
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account.',
         '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the NFT).',
+        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]).',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '4. `[signer, writable]` Seller of the nft.',
         '5. `[]` Mint account of the NFT.',
         "6. `[writable]` Seller's token account for the NFT.",
-        "7. `[writable]` Authority PDA's token account.",
+        '7. `[writable]` Metadata account of the NFT.',
         '8. `[]` Edition account of the NFT.',
         '9. `[]` Token program.',
-        '10. `[]` Associated Token program.',
-        '11. `[]` Token Metadata program.',
-        '12. `[]` System program.',
-        '13. `[]` Rent sysvar.',
-        '14. `[writable, optional]` Metadata account (pNFT).',
-        '15. `[writable, optional]` Seller token record (pNFT).',
-        '16. `[optional]` Auth rules account (pNFT).',
-        '17. `[optional]` Instructions sysvar (pNFT).',
-        '18. `[optional]` Auth rules program (pNFT).',
+        '10. `[]` Token Metadata program.',
+        '11. `[]` System program.',
+        '12. `[writable, optional]` Seller token record (pNFT).',
+        '13. `[optional]` Auth rules account (pNFT).',
+        '14. `[optional]` Instructions sysvar (pNFT).',
+        '15. `[optional]` Auth rules program (pNFT).',
       ];
       accounts: [
         {
@@ -872,22 +1112,21 @@ export type MallowGumball = {
           docs: ['Seller history account.'];
         },
         {
-          name: 'authorityPda';
+          name: 'addItemRequest';
           isMut: true;
           isSigner: false;
+          docs: ['Add item request account.'];
         },
         {
-          name: 'authority';
-          isMut: false;
-          isSigner: true;
-          docs: [
-            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
-          ];
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
         },
         {
           name: 'seller';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Seller of the nft'];
         },
         {
           name: 'mint';
@@ -900,7 +1139,7 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'metadata';
           isMut: true;
           isSigner: false;
         },
@@ -914,11 +1153,6 @@ export type MallowGumball = {
           isMut: false;
           isSigner: false;
         },
-        {
-          name: 'associatedTokenProgram';
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: 'tokenMetadataProgram';
           isMut: false;
@@ -930,29 +1164,15 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'rent';
-          isMut: false;
+          name: 'sellerTokenRecord';
+          isMut: true;
           isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS'];
         },
         {
-          name: 'metadata';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: [
-            'OPTIONAL PNFT ACCOUNTS',
-            '/// CHECK: Safe due to token metadata program check',
-          ];
-        },
-        {
-          name: 'sellerTokenRecord';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-        },
-        {
-          name: 'authRules';
-          isMut: false;
+          name: 'authRules';
+          isMut: false;
           isSigner: false;
           isOptional: true;
         },
@@ -969,27 +1189,21 @@ export type MallowGumball = {
           isOptional: true;
         },
       ];
-      args: [
-        {
-          name: 'index';
-          type: 'u32';
-        },
-      ];
+      args: [];
     },
     {
-      name: 'removeCoreAsset';
+      name: 'requestAddCoreAsset';
       docs: [
-        'Remove Core asset from the gumball machine.',
-        "Thaws and revokes delegate from the seller's asset and removes it from the config lines.",
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        'Request to add a core asset to the gumball machine.',
+        "Freezes the seller's asset and creates a request account.",
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account.',
         '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the asset).',
+        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]).',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '4. `[signer, writable]` Seller of the asset.',
         '5. `[writable]` Asset account.',
         '6. `[writable, optional]` Collection account if asset is part of one.',
         '7. `[]` MPL Core program.',
@@ -1009,20 +1223,21 @@ export type MallowGumball = {
           docs: ['Seller history account.'];
         },
         {
-          name: 'authorityPda';
+          name: 'addItemRequest';
           isMut: true;
           isSigner: false;
+          docs: ['Add item request account.'];
         },
         {
-          name: 'authority';
-          isMut: false;
-          isSigner: true;
-          docs: ['Seller of the asset.'];
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
         },
         {
           name: 'seller';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Seller of the asset.'];
         },
         {
           name: 'asset';
@@ -1047,42 +1262,36 @@ export type MallowGumball = {
           isSigner: false;
         },
       ];
-      args: [
-        {
-          name: 'index';
-          type: 'u32';
-        },
-      ];
+      args: [];
     },
     {
-      name: 'removeTokens';
+      name: 'cancelAddNftRequest';
       docs: [
-        'Remove fungible tokens from the gumball machine.',
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        'Cancel a request to add a NFT to the gumball machine.',
+        "Thaws and revokes delegate from the seller's NFT and closes the request account.",
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the tokens).',
-        '5. `[]` Mint account',
-        "6. `[writable]` Seller's token account",
-        "7. `[writable]` Gumball machine's token account",
-        '8. `[]` Token program',
-        '9. `[]` Associated Token program',
-        '10. `[]` System program',
-        '11. `[]` Rent sysvar',
-        'DEPRECATED: Use remove_tokens_span instead',
+        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
+        '3. `[signer, writable]` Seller of the NFT.',
+        '4. `[]` Mint account of the NFT.',
+        "5. `[writable]` Seller's token account for the NFT.",
+        "6. `[writable]` Authority PDA's token account.",
+        '7. `[]` Edition account of the NFT.',
+        '8. `[]` Token program.',
+        '9. `[]` Associated Token program.',
+        '10. `[]` Token Metadata program.',
+        '11. `[]` System program.',
+        '12. `[]` Rent sysvar.',
+        '13. `[writable, optional]` Metadata account (pNFT).',
+        '14. `[writable, optional]` Seller token record (pNFT).',
+        '15. `[optional]` Auth rules account (pNFT).',
+        '16. `[optional]` Instructions sysvar (pNFT).',
+        '17. `[optional]` Auth rules program (pNFT).',
       ];
       accounts: [
-        {
-          name: 'gumballMachine';
-          isMut: true;
-          isSigner: false;
-          docs: ['Gumball Machine account.'];
-        },
         {
           name: 'sellerHistory';
           isMut: true;
@@ -1090,22 +1299,21 @@ export type MallowGumball = {
           docs: ['Seller history account.'];
         },
         {
-          name: 'authorityPda';
+          name: 'addItemRequest';
           isMut: true;
           isSigner: false;
+          docs: ['Add item request account.'];
         },
         {
-          name: 'authority';
+          name: 'authorityPda';
           isMut: true;
-          isSigner: true;
-          docs: [
-            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
-          ];
+          isSigner: false;
         },
         {
           name: 'seller';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Seller of the NFT.'];
         },
         {
           name: 'mint';
@@ -1122,6 +1330,11 @@ export type MallowGumball = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'edition';
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: 'tokenProgram';
           isMut: false;
@@ -1132,6 +1345,11 @@ export type MallowGumball = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: 'systemProgram';
           isMut: false;
@@ -1142,46 +1360,61 @@ export type MallowGumball = {
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: 'indices';
-          type: 'bytes';
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL PNFT ACCOUNTS',
+            '/// CHECK: Safe due to token metadata program check',
+          ];
         },
         {
-          name: 'amount';
-          type: 'u64';
+          name: 'sellerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
         },
       ];
+      args: [];
     },
     {
-      name: 'removeTokensSpan';
+      name: 'cancelAddCoreAssetRequest';
       docs: [
-        'Remove fungible tokens from the gumball machine.',
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        'Cancel a request to add a core asset to the gumball machine.',
+        "Thaws and revokes delegate from the seller's asset and closes the request account.",
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the tokens).',
-        '5. `[]` Mint account',
-        "6. `[writable]` Seller's token account",
-        "7. `[writable]` Gumball machine's token account",
-        '8. `[]` Token program',
-        '9. `[]` Associated Token program',
-        '10. `[]` System program',
-        '11. `[]` Rent sysvar',
+        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
+        '3. `[signer, writable]` Seller of the asset.',
+        '4. `[writable]` Asset account.',
+        '5. `[writable, optional]` Collection account if asset is part of one.',
+        '6. `[]` MPL Core program.',
+        '7. `[]` System program.',
       ];
       accounts: [
-        {
-          name: 'gumballMachine';
-          isMut: true;
-          isSigner: false;
-          docs: ['Gumball Machine account.'];
-        },
         {
           name: 'sellerHistory';
           isMut: true;
@@ -1189,45 +1422,36 @@ export type MallowGumball = {
           docs: ['Seller history account.'];
         },
         {
-          name: 'authorityPda';
+          name: 'addItemRequest';
           isMut: true;
           isSigner: false;
+          docs: ['Add item request account.'];
         },
         {
-          name: 'authority';
+          name: 'authorityPda';
           isMut: true;
-          isSigner: true;
-          docs: [
-            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
-          ];
+          isSigner: false;
         },
         {
           name: 'seller';
           isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'mint';
-          isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Seller of the asset.'];
         },
         {
-          name: 'tokenAccount';
+          name: 'asset';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'collection';
           isMut: true;
           isSigner: false;
+          isOptional: true;
+          docs: ["Core asset's collection if it's part of one."];
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'associatedTokenProgram';
+          name: 'mplCoreProgram';
           isMut: false;
           isSigner: false;
         },
@@ -1236,247 +1460,284 @@ export type MallowGumball = {
           isMut: false;
           isSigner: false;
         },
-        {
-          name: 'rent';
-          isMut: false;
-          isSigner: false;
-        },
-      ];
-      args: [
-        {
-          name: 'amount';
-          type: 'u64';
-        },
-        {
-          name: 'startIndex';
-          type: 'u32';
-        },
-        {
-          name: 'endIndex';
-          type: 'u32';
-        },
       ];
+      args: [];
     },
     {
-      name: 'startSale';
+      name: 'closeExpiredAddItemRequest';
       docs: [
-        'Allows minting to begin.',
+        'Close an expired request to add an item to the gumball machine. Can be called by anyone.',
+        "Thaws and revokes delegate from the seller's item, closes the request account and",
+        'refunds rent to the seller.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
+        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
+        '3. `[signer, writable]` Payer.',
+        '4. `[writable]` Seller of the item.',
+        '5. `[writable]` Asset account (or mint for NFTs).',
+        '6. `[]` System program.',
+        '7. `[writable, optional]` Collection account (Core asset).',
+        '8. `[optional]` MPL Core program (Core asset).',
+        "9. `[writable, optional]` Seller's token account (NFT).",
+        "10. `[writable, optional]` Authority PDA's token account (NFT).",
+        '11. `[optional]` Edition account (NFT).',
+        '12. `[optional]` Token program (NFT).',
+        '13. `[optional]` Associated Token program (NFT).',
+        '14. `[optional]` Token Metadata program (NFT).',
+        '15. `[writable, optional]` Metadata account (pNFT).',
+        '16. `[writable, optional]` Seller token record (pNFT).',
+        '17. `[optional]` Auth rules account (pNFT).',
+        '18. `[optional]` Instructions sysvar (pNFT).',
+        '19. `[optional]` Auth rules program (pNFT).',
       ];
       accounts: [
         {
-          name: 'gumballMachine';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Seller history account.'];
         },
         {
-          name: 'authority';
-          isMut: false;
-          isSigner: true;
-          docs: [
-            'Gumball Machine authority. This can be the mint authority or the authority.',
-          ];
-        },
-      ];
-      args: [];
-    },
-    {
-      name: 'endSale';
-      docs: [
-        'Disables minting and allows sales to be settled.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer, writable]` Gumball Machine authority',
-      ];
-      accounts: [
+          name: 'addItemRequest';
+          isMut: true;
+          isSigner: false;
+          docs: ['Add item request account.'];
+        },
         {
-          name: 'gumballMachine';
+          name: 'authorityPda';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authority';
+          name: 'payer';
           isMut: true;
           isSigner: true;
           docs: [
-            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Payer of the transaction, anyone can close an expired request.',
           ];
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'draw';
-      docs: [
-        'Draw for a random item from the gumball machine.',
-        'Only the gumball machine mint authority is allowed to draw.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine mint authority',
-        '2. `[signer, writable]` Payer',
-        '3. `[]` Buyer account',
-        '4. `[]` System program',
-        '5. `[]` SlotHashes sysvar cluster data',
-      ];
-      accounts: [
         {
-          name: 'gumballMachine';
+          name: 'seller';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
         },
         {
-          name: 'mintAuthority';
+          name: 'asset';
+          isMut: true;
+          isSigner: false;
+          docs: ['Asset (or mint for NFTs) of the request.'];
+        },
+        {
+          name: 'systemProgram';
           isMut: false;
-          isSigner: true;
+          isSigner: false;
+        },
+        {
+          name: 'collection';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
           docs: [
-            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if closing a Core asset',
+            "Core asset's collection if it's part of one.",
           ];
         },
         {
-          name: 'payer';
+          name: 'mplCoreProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenAccount';
           isMut: true;
-          isSigner: true;
-          docs: ['Payer for the transaction and account allocation (rent).'];
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if closing a NFT or PNFT',
+          ];
         },
         {
-          name: 'buyer';
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'edition';
           isMut: false;
           isSigner: false;
-          docs: ['NFT account owner.', ''];
+          isOptional: true;
         },
         {
-          name: 'systemProgram';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
-          docs: ['System program.'];
+          isOptional: true;
         },
         {
-          name: 'recentSlothashes';
+          name: 'associatedTokenProgram';
           isMut: false;
           isSigner: false;
-          docs: ['SlotHashes sysvar cluster data.', ''];
+          isOptional: true;
         },
         {
-          name: 'eventAuthority';
+          name: 'tokenMetadataProgram';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'program';
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if closing a PNFT'];
+        },
+        {
+          name: 'sellerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
       ];
       args: [];
     },
     {
-      name: 'incrementTotalRevenue';
+      name: 'approveAddItem';
       docs: [
-        'Increments total revenue earned by the gumball machine.',
-        '',
-        'Only the gumball machine mint authority is allowed to increment revenue. This is',
-        "required as token transfers don't occur in this program, but total is needed",
-        'when settling.',
+        'Approve adding an item to the gumball machine.',
+        "Moves the item from the request to the gumball machine's config lines.",
+        'The authority can optionally set the proceeds weight of the item (defaults to 1).',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine mint authority',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer, writable]` Authority of the gumball machine.',
+        '4. `[writable]` Seller account (receiver of closed request account rent).',
+        '5. `[]` Asset/Mint account (checked via add_item_request constraint).',
+        '6. `[]` System program.',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'mintAuthority';
-          isMut: false;
+          name: 'addItemRequest';
+          isMut: true;
+          isSigner: false;
+          docs: ['Add item request account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: true;
           isSigner: true;
-          docs: [
-            'Gumball machine mint authority (mint only allowed for the mint_authority).',
-          ];
+          docs: ['Authority of the gumball machine.'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'asset';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
         },
       ];
       args: [
         {
-          name: 'revenue';
-          type: 'u64';
+          name: 'weight';
+          type: {
+            option: 'u32';
+          };
         },
       ];
     },
     {
-      name: 'sellItem';
+      name: 'rejectAddItem';
       docs: [
-        'Sell an item back to the gumball machine using buy back funds.',
-        'The payer must be the seller or the oracle_signer.',
-        'Buying back to the gumball machine is currently not supported, but will be in the future.',
-        'If buying back to the authority, the item is marked as claimed and transferred to the buyer (authority).',
+        'Reject a request to add an item to the gumball machine.',
+        'Thaws and revokes the NFT or Core asset and closes the request account (rent to seller).',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (must be seller or oracle_signer)',
-        '1. `[signer]` Oracle signer (must match buy_back_config)',
-        '2. `[writable]` Gumball Machine account',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '4. `[writable]` Mint account of the item (or Asset account for Core)',
-        '5. `[writable]` Seller account',
-        '6. `[writable]` Buyer account (must be gumball machine or authority)',
-        '7. `[]` System program',
-        '8. `[]` Token program',
-        '9. `[]` Associated Token program',
-        '10. `[]` Rent sysvar',
-        '11. `[writable, optional]` Fee account (if fee configured)',
-        '12. `[writable, optional]` Fee payment account (if fee configured)',
-        '13. `[writable, optional]` Payment mint (if not native SOL)',
-        '14. `[writable, optional]` Seller payment account (if not native SOL)',
-        '15. `[writable, optional]` Authority PDA payment account (if not native SOL)',
-        '16. `[writable, optional]` Collection account (for Core asset)',
-        '17. `[optional]` MPL Core program (for Core asset)',
-        '18. `[writable, optional]` Authority PDA token account (for NFT/Fungible)',
-        '19. `[writable, optional]` Seller token account (for NFT/Fungible)',
-        '20. `[writable, optional]` Buyer token account (for NFT/Fungible)',
-        '21. `[writable, optional]` Metadata account (for NFT/PNFT)',
-        '22. `[writable, optional]` Edition account (for NFT/PNFT)',
-        '23. `[optional]` Token Metadata program (for NFT/PNFT)',
-        '24. `[writable, optional]` Authority PDA token record (for pNFT)',
-        '25. `[writable, optional]` Buyer token record (for pNFT)',
-        '26. `[optional]` Auth rules account (for pNFT)',
-        '27. `[optional]` Instructions sysvar (for pNFT)',
-        '28. `[optional]` Auth rules program (for pNFT)',
+        '0. `[]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '4. `[signer, writable]` Authority of the gumball machine.',
+        '5. `[writable]` Seller account (receiver of closed request account rent).',
+        '6. `[writable]` Asset/Mint account (checked via add_item_request constraint).',
+        '7. `[]` System program.',
+        '8. `[writable, optional]` Collection account (Core asset).',
+        '9. `[optional]` MPL Core program (Core asset).',
+        "10. `[writable, optional]` Seller's token account (NFT).",
+        "11. `[writable, optional]` Authority PDA's token account (NFT).",
+        '12. `[optional]` Edition account (NFT).',
+        '13. `[optional]` Token program (NFT).',
+        '14. `[optional]` Associated Token program (NFT).',
+        '15. `[optional]` Token Metadata program (NFT).',
+        '16. `[writable, optional]` Metadata account (pNFT).',
+        '17. `[writable, optional]` Seller token record (pNFT).',
+        '18. `[optional]` Auth rules account (pNFT).',
+        '19. `[optional]` Instructions sysvar (pNFT).',
+        '20. `[optional]` Auth rules program (pNFT).',
       ];
       accounts: [
         {
-          name: 'payer';
-          isMut: true;
-          isSigner: true;
-          docs: [
-            'Must be the oracle signer or seller (oracle signer can sell on behalf of the seller to allow auto-buy back)',
-          ];
+          name: 'gumballMachine';
+          isMut: false;
+          isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'oracleSigner';
-          isMut: false;
-          isSigner: true;
-          docs: ['Oracle signer'];
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
         },
         {
-          name: 'gumballMachine';
+          name: 'addItemRequest';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Add item request account.'];
         },
         {
           name: 'authorityPda';
@@ -1484,22 +1745,21 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'mint';
+          name: 'authority';
           isMut: true;
-          isSigner: false;
-          docs: ['Mint of the item (or asset for Core assets)'];
+          isSigner: true;
+          docs: ['Authority of the gumball machine.'];
         },
         {
           name: 'seller';
           isMut: true;
           isSigner: false;
-          docs: ['Seller of the item'];
         },
         {
-          name: 'buyer';
+          name: 'asset';
           isMut: true;
           isSigner: false;
-          docs: ['Buyer of the item'];
+          docs: ['Asset (or mint for NFTs) of the request.'];
         },
         {
           name: 'systemProgram';
@@ -1507,70 +1767,14 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
+          name: 'collection';
+          isMut: true;
           isSigner: false;
-        },
-        {
-          name: 'associatedTokenProgram';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'rent';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'feeAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: [
-            'OPTIONAL FEE ACCOUNTS - only required if there is a fee config on the gumball machine',
-            'Marketplace fee account',
-          ];
-        },
-        {
-          name: 'feePaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Marketplace fee payment account'];
-        },
-        {
-          name: 'paymentMint';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: [
-            'OPTIONAL SPL TOKEN ACCOUNTS - only required if selling for SPL token',
-            'Mint of payment token',
-          ];
-        },
-        {
-          name: 'sellerPaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Seller payment account'];
-        },
-        {
-          name: 'authorityPdaPaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Authority PDA payment account'];
-        },
-        {
-          name: 'collection';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: [
-            'OPTIONAL CORE ASSET ACCOUNTS - only required if selling Core asset',
-            'Collection of the asset',
-          ];
+          isOptional: true;
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if rejecting a Core asset',
+            "Core asset's collection if it's part of one.",
+          ];
         },
         {
           name: 'mplCoreProgram';
@@ -1579,41 +1783,35 @@ export type MallowGumball = {
           isOptional: true;
         },
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'tokenAccount';
           isMut: true;
           isSigner: false;
           isOptional: true;
           docs: [
-            'OPTIONAL TOKEN ACCOUNTS - only required if selling NFT or Fungible assets',
-            'Authority PDA token account',
+            'OPTIONAL NFT ACCOUNTS - only required if rejecting a NFT or PNFT',
           ];
         },
         {
-          name: 'sellerTokenAccount';
+          name: 'authorityPdaTokenAccount';
           isMut: true;
           isSigner: false;
           isOptional: true;
-          docs: ['Seller token account'];
         },
         {
-          name: 'buyerTokenAccount';
-          isMut: true;
+          name: 'edition';
+          isMut: false;
           isSigner: false;
           isOptional: true;
-          docs: ['Buyer token account'];
         },
         {
-          name: 'metadata';
-          isMut: true;
+          name: 'tokenProgram';
+          isMut: false;
           isSigner: false;
           isOptional: true;
-          docs: [
-            'OPTIONAL NFT ACCOUNTS - only required if selling NFT or PNFT',
-          ];
         },
         {
-          name: 'edition';
-          isMut: true;
+          name: 'associatedTokenProgram';
+          isMut: false;
           isSigner: false;
           isOptional: true;
         },
@@ -1624,14 +1822,14 @@ export type MallowGumball = {
           isOptional: true;
         },
         {
-          name: 'authorityPdaTokenRecord';
+          name: 'metadata';
           isMut: true;
           isSigner: false;
           isOptional: true;
-          docs: ['OPTIONAL PNFT ACCOUNTS - only required if selling PNFT'];
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if rejecting a PNFT'];
         },
         {
-          name: 'buyerTokenRecord';
+          name: 'sellerTokenRecord';
           isMut: true;
           isSigner: false;
           isOptional: true;
@@ -1667,66 +1865,107 @@ export type MallowGumball = {
       ];
       args: [
         {
-          name: 'index';
-          type: 'u32';
-        },
-        {
-          name: 'amount';
-          type: 'u64';
-        },
-        {
-          name: 'buyPrice';
-          type: 'u64';
+          name: 'reason';
+          type: {
+            option: 'u8';
+          };
         },
       ];
     },
     {
-      name: 'claimCoreAsset';
+      name: 'removeNft';
       docs: [
-        'Claims a Core asset from the gumball machine for a specific buyer.',
-        'Transfers the asset from the PDA to the buyer.',
+        'Remove legacy NFT from the gumball machine.',
+        "Thaws and revokes delegate from the seller's NFT and removes it from the config lines.",
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can claim the item)',
-        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable]` Seller account',
-        '4. `[]` Buyer account',
-        '5. `[]` System program',
-        '6. `[writable]` Asset account',
-        '7. `[writable, optional]` Collection account if asset is part of one.',
-        '8. `[]` MPL Core program.',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the NFT).',
+        '5. `[]` Mint account of the NFT.',
+        "6. `[writable]` Seller's token account for the NFT.",
+        "7. `[writable]` Authority PDA's token account.",
+        '8. `[]` Edition account of the NFT.',
+        '9. `[]` Token program.',
+        '10. `[]` Associated Token program.',
+        '11. `[]` Token Metadata program.',
+        '12. `[]` System program.',
+        '13. `[]` Rent sysvar.',
+        '14. `[writable, optional]` Metadata account (pNFT).',
+        '15. `[writable, optional]` Seller token record (pNFT).',
+        '16. `[optional]` Auth rules account (pNFT).',
+        '17. `[optional]` Instructions sysvar (pNFT).',
+        '18. `[optional]` Auth rules program (pNFT).',
       ];
       accounts: [
         {
-          name: 'payer';
+          name: 'gumballMachine';
           isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
+          isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'gumballMachine';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Seller history account.'];
         },
         {
           name: 'authorityPda';
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+          ];
+        },
         {
           name: 'seller';
           isMut: true;
           isSigner: false;
-          docs: ['Seller of the nft'];
         },
         {
-          name: 'buyer';
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'edition';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenMetadataProgram';
           isMut: false;
           isSigner: false;
-          docs: ['buyer of the nft'];
         },
         {
           name: 'systemProgram';
@@ -1734,30 +1973,43 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'asset';
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'metadata';
           isMut: true;
           isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL PNFT ACCOUNTS',
+            '/// CHECK: Safe due to token metadata program check',
+          ];
         },
         {
-          name: 'collection';
+          name: 'sellerTokenRecord';
           isMut: true;
           isSigner: false;
           isOptional: true;
         },
         {
-          name: 'mplCoreProgram';
+          name: 'authRules';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'eventAuthority';
+          name: 'instructions';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'program';
+          name: 'authRulesProgram';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
       ];
       args: [
@@ -1768,47 +2020,35 @@ export type MallowGumball = {
       ];
     },
     {
-      name: 'claimNft';
+      name: 'removeNftsSpan';
       docs: [
-        'Claims a legacy NFT from the gumball machine for a specific buyer.',
-        'Thaws and transfers the NFT from the PDA to the buyer.',
+        'Remove a span of legacy NFTs from the gumball machine.',
+        'Thaws and returns each NFT to its seller and compacts the config lines.',
+        'The signer can be the Gumball Machine authority or the seller of every item in the span.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can claim the item)',
-        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable]` Seller account',
-        '4. `[]` Buyer account',
-        '5. `[]` Token program',
-        '6. `[]` Associated Token program',
-        '7. `[]` System program',
-        '8. `[]` Rent sysvar',
-        '9. `[]` Mint account',
-        "10. `[writable]` Buyer's token account",
-        "11. `[writable]` Authority PDA's token account",
-        '12. `[writable]` Metadata account',
-        '13. `[writable]` Edition account',
-        '14. `[]` Token Metadata program',
-        '15. `[writable, optional]` Seller token record (pNFT)',
-        '16. `[writable, optional]` Authority PDA token record (pNFT)',
-        '17. `[writable, optional]` Buyer token record (pNFT)',
-        '18. `[optional]` Auth rules account (pNFT)',
-        '19. `[optional]` Instructions sysvar (pNFT)',
-        '20. `[optional]` Auth rules program (pNFT)',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '2. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
+        '3. `[]` Token program.',
+        '4. `[]` Associated Token program.',
+        '5. `[]` Token Metadata program.',
+        '6. `[]` System program.',
+        '7. `[]` Rent sysvar.',
+        '8. `[optional]` Auth rules account (pNFT).',
+        '9. `[optional]` Instructions sysvar (pNFT).',
+        '10. `[optional]` Auth rules program (pNFT).',
+        'Remaining accounts: For each item in the span, the seller (writable), seller history (writable),',
+        "mint, seller's token account (writable), authority PDA's token account (writable), edition,",
+        'metadata (writable) and seller token record (writable, pNFT only)',
       ];
       accounts: [
-        {
-          name: 'payer';
-          isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
-        },
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Gumball Machine account.'];
         },
         {
           name: 'authorityPda';
@@ -1816,24 +2056,25 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'seller';
+          name: 'authority';
           isMut: true;
-          isSigner: false;
-          docs: ['Seller of the nft'];
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the nfts (must be the gumball machine auth or the seller of every nft)',
+          ];
         },
         {
-          name: 'buyer';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
-          docs: ['buyer of the nft'];
         },
         {
-          name: 'tokenProgram';
+          name: 'associatedTokenProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'associatedTokenProgram';
+          name: 'tokenMetadataProgram';
           isMut: false;
           isSigner: false;
         },
@@ -1848,129 +2089,206 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'mint';
+          name: 'authRules';
           isMut: false;
           isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS'];
         },
         {
-          name: 'tokenAccount';
-          isMut: true;
+          name: 'instructions';
+          isMut: false;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'buyerTokenAccount';
-          isMut: true;
+          name: 'authRulesProgram';
+          isMut: false;
           isSigner: false;
-          docs: ['Nft token account for buyer'];
+          isOptional: true;
         },
+      ];
+      args: [
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'startIndex';
+          type: 'u32';
+        },
+        {
+          name: 'endIndex';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'removeCoreAsset';
+      docs: [
+        'Remove Core asset from the gumball machine.',
+        "Thaws and revokes delegate from the seller's asset and removes it from the config lines.",
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the asset).',
+        '5. `[writable]` Asset account.',
+        '6. `[writable, optional]` Collection account if asset is part of one.',
+        '7. `[]` MPL Core program.',
+        '8. `[]` System program.',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'metadata';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
+          docs: ['Seller history account.'];
         },
         {
-          name: 'edition';
+          name: 'authorityPda';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'tokenMetadataProgram';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Seller of the asset.'];
         },
         {
-          name: 'sellerTokenRecord';
+          name: 'seller';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['OPTIONAL PNFT ACCOUNTS'];
         },
         {
-          name: 'authorityPdaTokenRecord';
+          name: 'asset';
           isMut: true;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'buyerTokenRecord';
+          name: 'collection';
           isMut: true;
           isSigner: false;
           isOptional: true;
+          docs: ["Core asset's collection if it's part of one."];
         },
         {
-          name: 'authRules';
+          name: 'mplCoreProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
         {
-          name: 'instructions';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
+      ];
+      args: [
         {
-          name: 'authRulesProgram';
-          isMut: false;
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'removeCoreAssetsSpan';
+      docs: [
+        'Remove a span of Core assets from the gumball machine.',
+        'Thaws and returns each asset to its seller and compacts the config lines.',
+        'The signer can be the Gumball Machine authority or the seller of every item in the span.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '2. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '3. `[]` MPL Core program.',
+        '4. `[]` System program.',
+        'Remaining accounts: For each item in the span, the seller (writable), seller history (writable),',
+        'asset (writable) and collection (writable, MPL Core program if the asset has none)',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
           isSigner: false;
-          isOptional: true;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'eventAuthority';
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the assets (must be the gumball machine auth or the seller of every asset)',
+          ];
+        },
+        {
+          name: 'mplCoreProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'program';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'index';
+          name: 'startIndex';
+          type: 'u32';
+        },
+        {
+          name: 'endIndex';
           type: 'u32';
         },
       ];
     },
     {
-      name: 'claimTokens';
+      name: 'removeCompressedNft';
       docs: [
-        'Claims fungible tokens from the gumball machine for a specific buyer.',
+        'Remove compressed nft from the gumball machine.',
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can claim the tokens)',
-        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable]` Gumball Machine authority',
-        '4. `[writable]` Seller account',
-        '5. `[]` Buyer account',
-        '6. `[]` Token program',
-        '7. `[]` Associated Token program',
-        '8. `[]` System program',
-        '9. `[]` Rent sysvar',
-        '10. `[]` Mint account',
-        "11. `[writable]` Buyer's token account (must match mint and buyer)",
-        "12. `[writable]` Authority PDA's token account (must match mint and authority PDA)",
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the compressed nft).',
+        '5. `[writable]` Merkle tree account.',
+        '6. `[]` Tree config account.',
+        '7. `[]` Bubblegum program.',
+        '8. `[]` SPL Account Compression program.',
+        '9. `[]` SPL Noop program.',
+        '10. `[]` System program.',
+        'Remaining accounts: Merkle proof nodes',
       ];
       accounts: [
         {
-          name: 'payer';
+          name: 'gumballMachine';
           isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
+          isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'gumballMachine';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Seller history account.'];
         },
         {
           name: 'authorityPda';
@@ -1979,64 +2297,44 @@ export type MallowGumball = {
         },
         {
           name: 'authority';
-          isMut: true;
-          isSigner: false;
-          docs: ['Gumball machine authority'];
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+          ];
         },
         {
           name: 'seller';
           isMut: true;
           isSigner: false;
-          docs: ['Seller of the nft'];
-        },
-        {
-          name: 'buyer';
-          isMut: false;
-          isSigner: false;
-          docs: ['buyer of the nft'];
-        },
-        {
-          name: 'tokenProgram';
-          isMut: false;
-          isSigner: false;
         },
         {
-          name: 'associatedTokenProgram';
-          isMut: false;
+          name: 'merkleTree';
+          isMut: true;
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'treeConfig';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'rent';
+          name: 'bubblegumProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'mint';
+          name: 'compressionProgram';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'buyerTokenAccount';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'authorityPdaTokenAccount';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'eventAuthority';
+          name: 'logWrapper';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'program';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
@@ -2046,51 +2344,48 @@ export type MallowGumball = {
           name: 'index';
           type: 'u32';
         },
+        {
+          name: 'compressedNft';
+          type: {
+            defined: 'CompressedNftArgs';
+          };
+        },
       ];
     },
     {
-      name: 'settleCoreAssetSale';
+      name: 'removeTokens';
       docs: [
-        'Settles a Core asset sale',
-        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
-        'Distributes proceeds according to royalties and fee configuration.',
+        'Remove fungible tokens from the gumball machine.',
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can settle the sale)',
-        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable, optional]` Authority PDA payment account',
-        '4. `[writable]` Authority account',
-        '5. `[writable, optional]` Authority payment account',
-        '6. `[writable]` Seller account',
-        '7. `[writable, optional]` Seller payment account',
-        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[]` Buyer account',
-        '10. `[writable, optional]` Fee account',
-        '11. `[writable, optional]` Fee payment account',
-        '12. `[optional]` Payment mint',
-        '13. `[]` Token program',
-        '14. `[]` Associated Token program',
-        '15. `[]` System program',
-        '16. `[]` Rent sysvar',
-        '17. `[writable]` Asset account',
-        '18. `[writable, optional]` Collection account if asset is part of one.',
-        '19. `[]` MPL Core program.',
-        'Remaining accounts: Royalty recipients',
+        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the tokens).',
+        '5. `[]` Mint account',
+        "6. `[writable]` Seller's token account",
+        "7. `[writable]` Gumball machine's token account",
+        '8. `[]` Token program (SPL Token or Token-2022)',
+        '9. `[]` Associated Token program',
+        '10. `[]` System program',
+        '11. `[]` Rent sysvar',
+        'DEPRECATED: Use remove_tokens_span instead',
       ];
       accounts: [
         {
-          name: 'payer';
+          name: 'gumballMachine';
           isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
+          isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'gumballMachine';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Seller history account.'];
         },
         {
           name: 'authorityPda';
@@ -2098,70 +2393,33 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'authorityPdaPaymentAccount';
+          name: 'authority';
           isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority pda if using token payment'];
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+          ];
         },
         {
-          name: 'authority';
+          name: 'seller';
           isMut: true;
           isSigner: false;
-          docs: ['Seller of the nft'];
         },
         {
-          name: 'authorityPaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority if using token payment'];
-        },
-        {
-          name: 'seller';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller of the nft'];
-        },
-        {
-          name: 'sellerPaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for seller if using token payment'];
-        },
-        {
-          name: 'sellerHistory';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller history account.'];
-        },
-        {
-          name: 'buyer';
+          name: 'mint';
           isMut: false;
           isSigner: false;
-          docs: ['buyer of the nft'];
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'];
         },
         {
-          name: 'feeAccount';
+          name: 'tokenAccount';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Fee account for marketplace fee if using fee config'];
         },
         {
-          name: 'feePaymentAccount';
+          name: 'authorityPdaTokenAccount';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for marketplace fee if using token payment'];
-        },
-        {
-          name: 'paymentMint';
-          isMut: false;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment mint if using non-native payment token'];
         },
         {
           name: 'tokenProgram';
@@ -2183,163 +2441,85 @@ export type MallowGumball = {
           isMut: false;
           isSigner: false;
         },
-        {
-          name: 'asset';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'collection';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-        },
-        {
-          name: 'mplCoreProgram';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'eventAuthority';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'program';
-          isMut: false;
-          isSigner: false;
-        },
       ];
       args: [
         {
-          name: 'index';
-          type: 'u32';
+          name: 'indices';
+          type: 'bytes';
+        },
+        {
+          name: 'amount';
+          type: 'u64';
         },
       ];
     },
     {
-      name: 'settleNftSale';
+      name: 'removeTokensSpan';
       docs: [
-        'Settles a legacy NFT sale',
-        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
-        'Distributes proceeds according to royalties and fee configuration. Marks primary sale happened if applicable.',
+        'Remove fungible tokens from the gumball machine.',
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can settle the sale)',
-        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable, optional]` Authority PDA payment account',
-        '4. `[writable]` Authority account',
-        '5. `[writable, optional]` Authority payment account',
-        '6. `[writable]` Seller account',
-        '7. `[writable, optional]` Seller payment account',
-        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[]` Buyer account',
-        '10. `[writable, optional]` Fee account',
-        '11. `[writable, optional]` Fee payment account',
-        '12. `[optional]` Payment mint',
-        '13. `[]` Token program',
-        '14. `[]` Associated Token program',
-        '15. `[]` System program',
-        '16. `[]` Rent sysvar',
-        '17. `[]` Mint account',
-        "18. `[writable]` Buyer's token account",
-        "19. `[writable]` Authority PDA's token account",
-        '20. `[writable]` Metadata account',
-        '21. `[writable]` Edition account',
-        '22. `[]` Token Metadata program',
-        '23. `[writable, optional]` Seller token record (pNFT)',
-        '24. `[writable, optional]` Authority PDA token record (pNFT)',
-        '25. `[writable, optional]` Buyer token record (pNFT)',
-        '26. `[optional]` Auth rules account (pNFT)',
-        '27. `[optional]` Instructions sysvar (pNFT)',
-        '28. `[optional]` Auth rules program (pNFT)',
-        'Remaining accounts: Royalty recipients',
+        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the tokens).',
+        '5. `[]` Mint account',
+        "6. `[writable]` Seller's token account",
+        "7. `[writable]` Gumball machine's token account",
+        '8. `[]` Token program (SPL Token or Token-2022)',
+        '9. `[]` Associated Token program',
+        '10. `[]` System program',
+        '11. `[]` Rent sysvar',
       ];
       accounts: [
-        {
-          name: 'payer';
-          isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
-        },
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball machine account.'];
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'authorityPda';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
+          docs: ['Seller history account.'];
         },
         {
-          name: 'authorityPdaPaymentAccount';
+          name: 'authorityPda';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority pda if using token payment'];
         },
         {
           name: 'authority';
           isMut: true;
-          isSigner: false;
-          docs: ['Seller of the nft'];
-        },
-        {
-          name: 'authorityPaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority if using token payment'];
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+          ];
         },
         {
           name: 'seller';
           isMut: true;
           isSigner: false;
-          docs: ['Seller of the nft'];
-        },
-        {
-          name: 'sellerPaymentAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for seller if using token payment'];
-        },
-        {
-          name: 'sellerHistory';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller history account.'];
         },
         {
-          name: 'buyer';
+          name: 'mint';
           isMut: false;
           isSigner: false;
-          docs: ['buyer of the nft'];
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'];
         },
         {
-          name: 'feeAccount';
+          name: 'tokenAccount';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Fee account for marketplace fee if using fee config'];
         },
         {
-          name: 'feePaymentAccount';
+          name: 'authorityPdaTokenAccount';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for marketplace fee if using token payment'];
-        },
-        {
-          name: 'paymentMint';
-          isMut: false;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment mint if using non-native payment token'];
         },
         {
           name: 'tokenProgram';
@@ -2361,135 +2541,129 @@ export type MallowGumball = {
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'mint';
-          isMut: false;
-          isSigner: false;
+          name: 'amount';
+          type: 'u64';
         },
         {
-          name: 'tokenAccount';
-          isMut: true;
-          isSigner: false;
+          name: 'startIndex';
+          type: 'u32';
         },
         {
-          name: 'buyerTokenAccount';
-          isMut: true;
-          isSigner: false;
-          docs: ['Nft token account for buyer'];
+          name: 'endIndex';
+          type: 'u32';
         },
+      ];
+    },
+    {
+      name: 'removeSolPrize';
+      docs: [
+        'Remove native SOL prizes from the gumball machine, returning their lamports to the seller.',
+        'The signer can be the Gumball Machine authority or the seller of the prizes.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account',
+        '5. `[]` System program',
+      ];
+      accounts: [
         {
-          name: 'authorityPdaTokenAccount';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
+          docs: ['Gumball Machine account.'];
         },
         {
-          name: 'metadata';
+          name: 'sellerHistory';
           isMut: true;
           isSigner: false;
+          docs: ['Seller history account.'];
         },
         {
-          name: 'edition';
+          name: 'authorityPda';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'tokenMetadataProgram';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: [
+            'Authority allowed to remove the prizes (must be the gumball machine auth or the seller of the prizes)',
+          ];
         },
         {
-          name: 'sellerTokenRecord';
+          name: 'seller';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['OPTIONAL PNFT ACCOUNTS'];
         },
         {
-          name: 'authorityPdaTokenRecord';
-          isMut: true;
+          name: 'systemProgram';
+          isMut: false;
           isSigner: false;
-          isOptional: true;
         },
+      ];
+      args: [
         {
-          name: 'buyerTokenRecord';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
+          name: 'amount';
+          type: 'u64';
         },
         {
-          name: 'authRules';
-          isMut: false;
-          isSigner: false;
-          isOptional: true;
+          name: 'startIndex';
+          type: 'u32';
         },
         {
-          name: 'instructions';
-          isMut: false;
-          isSigner: false;
-          isOptional: true;
-        },
-        {
-          name: 'authRulesProgram';
-          isMut: false;
-          isSigner: false;
-          isOptional: true;
+          name: 'endIndex';
+          type: 'u32';
         },
+      ];
+    },
+    {
+      name: 'startSale';
+      docs: [
+        'Allows minting to begin.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
+      ];
+      accounts: [
         {
-          name: 'eventAuthority';
-          isMut: false;
+          name: 'gumballMachine';
+          isMut: true;
           isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'program';
+          name: 'authority';
           isMut: false;
-          isSigner: false;
-        },
-      ];
-      args: [
-        {
-          name: 'index';
-          type: 'u32';
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This can be the mint authority or the authority.',
+          ];
         },
       ];
+      args: [];
     },
     {
-      name: 'settleTokensSale';
+      name: 'pauseSale';
       docs: [
-        'Settles a fungible tokens sale',
-        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
-        'Distributes proceeds according to fee configuration.',
+        'Pauses a live sale. Items cannot be drawn, sold back or added while paused, but',
+        'drawn items can still be claimed.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can settle the sale)',
-        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable, optional]` Authority PDA payment account',
-        '4. `[writable]` Authority account',
-        '5. `[writable, optional]` Authority payment account',
-        '6. `[writable]` Seller account',
-        '7. `[writable, optional]` Seller payment account',
-        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[]` Buyer account',
-        '10. `[writable, optional]` Fee account',
-        '11. `[writable, optional]` Fee payment account',
-        '12. `[optional]` Payment mint',
-        '13. `[]` Token program',
-        '14. `[]` Associated Token program',
-        '15. `[]` System program',
-        '16. `[]` Rent sysvar',
-        '17. `[]` Mint account',
-        "18. `[writable]` Receiver's token account (buyer or seller if buyer is default)",
-        "19. `[writable]` Authority PDA's token account",
-        'Remaining accounts: Fee recipients',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
       ];
       accounts: [
-        {
-          name: 'payer';
-          isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
-        },
         {
           name: 'gumballMachine';
           isMut: true;
@@ -2497,164 +2671,234 @@ export type MallowGumball = {
           docs: ['Gumball machine account.'];
         },
         {
-          name: 'authorityPda';
-          isMut: true;
-          isSigner: false;
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This can be the mint authority or the authority.',
+          ];
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'resumeSale';
+      docs: [
+        'Resumes a paused sale.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
+      ];
+      accounts: [
         {
-          name: 'authorityPdaPaymentAccount';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority pda if using token payment'];
+          docs: ['Gumball machine account.'];
         },
         {
           name: 'authority';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller of the nft'];
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This can be the mint authority or the authority.',
+          ];
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'endSale';
+      docs: [
+        'Disables minting and allows sales to be settled.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+      ];
+      accounts: [
         {
-          name: 'authorityPaymentAccount';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority if using token payment'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'seller';
+          name: 'authority';
           isMut: true;
-          isSigner: false;
-          docs: ['Seller of the item'];
+          isSigner: true;
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ];
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'draw';
+      docs: [
+        'Draw for a random item from the gumball machine.',
+        'Only the gumball machine mint authority is allowed to draw.',
+        'The item is reserved for the buyer and selected once the draw is revealed.',
+        '',
+        'The pending draw account is derived from the number of draws made on the gumball',
+        'machine (`draw_number`, little-endian u64), so a buyer can have several draws pending.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])',
+        '2. `[signer]` Gumball Machine mint authority',
+        '3. `[signer, writable]` Payer',
+        '4. `[]` Buyer account',
+        '5. `[]` System program',
+        '6. `[optional]` Randomness account (required when a randomness provider is set)',
+      ];
+      accounts: [
         {
-          name: 'sellerPaymentAccount';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for seller if using token payment'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'sellerHistory';
+          name: 'pendingDraw';
           isMut: true;
           isSigner: false;
-          docs: ['Seller history account.'];
+          docs: ['Pending draw account, closed once the draw is revealed.'];
         },
         {
-          name: 'buyer';
+          name: 'mintAuthority';
           isMut: false;
-          isSigner: false;
-          docs: ['buyer of the item'];
-        },
-        {
-          name: 'feeAccount';
-          isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Fee account for marketplace fee if using fee config'];
+          isSigner: true;
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ];
         },
         {
-          name: 'feePaymentAccount';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for marketplace fee if using token payment'];
+          isSigner: true;
+          docs: ['Payer for the transaction and account allocation (rent).'];
         },
         {
-          name: 'paymentMint';
+          name: 'buyer';
           isMut: false;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment mint if using non-native payment token'];
+          docs: ['NFT account owner.', ''];
         },
         {
-          name: 'tokenProgram';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
+          docs: ['System program.'];
         },
         {
-          name: 'associatedTokenProgram';
+          name: 'randomness';
           isMut: false;
           isSigner: false;
+          isOptional: true;
+          docs: [
+            'Randomness account of the provider (required when the gumball machine uses a',
+            'randomness provider).',
+            '',
+          ];
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'drawMany';
+      docs: [
+        'Draw multiple random items from the gumball machine for the same buyer.',
+        'Only the gumball machine mint authority is allowed to draw.',
+        'The items are reserved for the buyer and selected once the draw is revealed.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])',
+        '2. `[signer]` Gumball Machine mint authority',
+        '3. `[signer, writable]` Payer',
+        '4. `[]` Buyer account',
+        '5. `[]` System program',
+        '6. `[optional]` Randomness account (required when a randomness provider is set)',
+      ];
+      accounts: [
         {
-          name: 'systemProgram';
-          isMut: false;
+          name: 'gumballMachine';
+          isMut: true;
           isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'rent';
-          isMut: false;
+          name: 'pendingDraw';
+          isMut: true;
           isSigner: false;
+          docs: ['Pending draw account, closed once the draw is revealed.'];
         },
         {
-          name: 'mint';
+          name: 'mintAuthority';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ];
         },
         {
-          name: 'receiverTokenAccount';
+          name: 'payer';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Payer for the transaction and account allocation (rent).'];
         },
         {
-          name: 'authorityPdaTokenAccount';
-          isMut: true;
+          name: 'buyer';
+          isMut: false;
           isSigner: false;
+          docs: ['NFT account owner.', ''];
         },
         {
-          name: 'eventAuthority';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
+          docs: ['System program.'];
         },
         {
-          name: 'program';
+          name: 'randomness';
           isMut: false;
           isSigner: false;
+          isOptional: true;
+          docs: [
+            'Randomness account of the provider (required when the gumball machine uses a',
+            'randomness provider).',
+            '',
+          ];
         },
       ];
       args: [
         {
-          name: 'index';
-          type: 'u32';
+          name: 'quantity';
+          type: 'u16';
         },
       ];
     },
     {
-      name: 'settleTokensSaleClaimed';
+      name: 'buySpecific';
       docs: [
-        'Settles a fungible tokens sale that has already been claimed by the buyer or does not have a buyer.',
-        'This can settle multiple items in a single transaction via the `start_index` and `end_index` args.',
-        'Distributes proceeds according to fee configuration and sends unsold tokens back to the seller.',
+        'Assigns a specific available item to the buyer, skipping the random draw. Only',
+        'the mint authority (gumball guard) can buy specific items, where the payment is',
+        'added to the total revenue. Items cannot be bought while draws are pending reveal.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can settle the sale)',
-        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable, optional]` Authority PDA payment account',
-        '4. `[writable]` Authority account',
-        '5. `[writable, optional]` Authority payment account',
-        '6. `[writable]` Seller account',
-        '7. `[writable, optional]` Seller payment account',
-        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[optional]` Payment mint',
-        '10. `[]` Token program',
-        '11. `[]` Associated Token program',
-        '12. `[]` System program',
-        '13. `[]` Rent sysvar',
-        '14. `[]` Mint account',
-        "15. `[writable]` Seller's token account (for receiving unsold tokens)",
-        "16. `[writable]` Authority PDA's token account",
-        'Remaining accounts: Fee recipients',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine mint authority',
+        '2. `[]` Buyer account',
       ];
       accounts: [
-        {
-          name: 'payer';
-          isMut: true;
-          isSigner: true;
-          docs: ['Anyone can settle the sale'];
-        },
         {
           name: 'gumballMachine';
           isMut: true;
@@ -2662,215 +2906,252 @@ export type MallowGumball = {
           docs: ['Gumball machine account.'];
         },
         {
-          name: 'authorityPda';
-          isMut: true;
-          isSigner: false;
+          name: 'mintAuthority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ];
         },
         {
-          name: 'authorityPdaPaymentAccount';
-          isMut: true;
+          name: 'buyer';
+          isMut: false;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority pda if using token payment'];
+          docs: ['Buyer of the item.', ''];
         },
         {
-          name: 'authority';
-          isMut: true;
+          name: 'eventAuthority';
+          isMut: false;
           isSigner: false;
-          docs: ['Seller of the tokens'];
         },
         {
-          name: 'authorityPaymentAccount';
-          isMut: true;
+          name: 'program';
+          isMut: false;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority if using token payment'];
         },
+      ];
+      args: [
         {
-          name: 'seller';
-          isMut: true;
-          isSigner: false;
-          docs: ['Seller of the item'];
+          name: 'index';
+          type: 'u32';
         },
+      ];
+    },
+    {
+      name: 'reroll';
+      docs: [
+        'Gives back an unclaimed item drawn by the buyer and reserves a new item in its',
+        'place, to be revealed like a regular draw. Only the mint authority (gumball',
+        'guard) can reroll, so a fee can be charged through a guard group. Items cannot be',
+        'rerolled while draws are pending reveal.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])',
+        '2. `[signer]` Gumball Machine mint authority',
+        '3. `[signer, writable]` Payer',
+        '4. `[signer]` Buyer of the rerolled item',
+        '5. `[]` System program',
+        '6. `[optional]` Randomness account (required when a randomness provider is set)',
+      ];
+      accounts: [
         {
-          name: 'sellerPaymentAccount';
+          name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for seller if using token payment'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'sellerHistory';
+          name: 'pendingDraw';
           isMut: true;
           isSigner: false;
-          docs: ['Seller history account.'];
+          docs: ['Pending draw account, closed once the draw is revealed.'];
         },
         {
-          name: 'paymentMint';
+          name: 'mintAuthority';
           isMut: false;
-          isSigner: false;
-          isOptional: true;
-          docs: ['Payment mint if using non-native payment token'];
+          isSigner: true;
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ];
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
-          isSigner: false;
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Payer for the transaction and account allocation (rent).'];
         },
         {
-          name: 'associatedTokenProgram';
+          name: 'buyer';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+          docs: ['Buyer of the item being rerolled.'];
         },
         {
           name: 'systemProgram';
           isMut: false;
           isSigner: false;
+          docs: ['System program.'];
         },
         {
-          name: 'rent';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'mint';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'sellerTokenAccount';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'authorityPdaTokenAccount';
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: 'eventAuthority';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'program';
+          name: 'randomness';
           isMut: false;
           isSigner: false;
+          isOptional: true;
+          docs: [
+            'Randomness account of the provider (required when the gumball machine uses a',
+            'randomness provider).',
+            '',
+          ];
         },
       ];
       args: [
         {
-          name: 'args';
-          type: {
-            defined: 'SettleTokensSaleClaimedArgs';
-          };
+          name: 'index';
+          type: 'u32';
         },
       ];
     },
     {
-      name: 'setAuthority';
+      name: 'revealDraw';
       docs: [
-        'Set a new authority of the gumball machine.',
+        'Reveals a pending draw using the hash of its target slot and assigns the',
+        'reserved items to the buyer. Anyone can reveal a draw, but draws are revealed in',
+        'the order they were made.',
+        '',
+        'The seed of the draw is the slot hash (or provider value) hashed with the pending',
+        'draw address. Once the target slot is no longer in the SlotHashes sysvar (~512 slots),',
+        'the draw is moved to a new target slot and revealed again later.',
+        '',
+        'When instant delivery is enabled, the drawn items are also transferred to the buyer,',
+        'so the delivery accounts of every drawn item must be passed as remaining accounts.',
+        'When receipts are enabled instead, a receipt is minted to the buyer for every drawn',
+        'item, so the receipt accounts must be passed as remaining accounts.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine authority',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number]). Will be closed.',
+        '2. `[writable]` Payer of the pending draw account (receiver of closed account rent)',
+        '3. `[]` SlotHashes sysvar cluster data',
+        '4. `[optional]` Randomness account (required when the draw uses a randomness provider)',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball Machine account.'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authority';
-          isMut: false;
-          isSigner: true;
-          docs: ['Autority of the gumball machine.'];
+          name: 'pendingDraw';
+          isMut: true;
+          isSigner: false;
+          docs: ['Pending draw account.'];
         },
-      ];
-      args: [
         {
-          name: 'newAuthority';
-          type: 'publicKey';
+          name: 'payer';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Payer of the pending draw account (receiver of the closed account rent).',
+            '',
+          ];
         },
-      ];
-    },
-    {
-      name: 'setMintAuthority';
-      docs: [
-        'Set a new mint authority of the gumball machine.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine authority',
-        '2. `[signer]` New gumball machine authority',
-      ];
-      accounts: [
         {
-          name: 'gumballMachine';
-          isMut: true;
+          name: 'recentSlothashes';
+          isMut: false;
           isSigner: false;
-          docs: ['Gumball Machine account.'];
+          docs: ['SlotHashes sysvar cluster data.', ''];
         },
         {
-          name: 'authority';
+          name: 'randomness';
           isMut: false;
-          isSigner: true;
-          docs: ['Gumball Machine authority'];
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'Randomness account of the provider (required when the draw uses a randomness',
+            'provider).',
+            '',
+          ];
         },
         {
-          name: 'mintAuthority';
+          name: 'eventAuthority';
           isMut: false;
-          isSigner: true;
-          docs: ['New gumball machine authority'];
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
         },
       ];
       args: [];
     },
     {
-      name: 'withdraw';
+      name: 'cancelPendingDraw';
       docs: [
-        'Withdraw the rent lamports and send them to the authority address.',
-        'If a non-native payment mint was used, also closes the PDA payment token account,',
-        "sending its balance to the authority's associated token account.",
+        'Cancels the next pending draw of an abandoned gumball machine once the force close',
+        'grace period has passed, so that its items can be settled and the machine closed.',
+        'Anyone can cancel a draw, but only draws made with a randomness provider whose',
+        'request was replaced or never fulfilled: those can no longer be revealed.',
+        '',
+        'The reserved items are returned to the pool and the price of the draw (net of the',
+        'marketplace fee and of the share already paid out to settled items) is refunded to',
+        'the buyer.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account (will be closed)',
-        '1. `[signer, writable]` Gumball Machine authority',
-        '2. `[signer, writable]` Gumball Machine mint authority',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '4. `[writable, optional]` Authority PDA payment account',
-        '5. `[]` Token program',
-        'Remaining accounts (if closing non-native payment account):',
-        '- `[]` Payment Mint',
-        "- `[writable]` Authority's token account for payment mint",
-        '- `[]` Associated Token program',
-        '- `[]` System program',
-        '- `[]` Rent sysvar',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number]). Will be closed.',
+        '2. `[writable]` Payer of the pending draw account (receiver of closed account rent)',
+        '3. `[writable]` Buyer of the draw (receiver of the refund)',
+        "4. `[signer, writable]` Payer of the buyer's payment account",
+        '5. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '6. `[writable, optional]` Authority PDA payment account',
+        '7. `[writable, optional]` Buyer payment account',
+        '8. `[optional]` Payment mint',
+        '9. `[]` Randomness account of the draw',
+        '10. `[]` Token program',
+        '11. `[]` Associated Token program',
+        '12. `[]` System program',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball Machine acccount.'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authority';
+          name: 'pendingDraw';
           isMut: true;
-          isSigner: true;
-          docs: ['Authority of the gumball machine.'];
+          isSigner: false;
+          docs: ['Pending draw account.'];
         },
         {
-          name: 'mintAuthority';
+          name: 'payer';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Payer of the pending draw account (receiver of the closed account rent).',
+            '',
+          ];
+        },
+        {
+          name: 'buyer';
+          isMut: true;
+          isSigner: false;
+          docs: ['Buyer of the draw (receiver of the refund).', ''];
+        },
+        {
+          name: 'feePayer';
           isMut: true;
           isSigner: true;
-          docs: ['Mint authority of the gumball machine.'];
+          docs: [
+            "Payer of the buyer's payment account if it needs to be created.",
+          ];
         },
         {
           name: 'authorityPda';
@@ -2884,44 +3165,148 @@ export type MallowGumball = {
           isOptional: true;
           docs: ['Payment account for authority pda if using token payment'];
         },
+        {
+          name: 'buyerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Buyer's token account if using token payment"];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'randomness';
+          isMut: false;
+          isSigner: false;
+          docs: ['Randomness account of the provider used by the draw.', ''];
+        },
         {
           name: 'tokenProgram';
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [];
-    },
-    {
-      name: 'manageBuyBackFunds';
-      docs: [
-        'Manage the buy back funds of the gumball machine.',
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'incrementTotalRevenue';
+      docs: [
+        'Increments total revenue earned by the gumball machine.',
+        '',
+        'Only the gumball machine mint authority is allowed to increment revenue. This is',
+        "required as token transfers don't occur in this program, but total is needed",
+        'when settling.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[signer, writable]` Gumball Machine authority',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        "3. `[writable, optional]` Authority's payment account",
-        "4. `[writable, optional]` Authority PDA's payment account",
-        '5. `[optional]` Payment mint',
-        '6. `[]` Token program',
-        '7. `[]` Associated Token program',
-        '8. `[]` System program',
-        '9. `[]` Rent sysvar',
+        '1. `[signer]` Gumball Machine mint authority',
       ];
       accounts: [
         {
           name: 'gumballMachine';
           isMut: true;
           isSigner: false;
-          docs: ['Gumball Machine acccount.'];
+          docs: ['Gumball machine account.'];
         },
         {
-          name: 'authority';
+          name: 'mintAuthority';
+          isMut: false;
+          isSigner: true;
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ];
+        },
+      ];
+      args: [
+        {
+          name: 'revenue';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'sellItem';
+      docs: [
+        'Sell an item back to the gumball machine using buy back funds.',
+        'The payer must be the seller or the oracle_signer.',
+        'Buying back to the gumball machine is currently not supported, but will be in the future.',
+        'If buying back to the authority, the item is marked as claimed and transferred to the buyer (authority).',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (must be seller or oracle_signer)',
+        '1. `[signer]` Oracle signer (must match buy_back_config)',
+        '2. `[writable]` Gumball Machine account',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '4. `[writable]` Mint account of the item (or Asset account for Core)',
+        '5. `[writable]` Seller account',
+        '6. `[writable]` Buyer account (must be gumball machine or authority)',
+        '7. `[]` System program',
+        '8. `[]` Token program',
+        '9. `[]` Associated Token program',
+        '10. `[]` Rent sysvar',
+        '11. `[writable, optional]` Fee account (if fee configured)',
+        '12. `[writable, optional]` Fee payment account (if fee configured)',
+        '13. `[writable, optional]` Payment mint (if not native SOL)',
+        '14. `[writable, optional]` Seller payment account (if not native SOL)',
+        '15. `[writable, optional]` Authority PDA payment account (if not native SOL)',
+        '16. `[writable, optional]` Collection account (for Core asset)',
+        '17. `[optional]` MPL Core program (for Core asset)',
+        '18. `[writable, optional]` Authority PDA token account (for NFT/Fungible)',
+        '19. `[writable, optional]` Seller token account (for NFT/Fungible)',
+        '20. `[writable, optional]` Buyer token account (for NFT/Fungible)',
+        '21. `[writable, optional]` Metadata account (for NFT/PNFT)',
+        '22. `[writable, optional]` Edition account (for NFT/PNFT)',
+        '23. `[optional]` Token Metadata program (for NFT/PNFT)',
+        '24. `[writable, optional]` Authority PDA token record (for pNFT)',
+        '25. `[writable, optional]` Buyer token record (for pNFT)',
+        '26. `[optional]` Auth rules account (for pNFT)',
+        '27. `[optional]` Instructions sysvar (for pNFT)',
+        '28. `[optional]` Auth rules program (for pNFT)',
+        '29. `[optional]` Token-2022 program (for Token-2022 tokens)',
+        '30. `[writable, optional]` Merkle tree account (for compressed NFT)',
+        '31. `[optional]` Tree config account (for compressed NFT)',
+        '32. `[optional]` Bubblegum program (for compressed NFT)',
+        '33. `[optional]` SPL Account Compression program (for compressed NFT)',
+        '34. `[optional]` SPL Noop program (for compressed NFT)',
+        'Remaining accounts: Merkle proof nodes (for compressed NFT)',
+      ];
+      accounts: [
+        {
+          name: 'payer';
           isMut: true;
           isSigner: true;
-          docs: ['Authority of the gumball machine.'];
+          docs: [
+            'Must be the oracle signer or seller (oracle signer can sell on behalf of the seller to allow auto-buy back)',
+          ];
+        },
+        {
+          name: 'oracleSigner';
+          isMut: false;
+          isSigner: true;
+          docs: ['Oracle signer'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
         },
         {
           name: 'authorityPda';
@@ -2929,25 +3314,27 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'authorityPaymentAccount';
+          name: 'mint';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ["Authority's token account if using token payment"];
+          docs: ['Mint of the item (or asset for Core assets)'];
         },
         {
-          name: 'authorityPdaPaymentAccount';
+          name: 'seller';
           isMut: true;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment account for authority pda if using token payment'];
+          docs: ['Seller of the item'];
         },
         {
-          name: 'paymentMint';
+          name: 'buyer';
+          isMut: true;
+          isSigner: false;
+          docs: ['Buyer of the item'];
+        },
+        {
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
-          docs: ['Payment mint if using non-native payment token'];
         },
         {
           name: 'tokenProgram';
@@ -2960,210 +3347,2798 @@ export type MallowGumball = {
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'rent';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'rent';
+          name: 'feeAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL FEE ACCOUNTS - only required if there is a fee config on the gumball machine',
+            'Marketplace fee account',
+          ];
+        },
+        {
+          name: 'feePaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Marketplace fee payment account'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL SPL TOKEN ACCOUNTS - only required if selling for SPL token',
+            'Mint of payment token',
+          ];
+        },
+        {
+          name: 'sellerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Seller payment account'];
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Authority PDA payment account'];
+        },
+        {
+          name: 'collection';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if selling Core asset',
+            'Collection of the asset',
+          ];
+        },
+        {
+          name: 'mplCoreProgram';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
-      ];
-      args: [
         {
-          name: 'amount';
-          type: 'u64';
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL TOKEN ACCOUNTS - only required if selling NFT or Fungible assets',
+            'Authority PDA token account',
+          ];
         },
         {
-          name: 'isWithdraw';
-          type: 'bool';
+          name: 'sellerTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Seller token account'];
         },
-      ];
-    },
-  ];
-  accounts: [
-    {
-      name: 'addItemRequest';
-      docs: ['Add item request state.'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'gumballMachine';
-            docs: ['Gumball machine address.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'seller';
-            docs: ['Seller address.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'asset';
-            docs: ['Asset address.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'tokenStandard';
-            docs: ['Token standard.'];
-            type: {
-              defined: 'TokenStandard';
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'gumballMachine';
-      docs: ['Gumball machine state and config data.'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'version';
-            docs: ['Version of the account.'];
-            type: 'u8';
-          },
-          {
-            name: 'authority';
-            docs: ['Authority address.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'mintAuthority';
-            docs: [
-              'Authority address allowed to mint from the gumball machine.',
-            ];
-            type: 'publicKey';
-          },
-          {
-            name: 'marketplaceFeeConfig';
-            docs: ['Fee config for the marketplace this gumball is listed on'];
-            type: {
-              option: {
-                defined: 'FeeConfig';
-              };
-            };
-          },
-          {
-            name: 'itemsRedeemed';
-            docs: ['Number of assets redeemed.'];
-            type: 'u64';
-          },
-          {
-            name: 'itemsSettled';
-            docs: ['Number of assets settled after sale.'];
-            type: 'u64';
-          },
-          {
-            name: 'totalRevenue';
-            docs: ['Amount of lamports/tokens received from purchases.'];
-            type: 'u64';
-          },
-          {
-            name: 'state';
-            docs: [
-              'True if the authority has finalized details, which prevents adding more nfts.',
-            ];
-            type: {
-              defined: 'GumballState';
-            };
-          },
-          {
-            name: 'settings';
-            docs: ['User-defined settings'];
-            type: {
-              defined: 'GumballSettings';
+        {
+          name: 'buyerTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Buyer token account'];
+        },
+        {
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if selling NFT or PNFT',
+          ];
+        },
+        {
+          name: 'edition';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authorityPdaTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if selling PNFT'];
+        },
+        {
+          name: 'buyerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'token2022Program';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL TOKEN-2022 ACCOUNTS - only required if selling Token-2022 tokens',
+          ];
+        },
+        {
+          name: 'merkleTree';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL COMPRESSED NFT ACCOUNTS - only required if selling compressed NFT',
+          ];
+        },
+        {
+          name: 'treeConfig';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'bubblegumProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'compressionProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'logWrapper';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'buyPrice';
+          type: 'u64';
+        },
+        {
+          name: 'compressedNft';
+          type: {
+            option: {
+              defined: 'CompressedNftArgs';
             };
-          },
-        ];
-      };
+          };
+        },
+      ];
     },
     {
-      name: 'sellerHistory';
+      name: 'claimWithReceipt';
       docs: [
-        'Seller history state to track count of items submitted to a gumball machine.',
+        'Burns a draw receipt and delivers the item to the owner of the receipt. Receipts do',
+        'not expire: the item can be claimed after the claim deadline.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (must be in SaleLive, SaleEnded or Paused state)',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '2. `[writable]` Authority account',
+        '3. `[writable]` Seller account',
+        '4. `[signer, writable]` Owner of the receipt',
+        '5. `[writable]` Receipt asset account',
+        '6. `[writable]` Receipt collection account',
+        '7. `[writable]` Asset account (mint for NFTs and tokens, native mint for SOL prizes)',
+        '8. `[]` MPL Core program',
+        '9. `[]` System program',
+        '10. `[writable, optional]` Collection account (Core asset)',
+        "11. `[writable, optional]` Owner's token account (NFT and tokens)",
+        "12. `[writable, optional]` Seller's token account (NFT)",
+        "13. `[writable, optional]` Authority PDA's token account (NFT and tokens)",
+        '14. `[optional]` Token program (NFT and tokens)',
+        '15. `[optional]` Associated Token program (NFT and tokens)',
+        '16. `[optional]` Token-2022 program (Token-2022 tokens)',
+        '17. `[writable, optional]` Edition account (NFT)',
+        '18. `[writable, optional]` Metadata account (NFT)',
+        '19. `[optional]` Token Metadata program (NFT)',
+        '20. `[writable, optional]` Seller token record (pNFT)',
+        '21. `[writable, optional]` Authority PDA token record (pNFT)',
+        '22. `[writable, optional]` Owner token record (pNFT)',
+        '23. `[optional]` Auth rules account (pNFT)',
+        '24. `[optional]` Instructions sysvar (pNFT)',
+        '25. `[optional]` Auth rules program (pNFT)',
+        '26. `[writable, optional]` Merkle tree account (compressed NFT)',
+        '27. `[optional]` Tree config account (compressed NFT)',
+        '28. `[optional]` Bubblegum program (compressed NFT)',
+        '29. `[optional]` SPL Account Compression program (compressed NFT)',
+        '30. `[optional]` SPL Noop program (compressed NFT)',
+        'Remaining accounts: Merkle proof nodes (compressed NFT)',
       ];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'gumballMachine';
-            docs: ["Gumball machine we're tracking for"];
-            type: 'publicKey';
-          },
-          {
-            name: 'seller';
-            docs: ['Seller address'];
-            type: 'publicKey';
-          },
-          {
-            name: 'itemCount';
-            docs: ['Item count submitted by this seller'];
-            type: 'u64';
-          },
-        ];
-      };
-    },
-  ];
-  types: [
-    {
-      name: 'AddItemArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'sellerProofPath';
-            type: {
-              option: {
-                vec: {
-                  array: ['u8', 32];
-                };
-              };
-            };
-          },
-          {
-            name: 'index';
-            type: {
-              option: 'u32';
-            };
-          },
-        ];
-      };
-    },
-    {
-      name: 'InitializeArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'settings';
-            type: {
-              defined: 'GumballSettings';
-            };
-          },
-          {
-            name: 'feeConfig';
-            type: {
-              option: {
-                defined: 'FeeConfig';
-              };
-            };
-          },
-          {
-            name: 'disablePrimarySplit';
-            type: 'bool';
-          },
-          {
-            name: 'buyBackConfig';
-            type: {
-              option: {
-                defined: 'BuyBackConfig';
-              };
-            };
-          },
-          {
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Authority of the gumball machine, receives the rent of closed token accounts',
+          ];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the item'];
+        },
+        {
+          name: 'owner';
+          isMut: true;
+          isSigner: true;
+          docs: ['Owner of the receipt, receives the item'];
+        },
+        {
+          name: 'receipt';
+          isMut: true;
+          isSigner: false;
+          docs: ['Receipt asset, burnt once the item is claimed'];
+        },
+        {
+          name: 'receiptCollection';
+          isMut: true;
+          isSigner: false;
+          docs: ['Receipt collection'];
+        },
+        {
+          name: 'asset';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Asset (mint for NFTs and tokens, native mint for SOL prizes)',
+          ];
+        },
+        {
+          name: 'mplCoreProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'collection';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if claiming a Core asset',
+            "Core asset's collection if it's part of one.",
+          ];
+        },
+        {
+          name: 'ownerTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL TOKEN ACCOUNTS - only required if claiming a NFT, PNFT or tokens',
+            "Owner's token account",
+          ];
+        },
+        {
+          name: 'tokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Seller's token account (NFT)"];
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'token2022Program';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'Token-2022 program, only required if the item is a Token-2022 token',
+          ];
+        },
+        {
+          name: 'edition';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if claiming a NFT or PNFT',
+          ];
+        },
+        {
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'sellerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if claiming a PNFT'];
+        },
+        {
+          name: 'authorityPdaTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'ownerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'merkleTree';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL COMPRESSED NFT ACCOUNTS - only required if claiming a compressed NFT',
+          ];
+        },
+        {
+          name: 'treeConfig';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'bubblegumProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'compressionProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'logWrapper';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'compressedNft';
+          type: {
+            option: {
+              defined: 'CompressedNftArgs';
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'transferPrize';
+      docs: [
+        'Transfers the right to claim a drawn item to a new buyer, who can then claim it',
+        'or sell it back like the original buyer. The new buyer cannot be the authority PDA,',
+        'which holds the items represented by a receipt.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (must be in SaleLive, SaleEnded or Paused state)',
+        '1. `[signer]` Buyer of the item',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: true;
+          docs: ['Buyer of the item.'];
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'newBuyer';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'claimCoreAsset';
+      docs: [
+        'Claims a Core asset from the gumball machine for a specific buyer.',
+        'Transfers the asset from the PDA to the buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the item)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable]` Seller account',
+        '4. `[]` Buyer account',
+        '5. `[]` System program',
+        '6. `[writable]` Asset account',
+        '7. `[writable, optional]` Collection account if asset is part of one.',
+        '8. `[]` MPL Core program.',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'asset';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'collection';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'mplCoreProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'claimNft';
+      docs: [
+        'Claims a legacy NFT from the gumball machine for a specific buyer.',
+        'Thaws and transfers the NFT from the PDA to the buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the item)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable]` Seller account',
+        '4. `[]` Buyer account',
+        '5. `[]` Token program',
+        '6. `[]` Associated Token program',
+        '7. `[]` System program',
+        '8. `[]` Rent sysvar',
+        '9. `[]` Mint account',
+        "10. `[writable]` Buyer's token account",
+        "11. `[writable]` Authority PDA's token account",
+        '12. `[writable]` Metadata account',
+        '13. `[writable]` Edition account',
+        '14. `[]` Token Metadata program',
+        '15. `[writable, optional]` Seller token record (pNFT)',
+        '16. `[writable, optional]` Authority PDA token record (pNFT)',
+        '17. `[writable, optional]` Buyer token record (pNFT)',
+        '18. `[optional]` Auth rules account (pNFT)',
+        '19. `[optional]` Instructions sysvar (pNFT)',
+        '20. `[optional]` Auth rules program (pNFT)',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'buyerTokenAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['Nft token account for buyer'];
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'edition';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'sellerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS'];
+        },
+        {
+          name: 'authorityPdaTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'buyerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'claimTokens';
+      docs: [
+        'Claims fungible tokens from the gumball machine for a specific buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the tokens)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable]` Gumball Machine authority',
+        '4. `[writable]` Seller account',
+        '5. `[]` Buyer account',
+        '6. `[]` Token program (SPL Token or Token-2022)',
+        '7. `[]` Associated Token program',
+        '8. `[]` System program',
+        '9. `[]` Rent sysvar',
+        '10. `[]` Mint account',
+        "11. `[writable]` Buyer's token account (must match mint and buyer)",
+        "12. `[writable]` Authority PDA's token account (must match mint and authority PDA)",
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine authority'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'];
+        },
+        {
+          name: 'buyerTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'claimCompressedNft';
+      docs: [
+        'Claims a compressed NFT from the gumball machine for a specific buyer.',
+        'Transfers the leaf from the PDA to the buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the item)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable]` Seller account',
+        '4. `[]` Buyer account',
+        '5. `[writable]` Merkle tree account',
+        '6. `[]` Tree config account',
+        '7. `[]` Bubblegum program',
+        '8. `[]` SPL Account Compression program',
+        '9. `[]` SPL Noop program',
+        '10. `[]` System program',
+        'Remaining accounts: Merkle proof nodes',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'merkleTree';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'treeConfig';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bubblegumProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'compressionProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'logWrapper';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'compressedNft';
+          type: {
+            defined: 'CompressedNftArgs';
+          };
+        },
+      ];
+    },
+    {
+      name: 'claimSolPrize';
+      docs: [
+        'Claims a native SOL prize from the gumball machine for a specific buyer.',
+        'Transfers the lamports from the PDA to the buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the prize)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[]` Seller account',
+        '4. `[writable]` Buyer account',
+        '5. `[]` System program',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can claim the prize'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'seller';
+          isMut: false;
+          isSigner: false;
+          docs: ['Seller of the prize'];
+        },
+        {
+          name: 'buyer';
+          isMut: true;
+          isSigner: false;
+          docs: ['buyer of the prize'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'settleCoreAssetSale';
+      docs: [
+        'Settles a Core asset sale',
+        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Distributes proceeds according to royalties and fee configuration.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[]` Buyer account',
+        '10. `[writable, optional]` Fee account',
+        '11. `[writable, optional]` Fee payment account',
+        '12. `[optional]` Payment mint',
+        '13. `[]` Token program',
+        '14. `[]` Associated Token program',
+        '15. `[]` System program',
+        '16. `[]` Rent sysvar',
+        '17. `[writable]` Asset account',
+        '18. `[writable, optional]` Collection account if asset is part of one.',
+        '19. `[]` MPL Core program.',
+        'Remaining accounts: Royalty recipients',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'authorityPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority if using token payment'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'sellerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for seller if using token payment'];
+        },
+        {
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'feeAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Fee account for marketplace fee if using fee config'];
+        },
+        {
+          name: 'feePaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for marketplace fee if using token payment'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'asset';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'collection';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'mplCoreProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'settleNftSale';
+      docs: [
+        'Settles a legacy NFT sale',
+        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Distributes proceeds according to royalties and fee configuration. Marks primary sale happened if applicable.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[]` Buyer account',
+        '10. `[writable, optional]` Fee account',
+        '11. `[writable, optional]` Fee payment account',
+        '12. `[optional]` Payment mint',
+        '13. `[]` Token program',
+        '14. `[]` Associated Token program',
+        '15. `[]` System program',
+        '16. `[]` Rent sysvar',
+        '17. `[]` Mint account',
+        "18. `[writable]` Buyer's token account (authority's if the unclaimed item is sent to it)",
+        "19. `[writable]` Authority PDA's token account",
+        '20. `[writable]` Metadata account',
+        '21. `[writable]` Edition account',
+        '22. `[]` Token Metadata program',
+        '23. `[writable, optional]` Seller token record (pNFT)',
+        '24. `[writable, optional]` Authority PDA token record (pNFT)',
+        "25. `[writable, optional]` Buyer token record (pNFT, authority's if the unclaimed item is sent to it)",
+        '26. `[optional]` Auth rules account (pNFT)',
+        '27. `[optional]` Instructions sysvar (pNFT)',
+        '28. `[optional]` Auth rules program (pNFT)',
+        'Remaining accounts: Royalty recipients',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'authorityPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority if using token payment'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'sellerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for seller if using token payment'];
+        },
+        {
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'feeAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Fee account for marketplace fee if using fee config'];
+        },
+        {
+          name: 'feePaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for marketplace fee if using token payment'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'buyerTokenAccount';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Nft token account for buyer (or the authority when unclaimed items are sent to it)',
+          ];
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'edition';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'sellerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS'];
+        },
+        {
+          name: 'authorityPdaTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'buyerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'settleTokensSale';
+      docs: [
+        'Settles a fungible tokens sale',
+        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Native SOL prizes are settled with the native mint and paid out in lamports, the token accounts are unused.',
+        'Distributes proceeds according to fee configuration.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[writable]` Buyer account',
+        '10. `[writable, optional]` Fee account',
+        '11. `[writable, optional]` Fee payment account',
+        '12. `[optional]` Payment mint',
+        '13. `[]` Token program',
+        '14. `[]` Associated Token program',
+        '15. `[]` System program',
+        '16. `[]` Rent sysvar',
+        '17. `[]` Mint account',
+        "18. `[writable]` Receiver's token account (buyer, seller if buyer is default or the claim deadline destination)",
+        "19. `[writable]` Authority PDA's token account",
+        '20. `[optional]` Token-2022 program (for Token-2022 tokens)',
+        'Remaining accounts: Fee recipients',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'authorityPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority if using token payment'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the item'];
+        },
+        {
+          name: 'sellerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for seller if using token payment'];
+        },
+        {
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
+        },
+        {
+          name: 'buyer';
+          isMut: true;
+          isSigner: false;
+          docs: ['buyer of the item'];
+        },
+        {
+          name: 'feeAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Fee account for marketplace fee if using fee config'];
+        },
+        {
+          name: 'feePaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for marketplace fee if using token payment'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'receiverTokenAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['Receiver token account, unused for native SOL prizes'];
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['Authority PDA token account, unused for native SOL prizes'];
+        },
+        {
+          name: 'token2022Program';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'Token-2022 program, only required if the item is a Token-2022 token',
+          ];
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'settleTokensSaleClaimed';
+      docs: [
+        'Settles a fungible tokens sale that has already been claimed by the buyer or does not have a buyer.',
+        'This can settle multiple items in a single transaction via the `start_index` and `end_index` args.',
+        'Distributes proceeds according to fee configuration and sends unsold tokens back to the seller.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[optional]` Payment mint',
+        '10. `[]` Token program',
+        '11. `[]` Associated Token program',
+        '12. `[]` System program',
+        '13. `[]` Rent sysvar',
+        '14. `[]` Mint account',
+        "15. `[writable]` Seller's token account (for receiving unsold tokens)",
+        "16. `[writable]` Authority PDA's token account",
+        '17. `[optional]` Token-2022 program (for Token-2022 tokens)',
+        'Remaining accounts: Fee recipients',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the tokens'];
+        },
+        {
+          name: 'authorityPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority if using token payment'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the item'];
+        },
+        {
+          name: 'sellerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for seller if using token payment'];
+        },
+        {
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'sellerTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'token2022Program';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'Token-2022 program, only required if the items are Token-2022 tokens',
+          ];
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'args';
+          type: {
+            defined: 'SettleTokensSaleClaimedArgs';
+          };
+        },
+      ];
+    },
+    {
+      name: 'reclaimUnsoldItem';
+      docs: [
+        'Returns an unsold item to its seller after the sale has ended, without waiting for the',
+        'authority to settle it. The item is settled with zero proceeds, its share of the revenue',
+        'is left to the remaining items. Compressed NFTs must be settled with `settle_compressed_nft_sale`.',
+        '',
+        'Gumball machines older than version 5 must be migrated first.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '2. `[writable]` Authority account',
+        '3. `[signer, writable]` Seller account',
+        '4. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '5. `[writable]` Asset account (mint for NFTs and tokens, native mint for SOL prizes)',
+        '6. `[]` System program',
+        '7. `[writable, optional]` Collection account (Core asset)',
+        '8. `[optional]` MPL Core program (Core asset)',
+        "9. `[writable, optional]` Seller's token account (NFT and tokens)",
+        "10. `[writable, optional]` Authority PDA's token account (NFT and tokens)",
+        '11. `[optional]` Token program (NFT and tokens)',
+        '12. `[optional]` Associated Token program (NFT and tokens)',
+        '13. `[optional]` Token-2022 program (Token-2022 tokens)',
+        '14. `[writable, optional]` Edition account (NFT)',
+        '15. `[writable, optional]` Metadata account (NFT)',
+        '16. `[optional]` Token Metadata program (NFT)',
+        '17. `[writable, optional]` Seller token record (pNFT)',
+        '18. `[writable, optional]` Authority PDA token record (pNFT)',
+        '19. `[optional]` Auth rules account (pNFT)',
+        '20. `[optional]` Instructions sysvar (pNFT)',
+        '21. `[optional]` Auth rules program (pNFT)',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Authority of the gumball machine, receives the rent of closed token accounts',
+          ];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: true;
+          docs: ['Seller of the item'];
+        },
+        {
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
+        },
+        {
+          name: 'asset';
+          isMut: true;
+          isSigner: false;
+          docs: [
+            'Asset (mint for NFTs and tokens, native mint for SOL prizes)',
+          ];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'collection';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if reclaiming a Core asset',
+            "Core asset's collection if it's part of one.",
+          ];
+        },
+        {
+          name: 'mplCoreProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL TOKEN ACCOUNTS - only required if reclaiming a NFT, PNFT or tokens',
+            "Seller's token account",
+          ];
+        },
+        {
+          name: 'authorityPdaTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'token2022Program';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'Token-2022 program, only required if the item is a Token-2022 token',
+          ];
+        },
+        {
+          name: 'edition';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if reclaiming a NFT or PNFT',
+          ];
+        },
+        {
+          name: 'metadata';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'tokenMetadataProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'sellerTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if reclaiming a PNFT'];
+        },
+        {
+          name: 'authorityPdaTokenRecord';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRules';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'instructions';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'authRulesProgram';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+      ];
+    },
+    {
+      name: 'settleCompressedNftSale';
+      docs: [
+        'Settles a compressed NFT sale',
+        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Otherwise the current leaf is verified against the merkle tree, a burnt leaf pays no royalties.',
+        'Distributes proceeds according to royalties and fee configuration.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[]` Buyer account',
+        '10. `[writable, optional]` Fee account',
+        '11. `[writable, optional]` Fee payment account',
+        '12. `[optional]` Payment mint',
+        '13. `[]` Token program',
+        '14. `[]` Associated Token program',
+        '15. `[]` System program',
+        '16. `[]` Rent sysvar',
+        '17. `[writable]` Merkle tree account',
+        '18. `[]` Tree config account',
+        '19. `[]` Bubblegum program',
+        '20. `[]` SPL Account Compression program',
+        '21. `[]` SPL Noop program',
+        'Remaining accounts: Merkle proof nodes (proof_length), then royalty recipients',
+      ];
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+          docs: ['Anyone can settle the sale'];
+        },
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball machine account.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'authorityPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority if using token payment'];
+        },
+        {
+          name: 'seller';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller of the nft'];
+        },
+        {
+          name: 'sellerPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for seller if using token payment'];
+        },
+        {
+          name: 'sellerHistory';
+          isMut: true;
+          isSigner: false;
+          docs: ['Seller history account.'];
+        },
+        {
+          name: 'buyer';
+          isMut: false;
+          isSigner: false;
+          docs: ['buyer of the nft'];
+        },
+        {
+          name: 'feeAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Fee account for marketplace fee if using fee config'];
+        },
+        {
+          name: 'feePaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for marketplace fee if using token payment'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'merkleTree';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'treeConfig';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'bubblegumProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'compressionProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'logWrapper';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'eventAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'program';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u32';
+        },
+        {
+          name: 'args';
+          type: {
+            defined: 'SettleCompressedNftSaleArgs';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setAuthority';
+      docs: [
+        'Set a new authority of the gumball machine.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball Machine account.'];
+        },
+        {
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: ['Autority of the gumball machine.'];
+        },
+      ];
+      args: [
+        {
+          name: 'newAuthority';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'setMintAuthority';
+      docs: [
+        'Set a new mint authority of the gumball machine.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+        '2. `[signer]` New gumball machine authority',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball Machine account.'];
+        },
+        {
+          name: 'authority';
+          isMut: false;
+          isSigner: true;
+          docs: ['Gumball Machine authority'];
+        },
+        {
+          name: 'mintAuthority';
+          isMut: false;
+          isSigner: true;
+          docs: ['New gumball machine authority'];
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'withdraw';
+      docs: [
+        'Withdraw the rent lamports and send them to the authority address.',
+        'If a non-native payment mint was used, also closes the PDA payment token account,',
+        "sending its balance to the authority's associated token account.",
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (will be closed)',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[signer, writable]` Gumball Machine mint authority',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '4. `[writable, optional]` Authority PDA payment account',
+        '5. `[]` Token program',
+        'Remaining accounts (if closing non-native payment account):',
+        '- `[]` Payment Mint',
+        "- `[writable]` Authority's token account for payment mint",
+        '- `[]` Associated Token program',
+        '- `[]` System program',
+        '- `[]` Rent sysvar',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball Machine acccount.'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: true;
+          docs: ['Authority of the gumball machine.'];
+        },
+        {
+          name: 'mintAuthority';
+          isMut: true;
+          isSigner: true;
+          docs: ['Mint authority of the gumball machine.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'forceCloseGumballMachine';
+      docs: [
+        "Withdraw the rent lamports of an abandoned gumball machine without the mint authority's",
+        'signature. Only available once the force close grace period has passed since the sale',
+        'ended and every item has been settled.',
+        '',
+        'Every step before it is permissionless once the grace period has passed: pending draws',
+        'that can no longer be revealed are cancelled with `cancel_pending_draw`, and the',
+        '`settle_*_sale` instructions return unclaimed prizes to their buyers and unsold items',
+        'to their sellers.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (will be closed)',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[]` Token program',
+        'Remaining accounts (if closing non-native payment account):',
+        '- `[]` Payment Mint',
+        "- `[writable]` Authority's token account for payment mint",
+        '- `[]` Associated Token program',
+        '- `[]` System program',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball Machine acccount.'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: true;
+          docs: ['Authority of the gumball machine.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'manageBuyBackFunds';
+      docs: [
+        'Manage the buy back funds of the gumball machine.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        "3. `[writable, optional]` Authority's payment account",
+        "4. `[writable, optional]` Authority PDA's payment account",
+        '5. `[optional]` Payment mint',
+        '6. `[]` Token program',
+        '7. `[]` Associated Token program',
+        '8. `[]` System program',
+        '9. `[]` Rent sysvar',
+      ];
+      accounts: [
+        {
+          name: 'gumballMachine';
+          isMut: true;
+          isSigner: false;
+          docs: ['Gumball Machine acccount.'];
+        },
+        {
+          name: 'authority';
+          isMut: true;
+          isSigner: true;
+          docs: ['Authority of the gumball machine.'];
+        },
+        {
+          name: 'authorityPda';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'authorityPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Authority's token account if using token payment"];
+        },
+        {
+          name: 'authorityPdaPaymentAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment account for authority pda if using token payment'];
+        },
+        {
+          name: 'paymentMint';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ['Payment mint if using non-native payment token'];
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'associatedTokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rent';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'isWithdraw';
+          type: 'bool';
+        },
+      ];
+    },
+  ];
+  accounts: [
+    {
+      name: 'addItemRequest';
+      docs: ['Add item request state.'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'gumballMachine';
+            docs: ['Gumball machine address.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'seller';
+            docs: ['Seller address.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'asset';
+            docs: ['Asset address.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenStandard';
+            docs: ['Token standard.'];
+            type: {
+              defined: 'TokenStandard';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'gumballMachine';
+      docs: ['Gumball machine state and config data.'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'version';
+            docs: ['Version of the account.'];
+            type: 'u8';
+          },
+          {
+            name: 'authority';
+            docs: ['Authority address.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'mintAuthority';
+            docs: [
+              'Authority address allowed to mint from the gumball machine.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'marketplaceFeeConfig';
+            docs: ['Fee config for the marketplace this gumball is listed on'];
+            type: {
+              option: {
+                defined: 'FeeConfig';
+              };
+            };
+          },
+          {
+            name: 'itemsRedeemed';
+            docs: ['Number of assets redeemed.'];
+            type: 'u64';
+          },
+          {
+            name: 'itemsSettled';
+            docs: ['Number of assets settled after sale.'];
+            type: 'u64';
+          },
+          {
+            name: 'totalRevenue';
+            docs: ['Amount of lamports/tokens received from purchases.'];
+            type: 'u64';
+          },
+          {
+            name: 'state';
+            docs: [
+              'True if the authority has finalized details, which prevents adding more nfts.',
+            ];
+            type: {
+              defined: 'GumballState';
+            };
+          },
+          {
+            name: 'settings';
+            docs: ['User-defined settings'];
+            type: {
+              defined: 'GumballSettings';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'pendingDraw';
+      docs: [
+        'Pending draw state to reserve items for a buyer until the draw is revealed.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'gumballMachine';
+            docs: ['Gumball machine the items were reserved on'];
+            type: 'publicKey';
+          },
+          {
+            name: 'buyer';
+            docs: ['Wallet that will receive the items once revealed'];
+            type: 'publicKey';
+          },
+          {
+            name: 'payer';
+            docs: ['Wallet that paid the rent for this account'];
+            type: 'publicKey';
+          },
+          {
+            name: 'targetSlot';
+            docs: ['Slot whose hash is used to select the items'];
+            type: 'u64';
+          },
+          {
+            name: 'quantity';
+            docs: ['Number of items reserved'];
+            type: 'u16';
+          },
+          {
+            name: 'randomness';
+            docs: [
+              'Randomness account of the provider used to select the items (default pubkey when slot hashes are used)',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'rerolledIndex';
+            docs: ['Index of the item given back when the draw is a reroll'];
+            type: {
+              option: 'u32';
+            };
+          },
+          {
+            name: 'drawNumber';
+            docs: [
+              'Number of the draw on the gumball machine (seed of this account)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'randomnessRequestSlot';
+            docs: [
+              'Slot of the randomness request the draw is bound to (0 when slot hashes are used)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'revenue';
+            docs: [
+              'Revenue paid for the draw, split between the drawn items when revealed',
+            ];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'sellerHistory';
+      docs: [
+        'Seller history state to track count of items submitted to a gumball machine.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'gumballMachine';
+            docs: ["Gumball machine we're tracking for"];
+            type: 'publicKey';
+          },
+          {
+            name: 'seller';
+            docs: ['Seller address'];
+            type: 'publicKey';
+          },
+          {
+            name: 'itemCount';
+            docs: ['Item count submitted by this seller'];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: 'CompressedNftArgs';
+      docs: [
+        'Current state of a compressed NFT leaf, required to transfer it.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'root';
+            docs: ['Root of the merkle tree the proof was generated for.'];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'dataHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'creatorHash';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'nonce';
+            docs: ['Nonce of the leaf, used to derive the asset id.'];
+            type: 'u64';
+          },
+          {
+            name: 'index';
+            docs: ['Index of the leaf in the merkle tree.'];
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'AddItemArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'sellerProofPath';
+            type: {
+              option: {
+                vec: {
+                  array: ['u8', 32];
+                };
+              };
+            };
+          },
+          {
+            name: 'index';
+            type: {
+              option: 'u32';
+            };
+          },
+          {
+            name: 'weight';
+            docs: [
+              'Proceeds weight of the item (defaults to 1), can only be set by the authority',
+            ];
+            type: {
+              option: 'u32';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'InitializeArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'settings';
+            type: {
+              defined: 'GumballSettings';
+            };
+          },
+          {
+            name: 'feeConfig';
+            type: {
+              option: {
+                defined: 'FeeConfig';
+              };
+            };
+          },
+          {
+            name: 'disablePrimarySplit';
+            type: 'bool';
+          },
+          {
+            name: 'buyBackConfig';
+            type: {
+              option: {
+                defined: 'BuyBackConfig';
+              };
+            };
+          },
+          {
             name: 'disableRoyalties';
             type: 'bool';
           },
@@ -3171,929 +6146,3921 @@ export type MallowGumball = {
       };
     },
     {
-      name: 'SettleTokensSaleClaimedArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'startIndex';
-            type: 'u32';
-          },
-          {
-            name: 'endIndex';
-            type: 'u32';
-          },
-        ];
-      };
+      name: 'SettleCompressedNftSaleArgs';
+      docs: ['Arguments required to settle the sale of a compressed nft.'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'compressedNft';
+            docs: ['Current state of the leaf.'];
+            type: {
+              defined: 'CompressedNftArgs';
+            };
+          },
+          {
+            name: 'leafOwner';
+            docs: [
+              'Current owner of the leaf, or the default pubkey if it has been burnt.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'leafDelegate';
+            docs: ['Current delegate of the leaf.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'metadataHash';
+            docs: ['Metadata hash used to verify the data hash.'];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'sellerFeeBasisPoints';
+            type: 'u16';
+          },
+          {
+            name: 'creators';
+            docs: ['Creators used to verify the creator hash.'];
+            type: {
+              vec: {
+                defined: 'Creator';
+              };
+            };
+          },
+          {
+            name: 'proofLength';
+            docs: [
+              'Number of proof nodes at the start of the remaining accounts.',
+            ];
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'SettleTokensSaleClaimedArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'startIndex';
+            type: 'u32';
+          },
+          {
+            name: 'endIndex';
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'UpdateArgs';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'settings';
+            type: {
+              defined: 'GumballSettings';
+            };
+          },
+          {
+            name: 'buyBackConfig';
+            type: {
+              option: {
+                defined: 'BuyBackConfig';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'FeeConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'feeAccount';
+            docs: ['Where fees will go'];
+            type: 'publicKey';
+          },
+          {
+            name: 'feeBps';
+            docs: ['Sale basis points for fees'];
+            type: 'u16';
+          },
+        ];
+      };
+    },
+    {
+      name: 'BuyBackConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'enabled';
+            docs: ['Whether buying back prizes is enabled'];
+            type: 'bool';
+          },
+          {
+            name: 'toGumballMachine';
+            docs: [
+              'Whether buying back prizes should be added back to the gumball machine to be drawn again',
+            ];
+            type: 'bool';
+          },
+          {
+            name: 'oracleSigner';
+            docs: [
+              'Authority that must sign when buying back prizes, to ensure pricing is correct',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'valuePct';
+            docs: [
+              'Percentage of prize value the creator/gumball machine will pay for buying back prizes',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'marketplaceFeeBps';
+            docs: [
+              'Fee in basis points paid to marketplace authority when buying back prizes (paid from funds_available)',
+            ];
+            type: 'u16';
+          },
+          {
+            name: 'cutoffPct';
+            docs: [
+              'Buy backs are disabled when the percentage of items remaining is less than or equal to this value',
+              '0 means there is no cutoff, 100 means buy back is always disabled, 50 means buy back is disabled when 50% of items are sold',
+              'If an item is sold back to the gumball machine to increase the remaining % above this cutoff, buy back is re-enabled',
+            ];
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'RandomnessConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'provider';
+            docs: [
+              'Program that owns the randomness accounts, the default pubkey means slot hashes are used',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'valueOffset';
+            docs: [
+              'Offset of the 32 bytes random value in the randomness account data',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'slotOffset';
+            docs: [
+              'Offset of the slot (u64) at which the random value was produced in the randomness account data',
+            ];
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'RandomnessRequestConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'account';
+            docs: ['Randomness account of the provider used by every draw'];
+            type: 'publicKey';
+          },
+          {
+            name: 'discriminator';
+            docs: [
+              'Discriminator of the randomness account (first 8 bytes of its data)',
+            ];
+            type: {
+              array: ['u8', 8];
+            };
+          },
+          {
+            name: 'requestSlotOffset';
+            docs: [
+              'Offset of the slot (u64) at which the current value was requested in the randomness account data',
+            ];
+            type: 'u32';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ClaimDeadlineConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'claimPeriod';
+            docs: [
+              'Seconds after the sale ends that drawn items can be claimed, 0 means there is no deadline',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'destination';
+            docs: [
+              'Receiver of the items that were not claimed before the deadline',
+            ];
+            type: {
+              defined: 'UnclaimedItemDestination';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'TierConfig';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'weights';
+            docs: [
+              'Draw weight of each tier, the odds of drawing an item are proportional to its tier weight',
+            ];
+            type: {
+              array: ['u16', 8];
+            };
+          },
+          {
+            name: 'itemCounts';
+            docs: ['Number of items assigned to each tier'];
+            type: {
+              array: ['u32', 8];
+            };
+          },
+          {
+            name: 'itemsAvailable';
+            docs: ['Number of items in each tier that have not been drawn yet'];
+            type: {
+              array: ['u32', 8];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'ConfigLineInput';
+      docs: ['Config line struct for storing asset (NFT) data pre-mint.'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mint';
+            docs: ['Mint account of the asset.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'seller';
+            docs: ['Wallet that submitted the asset for sale.'];
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ConfigLineV2Input';
+      docs: ['Config line struct for storing asset (NFT) data pre-mint.'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mint';
+            docs: ['Mint account of the asset.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'seller';
+            docs: ['Wallet that submitted the asset for sale.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'amount';
+            docs: ['Amount of the asset.'];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ConfigLine';
+      docs: ['Config line struct for storing asset data.'];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mint';
+            docs: ['Mint account of the asset.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'seller';
+            docs: ['Wallet that submitted the asset for sale.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'buyer';
+            docs: [
+              'Wallet that will receive the asset upon sale. Empty until drawn.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenStandard';
+            docs: ['Token standard.'];
+            type: {
+              defined: 'TokenStandard';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'ConfigLineV2';
+      docs: [
+        'Config line struct for storing asset data.',
+        '',
+        'Version 6 lines are followed by the tier (u8) of the item, and version 8 lines by',
+        'the proceeds weight (u32) of the item.',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mint';
+            docs: ['Mint account of the asset.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'seller';
+            docs: ['Wallet that submitted the asset for sale.'];
+            type: 'publicKey';
+          },
+          {
+            name: 'buyer';
+            docs: [
+              'Wallet that will receive the asset upon sale. Empty until drawn.',
+            ];
+            type: 'publicKey';
+          },
+          {
+            name: 'tokenStandard';
+            docs: ['Token standard.'];
+            type: {
+              defined: 'TokenStandard';
+            };
+          },
+          {
+            name: 'amount';
+            docs: ['Amount of the asset.'];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'GumballSettings';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'uri';
+            docs: ['Uri of off-chain metadata, max length 196'];
+            type: 'string';
+          },
+          {
+            name: 'itemCapacity';
+            docs: ['Number of assets that can be added.'];
+            type: 'u64';
+          },
+          {
+            name: 'itemsPerSeller';
+            docs: ['Max number of items that can be added by a single seller.'];
+            type: 'u16';
+          },
+          {
+            name: 'sellersMerkleRoot';
+            docs: [
+              'Merkle root hash for sellers who can add items to the machine.',
+            ];
+            type: {
+              option: {
+                array: ['u8', 32];
+              };
+            };
+          },
+          {
+            name: 'curatorFeeBps';
+            docs: ['Fee basis points paid to the machine authority.'];
+            type: 'u16';
+          },
+          {
+            name: 'hideSoldItems';
+            docs: [
+              'True if the front end should hide items that have been sold.',
+            ];
+            type: 'bool';
+          },
+          {
+            name: 'paymentMint';
+            docs: ['Payment token for the mint'];
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ItemReceiver';
+      docs: ['Receiver of an item that is claimed while its sale is settled.'];
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Buyer';
+          },
+          {
+            name: 'Seller';
+          },
+          {
+            name: 'Authority';
+          },
+        ];
+      };
+    },
+    {
+      name: 'UnclaimedItemDestination';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Seller';
+          },
+          {
+            name: 'Authority';
+          },
+        ];
+      };
+    },
+    {
+      name: 'TokenStandard';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'NonFungible';
+          },
+          {
+            name: 'Core';
+          },
+          {
+            name: 'Fungible';
+          },
+          {
+            name: 'ProgrammableNonFungible';
+          },
+          {
+            name: 'Token2022Fungible';
+          },
+          {
+            name: 'CompressedNonFungible';
+          },
+          {
+            name: 'Sol';
+          },
+        ];
+      };
+    },
+    {
+      name: 'GumballState';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'None';
+          },
+          {
+            name: 'DetailsFinalized';
+          },
+          {
+            name: 'SaleLive';
+          },
+          {
+            name: 'SaleEnded';
+          },
+          {
+            name: 'Paused';
+          },
+        ];
+      };
+    },
+  ];
+  events: [
+    {
+      name: 'BuySpecificEvent';
+      fields: [
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'index';
+          type: 'u32';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ClaimItemEvent';
+      fields: [
+        {
+          name: 'mint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'seller';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'DrawItemEvent';
+      fields: [
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'index';
+          type: 'u32';
+          index: false;
+        },
+        {
+          name: 'tier';
+          type: 'u8';
+          index: false;
+        },
+        {
+          name: 'weight';
+          type: 'u16';
+          index: false;
+        },
+        {
+          name: 'totalWeight';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'MintReceiptEvent';
+      fields: [
+        {
+          name: 'receipt';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'index';
+          type: 'u32';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RejectAddItemEvent';
+      fields: [
+        {
+          name: 'gumballMachine';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'seller';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'asset';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'reason';
+          type: {
+            option: 'u8';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RerollItemEvent';
+      fields: [
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'rerolledIndex';
+          type: 'u32';
+          index: false;
+        },
+        {
+          name: 'index';
+          type: 'u32';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SellItemEvent';
+      fields: [
+        {
+          name: 'mint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'seller';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'tokenStandard';
+          type: {
+            defined: 'TokenStandard';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SettleItemSaleEvent';
+      fields: [
+        {
+          name: 'mint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'seller';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'totalProceeds';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'paymentMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'feeConfig';
+          type: {
+            option: {
+              defined: 'FeeConfig';
+            };
+          };
+          index: false;
+        },
+        {
+          name: 'curatorFeeBps';
+          type: 'u16';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'TransferPrizeEvent';
+      fields: [
+        {
+          name: 'mint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'authority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'seller';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'buyer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'newBuyer';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
     },
+  ];
+  errors: [
     {
-      name: 'UpdateArgs';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'settings';
-            type: {
-              defined: 'GumballSettings';
-            };
-          },
-          {
-            name: 'buyBackConfig';
-            type: {
-              option: {
-                defined: 'BuyBackConfig';
-              };
-            };
-          },
-        ];
-      };
+      code: 6000;
+      name: 'IncorrectOwner';
+      msg: 'Account does not have correct owner';
+    },
+    {
+      code: 6001;
+      name: 'Uninitialized';
+      msg: 'Account is not initialized';
+    },
+    {
+      code: 6002;
+      name: 'MintMismatch';
+      msg: 'Mint Mismatch';
+    },
+    {
+      code: 6003;
+      name: 'IndexGreaterThanLength';
+      msg: 'Index greater than length';
+    },
+    {
+      code: 6004;
+      name: 'NumericalOverflowError';
+      msg: 'Numerical overflow error';
+    },
+    {
+      code: 6005;
+      name: 'TooManyCreators';
+      msg: 'Can only provide up to 4 creators to gumball machine (because gumball machine is one)';
+    },
+    {
+      code: 6006;
+      name: 'GumballMachineEmpty';
+      msg: 'Gumball machine is empty';
+    },
+    {
+      code: 6007;
+      name: 'HiddenSettingsDoNotHaveConfigLines';
+      msg: 'Gumball machines using hidden uris do not have config lines, they have a single hash representing hashed order';
+    },
+    {
+      code: 6008;
+      name: 'CannotChangeNumberOfLines';
+      msg: 'Cannot change number of lines unless is a hidden config';
+    },
+    {
+      code: 6009;
+      name: 'CannotSwitchToHiddenSettings';
+      msg: 'Cannot switch to hidden settings after items available is greater than 0';
+    },
+    {
+      code: 6010;
+      name: 'IncorrectCollectionAuthority';
+      msg: 'Incorrect collection NFT authority';
+    },
+    {
+      code: 6011;
+      name: 'MetadataAccountMustBeEmpty';
+      msg: 'The metadata account has data in it, and this must be empty to mint a new NFT';
+    },
+    {
+      code: 6012;
+      name: 'NoChangingCollectionDuringMint';
+      msg: "Can't change collection settings after items have begun to be minted";
+    },
+    {
+      code: 6013;
+      name: 'ExceededLengthError';
+      msg: 'Value longer than expected maximum value';
+    },
+    {
+      code: 6014;
+      name: 'MissingConfigLinesSettings';
+      msg: 'Missing config lines settings';
+    },
+    {
+      code: 6015;
+      name: 'CannotIncreaseLength';
+      msg: 'Cannot increase the length in config lines settings';
+    },
+    {
+      code: 6016;
+      name: 'CannotSwitchFromHiddenSettings';
+      msg: 'Cannot switch from hidden settings';
+    },
+    {
+      code: 6017;
+      name: 'CannotChangeSequentialIndexGeneration';
+      msg: 'Cannot change sequential index generation after items have begun to be minted';
+    },
+    {
+      code: 6018;
+      name: 'CollectionKeyMismatch';
+      msg: 'Collection public key mismatch';
+    },
+    {
+      code: 6019;
+      name: 'CouldNotRetrieveConfigLineData';
+      msg: 'Could not retrive config line data';
+    },
+    {
+      code: 6020;
+      name: 'NotFullyLoaded';
+      msg: 'Not all config lines were added to the gumball machine';
+    },
+    {
+      code: 6021;
+      name: 'InstructionBuilderFailed';
+      msg: 'Instruction could not be created';
+    },
+    {
+      code: 6022;
+      name: 'MissingCollectionAuthorityRecord';
+      msg: 'Missing collection authority record';
+    },
+    {
+      code: 6023;
+      name: 'MissingMetadataDelegateRecord';
+      msg: 'Missing metadata delegate record';
+    },
+    {
+      code: 6024;
+      name: 'InvalidTokenStandard';
+      msg: 'Invalid token standard';
+    },
+    {
+      code: 6025;
+      name: 'MissingTokenAccount';
+      msg: 'Missing token account';
+    },
+    {
+      code: 6026;
+      name: 'MissingTokenRecord';
+      msg: 'Missing token record';
+    },
+    {
+      code: 6027;
+      name: 'MissingInstructionsSysvar';
+      msg: 'Missing instructions sysvar account';
+    },
+    {
+      code: 6028;
+      name: 'MissingSplAtaProgram';
+      msg: 'Missing SPL ATA program';
+    },
+    {
+      code: 6029;
+      name: 'InvalidAccountVersion';
+      msg: 'Invalid account version';
+    },
+    {
+      code: 6030;
+      name: 'NotPrimarySale';
+      msg: 'Not a primary sale asset';
+    },
+    {
+      code: 6031;
+      name: 'InvalidEditionAccount';
+      msg: 'Invalid edition account';
+    },
+    {
+      code: 6032;
+      name: 'InvalidMasterEditionSupply';
+      msg: 'Invalid master edition supply';
+    },
+    {
+      code: 6033;
+      name: 'PublicKeyMismatch';
+      msg: 'Public key mismatch';
+    },
+    {
+      code: 6034;
+      name: 'InvalidCollection';
+      msg: 'Invalid collection';
+    },
+    {
+      code: 6035;
+      name: 'GumballMachineDetailsFinalized';
+      msg: 'Gumball machine detailed finalized';
+    },
+    {
+      code: 6036;
+      name: 'InvalidState';
+      msg: 'Invalid state';
+    },
+    {
+      code: 6037;
+      name: 'InvalidAuthority';
+      msg: 'Invalid authority';
+    },
+    {
+      code: 6038;
+      name: 'InvalidMintAuthority';
+      msg: 'Invalid mint authority';
     },
     {
-      name: 'FeeConfig';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'feeAccount';
-            docs: ['Where fees will go'];
-            type: 'publicKey';
-          },
-          {
-            name: 'feeBps';
-            docs: ['Sale basis points for fees'];
-            type: 'u16';
-          },
-        ];
-      };
+      code: 6039;
+      name: 'InvalidMint';
+      msg: 'Invalid mint';
     },
     {
-      name: 'BuyBackConfig';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'enabled';
-            docs: ['Whether buying back prizes is enabled'];
-            type: 'bool';
-          },
-          {
-            name: 'toGumballMachine';
-            docs: [
-              'Whether buying back prizes should be added back to the gumball machine (not yet supported)',
-            ];
-            type: 'bool';
-          },
-          {
-            name: 'oracleSigner';
-            docs: [
-              'Authority that must sign when buying back prizes, to ensure pricing is correct',
-            ];
-            type: 'publicKey';
-          },
-          {
-            name: 'valuePct';
-            docs: [
-              'Percentage of prize value the creator/gumball machine will pay for buying back prizes',
-            ];
-            type: 'u8';
-          },
-          {
-            name: 'marketplaceFeeBps';
-            docs: [
-              'Fee in basis points paid to marketplace authority when buying back prizes (paid from funds_available)',
-            ];
-            type: 'u16';
-          },
-          {
-            name: 'cutoffPct';
-            docs: [
-              'Buy backs are disabled when the percentage of items remaining is less than or equal to this value',
-              '0 means there is no cutoff, 100 means buy back is always disabled, 50 means buy back is disabled when 50% of items are sold',
-              'If an item is sold back to the gumball machine to increase the remaining % above this cutoff, buy back is re-enabled',
-            ];
-            type: 'u8';
-          },
-        ];
-      };
+      code: 6040;
+      name: 'InvalidPaymentMint';
+      msg: 'Invalid payment mint';
     },
     {
-      name: 'ConfigLineInput';
-      docs: ['Config line struct for storing asset (NFT) data pre-mint.'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'mint';
-            docs: ['Mint account of the asset.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'seller';
-            docs: ['Wallet that submitted the asset for sale.'];
-            type: 'publicKey';
-          },
-        ];
-      };
+      code: 6041;
+      name: 'InvalidSeller';
+      msg: 'Invalid seller';
     },
     {
-      name: 'ConfigLineV2Input';
-      docs: ['Config line struct for storing asset (NFT) data pre-mint.'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'mint';
-            docs: ['Mint account of the asset.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'seller';
-            docs: ['Wallet that submitted the asset for sale.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'amount';
-            docs: ['Amount of the asset.'];
-            type: 'u64';
-          },
-        ];
-      };
+      code: 6042;
+      name: 'InvalidBuyer';
+      msg: 'Invalid buyer';
     },
     {
-      name: 'ConfigLine';
-      docs: ['Config line struct for storing asset data.'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'mint';
-            docs: ['Mint account of the asset.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'seller';
-            docs: ['Wallet that submitted the asset for sale.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'buyer';
-            docs: [
-              'Wallet that will receive the asset upon sale. Empty until drawn.',
-            ];
-            type: 'publicKey';
-          },
-          {
-            name: 'tokenStandard';
-            docs: ['Token standard.'];
-            type: {
-              defined: 'TokenStandard';
-            };
-          },
-        ];
-      };
+      code: 6043;
+      name: 'UriTooLong';
+      msg: 'URI too long';
     },
     {
-      name: 'ConfigLineV2';
-      docs: ['Config line struct for storing asset data.'];
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'mint';
-            docs: ['Mint account of the asset.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'seller';
-            docs: ['Wallet that submitted the asset for sale.'];
-            type: 'publicKey';
-          },
-          {
-            name: 'buyer';
-            docs: [
-              'Wallet that will receive the asset upon sale. Empty until drawn.',
-            ];
-            type: 'publicKey';
-          },
-          {
-            name: 'tokenStandard';
-            docs: ['Token standard.'];
-            type: {
-              defined: 'TokenStandard';
-            };
-          },
-          {
-            name: 'amount';
-            docs: ['Amount of the asset.'];
-            type: 'u64';
-          },
-        ];
-      };
+      code: 6044;
+      name: 'InvalidProofPath';
+      msg: 'Invalid proof path';
     },
     {
-      name: 'GumballSettings';
-      type: {
-        kind: 'struct';
-        fields: [
-          {
-            name: 'uri';
-            docs: ['Uri of off-chain metadata, max length 196'];
-            type: 'string';
-          },
-          {
-            name: 'itemCapacity';
-            docs: ['Number of assets that can be added.'];
-            type: 'u64';
-          },
-          {
-            name: 'itemsPerSeller';
-            docs: ['Max number of items that can be added by a single seller.'];
-            type: 'u16';
-          },
-          {
-            name: 'sellersMerkleRoot';
-            docs: [
-              'Merkle root hash for sellers who can add items to the machine.',
-            ];
-            type: {
-              option: {
-                array: ['u8', 32];
-              };
-            };
-          },
-          {
-            name: 'curatorFeeBps';
-            docs: ['Fee basis points paid to the machine authority.'];
-            type: 'u16';
-          },
-          {
-            name: 'hideSoldItems';
-            docs: [
-              'True if the front end should hide items that have been sold.',
-            ];
-            type: 'bool';
-          },
-          {
-            name: 'paymentMint';
-            docs: ['Payment token for the mint'];
-            type: 'publicKey';
-          },
-        ];
-      };
+      code: 6045;
+      name: 'InvalidSettingUpdate';
+      msg: 'Invalid setting update';
+    },
+    {
+      code: 6046;
+      name: 'SellerTooManyItems';
+      msg: 'Seller has too many items';
+    },
+    {
+      code: 6047;
+      name: 'NotAllSettled';
+      msg: 'Not all items have been settled';
+    },
+    {
+      code: 6048;
+      name: 'ItemAlreadySettled';
+      msg: 'Item already settled';
     },
     {
-      name: 'TokenStandard';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'NonFungible';
-          },
-          {
-            name: 'Core';
-          },
-          {
-            name: 'Fungible';
-          },
-          {
-            name: 'ProgrammableNonFungible';
-          },
-        ];
-      };
+      code: 6049;
+      name: 'ItemAlreadyClaimed';
+      msg: 'Item already claimed';
     },
     {
-      name: 'GumballState';
-      type: {
-        kind: 'enum';
-        variants: [
-          {
-            name: 'None';
-          },
-          {
-            name: 'DetailsFinalized';
-          },
-          {
-            name: 'SaleLive';
-          },
-          {
-            name: 'SaleEnded';
-          },
-        ];
-      };
+      code: 6050;
+      name: 'ItemAlreadyDrawn';
+      msg: 'Item already drawn';
     },
-  ];
-  events: [
     {
-      name: 'ClaimItemEvent';
-      fields: [
-        {
-          name: 'mint';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'authority';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'seller';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'buyer';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'amount';
-          type: 'u64';
-          index: false;
-        },
-      ];
+      code: 6051;
+      name: 'InvalidGumballMachine';
+      msg: 'Invalid gumball machine';
     },
     {
-      name: 'DrawItemEvent';
-      fields: [
-        {
-          name: 'authority';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'buyer';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'index';
-          type: 'u32';
-          index: false;
-        },
-      ];
+      code: 6052;
+      name: 'SellerCannotBeAuthority';
+      msg: 'Seller cannot be authority';
     },
     {
-      name: 'SellItemEvent';
-      fields: [
-        {
-          name: 'mint';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'authority';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'seller';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'buyer';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'amount';
-          type: 'u64';
-          index: false;
-        },
-        {
-          name: 'tokenStandard';
-          type: {
-            defined: 'TokenStandard';
-          };
-          index: false;
-        },
-      ];
+      code: 6053;
+      name: 'InvalidAssetPlugin';
+      msg: 'Asset has an invalid plugin';
     },
     {
-      name: 'SettleItemSaleEvent';
-      fields: [
-        {
-          name: 'mint';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'authority';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'seller';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'buyer';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'totalProceeds';
-          type: 'u64';
-          index: false;
-        },
-        {
-          name: 'paymentMint';
-          type: 'publicKey';
-          index: false;
-        },
-        {
-          name: 'feeConfig';
-          type: {
-            option: {
-              defined: 'FeeConfig';
-            };
-          };
-          index: false;
-        },
-        {
-          name: 'curatorFeeBps';
-          type: 'u16';
-          index: false;
-        },
-        {
-          name: 'amount';
-          type: 'u64';
-          index: false;
-        },
-      ];
+      code: 6054;
+      name: 'InvalidAmount';
+      msg: 'Invalid amount';
     },
-  ];
-  errors: [
     {
-      code: 6000;
-      name: 'IncorrectOwner';
-      msg: 'Account does not have correct owner';
+      code: 6055;
+      name: 'DuplicateIndex';
+      msg: 'Duplicate index';
     },
     {
-      code: 6001;
-      name: 'Uninitialized';
-      msg: 'Account is not initialized';
+      code: 6056;
+      name: 'InvalidInputLength';
+      msg: 'Invalid input length';
     },
     {
-      code: 6002;
-      name: 'MintMismatch';
-      msg: 'Mint Mismatch';
+      code: 6057;
+      name: 'BuyBackNotEnabled';
+      msg: 'Buy back not enabled';
+    },
+    {
+      code: 6058;
+      name: 'BuyBackFundsNotZero';
+      msg: 'Buy back funds not zero';
+    },
+    {
+      code: 6059;
+      name: 'InsufficientFunds';
+      msg: 'Insufficient funds';
+    },
+    {
+      code: 6060;
+      name: 'InvalidVersion';
+      msg: 'Invalid version';
+    },
+    {
+      code: 6061;
+      name: 'InvalidOracleSigner';
+      msg: 'Invalid oracle signer';
+    },
+    {
+      code: 6062;
+      name: 'InvalidPayer';
+      msg: 'Invalid payer';
+    },
+    {
+      code: 6063;
+      name: 'NotImplemented';
+      msg: 'Not implemented';
+    },
+    {
+      code: 6064;
+      name: 'BuyBackCutoffReached';
+      msg: 'Buy back cutoff reached';
+    },
+    {
+      code: 6065;
+      name: 'NotASoloGumball';
+      msg: 'Not a solo gumball';
+    },
+    {
+      code: 6066;
+      name: 'ItemNotClaimed';
+      msg: 'Item not claimed';
+    },
+    {
+      code: 6067;
+      name: 'ItemNotSettled';
+      msg: 'Item not settled';
     },
     {
-      code: 6003;
-      name: 'IndexGreaterThanLength';
-      msg: 'Index greater than length';
+      code: 6068;
+      name: 'MissingItemIndex';
+      msg: 'Missing item index';
     },
     {
-      code: 6004;
-      name: 'NumericalOverflowError';
-      msg: 'Numerical overflow error';
+      code: 6069;
+      name: 'InvalidQuantity';
+      msg: 'Invalid quantity';
     },
     {
-      code: 6005;
-      name: 'TooManyCreators';
-      msg: 'Can only provide up to 4 creators to gumball machine (because gumball machine is one)';
+      code: 6070;
+      name: 'RevealNotReady';
+      msg: 'Draw cannot be revealed yet';
     },
     {
-      code: 6006;
-      name: 'GumballMachineEmpty';
-      msg: 'Gumball machine is empty';
+      code: 6071;
+      name: 'ItemsPendingReveal';
+      msg: 'Items are pending reveal';
     },
     {
-      code: 6007;
-      name: 'HiddenSettingsDoNotHaveConfigLines';
-      msg: 'Gumball machines using hidden uris do not have config lines, they have a single hash representing hashed order';
+      code: 6072;
+      name: 'InvalidTier';
+      msg: 'Invalid tier';
     },
     {
-      code: 6008;
-      name: 'CannotChangeNumberOfLines';
-      msg: 'Cannot change number of lines unless is a hidden config';
+      code: 6073;
+      name: 'MissingRandomnessAccount';
+      msg: 'Missing randomness account';
     },
     {
-      code: 6009;
-      name: 'CannotSwitchToHiddenSettings';
-      msg: 'Cannot switch to hidden settings after items available is greater than 0';
+      code: 6074;
+      name: 'InvalidRandomnessAccount';
+      msg: 'Invalid randomness account';
     },
     {
-      code: 6010;
-      name: 'IncorrectCollectionAuthority';
-      msg: 'Incorrect collection NFT authority';
+      code: 6075;
+      name: 'AccountSizeIncreaseTooLarge';
+      msg: 'Account size increase is too large, resize in smaller steps';
     },
     {
-      code: 6011;
-      name: 'MetadataAccountMustBeEmpty';
-      msg: 'The metadata account has data in it, and this must be empty to mint a new NFT';
+      code: 6076;
+      name: 'InvalidProceedsWeight';
+      msg: 'Invalid proceeds weight';
     },
     {
-      code: 6012;
-      name: 'NoChangingCollectionDuringMint';
-      msg: "Can't change collection settings after items have begun to be minted";
+      code: 6077;
+      name: 'InvalidTokenProgram';
+      msg: 'Invalid token program';
     },
     {
-      code: 6013;
-      name: 'ExceededLengthError';
-      msg: 'Value longer than expected maximum value';
+      code: 6078;
+      name: 'NonTransferableMint';
+      msg: 'Non-transferable tokens cannot be added';
     },
     {
-      code: 6014;
-      name: 'MissingConfigLinesSettings';
-      msg: 'Missing config lines settings';
+      code: 6079;
+      name: 'UnsupportedMintExtension';
+      msg: 'Tokens with a transfer hook or permanent delegate cannot be added';
     },
     {
-      code: 6015;
-      name: 'CannotIncreaseLength';
-      msg: 'Cannot increase the length in config lines settings';
+      code: 6080;
+      name: 'MissingCompressedNftArgs';
+      msg: 'Compressed nft args are required';
     },
     {
-      code: 6016;
-      name: 'CannotSwitchFromHiddenSettings';
-      msg: 'Cannot switch from hidden settings';
+      code: 6081;
+      name: 'DuplicateItem';
+      msg: 'Duplicate item';
     },
     {
-      code: 6017;
-      name: 'CannotChangeSequentialIndexGeneration';
-      msg: 'Cannot change sequential index generation after items have begun to be minted';
+      code: 6082;
+      name: 'MissingAccount';
+      msg: 'Missing account';
     },
     {
-      code: 6018;
-      name: 'CollectionKeyMismatch';
-      msg: 'Collection public key mismatch';
+      code: 6083;
+      name: 'InvalidAddItemRequestExpiry';
+      msg: 'Invalid add item request expiry';
     },
     {
-      code: 6019;
-      name: 'CouldNotRetrieveConfigLineData';
-      msg: 'Could not retrive config line data';
+      code: 6084;
+      name: 'AddItemRequestNotExpired';
+      msg: 'Add item request has not expired';
     },
     {
-      code: 6020;
-      name: 'NotFullyLoaded';
-      msg: 'Not all config lines were added to the gumball machine';
+      code: 6085;
+      name: 'InvalidForceCloseGracePeriod';
+      msg: 'Invalid force close grace period';
     },
     {
-      code: 6021;
-      name: 'InstructionBuilderFailed';
-      msg: 'Instruction could not be created';
+      code: 6086;
+      name: 'ForceCloseNotAvailable';
+      msg: 'Gumball machine cannot be force closed yet';
     },
     {
-      code: 6022;
-      name: 'MissingCollectionAuthorityRecord';
-      msg: 'Missing collection authority record';
+      code: 6087;
+      name: 'InvalidClaimDeadline';
+      msg: 'Invalid claim deadline';
     },
     {
-      code: 6023;
-      name: 'MissingMetadataDelegateRecord';
-      msg: 'Missing metadata delegate record';
+      code: 6088;
+      name: 'ClaimDeadlinePassed';
+      msg: 'Claim deadline has passed';
     },
     {
-      code: 6024;
-      name: 'InvalidTokenStandard';
-      msg: 'Invalid token standard';
+      code: 6089;
+      name: 'InvalidInstantDeliveryAccount';
+      msg: 'Invalid instant delivery account';
     },
     {
-      code: 6025;
-      name: 'MissingTokenAccount';
-      msg: 'Missing token account';
+      code: 6090;
+      name: 'ReceiptsNotEnabled';
+      msg: 'Receipt collection is not set';
     },
     {
-      code: 6026;
-      name: 'MissingTokenRecord';
-      msg: 'Missing token record';
+      code: 6091;
+      name: 'InvalidReceiptCollection';
+      msg: 'Invalid receipt collection';
     },
     {
-      code: 6027;
-      name: 'MissingInstructionsSysvar';
-      msg: 'Missing instructions sysvar account';
+      code: 6092;
+      name: 'InvalidReceipt';
+      msg: 'Invalid receipt';
     },
     {
-      code: 6028;
-      name: 'MissingSplAtaProgram';
-      msg: 'Missing SPL ATA program';
+      code: 6093;
+      name: 'ItemHeldByReceipt';
+      msg: 'Item is held by a receipt';
     },
     {
-      code: 6029;
-      name: 'InvalidAccountVersion';
-      msg: 'Invalid account version';
+      code: 6094;
+      name: 'ClaimDeadlineRequired';
+      msg: 'Receipts require a claim deadline';
     },
     {
-      code: 6030;
-      name: 'NotPrimarySale';
-      msg: 'Not a primary sale asset';
+      code: 6095;
+      name: 'RevealOutOfOrder';
+      msg: 'Earlier draws must be revealed first';
     },
     {
-      code: 6031;
-      name: 'InvalidEditionAccount';
-      msg: 'Invalid edition account';
+      code: 6096;
+      name: 'RandomnessRequestFulfilled';
+      msg: 'The randomness request was already fulfilled';
     },
     {
-      code: 6032;
-      name: 'InvalidMasterEditionSupply';
-      msg: 'Invalid master edition supply';
+      code: 6097;
+      name: 'RandomnessRequestReplaced';
+      msg: 'The randomness request of the draw was replaced';
     },
     {
-      code: 6033;
-      name: 'PublicKeyMismatch';
-      msg: 'Public key mismatch';
+      code: 6098;
+      name: 'SolPrizeTooSmall';
+      msg: 'SOL prizes must be at least the rent-exempt minimum';
     },
     {
-      code: 6034;
-      name: 'InvalidCollection';
-      msg: 'Invalid collection';
+      code: 6099;
+      name: 'SolPrizesNotCovered';
+      msg: 'Transfer would use the lamports of SOL prizes';
     },
     {
-      code: 6035;
-      name: 'GumballMachineDetailsFinalized';
-      msg: 'Gumball machine detailed finalized';
+      code: 6100;
+      name: 'DrawCanBeRevealed';
+      msg: 'The draw can still be revealed';
     },
     {
-      code: 6036;
-      name: 'InvalidState';
-      msg: 'Invalid state';
+      code: 6101;
+      name: 'InstantDeliveryNotSupported';
+      msg: 'Compressed NFTs cannot be delivered instantly';
     },
     {
-      code: 6037;
-      name: 'InvalidAuthority';
-      msg: 'Invalid authority';
+      code: 6102;
+      name: 'InvalidReceiptAccount';
+      msg: 'Invalid receipt account';
     },
     {
-      code: 6038;
-      name: 'InvalidMintAuthority';
-      msg: 'Invalid mint authority';
+      code: 6103;
+      name: 'ReceiptsWithInstantDelivery';
+      msg: 'Receipts cannot be used with instant delivery';
     },
+  ];
+};
+
+export const IDL: MallowGumball = {
+  version: '1.0.0',
+  name: 'mallow_gumball',
+  instructions: [
     {
-      code: 6039;
-      name: 'InvalidMint';
-      msg: 'Invalid mint';
+      name: 'initialize',
+      docs: [
+        'Initialize the gumball machine account with the specified data.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (must be pre-allocated but zero content)',
+        '1. `[]` Gumball Machine authority',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Payer',
+        '4. `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Gumball Machine account. The account space must be allocated to allow accounts larger',
+            'than 10kb.',
+            '',
+          ],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: false,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            '',
+          ],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Payer of the transaction.'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'InitializeArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6040;
-      name: 'InvalidPaymentMint';
-      msg: 'Invalid payment mint';
+      name: 'updateSettings',
+      docs: [
+        'Updates gumball machine settings.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'UpdateArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6041;
-      name: 'InvalidSeller';
-      msg: 'Invalid seller';
+      name: 'setItemTiers',
+      docs: [
+        'Sets the tier of the items in the range `[start_index, end_index]`.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'startIndex',
+          type: 'u32',
+        },
+        {
+          name: 'endIndex',
+          type: 'u32',
+        },
+        {
+          name: 'tier',
+          type: 'u8',
+        },
+      ],
     },
     {
-      code: 6042;
-      name: 'InvalidBuyer';
-      msg: 'Invalid buyer';
+      name: 'setItemWeights',
+      docs: [
+        'Sets the proceeds weight of the items in the range `[start_index, end_index]`.',
+        'Sale proceeds are split between sellers proportionally to the weight of their items.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'startIndex',
+          type: 'u32',
+        },
+        {
+          name: 'endIndex',
+          type: 'u32',
+        },
+        {
+          name: 'weight',
+          type: 'u32',
+        },
+      ],
     },
     {
-      code: 6043;
-      name: 'UriTooLong';
-      msg: 'URI too long';
+      name: 'setTierWeights',
+      docs: [
+        'Sets the draw weight of each tier. The odds of drawing an item are proportional',
+        'to the weight of its tier.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'weights',
+          type: {
+            array: ['u16', 8],
+          },
+        },
+      ],
     },
     {
-      code: 6044;
-      name: 'InvalidProofPath';
-      msg: 'Invalid proof path';
+      name: 'setRandomnessConfig',
+      docs: [
+        'Sets the randomness provider used to draw items. When no provider is set, draws',
+        'are revealed using slot hashes.',
+        '',
+        'Every draw uses the configured randomness account, which must have an open request',
+        'when the draw is made. The draw is bound to that request and is only revealed with',
+        'its value; if the request is replaced, the draw cannot be revealed.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'randomnessConfig',
+          type: {
+            defined: 'RandomnessConfig',
+          },
+        },
+        {
+          name: 'randomnessRequestConfig',
+          type: {
+            defined: 'RandomnessRequestConfig',
+          },
+        },
+      ],
     },
     {
-      code: 6045;
-      name: 'InvalidSettingUpdate';
-      msg: 'Invalid setting update';
+      name: 'setAddItemRequestExpiry',
+      docs: [
+        'Sets how many seconds add item requests stay open before anyone can close them.',
+        'An expiry of 0 means requests never expire.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'expiry',
+          type: 'i64',
+        },
+      ],
     },
     {
-      code: 6046;
-      name: 'SellerTooManyItems';
-      msg: 'Seller has too many items';
+      name: 'setForceCloseGracePeriod',
+      docs: [
+        'Sets how many seconds after the sale ends the gumball machine can be force closed',
+        'without the mint authority. A grace period of 0 disables force closing. Once the sale',
+        'has started the grace period can only be increased.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'gracePeriod',
+          type: 'i64',
+        },
+      ],
     },
     {
-      code: 6047;
-      name: 'NotAllSettled';
-      msg: 'Not all items have been settled';
+      name: 'setClaimDeadline',
+      docs: [
+        'Sets the deadline to claim drawn items, counted from the end of the sale. Once it has',
+        'passed, buyers can no longer claim their items and settling an unclaimed item sends it',
+        'to the configured destination instead, while the seller is paid as usual. Items held',
+        'by a receipt do not expire.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'claimDeadlineConfig',
+          type: {
+            defined: 'ClaimDeadlineConfig',
+          },
+        },
+      ],
     },
     {
-      code: 6048;
-      name: 'ItemAlreadySettled';
-      msg: 'Item already settled';
+      name: 'setInstantDelivery',
+      docs: [
+        'Sets whether drawn items are transferred to the buyer when the draw is revealed,',
+        'instead of being claimed in a separate transaction. Items are only selected on',
+        "reveal, so the reveal transaction (sent by the buyer's client or a reveal crank)",
+        'passes the delivery accounts of the drawn items. Compressed NFTs cannot be delivered',
+        'this way, so instant delivery cannot be enabled on a machine that holds them, or on a',
+        'machine with receipts.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'instantDelivery',
+          type: 'bool',
+        },
+      ],
+    },
+    {
+      name: 'createReceiptCollection',
+      docs: [
+        'Creates the Core collection of the draw receipts, with the authority PDA as its',
+        'update authority. Receipts use the name and URI of the collection.',
+        '',
+        'Once the collection exists, every revealed draw mints a receipt for each drawn item,',
+        'with the gumball machine, index and draw slot as attributes. The receipt becomes the',
+        'only way to claim the item, so it can be traded until it is burnt with',
+        '`claim_with_receipt`. Items held by a receipt cannot be settled until it is burnt.',
+        'Receipts cannot be used with instant delivery.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '2. `[signer, writable]` Gumball Machine authority (payer of the collection)',
+        '3. `[signer, writable]` Receipt collection account',
+        '4. `[]` MPL Core program',
+        '5. `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Pays for the collection account.',
+          ],
+        },
+        {
+          name: 'receiptCollection',
+          isMut: true,
+          isSigner: true,
+          docs: ['Receipt collection account.'],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['System program.'],
+        },
+      ],
+      args: [
+        {
+          name: 'name',
+          type: 'string',
+        },
+        {
+          name: 'uri',
+          type: 'string',
+        },
+      ],
     },
     {
-      code: 6049;
-      name: 'ItemAlreadyClaimed';
-      msg: 'Item already claimed';
+      name: 'resizeCapacity',
+      docs: [
+        'Resizes the item capacity of the gumball machine, moving the data stored after',
+        'the config lines to its new position. The capacity can be decreased down to the',
+        'number of items loaded.',
+        '',
+        'The account can only grow by 10KiB per instruction, so a larger increase is done',
+        'over several calls with the same capacity: each call grows the account and the',
+        'data is moved by the last one.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Pays for (or receives) the rent difference.',
+          ],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['System program.'],
+        },
+      ],
+      args: [
+        {
+          name: 'itemCapacity',
+          type: 'u64',
+        },
+      ],
     },
     {
-      code: 6050;
-      name: 'ItemAlreadyDrawn';
-      msg: 'Item already drawn';
+      name: 'migrate',
+      docs: [
+        'Migrates the gumball machine account to the current version, upgrading config',
+        'lines and adding the fields of newer versions with default values.',
+        '',
+        'Accounts that need to grow by more than 10kb are resized over multiple calls,',
+        'the data is only migrated once the account reaches its final size.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Pays for the additional rent.',
+          ],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+          docs: ['System program.'],
+        },
+      ],
+      args: [],
     },
     {
-      code: 6051;
-      name: 'InvalidGumballMachine';
-      msg: 'Invalid gumball machine';
+      name: 'addNft',
+      docs: [
+        'Add legacy NFTs to the gumball machine.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[]` Mint account',
+        '5. `[writable]` Token account',
+        '6. `[writable]` Metadata account',
+        '7. `[]` Edition account',
+        '8. `[]` Token program',
+        '9. `[]` Token Metadata program',
+        '10. `[]` System program',
+        '11. `[writable, optional]` Seller token record (pNFT)',
+        '12. `[optional]` Auth rules account (pNFT)',
+        '13. `[optional]` Instructions sysvar (pNFT)',
+        '14. `[optional]` Auth rules program (pNFT)',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the nft'],
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'edition',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS'],
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'AddItemArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6052;
-      name: 'SellerCannotBeAuthority';
-      msg: 'Seller cannot be authority';
+      name: 'addCoreAsset',
+      docs: [
+        'Add Core assets to the gumball machine.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[writable]` Asset account',
+        '5. `[writable, optional]` Collection account',
+        '6. `[]` MPL Core program',
+        '7. `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the asset.'],
+        },
+        {
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Core asset's collection if it's part of one."],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'AddItemArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6053;
-      name: 'InvalidAssetPlugin';
-      msg: 'Asset has an invalid plugin';
+      name: 'addCoreAssets',
+      docs: [
+        'Add multiple core assets to the gumball machine.',
+        'Validates the seller once and freezes each asset with the authority PDA as delegate.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[writable, optional]` Collection account shared by the assets',
+        '5. `[]` MPL Core program',
+        '6. `[]` System program',
+        'Remaining accounts: Asset accounts (writable)',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the assets.'],
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Collection shared by the assets if they are part of one.'],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'AddItemArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6054;
-      name: 'InvalidAmount';
-      msg: 'Invalid amount';
+      name: 'addTokens',
+      docs: [
+        'Add fungible tokens to the gumball machine.',
+        'Token-2022 mints are supported, the seller pays any transfer fee so the gumball machine',
+        'receives the full amount. Non-transferable mints are rejected.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[]` Mint account',
+        "5. `[writable]` Seller's token account",
+        "6. `[writable]` Gumball machine's token account",
+        '7. `[]` Token program (SPL Token or Token-2022)',
+        '8. `[]` Associated Token program',
+        '9. `[]` System program',
+        '10. `[]` Rent sysvar',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the tokens'],
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'],
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'amount',
+          type: 'u64',
+        },
+        {
+          name: 'quantity',
+          type: 'u16',
+        },
+        {
+          name: 'args',
+          type: {
+            defined: 'AddItemArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6055;
-      name: 'DuplicateIndex';
-      msg: 'Duplicate index';
+      name: 'addCompressedNft',
+      docs: [
+        'Add compressed nft to the gumball machine.',
+        'Transfers the leaf to the authority PDA, the config line records the asset id.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[writable]` Merkle tree account',
+        '5. `[]` Tree config account',
+        '6. `[]` Bubblegum program',
+        '7. `[]` SPL Account Compression program',
+        '8. `[]` SPL Noop program',
+        '9. `[]` System program',
+        'Remaining accounts: Merkle proof nodes',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the nft'],
+        },
+        {
+          name: 'merkleTree',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'treeConfig',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bubblegumProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'compressionProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'logWrapper',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'args',
+          type: {
+            defined: 'AddItemArgs',
+          },
+        },
+        {
+          name: 'compressedNft',
+          type: {
+            defined: 'CompressedNftArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6056;
-      name: 'InvalidInputLength';
-      msg: 'Invalid input length';
+      name: 'addSolPrize',
+      docs: [
+        'Add native SOL prizes to the gumball machine.',
+        'Transfers `amount * quantity` lamports to the authority PDA, the config lines record the native mint.',
+        'Each prize must be at least the rent-exempt minimum, and the lamports of SOL prizes are',
+        'tracked apart from the sale revenue so payouts of revenue cannot use them.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Seller',
+        '4. `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the prize'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'amount',
+          type: 'u64',
+        },
+        {
+          name: 'quantity',
+          type: 'u16',
+        },
+        {
+          name: 'args',
+          type: {
+            defined: 'AddItemArgs',
+          },
+        },
+      ],
     },
     {
-      code: 6057;
-      name: 'BuyBackNotEnabled';
-      msg: 'Buy back not enabled';
+      name: 'requestAddNft',
+      docs: [
+        'Request to add a NFT to the gumball machine.',
+        "Freezes the seller's NFT and creates a request account.",
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]).',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '4. `[signer, writable]` Seller of the nft.',
+        '5. `[]` Mint account of the NFT.',
+        "6. `[writable]` Seller's token account for the NFT.",
+        '7. `[writable]` Metadata account of the NFT.',
+        '8. `[]` Edition account of the NFT.',
+        '9. `[]` Token program.',
+        '10. `[]` Token Metadata program.',
+        '11. `[]` System program.',
+        '12. `[writable, optional]` Seller token record (pNFT).',
+        '13. `[optional]` Auth rules account (pNFT).',
+        '14. `[optional]` Instructions sysvar (pNFT).',
+        '15. `[optional]` Auth rules program (pNFT).',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the nft'],
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'edition',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS'],
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6058;
-      name: 'BuyBackFundsNotZero';
-      msg: 'Buy back funds not zero';
+      name: 'requestAddCoreAsset',
+      docs: [
+        'Request to add a core asset to the gumball machine.',
+        "Freezes the seller's asset and creates a request account.",
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]).',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '4. `[signer, writable]` Seller of the asset.',
+        '5. `[writable]` Asset account.',
+        '6. `[writable, optional]` Collection account if asset is part of one.',
+        '7. `[]` MPL Core program.',
+        '8. `[]` System program.',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the asset.'],
+        },
+        {
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Core asset's collection if it's part of one."],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6059;
-      name: 'InsufficientFunds';
-      msg: 'Insufficient funds';
+      name: 'cancelAddNftRequest',
+      docs: [
+        'Cancel a request to add a NFT to the gumball machine.',
+        "Thaws and revokes delegate from the seller's NFT and closes the request account.",
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
+        '3. `[signer, writable]` Seller of the NFT.',
+        '4. `[]` Mint account of the NFT.',
+        "5. `[writable]` Seller's token account for the NFT.",
+        "6. `[writable]` Authority PDA's token account.",
+        '7. `[]` Edition account of the NFT.',
+        '8. `[]` Token program.',
+        '9. `[]` Associated Token program.',
+        '10. `[]` Token Metadata program.',
+        '11. `[]` System program.',
+        '12. `[]` Rent sysvar.',
+        '13. `[writable, optional]` Metadata account (pNFT).',
+        '14. `[writable, optional]` Seller token record (pNFT).',
+        '15. `[optional]` Auth rules account (pNFT).',
+        '16. `[optional]` Instructions sysvar (pNFT).',
+        '17. `[optional]` Auth rules program (pNFT).',
+      ],
+      accounts: [
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the NFT.'],
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'edition',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL PNFT ACCOUNTS',
+            '/// CHECK: Safe due to token metadata program check',
+          ],
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6060;
-      name: 'InvalidVersion';
-      msg: 'Invalid version';
+      name: 'cancelAddCoreAssetRequest',
+      docs: [
+        'Cancel a request to add a core asset to the gumball machine.',
+        "Thaws and revokes delegate from the seller's asset and closes the request account.",
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
+        '3. `[signer, writable]` Seller of the asset.',
+        '4. `[writable]` Asset account.',
+        '5. `[writable, optional]` Collection account if asset is part of one.',
+        '6. `[]` MPL Core program.',
+        '7. `[]` System program.',
+      ],
+      accounts: [
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: true,
+          docs: ['Seller of the asset.'],
+        },
+        {
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Core asset's collection if it's part of one."],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6061;
-      name: 'InvalidOracleSigner';
-      msg: 'Invalid oracle signer';
+      name: 'closeExpiredAddItemRequest',
+      docs: [
+        'Close an expired request to add an item to the gumball machine. Can be called by anyone.',
+        "Thaws and revokes delegate from the seller's item, closes the request account and",
+        'refunds rent to the seller.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
+        '3. `[signer, writable]` Payer.',
+        '4. `[writable]` Seller of the item.',
+        '5. `[writable]` Asset account (or mint for NFTs).',
+        '6. `[]` System program.',
+        '7. `[writable, optional]` Collection account (Core asset).',
+        '8. `[optional]` MPL Core program (Core asset).',
+        "9. `[writable, optional]` Seller's token account (NFT).",
+        "10. `[writable, optional]` Authority PDA's token account (NFT).",
+        '11. `[optional]` Edition account (NFT).',
+        '12. `[optional]` Token program (NFT).',
+        '13. `[optional]` Associated Token program (NFT).',
+        '14. `[optional]` Token Metadata program (NFT).',
+        '15. `[writable, optional]` Metadata account (pNFT).',
+        '16. `[writable, optional]` Seller token record (pNFT).',
+        '17. `[optional]` Auth rules account (pNFT).',
+        '18. `[optional]` Instructions sysvar (pNFT).',
+        '19. `[optional]` Auth rules program (pNFT).',
+      ],
+      accounts: [
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+          docs: [
+            'Payer of the transaction, anyone can close an expired request.',
+          ],
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+          docs: ['Asset (or mint for NFTs) of the request.'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if closing a Core asset',
+            "Core asset's collection if it's part of one.",
+          ],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if closing a NFT or PNFT',
+          ],
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'edition',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if closing a PNFT'],
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6062;
-      name: 'InvalidPayer';
-      msg: 'Invalid payer';
+      name: 'approveAddItem',
+      docs: [
+        'Approve adding an item to the gumball machine.',
+        "Moves the item from the request to the gumball machine's config lines.",
+        'The authority can optionally set the proceeds weight of the item (defaults to 1).',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer, writable]` Authority of the gumball machine.',
+        '4. `[writable]` Seller account (receiver of closed request account rent).',
+        '5. `[]` Asset/Mint account (checked via add_item_request constraint).',
+        '6. `[]` System program.',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: ['Authority of the gumball machine.'],
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asset',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'weight',
+          type: {
+            option: 'u32',
+          },
+        },
+      ],
     },
     {
-      code: 6063;
-      name: 'NotImplemented';
-      msg: 'Not implemented';
+      name: 'rejectAddItem',
+      docs: [
+        'Reject a request to add an item to the gumball machine.',
+        'Thaws and revokes the NFT or Core asset and closes the request account (rent to seller).',
+        '',
+        '# Accounts',
+        '',
+        '0. `[]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '4. `[signer, writable]` Authority of the gumball machine.',
+        '5. `[writable]` Seller account (receiver of closed request account rent).',
+        '6. `[writable]` Asset/Mint account (checked via add_item_request constraint).',
+        '7. `[]` System program.',
+        '8. `[writable, optional]` Collection account (Core asset).',
+        '9. `[optional]` MPL Core program (Core asset).',
+        "10. `[writable, optional]` Seller's token account (NFT).",
+        "11. `[writable, optional]` Authority PDA's token account (NFT).",
+        '12. `[optional]` Edition account (NFT).',
+        '13. `[optional]` Token program (NFT).',
+        '14. `[optional]` Associated Token program (NFT).',
+        '15. `[optional]` Token Metadata program (NFT).',
+        '16. `[writable, optional]` Metadata account (pNFT).',
+        '17. `[writable, optional]` Seller token record (pNFT).',
+        '18. `[optional]` Auth rules account (pNFT).',
+        '19. `[optional]` Instructions sysvar (pNFT).',
+        '20. `[optional]` Auth rules program (pNFT).',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: false,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'addItemRequest',
+          isMut: true,
+          isSigner: false,
+          docs: ['Add item request account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: ['Authority of the gumball machine.'],
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+          docs: ['Asset (or mint for NFTs) of the request.'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if rejecting a Core asset',
+            "Core asset's collection if it's part of one.",
+          ],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if rejecting a NFT or PNFT',
+          ],
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'edition',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if rejecting a PNFT'],
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'reason',
+          type: {
+            option: 'u8',
+          },
+        },
+      ],
     },
     {
-      code: 6064;
-      name: 'BuyBackCutoffReached';
-      msg: 'Buy back cutoff reached';
+      name: 'removeNft',
+      docs: [
+        'Remove legacy NFT from the gumball machine.',
+        "Thaws and revokes delegate from the seller's NFT and removes it from the config lines.",
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the NFT).',
+        '5. `[]` Mint account of the NFT.',
+        "6. `[writable]` Seller's token account for the NFT.",
+        "7. `[writable]` Authority PDA's token account.",
+        '8. `[]` Edition account of the NFT.',
+        '9. `[]` Token program.',
+        '10. `[]` Associated Token program.',
+        '11. `[]` Token Metadata program.',
+        '12. `[]` System program.',
+        '13. `[]` Rent sysvar.',
+        '14. `[writable, optional]` Metadata account (pNFT).',
+        '15. `[writable, optional]` Seller token record (pNFT).',
+        '16. `[optional]` Auth rules account (pNFT).',
+        '17. `[optional]` Instructions sysvar (pNFT).',
+        '18. `[optional]` Auth rules program (pNFT).',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+          ],
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'edition',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL PNFT ACCOUNTS',
+            '/// CHECK: Safe due to token metadata program check',
+          ],
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
+      ],
     },
     {
-      code: 6065;
-      name: 'NotASoloGumball';
-      msg: 'Not a solo gumball';
+      name: 'removeNftsSpan',
+      docs: [
+        'Remove a span of legacy NFTs from the gumball machine.',
+        'Thaws and returns each NFT to its seller and compacts the config lines.',
+        'The signer can be the Gumball Machine authority or the seller of every item in the span.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '2. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
+        '3. `[]` Token program.',
+        '4. `[]` Associated Token program.',
+        '5. `[]` Token Metadata program.',
+        '6. `[]` System program.',
+        '7. `[]` Rent sysvar.',
+        '8. `[optional]` Auth rules account (pNFT).',
+        '9. `[optional]` Instructions sysvar (pNFT).',
+        '10. `[optional]` Auth rules program (pNFT).',
+        'Remaining accounts: For each item in the span, the seller (writable), seller history (writable),',
+        "mint, seller's token account (writable), authority PDA's token account (writable), edition,",
+        'metadata (writable) and seller token record (writable, pNFT only)',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: [
+            'Authority allowed to remove the nfts (must be the gumball machine auth or the seller of every nft)',
+          ],
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS'],
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'startIndex',
+          type: 'u32',
+        },
+        {
+          name: 'endIndex',
+          type: 'u32',
+        },
+      ],
     },
     {
-      code: 6066;
-      name: 'ItemNotClaimed';
-      msg: 'Item not claimed';
+      name: 'removeCoreAsset',
+      docs: [
+        'Remove Core asset from the gumball machine.',
+        "Thaws and revokes delegate from the seller's asset and removes it from the config lines.",
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the asset).',
+        '5. `[writable]` Asset account.',
+        '6. `[writable, optional]` Collection account if asset is part of one.',
+        '7. `[]` MPL Core program.',
+        '8. `[]` System program.',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: ['Seller of the asset.'],
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Core asset's collection if it's part of one."],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
+      ],
     },
     {
-      code: 6067;
-      name: 'ItemNotSettled';
-      msg: 'Item not settled';
+      name: 'removeCoreAssetsSpan',
+      docs: [
+        'Remove a span of Core assets from the gumball machine.',
+        'Thaws and returns each asset to its seller and compacts the config lines.',
+        'The signer can be the Gumball Machine authority or the seller of every item in the span.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '2. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '3. `[]` MPL Core program.',
+        '4. `[]` System program.',
+        'Remaining accounts: For each item in the span, the seller (writable), seller history (writable),',
+        'asset (writable) and collection (writable, MPL Core program if the asset has none)',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Authority allowed to remove the assets (must be the gumball machine auth or the seller of every asset)',
+          ],
+        },
+        {
+          name: 'mplCoreProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'startIndex',
+          type: 'u32',
+        },
+        {
+          name: 'endIndex',
+          type: 'u32',
+        },
+      ],
     },
     {
-      code: 6068;
-      name: 'MissingItemIndex';
-      msg: 'Missing item index';
+      name: 'removeCompressedNft',
+      docs: [
+        'Remove compressed nft from the gumball machine.',
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account.',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the compressed nft).',
+        '5. `[writable]` Merkle tree account.',
+        '6. `[]` Tree config account.',
+        '7. `[]` Bubblegum program.',
+        '8. `[]` SPL Account Compression program.',
+        '9. `[]` SPL Noop program.',
+        '10. `[]` System program.',
+        'Remaining accounts: Merkle proof nodes',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+          ],
+        },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'merkleTree',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'treeConfig',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'bubblegumProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'compressionProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'logWrapper',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
+        {
+          name: 'compressedNft',
+          type: {
+            defined: 'CompressedNftArgs',
+          },
+        },
+      ],
     },
-  ];
-};
-
-export const IDL: MallowGumball = {
-  version: '1.0.0',
-  name: 'mallow_gumball',
-  instructions: [
     {
-      name: 'initialize',
+      name: 'removeTokens',
       docs: [
-        'Initialize the gumball machine account with the specified data.',
+        'Remove fungible tokens from the gumball machine.',
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account (must be pre-allocated but zero content)',
-        '1. `[]` Gumball Machine authority',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Payer',
-        '4. `[]` System program',
+        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the tokens).',
+        '5. `[]` Mint account',
+        "6. `[writable]` Seller's token account",
+        "7. `[writable]` Gumball machine's token account",
+        '8. `[]` Token program (SPL Token or Token-2022)',
+        '9. `[]` Associated Token program',
+        '10. `[]` System program',
+        '11. `[]` Rent sysvar',
+        'DEPRECATED: Use remove_tokens_span instead',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
           docs: [
-            'Gumball Machine account. The account space must be allocated to allow accounts larger',
-            'than 10kb.',
-            '',
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
           ],
         },
         {
-          name: 'authority',
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
           isMut: false,
           isSigner: false,
-          docs: [
-            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
-            '',
-          ],
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'],
         },
         {
-          name: 'authorityPda',
+          name: 'tokenAccount',
           isMut: true,
           isSigner: false,
         },
         {
-          name: 'payer',
+          name: 'authorityPdaTokenAccount',
           isMut: true,
-          isSigner: true,
-          docs: ['Payer of the transaction.'],
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
         },
         {
           name: 'systemProgram',
           isMut: false,
           isSigner: false,
         },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
-          name: 'args',
-          type: {
-            defined: 'InitializeArgs',
-          },
+          name: 'indices',
+          type: 'bytes',
+        },
+        {
+          name: 'amount',
+          type: 'u64',
         },
       ],
     },
     {
-      name: 'updateSettings',
+      name: 'removeTokensSpan',
       docs: [
-        'Updates gumball machine settings.',
+        'Remove fungible tokens from the gumball machine.',
+        'The signer can be the Gumball Machine authority or the seller of the specific item.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[signer, writable]` Gumball Machine authority',
+        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account (owner of the tokens).',
+        '5. `[]` Mint account',
+        "6. `[writable]` Seller's token account",
+        "7. `[writable]` Gumball machine's token account",
+        '8. `[]` Token program (SPL Token or Token-2022)',
+        '9. `[]` Associated Token program',
+        '10. `[]` System program',
+        '11. `[]` Rent sysvar',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball machine account.'],
+          docs: ['Gumball Machine account.'],
+        },
+        {
+          name: 'sellerHistory',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller history account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
         },
         {
           name: 'authority',
           isMut: true,
           isSigner: true,
           docs: [
-            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
           ],
         },
+        {
+          name: 'seller',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'],
+        },
+        {
+          name: 'tokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rent',
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
-          name: 'args',
-          type: {
-            defined: 'UpdateArgs',
-          },
+          name: 'amount',
+          type: 'u64',
+        },
+        {
+          name: 'startIndex',
+          type: 'u32',
+        },
+        {
+          name: 'endIndex',
+          type: 'u32',
         },
       ],
     },
     {
-      name: 'addNft',
+      name: 'removeSolPrize',
       docs: [
-        'Add legacy NFTs to the gumball machine.',
+        'Remove native SOL prizes from the gumball machine, returning their lamports to the seller.',
+        'The signer can be the Gumball Machine authority or the seller of the prizes.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
         '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Seller',
-        '4. `[]` Mint account',
-        '5. `[writable]` Token account',
-        '6. `[writable]` Metadata account',
-        '7. `[]` Edition account',
-        '8. `[]` Token program',
-        '9. `[]` Token Metadata program',
-        '10. `[]` System program',
-        '11. `[writable, optional]` Seller token record (pNFT)',
-        '12. `[optional]` Auth rules account (pNFT)',
-        '13. `[optional]` Instructions sysvar (pNFT)',
-        '14. `[optional]` Auth rules program (pNFT)',
+        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
+        '4. `[writable]` Seller account',
+        '5. `[]` System program',
       ],
       accounts: [
         {
@@ -4114,434 +10081,498 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'seller',
-          isMut: true,
+          name: 'authority',
+          isMut: false,
           isSigner: true,
-          docs: ['Seller of the nft'],
+          docs: [
+            'Authority allowed to remove the prizes (must be the gumball machine auth or the seller of the prizes)',
+          ],
         },
         {
-          name: 'mint',
-          isMut: false,
+          name: 'seller',
+          isMut: true,
           isSigner: false,
         },
         {
-          name: 'tokenAccount',
-          isMut: true,
+          name: 'systemProgram',
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: 'metadata',
-          isMut: true,
-          isSigner: false,
+          name: 'amount',
+          type: 'u64',
         },
         {
-          name: 'edition',
-          isMut: false,
-          isSigner: false,
+          name: 'startIndex',
+          type: 'u32',
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'endIndex',
+          type: 'u32',
         },
+      ],
+    },
+    {
+      name: 'startSale',
+      docs: [
+        'Allows minting to begin.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
+      ],
+      accounts: [
         {
-          name: 'tokenMetadataProgram',
-          isMut: false,
+          name: 'gumballMachine',
+          isMut: true,
           isSigner: false,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'systemProgram',
+          name: 'authority',
           isMut: false,
-          isSigner: false,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This can be the mint authority or the authority.',
+          ],
         },
+      ],
+      args: [],
+    },
+    {
+      name: 'pauseSale',
+      docs: [
+        'Pauses a live sale. Items cannot be drawn, sold back or added while paused, but',
+        'drawn items can still be claimed.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
+      ],
+      accounts: [
         {
-          name: 'sellerTokenRecord',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['OPTIONAL PNFT ACCOUNTS'],
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'authRules',
+          name: 'authority',
           isMut: false,
-          isSigner: false,
-          isOptional: true,
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This can be the mint authority or the authority.',
+          ],
         },
+      ],
+      args: [],
+    },
+    {
+      name: 'resumeSale',
+      docs: [
+        'Resumes a paused sale.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
+      ],
+      accounts: [
         {
-          name: 'instructions',
-          isMut: false,
+          name: 'gumballMachine',
+          isMut: true,
           isSigner: false,
-          isOptional: true,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'authRulesProgram',
+          name: 'authority',
           isMut: false,
-          isSigner: false,
-          isOptional: true,
-        },
-      ],
-      args: [
-        {
-          name: 'args',
-          type: {
-            defined: 'AddItemArgs',
-          },
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This can be the mint authority or the authority.',
+          ],
         },
       ],
+      args: [],
     },
     {
-      name: 'addCoreAsset',
+      name: 'endSale',
       docs: [
-        'Add Core assets to the gumball machine.',
+        'Disables minting and allows sales to be settled.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Seller',
-        '4. `[writable]` Asset account',
-        '5. `[writable, optional]` Collection account',
-        '6. `[]` MPL Core program',
-        '7. `[]` System program',
+        '1. `[signer, writable]` Gumball Machine authority',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'sellerHistory',
+          name: 'authority',
           isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
+          isSigner: true,
+          docs: [
+            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
+          ],
         },
+      ],
+      args: [],
+    },
+    {
+      name: 'draw',
+      docs: [
+        'Draw for a random item from the gumball machine.',
+        'Only the gumball machine mint authority is allowed to draw.',
+        'The item is reserved for the buyer and selected once the draw is revealed.',
+        '',
+        'The pending draw account is derived from the number of draws made on the gumball',
+        'machine (`draw_number`, little-endian u64), so a buyer can have several draws pending.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])',
+        '2. `[signer]` Gumball Machine mint authority',
+        '3. `[signer, writable]` Payer',
+        '4. `[]` Buyer account',
+        '5. `[]` System program',
+        '6. `[optional]` Randomness account (required when a randomness provider is set)',
+      ],
+      accounts: [
         {
-          name: 'authorityPda',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'seller',
+          name: 'pendingDraw',
           isMut: true,
-          isSigner: true,
-          docs: ['Seller of the asset.'],
+          isSigner: false,
+          docs: ['Pending draw account, closed once the draw is revealed.'],
         },
         {
-          name: 'asset',
-          isMut: true,
-          isSigner: false,
+          name: 'mintAuthority',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ],
         },
         {
-          name: 'collection',
+          name: 'payer',
           isMut: true,
-          isSigner: false,
-          isOptional: true,
-          docs: ["Core asset's collection if it's part of one."],
+          isSigner: true,
+          docs: ['Payer for the transaction and account allocation (rent).'],
         },
         {
-          name: 'mplCoreProgram',
+          name: 'buyer',
           isMut: false,
           isSigner: false,
+          docs: ['NFT account owner.', ''],
         },
         {
           name: 'systemProgram',
           isMut: false,
           isSigner: false,
+          docs: ['System program.'],
         },
-      ],
-      args: [
         {
-          name: 'args',
-          type: {
-            defined: 'AddItemArgs',
-          },
+          name: 'randomness',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'Randomness account of the provider (required when the gumball machine uses a',
+            'randomness provider).',
+            '',
+          ],
         },
       ],
+      args: [],
     },
     {
-      name: 'addTokens',
+      name: 'drawMany',
       docs: [
-        'Add fungible tokens to the gumball machine.',
+        'Draw multiple random items from the gumball machine for the same buyer.',
+        'Only the gumball machine mint authority is allowed to draw.',
+        'The items are reserved for the buyer and selected once the draw is revealed.',
         '',
         '# Accounts',
         '',
         '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Seller',
-        '4. `[]` Mint account',
-        "5. `[writable]` Seller's token account",
-        "6. `[writable]` Gumball machine's token account",
-        '7. `[]` Token program',
-        '8. `[]` Associated Token program',
-        '9. `[]` System program',
-        '10. `[]` Rent sysvar',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])',
+        '2. `[signer]` Gumball Machine mint authority',
+        '3. `[signer, writable]` Payer',
+        '4. `[]` Buyer account',
+        '5. `[]` System program',
+        '6. `[optional]` Randomness account (required when a randomness provider is set)',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
-        },
-        {
-          name: 'sellerHistory',
-          isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'authorityPda',
+          name: 'pendingDraw',
           isMut: true,
           isSigner: false,
+          docs: ['Pending draw account, closed once the draw is revealed.'],
         },
         {
-          name: 'seller',
-          isMut: true,
-          isSigner: true,
-          docs: ['Seller of the tokens'],
-        },
-        {
-          name: 'mint',
+          name: 'mintAuthority',
           isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'tokenAccount',
-          isMut: true,
-          isSigner: false,
+          isSigner: true,
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ],
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'payer',
           isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          isSigner: true,
+          docs: ['Payer for the transaction and account allocation (rent).'],
         },
         {
-          name: 'associatedTokenProgram',
+          name: 'buyer',
           isMut: false,
           isSigner: false,
+          docs: ['NFT account owner.', ''],
         },
         {
           name: 'systemProgram',
           isMut: false,
           isSigner: false,
+          docs: ['System program.'],
         },
         {
-          name: 'rent',
+          name: 'randomness',
           isMut: false,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'Randomness account of the provider (required when the gumball machine uses a',
+            'randomness provider).',
+            '',
+          ],
         },
       ],
       args: [
-        {
-          name: 'amount',
-          type: 'u64',
-        },
         {
           name: 'quantity',
           type: 'u16',
         },
-        {
-          name: 'args',
-          type: {
-            defined: 'AddItemArgs',
-          },
-        },
       ],
     },
     {
-      name: 'requestAddNft',
+      name: 'buySpecific',
       docs: [
-        'Request to add a NFT to the gumball machine.',
-        "Freezes the seller's NFT and creates a request account.",
+        'Assigns a specific available item to the buyer, skipping the random draw. Only',
+        'the mint authority (gumball guard) can buy specific items, where the payment is',
+        'added to the total revenue. Items cannot be bought while draws are pending reveal.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]).',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '4. `[signer, writable]` Seller of the nft.',
-        '5. `[]` Mint account of the NFT.',
-        "6. `[writable]` Seller's token account for the NFT.",
-        '7. `[writable]` Metadata account of the NFT.',
-        '8. `[]` Edition account of the NFT.',
-        '9. `[]` Token program.',
-        '10. `[]` Token Metadata program.',
-        '11. `[]` System program.',
-        '12. `[writable, optional]` Seller token record (pNFT).',
-        '13. `[optional]` Auth rules account (pNFT).',
-        '14. `[optional]` Instructions sysvar (pNFT).',
-        '15. `[optional]` Auth rules program (pNFT).',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine mint authority',
+        '2. `[]` Buyer account',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
-        },
-        {
-          name: 'sellerHistory',
-          isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
-        },
-        {
-          name: 'addItemRequest',
-          isMut: true,
-          isSigner: false,
-          docs: ['Add item request account.'],
-        },
-        {
-          name: 'authorityPda',
-          isMut: true,
-          isSigner: false,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'seller',
-          isMut: true,
+          name: 'mintAuthority',
+          isMut: false,
           isSigner: true,
-          docs: ['Seller of the nft'],
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ],
         },
         {
-          name: 'mint',
+          name: 'buyer',
           isMut: false,
           isSigner: false,
+          docs: ['Buyer of the item.', ''],
         },
         {
-          name: 'tokenAccount',
-          isMut: true,
+          name: 'eventAuthority',
+          isMut: false,
           isSigner: false,
         },
         {
-          name: 'metadata',
-          isMut: true,
+          name: 'program',
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: 'edition',
-          isMut: false,
-          isSigner: false,
+          name: 'index',
+          type: 'u32',
         },
+      ],
+    },
+    {
+      name: 'reroll',
+      docs: [
+        'Gives back an unclaimed item drawn by the buyer and reserves a new item in its',
+        'place, to be revealed like a regular draw. Only the mint authority (gumball',
+        'guard) can reroll, so a fee can be charged through a guard group. Items cannot be',
+        'rerolled while draws are pending reveal.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])',
+        '2. `[signer]` Gumball Machine mint authority',
+        '3. `[signer, writable]` Payer',
+        '4. `[signer]` Buyer of the rerolled item',
+        '5. `[]` System program',
+        '6. `[optional]` Randomness account (required when a randomness provider is set)',
+      ],
+      accounts: [
         {
-          name: 'tokenProgram',
-          isMut: false,
+          name: 'gumballMachine',
+          isMut: true,
           isSigner: false,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'tokenMetadataProgram',
-          isMut: false,
+          name: 'pendingDraw',
+          isMut: true,
           isSigner: false,
+          docs: ['Pending draw account, closed once the draw is revealed.'],
         },
         {
-          name: 'systemProgram',
+          name: 'mintAuthority',
           isMut: false,
-          isSigner: false,
+          isSigner: true,
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ],
         },
         {
-          name: 'sellerTokenRecord',
+          name: 'payer',
           isMut: true,
-          isSigner: false,
-          isOptional: true,
-          docs: ['OPTIONAL PNFT ACCOUNTS'],
+          isSigner: true,
+          docs: ['Payer for the transaction and account allocation (rent).'],
         },
         {
-          name: 'authRules',
+          name: 'buyer',
           isMut: false,
-          isSigner: false,
-          isOptional: true,
+          isSigner: true,
+          docs: ['Buyer of the item being rerolled.'],
         },
         {
-          name: 'instructions',
+          name: 'systemProgram',
           isMut: false,
           isSigner: false,
-          isOptional: true,
+          docs: ['System program.'],
         },
         {
-          name: 'authRulesProgram',
+          name: 'randomness',
           isMut: false,
           isSigner: false,
           isOptional: true,
+          docs: [
+            'Randomness account of the provider (required when the gumball machine uses a',
+            'randomness provider).',
+            '',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
         },
       ],
-      args: [],
     },
     {
-      name: 'requestAddCoreAsset',
+      name: 'revealDraw',
       docs: [
-        'Request to add a core asset to the gumball machine.',
-        "Freezes the seller's asset and creates a request account.",
+        'Reveals a pending draw using the hash of its target slot and assigns the',
+        'reserved items to the buyer. Anyone can reveal a draw, but draws are revealed in',
+        'the order they were made.',
+        '',
+        'The seed of the draw is the slot hash (or provider value) hashed with the pending',
+        'draw address. Once the target slot is no longer in the SlotHashes sysvar (~512 slots),',
+        'the draw is moved to a new target slot and revealed again later.',
+        '',
+        'When instant delivery is enabled, the drawn items are also transferred to the buyer,',
+        'so the delivery accounts of every drawn item must be passed as remaining accounts.',
+        'When receipts are enabled instead, a receipt is minted to the buyer for every drawn',
+        'item, so the receipt accounts must be passed as remaining accounts.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]).',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '4. `[signer, writable]` Seller of the asset.',
-        '5. `[writable]` Asset account.',
-        '6. `[writable, optional]` Collection account if asset is part of one.',
-        '7. `[]` MPL Core program.',
-        '8. `[]` System program.',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number]). Will be closed.',
+        '2. `[writable]` Payer of the pending draw account (receiver of closed account rent)',
+        '3. `[]` SlotHashes sysvar cluster data',
+        '4. `[optional]` Randomness account (required when the draw uses a randomness provider)',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
-        },
-        {
-          name: 'sellerHistory',
-          isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'addItemRequest',
+          name: 'pendingDraw',
           isMut: true,
           isSigner: false,
-          docs: ['Add item request account.'],
+          docs: ['Pending draw account.'],
         },
         {
-          name: 'authorityPda',
+          name: 'payer',
           isMut: true,
           isSigner: false,
+          docs: [
+            'Payer of the pending draw account (receiver of the closed account rent).',
+            '',
+          ],
         },
         {
-          name: 'seller',
-          isMut: true,
-          isSigner: true,
-          docs: ['Seller of the asset.'],
-        },
-        {
-          name: 'asset',
-          isMut: true,
+          name: 'recentSlothashes',
+          isMut: false,
           isSigner: false,
+          docs: ['SlotHashes sysvar cluster data.', ''],
         },
         {
-          name: 'collection',
-          isMut: true,
+          name: 'randomness',
+          isMut: false,
           isSigner: false,
           isOptional: true,
-          docs: ["Core asset's collection if it's part of one."],
+          docs: [
+            'Randomness account of the provider (required when the draw uses a randomness',
+            'provider).',
+            '',
+          ],
         },
         {
-          name: 'mplCoreProgram',
+          name: 'eventAuthority',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'systemProgram',
+          name: 'program',
           isMut: false,
           isSigner: false,
         },
@@ -4549,167 +10580,223 @@ export const IDL: MallowGumball = {
       args: [],
     },
     {
-      name: 'cancelAddNftRequest',
+      name: 'cancelPendingDraw',
       docs: [
-        'Cancel a request to add a NFT to the gumball machine.',
-        "Thaws and revokes delegate from the seller's NFT and closes the request account.",
+        'Cancels the next pending draw of an abandoned gumball machine once the force close',
+        'grace period has passed, so that its items can be settled and the machine closed.',
+        'Anyone can cancel a draw, but only draws made with a randomness provider whose',
+        'request was replaced or never fulfilled: those can no longer be revealed.',
+        '',
+        'The reserved items are returned to the pool and the price of the draw (net of the',
+        'marketplace fee and of the share already paid out to settled items) is refunded to',
+        'the buyer.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
-        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", mint]). Will be closed.',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
-        '3. `[signer, writable]` Seller of the NFT.',
-        '4. `[]` Mint account of the NFT.',
-        "5. `[writable]` Seller's token account for the NFT.",
-        "6. `[writable]` Authority PDA's token account.",
-        '7. `[]` Edition account of the NFT.',
-        '8. `[]` Token program.',
-        '9. `[]` Associated Token program.',
-        '10. `[]` Token Metadata program.',
-        '11. `[]` System program.',
-        '12. `[]` Rent sysvar.',
-        '13. `[writable, optional]` Metadata account (pNFT).',
-        '14. `[writable, optional]` Seller token record (pNFT).',
-        '15. `[optional]` Auth rules account (pNFT).',
-        '16. `[optional]` Instructions sysvar (pNFT).',
-        '17. `[optional]` Auth rules program (pNFT).',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number]). Will be closed.',
+        '2. `[writable]` Payer of the pending draw account (receiver of closed account rent)',
+        '3. `[writable]` Buyer of the draw (receiver of the refund)',
+        "4. `[signer, writable]` Payer of the buyer's payment account",
+        '5. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '6. `[writable, optional]` Authority PDA payment account',
+        '7. `[writable, optional]` Buyer payment account',
+        '8. `[optional]` Payment mint',
+        '9. `[]` Randomness account of the draw',
+        '10. `[]` Token program',
+        '11. `[]` Associated Token program',
+        '12. `[]` System program',
       ],
       accounts: [
         {
-          name: 'sellerHistory',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Seller history account.'],
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'addItemRequest',
+          name: 'pendingDraw',
           isMut: true,
           isSigner: false,
-          docs: ['Add item request account.'],
+          docs: ['Pending draw account.'],
         },
         {
-          name: 'authorityPda',
+          name: 'payer',
           isMut: true,
           isSigner: false,
+          docs: [
+            'Payer of the pending draw account (receiver of the closed account rent).',
+            '',
+          ],
         },
         {
-          name: 'seller',
+          name: 'buyer',
           isMut: true,
-          isSigner: true,
-          docs: ['Seller of the NFT.'],
-        },
-        {
-          name: 'mint',
-          isMut: false,
           isSigner: false,
+          docs: ['Buyer of the draw (receiver of the refund).', ''],
         },
         {
-          name: 'tokenAccount',
+          name: 'feePayer',
           isMut: true,
-          isSigner: false,
+          isSigner: true,
+          docs: [
+            "Payer of the buyer's payment account if it needs to be created.",
+          ],
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'authorityPda',
           isMut: true,
           isSigner: false,
         },
         {
-          name: 'edition',
-          isMut: false,
+          name: 'authorityPdaPaymentAccount',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for authority pda if using token payment'],
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
+          name: 'buyerPaymentAccount',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ["Buyer's token account if using token payment"],
         },
         {
-          name: 'associatedTokenProgram',
+          name: 'paymentMint',
           isMut: false,
           isSigner: false,
+          isOptional: true,
+          docs: ['Payment mint if using non-native payment token'],
         },
         {
-          name: 'tokenMetadataProgram',
+          name: 'randomness',
           isMut: false,
           isSigner: false,
+          docs: ['Randomness account of the provider used by the draw.', ''],
         },
         {
-          name: 'systemProgram',
+          name: 'tokenProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'rent',
+          name: 'associatedTokenProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'metadata',
-          isMut: true,
+          name: 'systemProgram',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
-          docs: [
-            'OPTIONAL PNFT ACCOUNTS',
-            '/// CHECK: Safe due to token metadata program check',
-          ],
         },
+      ],
+      args: [],
+    },
+    {
+      name: 'incrementTotalRevenue',
+      docs: [
+        'Increments total revenue earned by the gumball machine.',
+        '',
+        'Only the gumball machine mint authority is allowed to increment revenue. This is',
+        "required as token transfers don't occur in this program, but total is needed",
+        'when settling.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account',
+        '1. `[signer]` Gumball Machine mint authority',
+      ],
+      accounts: [
         {
-          name: 'sellerTokenRecord',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-        },
-        {
-          name: 'authRules',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'instructions',
+          name: 'mintAuthority',
           isMut: false,
-          isSigner: false,
-          isOptional: true,
+          isSigner: true,
+          docs: [
+            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+          ],
         },
+      ],
+      args: [
         {
-          name: 'authRulesProgram',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'revenue',
+          type: 'u64',
         },
       ],
-      args: [],
     },
     {
-      name: 'cancelAddCoreAssetRequest',
+      name: 'sellItem',
       docs: [
-        'Cancel a request to add a core asset to the gumball machine.',
-        "Thaws and revokes delegate from the seller's asset and closes the request account.",
+        'Sell an item back to the gumball machine using buy back funds.',
+        'The payer must be the seller or the oracle_signer.',
+        'Buying back to the gumball machine is currently not supported, but will be in the future.',
+        'If buying back to the authority, the item is marked as claimed and transferred to the buyer (authority).',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).',
-        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).',
-        '3. `[signer, writable]` Seller of the asset.',
-        '4. `[writable]` Asset account.',
-        '5. `[writable, optional]` Collection account if asset is part of one.',
-        '6. `[]` MPL Core program.',
-        '7. `[]` System program.',
+        '0. `[signer, writable]` Payer (must be seller or oracle_signer)',
+        '1. `[signer]` Oracle signer (must match buy_back_config)',
+        '2. `[writable]` Gumball Machine account',
+        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '4. `[writable]` Mint account of the item (or Asset account for Core)',
+        '5. `[writable]` Seller account',
+        '6. `[writable]` Buyer account (must be gumball machine or authority)',
+        '7. `[]` System program',
+        '8. `[]` Token program',
+        '9. `[]` Associated Token program',
+        '10. `[]` Rent sysvar',
+        '11. `[writable, optional]` Fee account (if fee configured)',
+        '12. `[writable, optional]` Fee payment account (if fee configured)',
+        '13. `[writable, optional]` Payment mint (if not native SOL)',
+        '14. `[writable, optional]` Seller payment account (if not native SOL)',
+        '15. `[writable, optional]` Authority PDA payment account (if not native SOL)',
+        '16. `[writable, optional]` Collection account (for Core asset)',
+        '17. `[optional]` MPL Core program (for Core asset)',
+        '18. `[writable, optional]` Authority PDA token account (for NFT/Fungible)',
+        '19. `[writable, optional]` Seller token account (for NFT/Fungible)',
+        '20. `[writable, optional]` Buyer token account (for NFT/Fungible)',
+        '21. `[writable, optional]` Metadata account (for NFT/PNFT)',
+        '22. `[writable, optional]` Edition account (for NFT/PNFT)',
+        '23. `[optional]` Token Metadata program (for NFT/PNFT)',
+        '24. `[writable, optional]` Authority PDA token record (for pNFT)',
+        '25. `[writable, optional]` Buyer token record (for pNFT)',
+        '26. `[optional]` Auth rules account (for pNFT)',
+        '27. `[optional]` Instructions sysvar (for pNFT)',
+        '28. `[optional]` Auth rules program (for pNFT)',
+        '29. `[optional]` Token-2022 program (for Token-2022 tokens)',
+        '30. `[writable, optional]` Merkle tree account (for compressed NFT)',
+        '31. `[optional]` Tree config account (for compressed NFT)',
+        '32. `[optional]` Bubblegum program (for compressed NFT)',
+        '33. `[optional]` SPL Account Compression program (for compressed NFT)',
+        '34. `[optional]` SPL Noop program (for compressed NFT)',
+        'Remaining accounts: Merkle proof nodes (for compressed NFT)',
       ],
       accounts: [
         {
-          name: 'sellerHistory',
+          name: 'payer',
           isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
+          isSigner: true,
+          docs: [
+            'Must be the oracle signer or seller (oracle signer can sell on behalf of the seller to allow auto-buy back)',
+          ],
         },
         {
-          name: 'addItemRequest',
+          name: 'oracleSigner',
+          isMut: false,
+          isSigner: true,
+          docs: ['Oracle signer'],
+        },
+        {
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Add item request account.'],
+          docs: ['Gumball machine account.'],
         },
         {
           name: 'authorityPda',
@@ -4717,211 +10804,154 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'seller',
+          name: 'mint',
           isMut: true,
-          isSigner: true,
-          docs: ['Seller of the asset.'],
+          isSigner: false,
+          docs: ['Mint of the item (or asset for Core assets)'],
         },
         {
-          name: 'asset',
+          name: 'seller',
           isMut: true,
           isSigner: false,
+          docs: ['Seller of the item'],
         },
         {
-          name: 'collection',
+          name: 'buyer',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ["Core asset's collection if it's part of one."],
+          docs: ['Buyer of the item'],
         },
         {
-          name: 'mplCoreProgram',
+          name: 'systemProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'systemProgram',
+          name: 'tokenProgram',
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: 'approveAddItem',
-      docs: [
-        'Approve adding an item to the gumball machine.',
-        "Moves the item from the request to the gumball machine's config lines.",
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '3. `[signer, writable]` Authority of the gumball machine.',
-        '4. `[writable]` Seller account (receiver of closed request account rent).',
-        '5. `[]` Asset/Mint account (checked via add_item_request constraint).',
-        '6. `[]` System program.',
-      ],
-      accounts: [
         {
-          name: 'gumballMachine',
-          isMut: true,
+          name: 'associatedTokenProgram',
+          isMut: false,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
         },
         {
-          name: 'addItemRequest',
-          isMut: true,
+          name: 'rent',
+          isMut: false,
           isSigner: false,
-          docs: ['Add item request account.'],
         },
         {
-          name: 'authorityPda',
+          name: 'feeAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL FEE ACCOUNTS - only required if there is a fee config on the gumball machine',
+            'Marketplace fee account',
+          ],
         },
         {
-          name: 'authority',
-          isMut: true,
-          isSigner: true,
-          docs: ['Authority of the gumball machine.'],
-        },
-        {
-          name: 'seller',
+          name: 'feePaymentAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['Marketplace fee payment account'],
         },
         {
-          name: 'asset',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
+          name: 'paymentMint',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL SPL TOKEN ACCOUNTS - only required if selling for SPL token',
+            'Mint of payment token',
+          ],
         },
-      ],
-      args: [],
-    },
-    {
-      name: 'removeNft',
-      docs: [
-        'Remove legacy NFT from the gumball machine.',
-        "Thaws and revokes delegate from the seller's NFT and removes it from the config lines.",
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the NFT).',
-        '5. `[]` Mint account of the NFT.',
-        "6. `[writable]` Seller's token account for the NFT.",
-        "7. `[writable]` Authority PDA's token account.",
-        '8. `[]` Edition account of the NFT.',
-        '9. `[]` Token program.',
-        '10. `[]` Associated Token program.',
-        '11. `[]` Token Metadata program.',
-        '12. `[]` System program.',
-        '13. `[]` Rent sysvar.',
-        '14. `[writable, optional]` Metadata account (pNFT).',
-        '15. `[writable, optional]` Seller token record (pNFT).',
-        '16. `[optional]` Auth rules account (pNFT).',
-        '17. `[optional]` Instructions sysvar (pNFT).',
-        '18. `[optional]` Auth rules program (pNFT).',
-      ],
-      accounts: [
         {
-          name: 'gumballMachine',
+          name: 'sellerPaymentAccount',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
+          isOptional: true,
+          docs: ['Seller payment account'],
         },
         {
-          name: 'sellerHistory',
+          name: 'authorityPdaPaymentAccount',
           isMut: true,
           isSigner: false,
-          docs: ['Seller history account.'],
+          isOptional: true,
+          docs: ['Authority PDA payment account'],
         },
         {
-          name: 'authorityPda',
+          name: 'collection',
           isMut: true,
           isSigner: false,
-        },
-        {
-          name: 'authority',
-          isMut: false,
-          isSigner: true,
+          isOptional: true,
           docs: [
-            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if selling Core asset',
+            'Collection of the asset',
           ],
         },
         {
-          name: 'seller',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'mint',
+          name: 'mplCoreProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'tokenAccount',
+          name: 'authorityPdaTokenAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL TOKEN ACCOUNTS - only required if selling NFT or Fungible assets',
+            'Authority PDA token account',
+          ],
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'sellerTokenAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['Seller token account'],
         },
         {
-          name: 'edition',
-          isMut: false,
+          name: 'buyerTokenAccount',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['Buyer token account'],
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
+          name: 'metadata',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if selling NFT or PNFT',
+          ],
         },
         {
-          name: 'associatedTokenProgram',
-          isMut: false,
+          name: 'edition',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
         },
         {
           name: 'tokenMetadataProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'rent',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'metadata',
+          name: 'authorityPdaTokenRecord',
           isMut: true,
           isSigner: false,
           isOptional: true,
-          docs: [
-            'OPTIONAL PNFT ACCOUNTS',
-            '/// CHECK: Safe due to token metadata program check',
-          ],
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if selling PNFT'],
         },
         {
-          name: 'sellerTokenRecord',
+          name: 'buyerTokenRecord',
           isMut: true,
           isSigner: false,
           isOptional: true,
@@ -4944,81 +10974,55 @@ export const IDL: MallowGumball = {
           isSigner: false,
           isOptional: true,
         },
-      ],
-      args: [
-        {
-          name: 'index',
-          type: 'u32',
-        },
-      ],
-    },
-    {
-      name: 'removeCoreAsset',
-      docs: [
-        'Remove Core asset from the gumball machine.',
-        "Thaws and revokes delegate from the seller's asset and removes it from the config lines.",
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account.',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).',
-        '3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the asset).',
-        '5. `[writable]` Asset account.',
-        '6. `[writable, optional]` Collection account if asset is part of one.',
-        '7. `[]` MPL Core program.',
-        '8. `[]` System program.',
-      ],
-      accounts: [
-        {
-          name: 'gumballMachine',
-          isMut: true,
-          isSigner: false,
-          docs: ['Gumball Machine account.'],
-        },
-        {
-          name: 'sellerHistory',
-          isMut: true,
+        {
+          name: 'token2022Program',
+          isMut: false,
           isSigner: false,
-          docs: ['Seller history account.'],
+          isOptional: true,
+          docs: [
+            'OPTIONAL TOKEN-2022 ACCOUNTS - only required if selling Token-2022 tokens',
+          ],
         },
         {
-          name: 'authorityPda',
+          name: 'merkleTree',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL COMPRESSED NFT ACCOUNTS - only required if selling compressed NFT',
+          ],
         },
         {
-          name: 'authority',
+          name: 'treeConfig',
           isMut: false,
-          isSigner: true,
-          docs: ['Seller of the asset.'],
+          isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'seller',
-          isMut: true,
+          name: 'bubblegumProgram',
+          isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'asset',
-          isMut: true,
+          name: 'compressionProgram',
+          isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'collection',
-          isMut: true,
+          name: 'logWrapper',
+          isMut: false,
           isSigner: false,
           isOptional: true,
-          docs: ["Core asset's collection if it's part of one."],
         },
         {
-          name: 'mplCoreProgram',
+          name: 'eventAuthority',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'systemProgram',
+          name: 'program',
           isMut: false,
           isSigner: false,
         },
@@ -5028,42 +11032,71 @@ export const IDL: MallowGumball = {
           name: 'index',
           type: 'u32',
         },
+        {
+          name: 'amount',
+          type: 'u64',
+        },
+        {
+          name: 'buyPrice',
+          type: 'u64',
+        },
+        {
+          name: 'compressedNft',
+          type: {
+            option: {
+              defined: 'CompressedNftArgs',
+            },
+          },
+        },
       ],
     },
     {
-      name: 'removeTokens',
+      name: 'claimWithReceipt',
       docs: [
-        'Remove fungible tokens from the gumball machine.',
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
+        'Burns a draw receipt and delivers the item to the owner of the receipt. Receipts do',
+        'not expire: the item can be claimed after the claim deadline.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the tokens).',
-        '5. `[]` Mint account',
-        "6. `[writable]` Seller's token account",
-        "7. `[writable]` Gumball machine's token account",
-        '8. `[]` Token program',
-        '9. `[]` Associated Token program',
-        '10. `[]` System program',
-        '11. `[]` Rent sysvar',
-        'DEPRECATED: Use remove_tokens_span instead',
+        '0. `[writable]` Gumball Machine account (must be in SaleLive, SaleEnded or Paused state)',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '2. `[writable]` Authority account',
+        '3. `[writable]` Seller account',
+        '4. `[signer, writable]` Owner of the receipt',
+        '5. `[writable]` Receipt asset account',
+        '6. `[writable]` Receipt collection account',
+        '7. `[writable]` Asset account (mint for NFTs and tokens, native mint for SOL prizes)',
+        '8. `[]` MPL Core program',
+        '9. `[]` System program',
+        '10. `[writable, optional]` Collection account (Core asset)',
+        "11. `[writable, optional]` Owner's token account (NFT and tokens)",
+        "12. `[writable, optional]` Seller's token account (NFT)",
+        "13. `[writable, optional]` Authority PDA's token account (NFT and tokens)",
+        '14. `[optional]` Token program (NFT and tokens)',
+        '15. `[optional]` Associated Token program (NFT and tokens)',
+        '16. `[optional]` Token-2022 program (Token-2022 tokens)',
+        '17. `[writable, optional]` Edition account (NFT)',
+        '18. `[writable, optional]` Metadata account (NFT)',
+        '19. `[optional]` Token Metadata program (NFT)',
+        '20. `[writable, optional]` Seller token record (pNFT)',
+        '21. `[writable, optional]` Authority PDA token record (pNFT)',
+        '22. `[writable, optional]` Owner token record (pNFT)',
+        '23. `[optional]` Auth rules account (pNFT)',
+        '24. `[optional]` Instructions sysvar (pNFT)',
+        '25. `[optional]` Auth rules program (pNFT)',
+        '26. `[writable, optional]` Merkle tree account (compressed NFT)',
+        '27. `[optional]` Tree config account (compressed NFT)',
+        '28. `[optional]` Bubblegum program (compressed NFT)',
+        '29. `[optional]` SPL Account Compression program (compressed NFT)',
+        '30. `[optional]` SPL Noop program (compressed NFT)',
+        'Remaining accounts: Merkle proof nodes (compressed NFT)',
       ],
       accounts: [
         {
           name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball Machine account.'],
-        },
-        {
-          name: 'sellerHistory',
-          isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
+          docs: ['Gumball machine account.'],
         },
         {
           name: 'authorityPda',
@@ -5073,38 +11106,45 @@ export const IDL: MallowGumball = {
         {
           name: 'authority',
           isMut: true,
-          isSigner: true,
+          isSigner: false,
           docs: [
-            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+            'Authority of the gumball machine, receives the rent of closed token accounts',
           ],
         },
         {
           name: 'seller',
           isMut: true,
           isSigner: false,
+          docs: ['Seller of the item'],
         },
         {
-          name: 'mint',
-          isMut: false,
-          isSigner: false,
+          name: 'owner',
+          isMut: true,
+          isSigner: true,
+          docs: ['Owner of the receipt, receives the item'],
         },
         {
-          name: 'tokenAccount',
+          name: 'receipt',
           isMut: true,
           isSigner: false,
+          docs: ['Receipt asset, burnt once the item is claimed'],
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'receiptCollection',
           isMut: true,
           isSigner: false,
+          docs: ['Receipt collection'],
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
+          name: 'asset',
+          isMut: true,
           isSigner: false,
+          docs: [
+            'Asset (mint for NFTs and tokens, native mint for SOL prizes)',
+          ],
         },
         {
-          name: 'associatedTokenProgram',
+          name: 'mplCoreProgram',
           isMut: false,
           isSigner: false,
         },
@@ -5114,234 +11154,149 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'rent',
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: 'indices',
-          type: 'bytes',
-        },
-        {
-          name: 'amount',
-          type: 'u64',
-        },
-      ],
-    },
-    {
-      name: 'removeTokensSpan',
-      docs: [
-        'Remove fungible tokens from the gumball machine.',
-        'The signer can be the Gumball Machine authority or the seller of the specific item.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).',
-        '4. `[writable]` Seller account (owner of the tokens).',
-        '5. `[]` Mint account',
-        "6. `[writable]` Seller's token account",
-        "7. `[writable]` Gumball machine's token account",
-        '8. `[]` Token program',
-        '9. `[]` Associated Token program',
-        '10. `[]` System program',
-        '11. `[]` Rent sysvar',
-      ],
-      accounts: [
-        {
-          name: 'gumballMachine',
-          isMut: true,
-          isSigner: false,
-          docs: ['Gumball Machine account.'],
-        },
-        {
-          name: 'sellerHistory',
-          isMut: true,
-          isSigner: false,
-          docs: ['Seller history account.'],
-        },
-        {
-          name: 'authorityPda',
+          name: 'collection',
           isMut: true,
           isSigner: false,
-        },
-        {
-          name: 'authority',
-          isMut: true,
-          isSigner: true,
+          isOptional: true,
           docs: [
-            'Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)',
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if claiming a Core asset',
+            "Core asset's collection if it's part of one.",
           ],
         },
         {
-          name: 'seller',
+          name: 'ownerTokenAccount',
           isMut: true,
           isSigner: false,
-        },
-        {
-          name: 'mint',
-          isMut: false,
-          isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL TOKEN ACCOUNTS - only required if claiming a NFT, PNFT or tokens',
+            "Owner's token account",
+          ],
         },
         {
           name: 'tokenAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ["Seller's token account (NFT)"],
         },
         {
           name: 'authorityPdaTokenAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
         },
         {
           name: 'tokenProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
           name: 'associatedTokenProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'systemProgram',
+          name: 'token2022Program',
           isMut: false,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'Token-2022 program, only required if the item is a Token-2022 token',
+          ],
         },
         {
-          name: 'rent',
-          isMut: false,
+          name: 'edition',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if claiming a NFT or PNFT',
+          ],
         },
-      ],
-      args: [
-        {
-          name: 'amount',
-          type: 'u64',
-        },
-        {
-          name: 'startIndex',
-          type: 'u32',
-        },
-        {
-          name: 'endIndex',
-          type: 'u32',
-        },
-      ],
-    },
-    {
-      name: 'startSale',
-      docs: [
-        'Allows minting to begin.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine authority (authority or mint_authority)',
-      ],
-      accounts: [
         {
-          name: 'gumballMachine',
+          name: 'metadata',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball machine account.'],
+          isOptional: true,
         },
         {
-          name: 'authority',
+          name: 'tokenMetadataProgram',
           isMut: false,
-          isSigner: true,
-          docs: [
-            'Gumball Machine authority. This can be the mint authority or the authority.',
-          ],
+          isSigner: false,
+          isOptional: true,
         },
-      ],
-      args: [],
-    },
-    {
-      name: 'endSale',
-      docs: [
-        'Disables minting and allows sales to be settled.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer, writable]` Gumball Machine authority',
-      ],
-      accounts: [
         {
-          name: 'gumballMachine',
+          name: 'sellerTokenRecord',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball machine account.'],
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if claiming a PNFT'],
         },
         {
-          name: 'authority',
+          name: 'authorityPdaTokenRecord',
           isMut: true,
-          isSigner: true,
-          docs: [
-            'Gumball Machine authority. This is the address that controls the upate of the gumball machine.',
-          ],
+          isSigner: false,
+          isOptional: true,
         },
-      ],
-      args: [],
-    },
-    {
-      name: 'draw',
-      docs: [
-        'Draw for a random item from the gumball machine.',
-        'Only the gumball machine mint authority is allowed to draw.',
-        '',
-        '# Accounts',
-        '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine mint authority',
-        '2. `[signer, writable]` Payer',
-        '3. `[]` Buyer account',
-        '4. `[]` System program',
-        '5. `[]` SlotHashes sysvar cluster data',
-      ],
-      accounts: [
         {
-          name: 'gumballMachine',
+          name: 'ownerTokenRecord',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball machine account.'],
+          isOptional: true,
         },
         {
-          name: 'mintAuthority',
+          name: 'authRules',
           isMut: false,
-          isSigner: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'merkleTree',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
           docs: [
-            'Gumball machine mint authority (mint only allowed for the mint_authority).',
+            'OPTIONAL COMPRESSED NFT ACCOUNTS - only required if claiming a compressed NFT',
           ],
         },
         {
-          name: 'payer',
-          isMut: true,
-          isSigner: true,
-          docs: ['Payer for the transaction and account allocation (rent).'],
+          name: 'treeConfig',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'buyer',
+          name: 'bubblegumProgram',
           isMut: false,
           isSigner: false,
-          docs: ['NFT account owner.', ''],
+          isOptional: true,
         },
         {
-          name: 'systemProgram',
+          name: 'compressionProgram',
           isMut: false,
           isSigner: false,
-          docs: ['System program.'],
+          isOptional: true,
         },
         {
-          name: 'recentSlothashes',
+          name: 'logWrapper',
           isMut: false,
           isSigner: false,
-          docs: ['SlotHashes sysvar cluster data.', ''],
+          isOptional: true,
         },
         {
           name: 'eventAuthority',
@@ -5354,21 +11309,32 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
       ],
-      args: [],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
+        {
+          name: 'compressedNft',
+          type: {
+            option: {
+              defined: 'CompressedNftArgs',
+            },
+          },
+        },
+      ],
     },
     {
-      name: 'incrementTotalRevenue',
+      name: 'transferPrize',
       docs: [
-        'Increments total revenue earned by the gumball machine.',
-        '',
-        'Only the gumball machine mint authority is allowed to increment revenue. This is',
-        "required as token transfers don't occur in this program, but total is needed",
-        'when settling.',
+        'Transfers the right to claim a drawn item to a new buyer, who can then claim it',
+        'or sell it back like the original buyer. The new buyer cannot be the authority PDA,',
+        'which holds the items represented by a receipt.',
         '',
         '# Accounts',
         '',
-        '0. `[writable]` Gumball Machine account',
-        '1. `[signer]` Gumball Machine mint authority',
+        '0. `[writable]` Gumball Machine account (must be in SaleLive, SaleEnded or Paused state)',
+        '1. `[signer]` Buyer of the item',
       ],
       accounts: [
         {
@@ -5378,75 +11344,57 @@ export const IDL: MallowGumball = {
           docs: ['Gumball machine account.'],
         },
         {
-          name: 'mintAuthority',
+          name: 'buyer',
           isMut: false,
           isSigner: true,
-          docs: [
-            'Gumball machine mint authority (mint only allowed for the mint_authority).',
-          ],
+          docs: ['Buyer of the item.'],
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
         },
       ],
       args: [
         {
-          name: 'revenue',
-          type: 'u64',
+          name: 'index',
+          type: 'u32',
+        },
+        {
+          name: 'newBuyer',
+          type: 'publicKey',
         },
       ],
     },
     {
-      name: 'sellItem',
+      name: 'claimCoreAsset',
       docs: [
-        'Sell an item back to the gumball machine using buy back funds.',
-        'The payer must be the seller or the oracle_signer.',
-        'Buying back to the gumball machine is currently not supported, but will be in the future.',
-        'If buying back to the authority, the item is marked as claimed and transferred to the buyer (authority).',
+        'Claims a Core asset from the gumball machine for a specific buyer.',
+        'Transfers the asset from the PDA to the buyer.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (must be seller or oracle_signer)',
-        '1. `[signer]` Oracle signer (must match buy_back_config)',
-        '2. `[writable]` Gumball Machine account',
-        '3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '4. `[writable]` Mint account of the item (or Asset account for Core)',
-        '5. `[writable]` Seller account',
-        '6. `[writable]` Buyer account (must be gumball machine or authority)',
-        '7. `[]` System program',
-        '8. `[]` Token program',
-        '9. `[]` Associated Token program',
-        '10. `[]` Rent sysvar',
-        '11. `[writable, optional]` Fee account (if fee configured)',
-        '12. `[writable, optional]` Fee payment account (if fee configured)',
-        '13. `[writable, optional]` Payment mint (if not native SOL)',
-        '14. `[writable, optional]` Seller payment account (if not native SOL)',
-        '15. `[writable, optional]` Authority PDA payment account (if not native SOL)',
-        '16. `[writable, optional]` Collection account (for Core asset)',
-        '17. `[optional]` MPL Core program (for Core asset)',
-        '18. `[writable, optional]` Authority PDA token account (for NFT/Fungible)',
-        '19. `[writable, optional]` Seller token account (for NFT/Fungible)',
-        '20. `[writable, optional]` Buyer token account (for NFT/Fungible)',
-        '21. `[writable, optional]` Metadata account (for NFT/PNFT)',
-        '22. `[writable, optional]` Edition account (for NFT/PNFT)',
-        '23. `[optional]` Token Metadata program (for NFT/PNFT)',
-        '24. `[writable, optional]` Authority PDA token record (for pNFT)',
-        '25. `[writable, optional]` Buyer token record (for pNFT)',
-        '26. `[optional]` Auth rules account (for pNFT)',
-        '27. `[optional]` Instructions sysvar (for pNFT)',
-        '28. `[optional]` Auth rules program (for pNFT)',
+        '0. `[signer, writable]` Payer (anyone can claim the item)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable]` Seller account',
+        '4. `[]` Buyer account',
+        '5. `[]` System program',
+        '6. `[writable]` Asset account',
+        '7. `[writable, optional]` Collection account if asset is part of one.',
+        '8. `[]` MPL Core program.',
       ],
       accounts: [
         {
           name: 'payer',
           isMut: true,
           isSigner: true,
-          docs: [
-            'Must be the oracle signer or seller (oracle signer can sell on behalf of the seller to allow auto-buy back)',
-          ],
-        },
-        {
-          name: 'oracleSigner',
-          isMut: false,
-          isSigner: true,
-          docs: ['Oracle signer'],
+          docs: ['Anyone can settle the sale'],
         },
         {
           name: 'gumballMachine',
@@ -5459,23 +11407,17 @@ export const IDL: MallowGumball = {
           isMut: true,
           isSigner: false,
         },
-        {
-          name: 'mint',
-          isMut: true,
-          isSigner: false,
-          docs: ['Mint of the item (or asset for Core assets)'],
-        },
         {
           name: 'seller',
           isMut: true,
           isSigner: false,
-          docs: ['Seller of the item'],
+          docs: ['Seller of the nft'],
         },
         {
           name: 'buyer',
-          isMut: true,
+          isMut: false,
           isSigner: false,
-          docs: ['Buyer of the item'],
+          docs: ['buyer of the nft'],
         },
         {
           name: 'systemProgram',
@@ -5483,128 +11425,167 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'tokenProgram',
+          name: 'asset',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'collection',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'mplCoreProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'associatedTokenProgram',
+          name: 'eventAuthority',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'rent',
+          name: 'program',
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: 'feeAccount',
+          name: 'index',
+          type: 'u32',
+        },
+      ],
+    },
+    {
+      name: 'claimNft',
+      docs: [
+        'Claims a legacy NFT from the gumball machine for a specific buyer.',
+        'Thaws and transfers the NFT from the PDA to the buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the item)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable]` Seller account',
+        '4. `[]` Buyer account',
+        '5. `[]` Token program',
+        '6. `[]` Associated Token program',
+        '7. `[]` System program',
+        '8. `[]` Rent sysvar',
+        '9. `[]` Mint account',
+        "10. `[writable]` Buyer's token account",
+        "11. `[writable]` Authority PDA's token account",
+        '12. `[writable]` Metadata account',
+        '13. `[writable]` Edition account',
+        '14. `[]` Token Metadata program',
+        '15. `[writable, optional]` Seller token record (pNFT)',
+        '16. `[writable, optional]` Authority PDA token record (pNFT)',
+        '17. `[writable, optional]` Buyer token record (pNFT)',
+        '18. `[optional]` Auth rules account (pNFT)',
+        '19. `[optional]` Instructions sysvar (pNFT)',
+        '20. `[optional]` Auth rules program (pNFT)',
+      ],
+      accounts: [
+        {
+          name: 'payer',
           isMut: true,
-          isSigner: false,
-          isOptional: true,
-          docs: [
-            'OPTIONAL FEE ACCOUNTS - only required if there is a fee config on the gumball machine',
-            'Marketplace fee account',
-          ],
+          isSigner: true,
+          docs: ['Anyone can settle the sale'],
         },
         {
-          name: 'feePaymentAccount',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['Marketplace fee payment account'],
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'paymentMint',
+          name: 'authorityPda',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: [
-            'OPTIONAL SPL TOKEN ACCOUNTS - only required if selling for SPL token',
-            'Mint of payment token',
-          ],
         },
         {
-          name: 'sellerPaymentAccount',
+          name: 'seller',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['Seller payment account'],
+          docs: ['Seller of the nft'],
         },
         {
-          name: 'authorityPdaPaymentAccount',
-          isMut: true,
+          name: 'buyer',
+          isMut: false,
+          isSigner: false,
+          docs: ['buyer of the nft'],
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
-          docs: ['Authority PDA payment account'],
         },
         {
-          name: 'collection',
-          isMut: true,
+          name: 'rent',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
-          docs: [
-            'OPTIONAL CORE ASSET ACCOUNTS - only required if selling Core asset',
-            'Collection of the asset',
-          ],
         },
         {
-          name: 'mplCoreProgram',
+          name: 'mint',
           isMut: false,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'tokenAccount',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: [
-            'OPTIONAL TOKEN ACCOUNTS - only required if selling NFT or Fungible assets',
-            'Authority PDA token account',
-          ],
         },
         {
-          name: 'sellerTokenAccount',
+          name: 'buyerTokenAccount',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['Seller token account'],
+          docs: ['Nft token account for buyer'],
         },
         {
-          name: 'buyerTokenAccount',
+          name: 'authorityPdaTokenAccount',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['Buyer token account'],
         },
         {
           name: 'metadata',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: [
-            'OPTIONAL NFT ACCOUNTS - only required if selling NFT or PNFT',
-          ],
         },
         {
           name: 'edition',
           isMut: true,
           isSigner: false,
-          isOptional: true,
         },
         {
           name: 'tokenMetadataProgram',
           isMut: false,
           isSigner: false,
+        },
+        {
+          name: 'sellerTokenRecord',
+          isMut: true,
+          isSigner: false,
           isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS'],
         },
         {
           name: 'authorityPdaTokenRecord',
           isMut: true,
           isSigner: false,
           isOptional: true,
-          docs: ['OPTIONAL PNFT ACCOUNTS - only required if selling PNFT'],
         },
         {
           name: 'buyerTokenRecord',
@@ -5646,33 +11627,28 @@ export const IDL: MallowGumball = {
           name: 'index',
           type: 'u32',
         },
-        {
-          name: 'amount',
-          type: 'u64',
-        },
-        {
-          name: 'buyPrice',
-          type: 'u64',
-        },
       ],
     },
     {
-      name: 'claimCoreAsset',
+      name: 'claimTokens',
       docs: [
-        'Claims a Core asset from the gumball machine for a specific buyer.',
-        'Transfers the asset from the PDA to the buyer.',
+        'Claims fungible tokens from the gumball machine for a specific buyer.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can claim the item)',
+        '0. `[signer, writable]` Payer (anyone can claim the tokens)',
         '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable]` Seller account',
-        '4. `[]` Buyer account',
-        '5. `[]` System program',
-        '6. `[writable]` Asset account',
-        '7. `[writable, optional]` Collection account if asset is part of one.',
-        '8. `[]` MPL Core program.',
+        '3. `[writable]` Gumball Machine authority',
+        '4. `[writable]` Seller account',
+        '5. `[]` Buyer account',
+        '6. `[]` Token program (SPL Token or Token-2022)',
+        '7. `[]` Associated Token program',
+        '8. `[]` System program',
+        '9. `[]` Rent sysvar',
+        '10. `[]` Mint account',
+        "11. `[writable]` Buyer's token account (must match mint and buyer)",
+        "12. `[writable]` Authority PDA's token account (must match mint and authority PDA)",
       ],
       accounts: [
         {
@@ -5692,6 +11668,12 @@ export const IDL: MallowGumball = {
           isMut: true,
           isSigner: false,
         },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine authority'],
+        },
         {
           name: 'seller',
           isMut: true,
@@ -5704,25 +11686,40 @@ export const IDL: MallowGumball = {
           isSigner: false,
           docs: ['buyer of the nft'],
         },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: 'systemProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'asset',
-          isMut: true,
+          name: 'rent',
+          isMut: false,
           isSigner: false,
         },
         {
-          name: 'collection',
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+          docs: ['Mint of the tokens (SPL Token or Token-2022)'],
+        },
+        {
+          name: 'buyerTokenAccount',
           isMut: true,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'mplCoreProgram',
-          isMut: false,
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
           isSigner: false,
         },
         {
@@ -5744,10 +11741,10 @@ export const IDL: MallowGumball = {
       ],
     },
     {
-      name: 'claimNft',
+      name: 'claimCompressedNft',
       docs: [
-        'Claims a legacy NFT from the gumball machine for a specific buyer.',
-        'Thaws and transfers the NFT from the PDA to the buyer.',
+        'Claims a compressed NFT from the gumball machine for a specific buyer.',
+        'Transfers the leaf from the PDA to the buyer.',
         '',
         '# Accounts',
         '',
@@ -5756,22 +11753,13 @@ export const IDL: MallowGumball = {
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
         '3. `[writable]` Seller account',
         '4. `[]` Buyer account',
-        '5. `[]` Token program',
-        '6. `[]` Associated Token program',
-        '7. `[]` System program',
-        '8. `[]` Rent sysvar',
-        '9. `[]` Mint account',
-        "10. `[writable]` Buyer's token account",
-        "11. `[writable]` Authority PDA's token account",
-        '12. `[writable]` Metadata account',
-        '13. `[writable]` Edition account',
-        '14. `[]` Token Metadata program',
-        '15. `[writable, optional]` Seller token record (pNFT)',
-        '16. `[writable, optional]` Authority PDA token record (pNFT)',
-        '17. `[writable, optional]` Buyer token record (pNFT)',
-        '18. `[optional]` Auth rules account (pNFT)',
-        '19. `[optional]` Instructions sysvar (pNFT)',
-        '20. `[optional]` Auth rules program (pNFT)',
+        '5. `[writable]` Merkle tree account',
+        '6. `[]` Tree config account',
+        '7. `[]` Bubblegum program',
+        '8. `[]` SPL Account Compression program',
+        '9. `[]` SPL Noop program',
+        '10. `[]` System program',
+        'Remaining accounts: Merkle proof nodes',
       ],
       accounts: [
         {
@@ -5804,97 +11792,108 @@ export const IDL: MallowGumball = {
           docs: ['buyer of the nft'],
         },
         {
-          name: 'tokenProgram',
-          isMut: false,
+          name: 'merkleTree',
+          isMut: true,
           isSigner: false,
         },
         {
-          name: 'associatedTokenProgram',
+          name: 'treeConfig',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'systemProgram',
+          name: 'bubblegumProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'rent',
+          name: 'compressionProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'mint',
+          name: 'logWrapper',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'tokenAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'buyerTokenAccount',
-          isMut: true,
+          name: 'systemProgram',
+          isMut: false,
           isSigner: false,
-          docs: ['Nft token account for buyer'],
         },
         {
-          name: 'authorityPdaTokenAccount',
-          isMut: true,
+          name: 'eventAuthority',
+          isMut: false,
           isSigner: false,
         },
         {
-          name: 'metadata',
-          isMut: true,
+          name: 'program',
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: 'edition',
-          isMut: true,
-          isSigner: false,
+          name: 'index',
+          type: 'u32',
         },
         {
-          name: 'tokenMetadataProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'compressedNft',
+          type: {
+            defined: 'CompressedNftArgs',
+          },
         },
+      ],
+    },
+    {
+      name: 'claimSolPrize',
+      docs: [
+        'Claims a native SOL prize from the gumball machine for a specific buyer.',
+        'Transfers the lamports from the PDA to the buyer.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can claim the prize)',
+        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[]` Seller account',
+        '4. `[writable]` Buyer account',
+        '5. `[]` System program',
+      ],
+      accounts: [
         {
-          name: 'sellerTokenRecord',
+          name: 'payer',
           isMut: true,
-          isSigner: false,
-          isOptional: true,
-          docs: ['OPTIONAL PNFT ACCOUNTS'],
+          isSigner: true,
+          docs: ['Anyone can claim the prize'],
         },
         {
-          name: 'authorityPdaTokenRecord',
+          name: 'gumballMachine',
           isMut: true,
           isSigner: false,
-          isOptional: true,
+          docs: ['Gumball machine account.'],
         },
         {
-          name: 'buyerTokenRecord',
+          name: 'authorityPda',
           isMut: true,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'authRules',
+          name: 'seller',
           isMut: false,
           isSigner: false,
-          isOptional: true,
+          docs: ['Seller of the prize'],
         },
         {
-          name: 'instructions',
-          isMut: false,
+          name: 'buyer',
+          isMut: true,
           isSigner: false,
-          isOptional: true,
+          docs: ['buyer of the prize'],
         },
         {
-          name: 'authRulesProgram',
+          name: 'systemProgram',
           isMut: false,
           isSigner: false,
-          isOptional: true,
         },
         {
           name: 'eventAuthority',
@@ -5915,55 +11914,94 @@ export const IDL: MallowGumball = {
       ],
     },
     {
-      name: 'claimTokens',
+      name: 'settleCoreAssetSale',
       docs: [
-        'Claims fungible tokens from the gumball machine for a specific buyer.',
+        'Settles a Core asset sale',
+        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Distributes proceeds according to royalties and fee configuration.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can claim the tokens)',
-        '1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
         '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable]` Gumball Machine authority',
-        '4. `[writable]` Seller account',
-        '5. `[]` Buyer account',
-        '6. `[]` Token program',
-        '7. `[]` Associated Token program',
-        '8. `[]` System program',
-        '9. `[]` Rent sysvar',
-        '10. `[]` Mint account',
-        "11. `[writable]` Buyer's token account (must match mint and buyer)",
-        "12. `[writable]` Authority PDA's token account (must match mint and authority PDA)",
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[]` Buyer account',
+        '10. `[writable, optional]` Fee account',
+        '11. `[writable, optional]` Fee payment account',
+        '12. `[optional]` Payment mint',
+        '13. `[]` Token program',
+        '14. `[]` Associated Token program',
+        '15. `[]` System program',
+        '16. `[]` Rent sysvar',
+        '17. `[writable]` Asset account',
+        '18. `[writable, optional]` Collection account if asset is part of one.',
+        '19. `[]` MPL Core program.',
+        'Remaining accounts: Royalty recipients',
       ],
       accounts: [
         {
-          name: 'payer',
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Anyone can settle the sale'],
+        },
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaPaymentAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for authority pda if using token payment'],
+        },
+        {
+          name: 'authority',
           isMut: true,
-          isSigner: true,
-          docs: ['Anyone can settle the sale'],
+          isSigner: false,
+          docs: ['Seller of the nft'],
         },
         {
-          name: 'gumballMachine',
+          name: 'authorityPaymentAccount',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball machine account.'],
+          isOptional: true,
+          docs: ['Payment account for authority if using token payment'],
         },
         {
-          name: 'authorityPda',
+          name: 'seller',
           isMut: true,
           isSigner: false,
+          docs: ['Seller of the nft'],
         },
         {
-          name: 'authority',
+          name: 'sellerPaymentAccount',
           isMut: true,
           isSigner: false,
-          docs: ['Gumball machine authority'],
+          isOptional: true,
+          docs: ['Payment account for seller if using token payment'],
         },
         {
-          name: 'seller',
+          name: 'sellerHistory',
           isMut: true,
           isSigner: false,
-          docs: ['Seller of the nft'],
+          docs: ['Seller history account.'],
         },
         {
           name: 'buyer',
@@ -5971,6 +12009,27 @@ export const IDL: MallowGumball = {
           isSigner: false,
           docs: ['buyer of the nft'],
         },
+        {
+          name: 'feeAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Fee account for marketplace fee if using fee config'],
+        },
+        {
+          name: 'feePaymentAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for marketplace fee if using token payment'],
+        },
+        {
+          name: 'paymentMint',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Payment mint if using non-native payment token'],
+        },
         {
           name: 'tokenProgram',
           isMut: false,
@@ -5992,18 +12051,19 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'mint',
-          isMut: false,
+          name: 'asset',
+          isMut: true,
           isSigner: false,
         },
         {
-          name: 'buyerTokenAccount',
+          name: 'collection',
           isMut: true,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'authorityPdaTokenAccount',
-          isMut: true,
+          name: 'mplCoreProgram',
+          isMut: false,
           isSigner: false,
         },
         {
@@ -6025,11 +12085,13 @@ export const IDL: MallowGumball = {
       ],
     },
     {
-      name: 'settleCoreAssetSale',
+      name: 'settleNftSale',
       docs: [
-        'Settles a Core asset sale',
+        'Settles a legacy NFT sale',
         "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
-        'Distributes proceeds according to royalties and fee configuration.',
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Distributes proceeds according to royalties and fee configuration. Marks primary sale happened if applicable.',
         '',
         '# Accounts',
         '',
@@ -6050,9 +12112,18 @@ export const IDL: MallowGumball = {
         '14. `[]` Associated Token program',
         '15. `[]` System program',
         '16. `[]` Rent sysvar',
-        '17. `[writable]` Asset account',
-        '18. `[writable, optional]` Collection account if asset is part of one.',
-        '19. `[]` MPL Core program.',
+        '17. `[]` Mint account',
+        "18. `[writable]` Buyer's token account (authority's if the unclaimed item is sent to it)",
+        "19. `[writable]` Authority PDA's token account",
+        '20. `[writable]` Metadata account',
+        '21. `[writable]` Edition account',
+        '22. `[]` Token Metadata program',
+        '23. `[writable, optional]` Seller token record (pNFT)',
+        '24. `[writable, optional]` Authority PDA token record (pNFT)',
+        "25. `[writable, optional]` Buyer token record (pNFT, authority's if the unclaimed item is sent to it)",
+        '26. `[optional]` Auth rules account (pNFT)',
+        '27. `[optional]` Instructions sysvar (pNFT)',
+        '28. `[optional]` Auth rules program (pNFT)',
         'Remaining accounts: Royalty recipients',
       ],
       accounts: [
@@ -6160,20 +12231,79 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'asset',
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAccount',
           isMut: true,
           isSigner: false,
         },
         {
-          name: 'collection',
+          name: 'buyerTokenAccount',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Nft token account for buyer (or the authority when unclaimed items are sent to it)',
+          ],
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'metadata',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'edition',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenMetadataProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'sellerTokenRecord',
           isMut: true,
           isSigner: false,
           isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS'],
         },
         {
-          name: 'mplCoreProgram',
+          name: 'authorityPdaTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'buyerTokenRecord',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
           name: 'eventAuthority',
@@ -6194,11 +12324,14 @@ export const IDL: MallowGumball = {
       ],
     },
     {
-      name: 'settleNftSale',
+      name: 'settleTokensSale',
       docs: [
-        'Settles a legacy NFT sale',
+        'Settles a fungible tokens sale',
         "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
-        'Distributes proceeds according to royalties and fee configuration. Marks primary sale happened if applicable.',
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Native SOL prizes are settled with the native mint and paid out in lamports, the token accounts are unused.',
+        'Distributes proceeds according to fee configuration.',
         '',
         '# Accounts',
         '',
@@ -6211,7 +12344,7 @@ export const IDL: MallowGumball = {
         '6. `[writable]` Seller account',
         '7. `[writable, optional]` Seller payment account',
         '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[]` Buyer account',
+        '9. `[writable]` Buyer account',
         '10. `[writable, optional]` Fee account',
         '11. `[writable, optional]` Fee payment account',
         '12. `[optional]` Payment mint',
@@ -6220,18 +12353,10 @@ export const IDL: MallowGumball = {
         '15. `[]` System program',
         '16. `[]` Rent sysvar',
         '17. `[]` Mint account',
-        "18. `[writable]` Buyer's token account",
+        "18. `[writable]` Receiver's token account (buyer, seller if buyer is default or the claim deadline destination)",
         "19. `[writable]` Authority PDA's token account",
-        '20. `[writable]` Metadata account',
-        '21. `[writable]` Edition account',
-        '22. `[]` Token Metadata program',
-        '23. `[writable, optional]` Seller token record (pNFT)',
-        '24. `[writable, optional]` Authority PDA token record (pNFT)',
-        '25. `[writable, optional]` Buyer token record (pNFT)',
-        '26. `[optional]` Auth rules account (pNFT)',
-        '27. `[optional]` Instructions sysvar (pNFT)',
-        '28. `[optional]` Auth rules program (pNFT)',
-        'Remaining accounts: Royalty recipients',
+        '20. `[optional]` Token-2022 program (for Token-2022 tokens)',
+        'Remaining accounts: Fee recipients',
       ],
       accounts: [
         {
@@ -6275,7 +12400,7 @@ export const IDL: MallowGumball = {
           name: 'seller',
           isMut: true,
           isSigner: false,
-          docs: ['Seller of the nft'],
+          docs: ['Seller of the item'],
         },
         {
           name: 'sellerPaymentAccount',
@@ -6292,9 +12417,9 @@ export const IDL: MallowGumball = {
         },
         {
           name: 'buyer',
-          isMut: false,
+          isMut: true,
           isSigner: false,
-          docs: ['buyer of the nft'],
+          docs: ['buyer of the item'],
         },
         {
           name: 'feeAccount',
@@ -6343,72 +12468,180 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'tokenAccount',
+          name: 'receiverTokenAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['Receiver token account, unused for native SOL prizes'],
+        },
+        {
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['Authority PDA token account, unused for native SOL prizes'],
+        },
+        {
+          name: 'token2022Program',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            'Token-2022 program, only required if the item is a Token-2022 token',
+          ],
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
+      ],
+    },
+    {
+      name: 'settleTokensSaleClaimed',
+      docs: [
+        'Settles a fungible tokens sale that has already been claimed by the buyer or does not have a buyer.',
+        'This can settle multiple items in a single transaction via the `start_index` and `end_index` args.',
+        'Distributes proceeds according to fee configuration and sends unsold tokens back to the seller.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[signer, writable]` Payer (anyone can settle the sale)',
+        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[writable]` Authority account',
+        '5. `[writable, optional]` Authority payment account',
+        '6. `[writable]` Seller account',
+        '7. `[writable, optional]` Seller payment account',
+        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '9. `[optional]` Payment mint',
+        '10. `[]` Token program',
+        '11. `[]` Associated Token program',
+        '12. `[]` System program',
+        '13. `[]` Rent sysvar',
+        '14. `[]` Mint account',
+        "15. `[writable]` Seller's token account (for receiving unsold tokens)",
+        "16. `[writable]` Authority PDA's token account",
+        '17. `[optional]` Token-2022 program (for Token-2022 tokens)',
+        'Remaining accounts: Fee recipients',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+          docs: ['Anyone can settle the sale'],
+        },
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball machine account.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaPaymentAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for authority pda if using token payment'],
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: false,
+          docs: ['Seller of the tokens'],
+        },
+        {
+          name: 'authorityPaymentAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for authority if using token payment'],
         },
         {
-          name: 'buyerTokenAccount',
+          name: 'seller',
           isMut: true,
           isSigner: false,
-          docs: ['Nft token account for buyer'],
+          docs: ['Seller of the item'],
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'sellerPaymentAccount',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for seller if using token payment'],
         },
         {
-          name: 'metadata',
+          name: 'sellerHistory',
           isMut: true,
           isSigner: false,
+          docs: ['Seller history account.'],
         },
         {
-          name: 'edition',
-          isMut: true,
+          name: 'paymentMint',
+          isMut: false,
           isSigner: false,
+          isOptional: true,
+          docs: ['Payment mint if using non-native payment token'],
         },
         {
-          name: 'tokenMetadataProgram',
+          name: 'tokenProgram',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'sellerTokenRecord',
-          isMut: true,
+          name: 'associatedTokenProgram',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
-          docs: ['OPTIONAL PNFT ACCOUNTS'],
         },
         {
-          name: 'authorityPdaTokenRecord',
-          isMut: true,
+          name: 'systemProgram',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'buyerTokenRecord',
-          isMut: true,
+          name: 'rent',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'authRules',
+          name: 'mint',
           isMut: false,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'instructions',
-          isMut: false,
+          name: 'sellerTokenAccount',
+          isMut: true,
           isSigner: false,
-          isOptional: true,
         },
         {
-          name: 'authRulesProgram',
+          name: 'authorityPdaTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'token2022Program',
           isMut: false,
           isSigner: false,
           isOptional: true,
+          docs: [
+            'Token-2022 program, only required if the items are Token-2022 tokens',
+          ],
         },
         {
           name: 'eventAuthority',
@@ -6423,49 +12656,48 @@ export const IDL: MallowGumball = {
       ],
       args: [
         {
-          name: 'index',
-          type: 'u32',
+          name: 'args',
+          type: {
+            defined: 'SettleTokensSaleClaimedArgs',
+          },
         },
       ],
     },
     {
-      name: 'settleTokensSale',
+      name: 'reclaimUnsoldItem',
       docs: [
-        'Settles a fungible tokens sale',
-        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
-        'Distributes proceeds according to fee configuration.',
+        'Returns an unsold item to its seller after the sale has ended, without waiting for the',
+        'authority to settle it. The item is settled with zero proceeds, its share of the revenue',
+        'is left to the remaining items. Compressed NFTs must be settled with `settle_compressed_nft_sale`.',
+        '',
+        'Gumball machines older than version 5 must be migrated first.',
         '',
         '# Accounts',
         '',
-        '0. `[signer, writable]` Payer (anyone can settle the sale)',
-        '1. `[writable]` Gumball Machine account (must be in SaleEnded state)',
-        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
-        '3. `[writable, optional]` Authority PDA payment account',
-        '4. `[writable]` Authority account',
-        '5. `[writable, optional]` Authority payment account',
-        '6. `[writable]` Seller account',
-        '7. `[writable, optional]` Seller payment account',
-        '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[]` Buyer account',
-        '10. `[writable, optional]` Fee account',
-        '11. `[writable, optional]` Fee payment account',
-        '12. `[optional]` Payment mint',
-        '13. `[]` Token program',
-        '14. `[]` Associated Token program',
-        '15. `[]` System program',
-        '16. `[]` Rent sysvar',
-        '17. `[]` Mint account',
-        "18. `[writable]` Receiver's token account (buyer or seller if buyer is default)",
-        "19. `[writable]` Authority PDA's token account",
-        'Remaining accounts: Fee recipients',
+        '0. `[writable]` Gumball Machine account (must be in SaleEnded state)',
+        '1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '2. `[writable]` Authority account',
+        '3. `[signer, writable]` Seller account',
+        '4. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
+        '5. `[writable]` Asset account (mint for NFTs and tokens, native mint for SOL prizes)',
+        '6. `[]` System program',
+        '7. `[writable, optional]` Collection account (Core asset)',
+        '8. `[optional]` MPL Core program (Core asset)',
+        "9. `[writable, optional]` Seller's token account (NFT and tokens)",
+        "10. `[writable, optional]` Authority PDA's token account (NFT and tokens)",
+        '11. `[optional]` Token program (NFT and tokens)',
+        '12. `[optional]` Associated Token program (NFT and tokens)',
+        '13. `[optional]` Token-2022 program (Token-2022 tokens)',
+        '14. `[writable, optional]` Edition account (NFT)',
+        '15. `[writable, optional]` Metadata account (NFT)',
+        '16. `[optional]` Token Metadata program (NFT)',
+        '17. `[writable, optional]` Seller token record (pNFT)',
+        '18. `[writable, optional]` Authority PDA token record (pNFT)',
+        '19. `[optional]` Auth rules account (pNFT)',
+        '20. `[optional]` Instructions sysvar (pNFT)',
+        '21. `[optional]` Auth rules program (pNFT)',
       ],
       accounts: [
-        {
-          name: 'payer',
-          isMut: true,
-          isSigner: true,
-          docs: ['Anyone can settle the sale'],
-        },
         {
           name: 'gumballMachine',
           isMut: true,
@@ -6478,105 +12710,142 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'authorityPdaPaymentAccount',
+          name: 'authority',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['Payment account for authority pda if using token payment'],
+          docs: [
+            'Authority of the gumball machine, receives the rent of closed token accounts',
+          ],
         },
         {
-          name: 'authority',
+          name: 'seller',
           isMut: true,
-          isSigner: false,
-          docs: ['Seller of the nft'],
+          isSigner: true,
+          docs: ['Seller of the item'],
         },
         {
-          name: 'authorityPaymentAccount',
+          name: 'sellerHistory',
           isMut: true,
           isSigner: false,
-          isOptional: true,
-          docs: ['Payment account for authority if using token payment'],
+          docs: ['Seller history account.'],
         },
         {
-          name: 'seller',
+          name: 'asset',
           isMut: true,
           isSigner: false,
-          docs: ['Seller of the item'],
+          docs: [
+            'Asset (mint for NFTs and tokens, native mint for SOL prizes)',
+          ],
         },
         {
-          name: 'sellerPaymentAccount',
-          isMut: true,
+          name: 'systemProgram',
+          isMut: false,
           isSigner: false,
-          isOptional: true,
-          docs: ['Payment account for seller if using token payment'],
         },
         {
-          name: 'sellerHistory',
+          name: 'collection',
           isMut: true,
           isSigner: false,
-          docs: ['Seller history account.'],
+          isOptional: true,
+          docs: [
+            'OPTIONAL CORE ASSET ACCOUNTS - only required if reclaiming a Core asset',
+            "Core asset's collection if it's part of one.",
+          ],
         },
         {
-          name: 'buyer',
+          name: 'mplCoreProgram',
           isMut: false,
           isSigner: false,
-          docs: ['buyer of the item'],
+          isOptional: true,
         },
         {
-          name: 'feeAccount',
+          name: 'tokenAccount',
           isMut: true,
           isSigner: false,
           isOptional: true,
-          docs: ['Fee account for marketplace fee if using fee config'],
+          docs: [
+            'OPTIONAL TOKEN ACCOUNTS - only required if reclaiming a NFT, PNFT or tokens',
+            "Seller's token account",
+          ],
         },
         {
-          name: 'feePaymentAccount',
+          name: 'authorityPdaTokenAccount',
           isMut: true,
           isSigner: false,
           isOptional: true,
-          docs: ['Payment account for marketplace fee if using token payment'],
         },
         {
-          name: 'paymentMint',
+          name: 'tokenProgram',
           isMut: false,
           isSigner: false,
           isOptional: true,
-          docs: ['Payment mint if using non-native payment token'],
         },
         {
-          name: 'tokenProgram',
+          name: 'associatedTokenProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'associatedTokenProgram',
+          name: 'token2022Program',
           isMut: false,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'Token-2022 program, only required if the item is a Token-2022 token',
+          ],
         },
         {
-          name: 'systemProgram',
-          isMut: false,
+          name: 'edition',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: [
+            'OPTIONAL NFT ACCOUNTS - only required if reclaiming a NFT or PNFT',
+          ],
         },
         {
-          name: 'rent',
-          isMut: false,
+          name: 'metadata',
+          isMut: true,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'mint',
+          name: 'tokenMetadataProgram',
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
         {
-          name: 'receiverTokenAccount',
+          name: 'sellerTokenRecord',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+          docs: ['OPTIONAL PNFT ACCOUNTS - only required if reclaiming a PNFT'],
         },
         {
-          name: 'authorityPdaTokenAccount',
+          name: 'authorityPdaTokenRecord',
           isMut: true,
           isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRules',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'instructions',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'authRulesProgram',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
         },
         {
           name: 'eventAuthority',
@@ -6597,11 +12866,14 @@ export const IDL: MallowGumball = {
       ],
     },
     {
-      name: 'settleTokensSaleClaimed',
+      name: 'settleCompressedNftSale',
       docs: [
-        'Settles a fungible tokens sale that has already been claimed by the buyer or does not have a buyer.',
-        'This can settle multiple items in a single transaction via the `start_index` and `end_index` args.',
-        'Distributes proceeds according to fee configuration and sends unsold tokens back to the seller.',
+        'Settles a compressed NFT sale',
+        "If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).",
+        'Once the claim deadline has passed, unclaimed items are sent to its destination instead.',
+        'Items held by a receipt cannot be settled until the receipt is burnt.',
+        'Otherwise the current leaf is verified against the merkle tree, a burnt leaf pays no royalties.',
+        'Distributes proceeds according to royalties and fee configuration.',
         '',
         '# Accounts',
         '',
@@ -6614,15 +12886,20 @@ export const IDL: MallowGumball = {
         '6. `[writable]` Seller account',
         '7. `[writable, optional]` Seller payment account',
         '8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])',
-        '9. `[optional]` Payment mint',
-        '10. `[]` Token program',
-        '11. `[]` Associated Token program',
-        '12. `[]` System program',
-        '13. `[]` Rent sysvar',
-        '14. `[]` Mint account',
-        "15. `[writable]` Seller's token account (for receiving unsold tokens)",
-        "16. `[writable]` Authority PDA's token account",
-        'Remaining accounts: Fee recipients',
+        '9. `[]` Buyer account',
+        '10. `[writable, optional]` Fee account',
+        '11. `[writable, optional]` Fee payment account',
+        '12. `[optional]` Payment mint',
+        '13. `[]` Token program',
+        '14. `[]` Associated Token program',
+        '15. `[]` System program',
+        '16. `[]` Rent sysvar',
+        '17. `[writable]` Merkle tree account',
+        '18. `[]` Tree config account',
+        '19. `[]` Bubblegum program',
+        '20. `[]` SPL Account Compression program',
+        '21. `[]` SPL Noop program',
+        'Remaining accounts: Merkle proof nodes (proof_length), then royalty recipients',
       ],
       accounts: [
         {
@@ -6653,7 +12930,7 @@ export const IDL: MallowGumball = {
           name: 'authority',
           isMut: true,
           isSigner: false,
-          docs: ['Seller of the tokens'],
+          docs: ['Seller of the nft'],
         },
         {
           name: 'authorityPaymentAccount',
@@ -6666,7 +12943,7 @@ export const IDL: MallowGumball = {
           name: 'seller',
           isMut: true,
           isSigner: false,
-          docs: ['Seller of the item'],
+          docs: ['Seller of the nft'],
         },
         {
           name: 'sellerPaymentAccount',
@@ -6681,6 +12958,26 @@ export const IDL: MallowGumball = {
           isSigner: false,
           docs: ['Seller history account.'],
         },
+        {
+          name: 'buyer',
+          isMut: false,
+          isSigner: false,
+          docs: ['buyer of the nft'],
+        },
+        {
+          name: 'feeAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Fee account for marketplace fee if using fee config'],
+        },
+        {
+          name: 'feePaymentAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for marketplace fee if using token payment'],
+        },
         {
           name: 'paymentMint',
           isMut: false,
@@ -6709,18 +13006,28 @@ export const IDL: MallowGumball = {
           isSigner: false,
         },
         {
-          name: 'mint',
+          name: 'merkleTree',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'treeConfig',
           isMut: false,
           isSigner: false,
         },
         {
-          name: 'sellerTokenAccount',
-          isMut: true,
+          name: 'bubblegumProgram',
+          isMut: false,
           isSigner: false,
         },
         {
-          name: 'authorityPdaTokenAccount',
-          isMut: true,
+          name: 'compressionProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'logWrapper',
+          isMut: false,
           isSigner: false,
         },
         {
@@ -6733,12 +13040,16 @@ export const IDL: MallowGumball = {
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u32',
+        },
         {
           name: 'args',
           type: {
-            defined: 'SettleTokensSaleClaimedArgs',
+            defined: 'SettleCompressedNftSaleArgs',
           },
         },
       ],
@@ -6868,6 +13179,64 @@ export const IDL: MallowGumball = {
       ],
       args: [],
     },
+    {
+      name: 'forceCloseGumballMachine',
+      docs: [
+        "Withdraw the rent lamports of an abandoned gumball machine without the mint authority's",
+        'signature. Only available once the force close grace period has passed since the sale',
+        'ended and every item has been settled.',
+        '',
+        'Every step before it is permissionless once the grace period has passed: pending draws',
+        'that can no longer be revealed are cancelled with `cancel_pending_draw`, and the',
+        '`settle_*_sale` instructions return unclaimed prizes to their buyers and unsold items',
+        'to their sellers.',
+        '',
+        '# Accounts',
+        '',
+        '0. `[writable]` Gumball Machine account (will be closed)',
+        '1. `[signer, writable]` Gumball Machine authority',
+        '2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])',
+        '3. `[writable, optional]` Authority PDA payment account',
+        '4. `[]` Token program',
+        'Remaining accounts (if closing non-native payment account):',
+        '- `[]` Payment Mint',
+        "- `[writable]` Authority's token account for payment mint",
+        '- `[]` Associated Token program',
+        '- `[]` System program',
+      ],
+      accounts: [
+        {
+          name: 'gumballMachine',
+          isMut: true,
+          isSigner: false,
+          docs: ['Gumball Machine acccount.'],
+        },
+        {
+          name: 'authority',
+          isMut: true,
+          isSigner: true,
+          docs: ['Authority of the gumball machine.'],
+        },
+        {
+          name: 'authorityPda',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'authorityPdaPaymentAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ['Payment account for authority pda if using token payment'],
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
     {
       name: 'manageBuyBackFunds',
       docs: [
@@ -7056,6 +13425,77 @@ export const IDL: MallowGumball = {
         ],
       },
     },
+    {
+      name: 'pendingDraw',
+      docs: [
+        'Pending draw state to reserve items for a buyer until the draw is revealed.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'gumballMachine',
+            docs: ['Gumball machine the items were reserved on'],
+            type: 'publicKey',
+          },
+          {
+            name: 'buyer',
+            docs: ['Wallet that will receive the items once revealed'],
+            type: 'publicKey',
+          },
+          {
+            name: 'payer',
+            docs: ['Wallet that paid the rent for this account'],
+            type: 'publicKey',
+          },
+          {
+            name: 'targetSlot',
+            docs: ['Slot whose hash is used to select the items'],
+            type: 'u64',
+          },
+          {
+            name: 'quantity',
+            docs: ['Number of items reserved'],
+            type: 'u16',
+          },
+          {
+            name: 'randomness',
+            docs: [
+              'Randomness account of the provider used to select the items (default pubkey when slot hashes are used)',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'rerolledIndex',
+            docs: ['Index of the item given back when the draw is a reroll'],
+            type: {
+              option: 'u32',
+            },
+          },
+          {
+            name: 'drawNumber',
+            docs: [
+              'Number of the draw on the gumball machine (seed of this account)',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'randomnessRequestSlot',
+            docs: [
+              'Slot of the randomness request the draw is bound to (0 when slot hashes are used)',
+            ],
+            type: 'u64',
+          },
+          {
+            name: 'revenue',
+            docs: [
+              'Revenue paid for the draw, split between the drawn items when revealed',
+            ],
+            type: 'u64',
+          },
+        ],
+      },
+    },
     {
       name: 'sellerHistory',
       docs: [
@@ -7084,6 +13524,46 @@ export const IDL: MallowGumball = {
     },
   ],
   types: [
+    {
+      name: 'CompressedNftArgs',
+      docs: [
+        'Current state of a compressed NFT leaf, required to transfer it.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'root',
+            docs: ['Root of the merkle tree the proof was generated for.'],
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'dataHash',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'creatorHash',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'nonce',
+            docs: ['Nonce of the leaf, used to derive the asset id.'],
+            type: 'u64',
+          },
+          {
+            name: 'index',
+            docs: ['Index of the leaf in the merkle tree.'],
+            type: 'u32',
+          },
+        ],
+      },
+    },
     {
       name: 'AddItemArgs',
       type: {
@@ -7105,6 +13585,15 @@ export const IDL: MallowGumball = {
               option: 'u32',
             },
           },
+          {
+            name: 'weight',
+            docs: [
+              'Proceeds weight of the item (defaults to 1), can only be set by the authority',
+            ],
+            type: {
+              option: 'u32',
+            },
+          },
         ],
       },
     },
@@ -7146,6 +13635,61 @@ export const IDL: MallowGumball = {
         ],
       },
     },
+    {
+      name: 'SettleCompressedNftSaleArgs',
+      docs: ['Arguments required to settle the sale of a compressed nft.'],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'compressedNft',
+            docs: ['Current state of the leaf.'],
+            type: {
+              defined: 'CompressedNftArgs',
+            },
+          },
+          {
+            name: 'leafOwner',
+            docs: [
+              'Current owner of the leaf, or the default pubkey if it has been burnt.',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'leafDelegate',
+            docs: ['Current delegate of the leaf.'],
+            type: 'publicKey',
+          },
+          {
+            name: 'metadataHash',
+            docs: ['Metadata hash used to verify the data hash.'],
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'sellerFeeBasisPoints',
+            type: 'u16',
+          },
+          {
+            name: 'creators',
+            docs: ['Creators used to verify the creator hash.'],
+            type: {
+              vec: {
+                defined: 'Creator',
+              },
+            },
+          },
+          {
+            name: 'proofLength',
+            docs: [
+              'Number of proof nodes at the start of the remaining accounts.',
+            ],
+            type: 'u8',
+          },
+        ],
+      },
+    },
     {
       name: 'SettleTokensSaleClaimedArgs',
       type: {
@@ -7215,7 +13759,7 @@ export const IDL: MallowGumball = {
           {
             name: 'toGumballMachine',
             docs: [
-              'Whether buying back prizes should be added back to the gumball machine (not yet supported)',
+              'Whether buying back prizes should be added back to the gumball machine to be drawn again',
             ],
             type: 'bool',
           },
@@ -7236,18 +13780,131 @@ export const IDL: MallowGumball = {
           {
             name: 'marketplaceFeeBps',
             docs: [
-              'Fee in basis points paid to marketplace authority when buying back prizes (paid from funds_available)',
+              'Fee in basis points paid to marketplace authority when buying back prizes (paid from funds_available)',
+            ],
+            type: 'u16',
+          },
+          {
+            name: 'cutoffPct',
+            docs: [
+              'Buy backs are disabled when the percentage of items remaining is less than or equal to this value',
+              '0 means there is no cutoff, 100 means buy back is always disabled, 50 means buy back is disabled when 50% of items are sold',
+              'If an item is sold back to the gumball machine to increase the remaining % above this cutoff, buy back is re-enabled',
+            ],
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'RandomnessConfig',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'provider',
+            docs: [
+              'Program that owns the randomness accounts, the default pubkey means slot hashes are used',
+            ],
+            type: 'publicKey',
+          },
+          {
+            name: 'valueOffset',
+            docs: [
+              'Offset of the 32 bytes random value in the randomness account data',
+            ],
+            type: 'u32',
+          },
+          {
+            name: 'slotOffset',
+            docs: [
+              'Offset of the slot (u64) at which the random value was produced in the randomness account data',
+            ],
+            type: 'u32',
+          },
+        ],
+      },
+    },
+    {
+      name: 'RandomnessRequestConfig',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'account',
+            docs: ['Randomness account of the provider used by every draw'],
+            type: 'publicKey',
+          },
+          {
+            name: 'discriminator',
+            docs: [
+              'Discriminator of the randomness account (first 8 bytes of its data)',
+            ],
+            type: {
+              array: ['u8', 8],
+            },
+          },
+          {
+            name: 'requestSlotOffset',
+            docs: [
+              'Offset of the slot (u64) at which the current value was requested in the randomness account data',
+            ],
+            type: 'u32',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimDeadlineConfig',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'claimPeriod',
+            docs: [
+              'Seconds after the sale ends that drawn items can be claimed, 0 means there is no deadline',
+            ],
+            type: 'i64',
+          },
+          {
+            name: 'destination',
+            docs: [
+              'Receiver of the items that were not claimed before the deadline',
+            ],
+            type: {
+              defined: 'UnclaimedItemDestination',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'TierConfig',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'weights',
+            docs: [
+              'Draw weight of each tier, the odds of drawing an item are proportional to its tier weight',
             ],
-            type: 'u16',
+            type: {
+              array: ['u16', 8],
+            },
           },
           {
-            name: 'cutoffPct',
-            docs: [
-              'Buy backs are disabled when the percentage of items remaining is less than or equal to this value',
-              '0 means there is no cutoff, 100 means buy back is always disabled, 50 means buy back is disabled when 50% of items are sold',
-              'If an item is sold back to the gumball machine to increase the remaining % above this cutoff, buy back is re-enabled',
-            ],
-            type: 'u8',
+            name: 'itemCounts',
+            docs: ['Number of items assigned to each tier'],
+            type: {
+              array: ['u32', 8],
+            },
+          },
+          {
+            name: 'itemsAvailable',
+            docs: ['Number of items in each tier that have not been drawn yet'],
+            type: {
+              array: ['u32', 8],
+            },
           },
         ],
       },
@@ -7330,7 +13987,12 @@ export const IDL: MallowGumball = {
     },
     {
       name: 'ConfigLineV2',
-      docs: ['Config line struct for storing asset data.'],
+      docs: [
+        'Config line struct for storing asset data.',
+        '',
+        'Version 6 lines are followed by the tier (u8) of the item, and version 8 lines by',
+        'the proceeds weight (u32) of the item.',
+      ],
       type: {
         kind: 'struct',
         fields: [
@@ -7417,6 +14079,38 @@ export const IDL: MallowGumball = {
         ],
       },
     },
+    {
+      name: 'ItemReceiver',
+      docs: ['Receiver of an item that is claimed while its sale is settled.'],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Buyer',
+          },
+          {
+            name: 'Seller',
+          },
+          {
+            name: 'Authority',
+          },
+        ],
+      },
+    },
+    {
+      name: 'UnclaimedItemDestination',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Seller',
+          },
+          {
+            name: 'Authority',
+          },
+        ],
+      },
+    },
     {
       name: 'TokenStandard',
       type: {
@@ -7434,6 +14128,15 @@ export const IDL: MallowGumball = {
           {
             name: 'ProgrammableNonFungible',
           },
+          {
+            name: 'Token2022Fungible',
+          },
+          {
+            name: 'CompressedNonFungible',
+          },
+          {
+            name: 'Sol',
+          },
         ],
       },
     },
@@ -7454,11 +14157,34 @@ export const IDL: MallowGumball = {
           {
             name: 'SaleEnded',
           },
+          {
+            name: 'Paused',
+          },
         ],
       },
     },
   ],
   events: [
+    {
+      name: 'BuySpecificEvent',
+      fields: [
+        {
+          name: 'authority',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'buyer',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'index',
+          type: 'u32',
+          index: false,
+        },
+      ],
+    },
     {
       name: 'ClaimItemEvent',
       fields: [
@@ -7507,6 +14233,103 @@ export const IDL: MallowGumball = {
           type: 'u32',
           index: false,
         },
+        {
+          name: 'tier',
+          type: 'u8',
+          index: false,
+        },
+        {
+          name: 'weight',
+          type: 'u16',
+          index: false,
+        },
+        {
+          name: 'totalWeight',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'MintReceiptEvent',
+      fields: [
+        {
+          name: 'receipt',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'authority',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'buyer',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'index',
+          type: 'u32',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'RejectAddItemEvent',
+      fields: [
+        {
+          name: 'gumballMachine',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'authority',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'seller',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'asset',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'reason',
+          type: {
+            option: 'u8',
+          },
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'RerollItemEvent',
+      fields: [
+        {
+          name: 'authority',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'buyer',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'rerolledIndex',
+          type: 'u32',
+          index: false,
+        },
+        {
+          name: 'index',
+          type: 'u32',
+          index: false,
+        },
       ],
     },
     {
@@ -7600,6 +14423,36 @@ export const IDL: MallowGumball = {
         },
       ],
     },
+    {
+      name: 'TransferPrizeEvent',
+      fields: [
+        {
+          name: 'mint',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'authority',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'seller',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'buyer',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'newBuyer',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
+    },
   ],
   errors: [
     {
@@ -7947,5 +14800,180 @@ export const IDL: MallowGumball = {
       name: 'MissingItemIndex',
       msg: 'Missing item index',
     },
+    {
+      code: 6069,
+      name: 'InvalidQuantity',
+      msg: 'Invalid quantity',
+    },
+    {
+      code: 6070,
+      name: 'RevealNotReady',
+      msg: 'Draw cannot be revealed yet',
+    },
+    {
+      code: 6071,
+      name: 'ItemsPendingReveal',
+      msg: 'Items are pending reveal',
+    },
+    {
+      code: 6072,
+      name: 'InvalidTier',
+      msg: 'Invalid tier',
+    },
+    {
+      code: 6073,
+      name: 'MissingRandomnessAccount',
+      msg: 'Missing randomness account',
+    },
+    {
+      code: 6074,
+      name: 'InvalidRandomnessAccount',
+      msg: 'Invalid randomness account',
+    },
+    {
+      code: 6075,
+      name: 'AccountSizeIncreaseTooLarge',
+      msg: 'Account size increase is too large, resize in smaller steps',
+    },
+    {
+      code: 6076,
+      name: 'InvalidProceedsWeight',
+      msg: 'Invalid proceeds weight',
+    },
+    {
+      code: 6077,
+      name: 'InvalidTokenProgram',
+      msg: 'Invalid token program',
+    },
+    {
+      code: 6078,
+      name: 'NonTransferableMint',
+      msg: 'Non-transferable tokens cannot be added',
+    },
+    {
+      code: 6079,
+      name: 'UnsupportedMintExtension',
+      msg: 'Tokens with a transfer hook or permanent delegate cannot be added',
+    },
+    {
+      code: 6080,
+      name: 'MissingCompressedNftArgs',
+      msg: 'Compressed nft args are required',
+    },
+    {
+      code: 6081,
+      name: 'DuplicateItem',
+      msg: 'Duplicate item',
+    },
+    {
+      code: 6082,
+      name: 'MissingAccount',
+      msg: 'Missing account',
+    },
+    {
+      code: 6083,
+      name: 'InvalidAddItemRequestExpiry',
+      msg: 'Invalid add item request expiry',
+    },
+    {
+      code: 6084,
+      name: 'AddItemRequestNotExpired',
+      msg: 'Add item request has not expired',
+    },
+    {
+      code: 6085,
+      name: 'InvalidForceCloseGracePeriod',
+      msg: 'Invalid force close grace period',
+    },
+    {
+      code: 6086,
+      name: 'ForceCloseNotAvailable',
+      msg: 'Gumball machine cannot be force closed yet',
+    },
+    {
+      code: 6087,
+      name: 'InvalidClaimDeadline',
+      msg: 'Invalid claim deadline',
+    },
+    {
+      code: 6088,
+      name: 'ClaimDeadlinePassed',
+      msg: 'Claim deadline has passed',
+    },
+    {
+      code: 6089,
+      name: 'InvalidInstantDeliveryAccount',
+      msg: 'Invalid instant delivery account',
+    },
+    {
+      code: 6090,
+      name: 'ReceiptsNotEnabled',
+      msg: 'Receipt collection is not set',
+    },
+    {
+      code: 6091,
+      name: 'InvalidReceiptCollection',
+      msg: 'Invalid receipt collection',
+    },
+    {
+      code: 6092,
+      name: 'InvalidReceipt',
+      msg: 'Invalid receipt',
+    },
+    {
+      code: 6093,
+      name: 'ItemHeldByReceipt',
+      msg: 'Item is held by a receipt',
+    },
+    {
+      code: 6094,
+      name: 'ClaimDeadlineRequired',
+      msg: 'Receipts require a claim deadline',
+    },
+    {
+      code: 6095,
+      name: 'RevealOutOfOrder',
+      msg: 'Earlier draws must be revealed first',
+    },
+    {
+      code: 6096,
+      name: 'RandomnessRequestFulfilled',
+      msg: 'The randomness request was already fulfilled',
+    },
+    {
+      code: 6097,
+      name: 'RandomnessRequestReplaced',
+      msg: 'The randomness request of the draw was replaced',
+    },
+    {
+      code: 6098,
+      name: 'SolPrizeTooSmall',
+      msg: 'SOL prizes must be at least the rent-exempt minimum',
+    },
+    {
+      code: 6099,
+      name: 'SolPrizesNotCovered',
+      msg: 'Transfer would use the lamports of SOL prizes',
+    },
+    {
+      code: 6100,
+      name: 'DrawCanBeRevealed',
+      msg: 'The draw can still be revealed',
+    },
+    {
+      code: 6101,
+      name: 'InstantDeliveryNotSupported',
+      msg: 'Compressed NFTs cannot be delivered instantly',
+    },
+    {
+      code: 6102,
+      name: 'InvalidReceiptAccount',
+      msg: 'Invalid receipt account',
+    },
+    {
+      code: 6103,
+      name: 'ReceiptsWithInstantDelivery',
+      msg: 'Receipts cannot be used with instant delivery',
+    },
   ],
 };
@@ -0,0 +1,60 @@
+import {
+  MPL_BUBBLEGUM_PROGRAM_ID,
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+  SPL_NOOP_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-bubblegum';
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import { MPL_TOKEN_METADATA_PROGRAM_ID } from '@metaplex-foundation/mpl-token-metadata';
+import {
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  SPL_TOKEN_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import { TransactionBuilder } from '@metaplex-foundation/umi';
+import { baseClaimWithReceipt, TokenStandard } from './generated';
+import { MPL_TOKEN_AUTH_RULES_PROGRAM_ID } from './programs';
+
+export type ClaimWithReceiptInput = Parameters<
+  typeof baseClaimWithReceipt
+>[1] & {
+  tokenStandard: TokenStandard;
+};
+
+export const claimWithReceipt = (
+  context: Parameters<typeof baseClaimWithReceipt>[0],
+  input: ClaimWithReceiptInput
+): TransactionBuilder =>
+  baseClaimWithReceipt(context, {
+    ...getDefaultsForTokenStandard(input.tokenStandard),
+    ...input,
+  });
+
+function getDefaultsForTokenStandard(tokenStandard: TokenStandard) {
+  const tokenDefaults = {
+    tokenProgram: SPL_TOKEN_PROGRAM_ID,
+    associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  };
+
+  switch (tokenStandard) {
+    case TokenStandard.NonFungible:
+      return {
+        ...tokenDefaults,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+      };
+    case TokenStandard.ProgrammableNonFungible:
+      return {
+        ...tokenDefaults,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        authRulesProgram: MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
+      };
+    case TokenStandard.Fungible:
+      return tokenDefaults;
+    case TokenStandard.CompressedNonFungible:
+      return {
+        bubblegumProgram: MPL_BUBBLEGUM_PROGRAM_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        logWrapper: SPL_NOOP_PROGRAM_ID,
+      };
+    default:
+      return {};
+  }
+}
@@ -0,0 +1,36 @@
+import {
+  publicKey,
+  PublicKey,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  baseCloseExpiredAddItemRequest,
+  findSellerHistoryPda,
+  TokenStandard,
+} from './generated';
+import { findGumballMachineAuthorityPda } from './hooked';
+import { getAddItemRequestProgramDefaults } from './rejectAddItem';
+
+export type CloseExpiredAddItemRequestInput = Omit<
+  Parameters<typeof baseCloseExpiredAddItemRequest>[1],
+  'sellerHistory' | 'authorityPda'
+> & {
+  gumballMachine: PublicKey;
+  tokenStandard: TokenStandard;
+};
+
+export const closeExpiredAddItemRequest = (
+  context: Parameters<typeof baseCloseExpiredAddItemRequest>[0],
+  input: CloseExpiredAddItemRequestInput
+): TransactionBuilder =>
+  baseCloseExpiredAddItemRequest(context, {
+    ...getAddItemRequestProgramDefaults(input.tokenStandard),
+    ...input,
+    sellerHistory: findSellerHistoryPda(context, {
+      gumballMachine: input.gumballMachine,
+      seller: publicKey(input.seller, false),
+    }),
+    authorityPda: findGumballMachineAuthorityPda(context, {
+      gumballMachine: input.gumballMachine,
+    }),
+  });
@@ -40,6 +40,30 @@ export const CONFIG_LINE_SIZE =
   32 + // buyer
   1; // token standard
 export const CONFIG_LINE_V2_SIZE = CONFIG_LINE_SIZE + 8; // amount
+export const CONFIG_LINE_V3_SIZE = CONFIG_LINE_V2_SIZE + 1; // tier
+export const CONFIG_LINE_V4_SIZE = CONFIG_LINE_V3_SIZE + 4; // weight
+export const CONFIG_LINE_V5_SIZE = CONFIG_LINE_V4_SIZE + 8; // drawSlot
+export const CONFIG_LINE_V6_SIZE = CONFIG_LINE_V5_SIZE + 8; // price
+
+export const MAX_TIERS = 8;
+export const TIER_CONFIG_SIZE =
+  MAX_TIERS * 2 + // weights
+  MAX_TIERS * 4 + // itemCounts
+  MAX_TIERS * 4; // itemsAvailable
+
+export const RANDOMNESS_CONFIG_SIZE =
+  32 + // provider
+  4 + // valueOffset
+  4; // slotOffset
+
+export const RANDOMNESS_REQUEST_CONFIG_SIZE =
+  32 + // account
+  8 + // discriminator
+  4; // requestSlotOffset
+
+export const CLAIM_DEADLINE_CONFIG_SIZE =
+  8 + // claimPeriod
+  1; // destination
 
 export const GUMBALL_GUARD_LABEL_SIZE = 6;
 export const GUMBALL_GUARD_DATA =
@@ -55,3 +79,7 @@ export const MASTER_EDITION_SIZE: number = 282;
 export const NATIVE_MINT = publicKey(
   'So11111111111111111111111111111111111111112'
 );
+
+export const SPL_TOKEN_2022_PROGRAM_ID = publicKey(
+  'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+);
@@ -20,10 +20,14 @@ import {
   NftGateArgs,
   NftPayment,
   NftPaymentArgs,
+  PremiumPrice,
+  PremiumPriceArgs,
   ProgramGate,
   ProgramGateArgs,
   RedeemedAmount,
   RedeemedAmountArgs,
+  RerollFee,
+  RerollFeeArgs,
   SolPayment,
   SolPaymentArgs,
   StartDate,
@@ -82,6 +86,8 @@ export type DefaultGuardSetArgs = GuardSetArgs & {
   programGate: OptionOrNullable<ProgramGateArgs>;
   allocation: OptionOrNullable<AllocationArgs>;
   token2022Payment: OptionOrNullable<Token2022PaymentArgs>;
+  premiumPrice: OptionOrNullable<PremiumPriceArgs>;
+  rerollFee: OptionOrNullable<RerollFeeArgs>;
 };
 
 /**
@@ -107,6 +113,8 @@ export type DefaultGuardSet = GuardSet & {
   programGate: Option<ProgramGate>;
   allocation: Option<Allocation>;
   token2022Payment: Option<Token2022Payment>;
+  premiumPrice: Option<PremiumPrice>;
+  rerollFee: Option<RerollFee>;
 };
 
 /**
@@ -132,6 +140,8 @@ export type DefaultGuardSetMintArgs = GuardSetMintArgs & {
   // programGate: no mint settings
   allocation: OptionOrNullable<AllocationMintArgs>;
   token2022Payment: OptionOrNullable<Token2022PaymentMintArgs>;
+  // premiumPrice: no mint settings
+  // rerollFee: no mint settings
 };
 
 /**
@@ -157,6 +167,8 @@ export type DefaultGuardSetRouteArgs = GuardSetRouteArgs & {
   // programGate: no route settings
   allocation: AllocationRouteArgs;
   // token2022Payment: no route settings
+  // premiumPrice: no route settings
+  // rerollFee: no route settings
 };
 
 /** @internal */
@@ -180,6 +192,8 @@ export const defaultGumballGuardNames: string[] = [
   'programGate',
   'allocation',
   'token2022Payment',
+  'premiumPrice',
+  'rerollFee',
 ];
 
 /** @internal */
@@ -9,8 +9,10 @@ export * from './mintLimit';
 export * from './nftBurn';
 export * from './nftGate';
 export * from './nftPayment';
+export * from './premiumPrice';
 export * from './programGate';
 export * from './redeemedAmount';
+export * from './rerollFee';
 export * from './solPayment';
 export * from './startDate';
 export * from './thirdPartySigner';
@@ -0,0 +1,26 @@
+import {
+  getPremiumPriceSerializer,
+  PremiumPrice,
+  PremiumPriceArgs,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The premiumPrice guard sets the price to buy a specific
+ * item instead of drawing one. Buying a specific item is
+ * only allowed when this guard is enabled.
+ *
+ * The premium price is either a flat amount or a multiple
+ * of the draw price in basis points (e.g. 20000 = 2x), and
+ * replaces the price of the payment guards and the amount
+ * of the tokenBurn guard.
+ */
+export const premiumPriceGuardManifest: GuardManifest<
+  PremiumPriceArgs,
+  PremiumPrice
+> = {
+  name: 'premiumPrice',
+  serializer: getPremiumPriceSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
@@ -0,0 +1,20 @@
+import { getRerollFeeSerializer, RerollFee, RerollFeeArgs } from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The rerollFee guard sets the fee to reroll an unclaimed prize.
+ * Rerolling is only allowed when this guard is enabled and groups
+ * with this guard can only be used to reroll.
+ *
+ * The fee replaces the price of the payment guards and the
+ * amount of the tokenBurn guard.
+ */
+export const rerollFeeGuardManifest: GuardManifest<
+  RerollFeeArgs,
+  RerollFee
+> = {
+  name: 'rerollFee',
+  serializer: getRerollFeeSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
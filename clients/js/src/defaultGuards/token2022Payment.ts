@@ -1,5 +1,6 @@
-import { PublicKey, publicKey } from '@metaplex-foundation/umi';
+import { PublicKey } from '@metaplex-foundation/umi';
 import { publicKey as publicKeySerializer } from '@metaplex-foundation/umi/serializers';
+import { SPL_TOKEN_2022_PROGRAM_ID } from '../constants';
 import {
   getToken2022PaymentSerializer,
   Token2022Payment,
@@ -7,10 +8,6 @@ import {
 } from '../generated';
 import { GuardManifest, noopParser } from '../guards';
 
-const SPL_TOKEN_2022_PROGRAM_ID = publicKey(
-  'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
-);
-
 /**
  * The token2022Payment guard allows minting by charging the
  * payer a specific amount of tokens from a certain mint acount
@@ -0,0 +1,83 @@
+import {
+  none,
+  Option,
+  OptionOrNullable,
+  publicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { DefaultGuardSetMintArgs } from './defaultGuards';
+import {
+  drawMany as baseDrawMany,
+  DrawManyInstructionAccounts,
+} from './generated/instructions/drawMany';
+import { MachineType } from './generated/types';
+import {
+  GuardRepository,
+  GuardSetMintArgs,
+  GumballGuardProgram,
+  MintContext,
+  parseGuardRemainingAccounts,
+  parseMintArgs,
+} from './guards';
+import { findGumballGuardPda } from './hooked';
+
+export { DrawManyInstructionAccounts };
+
+export type DrawManyInstructionData<MA extends GuardSetMintArgs> = {
+  discriminator: Array<number>;
+  quantity: number;
+  mintArgs: MA;
+  group: Option<string>;
+};
+
+export type DrawManyInstructionDataArgs<MA extends GuardSetMintArgs> = {
+  quantity: number;
+  mintArgs?: Partial<MA>;
+  group?: OptionOrNullable<string>;
+};
+
+export function drawMany<
+  MA extends GuardSetMintArgs = DefaultGuardSetMintArgs,
+>(
+  context: Parameters<typeof baseDrawMany>[0] & {
+    guards: GuardRepository;
+  },
+  input: DrawManyInstructionAccounts &
+    DrawManyInstructionDataArgs<
+      MA extends undefined ? DefaultGuardSetMintArgs : MA
+    >
+): TransactionBuilder {
+  const { mintArgs = {}, group = none(), ...rest } = input;
+
+  // Parsing mint data.
+  const program = context.programs.get<GumballGuardProgram>('gumballGuard');
+  const gumballMachine = publicKey(input.gumballMachine, false);
+  const mintContext: MintContext = {
+    buyer: input.buyer ?? context.identity,
+    payer: input.payer ?? context.payer,
+    machine: gumballMachine,
+    gumballGuard: publicKey(
+      input.gumballGuard ??
+        findGumballGuardPda(context, { base: gumballMachine }),
+      false
+    ),
+    machineType: MachineType.Gumball,
+  };
+  const { data, remainingAccounts } = parseMintArgs<
+    MA extends undefined ? DefaultGuardSetMintArgs : MA
+  >(context, program, mintContext, mintArgs);
+
+  const ix = baseDrawMany(context, {
+    ...rest,
+    mintArgs: data,
+    group,
+  }).items[0];
+
+  const [keys, signers] = parseGuardRemainingAccounts(remainingAccounts);
+  ix.instruction.keys.push(...keys);
+  ix.signers.push(...signers);
+  ix.bytesCreatedOnChain = 0;
+
+  return transactionBuilder([ix]);
+}
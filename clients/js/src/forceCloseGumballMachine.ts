@@ -0,0 +1,74 @@
+import {
+  findAssociatedTokenPda,
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  SPL_SYSTEM_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  defaultPublicKey,
+  publicKey,
+  PublicKey,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { NATIVE_MINT } from './constants';
+import { baseForceCloseGumballMachine } from './generated';
+import { findGumballMachineAuthorityPda } from './hooked';
+
+export type ForceCloseGumballMachineInput = Parameters<
+  typeof baseForceCloseGumballMachine
+>[1] & {
+  paymentMint?: PublicKey;
+};
+
+export const forceCloseGumballMachine = (
+  context: Parameters<typeof baseForceCloseGumballMachine>[0],
+  input: ForceCloseGumballMachineInput
+): TransactionBuilder => {
+  const { paymentMint } = input;
+  if (
+    paymentMint == null ||
+    paymentMint === defaultPublicKey() ||
+    paymentMint === NATIVE_MINT
+  ) {
+    return baseForceCloseGumballMachine(context, input);
+  }
+
+  const authorityPda = findGumballMachineAuthorityPda(context, {
+    gumballMachine: publicKey(input.gumballMachine, false),
+  })[0];
+  const authority = input.authority?.publicKey ?? context.identity.publicKey;
+
+  return transactionBuilder().add(
+    baseForceCloseGumballMachine(context, {
+      authorityPdaPaymentAccount: findAssociatedTokenPda(context, {
+        mint: paymentMint,
+        owner: authorityPda,
+      }),
+      ...input,
+    }).addRemainingAccounts([
+      {
+        pubkey: paymentMint,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findAssociatedTokenPda(context, {
+          mint: paymentMint,
+          owner: authority,
+        })[0],
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: SPL_SYSTEM_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ])
+  );
+};
@@ -11,4 +11,5 @@ export * from './allocationTracker';
 export * from './allowListProof';
 export * from './gumballMachine';
 export * from './mintCounter';
+export * from './pendingDraw';
 export * from './sellerHistory';
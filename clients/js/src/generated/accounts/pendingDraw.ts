@@ -0,0 +1,249 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Pending draw state to reserve items for a buyer until the draw is revealed. */
+export type PendingDraw = Account<PendingDrawAccountData>;
+
+export type PendingDrawAccountData = {
+  discriminator: Array<number>;
+  /** Gumball machine the items were reserved on */
+  gumballMachine: PublicKey;
+  /** Wallet that will receive the items once revealed */
+  buyer: PublicKey;
+  /** Wallet that paid the rent for this account */
+  payer: PublicKey;
+  /** Slot whose hash is used to select the items */
+  targetSlot: bigint;
+  /** Number of items reserved */
+  quantity: number;
+  /** Randomness account of the provider used to select the items (default pubkey when slot hashes are used) */
+  randomness: PublicKey;
+  /** Index of the item given back when the draw is a reroll */
+  rerolledIndex: Option<number>;
+  /** Number of the draw on the gumball machine (seed of this account) */
+  drawNumber: bigint;
+  /** Slot of the randomness request the draw is bound to (0 when slot hashes are used) */
+  randomnessRequestSlot: bigint;
+  /** Revenue paid for the draw, split between the drawn items when revealed */
+  revenue: bigint;
+};
+
+export type PendingDrawAccountDataArgs = {
+  /** Gumball machine the items were reserved on */
+  gumballMachine: PublicKey;
+  /** Wallet that will receive the items once revealed */
+  buyer: PublicKey;
+  /** Wallet that paid the rent for this account */
+  payer: PublicKey;
+  /** Slot whose hash is used to select the items */
+  targetSlot: number | bigint;
+  /** Number of items reserved */
+  quantity: number;
+  /** Randomness account of the provider used to select the items (default pubkey when slot hashes are used) */
+  randomness: PublicKey;
+  /** Index of the item given back when the draw is a reroll */
+  rerolledIndex: OptionOrNullable<number>;
+  /** Number of the draw on the gumball machine (seed of this account) */
+  drawNumber: number | bigint;
+  /** Slot of the randomness request the draw is bound to (0 when slot hashes are used) */
+  randomnessRequestSlot: number | bigint;
+  /** Revenue paid for the draw, split between the drawn items when revealed */
+  revenue: number | bigint;
+};
+
+export function getPendingDrawAccountDataSerializer(): Serializer<
+  PendingDrawAccountDataArgs,
+  PendingDrawAccountData
+> {
+  return mapSerializer<PendingDrawAccountDataArgs, any, PendingDrawAccountData>(
+    struct<PendingDrawAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['gumballMachine', publicKeySerializer()],
+        ['buyer', publicKeySerializer()],
+        ['payer', publicKeySerializer()],
+        ['targetSlot', u64()],
+        ['quantity', u16()],
+        ['randomness', publicKeySerializer()],
+        ['rerolledIndex', option(u32())],
+        ['drawNumber', u64()],
+        ['randomnessRequestSlot', u64()],
+        ['revenue', u64()],
+      ],
+      { description: 'PendingDrawAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [234, 129, 254, 162, 161, 147, 255, 138],
+    })
+  ) as Serializer<PendingDrawAccountDataArgs, PendingDrawAccountData>;
+}
+
+export function deserializePendingDraw(rawAccount: RpcAccount): PendingDraw {
+  return deserializeAccount(rawAccount, getPendingDrawAccountDataSerializer());
+}
+
+export async function fetchPendingDraw(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PendingDraw> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'PendingDraw');
+  return deserializePendingDraw(maybeAccount);
+}
+
+export async function safeFetchPendingDraw(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PendingDraw | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializePendingDraw(maybeAccount) : null;
+}
+
+export async function fetchAllPendingDraw(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PendingDraw[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'PendingDraw');
+    return deserializePendingDraw(maybeAccount);
+  });
+}
+
+export async function safeFetchAllPendingDraw(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PendingDraw[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializePendingDraw(maybeAccount as RpcAccount));
+}
+
+export function getPendingDrawGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      gumballMachine: PublicKey;
+      buyer: PublicKey;
+      payer: PublicKey;
+      targetSlot: number | bigint;
+      quantity: number;
+      randomness: PublicKey;
+      rerolledIndex: OptionOrNullable<number>;
+      drawNumber: number | bigint;
+      randomnessRequestSlot: number | bigint;
+      revenue: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      gumballMachine: [8, publicKeySerializer()],
+      buyer: [40, publicKeySerializer()],
+      payer: [72, publicKeySerializer()],
+      targetSlot: [104, u64()],
+      quantity: [112, u16()],
+      randomness: [114, publicKeySerializer()],
+      rerolledIndex: [146, option(u32())],
+      drawNumber: [null, u64()],
+      randomnessRequestSlot: [null, u64()],
+      revenue: [null, u64()],
+    })
+    .deserializeUsing<PendingDraw>((account) => deserializePendingDraw(account))
+    .whereField('discriminator', [234, 129, 254, 162, 161, 147, 255, 138]);
+}
+
+export function findPendingDrawPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the Gumball Machine account */
+    gumballMachine: PublicKey;
+    /** The number of the draw on the Gumball Machine */
+    drawNumber: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('pending_draw'),
+    publicKeySerializer().serialize(seeds.gumballMachine),
+    u64().serialize(seeds.drawNumber),
+  ]);
+}
+
+export async function fetchPendingDrawFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPendingDrawPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PendingDraw> {
+  return fetchPendingDraw(context, findPendingDrawPda(context, seeds), options);
+}
+
+export async function safeFetchPendingDrawFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPendingDrawPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PendingDraw | null> {
+  return safeFetchPendingDraw(
+    context,
+    findPendingDrawPda(context, seeds),
+    options
+  );
+}
@@ -796,6 +796,109 @@ export class CgInvalidMachineError extends ProgramError {
 codeToErrorMap.set(0x17a8, CgInvalidMachineError);
 nameToErrorMap.set('InvalidMachine', CgInvalidMachineError);
 
+/** InvalidQuantity: Invalid quantity */
+export class CgInvalidQuantityError extends ProgramError {
+  readonly name: string = 'InvalidQuantity';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid quantity', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a9, CgInvalidQuantityError);
+nameToErrorMap.set('InvalidQuantity', CgInvalidQuantityError);
+
+/** RerollFeeNotEnabled: Reroll fee guard must be enabled to reroll a prize */
+export class CgRerollFeeNotEnabledError extends ProgramError {
+  readonly name: string = 'RerollFeeNotEnabled';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super('Reroll fee guard must be enabled to reroll a prize', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, CgRerollFeeNotEnabledError);
+nameToErrorMap.set('RerollFeeNotEnabled', CgRerollFeeNotEnabledError);
+
+/** RerollOnlyGroup: Guard group can only be used to reroll a prize */
+export class CgRerollOnlyGroupError extends ProgramError {
+  readonly name: string = 'RerollOnlyGroup';
+
+  readonly code: number = 0x17ab; // 6059
+
+  constructor(program: Program, cause?: Error) {
+    super('Guard group can only be used to reroll a prize', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ab, CgRerollOnlyGroupError);
+nameToErrorMap.set('RerollOnlyGroup', CgRerollOnlyGroupError);
+
+/** PremiumPriceNotEnabled: Premium price guard must be enabled to buy a specific item */
+export class CgPremiumPriceNotEnabledError extends ProgramError {
+  readonly name: string = 'PremiumPriceNotEnabled';
+
+  readonly code: number = 0x17ac; // 6060
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Premium price guard must be enabled to buy a specific item',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ac, CgPremiumPriceNotEnabledError);
+nameToErrorMap.set('PremiumPriceNotEnabled', CgPremiumPriceNotEnabledError);
+
+/** InvalidPremiumPrice: Invalid premium price */
+export class CgInvalidPremiumPriceError extends ProgramError {
+  readonly name: string = 'InvalidPremiumPrice';
+
+  readonly code: number = 0x17ad; // 6061
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid premium price', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ad, CgInvalidPremiumPriceError);
+nameToErrorMap.set('InvalidPremiumPrice', CgInvalidPremiumPriceError);
+
+/** PremiumPriceNotSupported: Premium price cannot be charged with an NFT payment or NFT burn guard */
+export class CgPremiumPriceNotSupportedError extends ProgramError {
+  readonly name: string = 'PremiumPriceNotSupported';
+
+  readonly code: number = 0x17ae; // 6062
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Premium price cannot be charged with an NFT payment or NFT burn guard',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17ae, CgPremiumPriceNotSupportedError);
+nameToErrorMap.set('PremiumPriceNotSupported', CgPremiumPriceNotSupportedError);
+
+/** MultiplePaymentGuards: Reroll fee and flat premium price guards require a single payment guard */
+export class CgMultiplePaymentGuardsError extends ProgramError {
+  readonly name: string = 'MultiplePaymentGuards';
+
+  readonly code: number = 0x17af; // 6063
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Reroll fee and flat premium price guards require a single payment guard',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17af, CgMultiplePaymentGuardsError);
+nameToErrorMap.set('MultiplePaymentGuards', CgMultiplePaymentGuardsError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -987,6 +987,494 @@ export class MissingItemIndexError extends ProgramError {
 codeToErrorMap.set(0x17b4, MissingItemIndexError);
 nameToErrorMap.set('MissingItemIndex', MissingItemIndexError);
 
+/** InvalidQuantity: Invalid quantity */
+export class InvalidQuantityError extends ProgramError {
+  readonly name: string = 'InvalidQuantity';
+
+  readonly code: number = 0x17b5; // 6069
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid quantity', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b5, InvalidQuantityError);
+nameToErrorMap.set('InvalidQuantity', InvalidQuantityError);
+
+/** RevealNotReady: Draw cannot be revealed yet */
+export class RevealNotReadyError extends ProgramError {
+  readonly name: string = 'RevealNotReady';
+
+  readonly code: number = 0x17b6; // 6070
+
+  constructor(program: Program, cause?: Error) {
+    super('Draw cannot be revealed yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b6, RevealNotReadyError);
+nameToErrorMap.set('RevealNotReady', RevealNotReadyError);
+
+/** ItemsPendingReveal: Items are pending reveal */
+export class ItemsPendingRevealError extends ProgramError {
+  readonly name: string = 'ItemsPendingReveal';
+
+  readonly code: number = 0x17b7; // 6071
+
+  constructor(program: Program, cause?: Error) {
+    super('Items are pending reveal', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b7, ItemsPendingRevealError);
+nameToErrorMap.set('ItemsPendingReveal', ItemsPendingRevealError);
+
+/** InvalidTier: Invalid tier */
+export class InvalidTierError extends ProgramError {
+  readonly name: string = 'InvalidTier';
+
+  readonly code: number = 0x17b8; // 6072
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid tier', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b8, InvalidTierError);
+nameToErrorMap.set('InvalidTier', InvalidTierError);
+
+/** MissingRandomnessAccount: Missing randomness account */
+export class MissingRandomnessAccountError extends ProgramError {
+  readonly name: string = 'MissingRandomnessAccount';
+
+  readonly code: number = 0x17b9; // 6073
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing randomness account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b9, MissingRandomnessAccountError);
+nameToErrorMap.set('MissingRandomnessAccount', MissingRandomnessAccountError);
+
+/** InvalidRandomnessAccount: Invalid randomness account */
+export class InvalidRandomnessAccountError extends ProgramError {
+  readonly name: string = 'InvalidRandomnessAccount';
+
+  readonly code: number = 0x17ba; // 6074
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid randomness account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ba, InvalidRandomnessAccountError);
+nameToErrorMap.set('InvalidRandomnessAccount', InvalidRandomnessAccountError);
+
+/** AccountSizeIncreaseTooLarge: Account size increase is too large, resize in smaller steps */
+export class AccountSizeIncreaseTooLargeError extends ProgramError {
+  readonly name: string = 'AccountSizeIncreaseTooLarge';
+
+  readonly code: number = 0x17bb; // 6075
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Account size increase is too large, resize in smaller steps',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17bb, AccountSizeIncreaseTooLargeError);
+nameToErrorMap.set(
+  'AccountSizeIncreaseTooLarge',
+  AccountSizeIncreaseTooLargeError
+);
+
+/** InvalidProceedsWeight: Invalid proceeds weight */
+export class InvalidProceedsWeightError extends ProgramError {
+  readonly name: string = 'InvalidProceedsWeight';
+
+  readonly code: number = 0x17bc; // 6076
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid proceeds weight', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bc, InvalidProceedsWeightError);
+nameToErrorMap.set('InvalidProceedsWeight', InvalidProceedsWeightError);
+
+/** InvalidTokenProgram: Invalid token program */
+export class InvalidTokenProgramError extends ProgramError {
+  readonly name: string = 'InvalidTokenProgram';
+
+  readonly code: number = 0x17bd; // 6077
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid token program', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bd, InvalidTokenProgramError);
+nameToErrorMap.set('InvalidTokenProgram', InvalidTokenProgramError);
+
+/** NonTransferableMint: Non-transferable tokens cannot be added */
+export class NonTransferableMintError extends ProgramError {
+  readonly name: string = 'NonTransferableMint';
+
+  readonly code: number = 0x17be; // 6078
+
+  constructor(program: Program, cause?: Error) {
+    super('Non-transferable tokens cannot be added', program, cause);
+  }
+}
+codeToErrorMap.set(0x17be, NonTransferableMintError);
+nameToErrorMap.set('NonTransferableMint', NonTransferableMintError);
+
+/** UnsupportedMintExtension: Tokens with a transfer hook or permanent delegate cannot be added */
+export class UnsupportedMintExtensionError extends ProgramError {
+  readonly name: string = 'UnsupportedMintExtension';
+
+  readonly code: number = 0x17bf; // 6079
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Tokens with a transfer hook or permanent delegate cannot be added',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17bf, UnsupportedMintExtensionError);
+nameToErrorMap.set('UnsupportedMintExtension', UnsupportedMintExtensionError);
+
+/** MissingCompressedNftArgs: Compressed nft args are required */
+export class MissingCompressedNftArgsError extends ProgramError {
+  readonly name: string = 'MissingCompressedNftArgs';
+
+  readonly code: number = 0x17c0; // 6080
+
+  constructor(program: Program, cause?: Error) {
+    super('Compressed nft args are required', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c0, MissingCompressedNftArgsError);
+nameToErrorMap.set('MissingCompressedNftArgs', MissingCompressedNftArgsError);
+
+/** DuplicateItem: Duplicate item */
+export class DuplicateItemError extends ProgramError {
+  readonly name: string = 'DuplicateItem';
+
+  readonly code: number = 0x17c1; // 6081
+
+  constructor(program: Program, cause?: Error) {
+    super('Duplicate item', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c1, DuplicateItemError);
+nameToErrorMap.set('DuplicateItem', DuplicateItemError);
+
+/** MissingAccount: Missing account */
+export class MissingAccountError extends ProgramError {
+  readonly name: string = 'MissingAccount';
+
+  readonly code: number = 0x17c2; // 6082
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c2, MissingAccountError);
+nameToErrorMap.set('MissingAccount', MissingAccountError);
+
+/** InvalidAddItemRequestExpiry: Invalid add item request expiry */
+export class InvalidAddItemRequestExpiryError extends ProgramError {
+  readonly name: string = 'InvalidAddItemRequestExpiry';
+
+  readonly code: number = 0x17c3; // 6083
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid add item request expiry', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c3, InvalidAddItemRequestExpiryError);
+nameToErrorMap.set(
+  'InvalidAddItemRequestExpiry',
+  InvalidAddItemRequestExpiryError
+);
+
+/** AddItemRequestNotExpired: Add item request has not expired */
+export class AddItemRequestNotExpiredError extends ProgramError {
+  readonly name: string = 'AddItemRequestNotExpired';
+
+  readonly code: number = 0x17c4; // 6084
+
+  constructor(program: Program, cause?: Error) {
+    super('Add item request has not expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c4, AddItemRequestNotExpiredError);
+nameToErrorMap.set('AddItemRequestNotExpired', AddItemRequestNotExpiredError);
+
+/** InvalidForceCloseGracePeriod: Invalid force close grace period */
+export class InvalidForceCloseGracePeriodError extends ProgramError {
+  readonly name: string = 'InvalidForceCloseGracePeriod';
+
+  readonly code: number = 0x17c5; // 6085
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid force close grace period', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c5, InvalidForceCloseGracePeriodError);
+nameToErrorMap.set(
+  'InvalidForceCloseGracePeriod',
+  InvalidForceCloseGracePeriodError
+);
+
+/** ForceCloseNotAvailable: Gumball machine cannot be force closed yet */
+export class ForceCloseNotAvailableError extends ProgramError {
+  readonly name: string = 'ForceCloseNotAvailable';
+
+  readonly code: number = 0x17c6; // 6086
+
+  constructor(program: Program, cause?: Error) {
+    super('Gumball machine cannot be force closed yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c6, ForceCloseNotAvailableError);
+nameToErrorMap.set('ForceCloseNotAvailable', ForceCloseNotAvailableError);
+
+/** InvalidClaimDeadline: Invalid claim deadline */
+export class InvalidClaimDeadlineError extends ProgramError {
+  readonly name: string = 'InvalidClaimDeadline';
+
+  readonly code: number = 0x17c7; // 6087
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid claim deadline', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c7, InvalidClaimDeadlineError);
+nameToErrorMap.set('InvalidClaimDeadline', InvalidClaimDeadlineError);
+
+/** ClaimDeadlinePassed: Claim deadline has passed */
+export class ClaimDeadlinePassedError extends ProgramError {
+  readonly name: string = 'ClaimDeadlinePassed';
+
+  readonly code: number = 0x17c8; // 6088
+
+  constructor(program: Program, cause?: Error) {
+    super('Claim deadline has passed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c8, ClaimDeadlinePassedError);
+nameToErrorMap.set('ClaimDeadlinePassed', ClaimDeadlinePassedError);
+
+/** InvalidInstantDeliveryAccount: Invalid instant delivery account */
+export class InvalidInstantDeliveryAccountError extends ProgramError {
+  readonly name: string = 'InvalidInstantDeliveryAccount';
+
+  readonly code: number = 0x17c9; // 6089
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid instant delivery account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c9, InvalidInstantDeliveryAccountError);
+nameToErrorMap.set(
+  'InvalidInstantDeliveryAccount',
+  InvalidInstantDeliveryAccountError
+);
+
+/** ReceiptsNotEnabled: Receipt collection is not set */
+export class ReceiptsNotEnabledError extends ProgramError {
+  readonly name: string = 'ReceiptsNotEnabled';
+
+  readonly code: number = 0x17ca; // 6090
+
+  constructor(program: Program, cause?: Error) {
+    super('Receipt collection is not set', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ca, ReceiptsNotEnabledError);
+nameToErrorMap.set('ReceiptsNotEnabled', ReceiptsNotEnabledError);
+
+/** InvalidReceiptCollection: Invalid receipt collection */
+export class InvalidReceiptCollectionError extends ProgramError {
+  readonly name: string = 'InvalidReceiptCollection';
+
+  readonly code: number = 0x17cb; // 6091
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid receipt collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cb, InvalidReceiptCollectionError);
+nameToErrorMap.set('InvalidReceiptCollection', InvalidReceiptCollectionError);
+
+/** InvalidReceipt: Invalid receipt */
+export class InvalidReceiptError extends ProgramError {
+  readonly name: string = 'InvalidReceipt';
+
+  readonly code: number = 0x17cc; // 6092
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid receipt', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cc, InvalidReceiptError);
+nameToErrorMap.set('InvalidReceipt', InvalidReceiptError);
+
+/** ItemHeldByReceipt: Item is held by a receipt */
+export class ItemHeldByReceiptError extends ProgramError {
+  readonly name: string = 'ItemHeldByReceipt';
+
+  readonly code: number = 0x17cd; // 6093
+
+  constructor(program: Program, cause?: Error) {
+    super('Item is held by a receipt', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cd, ItemHeldByReceiptError);
+nameToErrorMap.set('ItemHeldByReceipt', ItemHeldByReceiptError);
+
+/** ClaimDeadlineRequired: Receipts require a claim deadline */
+export class ClaimDeadlineRequiredError extends ProgramError {
+  readonly name: string = 'ClaimDeadlineRequired';
+
+  readonly code: number = 0x17ce; // 6094
+
+  constructor(program: Program, cause?: Error) {
+    super('Receipts require a claim deadline', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ce, ClaimDeadlineRequiredError);
+nameToErrorMap.set('ClaimDeadlineRequired', ClaimDeadlineRequiredError);
+
+/** RevealOutOfOrder: Earlier draws must be revealed first */
+export class RevealOutOfOrderError extends ProgramError {
+  readonly name: string = 'RevealOutOfOrder';
+
+  readonly code: number = 0x17cf; // 6095
+
+  constructor(program: Program, cause?: Error) {
+    super('Earlier draws must be revealed first', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cf, RevealOutOfOrderError);
+nameToErrorMap.set('RevealOutOfOrder', RevealOutOfOrderError);
+
+/** RandomnessRequestFulfilled: The randomness request was already fulfilled */
+export class RandomnessRequestFulfilledError extends ProgramError {
+  readonly name: string = 'RandomnessRequestFulfilled';
+
+  readonly code: number = 0x17d0; // 6096
+
+  constructor(program: Program, cause?: Error) {
+    super('The randomness request was already fulfilled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d0, RandomnessRequestFulfilledError);
+nameToErrorMap.set(
+  'RandomnessRequestFulfilled',
+  RandomnessRequestFulfilledError
+);
+
+/** RandomnessRequestReplaced: The randomness request of the draw was replaced */
+export class RandomnessRequestReplacedError extends ProgramError {
+  readonly name: string = 'RandomnessRequestReplaced';
+
+  readonly code: number = 0x17d1; // 6097
+
+  constructor(program: Program, cause?: Error) {
+    super('The randomness request of the draw was replaced', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d1, RandomnessRequestReplacedError);
+nameToErrorMap.set('RandomnessRequestReplaced', RandomnessRequestReplacedError);
+
+/** SolPrizeTooSmall: SOL prizes must be at least the rent-exempt minimum */
+export class SolPrizeTooSmallError extends ProgramError {
+  readonly name: string = 'SolPrizeTooSmall';
+
+  readonly code: number = 0x17d2; // 6098
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'SOL prizes must be at least the rent-exempt minimum',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17d2, SolPrizeTooSmallError);
+nameToErrorMap.set('SolPrizeTooSmall', SolPrizeTooSmallError);
+
+/** SolPrizesNotCovered: Transfer would use the lamports of SOL prizes */
+export class SolPrizesNotCoveredError extends ProgramError {
+  readonly name: string = 'SolPrizesNotCovered';
+
+  readonly code: number = 0x17d3; // 6099
+
+  constructor(program: Program, cause?: Error) {
+    super('Transfer would use the lamports of SOL prizes', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d3, SolPrizesNotCoveredError);
+nameToErrorMap.set('SolPrizesNotCovered', SolPrizesNotCoveredError);
+
+/** DrawCanBeRevealed: The draw can still be revealed */
+export class DrawCanBeRevealedError extends ProgramError {
+  readonly name: string = 'DrawCanBeRevealed';
+
+  readonly code: number = 0x17d4; // 6100
+
+  constructor(program: Program, cause?: Error) {
+    super('The draw can still be revealed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d4, DrawCanBeRevealedError);
+nameToErrorMap.set('DrawCanBeRevealed', DrawCanBeRevealedError);
+
+/** InstantDeliveryNotSupported: Compressed NFTs cannot be delivered instantly */
+export class InstantDeliveryNotSupportedError extends ProgramError {
+  readonly name: string = 'InstantDeliveryNotSupported';
+
+  readonly code: number = 0x17d5; // 6101
+
+  constructor(program: Program, cause?: Error) {
+    super('Compressed NFTs cannot be delivered instantly', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d5, InstantDeliveryNotSupportedError);
+nameToErrorMap.set(
+  'InstantDeliveryNotSupported',
+  InstantDeliveryNotSupportedError
+);
+
+/** InvalidReceiptAccount: Invalid receipt account */
+export class InvalidReceiptAccountError extends ProgramError {
+  readonly name: string = 'InvalidReceiptAccount';
+
+  readonly code: number = 0x17d6; // 6102
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid receipt account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d6, InvalidReceiptAccountError);
+nameToErrorMap.set('InvalidReceiptAccount', InvalidReceiptAccountError);
+
+/** ReceiptsWithInstantDelivery: Receipts cannot be used with instant delivery */
+export class ReceiptsWithInstantDeliveryError extends ProgramError {
+  readonly name: string = 'ReceiptsWithInstantDelivery';
+
+  readonly code: number = 0x17d7; // 6103
+
+  constructor(program: Program, cause?: Error) {
+    super('Receipts cannot be used with instant delivery', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d7, ReceiptsWithInstantDeliveryError);
+nameToErrorMap.set(
+  'ReceiptsWithInstantDelivery',
+  ReceiptsWithInstantDeliveryError
+);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
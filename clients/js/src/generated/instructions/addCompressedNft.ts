@@ -0,0 +1,229 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AddItemArgs,
+  AddItemArgsArgs,
+  CompressedNftArgs,
+  CompressedNftArgsArgs,
+  getAddItemArgsSerializer,
+  getCompressedNftArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type AddCompressedNftInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Seller of the nft */
+  seller?: Signer;
+  merkleTree: PublicKey | Pda;
+  treeConfig: PublicKey | Pda;
+  bubblegumProgram?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddCompressedNftInstructionData = {
+  discriminator: Array<number>;
+  args: AddItemArgs;
+  compressedNft: CompressedNftArgs;
+};
+
+export type AddCompressedNftInstructionDataArgs = {
+  args: AddItemArgsArgs;
+  compressedNft: CompressedNftArgsArgs;
+};
+
+export function getAddCompressedNftInstructionDataSerializer(): Serializer<
+  AddCompressedNftInstructionDataArgs,
+  AddCompressedNftInstructionData
+> {
+  return mapSerializer<
+    AddCompressedNftInstructionDataArgs,
+    any,
+    AddCompressedNftInstructionData
+  >(
+    struct<AddCompressedNftInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['args', getAddItemArgsSerializer()],
+        ['compressedNft', getCompressedNftArgsSerializer()],
+      ],
+      { description: 'AddCompressedNftInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [163, 24, 199, 150, 188, 239, 177, 208],
+    })
+  ) as Serializer<
+    AddCompressedNftInstructionDataArgs,
+    AddCompressedNftInstructionData
+  >;
+}
+
+// Args.
+export type AddCompressedNftInstructionArgs =
+  AddCompressedNftInstructionDataArgs;
+
+// Instruction.
+export function addCompressedNft(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: AddCompressedNftInstructionAccounts & AddCompressedNftInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    sellerHistory: {
+      index: 1,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    seller: { index: 3, isWritable: true, value: input.seller ?? null },
+    merkleTree: { index: 4, isWritable: true, value: input.merkleTree ?? null },
+    treeConfig: {
+      index: 5,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 6,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 8,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: AddCompressedNftInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.seller.value) {
+    resolvedAccounts.seller.value = context.identity;
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'mplBubblegum',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'splNoop',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddCompressedNftInstructionDataSerializer().serialize(
+    resolvedArgs as AddCompressedNftInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
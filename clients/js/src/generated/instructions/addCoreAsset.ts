@@ -79,7 +79,11 @@ export function getAddCoreAssetInstructionDataSerializer(): Serializer<
     (value) => ({
       ...value,
       discriminator: [30, 144, 222, 2, 197, 195, 17, 163],
-      args: value.args ?? { sellerProofPath: none(), index: none() },
+      args: value.args ?? {
+        sellerProofPath: none(),
+        index: none(),
+        weight: none(),
+      },
     })
   ) as Serializer<AddCoreAssetInstructionDataArgs, AddCoreAssetInstructionData>;
 }
@@ -89,7 +89,11 @@ export function getAddNftInstructionDataSerializer(): Serializer<
     (value) => ({
       ...value,
       discriminator: [55, 57, 85, 145, 81, 134, 220, 223],
-      args: value.args ?? { sellerProofPath: none(), index: none() },
+      args: value.args ?? {
+        sellerProofPath: none(),
+        index: none(),
+        weight: none(),
+      },
     })
   ) as Serializer<AddNftInstructionDataArgs, AddNftInstructionData>;
 }
@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AddItemArgs,
+  AddItemArgsArgs,
+  getAddItemArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type AddSolPrizeInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Seller of the prize */
+  seller?: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddSolPrizeInstructionData = {
+  discriminator: Array<number>;
+  amount: bigint;
+  quantity: number;
+  args: AddItemArgs;
+};
+
+export type AddSolPrizeInstructionDataArgs = {
+  amount: number | bigint;
+  quantity: number;
+  args?: AddItemArgsArgs;
+};
+
+export function getAddSolPrizeInstructionDataSerializer(): Serializer<
+  AddSolPrizeInstructionDataArgs,
+  AddSolPrizeInstructionData
+> {
+  return mapSerializer<
+    AddSolPrizeInstructionDataArgs,
+    any,
+    AddSolPrizeInstructionData
+  >(
+    struct<AddSolPrizeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['amount', u64()],
+        ['quantity', u16()],
+        ['args', getAddItemArgsSerializer()],
+      ],
+      { description: 'AddSolPrizeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [223, 42, 94, 236, 206, 190, 63, 191],
+      args: value.args ?? {
+        sellerProofPath: none(),
+        index: none(),
+        weight: none(),
+      },
+    })
+  ) as Serializer<AddSolPrizeInstructionDataArgs, AddSolPrizeInstructionData>;
+}
+
+// Args.
+export type AddSolPrizeInstructionArgs = AddSolPrizeInstructionDataArgs;
+
+// Instruction.
+export function addSolPrize(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: AddSolPrizeInstructionAccounts & AddSolPrizeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    sellerHistory: {
+      index: 1,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    seller: { index: 3, isWritable: true, value: input.seller ?? null },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: AddSolPrizeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.seller.value) {
+    resolvedAccounts.seller.value = context.identity;
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddSolPrizeInstructionDataSerializer().serialize(
+    resolvedArgs as AddSolPrizeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -49,6 +49,7 @@ export type AddTokensInstructionAccounts = {
   authorityPda?: PublicKey | Pda;
   /** Seller of the tokens */
   seller?: Signer;
+  /** Mint of the tokens (SPL Token or Token-2022) */
   mint: PublicKey | Pda;
   tokenAccount?: PublicKey | Pda;
   authorityPdaTokenAccount?: PublicKey | Pda;
@@ -93,7 +94,11 @@ export function getAddTokensInstructionDataSerializer(): Serializer<
     (value) => ({
       ...value,
       discriminator: [28, 218, 30, 209, 175, 155, 153, 240],
-      args: value.args ?? { sellerProofPath: none(), index: none() },
+      args: value.args ?? {
+        sellerProofPath: none(),
+        index: none(),
+        weight: none(),
+      },
     })
   ) as Serializer<AddTokensInstructionDataArgs, AddTokensInstructionData>;
 }
@@ -8,17 +8,22 @@
 
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
   array,
   mapSerializer,
+  option,
   struct,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import { findGumballMachineAuthorityPda } from '../../hooked';
@@ -45,9 +50,14 @@ export type ApproveAddItemInstructionAccounts = {
 };
 
 // Data.
-export type ApproveAddItemInstructionData = { discriminator: Array<number> };
+export type ApproveAddItemInstructionData = {
+  discriminator: Array<number>;
+  weight: Option<number>;
+};
 
-export type ApproveAddItemInstructionDataArgs = {};
+export type ApproveAddItemInstructionDataArgs = {
+  weight?: OptionOrNullable<number>;
+};
 
 export function getApproveAddItemInstructionDataSerializer(): Serializer<
   ApproveAddItemInstructionDataArgs,
@@ -59,12 +69,16 @@ export function getApproveAddItemInstructionDataSerializer(): Serializer<
     ApproveAddItemInstructionData
   >(
     struct<ApproveAddItemInstructionData>(
-      [['discriminator', array(u8(), { size: 8 })]],
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['weight', option(u32())],
+      ],
       { description: 'ApproveAddItemInstructionData' }
     ),
     (value) => ({
       ...value,
       discriminator: [135, 250, 51, 252, 70, 171, 19, 48],
+      weight: value.weight ?? none(),
     })
   ) as Serializer<
     ApproveAddItemInstructionDataArgs,
@@ -72,10 +86,13 @@ export function getApproveAddItemInstructionDataSerializer(): Serializer<
   >;
 }
 
+// Args.
+export type ApproveAddItemInstructionArgs = ApproveAddItemInstructionDataArgs;
+
 // Instruction.
 export function approveAddItem(
   context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
-  input: ApproveAddItemInstructionAccounts
+  input: ApproveAddItemInstructionAccounts & ApproveAddItemInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -110,6 +127,9 @@ export function approveAddItem(
     },
   };
 
+  // Arguments.
+  const resolvedArgs: ApproveAddItemInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.addItemRequest.value) {
     resolvedAccounts.addItemRequest.value = findAddItemRequestPda(context, {
@@ -146,7 +166,9 @@ export function approveAddItem(
   );
 
   // Data.
-  const data = getApproveAddItemInstructionDataSerializer().serialize({});
+  const data = getApproveAddItemInstructionDataSerializer().serialize(
+    resolvedArgs as ApproveAddItemInstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
@@ -0,0 +1,196 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AddItemArgs,
+  AddItemArgsArgs,
+  getAddItemArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type BaseAddCoreAssetsInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Seller of the assets. */
+  seller?: Signer;
+  /** Collection shared by the assets if they are part of one. */
+  collection?: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseAddCoreAssetsInstructionData = {
+  discriminator: Array<number>;
+  args: AddItemArgs;
+};
+
+export type BaseAddCoreAssetsInstructionDataArgs = { args?: AddItemArgsArgs };
+
+export function getBaseAddCoreAssetsInstructionDataSerializer(): Serializer<
+  BaseAddCoreAssetsInstructionDataArgs,
+  BaseAddCoreAssetsInstructionData
+> {
+  return mapSerializer<
+    BaseAddCoreAssetsInstructionDataArgs,
+    any,
+    BaseAddCoreAssetsInstructionData
+  >(
+    struct<BaseAddCoreAssetsInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['args', getAddItemArgsSerializer()],
+      ],
+      { description: 'BaseAddCoreAssetsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [42, 49, 110, 107, 64, 170, 61, 58],
+      args: value.args ?? {
+        sellerProofPath: none(),
+        index: none(),
+        weight: none(),
+      },
+    })
+  ) as Serializer<
+    BaseAddCoreAssetsInstructionDataArgs,
+    BaseAddCoreAssetsInstructionData
+  >;
+}
+
+// Args.
+export type BaseAddCoreAssetsInstructionArgs =
+  BaseAddCoreAssetsInstructionDataArgs;
+
+// Instruction.
+export function baseAddCoreAssets(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseAddCoreAssetsInstructionAccounts & BaseAddCoreAssetsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    sellerHistory: {
+      index: 1,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    seller: { index: 3, isWritable: true, value: input.seller ?? null },
+    collection: { index: 4, isWritable: true, value: input.collection ?? null },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: BaseAddCoreAssetsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.seller.value) {
+    resolvedAccounts.seller.value = context.identity;
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCoreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBaseAddCoreAssetsInstructionDataSerializer().serialize(
+    resolvedArgs as BaseAddCoreAssetsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
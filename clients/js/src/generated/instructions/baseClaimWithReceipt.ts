@@ -0,0 +1,444 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+  findTokenRecordPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findEventAuthorityPda,
+  findGumballMachineAuthorityPda,
+} from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CompressedNftArgs,
+  CompressedNftArgsArgs,
+  getCompressedNftArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type BaseClaimWithReceiptInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority of the gumball machine, receives the rent of closed token accounts */
+  authority?: PublicKey | Pda;
+  /** Seller of the item */
+  seller: PublicKey | Pda;
+  /** Owner of the receipt, receives the item */
+  owner?: Signer;
+  /** Receipt asset, burnt once the item is claimed */
+  receipt: PublicKey | Pda;
+  /** Receipt collection */
+  receiptCollection: PublicKey | Pda;
+  /** Asset (mint for NFTs and tokens, native mint for SOL prizes) */
+  asset: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  /**
+   * OPTIONAL CORE ASSET ACCOUNTS - only required if claiming a Core asset
+   * Core asset's collection if it's part of one.
+   */
+
+  collection?: PublicKey | Pda;
+  /**
+   * OPTIONAL TOKEN ACCOUNTS - only required if claiming a NFT, PNFT or tokens
+   * Owner's token account
+   */
+
+  ownerTokenAccount?: PublicKey | Pda;
+  /** Seller's token account (NFT) */
+  tokenAccount?: PublicKey | Pda;
+  authorityPdaTokenAccount?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  /** Token-2022 program, only required if the item is a Token-2022 token */
+  token2022Program?: PublicKey | Pda;
+  /** OPTIONAL NFT ACCOUNTS - only required if claiming a NFT or PNFT */
+  edition?: PublicKey | Pda;
+  metadata?: PublicKey | Pda;
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** OPTIONAL PNFT ACCOUNTS - only required if claiming a PNFT */
+  sellerTokenRecord?: PublicKey | Pda;
+  authorityPdaTokenRecord?: PublicKey | Pda;
+  ownerTokenRecord?: PublicKey | Pda;
+  authRules?: PublicKey | Pda;
+  instructions?: PublicKey | Pda;
+  authRulesProgram?: PublicKey | Pda;
+  /** OPTIONAL COMPRESSED NFT ACCOUNTS - only required if claiming a compressed NFT */
+  merkleTree?: PublicKey | Pda;
+  treeConfig?: PublicKey | Pda;
+  bubblegumProgram?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseClaimWithReceiptInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  compressedNft: Option<CompressedNftArgs>;
+};
+
+export type BaseClaimWithReceiptInstructionDataArgs = {
+  index: number;
+  compressedNft?: OptionOrNullable<CompressedNftArgsArgs>;
+};
+
+export function getBaseClaimWithReceiptInstructionDataSerializer(): Serializer<
+  BaseClaimWithReceiptInstructionDataArgs,
+  BaseClaimWithReceiptInstructionData
+> {
+  return mapSerializer<
+    BaseClaimWithReceiptInstructionDataArgs,
+    any,
+    BaseClaimWithReceiptInstructionData
+  >(
+    struct<BaseClaimWithReceiptInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['compressedNft', option(getCompressedNftArgsSerializer())],
+      ],
+      { description: 'BaseClaimWithReceiptInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [24, 224, 86, 171, 204, 99, 52, 227],
+      compressedNft: value.compressedNft ?? none(),
+    })
+  ) as Serializer<
+    BaseClaimWithReceiptInstructionDataArgs,
+    BaseClaimWithReceiptInstructionData
+  >;
+}
+
+// Args.
+export type BaseClaimWithReceiptInstructionArgs =
+  BaseClaimWithReceiptInstructionDataArgs;
+
+// Instruction.
+export function baseClaimWithReceipt(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseClaimWithReceiptInstructionAccounts &
+    BaseClaimWithReceiptInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 2, isWritable: true, value: input.authority ?? null },
+    seller: { index: 3, isWritable: true, value: input.seller ?? null },
+    owner: { index: 4, isWritable: true, value: input.owner ?? null },
+    receipt: { index: 5, isWritable: true, value: input.receipt ?? null },
+    receiptCollection: {
+      index: 6,
+      isWritable: true,
+      value: input.receiptCollection ?? null,
+    },
+    asset: { index: 7, isWritable: true, value: input.asset ?? null },
+    mplCoreProgram: {
+      index: 8,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    collection: {
+      index: 10,
+      isWritable: true,
+      value: input.collection ?? null,
+    },
+    ownerTokenAccount: {
+      index: 11,
+      isWritable: true,
+      value: input.ownerTokenAccount ?? null,
+    },
+    tokenAccount: {
+      index: 12,
+      isWritable: true,
+      value: input.tokenAccount ?? null,
+    },
+    authorityPdaTokenAccount: {
+      index: 13,
+      isWritable: true,
+      value: input.authorityPdaTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 14,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 15,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    token2022Program: {
+      index: 16,
+      isWritable: false,
+      value: input.token2022Program ?? null,
+    },
+    edition: { index: 17, isWritable: true, value: input.edition ?? null },
+    metadata: { index: 18, isWritable: true, value: input.metadata ?? null },
+    tokenMetadataProgram: {
+      index: 19,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    sellerTokenRecord: {
+      index: 20,
+      isWritable: true,
+      value: input.sellerTokenRecord ?? null,
+    },
+    authorityPdaTokenRecord: {
+      index: 21,
+      isWritable: true,
+      value: input.authorityPdaTokenRecord ?? null,
+    },
+    ownerTokenRecord: {
+      index: 22,
+      isWritable: true,
+      value: input.ownerTokenRecord ?? null,
+    },
+    authRules: { index: 23, isWritable: false, value: input.authRules ?? null },
+    instructions: {
+      index: 24,
+      isWritable: false,
+      value: input.instructions ?? null,
+    },
+    authRulesProgram: {
+      index: 25,
+      isWritable: false,
+      value: input.authRulesProgram ?? null,
+    },
+    merkleTree: {
+      index: 26,
+      isWritable: true,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 27,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 28,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 29,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 30,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    eventAuthority: {
+      index: 31,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 32, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: BaseClaimWithReceiptInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.owner.value) {
+    resolvedAccounts.owner.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCoreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.ownerTokenAccount.value) {
+    if (resolvedAccounts.tokenProgram.value) {
+      resolvedAccounts.ownerTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          owner: expectPublicKey(resolvedAccounts.owner.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.tokenAccount.value) {
+    if (resolvedAccounts.tokenProgram.value) {
+      resolvedAccounts.tokenAccount.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        owner: expectPublicKey(resolvedAccounts.seller.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.authorityPdaTokenAccount.value) {
+    if (resolvedAccounts.tokenProgram.value) {
+      resolvedAccounts.authorityPdaTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.edition.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.edition.value = findMasterEditionPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.metadata.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.metadata.value = findMetadataPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.sellerTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.sellerTokenRecord.value = findTokenRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        token: expectPublicKey(resolvedAccounts.tokenAccount.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.authorityPdaTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.authorityPdaTokenRecord.value = findTokenRecordPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          token: expectPublicKey(
+            resolvedAccounts.authorityPdaTokenAccount.value
+          ),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.ownerTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.ownerTokenRecord.value = findTokenRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        token: expectPublicKey(resolvedAccounts.ownerTokenAccount.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.instructions.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.instructions.value = publicKey(
+        'Sysvar1nstructions1111111111111111111111111'
+      );
+    }
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBaseClaimWithReceiptInstructionDataSerializer().serialize(
+    resolvedArgs as BaseClaimWithReceiptInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,278 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+  findTokenRecordPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAddItemRequestPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BaseCloseExpiredAddItemRequestInstructionAccounts = {
+  /** Seller history account. */
+  sellerHistory: PublicKey | Pda;
+  /** Add item request account. */
+  addItemRequest?: PublicKey | Pda;
+  authorityPda: PublicKey | Pda;
+  /** Payer of the transaction, anyone can close an expired request. */
+  payer?: Signer;
+  seller: PublicKey | Pda;
+  /** Asset (or mint for NFTs) of the request. */
+  asset: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  /**
+   * OPTIONAL CORE ASSET ACCOUNTS - only required if closing a Core asset
+   * Core asset's collection if it's part of one.
+   */
+
+  collection?: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  /** OPTIONAL NFT ACCOUNTS - only required if closing a NFT or PNFT */
+  tokenAccount?: PublicKey | Pda;
+  authorityPdaTokenAccount?: PublicKey | Pda;
+  edition?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** OPTIONAL PNFT ACCOUNTS - only required if closing a PNFT */
+  metadata?: PublicKey | Pda;
+  sellerTokenRecord?: PublicKey | Pda;
+  authRules?: PublicKey | Pda;
+  instructions?: PublicKey | Pda;
+  authRulesProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseCloseExpiredAddItemRequestInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type BaseCloseExpiredAddItemRequestInstructionDataArgs = {};
+
+export function getBaseCloseExpiredAddItemRequestInstructionDataSerializer(): Serializer<
+  BaseCloseExpiredAddItemRequestInstructionDataArgs,
+  BaseCloseExpiredAddItemRequestInstructionData
+> {
+  return mapSerializer<
+    BaseCloseExpiredAddItemRequestInstructionDataArgs,
+    any,
+    BaseCloseExpiredAddItemRequestInstructionData
+  >(
+    struct<BaseCloseExpiredAddItemRequestInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'BaseCloseExpiredAddItemRequestInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [109, 132, 143, 231, 174, 246, 14, 49],
+    })
+  ) as Serializer<
+    BaseCloseExpiredAddItemRequestInstructionDataArgs,
+    BaseCloseExpiredAddItemRequestInstructionData
+  >;
+}
+
+// Instruction.
+export function baseCloseExpiredAddItemRequest(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: BaseCloseExpiredAddItemRequestInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    sellerHistory: {
+      index: 0,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    addItemRequest: {
+      index: 1,
+      isWritable: true,
+      value: input.addItemRequest ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    seller: { index: 4, isWritable: true, value: input.seller ?? null },
+    asset: { index: 5, isWritable: true, value: input.asset ?? null },
+    systemProgram: {
+      index: 6,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    collection: { index: 7, isWritable: true, value: input.collection ?? null },
+    mplCoreProgram: {
+      index: 8,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenAccount: {
+      index: 9,
+      isWritable: true,
+      value: input.tokenAccount ?? null,
+    },
+    authorityPdaTokenAccount: {
+      index: 10,
+      isWritable: true,
+      value: input.authorityPdaTokenAccount ?? null,
+    },
+    edition: { index: 11, isWritable: false, value: input.edition ?? null },
+    tokenProgram: {
+      index: 12,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 13,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 14,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    metadata: { index: 15, isWritable: true, value: input.metadata ?? null },
+    sellerTokenRecord: {
+      index: 16,
+      isWritable: true,
+      value: input.sellerTokenRecord ?? null,
+    },
+    authRules: { index: 17, isWritable: false, value: input.authRules ?? null },
+    instructions: {
+      index: 18,
+      isWritable: false,
+      value: input.instructions ?? null,
+    },
+    authRulesProgram: {
+      index: 19,
+      isWritable: false,
+      value: input.authRulesProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.addItemRequest.value) {
+    resolvedAccounts.addItemRequest.value = findAddItemRequestPda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenAccount.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.tokenAccount.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        owner: expectPublicKey(resolvedAccounts.seller.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.authorityPdaTokenAccount.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.authorityPdaTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.edition.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.edition.value = findMasterEditionPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.metadata.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.metadata.value = findMetadataPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.sellerTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.sellerTokenRecord.value = findTokenRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        token: expectPublicKey(resolvedAccounts.tokenAccount.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.instructions.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.instructions.value = publicKey(
+        'Sysvar1nstructions1111111111111111111111111'
+      );
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getBaseCloseExpiredAddItemRequestInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
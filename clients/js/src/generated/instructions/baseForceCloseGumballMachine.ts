@@ -0,0 +1,147 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BaseForceCloseGumballMachineInstructionAccounts = {
+  /** Gumball Machine acccount. */
+  gumballMachine: PublicKey | Pda;
+  /** Authority of the gumball machine. */
+  authority?: Signer;
+  authorityPda?: PublicKey | Pda;
+  /** Payment account for authority pda if using token payment */
+  authorityPdaPaymentAccount?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseForceCloseGumballMachineInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type BaseForceCloseGumballMachineInstructionDataArgs = {};
+
+export function getBaseForceCloseGumballMachineInstructionDataSerializer(): Serializer<
+  BaseForceCloseGumballMachineInstructionDataArgs,
+  BaseForceCloseGumballMachineInstructionData
+> {
+  return mapSerializer<
+    BaseForceCloseGumballMachineInstructionDataArgs,
+    any,
+    BaseForceCloseGumballMachineInstructionData
+  >(
+    struct<BaseForceCloseGumballMachineInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'BaseForceCloseGumballMachineInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [110, 15, 192, 22, 13, 20, 82, 81] })
+  ) as Serializer<
+    BaseForceCloseGumballMachineInstructionDataArgs,
+    BaseForceCloseGumballMachineInstructionData
+  >;
+}
+
+// Instruction.
+export function baseForceCloseGumballMachine(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseForceCloseGumballMachineInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: true, value: input.authority ?? null },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authorityPdaPaymentAccount: {
+      index: 3,
+      isWritable: true,
+      value: input.authorityPdaPaymentAccount ?? null,
+    },
+    tokenProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getBaseForceCloseGumballMachineInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,356 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+  findTokenRecordPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findEventAuthorityPda,
+  findGumballMachineAuthorityPda,
+} from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BaseReclaimUnsoldItemInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority of the gumball machine, receives the rent of closed token accounts */
+  authority?: PublicKey | Pda;
+  /** Seller of the item */
+  seller?: Signer;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  /** Asset (mint for NFTs and tokens, native mint for SOL prizes) */
+  asset: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  /**
+   * OPTIONAL CORE ASSET ACCOUNTS - only required if reclaiming a Core asset
+   * Core asset's collection if it's part of one.
+   */
+
+  collection?: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  /**
+   * OPTIONAL TOKEN ACCOUNTS - only required if reclaiming a NFT, PNFT or tokens
+   * Seller's token account
+   */
+
+  tokenAccount?: PublicKey | Pda;
+  authorityPdaTokenAccount?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  /** Token-2022 program, only required if the item is a Token-2022 token */
+  token2022Program?: PublicKey | Pda;
+  /** OPTIONAL NFT ACCOUNTS - only required if reclaiming a NFT or PNFT */
+  edition?: PublicKey | Pda;
+  metadata?: PublicKey | Pda;
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** OPTIONAL PNFT ACCOUNTS - only required if reclaiming a PNFT */
+  sellerTokenRecord?: PublicKey | Pda;
+  authorityPdaTokenRecord?: PublicKey | Pda;
+  authRules?: PublicKey | Pda;
+  instructions?: PublicKey | Pda;
+  authRulesProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseReclaimUnsoldItemInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+};
+
+export type BaseReclaimUnsoldItemInstructionDataArgs = { index: number };
+
+export function getBaseReclaimUnsoldItemInstructionDataSerializer(): Serializer<
+  BaseReclaimUnsoldItemInstructionDataArgs,
+  BaseReclaimUnsoldItemInstructionData
+> {
+  return mapSerializer<
+    BaseReclaimUnsoldItemInstructionDataArgs,
+    any,
+    BaseReclaimUnsoldItemInstructionData
+  >(
+    struct<BaseReclaimUnsoldItemInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+      ],
+      { description: 'BaseReclaimUnsoldItemInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [85, 175, 146, 61, 24, 150, 184, 107],
+    })
+  ) as Serializer<
+    BaseReclaimUnsoldItemInstructionDataArgs,
+    BaseReclaimUnsoldItemInstructionData
+  >;
+}
+
+// Args.
+export type BaseReclaimUnsoldItemInstructionArgs =
+  BaseReclaimUnsoldItemInstructionDataArgs;
+
+// Instruction.
+export function baseReclaimUnsoldItem(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseReclaimUnsoldItemInstructionAccounts &
+    BaseReclaimUnsoldItemInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 2, isWritable: true, value: input.authority ?? null },
+    seller: { index: 3, isWritable: true, value: input.seller ?? null },
+    sellerHistory: {
+      index: 4,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    asset: { index: 5, isWritable: true, value: input.asset ?? null },
+    systemProgram: {
+      index: 6,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    collection: { index: 7, isWritable: true, value: input.collection ?? null },
+    mplCoreProgram: {
+      index: 8,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenAccount: {
+      index: 9,
+      isWritable: true,
+      value: input.tokenAccount ?? null,
+    },
+    authorityPdaTokenAccount: {
+      index: 10,
+      isWritable: true,
+      value: input.authorityPdaTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 11,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 12,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    token2022Program: {
+      index: 13,
+      isWritable: false,
+      value: input.token2022Program ?? null,
+    },
+    edition: { index: 14, isWritable: true, value: input.edition ?? null },
+    metadata: { index: 15, isWritable: true, value: input.metadata ?? null },
+    tokenMetadataProgram: {
+      index: 16,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    sellerTokenRecord: {
+      index: 17,
+      isWritable: true,
+      value: input.sellerTokenRecord ?? null,
+    },
+    authorityPdaTokenRecord: {
+      index: 18,
+      isWritable: true,
+      value: input.authorityPdaTokenRecord ?? null,
+    },
+    authRules: { index: 19, isWritable: false, value: input.authRules ?? null },
+    instructions: {
+      index: 20,
+      isWritable: false,
+      value: input.instructions ?? null,
+    },
+    authRulesProgram: {
+      index: 21,
+      isWritable: false,
+      value: input.authRulesProgram ?? null,
+    },
+    eventAuthority: {
+      index: 22,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 23, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: BaseReclaimUnsoldItemInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.seller.value) {
+    resolvedAccounts.seller.value = context.identity;
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenAccount.value) {
+    if (resolvedAccounts.tokenProgram.value) {
+      resolvedAccounts.tokenAccount.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        owner: expectPublicKey(resolvedAccounts.seller.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.authorityPdaTokenAccount.value) {
+    if (resolvedAccounts.tokenProgram.value) {
+      resolvedAccounts.authorityPdaTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.edition.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.edition.value = findMasterEditionPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.metadata.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.metadata.value = findMetadataPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.sellerTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.sellerTokenRecord.value = findTokenRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        token: expectPublicKey(resolvedAccounts.tokenAccount.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.authorityPdaTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.authorityPdaTokenRecord.value = findTokenRecordPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          token: expectPublicKey(
+            resolvedAccounts.authorityPdaTokenAccount.value
+          ),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.instructions.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.instructions.value = publicKey(
+        'Sysvar1nstructions1111111111111111111111111'
+      );
+    }
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBaseReclaimUnsoldItemInstructionDataSerializer().serialize(
+    resolvedArgs as BaseReclaimUnsoldItemInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,338 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+  findTokenRecordPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findEventAuthorityPda,
+  findGumballMachineAuthorityPda,
+} from '../../hooked';
+import { findAddItemRequestPda, findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BaseRejectAddItemInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  /** Add item request account. */
+  addItemRequest?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority of the gumball machine. */
+  authority?: Signer;
+  seller: PublicKey | Pda;
+  /** Asset (or mint for NFTs) of the request. */
+  asset: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  /**
+   * OPTIONAL CORE ASSET ACCOUNTS - only required if rejecting a Core asset
+   * Core asset's collection if it's part of one.
+   */
+
+  collection?: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  /** OPTIONAL NFT ACCOUNTS - only required if rejecting a NFT or PNFT */
+  tokenAccount?: PublicKey | Pda;
+  authorityPdaTokenAccount?: PublicKey | Pda;
+  edition?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** OPTIONAL PNFT ACCOUNTS - only required if rejecting a PNFT */
+  metadata?: PublicKey | Pda;
+  sellerTokenRecord?: PublicKey | Pda;
+  authRules?: PublicKey | Pda;
+  instructions?: PublicKey | Pda;
+  authRulesProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseRejectAddItemInstructionData = {
+  discriminator: Array<number>;
+  reason: Option<number>;
+};
+
+export type BaseRejectAddItemInstructionDataArgs = {
+  reason?: OptionOrNullable<number>;
+};
+
+export function getBaseRejectAddItemInstructionDataSerializer(): Serializer<
+  BaseRejectAddItemInstructionDataArgs,
+  BaseRejectAddItemInstructionData
+> {
+  return mapSerializer<
+    BaseRejectAddItemInstructionDataArgs,
+    any,
+    BaseRejectAddItemInstructionData
+  >(
+    struct<BaseRejectAddItemInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['reason', option(u8())],
+      ],
+      { description: 'BaseRejectAddItemInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [85, 37, 234, 42, 167, 5, 85, 110],
+      reason: value.reason ?? none(),
+    })
+  ) as Serializer<
+    BaseRejectAddItemInstructionDataArgs,
+    BaseRejectAddItemInstructionData
+  >;
+}
+
+// Args.
+export type BaseRejectAddItemInstructionArgs =
+  BaseRejectAddItemInstructionDataArgs;
+
+// Instruction.
+export function baseRejectAddItem(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseRejectAddItemInstructionAccounts & BaseRejectAddItemInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: false,
+      value: input.gumballMachine ?? null,
+    },
+    sellerHistory: {
+      index: 1,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    addItemRequest: {
+      index: 2,
+      isWritable: true,
+      value: input.addItemRequest ?? null,
+    },
+    authorityPda: {
+      index: 3,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 4, isWritable: true, value: input.authority ?? null },
+    seller: { index: 5, isWritable: true, value: input.seller ?? null },
+    asset: { index: 6, isWritable: true, value: input.asset ?? null },
+    systemProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    collection: { index: 8, isWritable: true, value: input.collection ?? null },
+    mplCoreProgram: {
+      index: 9,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    tokenAccount: {
+      index: 10,
+      isWritable: true,
+      value: input.tokenAccount ?? null,
+    },
+    authorityPdaTokenAccount: {
+      index: 11,
+      isWritable: true,
+      value: input.authorityPdaTokenAccount ?? null,
+    },
+    edition: { index: 12, isWritable: false, value: input.edition ?? null },
+    tokenProgram: {
+      index: 13,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 14,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 15,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    metadata: { index: 16, isWritable: true, value: input.metadata ?? null },
+    sellerTokenRecord: {
+      index: 17,
+      isWritable: true,
+      value: input.sellerTokenRecord ?? null,
+    },
+    authRules: { index: 18, isWritable: false, value: input.authRules ?? null },
+    instructions: {
+      index: 19,
+      isWritable: false,
+      value: input.instructions ?? null,
+    },
+    authRulesProgram: {
+      index: 20,
+      isWritable: false,
+      value: input.authRulesProgram ?? null,
+    },
+    eventAuthority: {
+      index: 21,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 22, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: BaseRejectAddItemInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.addItemRequest.value) {
+    resolvedAccounts.addItemRequest.value = findAddItemRequestPda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenAccount.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.tokenAccount.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        owner: expectPublicKey(resolvedAccounts.seller.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.authorityPdaTokenAccount.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.authorityPdaTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.asset.value),
+          owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.edition.value) {
+    if (resolvedAccounts.tokenMetadataProgram.value) {
+      resolvedAccounts.edition.value = findMasterEditionPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.metadata.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.metadata.value = findMetadataPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.sellerTokenRecord.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.sellerTokenRecord.value = findTokenRecordPda(context, {
+        mint: expectPublicKey(resolvedAccounts.asset.value),
+        token: expectPublicKey(resolvedAccounts.tokenAccount.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.instructions.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.instructions.value = publicKey(
+        'Sysvar1nstructions1111111111111111111111111'
+      );
+    }
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBaseRejectAddItemInstructionDataSerializer().serialize(
+    resolvedArgs as BaseRejectAddItemInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
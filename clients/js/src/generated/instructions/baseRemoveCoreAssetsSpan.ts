@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BaseRemoveCoreAssetsSpanInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority allowed to remove the assets (must be the gumball machine auth or the seller of every asset) */
+  authority?: Signer;
+  mplCoreProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseRemoveCoreAssetsSpanInstructionData = {
+  discriminator: Array<number>;
+  startIndex: number;
+  endIndex: number;
+};
+
+export type BaseRemoveCoreAssetsSpanInstructionDataArgs = {
+  startIndex: number;
+  endIndex: number;
+};
+
+export function getBaseRemoveCoreAssetsSpanInstructionDataSerializer(): Serializer<
+  BaseRemoveCoreAssetsSpanInstructionDataArgs,
+  BaseRemoveCoreAssetsSpanInstructionData
+> {
+  return mapSerializer<
+    BaseRemoveCoreAssetsSpanInstructionDataArgs,
+    any,
+    BaseRemoveCoreAssetsSpanInstructionData
+  >(
+    struct<BaseRemoveCoreAssetsSpanInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['startIndex', u32()],
+        ['endIndex', u32()],
+      ],
+      { description: 'BaseRemoveCoreAssetsSpanInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [221, 63, 143, 94, 80, 1, 4, 159] })
+  ) as Serializer<
+    BaseRemoveCoreAssetsSpanInstructionDataArgs,
+    BaseRemoveCoreAssetsSpanInstructionData
+  >;
+}
+
+// Args.
+export type BaseRemoveCoreAssetsSpanInstructionArgs =
+  BaseRemoveCoreAssetsSpanInstructionDataArgs;
+
+// Instruction.
+export function baseRemoveCoreAssetsSpan(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseRemoveCoreAssetsSpanInstructionAccounts &
+    BaseRemoveCoreAssetsSpanInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 2, isWritable: false, value: input.authority ?? null },
+    mplCoreProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: BaseRemoveCoreAssetsSpanInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCoreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBaseRemoveCoreAssetsSpanInstructionDataSerializer().serialize(
+    resolvedArgs as BaseRemoveCoreAssetsSpanInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
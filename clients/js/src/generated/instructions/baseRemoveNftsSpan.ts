@@ -0,0 +1,232 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BaseRemoveNftsSpanInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority allowed to remove the nfts (must be the gumball machine auth or the seller of every nft) */
+  authority?: Signer;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  tokenMetadataProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  rent?: PublicKey | Pda;
+  /** OPTIONAL PNFT ACCOUNTS */
+  authRules?: PublicKey | Pda;
+  instructions?: PublicKey | Pda;
+  authRulesProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BaseRemoveNftsSpanInstructionData = {
+  discriminator: Array<number>;
+  startIndex: number;
+  endIndex: number;
+};
+
+export type BaseRemoveNftsSpanInstructionDataArgs = {
+  startIndex: number;
+  endIndex: number;
+};
+
+export function getBaseRemoveNftsSpanInstructionDataSerializer(): Serializer<
+  BaseRemoveNftsSpanInstructionDataArgs,
+  BaseRemoveNftsSpanInstructionData
+> {
+  return mapSerializer<
+    BaseRemoveNftsSpanInstructionDataArgs,
+    any,
+    BaseRemoveNftsSpanInstructionData
+  >(
+    struct<BaseRemoveNftsSpanInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['startIndex', u32()],
+        ['endIndex', u32()],
+      ],
+      { description: 'BaseRemoveNftsSpanInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [188, 123, 94, 127, 197, 126, 115, 124],
+    })
+  ) as Serializer<
+    BaseRemoveNftsSpanInstructionDataArgs,
+    BaseRemoveNftsSpanInstructionData
+  >;
+}
+
+// Args.
+export type BaseRemoveNftsSpanInstructionArgs =
+  BaseRemoveNftsSpanInstructionDataArgs;
+
+// Instruction.
+export function baseRemoveNftsSpan(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BaseRemoveNftsSpanInstructionAccounts &
+    BaseRemoveNftsSpanInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 2, isWritable: true, value: input.authority ?? null },
+    tokenProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    tokenMetadataProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    rent: { index: 7, isWritable: false, value: input.rent ?? null },
+    authRules: { index: 8, isWritable: false, value: input.authRules ?? null },
+    instructions: {
+      index: 9,
+      isWritable: false,
+      value: input.instructions ?? null,
+    },
+    authRulesProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.authRulesProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: BaseRemoveNftsSpanInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'splAssociatedToken',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.instructions.value) {
+    if (resolvedAccounts.authRulesProgram.value) {
+      resolvedAccounts.instructions.value = publicKey(
+        'Sysvar1nstructions1111111111111111111111111'
+      );
+    }
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBaseRemoveNftsSpanInstructionDataSerializer().serialize(
+    resolvedArgs as BaseRemoveNftsSpanInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
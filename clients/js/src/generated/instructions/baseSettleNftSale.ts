@@ -74,7 +74,7 @@ export type BaseSettleNftSaleInstructionAccounts = {
   rent?: PublicKey | Pda;
   mint: PublicKey | Pda;
   tokenAccount?: PublicKey | Pda;
-  /** Nft token account for buyer */
+  /** Nft token account for buyer (or the authority when unclaimed items are sent to it) */
   buyerTokenAccount?: PublicKey | Pda;
   authorityPdaTokenAccount?: PublicKey | Pda;
   metadata?: PublicKey | Pda;
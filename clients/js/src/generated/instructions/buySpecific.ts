@@ -0,0 +1,266 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEventAuthorityPda, findGumballGuardPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BuySpecificInstructionAccounts = {
+  /** Gumball Guard account. */
+  gumballGuard?: PublicKey | Pda;
+  /**
+   * Gumball Machine program account.
+   *
+   */
+
+  gumballMachineProgram?: PublicKey | Pda;
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Payer for the mint (SOL) fees. */
+  payer?: Signer;
+  /** Minter account for validation and non-SOL fees. */
+  buyer?: Signer;
+  /**
+   * Token Metadata program.
+   *
+   */
+
+  tokenMetadataProgram?: PublicKey | Pda;
+  /** SPL Token program. */
+  splTokenProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Instructions sysvar account.
+   *
+   */
+
+  sysvarInstructions?: PublicKey | Pda;
+  /**
+   * SlotHashes sysvar cluster data.
+   *
+   */
+
+  recentSlothashes?: PublicKey | Pda;
+  gumballEventAuthority?: PublicKey | Pda;
+};
+
+// Data.
+export type BuySpecificInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  mintArgs: Uint8Array;
+  group: Option<string>;
+};
+
+export type BuySpecificInstructionDataArgs = {
+  index: number;
+  mintArgs: Uint8Array;
+  group: OptionOrNullable<string>;
+};
+
+export function getBuySpecificInstructionDataSerializer(): Serializer<
+  BuySpecificInstructionDataArgs,
+  BuySpecificInstructionData
+> {
+  return mapSerializer<
+    BuySpecificInstructionDataArgs,
+    any,
+    BuySpecificInstructionData
+  >(
+    struct<BuySpecificInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['mintArgs', bytes({ size: u32() })],
+        ['group', option(string())],
+      ],
+      { description: 'BuySpecificInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [104, 61, 222, 76, 42, 124, 54, 20],
+    })
+  ) as Serializer<BuySpecificInstructionDataArgs, BuySpecificInstructionData>;
+}
+
+// Args.
+export type BuySpecificInstructionArgs = BuySpecificInstructionDataArgs;
+
+// Instruction.
+export function buySpecific(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: BuySpecificInstructionAccounts & BuySpecificInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'gumballGuard',
+    'GGRDy4ieS7ExrUu313QkszyuT9o3BvDLuc3H5VLgCpSF'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballGuard: {
+      index: 0,
+      isWritable: false,
+      value: input.gumballGuard ?? null,
+    },
+    gumballMachineProgram: {
+      index: 1,
+      isWritable: false,
+      value: input.gumballMachineProgram ?? null,
+    },
+    gumballMachine: {
+      index: 2,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    buyer: { index: 4, isWritable: true, value: input.buyer ?? null },
+    tokenMetadataProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.tokenMetadataProgram ?? null,
+    },
+    splTokenProgram: {
+      index: 6,
+      isWritable: false,
+      value: input.splTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 8,
+      isWritable: false,
+      value: input.sysvarInstructions ?? null,
+    },
+    recentSlothashes: {
+      index: 9,
+      isWritable: false,
+      value: input.recentSlothashes ?? null,
+    },
+    gumballEventAuthority: {
+      index: 10,
+      isWritable: false,
+      value: input.gumballEventAuthority ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: BuySpecificInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.gumballGuard.value) {
+    resolvedAccounts.gumballGuard.value = findGumballGuardPda(context, {
+      base: expectPublicKey(resolvedAccounts.gumballMachine.value),
+    });
+  }
+  if (!resolvedAccounts.gumballMachineProgram.value) {
+    resolvedAccounts.gumballMachineProgram.value =
+      context.programs.getPublicKey(
+        'mallowGumball',
+        'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+      );
+    resolvedAccounts.gumballMachineProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.buyer.value) {
+    resolvedAccounts.buyer.value = context.identity;
+  }
+  if (!resolvedAccounts.tokenMetadataProgram.value) {
+    resolvedAccounts.tokenMetadataProgram.value = context.programs.getPublicKey(
+      'mplTokenMetadata',
+      'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+    );
+    resolvedAccounts.tokenMetadataProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.splTokenProgram.value) {
+    resolvedAccounts.splTokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.splTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.recentSlothashes.value) {
+    resolvedAccounts.recentSlothashes.value = publicKey(
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.gumballEventAuthority.value) {
+    resolvedAccounts.gumballEventAuthority.value =
+      findEventAuthorityPda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBuySpecificInstructionDataSerializer().serialize(
+    resolvedArgs as BuySpecificInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEventAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BuySpecificFromGumballMachineInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball machine mint authority (mint only allowed for the mint_authority). */
+  mintAuthority?: Signer;
+  /**
+   * Buyer of the item.
+   *
+   */
+
+  buyer?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type BuySpecificFromGumballMachineInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+};
+
+export type BuySpecificFromGumballMachineInstructionDataArgs = {
+  index: number;
+};
+
+export function getBuySpecificFromGumballMachineInstructionDataSerializer(): Serializer<
+  BuySpecificFromGumballMachineInstructionDataArgs,
+  BuySpecificFromGumballMachineInstructionData
+> {
+  return mapSerializer<
+    BuySpecificFromGumballMachineInstructionDataArgs,
+    any,
+    BuySpecificFromGumballMachineInstructionData
+  >(
+    struct<BuySpecificFromGumballMachineInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+      ],
+      { description: 'BuySpecificFromGumballMachineInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [104, 61, 222, 76, 42, 124, 54, 20],
+    })
+  ) as Serializer<
+    BuySpecificFromGumballMachineInstructionDataArgs,
+    BuySpecificFromGumballMachineInstructionData
+  >;
+}
+
+// Args.
+export type BuySpecificFromGumballMachineInstructionArgs =
+  BuySpecificFromGumballMachineInstructionDataArgs;
+
+// Instruction.
+export function buySpecificFromGumballMachine(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: BuySpecificFromGumballMachineInstructionAccounts &
+    BuySpecificFromGumballMachineInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    mintAuthority: {
+      index: 1,
+      isWritable: false,
+      value: input.mintAuthority ?? null,
+    },
+    buyer: { index: 2, isWritable: false, value: input.buyer ?? null },
+    eventAuthority: {
+      index: 3,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 4, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: BuySpecificFromGumballMachineInstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.mintAuthority.value) {
+    resolvedAccounts.mintAuthority.value = context.identity;
+  }
+  if (!resolvedAccounts.buyer.value) {
+    resolvedAccounts.buyer.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getBuySpecificFromGumballMachineInstructionDataSerializer().serialize(
+      resolvedArgs as BuySpecificFromGumballMachineInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,244 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelPendingDrawInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Pending draw account. */
+  pendingDraw: PublicKey | Pda;
+  /**
+   * Payer of the pending draw account (receiver of the closed account rent).
+   *
+   */
+
+  payer?: PublicKey | Pda;
+  /**
+   * Buyer of the draw (receiver of the refund).
+   *
+   */
+
+  buyer: PublicKey | Pda;
+  /** Payer of the buyer's payment account if it needs to be created. */
+  feePayer?: Signer;
+  authorityPda?: PublicKey | Pda;
+  /** Payment account for authority pda if using token payment */
+  authorityPdaPaymentAccount?: PublicKey | Pda;
+  /** Buyer's token account if using token payment */
+  buyerPaymentAccount?: PublicKey | Pda;
+  /** Payment mint if using non-native payment token */
+  paymentMint?: PublicKey | Pda;
+  /**
+   * Randomness account of the provider used by the draw.
+   *
+   */
+
+  randomness: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CancelPendingDrawInstructionData = { discriminator: Array<number> };
+
+export type CancelPendingDrawInstructionDataArgs = {};
+
+export function getCancelPendingDrawInstructionDataSerializer(): Serializer<
+  CancelPendingDrawInstructionDataArgs,
+  CancelPendingDrawInstructionData
+> {
+  return mapSerializer<
+    CancelPendingDrawInstructionDataArgs,
+    any,
+    CancelPendingDrawInstructionData
+  >(
+    struct<CancelPendingDrawInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CancelPendingDrawInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [183, 30, 119, 130, 129, 34, 136, 78],
+    })
+  ) as Serializer<
+    CancelPendingDrawInstructionDataArgs,
+    CancelPendingDrawInstructionData
+  >;
+}
+
+// Instruction.
+export function cancelPendingDraw(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: CancelPendingDrawInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    pendingDraw: {
+      index: 1,
+      isWritable: true,
+      value: input.pendingDraw ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    buyer: { index: 3, isWritable: true, value: input.buyer ?? null },
+    feePayer: { index: 4, isWritable: true, value: input.feePayer ?? null },
+    authorityPda: {
+      index: 5,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authorityPdaPaymentAccount: {
+      index: 6,
+      isWritable: true,
+      value: input.authorityPdaPaymentAccount ?? null,
+    },
+    buyerPaymentAccount: {
+      index: 7,
+      isWritable: true,
+      value: input.buyerPaymentAccount ?? null,
+    },
+    paymentMint: {
+      index: 8,
+      isWritable: false,
+      value: input.paymentMint ?? null,
+    },
+    randomness: {
+      index: 9,
+      isWritable: false,
+      value: input.randomness ?? null,
+    },
+    tokenProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 11,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 12,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer.publicKey;
+  }
+  if (!resolvedAccounts.feePayer.value) {
+    resolvedAccounts.feePayer.value = context.payer;
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authorityPdaPaymentAccount.value) {
+    if (resolvedAccounts.paymentMint.value) {
+      resolvedAccounts.authorityPdaPaymentAccount.value =
+        findAssociatedTokenPda(context, {
+          mint: expectPublicKey(resolvedAccounts.paymentMint.value),
+          owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+        });
+    }
+  }
+  if (!resolvedAccounts.buyerPaymentAccount.value) {
+    if (resolvedAccounts.paymentMint.value) {
+      resolvedAccounts.buyerPaymentAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.paymentMint.value),
+          owner: expectPublicKey(resolvedAccounts.buyer.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'splAssociatedToken',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCancelPendingDrawInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,244 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findEventAuthorityPda,
+  findGumballMachineAuthorityPda,
+} from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CompressedNftArgs,
+  CompressedNftArgsArgs,
+  getCompressedNftArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type ClaimCompressedNftInstructionAccounts = {
+  /** Anyone can settle the sale */
+  payer?: Signer;
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Seller of the nft */
+  seller: PublicKey | Pda;
+  /** buyer of the nft */
+  buyer?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  treeConfig: PublicKey | Pda;
+  bubblegumProgram?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type ClaimCompressedNftInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  compressedNft: CompressedNftArgs;
+};
+
+export type ClaimCompressedNftInstructionDataArgs = {
+  index: number;
+  compressedNft: CompressedNftArgsArgs;
+};
+
+export function getClaimCompressedNftInstructionDataSerializer(): Serializer<
+  ClaimCompressedNftInstructionDataArgs,
+  ClaimCompressedNftInstructionData
+> {
+  return mapSerializer<
+    ClaimCompressedNftInstructionDataArgs,
+    any,
+    ClaimCompressedNftInstructionData
+  >(
+    struct<ClaimCompressedNftInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['compressedNft', getCompressedNftArgsSerializer()],
+      ],
+      { description: 'ClaimCompressedNftInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [245, 136, 184, 197, 35, 214, 68, 200],
+    })
+  ) as Serializer<
+    ClaimCompressedNftInstructionDataArgs,
+    ClaimCompressedNftInstructionData
+  >;
+}
+
+// Args.
+export type ClaimCompressedNftInstructionArgs =
+  ClaimCompressedNftInstructionDataArgs;
+
+// Instruction.
+export function claimCompressedNft(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: ClaimCompressedNftInstructionAccounts &
+    ClaimCompressedNftInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    gumballMachine: {
+      index: 1,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    seller: { index: 3, isWritable: true, value: input.seller ?? null },
+    buyer: { index: 4, isWritable: false, value: input.buyer ?? null },
+    merkleTree: { index: 5, isWritable: true, value: input.merkleTree ?? null },
+    treeConfig: {
+      index: 6,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 8,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 9,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    eventAuthority: {
+      index: 11,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 12, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: ClaimCompressedNftInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.buyer.value) {
+    resolvedAccounts.buyer.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'mplBubblegum',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'splNoop',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getClaimCompressedNftInstructionDataSerializer().serialize(
+    resolvedArgs as ClaimCompressedNftInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -52,6 +52,7 @@ export type ClaimTokensInstructionAccounts = {
   associatedTokenProgram?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   rent?: PublicKey | Pda;
+  /** Mint of the tokens (SPL Token or Token-2022) */
   mint: PublicKey | Pda;
   buyerTokenAccount?: PublicKey | Pda;
   authorityPdaTokenAccount?: PublicKey | Pda;
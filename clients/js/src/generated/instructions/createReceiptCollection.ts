@@ -0,0 +1,187 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreateReceiptCollectionInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /**
+   * Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+   * Pays for the collection account.
+   */
+
+  authority?: Signer;
+  /** Receipt collection account. */
+  receiptCollection: Signer;
+  mplCoreProgram?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateReceiptCollectionInstructionData = {
+  discriminator: Array<number>;
+  name: string;
+  uri: string;
+};
+
+export type CreateReceiptCollectionInstructionDataArgs = {
+  name: string;
+  uri: string;
+};
+
+export function getCreateReceiptCollectionInstructionDataSerializer(): Serializer<
+  CreateReceiptCollectionInstructionDataArgs,
+  CreateReceiptCollectionInstructionData
+> {
+  return mapSerializer<
+    CreateReceiptCollectionInstructionDataArgs,
+    any,
+    CreateReceiptCollectionInstructionData
+  >(
+    struct<CreateReceiptCollectionInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['name', string()],
+        ['uri', string()],
+      ],
+      { description: 'CreateReceiptCollectionInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [20, 251, 143, 103, 45, 143, 248, 64],
+    })
+  ) as Serializer<
+    CreateReceiptCollectionInstructionDataArgs,
+    CreateReceiptCollectionInstructionData
+  >;
+}
+
+// Args.
+export type CreateReceiptCollectionInstructionArgs =
+  CreateReceiptCollectionInstructionDataArgs;
+
+// Instruction.
+export function createReceiptCollection(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: CreateReceiptCollectionInstructionAccounts &
+    CreateReceiptCollectionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 1,
+      isWritable: false,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 2, isWritable: true, value: input.authority ?? null },
+    receiptCollection: {
+      index: 3,
+      isWritable: true,
+      value: input.receiptCollection ?? null,
+    },
+    mplCoreProgram: {
+      index: 4,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: CreateReceiptCollectionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCoreProgram',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateReceiptCollectionInstructionDataSerializer().serialize(
+    resolvedArgs as CreateReceiptCollectionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -28,7 +28,7 @@ import {
   u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { findEventAuthorityPda, findGumballGuardPda } from '../../hooked';
+import { findGumballGuardPda } from '../../hooked';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -74,7 +74,18 @@ export type DrawInstructionAccounts = {
    */
 
   recentSlothashes?: PublicKey | Pda;
-  gumballEventAuthority?: PublicKey | Pda;
+  /**
+   * Pending draw account.
+   *
+   */
+
+  pendingDraw: PublicKey | Pda;
+  /**
+   * Randomness account of the gumball machine's randomness provider.
+   *
+   */
+
+  randomness?: PublicKey | Pda;
 };
 
 // Data.
@@ -167,10 +178,15 @@ export function draw(
       isWritable: false,
       value: input.recentSlothashes ?? null,
     },
-    gumballEventAuthority: {
+    pendingDraw: {
       index: 10,
+      isWritable: true,
+      value: input.pendingDraw ?? null,
+    },
+    randomness: {
+      index: 11,
       isWritable: false,
-      value: input.gumballEventAuthority ?? null,
+      value: input.randomness ?? null,
     },
   };
 
@@ -228,10 +244,6 @@ export function draw(
       'SysvarS1otHashes111111111111111111111111111'
     );
   }
-  if (!resolvedAccounts.gumballEventAuthority.value) {
-    resolvedAccounts.gumballEventAuthority.value =
-      findEventAuthorityPda(context);
-  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
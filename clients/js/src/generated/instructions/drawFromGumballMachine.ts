@@ -12,7 +12,6 @@ import {
   PublicKey,
   Signer,
   TransactionBuilder,
-  publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
@@ -22,7 +21,6 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { findEventAuthorityPda } from '../../hooked';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -33,6 +31,8 @@ import {
 export type DrawFromGumballMachineInstructionAccounts = {
   /** Gumball machine account. */
   gumballMachine: PublicKey | Pda;
+  /** Pending draw account, closed once the draw is revealed. */
+  pendingDraw: PublicKey | Pda;
   /** Gumball machine mint authority (mint only allowed for the mint_authority). */
   mintAuthority?: Signer;
   /** Payer for the transaction and account allocation (rent). */
@@ -46,13 +46,12 @@ export type DrawFromGumballMachineInstructionAccounts = {
   /** System program. */
   systemProgram?: PublicKey | Pda;
   /**
-   * SlotHashes sysvar cluster data.
+   * Randomness account of the provider (required when the gumball machine uses a
+   * randomness provider).
    *
    */
 
-  recentSlothashes?: PublicKey | Pda;
-  eventAuthority?: PublicKey | Pda;
-  program?: PublicKey | Pda;
+  randomness?: PublicKey | Pda;
 };
 
 // Data.
@@ -87,7 +86,7 @@ export function getDrawFromGumballMachineInstructionDataSerializer(): Serializer
 
 // Instruction.
 export function drawFromGumballMachine(
-  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
   input: DrawFromGumballMachineInstructionAccounts
 ): TransactionBuilder {
   // Program ID.
@@ -103,29 +102,28 @@ export function drawFromGumballMachine(
       isWritable: true,
       value: input.gumballMachine ?? null,
     },
-    mintAuthority: {
+    pendingDraw: {
       index: 1,
+      isWritable: true,
+      value: input.pendingDraw ?? null,
+    },
+    mintAuthority: {
+      index: 2,
       isWritable: false,
       value: input.mintAuthority ?? null,
     },
-    payer: { index: 2, isWritable: true, value: input.payer ?? null },
-    buyer: { index: 3, isWritable: false, value: input.buyer ?? null },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    buyer: { index: 4, isWritable: false, value: input.buyer ?? null },
     systemProgram: {
-      index: 4,
-      isWritable: false,
-      value: input.systemProgram ?? null,
-    },
-    recentSlothashes: {
       index: 5,
       isWritable: false,
-      value: input.recentSlothashes ?? null,
+      value: input.systemProgram ?? null,
     },
-    eventAuthority: {
+    randomness: {
       index: 6,
       isWritable: false,
-      value: input.eventAuthority ?? null,
+      value: input.randomness ?? null,
     },
-    program: { index: 7, isWritable: false, value: input.program ?? null },
   };
 
   // Default values.
@@ -145,21 +143,6 @@ export function drawFromGumballMachine(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
-  if (!resolvedAccounts.recentSlothashes.value) {
-    resolvedAccounts.recentSlothashes.value = publicKey(
-      'SysvarS1otHashes111111111111111111111111111'
-    );
-  }
-  if (!resolvedAccounts.eventAuthority.value) {
-    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
-  }
-  if (!resolvedAccounts.program.value) {
-    resolvedAccounts.program.value = context.programs.getPublicKey(
-      'mallowGumball',
-      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
-    );
-    resolvedAccounts.program.isWritable = false;
-  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type DrawManyFromGumballMachineInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Pending draw account, closed once the draw is revealed. */
+  pendingDraw: PublicKey | Pda;
+  /** Gumball machine mint authority (mint only allowed for the mint_authority). */
+  mintAuthority?: Signer;
+  /** Payer for the transaction and account allocation (rent). */
+  payer?: Signer;
+  /**
+   * NFT account owner.
+   *
+   */
+
+  buyer?: PublicKey | Pda;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Randomness account of the provider (required when the gumball machine uses a
+   * randomness provider).
+   *
+   */
+
+  randomness?: PublicKey | Pda;
+};
+
+// Data.
+export type DrawManyFromGumballMachineInstructionData = {
+  discriminator: Array<number>;
+  quantity: number;
+};
+
+export type DrawManyFromGumballMachineInstructionDataArgs = {
+  quantity: number;
+};
+
+export function getDrawManyFromGumballMachineInstructionDataSerializer(): Serializer<
+  DrawManyFromGumballMachineInstructionDataArgs,
+  DrawManyFromGumballMachineInstructionData
+> {
+  return mapSerializer<
+    DrawManyFromGumballMachineInstructionDataArgs,
+    any,
+    DrawManyFromGumballMachineInstructionData
+  >(
+    struct<DrawManyFromGumballMachineInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['quantity', u16()],
+      ],
+      { description: 'DrawManyFromGumballMachineInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [44, 84, 107, 122, 142, 41, 2, 199],
+    })
+  ) as Serializer<
+    DrawManyFromGumballMachineInstructionDataArgs,
+    DrawManyFromGumballMachineInstructionData
+  >;
+}
+
+// Args.
+export type DrawManyFromGumballMachineInstructionArgs =
+  DrawManyFromGumballMachineInstructionDataArgs;
+
+// Instruction.
+export function drawManyFromGumballMachine(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: DrawManyFromGumballMachineInstructionAccounts &
+    DrawManyFromGumballMachineInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    pendingDraw: {
+      index: 1,
+      isWritable: true,
+      value: input.pendingDraw ?? null,
+    },
+    mintAuthority: {
+      index: 2,
+      isWritable: false,
+      value: input.mintAuthority ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    buyer: { index: 4, isWritable: false, value: input.buyer ?? null },
+    systemProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    randomness: {
+      index: 6,
+      isWritable: false,
+      value: input.randomness ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: DrawManyFromGumballMachineInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.mintAuthority.value) {
+    resolvedAccounts.mintAuthority.value = context.identity;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.buyer.value) {
+    resolvedAccounts.buyer.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getDrawManyFromGumballMachineInstructionDataSerializer().serialize(
+      resolvedArgs as DrawManyFromGumballMachineInstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
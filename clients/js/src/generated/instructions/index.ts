@@ -6,37 +6,73 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './addCompressedNft';
 export * from './addCoreAsset';
 export * from './addNft';
+export * from './addSolPrize';
 export * from './addTokens';
 export * from './approveAddItem';
+export * from './baseAddCoreAssets';
+export * from './baseClaimWithReceipt';
+export * from './baseCloseExpiredAddItemRequest';
+export * from './baseForceCloseGumballMachine';
+export * from './baseReclaimUnsoldItem';
+export * from './baseRejectAddItem';
+export * from './baseRemoveCoreAssetsSpan';
+export * from './baseRemoveNftsSpan';
 export * from './baseSettleCoreAssetSale';
 export * from './baseSettleNftSale';
+export * from './buySpecificFromGumballMachine';
 export * from './cancelAddCoreAssetRequest';
 export * from './cancelAddNftRequest';
+export * from './cancelPendingDraw';
+export * from './claimCompressedNft';
 export * from './claimCoreAsset';
 export * from './claimNft';
+export * from './claimSolPrize';
 export * from './claimTokens';
+export * from './createReceiptCollection';
 export * from './deleteGumballGuard';
 export * from './deleteGumballMachine';
 export * from './drawFromGumballMachine';
+export * from './drawManyFromGumballMachine';
 export * from './endSale';
 export * from './incrementTotalRevenue';
 export * from './initializeGumballMachine';
 export * from './manageBuyBackFunds';
+export * from './migrate';
+export * from './pauseGumballGuardSale';
+export * from './pauseSale';
+export * from './removeCompressedNft';
 export * from './removeCoreAsset';
 export * from './removeNft';
+export * from './removeSolPrize';
 export * from './removeTokens';
 export * from './removeTokensSpan';
 export * from './requestAddCoreAsset';
 export * from './requestAddNft';
+export * from './rerollFromGumballMachine';
+export * from './resizeCapacity';
+export * from './resumeGumballGuardSale';
+export * from './resumeSale';
+export * from './revealDraw';
 export * from './sellItemBack';
+export * from './setAddItemRequestExpiry';
+export * from './setClaimDeadline';
+export * from './setForceCloseGracePeriod';
 export * from './setGumballGuardAuthority';
 export * from './setGumballMachineAuthority';
+export * from './setInstantDelivery';
+export * from './setItemTiers';
+export * from './setItemWeights';
 export * from './setMintAuthority';
+export * from './setRandomnessConfig';
+export * from './setTierWeights';
+export * from './settleCompressedNftSale';
 export * from './settleTokensSale';
 export * from './settleTokensSaleClaimed';
 export * from './startSale';
+export * from './transferPrize';
 export * from './unwrap';
 export * from './updateSettings';
 export * from './wrap';
@@ -0,0 +1,110 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type PauseSaleInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This can be the mint authority or the authority. */
+  authority?: Signer;
+};
+
+// Data.
+export type PauseSaleInstructionData = { discriminator: Array<number> };
+
+export type PauseSaleInstructionDataArgs = {};
+
+export function getPauseSaleInstructionDataSerializer(): Serializer<
+  PauseSaleInstructionDataArgs,
+  PauseSaleInstructionData
+> {
+  return mapSerializer<
+    PauseSaleInstructionDataArgs,
+    any,
+    PauseSaleInstructionData
+  >(
+    struct<PauseSaleInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'PauseSaleInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [120, 107, 163, 108, 19, 201, 121, 223],
+    })
+  ) as Serializer<PauseSaleInstructionDataArgs, PauseSaleInstructionData>;
+}
+
+// Instruction.
+export function pauseSale(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: PauseSaleInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getPauseSaleInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,233 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CompressedNftArgs,
+  CompressedNftArgsArgs,
+  getCompressedNftArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type RemoveCompressedNftInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft) */
+  authority?: Signer;
+  seller?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  treeConfig: PublicKey | Pda;
+  bubblegumProgram?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveCompressedNftInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  compressedNft: CompressedNftArgs;
+};
+
+export type RemoveCompressedNftInstructionDataArgs = {
+  index: number;
+  compressedNft: CompressedNftArgsArgs;
+};
+
+export function getRemoveCompressedNftInstructionDataSerializer(): Serializer<
+  RemoveCompressedNftInstructionDataArgs,
+  RemoveCompressedNftInstructionData
+> {
+  return mapSerializer<
+    RemoveCompressedNftInstructionDataArgs,
+    any,
+    RemoveCompressedNftInstructionData
+  >(
+    struct<RemoveCompressedNftInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['compressedNft', getCompressedNftArgsSerializer()],
+      ],
+      { description: 'RemoveCompressedNftInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [254, 170, 251, 137, 221, 173, 55, 27],
+    })
+  ) as Serializer<
+    RemoveCompressedNftInstructionDataArgs,
+    RemoveCompressedNftInstructionData
+  >;
+}
+
+// Args.
+export type RemoveCompressedNftInstructionArgs =
+  RemoveCompressedNftInstructionDataArgs;
+
+// Instruction.
+export function removeCompressedNft(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: RemoveCompressedNftInstructionAccounts &
+    RemoveCompressedNftInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    sellerHistory: {
+      index: 1,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    seller: { index: 4, isWritable: true, value: input.seller ?? null },
+    merkleTree: { index: 5, isWritable: true, value: input.merkleTree ?? null },
+    treeConfig: {
+      index: 6,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 7,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 8,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 9,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: RemoveCompressedNftInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.seller.value) {
+    resolvedAccounts.seller.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'mplBubblegum',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'splNoop',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveCompressedNftInstructionDataSerializer().serialize(
+    resolvedArgs as RemoveCompressedNftInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
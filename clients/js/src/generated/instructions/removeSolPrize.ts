@@ -0,0 +1,183 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballMachineAuthorityPda } from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemoveSolPrizeInstructionAccounts = {
+  /** Gumball Machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Authority allowed to remove the prizes (must be the gumball machine auth or the seller of the prizes) */
+  authority?: Signer;
+  seller?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveSolPrizeInstructionData = {
+  discriminator: Array<number>;
+  amount: bigint;
+  startIndex: number;
+  endIndex: number;
+};
+
+export type RemoveSolPrizeInstructionDataArgs = {
+  amount: number | bigint;
+  startIndex: number;
+  endIndex: number;
+};
+
+export function getRemoveSolPrizeInstructionDataSerializer(): Serializer<
+  RemoveSolPrizeInstructionDataArgs,
+  RemoveSolPrizeInstructionData
+> {
+  return mapSerializer<
+    RemoveSolPrizeInstructionDataArgs,
+    any,
+    RemoveSolPrizeInstructionData
+  >(
+    struct<RemoveSolPrizeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['amount', u64()],
+        ['startIndex', u32()],
+        ['endIndex', u32()],
+      ],
+      { description: 'RemoveSolPrizeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [250, 86, 121, 237, 139, 93, 159, 33],
+    })
+  ) as Serializer<
+    RemoveSolPrizeInstructionDataArgs,
+    RemoveSolPrizeInstructionData
+  >;
+}
+
+// Args.
+export type RemoveSolPrizeInstructionArgs = RemoveSolPrizeInstructionDataArgs;
+
+// Instruction.
+export function removeSolPrize(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: RemoveSolPrizeInstructionAccounts & RemoveSolPrizeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    sellerHistory: {
+      index: 1,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authority: { index: 3, isWritable: false, value: input.authority ?? null },
+    seller: { index: 4, isWritable: true, value: input.seller ?? null },
+    systemProgram: {
+      index: 5,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: RemoveSolPrizeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.seller.value) {
+    resolvedAccounts.seller.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRemoveSolPrizeInstructionDataSerializer().serialize(
+    resolvedArgs as RemoveSolPrizeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -44,6 +44,7 @@ export type RemoveTokensSpanInstructionAccounts = {
   /** Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft) */
   authority?: Signer;
   seller?: PublicKey | Pda;
+  /** Mint of the tokens (SPL Token or Token-2022) */
   mint: PublicKey | Pda;
   tokenAccount?: PublicKey | Pda;
   authorityPdaTokenAccount?: PublicKey | Pda;
@@ -0,0 +1,148 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ResizeCapacityInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /**
+   * Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+   * Pays for (or receives) the rent difference.
+   */
+
+  authority?: Signer;
+  /** System program. */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ResizeCapacityInstructionData = {
+  discriminator: Array<number>;
+  itemCapacity: bigint;
+};
+
+export type ResizeCapacityInstructionDataArgs = {
+  itemCapacity: number | bigint;
+};
+
+export function getResizeCapacityInstructionDataSerializer(): Serializer<
+  ResizeCapacityInstructionDataArgs,
+  ResizeCapacityInstructionData
+> {
+  return mapSerializer<
+    ResizeCapacityInstructionDataArgs,
+    any,
+    ResizeCapacityInstructionData
+  >(
+    struct<ResizeCapacityInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['itemCapacity', u64()],
+      ],
+      { description: 'ResizeCapacityInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [136, 178, 234, 53, 247, 141, 31, 192],
+    })
+  ) as Serializer<
+    ResizeCapacityInstructionDataArgs,
+    ResizeCapacityInstructionData
+  >;
+}
+
+// Args.
+export type ResizeCapacityInstructionArgs = ResizeCapacityInstructionDataArgs;
+
+// Instruction.
+export function resizeCapacity(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: ResizeCapacityInstructionAccounts & ResizeCapacityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: true, value: input.authority ?? null },
+    systemProgram: {
+      index: 2,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: ResizeCapacityInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getResizeCapacityInstructionDataSerializer().serialize(
+    resolvedArgs as ResizeCapacityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
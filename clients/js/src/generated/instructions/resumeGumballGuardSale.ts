@@ -0,0 +1,142 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findGumballGuardPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ResumeGumballGuardSaleInstructionAccounts = {
+  gumballGuard?: PublicKey | Pda;
+  authority?: Signer;
+  gumballMachine: PublicKey | Pda;
+  gumballMachineProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ResumeGumballGuardSaleInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type ResumeGumballGuardSaleInstructionDataArgs = {};
+
+export function getResumeGumballGuardSaleInstructionDataSerializer(): Serializer<
+  ResumeGumballGuardSaleInstructionDataArgs,
+  ResumeGumballGuardSaleInstructionData
+> {
+  return mapSerializer<
+    ResumeGumballGuardSaleInstructionDataArgs,
+    any,
+    ResumeGumballGuardSaleInstructionData
+  >(
+    struct<ResumeGumballGuardSaleInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'ResumeGumballGuardSaleInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [222, 242, 38, 239, 148, 224, 167, 188],
+    })
+  ) as Serializer<
+    ResumeGumballGuardSaleInstructionDataArgs,
+    ResumeGumballGuardSaleInstructionData
+  >;
+}
+
+// Instruction.
+export function resumeGumballGuardSale(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: ResumeGumballGuardSaleInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'gumballGuard',
+    'GGRDy4ieS7ExrUu313QkszyuT9o3BvDLuc3H5VLgCpSF'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballGuard: {
+      index: 0,
+      isWritable: false,
+      value: input.gumballGuard ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+    gumballMachine: {
+      index: 2,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    gumballMachineProgram: {
+      index: 3,
+      isWritable: false,
+      value: input.gumballMachineProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.gumballGuard.value) {
+    resolvedAccounts.gumballGuard.value = findGumballGuardPda(context, {
+      base: expectPublicKey(resolvedAccounts.gumballMachine.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.gumballMachineProgram.value) {
+    resolvedAccounts.gumballMachineProgram.value =
+      context.programs.getPublicKey(
+        'mallowGumball',
+        'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+      );
+    resolvedAccounts.gumballMachineProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getResumeGumballGuardSaleInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
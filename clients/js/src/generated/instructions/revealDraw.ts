@@ -0,0 +1,168 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEventAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevealDrawInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Pending draw account. */
+  pendingDraw: PublicKey | Pda;
+  /**
+   * Payer of the pending draw account (receiver of the closed account rent).
+   *
+   */
+
+  payer?: PublicKey | Pda;
+  /**
+   * SlotHashes sysvar cluster data.
+   *
+   */
+
+  recentSlothashes?: PublicKey | Pda;
+  /**
+   * Randomness account of the provider (required when the draw uses a randomness
+   * provider).
+   *
+   */
+
+  randomness?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type RevealDrawInstructionData = { discriminator: Array<number> };
+
+export type RevealDrawInstructionDataArgs = {};
+
+export function getRevealDrawInstructionDataSerializer(): Serializer<
+  RevealDrawInstructionDataArgs,
+  RevealDrawInstructionData
+> {
+  return mapSerializer<
+    RevealDrawInstructionDataArgs,
+    any,
+    RevealDrawInstructionData
+  >(
+    struct<RevealDrawInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'RevealDrawInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [13, 149, 75, 226, 184, 105, 114, 48],
+    })
+  ) as Serializer<RevealDrawInstructionDataArgs, RevealDrawInstructionData>;
+}
+
+// Instruction.
+export function revealDraw(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RevealDrawInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    pendingDraw: {
+      index: 1,
+      isWritable: true,
+      value: input.pendingDraw ?? null,
+    },
+    payer: { index: 2, isWritable: true, value: input.payer ?? null },
+    recentSlothashes: {
+      index: 3,
+      isWritable: false,
+      value: input.recentSlothashes ?? null,
+    },
+    randomness: {
+      index: 4,
+      isWritable: false,
+      value: input.randomness ?? null,
+    },
+    eventAuthority: {
+      index: 5,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 6, isWritable: false, value: input.program ?? null },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer.publicKey;
+  }
+  if (!resolvedAccounts.recentSlothashes.value) {
+    resolvedAccounts.recentSlothashes.value = publicKey(
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevealDrawInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -14,6 +14,8 @@ import {
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -25,6 +27,7 @@ import {
   Serializer,
   array,
   mapSerializer,
+  option,
   struct,
   u32,
   u64,
@@ -40,6 +43,11 @@ import {
   expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  CompressedNftArgs,
+  CompressedNftArgsArgs,
+  getCompressedNftArgsSerializer,
+} from '../types';
 
 // Accounts.
 export type SellItemBackInstructionAccounts = {
@@ -105,6 +113,14 @@ export type SellItemBackInstructionAccounts = {
   authRules?: PublicKey | Pda;
   instructions?: PublicKey | Pda;
   authRulesProgram?: PublicKey | Pda;
+  /** OPTIONAL TOKEN-2022 ACCOUNTS - only required if selling Token-2022 tokens */
+  token2022Program?: PublicKey | Pda;
+  /** OPTIONAL COMPRESSED NFT ACCOUNTS - only required if selling compressed NFT */
+  merkleTree?: PublicKey | Pda;
+  treeConfig?: PublicKey | Pda;
+  bubblegumProgram?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
   eventAuthority?: PublicKey | Pda;
   program?: PublicKey | Pda;
 };
@@ -115,12 +131,14 @@ export type SellItemBackInstructionData = {
   index: number;
   amount: bigint;
   buyPrice: bigint;
+  compressedNft: Option<CompressedNftArgs>;
 };
 
 export type SellItemBackInstructionDataArgs = {
   index: number;
   amount: number | bigint;
   buyPrice: number | bigint;
+  compressedNft: OptionOrNullable<CompressedNftArgsArgs>;
 };
 
 export function getSellItemBackInstructionDataSerializer(): Serializer<
@@ -138,6 +156,7 @@ export function getSellItemBackInstructionDataSerializer(): Serializer<
         ['index', u32()],
         ['amount', u64()],
         ['buyPrice', u64()],
+        ['compressedNft', option(getCompressedNftArgsSerializer())],
       ],
       { description: 'SellItemBackInstructionData' }
     ),
@@ -277,12 +296,42 @@ export function sellItemBack(
       isWritable: false,
       value: input.authRulesProgram ?? null,
     },
-    eventAuthority: {
+    token2022Program: {
       index: 29,
       isWritable: false,
+      value: input.token2022Program ?? null,
+    },
+    merkleTree: {
+      index: 30,
+      isWritable: true,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 31,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 32,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 33,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 34,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    eventAuthority: {
+      index: 35,
+      isWritable: false,
       value: input.eventAuthority ?? null,
     },
-    program: { index: 30, isWritable: false, value: input.program ?? null },
+    program: { index: 36, isWritable: false, value: input.program ?? null },
   };
 
   // Arguments.
@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetAddItemRequestExpiryInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetAddItemRequestExpiryInstructionData = {
+  discriminator: Array<number>;
+  expiry: bigint;
+};
+
+export type SetAddItemRequestExpiryInstructionDataArgs = {
+  expiry: number | bigint;
+};
+
+export function getSetAddItemRequestExpiryInstructionDataSerializer(): Serializer<
+  SetAddItemRequestExpiryInstructionDataArgs,
+  SetAddItemRequestExpiryInstructionData
+> {
+  return mapSerializer<
+    SetAddItemRequestExpiryInstructionDataArgs,
+    any,
+    SetAddItemRequestExpiryInstructionData
+  >(
+    struct<SetAddItemRequestExpiryInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['expiry', i64()],
+      ],
+      { description: 'SetAddItemRequestExpiryInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [142, 38, 239, 31, 127, 145, 180, 8],
+    })
+  ) as Serializer<
+    SetAddItemRequestExpiryInstructionDataArgs,
+    SetAddItemRequestExpiryInstructionData
+  >;
+}
+
+// Args.
+export type SetAddItemRequestExpiryInstructionArgs =
+  SetAddItemRequestExpiryInstructionDataArgs;
+
+// Instruction.
+export function setAddItemRequestExpiry(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetAddItemRequestExpiryInstructionAccounts &
+    SetAddItemRequestExpiryInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetAddItemRequestExpiryInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetAddItemRequestExpiryInstructionDataSerializer().serialize(
+    resolvedArgs as SetAddItemRequestExpiryInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,140 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  UnclaimedItemDestination,
+  UnclaimedItemDestinationArgs,
+  getUnclaimedItemDestinationSerializer,
+} from '../types';
+
+// Accounts.
+export type SetClaimDeadlineInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetClaimDeadlineInstructionData = {
+  discriminator: Array<number>;
+  /** Seconds after the sale ends that drawn items can be claimed, 0 means there is no deadline */
+  claimPeriod: bigint;
+  /** Receiver of the items that were not claimed before the deadline */
+  destination: UnclaimedItemDestination;
+};
+
+export type SetClaimDeadlineInstructionDataArgs = {
+  /** Seconds after the sale ends that drawn items can be claimed, 0 means there is no deadline */
+  claimPeriod: number | bigint;
+  /** Receiver of the items that were not claimed before the deadline */
+  destination: UnclaimedItemDestinationArgs;
+};
+
+export function getSetClaimDeadlineInstructionDataSerializer(): Serializer<
+  SetClaimDeadlineInstructionDataArgs,
+  SetClaimDeadlineInstructionData
+> {
+  return mapSerializer<
+    SetClaimDeadlineInstructionDataArgs,
+    any,
+    SetClaimDeadlineInstructionData
+  >(
+    struct<SetClaimDeadlineInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['claimPeriod', i64()],
+        ['destination', getUnclaimedItemDestinationSerializer()],
+      ],
+      { description: 'SetClaimDeadlineInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [95, 193, 53, 36, 33, 162, 149, 8] })
+  ) as Serializer<
+    SetClaimDeadlineInstructionDataArgs,
+    SetClaimDeadlineInstructionData
+  >;
+}
+
+// Args.
+export type SetClaimDeadlineInstructionArgs =
+  SetClaimDeadlineInstructionDataArgs;
+
+// Instruction.
+export function setClaimDeadline(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetClaimDeadlineInstructionAccounts & SetClaimDeadlineInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetClaimDeadlineInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetClaimDeadlineInstructionDataSerializer().serialize(
+    resolvedArgs as SetClaimDeadlineInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
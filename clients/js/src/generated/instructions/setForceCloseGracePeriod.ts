@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetForceCloseGracePeriodInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetForceCloseGracePeriodInstructionData = {
+  discriminator: Array<number>;
+  gracePeriod: bigint;
+};
+
+export type SetForceCloseGracePeriodInstructionDataArgs = {
+  gracePeriod: number | bigint;
+};
+
+export function getSetForceCloseGracePeriodInstructionDataSerializer(): Serializer<
+  SetForceCloseGracePeriodInstructionDataArgs,
+  SetForceCloseGracePeriodInstructionData
+> {
+  return mapSerializer<
+    SetForceCloseGracePeriodInstructionDataArgs,
+    any,
+    SetForceCloseGracePeriodInstructionData
+  >(
+    struct<SetForceCloseGracePeriodInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['gracePeriod', i64()],
+      ],
+      { description: 'SetForceCloseGracePeriodInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [140, 140, 105, 1, 186, 19, 190, 3],
+    })
+  ) as Serializer<
+    SetForceCloseGracePeriodInstructionDataArgs,
+    SetForceCloseGracePeriodInstructionData
+  >;
+}
+
+// Args.
+export type SetForceCloseGracePeriodInstructionArgs =
+  SetForceCloseGracePeriodInstructionDataArgs;
+
+// Instruction.
+export function setForceCloseGracePeriod(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetForceCloseGracePeriodInstructionAccounts &
+    SetForceCloseGracePeriodInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetForceCloseGracePeriodInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetForceCloseGracePeriodInstructionDataSerializer().serialize(
+    resolvedArgs as SetForceCloseGracePeriodInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
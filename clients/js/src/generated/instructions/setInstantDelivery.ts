@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetInstantDeliveryInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetInstantDeliveryInstructionData = {
+  discriminator: Array<number>;
+  instantDelivery: boolean;
+};
+
+export type SetInstantDeliveryInstructionDataArgs = {
+  instantDelivery: boolean;
+};
+
+export function getSetInstantDeliveryInstructionDataSerializer(): Serializer<
+  SetInstantDeliveryInstructionDataArgs,
+  SetInstantDeliveryInstructionData
+> {
+  return mapSerializer<
+    SetInstantDeliveryInstructionDataArgs,
+    any,
+    SetInstantDeliveryInstructionData
+  >(
+    struct<SetInstantDeliveryInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['instantDelivery', bool()],
+      ],
+      { description: 'SetInstantDeliveryInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [156, 204, 217, 199, 194, 208, 180, 98],
+    })
+  ) as Serializer<
+    SetInstantDeliveryInstructionDataArgs,
+    SetInstantDeliveryInstructionData
+  >;
+}
+
+// Args.
+export type SetInstantDeliveryInstructionArgs =
+  SetInstantDeliveryInstructionDataArgs;
+
+// Instruction.
+export function setInstantDelivery(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetInstantDeliveryInstructionAccounts &
+    SetInstantDeliveryInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetInstantDeliveryInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetInstantDeliveryInstructionDataSerializer().serialize(
+    resolvedArgs as SetInstantDeliveryInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,133 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetItemTiersInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetItemTiersInstructionData = {
+  discriminator: Array<number>;
+  startIndex: number;
+  endIndex: number;
+  tier: number;
+};
+
+export type SetItemTiersInstructionDataArgs = {
+  startIndex: number;
+  endIndex: number;
+  tier: number;
+};
+
+export function getSetItemTiersInstructionDataSerializer(): Serializer<
+  SetItemTiersInstructionDataArgs,
+  SetItemTiersInstructionData
+> {
+  return mapSerializer<
+    SetItemTiersInstructionDataArgs,
+    any,
+    SetItemTiersInstructionData
+  >(
+    struct<SetItemTiersInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['startIndex', u32()],
+        ['endIndex', u32()],
+        ['tier', u8()],
+      ],
+      { description: 'SetItemTiersInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [187, 121, 49, 253, 34, 182, 125, 40],
+    })
+  ) as Serializer<SetItemTiersInstructionDataArgs, SetItemTiersInstructionData>;
+}
+
+// Args.
+export type SetItemTiersInstructionArgs = SetItemTiersInstructionDataArgs;
+
+// Instruction.
+export function setItemTiers(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetItemTiersInstructionAccounts & SetItemTiersInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetItemTiersInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetItemTiersInstructionDataSerializer().serialize(
+    resolvedArgs as SetItemTiersInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,136 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetItemWeightsInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetItemWeightsInstructionData = {
+  discriminator: Array<number>;
+  startIndex: number;
+  endIndex: number;
+  weight: number;
+};
+
+export type SetItemWeightsInstructionDataArgs = {
+  startIndex: number;
+  endIndex: number;
+  weight: number;
+};
+
+export function getSetItemWeightsInstructionDataSerializer(): Serializer<
+  SetItemWeightsInstructionDataArgs,
+  SetItemWeightsInstructionData
+> {
+  return mapSerializer<
+    SetItemWeightsInstructionDataArgs,
+    any,
+    SetItemWeightsInstructionData
+  >(
+    struct<SetItemWeightsInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['startIndex', u32()],
+        ['endIndex', u32()],
+        ['weight', u32()],
+      ],
+      { description: 'SetItemWeightsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [205, 111, 171, 76, 106, 220, 82, 153],
+    })
+  ) as Serializer<
+    SetItemWeightsInstructionDataArgs,
+    SetItemWeightsInstructionData
+  >;
+}
+
+// Args.
+export type SetItemWeightsInstructionArgs = SetItemWeightsInstructionDataArgs;
+
+// Instruction.
+export function setItemWeights(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetItemWeightsInstructionAccounts & SetItemWeightsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetItemWeightsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetItemWeightsInstructionDataSerializer().serialize(
+    resolvedArgs as SetItemWeightsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetRandomnessConfigInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetRandomnessConfigInstructionData = {
+  discriminator: Array<number>;
+  /** Program that owns the randomness accounts, the default pubkey means slot hashes are used */
+  provider: PublicKey;
+  /** Offset of the 32 bytes random value in the randomness account data */
+  valueOffset: number;
+  /** Offset of the slot (u64) at which the random value was produced in the randomness account data */
+  slotOffset: number;
+  /** Randomness account of the provider used by every draw */
+  account: PublicKey;
+  /** Discriminator of the randomness account (first 8 bytes of its data) */
+  discriminator: Uint8Array;
+  /** Offset of the slot (u64) at which the current value was requested in the randomness account data */
+  requestSlotOffset: number;
+};
+
+export type SetRandomnessConfigInstructionDataArgs = {
+  /** Program that owns the randomness accounts, the default pubkey means slot hashes are used */
+  provider: PublicKey;
+  /** Offset of the 32 bytes random value in the randomness account data */
+  valueOffset: number;
+  /** Offset of the slot (u64) at which the random value was produced in the randomness account data */
+  slotOffset: number;
+  /** Randomness account of the provider used by every draw */
+  account: PublicKey;
+  /** Discriminator of the randomness account (first 8 bytes of its data) */
+  discriminator: Uint8Array;
+  /** Offset of the slot (u64) at which the current value was requested in the randomness account data */
+  requestSlotOffset: number;
+};
+
+export function getSetRandomnessConfigInstructionDataSerializer(): Serializer<
+  SetRandomnessConfigInstructionDataArgs,
+  SetRandomnessConfigInstructionData
+> {
+  return mapSerializer<
+    SetRandomnessConfigInstructionDataArgs,
+    any,
+    SetRandomnessConfigInstructionData
+  >(
+    struct<SetRandomnessConfigInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['provider', publicKeySerializer()],
+        ['valueOffset', u32()],
+        ['slotOffset', u32()],
+        ['account', publicKeySerializer()],
+        ['discriminator', bytes({ size: 8 })],
+        ['requestSlotOffset', u32()],
+      ],
+      { description: 'SetRandomnessConfigInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [49, 16, 108, 232, 247, 227, 240, 161],
+    })
+  ) as Serializer<
+    SetRandomnessConfigInstructionDataArgs,
+    SetRandomnessConfigInstructionData
+  >;
+}
+
+// Args.
+export type SetRandomnessConfigInstructionArgs =
+  SetRandomnessConfigInstructionDataArgs;
+
+// Instruction.
+export function setRandomnessConfig(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetRandomnessConfigInstructionAccounts &
+    SetRandomnessConfigInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetRandomnessConfigInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetRandomnessConfigInstructionDataSerializer().serialize(
+    resolvedArgs as SetRandomnessConfigInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
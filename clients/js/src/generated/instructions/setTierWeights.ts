@@ -0,0 +1,128 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetTierWeightsInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Gumball Machine authority. This is the address that controls the upate of the gumball machine. */
+  authority?: Signer;
+};
+
+// Data.
+export type SetTierWeightsInstructionData = {
+  discriminator: Array<number>;
+  weights: Array<number>;
+};
+
+export type SetTierWeightsInstructionDataArgs = { weights: Array<number> };
+
+export function getSetTierWeightsInstructionDataSerializer(): Serializer<
+  SetTierWeightsInstructionDataArgs,
+  SetTierWeightsInstructionData
+> {
+  return mapSerializer<
+    SetTierWeightsInstructionDataArgs,
+    any,
+    SetTierWeightsInstructionData
+  >(
+    struct<SetTierWeightsInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['weights', array(u16(), { size: 8 })],
+      ],
+      { description: 'SetTierWeightsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [167, 14, 10, 46, 131, 173, 114, 171],
+    })
+  ) as Serializer<
+    SetTierWeightsInstructionDataArgs,
+    SetTierWeightsInstructionData
+  >;
+}
+
+// Args.
+export type SetTierWeightsInstructionArgs = SetTierWeightsInstructionDataArgs;
+
+// Instruction.
+export function setTierWeights(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetTierWeightsInstructionAccounts & SetTierWeightsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authority: { index: 1, isWritable: false, value: input.authority ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SetTierWeightsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetTierWeightsInstructionDataSerializer().serialize(
+    resolvedArgs as SetTierWeightsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,413 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findEventAuthorityPda,
+  findGumballMachineAuthorityPda,
+} from '../../hooked';
+import { findSellerHistoryPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CompressedNftArgs,
+  CompressedNftArgsArgs,
+  Creator,
+  CreatorArgs,
+  getCompressedNftArgsSerializer,
+  getCreatorSerializer,
+} from '../types';
+
+// Accounts.
+export type SettleCompressedNftSaleInstructionAccounts = {
+  /** Anyone can settle the sale */
+  payer?: Signer;
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  /** Payment account for authority pda if using token payment */
+  authorityPdaPaymentAccount?: PublicKey | Pda;
+  /** Seller of the nft */
+  authority?: PublicKey | Pda;
+  /** Payment account for authority if using token payment */
+  authorityPaymentAccount?: PublicKey | Pda;
+  /** Seller of the nft */
+  seller: PublicKey | Pda;
+  /** Payment account for seller if using token payment */
+  sellerPaymentAccount?: PublicKey | Pda;
+  /** Seller history account. */
+  sellerHistory?: PublicKey | Pda;
+  /** buyer of the nft */
+  buyer?: PublicKey | Pda;
+  /** Fee account for marketplace fee if using fee config */
+  feeAccount?: PublicKey | Pda;
+  /** Payment account for marketplace fee if using token payment */
+  feePaymentAccount?: PublicKey | Pda;
+  /** Payment mint if using non-native payment token */
+  paymentMint?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  rent?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  treeConfig: PublicKey | Pda;
+  bubblegumProgram?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type SettleCompressedNftSaleInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  /** Current state of the leaf. */
+  compressedNft: CompressedNftArgs;
+  /** Current owner of the leaf, or the default pubkey if it has been burnt. */
+  leafOwner: PublicKey;
+  /** Current delegate of the leaf. */
+  leafDelegate: PublicKey;
+  /** Metadata hash used to verify the data hash. */
+  metadataHash: Uint8Array;
+  sellerFeeBasisPoints: number;
+  /** Creators used to verify the creator hash. */
+  creators: Array<Creator>;
+  /** Number of proof nodes at the start of the remaining accounts. */
+  proofLength: number;
+};
+
+export type SettleCompressedNftSaleInstructionDataArgs = {
+  index: number;
+  /** Current state of the leaf. */
+  compressedNft: CompressedNftArgsArgs;
+  /** Current owner of the leaf, or the default pubkey if it has been burnt. */
+  leafOwner: PublicKey;
+  /** Current delegate of the leaf. */
+  leafDelegate: PublicKey;
+  /** Metadata hash used to verify the data hash. */
+  metadataHash: Uint8Array;
+  sellerFeeBasisPoints: number;
+  /** Creators used to verify the creator hash. */
+  creators: Array<CreatorArgs>;
+  /** Number of proof nodes at the start of the remaining accounts. */
+  proofLength: number;
+};
+
+export function getSettleCompressedNftSaleInstructionDataSerializer(): Serializer<
+  SettleCompressedNftSaleInstructionDataArgs,
+  SettleCompressedNftSaleInstructionData
+> {
+  return mapSerializer<
+    SettleCompressedNftSaleInstructionDataArgs,
+    any,
+    SettleCompressedNftSaleInstructionData
+  >(
+    struct<SettleCompressedNftSaleInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['compressedNft', getCompressedNftArgsSerializer()],
+        ['leafOwner', publicKeySerializer()],
+        ['leafDelegate', publicKeySerializer()],
+        ['metadataHash', bytes({ size: 32 })],
+        ['sellerFeeBasisPoints', u16()],
+        ['creators', array(getCreatorSerializer())],
+        ['proofLength', u8()],
+      ],
+      { description: 'SettleCompressedNftSaleInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [5, 88, 209, 87, 168, 230, 65, 211],
+    })
+  ) as Serializer<
+    SettleCompressedNftSaleInstructionDataArgs,
+    SettleCompressedNftSaleInstructionData
+  >;
+}
+
+// Args.
+export type SettleCompressedNftSaleInstructionArgs =
+  SettleCompressedNftSaleInstructionDataArgs;
+
+// Instruction.
+export function settleCompressedNftSale(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: SettleCompressedNftSaleInstructionAccounts &
+    SettleCompressedNftSaleInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    gumballMachine: {
+      index: 1,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    authorityPda: {
+      index: 2,
+      isWritable: true,
+      value: input.authorityPda ?? null,
+    },
+    authorityPdaPaymentAccount: {
+      index: 3,
+      isWritable: true,
+      value: input.authorityPdaPaymentAccount ?? null,
+    },
+    authority: { index: 4, isWritable: true, value: input.authority ?? null },
+    authorityPaymentAccount: {
+      index: 5,
+      isWritable: true,
+      value: input.authorityPaymentAccount ?? null,
+    },
+    seller: { index: 6, isWritable: true, value: input.seller ?? null },
+    sellerPaymentAccount: {
+      index: 7,
+      isWritable: true,
+      value: input.sellerPaymentAccount ?? null,
+    },
+    sellerHistory: {
+      index: 8,
+      isWritable: true,
+      value: input.sellerHistory ?? null,
+    },
+    buyer: { index: 9, isWritable: false, value: input.buyer ?? null },
+    feeAccount: {
+      index: 10,
+      isWritable: true,
+      value: input.feeAccount ?? null,
+    },
+    feePaymentAccount: {
+      index: 11,
+      isWritable: true,
+      value: input.feePaymentAccount ?? null,
+    },
+    paymentMint: {
+      index: 12,
+      isWritable: false,
+      value: input.paymentMint ?? null,
+    },
+    tokenProgram: {
+      index: 13,
+      isWritable: false,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 14,
+      isWritable: false,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 15,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    rent: { index: 16, isWritable: false, value: input.rent ?? null },
+    merkleTree: {
+      index: 17,
+      isWritable: true,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 18,
+      isWritable: false,
+      value: input.treeConfig ?? null,
+    },
+    bubblegumProgram: {
+      index: 19,
+      isWritable: false,
+      value: input.bubblegumProgram ?? null,
+    },
+    compressionProgram: {
+      index: 20,
+      isWritable: false,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 21,
+      isWritable: false,
+      value: input.logWrapper ?? null,
+    },
+    eventAuthority: {
+      index: 22,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 23, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: SettleCompressedNftSaleInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda.value = findGumballMachineAuthorityPda(
+      context,
+      { gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value) }
+    );
+  }
+  if (!resolvedAccounts.authorityPdaPaymentAccount.value) {
+    if (resolvedAccounts.paymentMint.value) {
+      resolvedAccounts.authorityPdaPaymentAccount.value =
+        findAssociatedTokenPda(context, {
+          mint: expectPublicKey(resolvedAccounts.paymentMint.value),
+          owner: expectPublicKey(resolvedAccounts.authorityPda.value),
+        });
+    }
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.authorityPaymentAccount.value) {
+    if (resolvedAccounts.paymentMint.value) {
+      resolvedAccounts.authorityPaymentAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.paymentMint.value),
+          owner: expectPublicKey(resolvedAccounts.authority.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.sellerPaymentAccount.value) {
+    if (resolvedAccounts.paymentMint.value) {
+      resolvedAccounts.sellerPaymentAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.paymentMint.value),
+          owner: expectPublicKey(resolvedAccounts.seller.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.sellerHistory.value) {
+    resolvedAccounts.sellerHistory.value = findSellerHistoryPda(context, {
+      gumballMachine: expectPublicKey(resolvedAccounts.gumballMachine.value),
+      seller: expectPublicKey(resolvedAccounts.seller.value),
+    });
+  }
+  if (!resolvedAccounts.buyer.value) {
+    resolvedAccounts.buyer.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value =
+      context.programs.getPublicKey(
+        'splAssociatedToken',
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+      );
+    resolvedAccounts.associatedTokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'mplBubblegum',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'splNoop',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSettleCompressedNftSaleInstructionDataSerializer().serialize(
+    resolvedArgs as SettleCompressedNftSaleInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -68,8 +68,12 @@ export type SettleTokensSaleInstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   rent?: PublicKey | Pda;
   mint: PublicKey | Pda;
+  /** Receiver token account, unused for native SOL prizes */
   receiverTokenAccount: PublicKey | Pda;
+  /** Authority PDA token account, unused for native SOL prizes */
   authorityPdaTokenAccount?: PublicKey | Pda;
+  /** Token-2022 program, only required if the item is a Token-2022 token */
+  token2022Program?: PublicKey | Pda;
   eventAuthority?: PublicKey | Pda;
   program?: PublicKey | Pda;
 };
@@ -158,7 +162,7 @@ export function settleTokensSale(
       isWritable: true,
       value: input.sellerHistory ?? null,
     },
-    buyer: { index: 9, isWritable: false, value: input.buyer ?? null },
+    buyer: { index: 9, isWritable: true, value: input.buyer ?? null },
     feeAccount: {
       index: 10,
       isWritable: true,
@@ -201,12 +205,17 @@ export function settleTokensSale(
       isWritable: true,
       value: input.authorityPdaTokenAccount ?? null,
     },
-    eventAuthority: {
+    token2022Program: {
       index: 20,
       isWritable: false,
+      value: input.token2022Program ?? null,
+    },
+    eventAuthority: {
+      index: 21,
+      isWritable: false,
       value: input.eventAuthority ?? null,
     },
-    program: { index: 21, isWritable: false, value: input.program ?? null },
+    program: { index: 22, isWritable: false, value: input.program ?? null },
   };
 
   // Arguments.
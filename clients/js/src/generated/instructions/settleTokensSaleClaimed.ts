@@ -64,6 +64,8 @@ export type SettleTokensSaleClaimedInstructionAccounts = {
   mint: PublicKey | Pda;
   sellerTokenAccount?: PublicKey | Pda;
   authorityPdaTokenAccount?: PublicKey | Pda;
+  /** Token-2022 program, only required if the items are Token-2022 tokens */
+  token2022Program?: PublicKey | Pda;
   eventAuthority?: PublicKey | Pda;
   program?: PublicKey | Pda;
 };
@@ -187,12 +189,17 @@ export function settleTokensSaleClaimed(
       isWritable: true,
       value: input.authorityPdaTokenAccount ?? null,
     },
-    eventAuthority: {
+    token2022Program: {
       index: 17,
       isWritable: false,
+      value: input.token2022Program ?? null,
+    },
+    eventAuthority: {
+      index: 18,
+      isWritable: false,
       value: input.eventAuthority ?? null,
     },
-    program: { index: 18, isWritable: false, value: input.program ?? null },
+    program: { index: 19, isWritable: false, value: input.program ?? null },
   };
 
   // Arguments.
@@ -0,0 +1,153 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findEventAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferPrizeInstructionAccounts = {
+  /** Gumball machine account. */
+  gumballMachine: PublicKey | Pda;
+  /** Buyer of the item. */
+  buyer?: Signer;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type TransferPrizeInstructionData = {
+  discriminator: Array<number>;
+  index: number;
+  newBuyer: PublicKey;
+};
+
+export type TransferPrizeInstructionDataArgs = {
+  index: number;
+  newBuyer: PublicKey;
+};
+
+export function getTransferPrizeInstructionDataSerializer(): Serializer<
+  TransferPrizeInstructionDataArgs,
+  TransferPrizeInstructionData
+> {
+  return mapSerializer<
+    TransferPrizeInstructionDataArgs,
+    any,
+    TransferPrizeInstructionData
+  >(
+    struct<TransferPrizeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['index', u32()],
+        ['newBuyer', publicKeySerializer()],
+      ],
+      { description: 'TransferPrizeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [221, 144, 213, 233, 78, 46, 86, 68],
+    })
+  ) as Serializer<
+    TransferPrizeInstructionDataArgs,
+    TransferPrizeInstructionData
+  >;
+}
+
+// Args.
+export type TransferPrizeInstructionArgs = TransferPrizeInstructionDataArgs;
+
+// Instruction.
+export function transferPrize(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: TransferPrizeInstructionAccounts & TransferPrizeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mallowGumball',
+    'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    gumballMachine: {
+      index: 0,
+      isWritable: true,
+      value: input.gumballMachine ?? null,
+    },
+    buyer: { index: 1, isWritable: false, value: input.buyer ?? null },
+    eventAuthority: {
+      index: 2,
+      isWritable: false,
+      value: input.eventAuthority ?? null,
+    },
+    program: { index: 3, isWritable: false, value: input.program ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: TransferPrizeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.buyer.value) {
+    resolvedAccounts.buyer.value = context.identity;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = findEventAuthorityPda(context);
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = context.programs.getPublicKey(
+      'mallowGumball',
+      'MGUMqztv7MHgoHBYWbvMyL3E3NJ4UHfTwgLJUQAbKGa'
+    );
+    resolvedAccounts.program.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTransferPrizeInstructionDataSerializer().serialize(
+    resolvedArgs as TransferPrizeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
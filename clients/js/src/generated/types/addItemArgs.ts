@@ -20,11 +20,15 @@ import {
 export type AddItemArgs = {
   sellerProofPath: Option<Array<Uint8Array>>;
   index: Option<number>;
+  /** Proceeds weight of the item (defaults to 1), can only be set by the authority */
+  weight: Option<number>;
 };
 
 export type AddItemArgsArgs = {
   sellerProofPath?: OptionOrNullable<Array<Uint8Array>>;
   index?: OptionOrNullable<number>;
+  /** Proceeds weight of the item (defaults to 1), can only be set by the authority */
+  weight?: OptionOrNullable<number>;
 };
 
 export function getAddItemArgsSerializer(): Serializer<
@@ -36,6 +40,7 @@ export function getAddItemArgsSerializer(): Serializer<
       [
         ['sellerProofPath', option(array(bytes({ size: 32 })))],
         ['index', option(u32())],
+        ['weight', option(u32())],
       ],
       { description: 'AddItemArgs' }
     ),
@@ -43,6 +48,7 @@ export function getAddItemArgsSerializer(): Serializer<
       ...value,
       sellerProofPath: value.sellerProofPath ?? none(),
       index: value.index ?? none(),
+      weight: value.weight ?? none(),
     })
   ) as Serializer<AddItemArgsArgs, AddItemArgs>;
 }
@@ -19,7 +19,7 @@ import {
 export type BuyBackConfig = {
   /** Whether buying back prizes is enabled */
   enabled: boolean;
-  /** Whether buying back prizes should be added back to the gumball machine (not yet supported) */
+  /** Whether buying back prizes should be added back to the gumball machine to be drawn again */
   toGumballMachine: boolean;
   /** Authority that must sign when buying back prizes, to ensure pricing is correct */
   oracleSigner: PublicKey;
@@ -0,0 +1,54 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bytes,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Current state of a compressed NFT leaf, required to transfer it. */
+export type CompressedNftArgs = {
+  /** Root of the merkle tree the proof was generated for. */
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  /** Nonce of the leaf, used to derive the asset id. */
+  nonce: bigint;
+  /** Index of the leaf in the merkle tree. */
+  index: number;
+};
+
+export type CompressedNftArgsArgs = {
+  /** Root of the merkle tree the proof was generated for. */
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  /** Nonce of the leaf, used to derive the asset id. */
+  nonce: number | bigint;
+  /** Index of the leaf in the merkle tree. */
+  index: number;
+};
+
+export function getCompressedNftArgsSerializer(): Serializer<
+  CompressedNftArgsArgs,
+  CompressedNftArgs
+> {
+  return struct<CompressedNftArgs>(
+    [
+      ['root', bytes({ size: 32 })],
+      ['dataHash', bytes({ size: 32 })],
+      ['creatorHash', bytes({ size: 32 })],
+      ['nonce', u64()],
+      ['index', u32()],
+    ],
+    { description: 'CompressedNftArgs' }
+  ) as Serializer<CompressedNftArgsArgs, CompressedNftArgs>;
+}
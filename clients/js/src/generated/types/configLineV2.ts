@@ -19,7 +19,13 @@ import {
   getTokenStandardSerializer,
 } from '.';
 
-/** Config line struct for storing asset data. */
+/**
+ * Config line struct for storing asset data.
+ *
+ * Version 6 lines are followed by the tier (u8) of the item, and version 8 lines by
+ * the proceeds weight (u32) of the item.
+ */
+
 export type ConfigLineV2 = {
   /** Mint account of the asset. */
   mint: PublicKey;
@@ -29,6 +29,8 @@ export enum GuardType {
   ProgramGate,
   Allocation,
   Token2022Payment,
+  PremiumPrice,
+  RerollFee,
 }
 
 export type GuardTypeArgs = GuardType;
@@ -13,6 +13,7 @@ export enum GumballState {
   DetailsFinalized,
   SaleLive,
   SaleEnded,
+  Paused,
 }
 
 export type GumballStateArgs = GumballState;
@@ -12,6 +12,7 @@ export * from './allocation';
 export * from './allowList';
 export * from './botTax';
 export * from './buyBackConfig';
+export * from './compressedNftArgs';
 export * from './configLine';
 export * from './configLineInput';
 export * from './configLineV2';
@@ -22,18 +23,24 @@ export * from './gatekeeper';
 export * from './guardType';
 export * from './gumballSettings';
 export * from './gumballState';
+export * from './itemReceiver';
 export * from './machineType';
 export * from './mintLimit';
 export * from './nftBurn';
 export * from './nftGate';
 export * from './nftPayment';
+export * from './premiumPrice';
+export * from './premiumPriceType';
 export * from './programGate';
 export * from './redeemedAmount';
+export * from './rerollFee';
 export * from './solPayment';
 export * from './startDate';
 export * from './thirdPartySigner';
+export * from './tierConfig';
 export * from './token2022Payment';
 export * from './tokenBurn';
 export * from './tokenGate';
 export * from './tokenPayment';
 export * from './tokenStandard';
+export * from './unclaimedItemDestination';
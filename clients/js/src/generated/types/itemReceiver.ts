@@ -0,0 +1,27 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Receiver of an item that is claimed while its sale is settled. */
+export enum ItemReceiver {
+  Buyer,
+  Seller,
+  Authority,
+}
+
+export type ItemReceiverArgs = ItemReceiver;
+
+export function getItemReceiverSerializer(): Serializer<
+  ItemReceiverArgs,
+  ItemReceiver
+> {
+  return scalarEnum<ItemReceiver>(ItemReceiver, {
+    description: 'ItemReceiver',
+  }) as Serializer<ItemReceiverArgs, ItemReceiver>;
+}
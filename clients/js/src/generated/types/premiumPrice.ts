@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import {
+  PremiumPriceType,
+  PremiumPriceTypeArgs,
+  getPremiumPriceTypeSerializer,
+} from '.';
+
+/**
+ * Guard that sets the price to buy a specific item instead of drawing one. Buying a
+ * specific item is only allowed when this guard is enabled.
+ *
+ * The premium price replaces the price of the payment guards (sol payment, token
+ * payment and token2022 payment) and the amount of the token burn guard. A flat price
+ * is a single amount, so guard sets with a flat premium price can only have one of
+ * those guards.
+ */
+
+export type PremiumPrice = { priceType: PremiumPriceType; value: bigint };
+
+export type PremiumPriceArgs = {
+  priceType: PremiumPriceTypeArgs;
+  value: number | bigint;
+};
+
+export function getPremiumPriceSerializer(): Serializer<
+  PremiumPriceArgs,
+  PremiumPrice
+> {
+  return struct<PremiumPrice>(
+    [
+      ['priceType', getPremiumPriceTypeSerializer()],
+      ['value', u64()],
+    ],
+    { description: 'PremiumPrice' }
+  ) as Serializer<PremiumPriceArgs, PremiumPrice>;
+}
@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** How the price of a specific item is calculated. */
+export enum PremiumPriceType {
+  Flat,
+  Multiplier,
+}
+
+export type PremiumPriceTypeArgs = PremiumPriceType;
+
+export function getPremiumPriceTypeSerializer(): Serializer<
+  PremiumPriceTypeArgs,
+  PremiumPriceType
+> {
+  return scalarEnum<PremiumPriceType>(PremiumPriceType, {
+    description: 'PremiumPriceType',
+  }) as Serializer<PremiumPriceTypeArgs, PremiumPriceType>;
+}
@@ -0,0 +1,28 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that sets the fee to reroll an unclaimed prize. Rerolling is only allowed when
+ * this guard is enabled, and groups with this guard can only be used to reroll.
+ *
+ * The fee replaces the price of the payment guards (sol payment, token payment and
+ * token2022 payment) and the amount of the token burn guard. Since the fee is a single
+ * amount, guard sets with a reroll fee can only have one of those guards.
+ */
+
+export type RerollFee = { amount: bigint };
+
+export type RerollFeeArgs = { amount: number | bigint };
+
+export function getRerollFeeSerializer(): Serializer<RerollFeeArgs, RerollFee> {
+  return struct<RerollFee>([['amount', u64()]], {
+    description: 'RerollFee',
+  }) as Serializer<RerollFeeArgs, RerollFee>;
+}
@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u16,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TierConfig = {
+  /** Draw weight of each tier, the odds of drawing an item are proportional to its tier weight */
+  weights: Array<number>;
+  /** Number of items assigned to each tier */
+  itemCounts: Array<number>;
+  /** Number of items in each tier that have not been drawn yet */
+  itemsAvailable: Array<number>;
+};
+
+export type TierConfigArgs = TierConfig;
+
+export function getTierConfigSerializer(): Serializer<
+  TierConfigArgs,
+  TierConfig
+> {
+  return struct<TierConfig>(
+    [
+      ['weights', array(u16(), { size: 8 })],
+      ['itemCounts', array(u32(), { size: 8 })],
+      ['itemsAvailable', array(u32(), { size: 8 })],
+    ],
+    { description: 'TierConfig' }
+  ) as Serializer<TierConfigArgs, TierConfig>;
+}
@@ -13,6 +13,9 @@ export enum TokenStandard {
   Core,
   Fungible,
   ProgrammableNonFungible,
+  Token2022Fungible,
+  CompressedNonFungible,
+  Sol,
 }
 
 export type TokenStandardArgs = TokenStandard;
@@ -1,7 +1,11 @@
 import {
   BUY_BACK_CONFIG_SIZE,
-  CONFIG_LINE_V2_SIZE,
+  CLAIM_DEADLINE_CONFIG_SIZE,
+  CONFIG_LINE_V6_SIZE,
   GUMBALL_MACHINE_SIZE,
+  RANDOMNESS_CONFIG_SIZE,
+  RANDOMNESS_REQUEST_CONFIG_SIZE,
+  TIER_CONFIG_SIZE,
 } from '../constants';
 
 export function getGumballMachineSizeForItemCount(
@@ -14,7 +18,7 @@ export function getGumballMachineSizeForItemCount(
       // Number of currently items inserted.
       4 +
       // Config line data.
-      items * CONFIG_LINE_V2_SIZE +
+      items * CONFIG_LINE_V6_SIZE +
       // Bit mask to keep track of which items have been claimed.
       (4 + Math.floor(items / 8) + 1) +
       // Bit mask to keep track of which items have been settled.
@@ -25,6 +29,21 @@ export function getGumballMachineSizeForItemCount(
       1 + // disablePrimarySplit
       BUY_BACK_CONFIG_SIZE +
       8 + // buyBackFundsAvailable
-      8 // totalProceedsSettled
+      8 + // totalProceedsSettled
+      TIER_CONFIG_SIZE +
+      RANDOMNESS_CONFIG_SIZE +
+      8 + // totalWeight
+      8 + // totalWeightSettled
+      8 + // addItemRequestExpiry
+      8 + // saleEndedAt
+      8 + // forceCloseGracePeriod
+      CLAIM_DEADLINE_CONFIG_SIZE +
+      1 + // instantDelivery
+      32 + // receiptCollection
+      8 + // drawsCommitted
+      8 + // drawsRevealed
+      RANDOMNESS_REQUEST_CONFIG_SIZE +
+      8 + // totalRevenueDrawn
+      8 // solPrizesHeld
   );
 }
@@ -3,19 +3,26 @@ import {
   array,
   bitArray,
   bool,
+  bytes,
+  i64,
   mapSerializer,
   publicKey,
   Serializer,
   struct,
+  u16,
   u32,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { GUMBALL_MACHINE_HIDDEN_SECTION } from '../constants';
+import { GUMBALL_MACHINE_HIDDEN_SECTION, MAX_TIERS } from '../constants';
 import {
   BuyBackConfig,
   getBuyBackConfigSerializer,
+  getTierConfigSerializer,
+  getUnclaimedItemDestinationSerializer,
+  TierConfig,
   TokenStandard,
+  UnclaimedItemDestination,
 } from '../generated';
 import {
   getGumballMachineAccountDataSerializer as baseGetGumballMachineAccountDataSerializer,
@@ -25,12 +32,28 @@ import {
 
 export type GumballMachineAccountData = BaseGumballMachineAccountData & {
   itemsLoaded: number;
+  itemsPending: number;
   items: GumballMachineItem[];
   disablePrimarySplit: boolean;
   disableRoyalties: boolean;
   buyBackConfig: BuyBackConfig;
   buyBackFundsAvailable: number | bigint;
   totalProceedsSettled: number | bigint;
+  tierConfig: TierConfig;
+  randomnessConfig: RandomnessConfig;
+  totalWeight: number | bigint;
+  totalWeightSettled: number | bigint;
+  addItemRequestExpiry: number | bigint;
+  saleEndedAt: number | bigint;
+  forceCloseGracePeriod: number | bigint;
+  claimDeadlineConfig: ClaimDeadlineConfig;
+  instantDelivery: boolean;
+  receiptCollection: PublicKey;
+  drawsCommitted: number | bigint;
+  drawsRevealed: number | bigint;
+  randomnessRequestConfig: RandomnessRequestConfig;
+  totalRevenueDrawn: number | bigint;
+  solPrizesHeld: number | bigint;
 };
 
 export type GumballMachineAccountDataArgs = BaseGumballMachineAccountDataArgs;
@@ -47,7 +70,7 @@ export type GumballMachineItem = {
   /** The index of the config line. */
   readonly index: number;
 
-  /** Whether the item has been drawn or not. */
+  /** Whether the item has been drawn and revealed or not. */
   readonly isDrawn: boolean;
 
   /** Whether the item has been claimed or not. */
@@ -67,8 +90,89 @@ export type GumballMachineItem = {
   readonly tokenStandard: TokenStandard;
 
   readonly amount: number;
+
+  /** The rarity tier of the item. */
+  readonly tier: number;
+
+  /** The share of the sale proceeds of the item relative to other items. */
+  readonly weight: number;
+
+  /** The slot the item was drawn at, 0 when it was not drawn. */
+  readonly drawSlot: bigint;
+
+  /** The price paid for the item, 0 when it was not drawn. */
+  readonly price: bigint;
+};
+
+/** The randomness provider used to reveal draws. */
+export type RandomnessConfig = {
+  /** The program owning the randomness accounts, or the default public key for slot hashes. */
+  provider: PublicKey;
+  /** The offset of the 32 bytes random value in the account data. */
+  valueOffset: number;
+  /** The offset of the slot the random value was produced at. */
+  slotOffset: number;
+};
+
+export function getRandomnessConfigSerializer(): Serializer<
+  RandomnessConfig,
+  RandomnessConfig
+> {
+  return struct<RandomnessConfig>(
+    [
+      ['provider', publicKey()],
+      ['valueOffset', u32()],
+      ['slotOffset', u32()],
+    ],
+    { description: 'RandomnessConfig' }
+  );
+}
+
+/** The randomness account used by every draw and the layout of its requests. */
+export type RandomnessRequestConfig = {
+  /** The randomness account of the provider. */
+  account: PublicKey;
+  /** The first 8 bytes of the randomness account data. */
+  discriminator: Uint8Array;
+  /** The offset of the slot the current value was requested at. */
+  requestSlotOffset: number;
+};
+
+export function getRandomnessRequestConfigSerializer(): Serializer<
+  RandomnessRequestConfig,
+  RandomnessRequestConfig
+> {
+  return struct<RandomnessRequestConfig>(
+    [
+      ['account', publicKey()],
+      ['discriminator', bytes({ size: 8 })],
+      ['requestSlotOffset', u32()],
+    ],
+    { description: 'RandomnessRequestConfig' }
+  );
+}
+
+/** The deadline to claim drawn items and where unclaimed items are sent after it. */
+export type ClaimDeadlineConfig = {
+  /** Seconds after the sale ends that drawn items can be claimed, 0 means there is no deadline. */
+  claimPeriod: number | bigint;
+  /** The receiver of the items that were not claimed before the deadline. */
+  destination: UnclaimedItemDestination;
 };
 
+export function getClaimDeadlineConfigSerializer(): Serializer<
+  ClaimDeadlineConfig,
+  ClaimDeadlineConfig
+> {
+  return struct<ClaimDeadlineConfig>(
+    [
+      ['claimPeriod', i64()],
+      ['destination', getUnclaimedItemDestinationSerializer()],
+    ],
+    { description: 'ClaimDeadlineConfig' }
+  );
+}
+
 type GumballMachineHiddenSection = {
   itemsLoaded: number;
   rawConfigLines: {
@@ -80,6 +184,8 @@ type GumballMachineHiddenSection = {
   itemsClaimedMap: boolean[];
   itemsSettledMap: boolean[];
   itemsLeftToMint: number[];
+  disableRoyalties: boolean;
+  itemsPending: number;
 };
 
 type GumballMachineHiddenSectionV2 = {
@@ -94,10 +200,11 @@ type GumballMachineHiddenSectionV2 = {
   itemsClaimedMap: boolean[];
   itemsSettledMap: boolean[];
   itemsLeftToMint: number[];
+  disableRoyalties: boolean;
+  itemsPending: number;
 };
 
 type GumballMachineHiddenSectionV3 = GumballMachineHiddenSectionV2 & {
-  disableRoyalties: boolean;
   unused: number[];
   disablePrimarySplit: boolean;
 };
@@ -111,6 +218,110 @@ type GumballMachineHiddenSectionV5 = GumballMachineHiddenSectionV4 & {
   totalProceedsSettled: number | bigint;
 };
 
+type GumballMachineHiddenSectionV6 = Omit<
+  GumballMachineHiddenSectionV5,
+  'rawConfigLines'
+> & {
+  rawConfigLines: {
+    mint: PublicKey;
+    seller: PublicKey;
+    buyer: PublicKey;
+    tokenStandard: TokenStandard;
+    amount: number | bigint;
+    tier: number;
+  }[];
+  tierConfig: TierConfig;
+};
+
+type GumballMachineHiddenSectionV7 = GumballMachineHiddenSectionV6 & {
+  randomnessConfig: RandomnessConfig;
+};
+
+type GumballMachineHiddenSectionV8 = Omit<
+  GumballMachineHiddenSectionV7,
+  'rawConfigLines'
+> & {
+  rawConfigLines: {
+    mint: PublicKey;
+    seller: PublicKey;
+    buyer: PublicKey;
+    tokenStandard: TokenStandard;
+    amount: number | bigint;
+    tier: number;
+    weight: number;
+  }[];
+  totalWeight: number | bigint;
+  totalWeightSettled: number | bigint;
+};
+
+type GumballMachineHiddenSectionV9 = GumballMachineHiddenSectionV8 & {
+  addItemRequestExpiry: number | bigint;
+};
+
+type GumballMachineHiddenSectionV10 = GumballMachineHiddenSectionV9 & {
+  saleEndedAt: number | bigint;
+  forceCloseGracePeriod: number | bigint;
+};
+
+type GumballMachineHiddenSectionV11 = GumballMachineHiddenSectionV10 & {
+  claimDeadlineConfig: ClaimDeadlineConfig;
+};
+
+type GumballMachineHiddenSectionV12 = GumballMachineHiddenSectionV11 & {
+  instantDelivery: boolean;
+};
+
+type GumballMachineHiddenSectionV13 = GumballMachineHiddenSectionV12 & {
+  receiptCollection: PublicKey;
+};
+
+type GumballMachineHiddenSectionV14 = Omit<
+  GumballMachineHiddenSectionV13,
+  'rawConfigLines'
+> & {
+  rawConfigLines: {
+    mint: PublicKey;
+    seller: PublicKey;
+    buyer: PublicKey;
+    tokenStandard: TokenStandard;
+    amount: number | bigint;
+    tier: number;
+    weight: number;
+    drawSlot: number | bigint;
+  }[];
+};
+
+type GumballMachineHiddenSectionV15 = GumballMachineHiddenSectionV14 & {
+  drawsCommitted: number | bigint;
+  drawsRevealed: number | bigint;
+};
+
+type GumballMachineHiddenSectionV16 = GumballMachineHiddenSectionV15 & {
+  randomnessRequestConfig: RandomnessRequestConfig;
+};
+
+type GumballMachineHiddenSectionV17 = Omit<
+  GumballMachineHiddenSectionV16,
+  'rawConfigLines'
+> & {
+  rawConfigLines: {
+    mint: PublicKey;
+    seller: PublicKey;
+    buyer: PublicKey;
+    tokenStandard: TokenStandard;
+    amount: number | bigint;
+    tier: number;
+    weight: number;
+    drawSlot: number | bigint;
+    price: number | bigint;
+  }[];
+  totalRevenueDrawn: number | bigint;
+};
+
+type GumballMachineHiddenSectionV18 = GumballMachineHiddenSectionV17 & {
+  solPrizesHeld: number | bigint;
+};
+
 export function getDefaultBuyBackConfig(): BuyBackConfig {
   return {
     enabled: false,
@@ -122,11 +333,42 @@ export function getDefaultBuyBackConfig(): BuyBackConfig {
   };
 }
 
+export function getDefaultTierConfig(): TierConfig {
+  return {
+    weights: Array(MAX_TIERS).fill(0),
+    itemCounts: Array(MAX_TIERS).fill(0),
+    itemsAvailable: Array(MAX_TIERS).fill(0),
+  };
+}
+
+export function getDefaultRandomnessConfig(): RandomnessConfig {
+  return {
+    provider: defaultPublicKey(),
+    valueOffset: 0,
+    slotOffset: 0,
+  };
+}
+
+export function getDefaultRandomnessRequestConfig(): RandomnessRequestConfig {
+  return {
+    account: defaultPublicKey(),
+    discriminator: new Uint8Array(8),
+    requestSlotOffset: 0,
+  };
+}
+
+export function getDefaultClaimDeadlineConfig(): ClaimDeadlineConfig {
+  return {
+    claimPeriod: 0n,
+    destination: UnclaimedItemDestination.Seller,
+  };
+}
+
 function getHiddenSection(
   version: number,
   itemCapacity: number,
   slice: Uint8Array
-): GumballMachineHiddenSectionV5 {
+): GumballMachineHiddenSectionV18 {
   if (version <= 1) {
     const hiddenSectionSerializer: Serializer<GumballMachineHiddenSection> =
       struct<GumballMachineHiddenSection>([
@@ -151,6 +393,8 @@ function getHiddenSection(
         ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
         ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
         ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+        ['disableRoyalties', bool()],
+        ['itemsPending', u16()],
       ]);
 
     const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
@@ -159,13 +403,32 @@ function getHiddenSection(
       rawConfigLines: hiddenSection.rawConfigLines.map((item) => ({
         ...item,
         amount: 1n,
+        tier: 0,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
       })),
       disableRoyalties: false,
-      unused: [0, 0, 0],
+      unused: [0],
       disablePrimarySplit: false,
       buyBackConfig: getDefaultBuyBackConfig(),
       buyBackFundsAvailable: 0n,
       totalProceedsSettled: 0n,
+      tierConfig: getDefaultTierConfig(),
+      randomnessConfig: getDefaultRandomnessConfig(),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
     };
   }
 
@@ -173,12 +436,34 @@ function getHiddenSection(
     const v2 = getHiddenSectionV2(itemCapacity, slice);
     return {
       ...v2,
+      rawConfigLines: v2.rawConfigLines.map((item) => ({
+        ...item,
+        tier: 0,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
+      })),
       disableRoyalties: false,
-      unused: [0, 0, 0],
+      unused: [0],
       disablePrimarySplit: false,
       buyBackConfig: getDefaultBuyBackConfig(),
       buyBackFundsAvailable: 0n,
       totalProceedsSettled: 0n,
+      tierConfig: getDefaultTierConfig(),
+      randomnessConfig: getDefaultRandomnessConfig(),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
     };
   }
 
@@ -186,9 +471,31 @@ function getHiddenSection(
     const v3 = getHiddenSectionV3(itemCapacity, slice);
     return {
       ...v3,
+      rawConfigLines: v3.rawConfigLines.map((item) => ({
+        ...item,
+        tier: 0,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
+      })),
       buyBackConfig: getDefaultBuyBackConfig(),
       buyBackFundsAvailable: 0n,
       totalProceedsSettled: 0n,
+      tierConfig: getDefaultTierConfig(),
+      randomnessConfig: getDefaultRandomnessConfig(),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
     };
   }
 
@@ -196,11 +503,284 @@ function getHiddenSection(
     const v4 = getHiddenSectionV4(itemCapacity, slice);
     return {
       ...v4,
+      rawConfigLines: v4.rawConfigLines.map((item) => ({
+        ...item,
+        tier: 0,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
+      })),
       totalProceedsSettled: 0n,
+      tierConfig: getDefaultTierConfig(),
+      randomnessConfig: getDefaultRandomnessConfig(),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 5) {
+    const v5 = getHiddenSectionV5(itemCapacity, slice);
+    return {
+      ...v5,
+      rawConfigLines: v5.rawConfigLines.map((item) => ({
+        ...item,
+        tier: 0,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      tierConfig: getDefaultTierConfig(),
+      randomnessConfig: getDefaultRandomnessConfig(),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 6) {
+    const v6 = getHiddenSectionV6(itemCapacity, slice);
+    return {
+      ...v6,
+      rawConfigLines: v6.rawConfigLines.map((item) => ({
+        ...item,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      randomnessConfig: getDefaultRandomnessConfig(),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 7) {
+    const v7 = getHiddenSectionV7(itemCapacity, slice);
+    return {
+      ...v7,
+      rawConfigLines: v7.rawConfigLines.map((item) => ({
+        ...item,
+        weight: 1,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      totalWeight: 0n,
+      totalWeightSettled: 0n,
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 8) {
+    const v8 = getHiddenSectionV8(itemCapacity, slice);
+    return {
+      ...v8,
+      rawConfigLines: v8.rawConfigLines.map((item) => ({
+        ...item,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      addItemRequestExpiry: 0n,
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 9) {
+    const v9 = getHiddenSectionV9(itemCapacity, slice);
+    return {
+      ...v9,
+      rawConfigLines: v9.rawConfigLines.map((item) => ({
+        ...item,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      saleEndedAt: 0n,
+      forceCloseGracePeriod: 0n,
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 10) {
+    const v10 = getHiddenSectionV10(itemCapacity, slice);
+    return {
+      ...v10,
+      rawConfigLines: v10.rawConfigLines.map((item) => ({
+        ...item,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      claimDeadlineConfig: getDefaultClaimDeadlineConfig(),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 11) {
+    const v11 = getHiddenSectionV11(itemCapacity, slice);
+    return {
+      ...v11,
+      rawConfigLines: v11.rawConfigLines.map((item) => ({
+        ...item,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      instantDelivery: false,
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 12) {
+    const v12 = getHiddenSectionV12(itemCapacity, slice);
+    return {
+      ...v12,
+      rawConfigLines: v12.rawConfigLines.map((item) => ({
+        ...item,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      receiptCollection: defaultPublicKey(),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 13) {
+    const v13 = getHiddenSectionV13(itemCapacity, slice);
+    return {
+      ...v13,
+      rawConfigLines: v13.rawConfigLines.map((item) => ({
+        ...item,
+        drawSlot: 0n,
+        price: 0n,
+      })),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 14) {
+    const v14 = getHiddenSectionV14(itemCapacity, slice);
+    return {
+      ...v14,
+      rawConfigLines: v14.rawConfigLines.map((item) => ({
+        ...item,
+        price: 0n,
+      })),
+      drawsCommitted: 0n,
+      drawsRevealed: 0n,
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 15) {
+    const v15 = getHiddenSectionV15(itemCapacity, slice);
+    return {
+      ...v15,
+      rawConfigLines: v15.rawConfigLines.map((item) => ({
+        ...item,
+        price: 0n,
+      })),
+      randomnessRequestConfig: getDefaultRandomnessRequestConfig(),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 16) {
+    const v16 = getHiddenSectionV16(itemCapacity, slice);
+    return {
+      ...v16,
+      rawConfigLines: v16.rawConfigLines.map((item) => ({
+        ...item,
+        price: 0n,
+      })),
+      totalRevenueDrawn: 0n,
+      solPrizesHeld: 0n,
+    };
+  }
+
+  if (version === 17) {
+    return {
+      ...getHiddenSectionV17(itemCapacity, slice),
+      solPrizesHeld: 0n,
     };
   }
 
-  return getHiddenSectionV5(itemCapacity, slice);
+  return getHiddenSectionV18(itemCapacity, slice);
 }
 
 function getHiddenSectionV2(
@@ -232,6 +812,8 @@ function getHiddenSectionV2(
       ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
       ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
       ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
     ]);
 
   const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
@@ -268,7 +850,8 @@ function getHiddenSectionV3(
       ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
       ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
       ['disableRoyalties', bool()],
-      ['unused', array(u8(), { size: 3 })],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
       ['disablePrimarySplit', bool()],
     ]);
 
@@ -306,7 +889,8 @@ function getHiddenSectionV4(
       ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
       ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
       ['disableRoyalties', bool()],
-      ['unused', array(u8(), { size: 3 })],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
       ['disablePrimarySplit', bool()],
       ['buyBackConfig', getBuyBackConfigSerializer()],
       ['buyBackFundsAvailable', u64()],
@@ -346,17 +930,758 @@ function getHiddenSectionV5(
       ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
       ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
       ['disableRoyalties', bool()],
-      ['unused', array(u8(), { size: 3 })],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV6(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV6 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV6> =
+    struct<GumballMachineHiddenSectionV6>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV7(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV7 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV7> =
+    struct<GumballMachineHiddenSectionV7>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
       ['disablePrimarySplit', bool()],
       ['buyBackConfig', getBuyBackConfigSerializer()],
       ['buyBackFundsAvailable', u64()],
       ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
     ]);
 
   const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
   return hiddenSection;
 }
 
+function getHiddenSectionV8(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV8 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV8> =
+    struct<GumballMachineHiddenSectionV8>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV9(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV9 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV9> =
+    struct<GumballMachineHiddenSectionV9>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV10(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV10 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV10> =
+    struct<GumballMachineHiddenSectionV10>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV11(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV11 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV11> =
+    struct<GumballMachineHiddenSectionV11>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV12(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV12 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV12> =
+    struct<GumballMachineHiddenSectionV12>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV13(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV13 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV13> =
+    struct<GumballMachineHiddenSectionV13>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+      ['receiptCollection', publicKey()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV14(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV14 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV14> =
+    struct<GumballMachineHiddenSectionV14>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+            drawSlot: number | bigint;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+            ['drawSlot', u64()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+      ['receiptCollection', publicKey()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV15(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV15 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV15> =
+    struct<GumballMachineHiddenSectionV15>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+            drawSlot: number | bigint;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+            ['drawSlot', u64()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+      ['receiptCollection', publicKey()],
+      ['drawsCommitted', u64()],
+      ['drawsRevealed', u64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV16(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV16 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV16> =
+    struct<GumballMachineHiddenSectionV16>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+            drawSlot: number | bigint;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+            ['drawSlot', u64()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+      ['receiptCollection', publicKey()],
+      ['drawsCommitted', u64()],
+      ['drawsRevealed', u64()],
+      ['randomnessRequestConfig', getRandomnessRequestConfigSerializer()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV17(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV17 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV17> =
+    struct<GumballMachineHiddenSectionV17>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+            drawSlot: number | bigint;
+            price: number | bigint;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+            ['drawSlot', u64()],
+            ['price', u64()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+      ['receiptCollection', publicKey()],
+      ['drawsCommitted', u64()],
+      ['drawsRevealed', u64()],
+      ['randomnessRequestConfig', getRandomnessRequestConfigSerializer()],
+      ['totalRevenueDrawn', u64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+function getHiddenSectionV18(
+  itemCapacity: number,
+  slice: Uint8Array
+): GumballMachineHiddenSectionV18 {
+  const hiddenSectionSerializer: Serializer<GumballMachineHiddenSectionV18> =
+    struct<GumballMachineHiddenSectionV18>([
+      ['itemsLoaded', u32()],
+      [
+        'rawConfigLines',
+        array(
+          struct<{
+            mint: PublicKey;
+            seller: PublicKey;
+            buyer: PublicKey;
+            tokenStandard: TokenStandard;
+            amount: number | bigint;
+            tier: number;
+            weight: number;
+            drawSlot: number | bigint;
+            price: number | bigint;
+          }>([
+            ['mint', publicKey()],
+            ['seller', publicKey()],
+            ['buyer', publicKey()],
+            ['tokenStandard', u8()],
+            ['amount', u64()],
+            ['tier', u8()],
+            ['weight', u32()],
+            ['drawSlot', u64()],
+            ['price', u64()],
+          ]),
+          { size: itemCapacity }
+        ),
+      ],
+      ['itemsClaimedMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsSettledMap', bitArray(Math.floor(itemCapacity / 8) + 1)],
+      ['itemsLeftToMint', array(u32(), { size: itemCapacity })],
+      ['disableRoyalties', bool()],
+      ['itemsPending', u16()],
+      ['unused', array(u8(), { size: 1 })],
+      ['disablePrimarySplit', bool()],
+      ['buyBackConfig', getBuyBackConfigSerializer()],
+      ['buyBackFundsAvailable', u64()],
+      ['totalProceedsSettled', u64()],
+      ['tierConfig', getTierConfigSerializer()],
+      ['randomnessConfig', getRandomnessConfigSerializer()],
+      ['totalWeight', u64()],
+      ['totalWeightSettled', u64()],
+      ['addItemRequestExpiry', i64()],
+      ['saleEndedAt', i64()],
+      ['forceCloseGracePeriod', i64()],
+      ['claimDeadlineConfig', getClaimDeadlineConfigSerializer()],
+      ['instantDelivery', bool()],
+      ['receiptCollection', publicKey()],
+      ['drawsCommitted', u64()],
+      ['drawsRevealed', u64()],
+      ['randomnessRequestConfig', getRandomnessRequestConfigSerializer()],
+      ['totalRevenueDrawn', u64()],
+      ['solPrizesHeld', u64()],
+    ]);
+
+  const [hiddenSection] = hiddenSectionSerializer.deserialize(slice);
+  return hiddenSection;
+}
+
+/**
+ * Mint indices are grouped by tier, with the available items
+ * at the start of each tier.
+ */
+function getTieredItemsLeftToMint(
+  hiddenSection: GumballMachineHiddenSectionV18
+): number[] {
+  const { itemCounts, itemsAvailable } = hiddenSection.tierConfig;
+  const itemsLeftToMint: number[] = [];
+  let tierStart = 0;
+  for (let tier = 0; tier < MAX_TIERS; tier += 1) {
+    itemsLeftToMint.push(
+      ...hiddenSection.itemsLeftToMint.slice(
+        tierStart,
+        tierStart + itemsAvailable[tier]
+      )
+    );
+    tierStart += itemCounts[tier];
+  }
+  return itemsLeftToMint;
+}
+
 export function getGumballMachineAccountDataSerializer(): Serializer<
   GumballMachineAccountDataArgs,
   GumballMachineAccountData
@@ -375,13 +1700,15 @@ export function getGumballMachineAccountDataSerializer(): Serializer<
 
       const hiddenSection = getHiddenSection(base.version, itemCapacity, slice);
 
-      const itemsMinted = Number(base.itemsRedeemed);
-      const itemsRemaining = hiddenSection.itemsLoaded - itemsMinted;
+      // pending items are still in the available indices until they are revealed
+      const itemsAssigned =
+        Number(base.itemsRedeemed) - hiddenSection.itemsPending;
+      const itemsRemaining = hiddenSection.itemsLoaded - itemsAssigned;
 
-      const itemsLeftToMint = hiddenSection.itemsLeftToMint.slice(
-        0,
-        itemsRemaining
-      );
+      const itemsLeftToMint =
+        base.version >= 6
+          ? getTieredItemsLeftToMint(hiddenSection)
+          : hiddenSection.itemsLeftToMint.slice(0, itemsRemaining);
 
       const items: GumballMachineItem[] = [];
       hiddenSection.itemsClaimedMap.forEach((isClaimed, index) => {
@@ -401,6 +1728,10 @@ export function getGumballMachineAccountDataSerializer(): Serializer<
             rawItem.buyer === defaultPublicKey() ? undefined : rawItem.buyer,
           tokenStandard: rawItem.tokenStandard,
           amount: Number(rawItem.amount),
+          tier: rawItem.tier,
+          weight: rawItem.weight,
+          drawSlot: BigInt(rawItem.drawSlot),
+          price: BigInt(rawItem.price),
         };
         items.push(item);
       });
@@ -409,11 +1740,27 @@ export function getGumballMachineAccountDataSerializer(): Serializer<
         ...base,
         items,
         itemsLoaded: hiddenSection.itemsLoaded,
+        itemsPending: hiddenSection.itemsPending,
         disablePrimarySplit: hiddenSection.disablePrimarySplit,
         disableRoyalties: hiddenSection.disableRoyalties,
         buyBackConfig: hiddenSection.buyBackConfig,
         buyBackFundsAvailable: hiddenSection.buyBackFundsAvailable,
         totalProceedsSettled: hiddenSection.totalProceedsSettled,
+        tierConfig: hiddenSection.tierConfig,
+        randomnessConfig: hiddenSection.randomnessConfig,
+        totalWeight: hiddenSection.totalWeight,
+        totalWeightSettled: hiddenSection.totalWeightSettled,
+        addItemRequestExpiry: hiddenSection.addItemRequestExpiry,
+        saleEndedAt: hiddenSection.saleEndedAt,
+        forceCloseGracePeriod: hiddenSection.forceCloseGracePeriod,
+        claimDeadlineConfig: hiddenSection.claimDeadlineConfig,
+        instantDelivery: hiddenSection.instantDelivery,
+        receiptCollection: hiddenSection.receiptCollection,
+        drawsCommitted: hiddenSection.drawsCommitted,
+        drawsRevealed: hiddenSection.drawsRevealed,
+        randomnessRequestConfig: hiddenSection.randomnessRequestConfig,
+        totalRevenueDrawn: hiddenSection.totalRevenueDrawn,
+        solPrizesHeld: hiddenSection.solPrizesHeld,
       };
     }
   );
@@ -1,4 +1,8 @@
 export * as AnchorIdls from './anchorIdls';
+export * from './addCoreAssets';
+export * from './buySpecific';
+export * from './claimWithReceipt';
+export * from './closeExpiredAddItemRequest';
 export * from './closeGumballMachine';
 export * from './constants';
 export * from './create';
@@ -7,14 +11,24 @@ export * from './createGumballMachine';
 export * from './createLutForGumballMachine';
 export * from './defaultGuards';
 export * from './draw';
+export * from './drawMany';
 export * from './drawJellybean';
 export * from './errors';
+export * from './forceCloseGumballMachine';
 export * from './generated';
 export * from './guards';
 export * from './hooked';
 export * from './merkle';
+export * from './pendingDraw';
 export * from './plugin';
 export * from './programs';
+export * from './reclaimUnsoldItem';
+export * from './rejectAddItem';
+export * from './removeCoreAssetsSpan';
+export * from './removeNftsSpan';
+export * from './reroll';
+export * from './revealDrawWithDelivery';
+export * from './revealDrawWithReceipts';
 export * from './route';
 export * from './sellItem';
 export * from './settleCoreAssetSale';
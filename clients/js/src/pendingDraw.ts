@@ -0,0 +1,43 @@
+import { Context, Pda, PublicKey } from '@metaplex-foundation/umi';
+import { fetchGumballMachine, findPendingDrawPda } from './generated';
+
+/**
+ * Pending draws are derived from the number of draws made on the gumball
+ * machine, so the account of the next draw depends on the machine state.
+ */
+export const fetchNextPendingDrawPda = async (
+  context: Pick<Context, 'rpc' | 'eddsa' | 'programs'>,
+  gumballMachine: PublicKey
+): Promise<Pda> => {
+  const { drawsCommitted } = await fetchGumballMachine(context, gumballMachine);
+
+  return findPendingDrawPda(context, {
+    gumballMachine,
+    drawNumber: drawsCommitted,
+  });
+};
+
+/**
+ * Pending draws are revealed in the order they were made, this returns
+ * the accounts of the draws waiting to be revealed in that order.
+ */
+export const fetchPendingDrawPdasToReveal = async (
+  context: Pick<Context, 'rpc' | 'eddsa' | 'programs'>,
+  gumballMachine: PublicKey
+): Promise<Pda[]> => {
+  const { drawsCommitted, drawsRevealed } = await fetchGumballMachine(
+    context,
+    gumballMachine
+  );
+
+  const pdas: Pda[] = [];
+  for (
+    let drawNumber = BigInt(drawsRevealed);
+    drawNumber < BigInt(drawsCommitted);
+    drawNumber += 1n
+  ) {
+    pdas.push(findPendingDrawPda(context, { gumballMachine, drawNumber }));
+  }
+
+  return pdas;
+};
@@ -12,8 +12,10 @@ import {
   nftBurnGuardManifest,
   nftGateGuardManifest,
   nftPaymentGuardManifest,
+  premiumPriceGuardManifest,
   programGateGuardManifest,
   redeemedAmountGuardManifest,
+  rerollFeeGuardManifest,
   solPaymentGuardManifest,
   startDateGuardManifest,
   thirdPartySignerGuardManifest,
@@ -73,7 +75,9 @@ export const mallowGumball = (): UmiPlugin => ({
       tokenBurnGuardManifest,
       programGateGuardManifest,
       allocationGuardManifest,
-      token2022PaymentGuardManifest
+      token2022PaymentGuardManifest,
+      premiumPriceGuardManifest,
+      rerollFeeGuardManifest
     );
   },
 });
@@ -0,0 +1,53 @@
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import { MPL_TOKEN_METADATA_PROGRAM_ID } from '@metaplex-foundation/mpl-token-metadata';
+import {
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  SPL_TOKEN_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import { TransactionBuilder } from '@metaplex-foundation/umi';
+import { baseReclaimUnsoldItem, TokenStandard } from './generated';
+import { MPL_TOKEN_AUTH_RULES_PROGRAM_ID } from './programs';
+
+export type ReclaimUnsoldItemInput = Parameters<
+  typeof baseReclaimUnsoldItem
+>[1] & {
+  tokenStandard: TokenStandard;
+};
+
+export const reclaimUnsoldItem = (
+  context: Parameters<typeof baseReclaimUnsoldItem>[0],
+  input: ReclaimUnsoldItemInput
+): TransactionBuilder =>
+  baseReclaimUnsoldItem(context, {
+    ...getDefaultsForTokenStandard(input.tokenStandard),
+    ...input,
+  });
+
+function getDefaultsForTokenStandard(tokenStandard: TokenStandard) {
+  const tokenDefaults = {
+    tokenProgram: SPL_TOKEN_PROGRAM_ID,
+    associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  };
+
+  switch (tokenStandard) {
+    case TokenStandard.Core:
+      return {
+        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+      };
+    case TokenStandard.NonFungible:
+      return {
+        ...tokenDefaults,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+      };
+    case TokenStandard.ProgrammableNonFungible:
+      return {
+        ...tokenDefaults,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        authRulesProgram: MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
+      };
+    case TokenStandard.Fungible:
+      return tokenDefaults;
+    default:
+      return {};
+  }
+}
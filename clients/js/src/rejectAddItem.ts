@@ -0,0 +1,48 @@
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import { MPL_TOKEN_METADATA_PROGRAM_ID } from '@metaplex-foundation/mpl-token-metadata';
+import {
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  SPL_TOKEN_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import { TransactionBuilder } from '@metaplex-foundation/umi';
+import { baseRejectAddItem, TokenStandard } from './generated';
+import { MPL_TOKEN_AUTH_RULES_PROGRAM_ID } from './programs';
+
+export type RejectAddItemInput = Parameters<typeof baseRejectAddItem>[1] & {
+  tokenStandard: TokenStandard;
+};
+
+export const rejectAddItem = (
+  context: Parameters<typeof baseRejectAddItem>[0],
+  input: RejectAddItemInput
+): TransactionBuilder =>
+  baseRejectAddItem(context, {
+    ...getAddItemRequestProgramDefaults(input.tokenStandard),
+    ...input,
+  });
+
+export function getAddItemRequestProgramDefaults(
+  tokenStandard: TokenStandard
+) {
+  const nftDefaults = {
+    tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+    tokenProgram: SPL_TOKEN_PROGRAM_ID,
+    associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  };
+
+  switch (tokenStandard) {
+    case TokenStandard.Core:
+      return {
+        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+      };
+    case TokenStandard.NonFungible:
+      return nftDefaults;
+    case TokenStandard.ProgrammableNonFungible:
+      return {
+        ...nftDefaults,
+        authRulesProgram: MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
+      };
+    default:
+      return {};
+  }
+}
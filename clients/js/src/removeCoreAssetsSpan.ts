@@ -0,0 +1,41 @@
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import {
+  publicKey,
+  PublicKey,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { baseRemoveCoreAssetsSpan, findSellerHistoryPda } from './generated';
+
+export type RemoveCoreAssetsSpanInput = Parameters<
+  typeof baseRemoveCoreAssetsSpan
+>[1] & {
+  items: { asset: PublicKey; seller: PublicKey; collection?: PublicKey }[];
+};
+
+export const removeCoreAssetsSpan = (
+  context: Parameters<typeof baseRemoveCoreAssetsSpan>[0],
+  input: RemoveCoreAssetsSpanInput
+): TransactionBuilder => {
+  const gumballMachine = publicKey(input.gumballMachine, false);
+
+  return transactionBuilder().add(
+    baseRemoveCoreAssetsSpan(context, input).addRemainingAccounts(
+      input.items.flatMap(({ asset, seller, collection }) => [
+        { pubkey: seller, isSigner: false, isWritable: true },
+        {
+          pubkey: findSellerHistoryPda(context, { gumballMachine, seller })[0],
+          isSigner: false,
+          isWritable: true,
+        },
+        { pubkey: asset, isSigner: false, isWritable: true },
+        {
+          // The MPL Core program stands in for a missing collection.
+          pubkey: collection ?? MPL_CORE_PROGRAM_ID,
+          isSigner: false,
+          isWritable: collection != null,
+        },
+      ])
+    )
+  );
+};
@@ -0,0 +1,78 @@
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+  findTokenRecordPda,
+} from '@metaplex-foundation/mpl-token-metadata';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  publicKey,
+  PublicKey,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { baseRemoveNftsSpan, findSellerHistoryPda } from './generated';
+import { findGumballMachineAuthorityPda } from './hooked';
+
+export type RemoveNftsSpanInput = Parameters<typeof baseRemoveNftsSpan>[1] & {
+  items: { mint: PublicKey; seller: PublicKey }[];
+};
+
+export const removeNftsSpan = (
+  context: Parameters<typeof baseRemoveNftsSpan>[0],
+  input: RemoveNftsSpanInput
+): TransactionBuilder => {
+  const gumballMachine = publicKey(input.gumballMachine, false);
+  const authorityPda = findGumballMachineAuthorityPda(context, {
+    gumballMachine,
+  })[0];
+
+  return transactionBuilder().add(
+    baseRemoveNftsSpan(context, input).addRemainingAccounts(
+      input.items.flatMap(({ mint, seller }) => {
+        const sellerTokenAccount = findAssociatedTokenPda(context, {
+          mint,
+          owner: seller,
+        })[0];
+        return [
+          { pubkey: seller, isSigner: false, isWritable: true },
+          {
+            pubkey: findSellerHistoryPda(context, {
+              gumballMachine,
+              seller,
+            })[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: mint, isSigner: false, isWritable: false },
+          { pubkey: sellerTokenAccount, isSigner: false, isWritable: true },
+          {
+            pubkey: findAssociatedTokenPda(context, {
+              mint,
+              owner: authorityPda,
+            })[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: findMasterEditionPda(context, { mint })[0],
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: findMetadataPda(context, { mint })[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: findTokenRecordPda(context, {
+              mint,
+              token: sellerTokenAccount,
+            })[0],
+            isSigner: false,
+            isWritable: true,
+          },
+        ];
+      })
+    )
+  );
+};
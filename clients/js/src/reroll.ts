@@ -0,0 +1,82 @@
+import {
+  none,
+  Option,
+  OptionOrNullable,
+  publicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { DefaultGuardSetMintArgs } from './defaultGuards';
+import {
+  reroll as baseReroll,
+  RerollInstructionAccounts,
+} from './generated/instructions/reroll';
+import { MachineType } from './generated/types';
+import {
+  GuardRepository,
+  GuardSetMintArgs,
+  GumballGuardProgram,
+  MintContext,
+  parseGuardRemainingAccounts,
+  parseMintArgs,
+} from './guards';
+import { findGumballGuardPda } from './hooked';
+
+export { RerollInstructionAccounts };
+
+export type RerollInstructionData<MA extends GuardSetMintArgs> = {
+  discriminator: Array<number>;
+  index: number;
+  mintArgs: MA;
+  group: Option<string>;
+};
+
+export type RerollInstructionDataArgs<MA extends GuardSetMintArgs> = {
+  /** The index of the unclaimed item to give back. */
+  index: number;
+  mintArgs?: Partial<MA>;
+  group?: OptionOrNullable<string>;
+};
+
+export function reroll<MA extends GuardSetMintArgs = DefaultGuardSetMintArgs>(
+  context: Parameters<typeof baseReroll>[0] & {
+    guards: GuardRepository;
+  },
+  input: RerollInstructionAccounts &
+    RerollInstructionDataArgs<
+      MA extends undefined ? DefaultGuardSetMintArgs : MA
+    >
+): TransactionBuilder {
+  const { mintArgs = {}, group = none(), ...rest } = input;
+
+  // Parsing mint data.
+  const program = context.programs.get<GumballGuardProgram>('gumballGuard');
+  const gumballMachine = publicKey(input.gumballMachine, false);
+  const mintContext: MintContext = {
+    buyer: input.buyer ?? context.identity,
+    payer: input.payer ?? context.payer,
+    machine: gumballMachine,
+    gumballGuard: publicKey(
+      input.gumballGuard ??
+        findGumballGuardPda(context, { base: gumballMachine }),
+      false
+    ),
+    machineType: MachineType.Gumball,
+  };
+  const { data, remainingAccounts } = parseMintArgs<
+    MA extends undefined ? DefaultGuardSetMintArgs : MA
+  >(context, program, mintContext, mintArgs);
+
+  const ix = baseReroll(context, {
+    ...rest,
+    mintArgs: data,
+    group,
+  }).items[0];
+
+  const [keys, signers] = parseGuardRemainingAccounts(remainingAccounts);
+  ix.instruction.keys.push(...keys);
+  ix.signers.push(...signers);
+  ix.bytesCreatedOnChain = 0;
+
+  return transactionBuilder([ix]);
+}
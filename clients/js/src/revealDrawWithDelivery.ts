@@ -0,0 +1,184 @@
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import {
+  findMasterEditionPda,
+  findMetadataPda,
+  findTokenRecordPda,
+  MPL_TOKEN_METADATA_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  findAssociatedTokenPda,
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+  SPL_SYSTEM_PROGRAM_ID,
+  SPL_TOKEN_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  AccountMeta,
+  Context,
+  publicKey,
+  PublicKey,
+  Signer,
+  SignerMeta,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { NATIVE_MINT, SPL_TOKEN_2022_PROGRAM_ID } from './constants';
+import {
+  findGumballMachineAuthorityPda,
+  MALLOW_GUMBALL_PROGRAM_ID,
+  revealDraw,
+  TokenStandard,
+} from './generated';
+import { MPL_TOKEN_AUTH_RULES_PROGRAM_ID } from './programs';
+
+const SYSVAR_INSTRUCTIONS_ID = publicKey(
+  'Sysvar1nstructions1111111111111111111111111'
+);
+
+export type DeliveredItem = {
+  /**
+   * The asset, the mint for tokens and NFTs or the native mint for SOL
+   * prizes.
+   */
+  asset: PublicKey;
+  seller: PublicKey;
+  tokenStandard: TokenStandard;
+  /** The collection of a Core asset. */
+  collection?: PublicKey;
+  /**
+   * The token account of an NFT, defaults to the seller's associated token
+   * account.
+   */
+  tokenAccount?: PublicKey;
+  /** The auth rules of a pNFT. */
+  authRules?: PublicKey;
+};
+
+export type RevealDrawWithDeliveryInput = Parameters<typeof revealDraw>[1] & {
+  /** The authority of the gumball machine. */
+  authority: PublicKey;
+  buyer: PublicKey;
+  /** Pays for the buyer token accounts, defaults to `context.payer`. */
+  deliveryPayer?: Signer;
+  /** The items expected to be drawn, in draw order. */
+  items: DeliveredItem[];
+};
+
+/**
+ * Reveals a draw and passes the accounts to transfer the drawn items
+ * to the buyer, which is required when the gumball machine uses instant
+ * delivery. Compressed NFTs cannot be delivered.
+ */
+export const revealDrawWithDelivery = (
+  context: Parameters<typeof revealDraw>[0] &
+    Pick<Context, 'eddsa' | 'payer'>,
+  input: RevealDrawWithDeliveryInput
+): TransactionBuilder => {
+  const gumballMachine = publicKey(input.gumballMachine, false);
+  const authorityPda = findGumballMachineAuthorityPda(context, {
+    gumballMachine,
+  })[0];
+  const account = (pubkey: PublicKey, isWritable = false): AccountMeta => ({
+    pubkey,
+    isSigner: false,
+    isWritable,
+  });
+
+  const sharedAccounts: (AccountMeta | SignerMeta)[] = [
+    { signer: input.deliveryPayer ?? context.payer, isWritable: true },
+    account(authorityPda, true),
+    account(input.authority, true),
+    account(input.buyer, true),
+    account(SPL_SYSTEM_PROGRAM_ID),
+    account(MPL_CORE_PROGRAM_ID),
+    account(SPL_TOKEN_PROGRAM_ID),
+    account(SPL_TOKEN_2022_PROGRAM_ID),
+    account(SPL_ASSOCIATED_TOKEN_PROGRAM_ID),
+    account(MPL_TOKEN_METADATA_PROGRAM_ID),
+    account(SYSVAR_INSTRUCTIONS_ID),
+    account(MPL_TOKEN_AUTH_RULES_PROGRAM_ID),
+  ];
+
+  const itemAccounts = input.items.flatMap((item): AccountMeta[] => {
+    switch (item.tokenStandard) {
+      case TokenStandard.Sol:
+        return [account(NATIVE_MINT, true), account(item.seller, true)];
+      case TokenStandard.Core:
+        // The gumball program stands in for a missing collection.
+        return [
+          account(item.asset, true),
+          account(item.seller, true),
+          item.collection
+            ? account(item.collection, true)
+            : account(MALLOW_GUMBALL_PROGRAM_ID),
+        ];
+      case TokenStandard.Fungible:
+      case TokenStandard.Token2022Fungible: {
+        const tokenProgramId =
+          item.tokenStandard === TokenStandard.Fungible
+            ? SPL_TOKEN_PROGRAM_ID
+            : SPL_TOKEN_2022_PROGRAM_ID;
+        return [
+          account(item.asset, true),
+          account(item.seller, true),
+          ...[input.buyer, authorityPda].map((owner) =>
+            account(
+              findAssociatedTokenPda(context, {
+                mint: item.asset,
+                owner,
+                tokenProgramId,
+              })[0],
+              true
+            )
+          ),
+        ];
+      }
+      case TokenStandard.NonFungible:
+      case TokenStandard.ProgrammableNonFungible: {
+        const tokenAccounts = [
+          item.tokenAccount ??
+            findAssociatedTokenPda(context, {
+              mint: item.asset,
+              owner: item.seller,
+            })[0],
+          ...[input.buyer, authorityPda].map(
+            (owner) =>
+              findAssociatedTokenPda(context, { mint: item.asset, owner })[0]
+          ),
+        ];
+        const accounts = [
+          account(item.asset, true),
+          account(item.seller, true),
+          ...tokenAccounts.map((token) => account(token, true)),
+          account(
+            findMasterEditionPda(context, { mint: item.asset })[0],
+            true
+          ),
+          account(findMetadataPda(context, { mint: item.asset })[0], true),
+        ];
+        if (item.tokenStandard === TokenStandard.NonFungible) {
+          return accounts;
+        }
+        // The gumball program stands in for missing auth rules.
+        return [
+          ...accounts,
+          ...tokenAccounts.map((token) =>
+            account(
+              findTokenRecordPda(context, { mint: item.asset, token })[0],
+              true
+            )
+          ),
+          account(item.authRules ?? MALLOW_GUMBALL_PROGRAM_ID),
+        ];
+      }
+      default:
+        throw new Error('Compressed NFTs cannot be delivered instantly');
+    }
+  });
+
+  return transactionBuilder().add(
+    revealDraw(context, input).addRemainingAccounts([
+      ...sharedAccounts,
+      ...itemAccounts,
+    ])
+  );
+};
@@ -0,0 +1,53 @@
+import { MPL_CORE_PROGRAM_ID } from '@metaplex-foundation/mpl-core';
+import { SPL_SYSTEM_PROGRAM_ID } from '@metaplex-foundation/mpl-toolbox';
+import {
+  AccountMeta,
+  Context,
+  publicKey,
+  PublicKey,
+  Signer,
+  SignerMeta,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { findGumballMachineAuthorityPda, revealDraw } from './generated';
+
+export type RevealDrawWithReceiptsInput = Parameters<typeof revealDraw>[1] & {
+  buyer: PublicKey;
+  receiptCollection: PublicKey;
+  /** Pays for the receipts, defaults to `context.payer`. */
+  receiptPayer?: Signer;
+  /** A new receipt asset for each drawn item, in draw order. */
+  receipts: Signer[];
+};
+
+/**
+ * Reveals a draw and passes the accounts to mint a receipt for each drawn
+ * item, which is required when the gumball machine has a receipt collection.
+ */
+export const revealDrawWithReceipts = (
+  context: Parameters<typeof revealDraw>[0] &
+    Pick<Context, 'eddsa' | 'payer'>,
+  input: RevealDrawWithReceiptsInput
+): TransactionBuilder => {
+  const gumballMachine = publicKey(input.gumballMachine, false);
+  const account = (pubkey: PublicKey, isWritable = false): AccountMeta => ({
+    pubkey,
+    isSigner: false,
+    isWritable,
+  });
+
+  const remainingAccounts: (AccountMeta | SignerMeta)[] = [
+    { signer: input.receiptPayer ?? context.payer, isWritable: true },
+    account(findGumballMachineAuthorityPda(context, { gumballMachine })[0]),
+    account(input.buyer),
+    account(input.receiptCollection, true),
+    account(MPL_CORE_PROGRAM_ID),
+    account(SPL_SYSTEM_PROGRAM_ID),
+    ...input.receipts.map((signer) => ({ signer, isWritable: true })),
+  ];
+
+  return transactionBuilder().add(
+    revealDraw(context, input).addRemainingAccounts(remainingAccounts)
+  );
+};
@@ -1,4 +1,14 @@
 /* eslint-disable import/no-extraneous-dependencies */
+import {
+  createTree,
+  getMerkleProof as getCompressedNftMerkleProof,
+  getMerkleRoot as getCompressedNftMerkleRoot,
+  hashLeaf,
+  hashMetadataCreators,
+  hashMetadataData,
+  MetadataArgsArgs,
+  mintV1,
+} from '@metaplex-foundation/mpl-bubblegum';
 import {
   create as baseCreateCoreAsset,
   createCollection,
@@ -22,14 +32,17 @@ import {
   setComputeUnitLimit,
 } from '@metaplex-foundation/mpl-toolbox';
 import {
+  AccountMeta,
   assertAccountExists,
   DateTime,
+  defaultPublicKey,
   generateSigner,
   none,
   now,
   percentAmount,
   PublicKey,
   publicKey,
+  publicKeyBytes,
   PublicKeyInput,
   Signer,
   some,
@@ -46,11 +59,16 @@ import {
   addTokens,
   createGumballGuard as baseCreateGumballGuard,
   createGumballMachine as baseCreateGumballMachineV2,
+  CompressedNftArgsArgs,
   ConfigLineInput,
   CreateGumballGuardInstructionDataArgs,
   DefaultGuardSetArgs,
-  draw,
+  DeliveredItem,
+  drawMany,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  fetchPendingDraw,
+  fetchPendingDrawPdasToReveal,
   findGumballGuardPda,
   GuardSetArgs,
   GumballGuardDataArgs,
@@ -59,6 +77,9 @@ import {
   InitializeGumballGuardInstructionAccounts,
   mallowGumball,
   MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
+  revealDraw,
+  revealDrawWithDelivery,
+  revealDrawWithReceipts,
   startSale,
   TokenStandard,
   wrap,
@@ -143,6 +164,77 @@ export const createCoreCollection = async (
   return collection;
 };
 
+// Compressed NFTs are minted in their own tree, so the proof of a leaf
+// can be computed from the leaf alone.
+const COMPRESSED_NFT_TREE_DEPTH = 3;
+
+export type CompressedNft = {
+  merkleTree: PublicKey;
+  leafIndex: number;
+  metadata: MetadataArgsArgs;
+};
+
+export const createCompressedNft = async (
+  umi: Umi,
+  input: { leafOwner?: PublicKey } = {}
+): Promise<CompressedNft> => {
+  const merkleTree = generateSigner(umi);
+  await (
+    await createTree(umi, {
+      merkleTree,
+      maxDepth: COMPRESSED_NFT_TREE_DEPTH,
+      maxBufferSize: 8,
+    })
+  ).sendAndConfirm(umi);
+
+  const metadata: MetadataArgsArgs = {
+    name: 'My Compressed Asset',
+    uri: 'https://example.com/my-compressed-asset.json',
+    sellerFeeBasisPoints: 1000,
+    collection: none(),
+    creators: [],
+  };
+  await mintV1(umi, {
+    leafOwner: input.leafOwner ?? umi.identity.publicKey,
+    merkleTree: merkleTree.publicKey,
+    metadata,
+  }).sendAndConfirm(umi);
+
+  return { merkleTree: merkleTree.publicKey, leafIndex: 0, metadata };
+};
+
+export const getCompressedNftArgs = (
+  umi: Umi,
+  compressedNft: CompressedNft,
+  leafOwner: PublicKey
+): { compressedNft: CompressedNftArgsArgs; proof: AccountMeta[] } => {
+  const leaf = publicKey(
+    hashLeaf(umi, {
+      merkleTree: compressedNft.merkleTree,
+      owner: leafOwner,
+      leafIndex: compressedNft.leafIndex,
+      metadata: compressedNft.metadata,
+    })
+  );
+
+  return {
+    compressedNft: {
+      root: publicKeyBytes(
+        getCompressedNftMerkleRoot([leaf], COMPRESSED_NFT_TREE_DEPTH)
+      ),
+      dataHash: hashMetadataData(compressedNft.metadata),
+      creatorHash: hashMetadataCreators(compressedNft.metadata.creators),
+      nonce: compressedNft.leafIndex,
+      index: compressedNft.leafIndex,
+    },
+    proof: getCompressedNftMerkleProof(
+      [leaf],
+      COMPRESSED_NFT_TREE_DEPTH,
+      leaf
+    ).map((node) => ({ pubkey: node, isSigner: false, isWritable: false })),
+  };
+};
+
 export const createProgrammableNft = async (
   umi: Umi,
   input: Partial<Parameters<typeof baseCreateProgrammableNft>[1]> = {},
@@ -446,6 +538,89 @@ export const assertSuccessfulMint = async (
   else if (uri !== undefined) t.regex(nft.metadata.uri, uri);
 };
 
+export const revealPendingDraw = async (
+  umi: Umi,
+  input: {
+    gumballMachine: PublicKey;
+    buyer?: PublicKey;
+    /** The items to deliver to the buyer with instant delivery. */
+    deliveredItems?: DeliveredItem[];
+    /** Reveals without the receipt accounts. */
+    skipReceipts?: boolean;
+  }
+): Promise<Signer[]> => {
+  // Draws are revealed in the order they were made, so every pending draw of
+  // the gumball machine is revealed.
+  const pendingDrawPdas = await fetchPendingDrawPdasToReveal(
+    umi,
+    input.gumballMachine
+  );
+  const { receiptCollection } = await fetchGumballMachine(
+    umi,
+    input.gumballMachine
+  );
+  const receipts: Signer[] = [];
+
+  for (const pendingDrawPda of pendingDrawPdas) {
+    const pendingDraw = await fetchPendingDraw(umi, pendingDrawPda);
+
+    // The draw can only be revealed once the target slot has passed.
+    while ((await umi.rpc.getSlot()) <= pendingDraw.targetSlot) {
+      await new Promise((resolve) => {
+        setTimeout(resolve, 400);
+      });
+    }
+
+    const revealInput = {
+      gumballMachine: input.gumballMachine,
+      pendingDraw: pendingDraw.publicKey,
+      payer: pendingDraw.payer,
+      randomness:
+        pendingDraw.randomness === defaultPublicKey()
+          ? undefined
+          : pendingDraw.randomness,
+    };
+
+    const deliver =
+      input.deliveredItems &&
+      pendingDraw.buyer === (input.buyer ?? umi.identity.publicKey);
+
+    // A receipt is minted for every drawn item when the gumball machine has
+    // a receipt collection.
+    const drawReceipts =
+      receiptCollection === defaultPublicKey() || input.skipReceipts
+        ? []
+        : Array.from({ length: pendingDraw.quantity }, () =>
+            generateSigner(umi)
+          );
+    receipts.push(...drawReceipts);
+
+    let builder = revealDraw(umi, revealInput);
+    if (deliver) {
+      builder = revealDrawWithDelivery(umi, {
+        ...revealInput,
+        authority: umi.identity.publicKey,
+        buyer: pendingDraw.buyer,
+        items: input.deliveredItems!,
+      });
+    } else if (drawReceipts.length > 0) {
+      builder = revealDrawWithReceipts(umi, {
+        ...revealInput,
+        buyer: pendingDraw.buyer,
+        receiptCollection,
+        receipts: drawReceipts,
+      });
+    }
+
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 1_400_000 }))
+      .add(builder)
+      .sendAndConfirm(umi);
+  }
+
+  return receipts;
+};
+
 export const assertItemBought = async (
   t: Assertions,
   umi: Umi,
@@ -455,6 +630,8 @@ export const assertItemBought = async (
     count?: number;
   }
 ) => {
+  await revealPendingDraw(umi, input);
+
   const gumballMachineAccount = await fetchGumballMachine(
     umi,
     input.gumballMachine
@@ -528,21 +705,19 @@ export const drawRemainingItems = async (
     const buyer = generateSigner(umi);
     const batchSize = Math.min(batchSizeSetting, available - i);
 
-    let builder = transactionBuilder().add(
-      setComputeUnitLimit(umi, { units: 1_400_000 })
-    );
-
-    // Add all draws to the same transaction
-    for (let j = 0; j < batchSize; j += 1) {
-      builder = builder.add(
-        draw(umi, {
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 1_400_000 }))
+      .add(
+        drawMany(umi, {
           gumballMachine,
+          pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
           buyer,
+          quantity: batchSize,
         })
-      );
-    }
+      )
+      .sendAndConfirm(umi);
 
-    await builder.sendAndConfirm(umi);
+    await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
     // Fetch the machine once after the batch completes
     const gumballMachineAccount = await fetchGumballMachine(
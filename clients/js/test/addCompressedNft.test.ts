@@ -0,0 +1,121 @@
+import {
+  findLeafAssetIdPda,
+  findTreeConfigPda,
+} from '@metaplex-foundation/mpl-bubblegum';
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCompressedNft,
+  fetchGumballMachine,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  removeCompressedNft,
+  TokenStandard,
+} from '../src';
+import {
+  create,
+  createCompressedNft,
+  createUmi,
+  getCompressedNftArgs,
+} from './_setup';
+
+test('it can add a compressed nft to a gumball machine', async (t) => {
+  // Given a gumball machine and a compressed nft.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const compressedNft = await createCompressedNft(umi);
+  const { merkleTree } = compressedNft;
+  const { compressedNft: args, proof } = getCompressedNftArgs(
+    umi,
+    compressedNft,
+    umi.identity.publicKey
+  );
+
+  // When we add the compressed nft to the gumball machine.
+  await transactionBuilder()
+    .add(
+      addCompressedNft(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        merkleTree,
+        treeConfig: findTreeConfigPda(umi, { merkleTree }),
+        compressedNft: args,
+      }).addRemainingAccounts(proof)
+    )
+    .sendAndConfirm(umi);
+
+  // Then the asset id of the leaf was added to the gumball machine.
+  const [assetId] = findLeafAssetIdPda(umi, { merkleTree, leafIndex: 0 });
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 1,
+    items: [
+      {
+        index: 0,
+        isDrawn: false,
+        mint: assetId,
+        seller: umi.identity.publicKey,
+        tokenStandard: TokenStandard.CompressedNonFungible,
+        amount: 1,
+      },
+    ],
+  });
+
+  // And the leaf is now owned by the authority pda, so it can be removed.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine: gumballMachine.publicKey,
+  })[0];
+  const removeArgs = getCompressedNftArgs(umi, compressedNft, authorityPda);
+  await transactionBuilder()
+    .add(
+      removeCompressedNft(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        merkleTree,
+        treeConfig: findTreeConfigPda(umi, { merkleTree }),
+        index: 0,
+        compressedNft: removeArgs.compressedNft,
+      }).addRemainingAccounts(removeArgs.proof)
+    )
+    .sendAndConfirm(umi);
+  t.like(await fetchGumballMachine(umi, gumballMachine.publicKey), {
+    itemsLoaded: 0,
+  });
+});
+
+test('it cannot add a compressed nft with an invalid proof', async (t) => {
+  // Given a gumball machine and a compressed nft.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const compressedNft = await createCompressedNft(umi);
+  const { merkleTree } = compressedNft;
+
+  // When we try to add it with the proof of a leaf owned by another wallet.
+  const otherUmi = await createUmi();
+  const { compressedNft: args, proof } = getCompressedNftArgs(
+    umi,
+    compressedNft,
+    otherUmi.identity.publicKey
+  );
+  const promise = transactionBuilder()
+    .add(
+      addCompressedNft(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        merkleTree,
+        treeConfig: findTreeConfigPda(umi, { merkleTree }),
+        compressedNft: args,
+      }).addRemainingAccounts(proof)
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect the transfer to fail.
+  await t.throwsAsync(promise);
+
+  // And the gumball machine was not changed.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.is(gumballMachineAccount.itemsLoaded, 0);
+});
@@ -17,6 +17,7 @@ import {
   addCoreAsset,
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   fetchSellerHistory,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
@@ -27,7 +28,12 @@ import {
   settleCoreAssetSale,
   TokenStandard,
 } from '../src';
-import { create, createCoreAsset, createUmi } from './_setup';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can add core assets to a gumball machine', async (t) => {
   // Given a Gumball Machine with 5 core assets.
@@ -442,11 +448,13 @@ test('it can re-add core asset to a gumball machine as the authority', async (t)
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
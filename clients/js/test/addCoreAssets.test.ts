@@ -0,0 +1,151 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCoreAssets,
+  fetchGumballMachine,
+  fetchSellerHistoryFromSeeds,
+  findGumballMachineAuthorityPda,
+  getMerkleProof,
+  getMerkleRoot,
+  GumballMachine,
+  SellerHistory,
+  TokenStandard,
+} from '../src';
+import { create, createCoreAsset, createUmi } from './_setup';
+
+test('it can add multiple core assets to a gumball machine at once', async (t) => {
+  // Given a gumball machine and three core assets.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const coreAssets = await Promise.all([
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+  ]);
+
+  // When we add the core assets in a single instruction.
+  await transactionBuilder()
+    .add(
+      addCoreAssets(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        assets: coreAssets.map((asset) => asset.publicKey),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the assets were added in order.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 3,
+    items: coreAssets.map((asset, index) => ({
+      index,
+      mint: asset.publicKey,
+      seller: umi.identity.publicKey,
+      tokenStandard: TokenStandard.Core,
+      amount: 1,
+    })),
+  });
+
+  // And each asset is frozen and delegated to the authority pda.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine: gumballMachine.publicKey,
+  })[0];
+  const assets = await Promise.all(
+    coreAssets.map((asset) => fetchAssetV1(umi, asset.publicKey))
+  );
+  assets.forEach((asset) => {
+    t.like(asset, <AssetV1>{
+      owner: umi.identity.publicKey,
+      transferDelegate: {
+        authority: { type: 'Address', address: authorityPda },
+      },
+      freezeDelegate: {
+        authority: { type: 'Address', address: authorityPda },
+        frozen: true,
+      },
+    });
+  });
+
+  // And the seller history counts all the assets.
+  const sellerHistoryAccount = await fetchSellerHistoryFromSeeds(umi, {
+    gumballMachine: gumballMachine.publicKey,
+    seller: umi.identity.publicKey,
+  });
+  t.like(sellerHistoryAccount, <SellerHistory>{ itemCount: 3n });
+});
+
+test('it cannot add the same core asset twice in a batch', async (t) => {
+  // Given a gumball machine and a core asset.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const coreAsset = await createCoreAsset(umi);
+
+  // When we try to add the asset twice in a single instruction.
+  const promise = transactionBuilder()
+    .add(
+      addCoreAssets(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        assets: [coreAsset.publicKey, coreAsset.publicKey],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /DuplicateItem/ });
+});
+
+test('it cannot add a batch of core assets without any asset', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  // When we try to add an empty batch.
+  const promise = transactionBuilder()
+    .add(
+      addCoreAssets(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        assets: [],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidQuantity/ });
+});
+
+test('it cannot add a batch of core assets above the seller limit', async (t) => {
+  // Given a gumball machine that allows 1 item per allowlisted seller.
+  const umi = await createUmi();
+  const sellerUmi = await createUmi();
+  const sellersMerkleRoot = getMerkleRoot([sellerUmi.identity.publicKey]);
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5, itemsPerSeller: 1, sellersMerkleRoot },
+  });
+  const coreAssets = await Promise.all([
+    createCoreAsset(sellerUmi),
+    createCoreAsset(sellerUmi),
+  ]);
+
+  // When the seller tries to add two core assets at once.
+  const promise = transactionBuilder()
+    .add(
+      addCoreAssets(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        assets: coreAssets.map((asset) => asset.publicKey),
+        args: {
+          sellerProofPath: getMerkleProof(
+            [sellerUmi.identity.publicKey],
+            sellerUmi.identity.publicKey
+          ),
+        },
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /SellerTooManyItems/ });
+});
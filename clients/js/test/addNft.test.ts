@@ -24,6 +24,7 @@ import {
   claimNft,
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   fetchSellerHistory,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
@@ -35,7 +36,13 @@ import {
   settleNftSale,
   TokenStandard,
 } from '../src';
-import { create, createNft, createProgrammableNft, createUmi } from './_setup';
+import {
+  create,
+  createNft,
+  createProgrammableNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can add nft to a gumball machine as the authority', async (t) => {
   // Given a Gumball Machine with 5 nfts.
@@ -587,11 +594,13 @@ test('it can re-add nft to a gumball machine as the authority', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
@@ -747,11 +756,13 @@ test('it can re-add pnft to a gumball machine as the authority', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
@@ -973,11 +984,13 @@ test('it cannot re-add nft to index with an unsettled item', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
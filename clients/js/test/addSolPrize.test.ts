@@ -0,0 +1,159 @@
+import {
+  sol,
+  subtractAmounts,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addSolPrize,
+  fetchGumballMachine,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  NATIVE_MINT,
+  removeSolPrize,
+  TokenStandard,
+} from '../src';
+import { create, createUmi } from './_setup';
+
+test('it can add SOL prizes to a gumball machine', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine: gumballMachine.publicKey,
+  })[0];
+  const authorityPdaBalance = await umi.rpc.getBalance(authorityPda);
+
+  // When we add two prizes of 1 SOL.
+  await transactionBuilder()
+    .add(
+      addSolPrize(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        amount: sol(1).basisPoints,
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the prizes were added with the native mint.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 2,
+    solPrizesHeld: sol(2).basisPoints,
+    items: [
+      {
+        index: 0,
+        mint: NATIVE_MINT,
+        seller: umi.identity.publicKey,
+        tokenStandard: TokenStandard.Sol,
+        amount: Number(sol(1).basisPoints),
+      },
+      {
+        index: 1,
+        mint: NATIVE_MINT,
+        seller: umi.identity.publicKey,
+        tokenStandard: TokenStandard.Sol,
+        amount: Number(sol(1).basisPoints),
+      },
+    ],
+  });
+
+  // And the authority pda holds the lamports of both prizes.
+  const newAuthorityPdaBalance = await umi.rpc.getBalance(authorityPda);
+  t.deepEqual(
+    subtractAmounts(newAuthorityPdaBalance, authorityPdaBalance),
+    sol(2)
+  );
+});
+
+test('it can remove SOL prizes from a gumball machine', async (t) => {
+  // Given a gumball machine with two prizes of 1 SOL.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine: gumballMachine.publicKey,
+  })[0];
+  await transactionBuilder()
+    .add(
+      addSolPrize(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        amount: sol(1).basisPoints,
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+  const authorityPdaBalance = await umi.rpc.getBalance(authorityPda);
+
+  // When we remove both prizes.
+  await transactionBuilder()
+    .add(
+      removeSolPrize(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        amount: sol(1).basisPoints,
+        startIndex: 0,
+        endIndex: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine is empty.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 0,
+    solPrizesHeld: 0n,
+  });
+
+  // And the lamports were returned to the seller.
+  const newAuthorityPdaBalance = await umi.rpc.getBalance(authorityPda);
+  t.deepEqual(
+    subtractAmounts(authorityPdaBalance, newAuthorityPdaBalance),
+    sol(2)
+  );
+});
+
+test('it cannot add a SOL prize of zero lamports', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  // When we try to add a prize of 0 SOL.
+  const promise = transactionBuilder()
+    .add(
+      addSolPrize(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        amount: 0,
+        quantity: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidAmount/ });
+});
+
+test('it cannot add a SOL prize below the rent-exempt minimum', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  // When we try to add a prize of fewer lamports than the rent-exempt minimum.
+  const rentExemptMinimum = await umi.rpc.getRent(0);
+  const promise = transactionBuilder()
+    .add(
+      addSolPrize(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        amount: rentExemptMinimum.basisPoints - 1n,
+        quantity: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /SolPrizeTooSmall/ });
+});
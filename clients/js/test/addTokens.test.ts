@@ -18,7 +18,9 @@ import {
   addNft,
   addTokens,
   draw,
+  drawMany,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   fetchSellerHistory,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
@@ -29,7 +31,13 @@ import {
   settleTokensSale,
   TokenStandard,
 } from '../src';
-import { create, createMintWithHolders, createNft, createUmi } from './_setup';
+import {
+  create,
+  createMintWithHolders,
+  createNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can add tokens to a gumball machine as the authority', async (t) => {
   // Given a Gumball Machine with 5 nfts.
@@ -124,6 +132,65 @@ test('it can add tokens to a gumball machine as the authority', async (t) => {
   });
 });
 
+test('it can add Token-2022 tokens to a gumball machine', async (t) => {
+  // Given a Umi instance using the SPL Token 2022 program.
+  const umi = await createUmi();
+  const programsWithToken22 = umi.programs.clone();
+  programsWithToken22.bind('splToken', 'splToken2022');
+  const umi22 = { ...umi, programs: programsWithToken22 };
+
+  // And a gumball machine and a Token-2022 mint.
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const [tokenMint] = await createMintWithHolders(umi22, {
+    holders: [{ owner: umi.identity, amount: 100 }],
+  });
+
+  // When we add the tokens to the gumball machine.
+  await transactionBuilder()
+    .add(
+      addTokens(umi22, {
+        gumballMachine: gumballMachine.publicKey,
+        mint: tokenMint.publicKey,
+        amount: 100,
+        quantity: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item was added as a Token-2022 fungible.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 1,
+    items: [
+      {
+        index: 0,
+        mint: tokenMint.publicKey,
+        tokenStandard: TokenStandard.Token2022Fungible,
+        amount: 100,
+      },
+    ],
+  });
+
+  // And the authority pda's token account holds the tokens.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine: gumballMachine.publicKey,
+  })[0];
+  const authorityTokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi22, {
+      mint: tokenMint.publicKey,
+      owner: authorityPda,
+    })[0]
+  );
+  t.like(authorityTokenAccount, {
+    owner: authorityPda,
+    amount: 100n,
+  });
+});
+
 test('it can add multiple tokens items to a gumball machine as the authority', async (t) => {
   const quantity = 1000;
   // Given a Gumball Machine with 5 nfts.
@@ -801,11 +868,13 @@ test('it can re-add tokens to a gumball machine as the authority', async (t) =>
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
@@ -947,24 +1016,15 @@ test('it can re-add a span of tokens to a gumball machine', async (t) => {
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 1_400_000 }))
     .add(
-      draw(umi, {
-        gumballMachine,
-        buyer,
-      })
-    )
-    .add(
-      draw(umi, {
-        gumballMachine,
-        buyer,
-      })
-    )
-    .add(
-      draw(umi, {
+      drawMany(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
+        quantity: 3,
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
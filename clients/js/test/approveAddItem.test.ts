@@ -251,3 +251,73 @@ test('it cannot approve a request to add core asset to a gumball machine after t
 
   await t.throwsAsync(promise, { message: /InvalidState/ });
 });
+
+test('it can approve a request to add an item with a proceeds weight', async (t) => {
+  // Given a gumball machine and a request to add a core asset to it.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When we approve the request with a weight of 3.
+  await transactionBuilder()
+    .add(
+      approveAddItem(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        weight: 3,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item was added with that weight.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 1,
+    totalWeight: 3n,
+    items: [{ index: 0, mint: coreAsset.publicKey, weight: 3 }],
+  });
+});
+
+test('it cannot approve a request with a proceeds weight of zero', async (t) => {
+  // Given a gumball machine and a request to add a core asset to it.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When we try to approve the request with a weight of 0.
+  const promise = transactionBuilder()
+    .add(
+      approveAddItem(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        weight: 0,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidProceedsWeight/ });
+});
@@ -0,0 +1,277 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  addAmounts,
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  buySpecific,
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  PremiumPriceType,
+  TokenStandard,
+} from '../src';
+import { create, createCoreAsset, createUmi } from './_setup';
+
+test('it can buy a specific item at a flat premium price', async (t) => {
+  // Given a gumball machine with a premium price of 2 SOL.
+  const umi = await createUmi();
+  const assets = [await createCoreAsset(umi), await createCoreAsset(umi)];
+  const { publicKey: gumballMachine } = await create(umi, {
+    items: assets.map((asset) => ({
+      id: asset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {
+      solPayment: some({ lamports: sol(1) }),
+      premiumPrice: some({
+        priceType: PremiumPriceType.Flat,
+        value: sol(2).basisPoints,
+      }),
+    },
+  });
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const authorityPdaPreBalance = await umi.rpc.getBalance(authorityPda);
+
+  // When a buyer buys the second item.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      buySpecific(buyerUmi, {
+        gumballMachine,
+        index: 1,
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the buyer owns that item.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 1n,
+    items: [
+      {
+        index: 0,
+        isDrawn: false,
+        buyer: undefined,
+      },
+      {
+        index: 1,
+        isDrawn: true,
+        mint: assets[1].publicKey,
+        buyer: buyerUmi.identity.publicKey,
+      },
+    ],
+  });
+
+  // And the premium price was charged.
+  const authorityPdaPostBalance = await umi.rpc.getBalance(authorityPda);
+  t.true(
+    isEqualToAmount(
+      authorityPdaPostBalance,
+      addAmounts(authorityPdaPreBalance, sol(2)),
+      sol(0.01)
+    )
+  );
+});
+
+test('it can buy a specific item at a multiple of the draw price', async (t) => {
+  // Given a gumball machine with a premium price of 3x the draw price.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const { publicKey: gumballMachine } = await create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+    guards: {
+      solPayment: some({ lamports: sol(1) }),
+      premiumPrice: some({
+        priceType: PremiumPriceType.Multiplier,
+        value: 30_000,
+      }),
+    },
+  });
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const authorityPdaPreBalance = await umi.rpc.getBalance(authorityPda);
+
+  // When a buyer buys the item.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      buySpecific(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then three times the draw price was charged.
+  const authorityPdaPostBalance = await umi.rpc.getBalance(authorityPda);
+  t.true(
+    isEqualToAmount(
+      authorityPdaPostBalance,
+      addAmounts(authorityPdaPreBalance, sol(3)),
+      sol(0.01)
+    )
+  );
+});
+
+test('it cannot buy a specific item without the premium price guard', async (t) => {
+  // Given a gumball machine without a premium price.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const { publicKey: gumballMachine } = await create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+    guards: {
+      solPayment: some({ lamports: sol(1) }),
+    },
+  });
+
+  // When a buyer tries to buy the item.
+  const buyerUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      buySpecific(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PremiumPriceNotEnabled/ });
+});
+
+test('it cannot buy an item that was already bought', async (t) => {
+  // Given a gumball machine with an item bought by a buyer.
+  const umi = await createUmi();
+  const assets = [await createCoreAsset(umi), await createCoreAsset(umi)];
+  const { publicKey: gumballMachine } = await create(umi, {
+    items: assets.map((asset) => ({
+      id: asset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {
+      premiumPrice: some({
+        priceType: PremiumPriceType.Flat,
+        value: sol(2).basisPoints,
+      }),
+    },
+  });
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(buySpecific(buyerUmi, { gumballMachine, index: 0 }))
+    .sendAndConfirm(buyerUmi);
+
+  // When another buyer tries to buy the same item.
+  const otherUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(otherUmi, { units: 600_000 }))
+    .add(buySpecific(otherUmi, { gumballMachine, index: 0 }))
+    .sendAndConfirm(otherUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemAlreadyDrawn/ });
+});
+
+
+test('it cannot buy a specific item while a draw is pending', async (t) => {
+  // Given a gumball machine with a draw waiting to be revealed.
+  const umi = await createUmi();
+  const assets = [await createCoreAsset(umi), await createCoreAsset(umi)];
+  const { publicKey: gumballMachine } = await create(umi, {
+    items: assets.map((asset) => ({
+      id: asset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {
+      premiumPrice: some({
+        priceType: PremiumPriceType.Flat,
+        value: sol(2).basisPoints,
+      }),
+    },
+  });
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // When another buyer tries to buy a specific item.
+  const otherUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(otherUmi, { units: 600_000 }))
+    .add(buySpecific(otherUmi, { gumballMachine, index: 0 }))
+    .sendAndConfirm(otherUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemsPendingReveal/ });
+});
+test('it cannot set a multiplier below the draw price', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+
+  // When we try to set a premium price cheaper than a draw.
+  const promise = create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    guards: {
+      solPayment: some({ lamports: sol(1) }),
+      premiumPrice: some({
+        priceType: PremiumPriceType.Multiplier,
+        value: 5_000,
+      }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidPremiumPrice/ });
+});
+
+test('it cannot set a flat premium price with several payment guards', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+
+  // When we try to set a flat premium price next to a sol payment and a token burn.
+  const promise = create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    guards: {
+      solPayment: some({ lamports: sol(1) }),
+      tokenBurn: some({ amount: 1, mint: generateSigner(umi).publicKey }),
+      premiumPrice: some({
+        priceType: PremiumPriceType.Flat,
+        value: sol(2).basisPoints,
+      }),
+    },
+  });
+
+  // Then we expect a program error, since the price would be charged in lamports
+  // and in tokens.
+  await t.throwsAsync(promise, { message: /MultiplePaymentGuards/ });
+});
@@ -0,0 +1,155 @@
+import { findTreeConfigPda } from '@metaplex-foundation/mpl-bubblegum';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCompressedNft,
+  claimCompressedNft,
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  startSale,
+} from '../src';
+import {
+  assertItemBought,
+  create,
+  createCompressedNft,
+  createUmi,
+  getCompressedNftArgs,
+} from './_setup';
+
+test('it can claim a compressed nft item', async (t) => {
+  // Given a gumball machine with a compressed nft and a gumball guard.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, { guards: {} });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const compressedNft = await createCompressedNft(umi);
+  const { merkleTree } = compressedNft;
+  const treeConfig = findTreeConfigPda(umi, { merkleTree });
+  const addArgs = getCompressedNftArgs(
+    umi,
+    compressedNft,
+    umi.identity.publicKey
+  );
+  await transactionBuilder()
+    .add(
+      addCompressedNft(umi, {
+        gumballMachine,
+        merkleTree,
+        treeConfig,
+        compressedNft: addArgs.compressedNft,
+      }).addRemainingAccounts(addArgs.proof)
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // And a buyer who drew the item.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When the buyer claims the item.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const claimArgs = getCompressedNftArgs(umi, compressedNft, authorityPda);
+  await transactionBuilder()
+    .add(
+      claimCompressedNft(buyerUmi, {
+        gumballMachine,
+        seller: umi.identity.publicKey,
+        merkleTree,
+        treeConfig,
+        index: 0,
+        compressedNft: claimArgs.compressedNft,
+      }).addRemainingAccounts(claimArgs.proof)
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the item was claimed.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 1n,
+    items: [
+      {
+        index: 0,
+        isClaimed: true,
+        buyer: buyerUmi.identity.publicKey,
+      },
+    ],
+  });
+});
+
+test('it cannot claim a compressed nft item as another buyer', async (t) => {
+  // Given a gumball machine with a drawn compressed nft.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, { guards: {} });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const compressedNft = await createCompressedNft(umi);
+  const { merkleTree } = compressedNft;
+  const treeConfig = findTreeConfigPda(umi, { merkleTree });
+  const addArgs = getCompressedNftArgs(
+    umi,
+    compressedNft,
+    umi.identity.publicKey
+  );
+  await transactionBuilder()
+    .add(
+      addCompressedNft(umi, {
+        gumballMachine,
+        merkleTree,
+        treeConfig,
+        compressedNft: addArgs.compressedNft,
+      }).addRemainingAccounts(addArgs.proof)
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When another wallet tries to claim the item.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const claimArgs = getCompressedNftArgs(umi, compressedNft, authorityPda);
+  const promise = transactionBuilder()
+    .add(
+      claimCompressedNft(umi, {
+        gumballMachine,
+        seller: umi.identity.publicKey,
+        merkleTree,
+        treeConfig,
+        index: 0,
+        compressedNft: claimArgs.compressedNft,
+      }).addRemainingAccounts(claimArgs.proof)
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+});
@@ -7,10 +7,17 @@ import {
   claimCoreAsset,
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   GumballMachine,
   TokenStandard,
 } from '../src';
-import { assertItemBought, create, createCoreAsset, createUmi } from './_setup';
+import {
+  assertItemBought,
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can claim a core asset item', async (t) => {
   // Given a gumball machine with a gumball guard that has no guards.
@@ -36,6 +43,7 @@ test('it can claim a core asset item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
@@ -111,9 +119,14 @@ test('it cannot claim a core asset item as another buyer', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const promise = transactionBuilder()
     .add(
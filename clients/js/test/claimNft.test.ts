@@ -11,6 +11,7 @@ import {
   claimNft,
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   GumballMachine,
   MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
   TokenStandard,
@@ -21,6 +22,7 @@ import {
   createNft,
   createProgrammableNft,
   createUmi,
+  revealPendingDraw,
 } from './_setup';
 
 test('it can claim an nft item', async (t) => {
@@ -47,6 +49,7 @@ test('it can claim an nft item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
@@ -130,6 +133,7 @@ test('it can claim a pnft item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
@@ -215,9 +219,14 @@ test('it cannot claim an nft item as another buyer', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const promise = transactionBuilder()
     .add(
@@ -0,0 +1,129 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  sol,
+  subtractAmounts,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addSolPrize,
+  claimSolPrize,
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  GumballMachine,
+  startSale,
+  TokenStandard,
+} from '../src';
+import { assertItemBought, create, createUmi } from './_setup';
+
+test('it can claim a SOL prize', async (t) => {
+  // Given a gumball machine with a prize of 1 SOL and a gumball guard.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, { guards: {} });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      addSolPrize(umi, {
+        gumballMachine,
+        amount: sol(1).basisPoints,
+        quantity: 1,
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // And a buyer who drew the prize.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  const buyerBalance = await umi.rpc.getBalance(buyerUmi.identity.publicKey);
+
+  // When the prize is claimed on behalf of the buyer.
+  await transactionBuilder()
+    .add(
+      claimSolPrize(umi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        buyer: buyerUmi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item was claimed.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 1n,
+    solPrizesHeld: 0n,
+    items: [
+      {
+        index: 0,
+        isClaimed: true,
+        buyer: buyerUmi.identity.publicKey,
+        tokenStandard: TokenStandard.Sol,
+      },
+    ],
+  });
+
+  // And the buyer received the lamports of the prize.
+  const newBuyerBalance = await umi.rpc.getBalance(buyerUmi.identity.publicKey);
+  t.deepEqual(subtractAmounts(newBuyerBalance, buyerBalance), sol(1));
+});
+
+test('it cannot claim a SOL prize for another buyer', async (t) => {
+  // Given a gumball machine with a drawn prize of 1 SOL.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, { guards: {} });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      addSolPrize(umi, {
+        gumballMachine,
+        amount: sol(1).basisPoints,
+        quantity: 1,
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When we try to claim the prize for another wallet.
+  const promise = transactionBuilder()
+    .add(
+      claimSolPrize(umi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        buyer: umi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+});
@@ -12,11 +12,14 @@ import {
 } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
+  addTokens,
   claimTokens,
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   GumballMachine,
+  startSale,
   TokenStandard,
 } from '../src';
 import {
@@ -24,6 +27,7 @@ import {
   create,
   createMintWithHolders,
   createUmi,
+  revealPendingDraw,
 } from './_setup';
 
 test('it can claim a tokens item', async (t) => {
@@ -64,6 +68,7 @@ test('it can claim a tokens item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
@@ -161,9 +166,14 @@ test('it cannot claim a tokens item as another buyer', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Then attempt to claim with a different user
   const promise = transactionBuilder()
@@ -180,3 +190,87 @@ test('it cannot claim a tokens item as another buyer', async (t) => {
 
   await t.throwsAsync(promise, { message: /InvalidBuyer/ });
 });
+
+test('it can claim a Token-2022 tokens item', async (t) => {
+  // Given a Umi instance using the SPL Token 2022 program.
+  const umi = await createUmi();
+  const programsWithToken22 = umi.programs.clone();
+  programsWithToken22.bind('splToken', 'splToken2022');
+  const umi22 = { ...umi, programs: programsWithToken22 };
+
+  // And a gumball machine loaded with Token-2022 tokens.
+  const [tokenMint] = await createMintWithHolders(umi22, {
+    holders: [{ owner: umi.identity, amount: 100 }],
+  });
+  const gumballMachineSigner = await create(umi, { guards: {} });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      addTokens(umi22, {
+        gumballMachine,
+        mint: tokenMint.publicKey,
+        amount: 100,
+        quantity: 1,
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // And a buyer who drew the item.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await assertItemDrawn(t, umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When the buyer claims the item with the Token-2022 program.
+  await transactionBuilder()
+    .add(
+      claimTokens(
+        { ...buyerUmi, programs: programsWithToken22 },
+        {
+          gumballMachine,
+          authority: umi.identity.publicKey,
+          index: 0,
+          seller: umi.identity.publicKey,
+          mint: tokenMint.publicKey,
+        }
+      )
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the item was claimed.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 1n,
+    items: [
+      {
+        index: 0,
+        isClaimed: true,
+        tokenStandard: TokenStandard.Token2022Fungible,
+      },
+    ],
+  });
+
+  // And the buyer owns the tokens.
+  const tokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi22, {
+      mint: tokenMint.publicKey,
+      owner: buyerUmi.identity.publicKey,
+    })[0]
+  );
+  t.like(tokenAccount, {
+    owner: buyerUmi.identity.publicKey,
+    amount: 100n,
+  });
+});
@@ -0,0 +1,288 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  claimCoreAsset,
+  claimWithReceipt,
+  createReceiptCollection,
+  draw,
+  endSale,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  setClaimDeadline,
+  setInstantDelivery,
+  settleCoreAssetSale,
+  startSale,
+  TokenStandard,
+  UnclaimedItemDestination,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+const createWithReceipts = async (
+  umi: Umi,
+  asset: PublicKey,
+  claimPeriod = 0
+) => {
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: asset, tokenStandard: TokenStandard.Core }],
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const receiptCollection = generateSigner(umi);
+  await transactionBuilder()
+    .add(
+      setClaimDeadline(umi, {
+        gumballMachine,
+        claimPeriod,
+        destination: UnclaimedItemDestination.Seller,
+      })
+    )
+    .add(
+      createReceiptCollection(umi, {
+        gumballMachine,
+        receiptCollection,
+        name: 'Receipts',
+        uri: 'https://example.com/receipts.json',
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  return { gumballMachine, receiptCollection: receiptCollection.publicKey };
+};
+
+const drawItem = async (umi: Umi, gumballMachine: PublicKey) => {
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(umi);
+};
+
+test('it can create a receipt collection', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+
+  // When we create its receipt collection.
+  const { gumballMachine, receiptCollection } = await createWithReceipts(
+    umi,
+    asset.publicKey
+  );
+
+  // Then the gumball machine was updated.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    receiptCollection,
+  });
+});
+
+test('it cannot create a receipt collection with instant delivery', async (t) => {
+  // Given a gumball machine with instant delivery.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+  await transactionBuilder()
+    .add(
+      setInstantDelivery(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        instantDelivery: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to create its receipt collection.
+  const promise = transactionBuilder()
+    .add(
+      createReceiptCollection(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        receiptCollection: generateSigner(umi),
+        name: 'Receipts',
+        uri: 'https://example.com/receipts.json',
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ReceiptsWithInstantDelivery/ });
+});
+
+test('it mints a receipt when the draw is revealed', async (t) => {
+  // Given a gumball machine with receipts and a drawn core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const { gumballMachine, receiptCollection } = await createWithReceipts(
+    umi,
+    asset.publicKey
+  );
+  const buyerUmi = await createUmi();
+  await drawItem(buyerUmi, gumballMachine);
+
+  // When the draw is revealed.
+  const [receipt] = await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // Then the receipt holds the item.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    items: [
+      {
+        index: 0,
+        isDrawn: true,
+        isClaimed: false,
+        buyer: authorityPda,
+      },
+    ],
+  });
+  t.true(gumballMachineAccount.items[0].drawSlot > 0n);
+
+  // And the buyer owns the receipt.
+  const receiptAsset = await fetchAssetV1(umi, receipt.publicKey);
+  t.like(receiptAsset, <AssetV1>{
+    owner: buyerUmi.identity.publicKey,
+  });
+
+  // And the buyer can no longer claim the item directly.
+  const promise = transactionBuilder()
+    .add(
+      claimCoreAsset(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        asset: asset.publicKey,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+
+  // When the buyer claims the item with the receipt.
+  await transactionBuilder()
+    .add(
+      claimWithReceipt(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        receipt: receipt.publicKey,
+        receiptCollection,
+        asset: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the buyer owns the asset.
+  const coreAsset = await fetchAssetV1(umi, asset.publicKey);
+  t.like(coreAsset, <AssetV1>{
+    owner: buyerUmi.identity.publicKey,
+  });
+
+  // And the receipt was burnt.
+  await t.throwsAsync(fetchAssetV1(umi, receipt.publicKey));
+});
+
+test('it cannot reveal a draw without the receipt accounts', async (t) => {
+  // Given a gumball machine with receipts and a drawn core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const { gumballMachine } = await createWithReceipts(umi, asset.publicKey);
+  const buyerUmi = await createUmi();
+  await drawItem(buyerUmi, gumballMachine);
+
+  // When the draw is revealed without the receipt accounts.
+  const promise = revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+    skipReceipts: true,
+  });
+
+  // Then we expect a program error, since the receipt would not be minted.
+  await t.throwsAsync(promise, { message: /InvalidReceiptAccount/ });
+});
+
+test('it keeps items held by a receipt after the claim deadline', async (t) => {
+  // Given a gumball machine with receipts and a claim deadline.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const { gumballMachine, receiptCollection } = await createWithReceipts(
+    umi,
+    asset.publicKey,
+    1
+  );
+
+  // And an item held by a receipt.
+  const buyerUmi = await createUmi();
+  await drawItem(buyerUmi, gumballMachine);
+  const [receipt] = await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // And the claim deadline has passed.
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+
+  // When we try to settle the sale.
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const settle = () =>
+    transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        settleCoreAssetSale(umi, {
+          index: 0,
+          gumballMachine,
+          authority: umi.identity.publicKey,
+          seller: umi.identity.publicKey,
+          buyer: authorityPda,
+          asset: asset.publicKey,
+          creators: [umi.identity.publicKey],
+        })
+      )
+      .sendAndConfirm(umi);
+
+  // Then we expect a program error, since the receipt still holds the item.
+  await t.throwsAsync(settle(), { message: /ItemHeldByReceipt/ });
+
+  // When the buyer claims the item with the receipt.
+  await transactionBuilder()
+    .add(
+      claimWithReceipt(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        receipt: receipt.publicKey,
+        receiptCollection,
+        asset: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the buyer owns the asset.
+  const coreAsset = await fetchAssetV1(umi, asset.publicKey);
+  t.like(coreAsset, <AssetV1>{
+    owner: buyerUmi.identity.publicKey,
+  });
+});
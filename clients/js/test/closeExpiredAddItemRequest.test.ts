@@ -0,0 +1,215 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  TokenState,
+} from '@metaplex-foundation/mpl-toolbox';
+import { none, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  closeExpiredAddItemRequest,
+  requestAddCoreAsset,
+  requestAddNft,
+  safeFetchAddItemRequestFromSeeds,
+  safeFetchSellerHistoryFromSeeds,
+  setAddItemRequestExpiry,
+  TokenStandard,
+} from '../src';
+import { create, createCoreAsset, createNft, createUmi } from './_setup';
+
+test('anyone can close an expired request to add core asset to a gumball machine', async (t) => {
+  // Given a Gumball Machine where add item requests expire after 1 second.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  await transactionBuilder()
+    .add(
+      setAddItemRequestExpiry(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        expiry: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And a request to add a core asset.
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When the request has expired and a third party closes it.
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+  const crankUmi = await createUmi();
+  await transactionBuilder()
+    .add(
+      closeExpiredAddItemRequest(crankUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(crankUmi);
+
+  // Then the request is closed.
+  const addItemRequestAccount = await safeFetchAddItemRequestFromSeeds(umi, {
+    asset: coreAsset.publicKey,
+  });
+  t.falsy(addItemRequestAccount);
+
+  // And the asset is unfrozen and revoked.
+  const asset = await fetchAssetV1(umi, coreAsset.publicKey);
+  t.like(asset, <AssetV1>{
+    freezeDelegate: undefined,
+    transferDelegate: undefined,
+    owner: sellerUmi.identity.publicKey,
+  });
+
+  // And the seller history is closed.
+  const sellerHistoryAccount = await safeFetchSellerHistoryFromSeeds(umi, {
+    gumballMachine: gumballMachine.publicKey,
+    seller: sellerUmi.identity.publicKey,
+  });
+  t.falsy(sellerHistoryAccount);
+});
+
+test('anyone can close an expired request to add an nft to a gumball machine', async (t) => {
+  // Given a Gumball Machine where add item requests expire after 1 second.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  await transactionBuilder()
+    .add(
+      setAddItemRequestExpiry(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        expiry: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And a request to add an nft.
+  const sellerUmi = await createUmi();
+  const nft = await createNft(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddNft(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        mint: nft.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When the request has expired and a third party closes it.
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+  const crankUmi = await createUmi();
+  await transactionBuilder()
+    .add(
+      closeExpiredAddItemRequest(crankUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: nft.publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      })
+    )
+    .sendAndConfirm(crankUmi);
+
+  // Then the request is closed.
+  const addItemRequestAccount = await safeFetchAddItemRequestFromSeeds(umi, {
+    asset: nft.publicKey,
+  });
+  t.falsy(addItemRequestAccount);
+
+  // And the nft is unfrozen and revoked.
+  const tokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi, {
+      mint: nft.publicKey,
+      owner: sellerUmi.identity.publicKey,
+    })[0]
+  );
+  t.like(tokenAccount, {
+    state: TokenState.Initialized,
+    owner: sellerUmi.identity.publicKey,
+    delegate: none(),
+  });
+});
+
+test('it cannot close a request that has not expired', async (t) => {
+  // Given a Gumball Machine where add item requests expire after 1 hour.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  await transactionBuilder()
+    .add(
+      setAddItemRequestExpiry(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        expiry: 3600,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And a request to add a core asset.
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When a third party tries to close the request.
+  const crankUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(
+      closeExpiredAddItemRequest(crankUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(crankUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AddItemRequestNotExpired/ });
+});
+
+test('it cannot close a request that never expires', async (t) => {
+  // Given a Gumball Machine without an add item request expiry.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  // And a request to add a core asset.
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When a third party tries to close the request.
+  const crankUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(
+      closeExpiredAddItemRequest(crankUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(crankUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AddItemRequestNotExpired/ });
+});
@@ -51,7 +51,7 @@ test('it can create a gumball machine using config line settings', async (t) =>
     publicKey: publicKey(gumballMachine),
     authority: publicKey(umi.identity),
     mintAuthority: publicKey(umi.identity),
-    version: 5,
+    version: 18,
     itemsRedeemed: 0n,
     settings,
     state: GumballState.None,
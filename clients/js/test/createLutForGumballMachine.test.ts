@@ -11,6 +11,7 @@ import test from 'ava';
 import {
   createLutForGumballMachine,
   draw,
+  fetchNextPendingDrawPda,
   findGumballGuardPda,
   getMallowGumballProgramId,
   setMintAuthority,
@@ -38,6 +39,7 @@ test('it can create a LUT for a gumball machine v2', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     );
 
@@ -6,7 +6,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -39,6 +39,7 @@ test('it allows minting from a specific address only', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer: allowedAddress,
       })
     )
@@ -75,6 +76,7 @@ test('it forbids minting from anyone else', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer: unauthorizedMinter,
       })
     )
@@ -110,6 +112,7 @@ test('it charges a bot tax when trying to mint using the wrong address', async (
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer: unauthorizedMinter,
       })
     )
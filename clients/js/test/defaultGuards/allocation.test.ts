@@ -9,6 +9,7 @@ import test from 'ava';
 import {
   draw,
   fetchAllocationTracker,
+  fetchNextPendingDrawPda,
   findAllocationTrackerPda,
   findGumballGuardPda,
   route,
@@ -20,6 +21,7 @@ import {
   create,
   createNft,
   createUmi,
+  revealPendingDraw,
 } from '../_setup';
 
 test('it allows minting when the allocation limit is not reached', async (t) => {
@@ -64,6 +66,7 @@ test('it allows minting when the allocation limit is not reached', async (t) =>
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: { allocation: some({ id: 1 }) },
       })
     )
@@ -124,10 +127,12 @@ test('it forbids minting when the allocation limit is reached', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: { allocation: some({ id: 1 }) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine });
 
   // When we try to mint again.
   const promise = transactionBuilder()
@@ -135,6 +140,7 @@ test('it forbids minting when the allocation limit is reached', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: { allocation: some({ id: 1 }) },
       })
     )
@@ -210,6 +216,7 @@ test('the allocation limit is local to each id', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer: buyerA,
         mintArgs: { allocation: some({ id: 1 }) },
         group: some('GROUPA'),
@@ -225,6 +232,7 @@ test('the allocation limit is local to each id', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer: buyerB,
         mintArgs: { allocation: some({ id: 2 }) },
         group: some('GROUPB'),
@@ -278,11 +286,13 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { allocation: some({ id: 1 }) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine });
 
   // When the identity tries to mint from the same Gumball Machine again.
   const { signature } = await transactionBuilder()
@@ -290,6 +300,7 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { allocation: some({ id: 1 }) },
       })
@@ -10,6 +10,7 @@ import {
 import test from 'ava';
 import {
   draw,
+  fetchNextPendingDrawPda,
   findAllowListProofPda,
   findGumballGuardPda,
   getMerkleProof,
@@ -74,6 +75,7 @@ test('it allows minting from wallets of a predefined list', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: { allowList: some({ merkleRoot }) },
       })
     )
@@ -129,6 +131,7 @@ test('it is possible to verify the proof and mint in the same transaction if the
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { allowList: some({ merkleRoot }) },
       })
@@ -186,6 +189,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { allowList: some({ merkleRoot }) },
       })
@@ -327,6 +331,7 @@ test('it forbids minting if the wallet has not been verified via the route instr
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { allowList: some({ merkleRoot }) },
       })
@@ -373,6 +378,7 @@ test('it charges a bot tax when trying to mint whilst not verified', async (t) =
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { allowList: some({ merkleRoot }) },
       })
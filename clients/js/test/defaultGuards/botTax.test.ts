@@ -1,7 +1,7 @@
 import { addMemo, setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
 import { sol, some, transactionBuilder } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -34,6 +34,7 @@ test('it does nothing if all conditions are valid', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -66,6 +67,7 @@ test('it optionally charges a bot tax if the mint instruction is not the last on
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .add(addMemo(umi, { memo: 'I am a post-mint instruction' }))
@@ -1,7 +1,7 @@
 import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
 import { sol, some, transactionBuilder } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -36,6 +36,7 @@ test('it allows minting before the end date', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -68,6 +69,7 @@ test('it forbids minting after the end date', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -101,6 +103,7 @@ test('it charges a bot tax when trying to mint after the end date', async (t) =>
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -10,6 +10,7 @@ import test from 'ava';
 import {
   draw,
   fetchMintCounter,
+  fetchNextPendingDrawPda,
   findGumballGuardPda,
   findMintCounterPda,
   TokenStandard,
@@ -20,6 +21,7 @@ import {
   create,
   createNft,
   createUmi,
+  revealPendingDraw,
 } from '../_setup';
 
 test('it allows minting when the mint limit is not reached', async (t) => {
@@ -50,6 +52,7 @@ test('it allows minting when the mint limit is not reached', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { mintLimit: some({ id: 1 }) },
       })
@@ -99,6 +102,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
 
@@ -149,10 +153,12 @@ test('it forbids minting when the mint limit is reached', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: { mintLimit: some({ id: 42 }) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine });
 
   // When that same identity tries to mint from the same Gumball Machine again.
   const promise = transactionBuilder()
@@ -160,6 +166,7 @@ test('it forbids minting when the mint limit is reached', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: { mintLimit: some({ id: 42 }) },
       })
     )
@@ -198,6 +205,7 @@ test('the mint limit is local to each wallet', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer: buyerA,
 
@@ -215,6 +223,7 @@ test('the mint limit is local to each wallet', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer: buyerB,
 
@@ -256,11 +265,13 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { mintLimit: some({ id: 42 }) },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine });
 
   // When the identity tries to mint from the same Gumball Machine again.
 
@@ -269,6 +280,7 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: { mintLimit: some({ id: 42 }) },
       })
@@ -8,7 +8,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertBurnedNft,
@@ -56,6 +56,7 @@ test('it burns a specific NFT to allow minting', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftBurn: some({
@@ -111,6 +112,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
 
@@ -158,6 +160,7 @@ test('it fails if there is not valid NFT to burn', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftBurn: some({
@@ -202,6 +205,7 @@ test('it charges a bot tax when trying to mint using the wrong NFT', async (t) =
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftBurn: some({
@@ -253,6 +257,7 @@ test('it burns a specific Programmable NFT to allow minting', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftBurn: some({
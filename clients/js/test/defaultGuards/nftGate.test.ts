@@ -15,7 +15,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -63,6 +63,7 @@ test('it allows minting when the payer owns an NFT from a certain collection', a
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftGate: some({ mint: nftToVerify.publicKey }),
@@ -111,6 +112,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
 
@@ -174,6 +176,7 @@ test('it allows minting when the NFT is not on an associated token account', asy
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftGate: some({
@@ -244,6 +247,7 @@ test('it forbids minting when the payer does not own an NFT from a certain colle
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftGate: some({ mint: nftToVerify }),
@@ -295,6 +299,7 @@ test('it forbids minting when the payer tries to provide an NFT from the wrong c
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftGate: some({ mint: nftToVerify }),
@@ -340,6 +345,7 @@ test('it forbids minting when the payer tries to provide an NFT from an unverifi
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftGate: some({ mint: nftToVerify }),
@@ -379,6 +385,7 @@ test('it charges a bot tax when trying to mint without owning the right NFT', as
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftGate: some({ mint: wrongNft.publicKey }),
@@ -422,6 +429,7 @@ test('it allows minting when the payer owns an NFT from a core collection', asyn
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           nftGate: some({
             mint: nftToVerify.publicKey,
@@ -468,6 +476,7 @@ test('it forbids minting when the payer does not own a core asset from a certain
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           nftGate: some({
             mint: nftToVerify.publicKey,
@@ -514,6 +523,7 @@ test('it forbids minting when the payer tries to provide a core asset from the w
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           nftGate: some({
             mint: nftToVerify.publicKey,
@@ -15,7 +15,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -63,6 +63,7 @@ test('it transfers an NFT from the payer to the destination', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           nftPayment: some({
             tokenStandard: MplTokenStandard.NonFungible,
@@ -124,6 +125,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
 
@@ -203,6 +205,7 @@ test('it works when the provided NFT is not on an associated token account', asy
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftPayment: some({
@@ -263,6 +266,7 @@ test('it fails if the payer does not own the right NFT', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftPayment: some({
@@ -315,6 +319,7 @@ test('it fails if the payer tries to provide an NFT from an unverified collectio
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftPayment: some({
@@ -367,6 +372,7 @@ test('it charges a bot tax when trying to pay with the wrong NFT', async (t) =>
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftPayment: some({
@@ -421,6 +427,7 @@ test('it transfers a Programmable NFT from the payer to the destination', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           nftPayment: some({
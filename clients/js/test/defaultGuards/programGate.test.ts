@@ -11,7 +11,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -46,6 +46,7 @@ test('it allows minting with specified program in transaction', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -82,6 +83,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
       })
@@ -117,6 +119,7 @@ test('it forbids minting with unspecified program in transaction', async (t) =>
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -177,6 +180,7 @@ test('it charges a bot tax when minting with unspecified program in transaction'
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -1,7 +1,7 @@
 import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
 import { sol, some, transactionBuilder } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -38,6 +38,7 @@ test('it allows minting until a threshold of NFTs have been redeemed', async (t)
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -74,6 +75,7 @@ test('it forbids minting once the redeemed threshold has been reached', async (t
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -86,6 +88,7 @@ test('it forbids minting once the redeemed threshold has been reached', async (t
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -124,6 +127,7 @@ test('it charges a bot tax when trying to mint once the threshold has been reach
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -136,6 +140,7 @@ test('it charges a bot tax when trying to mint once the threshold has been reach
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -13,6 +13,7 @@ import test from 'ava';
 import {
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   TokenStandard,
 } from '../../src';
@@ -50,6 +51,7 @@ test('it transfers SOL from the payer to the authority pda', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         payer,
         mintArgs: { solPayment: some(true) },
@@ -107,6 +109,7 @@ test('it fails if the payer does not have enough funds', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         mintArgs: { solPayment: some(true) },
       })
@@ -147,6 +150,7 @@ test('it charges a bot tax if the payer does not have enough funds', async (t) =
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         mintArgs: { solPayment: some(true) },
       })
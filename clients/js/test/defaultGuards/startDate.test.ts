@@ -7,7 +7,7 @@ import {
 } from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -46,6 +46,7 @@ test('it allows minting after the start date', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         payer,
         mintArgs: { solPayment: some(true) },
@@ -81,6 +82,7 @@ test('it forbids minting before the start date', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -114,6 +116,7 @@ test('it charges a bot tax when trying to mint before the start date', async (t)
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
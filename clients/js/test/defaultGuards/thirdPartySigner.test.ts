@@ -6,7 +6,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -40,6 +40,7 @@ test('it allows minting when the third party signer is provided', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           thirdPartySigner: some({ signer: thirdPartySigner }),
@@ -78,6 +79,7 @@ test('it forbids minting when the third party signer is wrong', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           thirdPartySigner: some({ signer: wrongThirdPartySigner }),
@@ -117,6 +119,7 @@ test('it charges a bot tax when trying to mint using the wrong third party signe
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           thirdPartySigner: some({ signer: wrongThirdPartySigner }),
@@ -8,7 +8,12 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, fetchGumballMachine, TokenStandard } from '../../src';
+import {
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  TokenStandard,
+} from '../../src';
 import {
   assertItemBought,
   create,
@@ -66,6 +71,7 @@ test('it transfers Token2022 tokens from the payer to the destination', async (t
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           token2022Payment: some({ mint: tokenMint.publicKey, destinationAta }),
         },
@@ -145,6 +151,7 @@ test('it transfers Token2022 tokens from the payer to the fee account', async (t
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           token2022Payment: some({
             mint: tokenMint.publicKey,
@@ -12,7 +12,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -57,6 +57,7 @@ test('it burns a specific token to allow minting', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenBurn: some({ mint: tokenMint.publicKey }),
@@ -116,6 +117,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
 
@@ -176,6 +178,7 @@ test('it may burn multiple tokens from a specific mint', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenBurn: some({ mint: tokenMint.publicKey }),
@@ -234,6 +237,7 @@ test('it fails to mint if there are not enough tokens to burn', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenBurn: some({ mint: tokenMint.publicKey }),
@@ -293,6 +297,7 @@ test('it charges a bot tax when trying to mint without the required amount of to
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenBurn: some({ mint: tokenMint.publicKey }),
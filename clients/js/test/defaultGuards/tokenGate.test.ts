@@ -12,7 +12,7 @@ import {
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import test from 'ava';
-import { draw, TokenStandard } from '../../src';
+import { draw, fetchNextPendingDrawPda, TokenStandard } from '../../src';
 import {
   assertBotTax,
   assertItemBought,
@@ -57,6 +57,7 @@ test('it allows minting when the payer owns a specific token', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenGate: some({ mint: tokenMint.publicKey }),
@@ -106,6 +107,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         buyer,
 
@@ -156,6 +158,7 @@ test('it allows minting when the payer owns multiple tokens from a specific mint
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenGate: some({ mint: tokenMint.publicKey }),
@@ -214,6 +217,7 @@ test('it forbids minting when the owner does not own any required tokens', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenGate: some({ mint: tokenMint.publicKey }),
@@ -262,6 +266,7 @@ test('it forbids minting when the owner does not own enough tokens', async (t) =
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenGate: some({ mint: tokenMint.publicKey }),
@@ -311,6 +316,7 @@ test('it charges a bot tax when trying to mint without the right amount of token
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenGate: some({ mint: tokenMint.publicKey }),
@@ -13,6 +13,7 @@ import test from 'ava';
 import {
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   TokenStandard,
 } from '../../src';
@@ -74,6 +75,7 @@ test('it transfers tokens from the payer to the destination', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           tokenPayment: some({ mint: tokenMint.publicKey }),
         },
@@ -150,6 +152,7 @@ test('it transfers tokens from the payer to the fee account', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           tokenPayment: some({
             mint: tokenMint.publicKey,
@@ -229,6 +232,7 @@ test('it allows minting even when the payer is different from the buyer', async
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: {
           tokenPayment: some({ mint: tokenMint.publicKey }),
@@ -293,6 +297,7 @@ test('it fails if the payer does not have enough tokens', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenPayment: some({ mint: tokenMint.publicKey }),
@@ -354,6 +359,7 @@ test('it charges a bot tax if the payer does not have enough tokens', async (t)
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
 
         mintArgs: {
           tokenPayment: some({ mint: tokenMint.publicKey }),
@@ -419,6 +425,7 @@ test('it fails if a different mint is provided in draw', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           tokenPayment: some({ mint: otherTokenMint.publicKey }),
         },
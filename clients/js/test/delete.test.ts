@@ -17,6 +17,7 @@ import {
   closeGumballMachine,
   deleteGumballMachine,
   draw,
+  fetchNextPendingDrawPda,
   findGumballGuardPda,
   findGumballMachineAuthorityPda,
   getDefaultBuyBackConfig,
@@ -103,6 +104,7 @@ test('it can delete a settled gumball machine with native token', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine: machine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, machine),
         mintArgs: {
           solPayment: some(true),
         },
@@ -184,6 +186,7 @@ test('it can delete a settled gumball machine with payment token', async (t) =>
     .add(
       draw(buyerUmi, {
         gumballMachine: machine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, machine),
         mintArgs: {
           tokenPayment: some({ mint: tokenMint.publicKey }),
         },
@@ -17,6 +17,7 @@ import {
   addNft,
   draw,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   GumballMachine,
   GumballState,
@@ -59,6 +60,7 @@ test('it can mint from a gumball guard with no guards', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
       })
     )
@@ -112,6 +114,7 @@ test('it sets state to SaleEnded on final draw', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
       })
     )
@@ -156,6 +159,7 @@ test('it can mint from a gumball guard with guards', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -208,6 +212,7 @@ test('it can mint from a gumball guard with guards and fee account', async (t) =
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -275,6 +280,7 @@ test('it can mint from a gumball guard with token payment guard', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
         mintArgs: {
           tokenPayment: { mint: tokenMint.publicKey },
         },
@@ -327,6 +333,7 @@ test('it can mint from a gumball guard with groups', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
         group: 'GROUP1',
@@ -365,6 +372,7 @@ test('it cannot mint using the default guards if the gumball guard has groups',
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
         group: none(),
@@ -399,6 +407,7 @@ test('it cannot mint from a group if the provided group label does not exist', a
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         mintArgs: { solPayment: some(true) },
         group: 'GROUPX',
@@ -435,6 +444,7 @@ test('it can mint using an explicit payer', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
         payer,
         mintArgs: { solPayment: some(true) },
@@ -471,6 +481,7 @@ test('it cannot mint from a gumball machine not in sale started state', async (t
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         buyer,
       })
     )
@@ -501,6 +512,7 @@ test('it cannot mint from a gumball machine that has been fully minted', async (
       draw(umi, {
         buyer: umi.identity,
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -512,6 +524,7 @@ test('it cannot mint from a gumball machine that has been fully minted', async (
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
       })
     )
     .sendAndConfirm(umi);
@@ -4,6 +4,7 @@ import test from 'ava';
 import {
   drawFromGumballMachine,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   GumballMachine,
   TokenStandard,
 } from '../src';
@@ -33,6 +34,7 @@ test('it can mint directly from a gumball machine as the mint authority', async
     .add(
       drawFromGumballMachine(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintAuthority: umi.identity,
       })
     )
@@ -70,6 +72,7 @@ test('it cannot mint directly from a gumball machine if we are not the mint auth
     .add(
       drawFromGumballMachine(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintAuthority: mintAuthorityB,
       })
     )
@@ -0,0 +1,166 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  drawMany,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  GumballMachine,
+  GumballState,
+  TokenStandard,
+} from '../src';
+import { assertItemBought, create, createNft, createUmi } from './_setup';
+
+test('it can draw multiple items from a gumball guard with no guards', async (t) => {
+  // Given a gumball machine with a gumball guard that has no guards.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+    groups: [],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we draw two items from the gumball guard.
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 800_000 }))
+    .add(
+      drawMany(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then both items were bought by the buyer.
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyer.publicKey,
+    count: 2,
+  });
+
+  // And the gumball machine was updated.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsRedeemed: 2n,
+    state: GumballState.SaleLive,
+  });
+});
+
+test('it charges the sol payment for every item drawn', async (t) => {
+  // Given a gumball machine with a sol payment guard.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {
+      solPayment: { lamports: sol(1) },
+    },
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we draw both items at once.
+  const buyer = generateSigner(umi);
+  const payer = await generateSignerWithSol(umi, sol(10));
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 800_000 }))
+    .add(
+      drawMany(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        payer,
+        buyer,
+        quantity: 2,
+        mintArgs: {
+          solPayment: some(true),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then both items were bought.
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyer.publicKey,
+    count: 2,
+  });
+
+  // And the payer was charged the price of both items.
+  const payerBalance = await umi.rpc.getBalance(payer.publicKey);
+  t.true(isEqualToAmount(payerBalance, sol(8), sol(0.1)));
+
+  // And the gumball machine sold out.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsRedeemed: 2n,
+    state: GumballState.SaleEnded,
+  });
+});
+
+test('it cannot draw more items than are available', async (t) => {
+  // Given a gumball machine with a single item.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to draw two items from it.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 800_000 }))
+    .add(
+      drawMany(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidQuantity/ });
+
+  // And no item was drawn.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{ itemsRedeemed: 0n });
+});
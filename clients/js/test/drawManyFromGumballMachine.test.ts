@@ -0,0 +1,120 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  drawManyFromGumballMachine,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  GumballMachine,
+  TokenStandard,
+} from '../src';
+import { assertItemBought, create, createNft, createUmi } from './_setup';
+
+test('it can draw multiple items directly from a gumball machine as the mint authority', async (t) => {
+  // Given a loaded gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we draw both items as the mint authority.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      drawManyFromGumballMachine(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        mintAuthority: umi.identity,
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then both items were bought.
+  await assertItemBought(t, umi, { gumballMachine, count: 2 });
+
+  // And the gumball machine was updated.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{ itemsRedeemed: 2n });
+});
+
+test('it cannot draw zero items from a gumball machine', async (t) => {
+  // Given a loaded gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to draw zero items.
+  const promise = transactionBuilder()
+    .add(
+      drawManyFromGumballMachine(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        mintAuthority: umi.identity,
+        quantity: 0,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidQuantity/ });
+
+  // And the gumball machine stayed the same.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{ itemsRedeemed: 0n });
+});
+
+test('it cannot draw multiple items if we are not the mint authority', async (t) => {
+  // Given a loaded gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to draw as another mint authority.
+  const promise = transactionBuilder()
+    .add(
+      drawManyFromGumballMachine(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        mintAuthority: generateSigner(umi),
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, {
+    message: /A has one constraint was violated/,
+  });
+});
@@ -0,0 +1,112 @@
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  endSale,
+  fetchGumballMachine,
+  forceCloseGumballMachine,
+  reclaimUnsoldItem,
+  setForceCloseGracePeriod,
+  TokenStandard,
+} from '../src';
+import { create, createCoreAsset, createUmi } from './_setup';
+
+test('it can force close a settled gumball machine after the grace period', async (t) => {
+  // Given a gumball machine with a grace period of 1 second.
+  const umi = await createUmi();
+  const coreAsset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: coreAsset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(setForceCloseGracePeriod(umi, { gumballMachine, gracePeriod: 1 }))
+    .add(endSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // And the sale end was recorded.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.true(gumballMachineAccount.saleEndedAt > 0n);
+
+  // And every item was settled.
+  await transactionBuilder()
+    .add(
+      reclaimUnsoldItem(umi, {
+        gumballMachine,
+        asset: coreAsset.publicKey,
+        index: 0,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the grace period has elapsed and the authority force closes it.
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+  await transactionBuilder()
+    .add(forceCloseGumballMachine(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine account no longer exists.
+  t.false(await umi.rpc.accountExists(gumballMachine));
+});
+
+test('it cannot force close a gumball machine before the grace period has elapsed', async (t) => {
+  // Given an empty gumball machine whose sale ended a day before it closes.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, { startSale: true });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      setForceCloseGracePeriod(umi, { gumballMachine, gracePeriod: 86400 })
+    )
+    .add(endSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority tries to force close it.
+  const promise = transactionBuilder()
+    .add(forceCloseGumballMachine(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ForceCloseNotAvailable/ });
+});
+
+test('it cannot force close a gumball machine without a grace period', async (t) => {
+  // Given an empty gumball machine whose sale ended without a grace period.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, { startSale: true });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+
+  // When the authority tries to force close it.
+  const promise = transactionBuilder()
+    .add(forceCloseGumballMachine(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ForceCloseNotAvailable/ });
+});
+
+test('it cannot force close a gumball machine that has not been fully settled', async (t) => {
+  // Given a gumball machine whose sale ended with a grace period of 1 second.
+  const umi = await createUmi();
+  const coreAsset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: coreAsset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(setForceCloseGracePeriod(umi, { gumballMachine, gracePeriod: 1 }))
+    .add(endSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // When the grace period has elapsed but the item was never settled.
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+  const promise = transactionBuilder()
+    .add(forceCloseGumballMachine(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /NotAllSettled/ });
+});
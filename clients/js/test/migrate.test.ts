@@ -0,0 +1,49 @@
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import { fetchGumballMachine, migrate, TokenStandard } from '../src';
+import { create, createNft, createUmi } from './_setup';
+
+test('it cannot migrate a gumball machine that is already up to date', async (t) => {
+  // Given a gumball machine created with the current version.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const { version } = await fetchGumballMachine(umi, gumballMachine);
+
+  // When we try to migrate it.
+  const promise = transactionBuilder()
+    .add(migrate(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidVersion/ });
+
+  // And the gumball machine was not changed.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.is(gumballMachineAccount.version, version);
+  t.is(gumballMachineAccount.itemsLoaded, 1);
+});
+
+test('it cannot migrate a gumball machine as another wallet', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi);
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When another wallet tries to migrate it.
+  const promise = transactionBuilder()
+    .add(migrate(umi, { gumballMachine, authority: generateSigner(umi) }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, {
+    message: /A has one constraint was violated/,
+  });
+});
@@ -0,0 +1,266 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  claimNft,
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  GumballMachine,
+  GumballState,
+  pauseGumballGuardSale,
+  pauseSale,
+  resumeGumballGuardSale,
+  resumeSale,
+  TokenStandard,
+} from '../src';
+import {
+  assertItemBought,
+  create,
+  createNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+test('it can pause and resume a live sale', async (t) => {
+  // Given a gumball machine with a live sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we pause the sale.
+  await transactionBuilder()
+    .add(pauseSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine is paused.
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    state: GumballState.Paused,
+  });
+
+  // And when we resume the sale.
+  await transactionBuilder()
+    .add(resumeSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then the sale is live again.
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    state: GumballState.SaleLive,
+  });
+});
+
+test('it cannot draw from a paused sale', async (t) => {
+  // Given a paused gumball machine with a gumball guard.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(pauseSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // When we try to draw from it.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+
+  // And once the sale is resumed, we can draw again.
+  await transactionBuilder()
+    .add(resumeSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(umi);
+  await assertItemBought(t, umi, { gumballMachine });
+});
+
+test('it can claim drawn items while the sale is paused', async (t) => {
+  // Given a gumball machine with a drawn item.
+  const umi = await createUmi();
+  const nft = await createNft(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: nft.publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // And the sale was paused before the draw was revealed.
+  await transactionBuilder()
+    .add(pauseSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+  await revealPendingDraw(buyerUmi, { gumballMachine });
+
+  // When the buyer claims the item.
+  await transactionBuilder()
+    .add(
+      claimNft(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        mint: nft.publicKey,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the item was claimed.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    state: GumballState.Paused,
+    items: [{ isClaimed: true }],
+  });
+});
+
+test('the gumball guard authority can pause and resume a wrapped sale', async (t) => {
+  // Given a gumball machine wrapped in a gumball guard.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When the gumball guard authority pauses the sale.
+  await transactionBuilder()
+    .add(pauseGumballGuardSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine is paused.
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    state: GumballState.Paused,
+  });
+
+  // And when it resumes the sale.
+  await transactionBuilder()
+    .add(resumeGumballGuardSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then the sale is live again.
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    state: GumballState.SaleLive,
+  });
+});
+
+test('it cannot pause a sale that is not live', async (t) => {
+  // Given a gumball machine that did not start its sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to pause the sale.
+  const promise = transactionBuilder()
+    .add(pauseSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('it cannot resume a sale that is not paused', async (t) => {
+  // Given a gumball machine with a live sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to resume the sale.
+  const promise = transactionBuilder()
+    .add(resumeSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('it cannot pause a sale as another wallet', async (t) => {
+  // Given a gumball machine with a live sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When another wallet tries to pause the sale.
+  const promise = transactionBuilder()
+    .add(pauseSale(umi, { gumballMachine, authority: generateSigner(umi) }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidAuthority/ });
+});
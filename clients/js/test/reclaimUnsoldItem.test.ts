@@ -0,0 +1,211 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  setComputeUnitLimit,
+  TokenState,
+} from '@metaplex-foundation/mpl-toolbox';
+import { none, publicKey, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  approveAddItem,
+  draw,
+  endSale,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  GumballMachine,
+  reclaimUnsoldItem,
+  requestAddCoreAsset,
+  startSale,
+  TokenStandard,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+test('a seller can reclaim an unsold core asset after the sale has ended', async (t) => {
+  // Given a gumball machine with a core asset from another seller.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 5 },
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+  await transactionBuilder()
+    .add(
+      approveAddItem(umi, {
+        gumballMachine,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the sale ended without the item being sold.
+  await transactionBuilder()
+    .add(startSale(umi, { gumballMachine }))
+    .add(endSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // When the seller reclaims the item.
+  await transactionBuilder()
+    .add(
+      reclaimUnsoldItem(sellerUmi, {
+        gumballMachine,
+        authority: umi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        index: 0,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // Then the item is settled.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsSettled: 1n,
+    items: [{ index: 0, isSettled: true, buyer: undefined }],
+  });
+
+  // And the seller owns the unfrozen asset.
+  const asset = await fetchAssetV1(umi, coreAsset.publicKey);
+  t.like(asset, <AssetV1>{
+    freezeDelegate: undefined,
+    transferDelegate: undefined,
+    owner: sellerUmi.identity.publicKey,
+  });
+});
+
+test('a seller can reclaim an unsold nft after the sale has ended', async (t) => {
+  // Given a gumball machine with an nft whose sale has ended.
+  const umi = await createUmi();
+  const nft = await createNft(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: nft.publicKey, tokenStandard: TokenStandard.NonFungible }],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+
+  // When the seller reclaims the item.
+  await transactionBuilder()
+    .add(
+      reclaimUnsoldItem(umi, {
+        gumballMachine,
+        asset: nft.publicKey,
+        index: 0,
+        tokenStandard: TokenStandard.NonFungible,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the item is settled.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsSettled: 1n,
+    items: [{ index: 0, isSettled: true }],
+  });
+
+  // And the seller holds the unfrozen nft.
+  const tokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi, {
+      mint: nft.publicKey,
+      owner: umi.identity.publicKey,
+    })[0]
+  );
+  t.like(tokenAccount, {
+    state: TokenState.Initialized,
+    owner: umi.identity.publicKey,
+    amount: 1n,
+    delegate: none(),
+  });
+});
+
+test('a seller cannot reclaim an item before the sale has ended', async (t) => {
+  // Given a gumball machine with a core asset whose sale is live.
+  const umi = await createUmi();
+  const coreAsset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: coreAsset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+  });
+
+  // When the seller tries to reclaim the item.
+  const promise = transactionBuilder()
+    .add(
+      reclaimUnsoldItem(umi, {
+        gumballMachine: gumballMachineSigner.publicKey,
+        asset: coreAsset.publicKey,
+        index: 0,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('a seller cannot reclaim an item that was sold', async (t) => {
+  // Given a gumball machine with two core assets.
+  const umi = await createUmi();
+  const coreAssets = [await createCoreAsset(umi), await createCoreAsset(umi)];
+  const gumballMachineSigner = await create(umi, {
+    items: coreAssets.map((coreAsset) => ({
+      id: coreAsset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // And a buyer who drew one of them before the sale ended.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+
+  // When the seller tries to reclaim the sold item.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const soldItem = gumballMachineAccount.items.find((i) => i.buyer != null)!;
+  const promise = transactionBuilder()
+    .add(
+      reclaimUnsoldItem(umi, {
+        gumballMachine,
+        asset: publicKey(soldItem.mint),
+        index: soldItem.index,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+});
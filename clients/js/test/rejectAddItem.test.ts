@@ -0,0 +1,205 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  TokenState,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
+  rejectAddItem,
+  requestAddCoreAsset,
+  requestAddNft,
+  safeFetchAddItemRequestFromSeeds,
+  safeFetchSellerHistoryFromSeeds,
+  TokenStandard,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createNft,
+  createProgrammableNft,
+  createUmi,
+} from './_setup';
+
+test('it can reject a request to add core asset to a gumball machine', async (t) => {
+  // Given a Gumball Machine with a request to add a core asset.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When the gumball machine authority rejects the request.
+  await transactionBuilder()
+    .add(
+      rejectAddItem(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        tokenStandard: TokenStandard.Core,
+        reason: some(1),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the request is closed.
+  const addItemRequestAccount = await safeFetchAddItemRequestFromSeeds(umi, {
+    asset: coreAsset.publicKey,
+  });
+  t.falsy(addItemRequestAccount);
+
+  // And the asset is unfrozen and revoked.
+  const asset = await fetchAssetV1(umi, coreAsset.publicKey);
+  t.like(asset, <AssetV1>{
+    freezeDelegate: undefined,
+    transferDelegate: undefined,
+    owner: sellerUmi.identity.publicKey,
+  });
+
+  // And the seller history is closed.
+  const sellerHistoryAccount = await safeFetchSellerHistoryFromSeeds(umi, {
+    gumballMachine: gumballMachine.publicKey,
+    seller: sellerUmi.identity.publicKey,
+  });
+  t.falsy(sellerHistoryAccount);
+});
+
+test('it can reject a request to add an nft to a gumball machine', async (t) => {
+  // Given a Gumball Machine with a request to add an nft.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  const sellerUmi = await createUmi();
+  const nft = await createNft(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddNft(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        mint: nft.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When the gumball machine authority rejects the request.
+  await transactionBuilder()
+    .add(
+      rejectAddItem(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: nft.publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the request is closed.
+  const addItemRequestAccount = await safeFetchAddItemRequestFromSeeds(umi, {
+    asset: nft.publicKey,
+  });
+  t.falsy(addItemRequestAccount);
+
+  // And the nft is unfrozen and revoked.
+  const tokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi, {
+      mint: nft.publicKey,
+      owner: sellerUmi.identity.publicKey,
+    })[0]
+  );
+  t.like(tokenAccount, {
+    state: TokenState.Initialized,
+    owner: sellerUmi.identity.publicKey,
+    delegate: none(),
+  });
+});
+
+test('it can reject a request to add a pnft to a gumball machine', async (t) => {
+  // Given a Gumball Machine with a request to add a pnft.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  const sellerUmi = await createUmi();
+  const nft = await createProgrammableNft(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddNft(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        mint: nft.publicKey,
+        authRulesProgram: MPL_TOKEN_AUTH_RULES_PROGRAM_ID,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When the gumball machine authority rejects the request.
+  await transactionBuilder()
+    .add(
+      rejectAddItem(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: nft.publicKey,
+        tokenStandard: TokenStandard.ProgrammableNonFungible,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the request is closed.
+  const addItemRequestAccount = await safeFetchAddItemRequestFromSeeds(umi, {
+    asset: nft.publicKey,
+  });
+  t.falsy(addItemRequestAccount);
+
+  // And the seller history is closed.
+  const sellerHistoryAccount = await safeFetchSellerHistoryFromSeeds(umi, {
+    gumballMachine: gumballMachine.publicKey,
+    seller: sellerUmi.identity.publicKey,
+  });
+  t.falsy(sellerHistoryAccount);
+});
+
+test('it cannot reject a request as a different authority', async (t) => {
+  // Given a Gumball Machine with a request to add a core asset.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+
+  const sellerUmi = await createUmi();
+  const coreAsset = await createCoreAsset(sellerUmi);
+  await transactionBuilder()
+    .add(
+      requestAddCoreAsset(sellerUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // When a different authority tries to reject the request.
+  const promise = transactionBuilder()
+    .add(
+      rejectAddItem(umi, {
+        authority: generateSigner(umi),
+        gumballMachine: gumballMachine.publicKey,
+        seller: sellerUmi.identity.publicKey,
+        asset: coreAsset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then an error is thrown.
+  await t.throwsAsync(promise, { message: /InvalidAuthority/ });
+});
@@ -0,0 +1,184 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCoreAsset,
+  fetchGumballMachine,
+  findSellerHistoryPda,
+  GumballMachine,
+  removeCoreAssetsSpan,
+  safeFetchSellerHistory,
+  TokenStandard,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createCoreCollection,
+  createUmi,
+} from './_setup';
+
+test('it can remove a span of core assets from a gumball machine', async (t) => {
+  // Given a Gumball Machine with 3 core assets.
+  const umi = await createUmi();
+  const coreAssets = [
+    await createCoreAsset(umi),
+    await createCoreAsset(umi),
+    await createCoreAsset(umi),
+  ];
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: coreAssets.map((coreAsset) => ({
+      id: coreAsset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+  });
+
+  // When we remove the last two assets.
+  await transactionBuilder()
+    .add(
+      removeCoreAssetsSpan(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 1,
+        endIndex: 2,
+        items: coreAssets.slice(1).map((coreAsset) => ({
+          asset: coreAsset.publicKey,
+          seller: umi.identity.publicKey,
+        })),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then only the first asset remains in the Gumball Machine.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <Pick<GumballMachine, 'itemsLoaded'>>{
+    itemsLoaded: 1,
+  });
+  t.deepEqual(
+    gumballMachineAccount.items.map((item) => item.mint),
+    [coreAssets[0].publicKey]
+  );
+
+  // And the removed assets are unfrozen and revoked.
+  await Promise.all(
+    coreAssets.slice(1).map(async (coreAsset) => {
+      const asset = await fetchAssetV1(umi, coreAsset.publicKey);
+      t.like(asset, <AssetV1>{
+        freezeDelegate: undefined,
+        transferDelegate: undefined,
+        owner: umi.identity.publicKey,
+      });
+    })
+  );
+});
+
+test('it can remove a span of core assets that belong to a collection', async (t) => {
+  // Given a Gumball Machine with 2 core assets from a collection.
+  const umi = await createUmi();
+  const collection = await createCoreCollection(umi);
+  const coreAssets = [
+    await createCoreAsset(umi, { collection: collection.publicKey }),
+    await createCoreAsset(umi, { collection: collection.publicKey }),
+  ];
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const builder = transactionBuilder();
+  coreAssets.forEach((coreAsset) =>
+    builder.add(
+      addCoreAsset(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        asset: coreAsset.publicKey,
+        collection: collection.publicKey,
+      })
+    )
+  );
+  await builder.sendAndConfirm(umi);
+
+  // When we remove both assets.
+  await transactionBuilder()
+    .add(
+      removeCoreAssetsSpan(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 0,
+        endIndex: 1,
+        items: coreAssets.map((coreAsset) => ({
+          asset: coreAsset.publicKey,
+          seller: umi.identity.publicKey,
+          collection: collection.publicKey,
+        })),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the Gumball Machine is empty.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <Pick<GumballMachine, 'itemsLoaded' | 'items'>>{
+    itemsLoaded: 0,
+    items: [],
+  });
+
+  // And the seller history no longer exists.
+  const sellerHistoryAccount = await safeFetchSellerHistory(
+    umi,
+    findSellerHistoryPda(umi, {
+      gumballMachine: gumballMachine.publicKey,
+      seller: umi.identity.publicKey,
+    })[0]
+  );
+  t.falsy(sellerHistoryAccount);
+});
+
+test('it cannot remove a span of core assets as a different authority', async (t) => {
+  // Given a Gumball Machine with 1 core asset.
+  const umi = await createUmi();
+  const coreAsset = await createCoreAsset(umi);
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: [{ id: coreAsset.publicKey, tokenStandard: TokenStandard.Core }],
+  });
+
+  // When we try to remove it as a different authority.
+  const promise = transactionBuilder()
+    .add(
+      removeCoreAssetsSpan(umi, {
+        authority: generateSigner(umi),
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 0,
+        endIndex: 0,
+        items: [{ asset: coreAsset.publicKey, seller: umi.identity.publicKey }],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then an error is thrown.
+  await t.throwsAsync(promise, { message: /InvalidAuthority/ });
+});
+
+test('it cannot remove a span that covers every index', async (t) => {
+  // Given a Gumball Machine with 1 core asset.
+  const umi = await createUmi();
+  const coreAsset = await createCoreAsset(umi);
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: [{ id: coreAsset.publicKey, tokenStandard: TokenStandard.Core }],
+  });
+
+  // When we try to remove a span ending at the largest index.
+  const promise = transactionBuilder()
+    .add(
+      removeCoreAssetsSpan(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 0,
+        endIndex: 0xffffffff,
+        items: [{ asset: coreAsset.publicKey, seller: umi.identity.publicKey }],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then an error is thrown instead of the span length overflowing.
+  await t.throwsAsync(promise, { message: /InvalidInputLength/ });
+});
@@ -0,0 +1,156 @@
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  TokenState,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  fetchGumballMachine,
+  findSellerHistoryPda,
+  GumballMachine,
+  removeNftsSpan,
+  safeFetchSellerHistory,
+  TokenStandard,
+} from '../src';
+import { create, createNft, createUmi } from './_setup';
+
+test('it can remove a span of nfts from a gumball machine', async (t) => {
+  // Given a Gumball Machine with 3 nfts.
+  const umi = await createUmi();
+  const nfts = [
+    await createNft(umi),
+    await createNft(umi),
+    await createNft(umi),
+  ];
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: nfts.map((nft) => ({
+      id: nft.publicKey,
+      tokenStandard: TokenStandard.NonFungible,
+    })),
+  });
+
+  // When we remove the first two nfts.
+  await transactionBuilder()
+    .add(
+      removeNftsSpan(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 0,
+        endIndex: 1,
+        items: nfts.slice(0, 2).map((nft) => ({
+          mint: nft.publicKey,
+          seller: umi.identity.publicKey,
+        })),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then only the last nft remains in the Gumball Machine.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <Pick<GumballMachine, 'itemsLoaded'>>{
+    itemsLoaded: 1,
+  });
+  t.deepEqual(
+    gumballMachineAccount.items.map((item) => item.mint),
+    [nfts[2].publicKey]
+  );
+
+  // And the removed nfts are unfrozen and revoked.
+  await Promise.all(
+    nfts.slice(0, 2).map(async (nft) => {
+      const tokenAccount = await fetchToken(
+        umi,
+        findAssociatedTokenPda(umi, {
+          mint: nft.publicKey,
+          owner: umi.identity.publicKey,
+        })[0]
+      );
+      t.like(tokenAccount, {
+        state: TokenState.Initialized,
+        owner: umi.identity.publicKey,
+        delegate: none(),
+      });
+    })
+  );
+
+  // And the seller history still exists for the remaining nft.
+  const sellerHistoryAccount = await safeFetchSellerHistory(
+    umi,
+    findSellerHistoryPda(umi, {
+      gumballMachine: gumballMachine.publicKey,
+      seller: umi.identity.publicKey,
+    })[0]
+  );
+  t.like(sellerHistoryAccount, { itemCount: 1n });
+});
+
+test('it cannot remove a span of nfts as a different authority', async (t) => {
+  // Given a Gumball Machine with 2 nfts.
+  const umi = await createUmi();
+  const nfts = [await createNft(umi), await createNft(umi)];
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: nfts.map((nft) => ({
+      id: nft.publicKey,
+      tokenStandard: TokenStandard.NonFungible,
+    })),
+  });
+
+  // When we try to remove them as a different authority.
+  const promise = transactionBuilder()
+    .add(
+      removeNftsSpan(umi, {
+        authority: generateSigner(umi),
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 0,
+        endIndex: 1,
+        items: nfts.map((nft) => ({
+          mint: nft.publicKey,
+          seller: umi.identity.publicKey,
+        })),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then an error is thrown.
+  await t.throwsAsync(promise, { message: /InvalidAuthority/ });
+});
+
+test('it cannot remove a span of nfts with mismatched mints', async (t) => {
+  // Given a Gumball Machine with 2 nfts.
+  const umi = await createUmi();
+  const nfts = [await createNft(umi), await createNft(umi)];
+  const gumballMachine = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: nfts.map((nft) => ({
+      id: nft.publicKey,
+      tokenStandard: TokenStandard.NonFungible,
+    })),
+  });
+
+  // When we pass the nfts in the wrong order.
+  const promise = transactionBuilder()
+    .add(
+      removeNftsSpan(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        startIndex: 0,
+        endIndex: 1,
+        items: [...nfts].reverse().map((nft) => ({
+          mint: nft.publicKey,
+          seller: umi.identity.publicKey,
+        })),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then an error is thrown.
+  await t.throwsAsync(promise, { message: /InvalidMint/ });
+});
@@ -0,0 +1,285 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  addAmounts,
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  reroll,
+  TokenStandard,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+const createWithRerollGroup = async (umi: Umi) => {
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createCoreAsset(umi)).publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+      {
+        id: (await createCoreAsset(umi)).publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+    startSale: true,
+    guards: {},
+    groups: [
+      {
+        label: 'draw',
+        guards: {
+          solPayment: some({ lamports: sol(1) }),
+        },
+      },
+      {
+        label: 'reroll',
+        guards: {
+          solPayment: some({ lamports: sol(1) }),
+          rerollFee: some({ amount: sol(0.5).basisPoints }),
+        },
+      },
+    ],
+  });
+  return gumballMachineSigner.publicKey;
+};
+
+test('it can reroll an unclaimed prize for a fee', async (t) => {
+  // Given a gumball machine with a reroll group.
+  const umi = await createUmi();
+  const gumballMachine = await createWithRerollGroup(umi);
+
+  // And an item drawn by a buyer.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        group: 'draw',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const drawnItem = gumballMachineAccount.items.find(
+    (item) => item.buyer === buyerUmi.identity.publicKey
+  )!;
+  const authorityPda = findGumballMachineAuthorityPda(umi, {
+    gumballMachine,
+  })[0];
+  const authorityPdaPreBalance = await umi.rpc.getBalance(authorityPda);
+
+  // When the buyer rerolls the item.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      reroll(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        index: drawnItem.index,
+        group: 'reroll',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // Then the buyer still has a single drawn item.
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 1n,
+  });
+  t.is(
+    gumballMachineAccount.items.filter(
+      (item) => item.buyer === buyerUmi.identity.publicKey
+    ).length,
+    1
+  );
+
+  // And the reroll fee was charged instead of the price.
+  const authorityPdaPostBalance = await umi.rpc.getBalance(authorityPda);
+  t.true(
+    isEqualToAmount(
+      authorityPdaPostBalance,
+      addAmounts(authorityPdaPreBalance, sol(0.5)),
+      sol(0.01)
+    )
+  );
+});
+
+test('it cannot reroll with a group without the reroll fee guard', async (t) => {
+  // Given a gumball machine with an item drawn by a buyer.
+  const umi = await createUmi();
+  const gumballMachine = await createWithRerollGroup(umi);
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        group: 'draw',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const drawnItem = gumballMachineAccount.items.find(
+    (item) => item.buyer === buyerUmi.identity.publicKey
+  )!;
+
+  // When the buyer tries to reroll with the draw group.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      reroll(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        index: drawnItem.index,
+        group: 'draw',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RerollFeeNotEnabled/ });
+});
+
+test('it cannot draw with a reroll group', async (t) => {
+  // Given a gumball machine with a reroll group.
+  const umi = await createUmi();
+  const gumballMachine = await createWithRerollGroup(umi);
+
+  // When a buyer tries to draw with the reroll group.
+  const buyerUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        group: 'reroll',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RerollOnlyGroup/ });
+});
+
+test('it cannot reroll while a draw is pending', async (t) => {
+  // Given a gumball machine with an item drawn by a buyer.
+  const umi = await createUmi();
+  const gumballMachine = await createWithRerollGroup(umi);
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        group: 'draw',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const drawnItem = gumballMachineAccount.items.find(
+    (item) => item.buyer === buyerUmi.identity.publicKey
+  )!;
+
+  // And a draw of another buyer waiting to be revealed.
+  const otherUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(otherUmi, { units: 600_000 }))
+    .add(
+      draw(otherUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(otherUmi, gumballMachine),
+        group: 'draw',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(otherUmi);
+
+  // When the buyer tries to reroll the item.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      reroll(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        index: drawnItem.index,
+        group: 'reroll',
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemsPendingReveal/ });
+});
+
+test('it cannot set a reroll fee in a group with several payment guards', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+
+  // When we try to add a reroll fee to a group that also inherits a token burn
+  // from the default guards.
+  const promise = create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    guards: {
+      tokenBurn: some({ amount: 1, mint: generateSigner(umi).publicKey }),
+    },
+    groups: [
+      {
+        label: 'reroll',
+        guards: {
+          solPayment: some({ lamports: sol(1) }),
+          rerollFee: some({ amount: sol(0.5).basisPoints }),
+        },
+      },
+    ],
+  });
+
+  // Then we expect a program error, since the fee would be charged in lamports
+  // and in tokens.
+  await t.throwsAsync(promise, { message: /MultiplePaymentGuards/ });
+});
@@ -0,0 +1,179 @@
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCoreAsset,
+  fetchGumballMachine,
+  GumballMachine,
+  resizeCapacity,
+  setTierWeights,
+  TokenStandard,
+} from '../src';
+import { create, createCoreAsset, createNft, createUmi } from './_setup';
+
+test('it can increase the item capacity of a gumball machine', async (t) => {
+  // Given a full gumball machine with a capacity of 1 item.
+  const umi = await createUmi();
+  const nft = await createNft(umi);
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 1 },
+    items: [{ id: nft.publicKey, tokenStandard: TokenStandard.NonFungible }],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we increase its capacity to 3 items.
+  await transactionBuilder()
+    .add(resizeCapacity(umi, { gumballMachine, itemCapacity: 3 }))
+    .sendAndConfirm(umi);
+
+  // Then the capacity was updated and the loaded item was kept.
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    settings: { itemCapacity: 3n },
+    itemsLoaded: 1,
+    items: [{ index: 0, mint: nft.publicKey, isDrawn: false }],
+  });
+
+  // And more items can be added.
+  const coreAsset = await createCoreAsset(umi);
+  await transactionBuilder()
+    .add(addCoreAsset(umi, { gumballMachine, asset: coreAsset.publicKey }))
+    .sendAndConfirm(umi);
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsLoaded: 2,
+    items: [
+      { index: 0, mint: nft.publicKey },
+      { index: 1, mint: coreAsset.publicKey },
+    ],
+  });
+});
+
+test('it increases the item capacity over several calls when the account grows by more than 10KiB', async (t) => {
+  // Given a full gumball machine with a capacity of 1 item.
+  const umi = await createUmi();
+  const nft = await createNft(umi);
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 1 },
+    items: [{ id: nft.publicKey, tokenStandard: TokenStandard.NonFungible }],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we increase its capacity to 300 items once.
+  await transactionBuilder()
+    .add(resizeCapacity(umi, { gumballMachine, itemCapacity: 300 }))
+    .sendAndConfirm(umi);
+
+  // Then the account grew but the capacity is unchanged.
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    settings: { itemCapacity: 1n },
+    itemsLoaded: 1,
+  });
+
+  // And once we keep resizing until the account is large enough.
+  for (let call = 0; call < 5; call += 1) {
+    if (gumballMachineAccount.settings.itemCapacity === 300n) break;
+    await transactionBuilder()
+      .add(resizeCapacity(umi, { gumballMachine, itemCapacity: 300 }))
+      .sendAndConfirm(umi);
+    gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  }
+
+  // The capacity was updated and the loaded item was kept.
+  t.like(gumballMachineAccount, <GumballMachine>{
+    settings: { itemCapacity: 300n },
+    itemsLoaded: 1,
+    items: [{ index: 0, mint: nft.publicKey, isDrawn: false }],
+  });
+});
+
+test('it can decrease the item capacity down to the items loaded', async (t) => {
+  // Given a gumball machine with 1 item and a capacity of 5 items.
+  const umi = await createUmi();
+  const nft = await createNft(umi);
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: [{ id: nft.publicKey, tokenStandard: TokenStandard.NonFungible }],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const getAccountSize = async () => {
+    const account = await umi.rpc.getAccount(gumballMachine);
+    return account.exists ? account.data.length : 0;
+  };
+  const sizeBefore = await getAccountSize();
+
+  // When we decrease its capacity to 1 item.
+  await transactionBuilder()
+    .add(resizeCapacity(umi, { gumballMachine, itemCapacity: 1 }))
+    .sendAndConfirm(umi);
+
+  // Then the capacity was updated and the loaded item was kept.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    settings: { itemCapacity: 1n },
+    itemsLoaded: 1,
+    items: [{ index: 0, mint: nft.publicKey, isDrawn: false }],
+  });
+
+  // And the account was shrunk.
+  t.true((await getAccountSize()) < sizeBefore);
+});
+
+test('it keeps the settings stored after the items when resizing', async (t) => {
+  // Given a gumball machine with custom tier weights.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 2 },
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const weights = [10, 20, 30, 40, 50, 60, 70, 80];
+  await transactionBuilder()
+    .add(setTierWeights(umi, { gumballMachine, weights }))
+    .sendAndConfirm(umi);
+
+  // When we increase its capacity.
+  await transactionBuilder()
+    .add(resizeCapacity(umi, { gumballMachine, itemCapacity: 20 }))
+    .sendAndConfirm(umi);
+
+  // Then the tier config was moved with the rest of the data.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.deepEqual(gumballMachineAccount.tierConfig.weights, weights);
+  t.deepEqual(
+    gumballMachineAccount.tierConfig.itemCounts,
+    [1, 0, 0, 0, 0, 0, 0, 0]
+  );
+});
+
+test('it cannot decrease the item capacity below the items loaded', async (t) => {
+  // Given a gumball machine with 2 items.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to decrease its capacity to 1 item.
+  const promise = transactionBuilder()
+    .add(resizeCapacity(umi, { gumballMachine, itemCapacity: 1 }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidSettingUpdate/ });
+});
@@ -0,0 +1,414 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  draw,
+  drawMany,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  fetchPendingDrawFromSeeds,
+  findPendingDrawPda,
+  GumballMachine,
+  PendingDraw,
+  revealDraw,
+  safeFetchPendingDrawFromSeeds,
+  TokenStandard,
+} from '../src';
+import {
+  assertItemBought,
+  create,
+  createNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+test('it reserves an item when drawing until the draw is revealed', async (t) => {
+  // Given a gumball machine with a gumball guard that has no guards.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we draw from the gumball guard.
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then a pending draw was created for the buyer.
+  const pendingDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 0,
+  });
+  t.like(pendingDraw, <PendingDraw>{
+    gumballMachine,
+    buyer: buyer.publicKey,
+    payer: umi.identity.publicKey,
+    quantity: 1,
+  });
+
+  // And the item is reserved but not assigned to the buyer yet.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsRedeemed: 1n,
+    itemsPending: 1,
+  });
+  t.true(gumballMachineAccount.items.every((item) => !item.buyer));
+  t.true(gumballMachineAccount.items.every((item) => !item.isDrawn));
+});
+
+test('anyone can reveal a pending draw once the target slot has passed', async (t) => {
+  // Given a gumball machine with a pending draw.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When a third party reveals the draw.
+  const thirdPartyUmi = await createUmi();
+  await revealPendingDraw(thirdPartyUmi, {
+    gumballMachine,
+    buyer: buyer.publicKey,
+  });
+
+  // Then the item was assigned to the buyer.
+  await assertItemBought(t, umi, { gumballMachine, buyer: buyer.publicKey });
+
+  // And the pending draw was closed.
+  t.is(
+    await safeFetchPendingDrawFromSeeds(umi, {
+      gumballMachine,
+      drawNumber: 0,
+    }),
+    null
+  );
+
+  // And the gumball machine has no pending items.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsRedeemed: 1n,
+    itemsPending: 0,
+  });
+});
+
+test('it reveals every item of a pending draw with multiple items', async (t) => {
+  // Given a gumball machine with three items.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we draw all three items and reveal the draw.
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 800_000 }))
+    .add(
+      drawMany(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+        quantity: 3,
+      })
+    )
+    .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
+
+  // Then every item was assigned to the buyer.
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyer.publicKey,
+    count: 3,
+  });
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.true(gumballMachineAccount.items.every((item) => item.isDrawn));
+});
+
+test('it cannot reveal a draw before the target slot', async (t) => {
+  // Given a gumball machine with a pending draw.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to reveal it straight away.
+  const promise = transactionBuilder()
+    .add(
+      revealDraw(umi, {
+        gumballMachine,
+        pendingDraw: (
+          await fetchPendingDrawFromSeeds(umi, {
+            gumballMachine,
+            drawNumber: 0,
+          })
+        ).publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RevealNotReady/ });
+
+  // And the item is still pending.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{ itemsPending: 1 });
+});
+
+test('it assigns each item to a single buyer across batched draws', async (t) => {
+  // Given a gumball machine with eight items.
+  const umi = await createUmi();
+  const items = [];
+  for (let i = 0; i < 8; i += 1) {
+    items.push({
+      id: (await createNft(umi)).publicKey,
+      tokenStandard: TokenStandard.NonFungible,
+    });
+  }
+  const gumballMachineSigner = await create(umi, {
+    items,
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When two buyers draw four items each.
+  const buyerA = generateSigner(umi);
+  const buyerB = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 1_400_000 }))
+    .add(
+      drawMany(umi, {
+        gumballMachine,
+        pendingDraw: findPendingDrawPda(umi, { gumballMachine, drawNumber: 0 }),
+        buyer: buyerA,
+        quantity: 4,
+      })
+    )
+    .add(
+      drawMany(umi, {
+        gumballMachine,
+        pendingDraw: findPendingDrawPda(umi, { gumballMachine, drawNumber: 1 }),
+        buyer: buyerB,
+        quantity: 4,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then each buyer was assigned four different items.
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyerA.publicKey,
+    count: 4,
+  });
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyerB.publicKey,
+    count: 4,
+  });
+
+  // And every item was drawn exactly once.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.true(gumballMachineAccount.items.every((item) => item.isDrawn));
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsRedeemed: 8n,
+    itemsPending: 0,
+  });
+});
+
+test('a buyer can have several draws pending at once', async (t) => {
+  // Given a gumball machine with two items.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When the same buyer draws twice before any draw is revealed.
+  const buyer = generateSigner(umi);
+  for (let i = 0; i < 2; i += 1) {
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        draw(umi, {
+          gumballMachine,
+          pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+          buyer,
+        })
+      )
+      .sendAndConfirm(umi);
+  }
+
+  // Then each draw has its own pending draw account.
+  const firstDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 0,
+  });
+  const secondDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 1,
+  });
+  t.like(firstDraw, <PendingDraw>{ buyer: buyer.publicKey, drawNumber: 0n });
+  t.like(secondDraw, <PendingDraw>{ buyer: buyer.publicKey, drawNumber: 1n });
+
+  // And both items are assigned to the buyer once revealed.
+  await assertItemBought(t, umi, {
+    gumballMachine,
+    buyer: buyer.publicKey,
+    count: 2,
+  });
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    drawsCommitted: 2n,
+    drawsRevealed: 2n,
+  });
+});
+
+test('it cannot reveal a draw before the earlier draws', async (t) => {
+  // Given a gumball machine with two pending draws.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  for (let i = 0; i < 2; i += 1) {
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        draw(umi, {
+          gumballMachine,
+          pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+          buyer: generateSigner(umi),
+        })
+      )
+      .sendAndConfirm(umi);
+  }
+
+  // And the target slot of the second draw has passed.
+  const secondDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 1,
+  });
+  while ((await umi.rpc.getSlot()) <= secondDraw.targetSlot) {
+    await new Promise((resolve) => {
+      setTimeout(resolve, 400);
+    });
+  }
+
+  // When we try to reveal the second draw first.
+  const promise = transactionBuilder()
+    .add(
+      revealDraw(umi, {
+        gumballMachine,
+        pendingDraw: secondDraw.publicKey,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RevealOutOfOrder/ });
+
+  // And both draws are still pending.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsPending: 2,
+    drawsRevealed: 0n,
+  });
+});
@@ -22,7 +22,9 @@ import {
   addNft,
   closeGumballMachine,
   draw,
+  drawMany,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballGuardPda,
   findGumballMachineAuthorityPda,
   getDefaultBuyBackConfig,
@@ -40,6 +42,7 @@ import {
   createNft,
   createProgrammableNft,
   createUmi,
+  revealPendingDraw,
 } from './_setup';
 
 test('it can sell an nft item', async (t) => {
@@ -87,9 +90,14 @@ test('it can sell an nft item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const preBuyerTokenAccount = await umi.rpc.getBalance(
     buyerUmi.identity.publicKey
@@ -202,9 +210,14 @@ test('it can sell a pnft item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -327,9 +340,14 @@ test('it can sell a tokens item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -438,9 +456,14 @@ test('it can sell a core asset item', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -565,9 +588,14 @@ test('it can sell an item when cutoff pct has not been reached', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const drawnIndex = await fetchGumballMachine(umi, gumballMachine).then(
     (gumballMachine) => gumballMachine.items.findIndex((item) => item.isDrawn)
@@ -654,6 +682,7 @@ test('it can sell using spl token buy back funds', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
         mintArgs: {
           tokenPayment: {
             mint: tokenMint.publicKey,
@@ -662,6 +691,10 @@ test('it can sell using spl token buy back funds', async (t) => {
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Buyer can sell back to the seller
   await transactionBuilder()
@@ -815,9 +848,14 @@ test('it can settle and close a gumball after selling a token item back', async
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -935,9 +973,14 @@ test('it can sell an item with a marketplace fee', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const preBuyerBalance = await umi.rpc.getBalance(buyerUmi.identity.publicKey);
   const preFeeBalance = await umi.rpc.getBalance(feeAccount.publicKey);
@@ -1064,6 +1107,7 @@ test('it can sell an item with a marketplace fee using a payment mint', async (t
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
         mintArgs: {
           tokenPayment: {
             mint: paymentMint.publicKey,
@@ -1073,6 +1117,10 @@ test('it can sell an item with a marketplace fee using a payment mint', async (t
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Buyer can sell back to the seller
   await transactionBuilder()
@@ -1163,9 +1211,14 @@ test('it cannot sell an item with invalid oracle signer', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Try to sell with a different oracle signer
   const invalidOracleSigner = generateSigner(umi);
@@ -1231,9 +1284,14 @@ test('it cannot sell an item with insufficient buy back funds', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Try to sell with a price higher than available funds
   const promise = transactionBuilder()
@@ -1282,9 +1340,14 @@ test('it cannot sell to a gumball without buyback enabled', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const preBuyerTokenAccount = await umi.rpc.getBalance(
     buyerUmi.identity.publicKey
@@ -1366,16 +1429,17 @@ test('it cannot sell to a gumball with cutoff pct reached', async (t) => {
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
     .add(
-      draw(buyerUmi, {
-        gumballMachine,
-      })
-    )
-    .add(
-      draw(buyerUmi, {
+      drawMany(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        quantity: 2,
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const promise = transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -1453,9 +1517,14 @@ test('it cannot sell a tokens item with a different amount', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   const promise = transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -1532,10 +1601,15 @@ test('it can sell an nft item, re-add it, and sell it again', async (t) => {
     .add(
       draw(buyerUmi, {
         gumballMachine: machine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, machine),
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine: machine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Figure out which was drawn
   let gumballMachineAccount = await fetchGumballMachine(umi, machine);
@@ -1593,18 +1667,18 @@ test('it can sell an nft item, re-add it, and sell it again', async (t) => {
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
     .add(
-      draw(buyerUmi, {
-        gumballMachine: machine,
-        mintArgs: { solPayment: some(true) },
-      })
-    )
-    .add(
-      draw(buyerUmi, {
+      drawMany(buyerUmi, {
         gumballMachine: machine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, machine),
+        quantity: 2,
         mintArgs: { solPayment: some(true) },
       })
     )
     .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine: machine,
+    buyer: buyerUmi.identity.publicKey,
+  });
 
   // Buyer can sell back again to the seller
   await transactionBuilder()
@@ -1725,3 +1799,340 @@ test('it can sell an nft item, re-add it, and sell it again', async (t) => {
     )
     .sendAndConfirm(umi);
 });
+
+test('it can sell an item back into the gumball machine to be drawn again', async (t) => {
+  // Given a gumball machine that buys back prizes into the machine.
+  const umi = await createUmi();
+  const assets = await Promise.all([
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+  ]);
+  const oracleSigner = generateSigner(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: assets.map((asset) => ({
+      id: asset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {},
+    buyBackConfig: {
+      ...getDefaultBuyBackConfig(),
+      oracleSigner: oracleSigner.publicKey,
+      enabled: true,
+      toGumballMachine: true,
+    },
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      manageBuyBackFunds(umi, {
+        gumballMachine,
+        amount: LAMPORTS_PER_SOL,
+        isWithdraw: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And a buyer that drew one of the items.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const drawnItem = gumballMachineAccount.items.find(
+    (item) => item.buyer === buyerUmi.identity.publicKey
+  )!;
+
+  // When the buyer sells the item back into the gumball machine.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      sellItem(buyerUmi, {
+        gumballMachine,
+        index: drawnItem.index,
+        amount: 1,
+        buyPrice: LAMPORTS_PER_SOL / 2,
+        oracleSigner,
+        buyer: gumballMachine,
+        mint: drawnItem.mint,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the item can be drawn again.
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 0n,
+    buyBackFundsAvailable: BigInt(LAMPORTS_PER_SOL / 2),
+  });
+  t.like(gumballMachineAccount.items[drawnItem.index], {
+    isDrawn: false,
+    isClaimed: false,
+    buyer: undefined,
+  });
+
+  // And another buyer can draw every item.
+  const otherBuyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(otherBuyerUmi, { units: 800_000 }))
+    .add(
+      drawMany(otherBuyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(
+          otherBuyerUmi,
+          gumballMachine
+        ),
+        quantity: 2,
+      })
+    )
+    .sendAndConfirm(otherBuyerUmi);
+  await revealPendingDraw(otherBuyerUmi, { gumballMachine });
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.true(
+    gumballMachineAccount.items.every(
+      (item) => item.buyer === otherBuyerUmi.identity.publicKey
+    )
+  );
+});
+
+test('it reverses the price of an item sold back into the gumball machine', async (t) => {
+  // Given a gumball machine that sells items for 1 SOL and buys back prizes into the machine.
+  const umi = await createUmi();
+  const assets = await Promise.all([
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+  ]);
+  const oracleSigner = generateSigner(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: assets.map((asset) => ({
+      id: asset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {
+      solPayment: some({ lamports: sol(1) }),
+    },
+    buyBackConfig: {
+      ...getDefaultBuyBackConfig(),
+      oracleSigner: oracleSigner.publicKey,
+      enabled: true,
+      toGumballMachine: true,
+    },
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      manageBuyBackFunds(umi, {
+        gumballMachine,
+        amount: LAMPORTS_PER_SOL,
+        isWithdraw: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And a buyer that drew one of the items.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+        mintArgs: { solPayment: some(true) },
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const drawnItem = gumballMachineAccount.items.find(
+    (item) => item.buyer === buyerUmi.identity.publicKey
+  )!;
+
+  // And the price of the draw was stored on the item.
+  t.is(drawnItem.price, BigInt(LAMPORTS_PER_SOL));
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    totalRevenue: BigInt(LAMPORTS_PER_SOL),
+    totalRevenueDrawn: BigInt(LAMPORTS_PER_SOL),
+  });
+
+  // When the buyer sells the item back into the gumball machine.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      sellItem(buyerUmi, {
+        gumballMachine,
+        index: drawnItem.index,
+        amount: 1,
+        buyPrice: LAMPORTS_PER_SOL / 2,
+        oracleSigner,
+        buyer: gumballMachine,
+        mint: drawnItem.mint,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then exactly the price of the item was removed from the revenue.
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 0n,
+    totalRevenue: 0n,
+    totalRevenueDrawn: 0n,
+    buyBackFundsAvailable: BigInt(LAMPORTS_PER_SOL * 1.5),
+  });
+  t.like(gumballMachineAccount.items[drawnItem.index], {
+    isDrawn: false,
+    buyer: undefined,
+    price: 0n,
+  });
+});
+
+test('it cannot sell an item back into the gumball machine once the sale ended', async (t) => {
+  // Given a gumball machine that buys back prizes into the machine.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const oracleSigner = generateSigner(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+    guards: {},
+    buyBackConfig: {
+      ...getDefaultBuyBackConfig(),
+      oracleSigner: oracleSigner.publicKey,
+      enabled: true,
+      toGumballMachine: true,
+    },
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      manageBuyBackFunds(umi, {
+        gumballMachine,
+        amount: LAMPORTS_PER_SOL,
+        isWithdraw: false,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the last item was drawn, which ended the sale.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When the buyer tries to sell the item back into the gumball machine.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      sellItem(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        amount: 1,
+        buyPrice: LAMPORTS_PER_SOL / 2,
+        oracleSigner,
+        buyer: gumballMachine,
+        mint: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('it cannot sell an item back into the gumball machine to its authority', async (t) => {
+  // Given a gumball machine that buys back prizes into the machine.
+  const umi = await createUmi();
+  const assets = await Promise.all([
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+  ]);
+  const oracleSigner = generateSigner(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: assets.map((asset) => ({
+      id: asset.publicKey,
+      tokenStandard: TokenStandard.Core,
+    })),
+    startSale: true,
+    guards: {},
+    buyBackConfig: {
+      ...getDefaultBuyBackConfig(),
+      oracleSigner: oracleSigner.publicKey,
+      enabled: true,
+      toGumballMachine: true,
+    },
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      manageBuyBackFunds(umi, {
+        gumballMachine,
+        amount: LAMPORTS_PER_SOL,
+        isWithdraw: false,
+      })
+    )
+    .sendAndConfirm(umi);
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  const drawnItem = gumballMachineAccount.items.find(
+    (item) => item.buyer === buyerUmi.identity.publicKey
+  )!;
+
+  // When the buyer tries to sell the item to the authority instead.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      sellItem(buyerUmi, {
+        gumballMachine,
+        index: drawnItem.index,
+        amount: 1,
+        buyPrice: LAMPORTS_PER_SOL / 2,
+        oracleSigner,
+        buyer: umi.identity.publicKey,
+        mint: drawnItem.mint,
+        tokenStandard: TokenStandard.Core,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+});
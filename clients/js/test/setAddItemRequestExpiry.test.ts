@@ -0,0 +1,81 @@
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  fetchGumballMachine,
+  GumballMachine,
+  setAddItemRequestExpiry,
+} from '../src';
+import { create, createUmi } from './_setup';
+
+test('it can set the add item request expiry of a gumball machine', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // And add item requests never expire by default.
+  let gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.is(gumballMachineAccount.addItemRequestExpiry, 0n);
+
+  // When we set the add item request expiry to one hour.
+  await transactionBuilder()
+    .add(
+      setAddItemRequestExpiry(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        expiry: 3600,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine was updated.
+  gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    addItemRequestExpiry: 3600n,
+  });
+});
+
+test('it cannot set a negative add item request expiry', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When we try to set a negative add item request expiry.
+  const promise = transactionBuilder()
+    .add(
+      setAddItemRequestExpiry(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        expiry: -1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidAddItemRequestExpiry/ });
+});
+
+test('it cannot set the add item request expiry as a different authority', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When a different authority tries to set the add item request expiry.
+  const promise = transactionBuilder()
+    .add(
+      setAddItemRequestExpiry(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        authority: generateSigner(umi),
+        expiry: 3600,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, {
+    message: /A has one constraint was violated/,
+  });
+});
@@ -0,0 +1,180 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  claimCoreAsset,
+  draw,
+  endSale,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  GumballMachine,
+  setClaimDeadline,
+  settleCoreAssetSale,
+  startSale,
+  TokenStandard,
+  UnclaimedItemDestination,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+test('it can set the claim deadline of a gumball machine', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When we set a claim period of one day for the authority.
+  await transactionBuilder()
+    .add(
+      setClaimDeadline(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        claimPeriod: 86400,
+        destination: UnclaimedItemDestination.Authority,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine was updated.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    claimDeadlineConfig: {
+      claimPeriod: 86400n,
+      destination: UnclaimedItemDestination.Authority,
+    },
+  });
+});
+
+test('it cannot set a negative claim period', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When we try to set a negative claim period.
+  const promise = transactionBuilder()
+    .add(
+      setClaimDeadline(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        claimPeriod: -1,
+        destination: UnclaimedItemDestination.Seller,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidClaimDeadline/ });
+});
+
+test('it cannot set the claim deadline once the sale has started', async (t) => {
+  // Given a gumball machine with a started sale.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+  await startSale(umi, {
+    gumballMachine: gumballMachine.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When we try to set the claim deadline.
+  const promise = transactionBuilder()
+    .add(
+      setClaimDeadline(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        claimPeriod: 86400,
+        destination: UnclaimedItemDestination.Seller,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('it reverts unclaimed items to the seller after the claim deadline', async (t) => {
+  // Given a gumball machine with a claim period of one second.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      setClaimDeadline(umi, {
+        gumballMachine,
+        claimPeriod: 1,
+        destination: UnclaimedItemDestination.Seller,
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // And an item that was drawn but never claimed.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // And the claim deadline has passed.
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+
+  // When the buyer tries to claim the item.
+  const promise = transactionBuilder()
+    .add(
+      claimCoreAsset(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        asset: asset.publicKey,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ClaimDeadlinePassed/ });
+
+  // When we settle the sale.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      settleCoreAssetSale(umi, {
+        index: 0,
+        gumballMachine,
+        authority: umi.identity.publicKey,
+        seller: umi.identity.publicKey,
+        buyer: buyerUmi.identity.publicKey,
+        asset: asset.publicKey,
+        creators: [umi.identity.publicKey],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the asset went back to the seller.
+  const coreAsset = await fetchAssetV1(umi, asset.publicKey);
+  t.like(coreAsset, <AssetV1>{
+    freezeDelegate: undefined,
+    transferDelegate: undefined,
+    owner: umi.identity.publicKey,
+  });
+});
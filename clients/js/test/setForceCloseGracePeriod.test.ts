@@ -0,0 +1,89 @@
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  fetchGumballMachine,
+  GumballMachine,
+  setForceCloseGracePeriod,
+  startSale,
+} from '../src';
+import { create, createUmi } from './_setup';
+
+test('it can set the force close grace period of a gumball machine', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // And force closing is disabled by default.
+  let gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.is(gumballMachineAccount.forceCloseGracePeriod, 0n);
+
+  // When we set the grace period to one day.
+  await transactionBuilder()
+    .add(
+      setForceCloseGracePeriod(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        gracePeriod: 86400,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine was updated.
+  gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    forceCloseGracePeriod: 86400n,
+    saleEndedAt: 0n,
+  });
+});
+
+test('it cannot set a negative force close grace period', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When we try to set a negative grace period.
+  const promise = transactionBuilder()
+    .add(
+      setForceCloseGracePeriod(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        gracePeriod: -1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidForceCloseGracePeriod/ });
+});
+
+test('it cannot shorten the force close grace period once the sale has started', async (t) => {
+  // Given a gumball machine with a grace period of one day.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+  await transactionBuilder()
+    .add(
+      setForceCloseGracePeriod(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        gracePeriod: 86400,
+      })
+    )
+    .add(startSale(umi, { gumballMachine: gumballMachine.publicKey }))
+    .sendAndConfirm(umi);
+
+  // When we try to shorten the grace period.
+  const promise = transactionBuilder()
+    .add(
+      setForceCloseGracePeriod(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        gracePeriod: 3600,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidForceCloseGracePeriod/ });
+});
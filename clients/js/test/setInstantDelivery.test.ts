@@ -0,0 +1,387 @@
+import { findTreeConfigPda } from '@metaplex-foundation/mpl-bubblegum';
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  setComputeUnitLimit,
+  TokenState,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCompressedNft,
+  createReceiptCollection,
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  setInstantDelivery,
+  TokenStandard,
+} from '../src';
+import {
+  create,
+  createCompressedNft,
+  createCoreAsset,
+  createMintWithHolders,
+  createNft,
+  createUmi,
+  getCompressedNftArgs,
+  revealPendingDraw,
+} from './_setup';
+
+test('it can enable instant delivery on a gumball machine', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When we enable instant delivery.
+  await transactionBuilder()
+    .add(
+      setInstantDelivery(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        instantDelivery: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the gumball machine was updated.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachine.publicKey
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    instantDelivery: true,
+  });
+});
+
+test('it cannot enable instant delivery as another authority', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+
+  // When another authority tries to enable instant delivery.
+  const otherUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(
+      setInstantDelivery(otherUmi, {
+        gumballMachine: gumballMachine.publicKey,
+        instantDelivery: true,
+      })
+    )
+    .sendAndConfirm(otherUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, {
+    message: /A has one constraint was violated/,
+  });
+});
+
+test('it delivers a core asset when the draw is revealed', async (t) => {
+  // Given a gumball machine with instant delivery and a core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(setInstantDelivery(umi, { gumballMachine, instantDelivery: true }))
+    .sendAndConfirm(umi);
+
+  // When a buyer draws the asset and the draw is revealed with the
+  // delivery accounts.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+    deliveredItems: [
+      {
+        asset: asset.publicKey,
+        seller: umi.identity.publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+  });
+
+  // Then the item was claimed in the reveal transaction.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    itemsRedeemed: 1n,
+    items: [
+      {
+        index: 0,
+        isDrawn: true,
+        isClaimed: true,
+        buyer: buyerUmi.identity.publicKey,
+      },
+    ],
+  });
+
+  // And the buyer owns the asset.
+  const coreAsset = await fetchAssetV1(umi, asset.publicKey);
+  t.like(coreAsset, <AssetV1>{
+    freezeDelegate: undefined,
+    transferDelegate: undefined,
+    owner: buyerUmi.identity.publicKey,
+  });
+});
+
+test('it delivers tokens when the draw is revealed', async (t) => {
+  // Given a gumball machine with instant delivery and a tokens item.
+  const umi = await createUmi();
+  const gumballMachineSigner = generateSigner(umi);
+  const gumballMachine = gumballMachineSigner.publicKey;
+  const [tokenMint] = await createMintWithHolders(umi, {
+    holders: [
+      { owner: umi.identity, amount: 100 },
+      {
+        owner: findGumballMachineAuthorityPda(umi, { gumballMachine }),
+        amount: 0,
+      },
+    ],
+  });
+  await create(umi, {
+    gumballMachine: gumballMachineSigner,
+    items: [
+      {
+        id: tokenMint.publicKey,
+        tokenStandard: TokenStandard.Fungible,
+        amount: 100,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  await transactionBuilder()
+    .add(setInstantDelivery(umi, { gumballMachine, instantDelivery: true }))
+    .sendAndConfirm(umi);
+
+  // When a buyer draws the tokens and the draw is revealed with the
+  // delivery accounts.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+    deliveredItems: [
+      {
+        asset: tokenMint.publicKey,
+        seller: umi.identity.publicKey,
+        tokenStandard: TokenStandard.Fungible,
+      },
+    ],
+  });
+
+  // Then the item was claimed in the reveal transaction.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    items: [
+      {
+        index: 0,
+        isClaimed: true,
+        buyer: buyerUmi.identity.publicKey,
+      },
+    ],
+  });
+
+  // And the buyer owns the tokens.
+  const tokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi, {
+      mint: tokenMint.publicKey,
+      owner: buyerUmi.identity.publicKey,
+    })[0]
+  );
+  t.is(tokenAccount.amount, 100n);
+});
+
+test('it delivers an nft when the draw is revealed', async (t) => {
+  // Given a gumball machine with instant delivery and an nft.
+  const umi = await createUmi();
+  const nft = await createNft(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: nft.publicKey, tokenStandard: TokenStandard.NonFungible }],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(setInstantDelivery(umi, { gumballMachine, instantDelivery: true }))
+    .sendAndConfirm(umi);
+
+  // When a buyer draws the nft and the draw is revealed with the
+  // delivery accounts.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+    deliveredItems: [
+      {
+        asset: nft.publicKey,
+        seller: umi.identity.publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+
+  // Then the item was claimed in the reveal transaction.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    items: [
+      {
+        index: 0,
+        isClaimed: true,
+        buyer: buyerUmi.identity.publicKey,
+      },
+    ],
+  });
+
+  // And the buyer owns the unfrozen nft.
+  const tokenAccount = await fetchToken(
+    umi,
+    findAssociatedTokenPda(umi, {
+      mint: nft.publicKey,
+      owner: buyerUmi.identity.publicKey,
+    })[0]
+  );
+  t.like(tokenAccount, {
+    state: TokenState.Initialized,
+    owner: buyerUmi.identity.publicKey,
+    delegate: none(),
+    amount: 1n,
+  });
+});
+
+test('it cannot reveal a draw without the delivery accounts', async (t) => {
+  // Given a gumball machine with instant delivery and a core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [{ id: asset.publicKey, tokenStandard: TokenStandard.Core }],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(setInstantDelivery(umi, { gumballMachine, instantDelivery: true }))
+    .sendAndConfirm(umi);
+
+  // When the draw is revealed without the delivery accounts.
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  const promise = revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // Then we expect a program error, since the item would not be delivered.
+  await t.throwsAsync(promise, { message: /InvalidInstantDeliveryAccount/ });
+});
+
+test('it cannot enable instant delivery with compressed nfts', async (t) => {
+  // Given a gumball machine with a compressed nft.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi, { settings: { itemCapacity: 5 } });
+  const compressedNft = await createCompressedNft(umi);
+  const { merkleTree } = compressedNft;
+  const { compressedNft: args, proof } = getCompressedNftArgs(
+    umi,
+    compressedNft,
+    umi.identity.publicKey
+  );
+  await transactionBuilder()
+    .add(
+      addCompressedNft(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        merkleTree,
+        treeConfig: findTreeConfigPda(umi, { merkleTree }),
+        compressedNft: args,
+      }).addRemainingAccounts(proof)
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to enable instant delivery.
+  const promise = transactionBuilder()
+    .add(
+      setInstantDelivery(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        instantDelivery: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error, since compressed nfts cannot be delivered
+  // when the draw is revealed.
+  await t.throwsAsync(promise, { message: /InstantDeliveryNotSupported/ });
+});
+
+test('it cannot enable instant delivery with receipts', async (t) => {
+  // Given a gumball machine with a receipt collection.
+  const umi = await createUmi();
+  const gumballMachine = await create(umi);
+  await transactionBuilder()
+    .add(
+      createReceiptCollection(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        receiptCollection: generateSigner(umi),
+        name: 'Receipts',
+        uri: 'https://example.com/receipts.json',
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to enable instant delivery.
+  const promise = transactionBuilder()
+    .add(
+      setInstantDelivery(umi, {
+        gumballMachine: gumballMachine.publicKey,
+        instantDelivery: true,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error, since delivered items cannot be held by
+  // a receipt.
+  await t.throwsAsync(promise, { message: /ReceiptsWithInstantDelivery/ });
+});
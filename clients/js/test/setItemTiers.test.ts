@@ -0,0 +1,152 @@
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  fetchGumballMachine,
+  GumballMachine,
+  setItemTiers,
+  TokenStandard,
+} from '../src';
+import { create, createNft, createUmi } from './_setup';
+
+test('it can set the tier of a range of items', async (t) => {
+  // Given a gumball machine with three items.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we move the last two items to tier 2.
+  await transactionBuilder()
+    .add(
+      setItemTiers(umi, {
+        gumballMachine,
+        startIndex: 1,
+        endIndex: 2,
+        tier: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the items and the tier counts were updated.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.deepEqual(gumballMachineAccount.items.map((item) => item.tier), [0, 2, 2]);
+  t.deepEqual(
+    gumballMachineAccount.tierConfig.itemCounts,
+    [1, 0, 2, 0, 0, 0, 0, 0]
+  );
+  t.deepEqual(
+    gumballMachineAccount.tierConfig.itemsAvailable,
+    [1, 0, 2, 0, 0, 0, 0, 0]
+  );
+});
+
+test('it cannot set an item tier above the maximum tier', async (t) => {
+  // Given a gumball machine with one item.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to move the item to tier 8.
+  const promise = transactionBuilder()
+    .add(
+      setItemTiers(umi, {
+        gumballMachine,
+        startIndex: 0,
+        endIndex: 0,
+        tier: 8,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidTier/ });
+});
+
+test('it cannot set item tiers once the sale has started', async (t) => {
+  // Given a gumball machine that started its sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to change the tier of the item.
+  const promise = transactionBuilder()
+    .add(
+      setItemTiers(umi, {
+        gumballMachine,
+        startIndex: 0,
+        endIndex: 0,
+        tier: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+
+  // And the item kept its tier.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    items: [{ tier: 0 }],
+  });
+});
+
+test('it cannot set item tiers as another authority', async (t) => {
+  // Given a gumball machine with one item.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When another wallet tries to change the tier of the item.
+  const promise = transactionBuilder()
+    .add(
+      setItemTiers(umi, {
+        gumballMachine,
+        authority: generateSigner(umi),
+        startIndex: 0,
+        endIndex: 0,
+        tier: 1,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, {
+    message: /A has one constraint was violated/,
+  });
+});
@@ -0,0 +1,151 @@
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  fetchGumballMachine,
+  GumballMachine,
+  setItemWeights,
+  TokenStandard,
+} from '../src';
+import { create, createNft, createUmi } from './_setup';
+
+test('it can set the proceeds weight of a range of items', async (t) => {
+  // Given a gumball machine with three items.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // And the items were added with a weight of 1.
+  let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.deepEqual(
+    gumballMachineAccount.items.map((item) => item.weight),
+    [1, 1, 1]
+  );
+  t.is(gumballMachineAccount.totalWeight, 3n);
+
+  // When we set the weight of the first two items to 5.
+  await transactionBuilder()
+    .add(
+      setItemWeights(umi, {
+        gumballMachine,
+        startIndex: 0,
+        endIndex: 1,
+        weight: 5,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the items and the total weight were updated.
+  gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.deepEqual(
+    gumballMachineAccount.items.map((item) => item.weight),
+    [5, 5, 1]
+  );
+  t.like(gumballMachineAccount, <GumballMachine>{
+    totalWeight: 11n,
+    totalWeightSettled: 0n,
+  });
+});
+
+test('it cannot set a proceeds weight of zero', async (t) => {
+  // Given a gumball machine with one item.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to set its weight to 0.
+  const promise = transactionBuilder()
+    .add(
+      setItemWeights(umi, {
+        gumballMachine,
+        startIndex: 0,
+        endIndex: 0,
+        weight: 0,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidProceedsWeight/ });
+});
+
+test('it cannot set the proceeds weight of items that are not loaded', async (t) => {
+  // Given a gumball machine with one item.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    settings: { itemCapacity: 5 },
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to set the weight of the first two items.
+  const promise = transactionBuilder()
+    .add(
+      setItemWeights(umi, {
+        gumballMachine,
+        startIndex: 0,
+        endIndex: 1,
+        weight: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /IndexGreaterThanLength/ });
+});
+
+test('it cannot set the proceeds weight once the sale has started', async (t) => {
+  // Given a gumball machine that started its sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to set the weight of its item.
+  const promise = transactionBuilder()
+    .add(
+      setItemWeights(umi, {
+        gumballMachine,
+        startIndex: 0,
+        endIndex: 0,
+        weight: 2,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
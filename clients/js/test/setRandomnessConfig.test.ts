@@ -0,0 +1,494 @@
+import {
+  approveTokenDelegate,
+  mintTokensTo,
+  setComputeUnitLimit,
+  SPL_TOKEN_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  publicKeyBytes,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  cancelPendingDraw,
+  draw,
+  endSale,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  fetchPendingDrawFromSeeds,
+  GumballMachine,
+  PendingDraw,
+  RandomnessConfig,
+  RandomnessRequestConfig,
+  revealDraw,
+  setForceCloseGracePeriod,
+  setRandomnessConfig,
+  startSale,
+  TokenStandard,
+} from '../src';
+import {
+  assertItemBought,
+  create,
+  createMintWithHolders,
+  createNft,
+  createUmi,
+} from './_setup';
+
+// A token account is used as randomness account in tests: its first 8 bytes
+// (the start of the mint address) act as discriminator, the delegated amount
+// as the slot of the request and the token amount as the slot of the value.
+const TOKEN_OWNER_OFFSET = 32;
+const TOKEN_AMOUNT_OFFSET = 64;
+const TOKEN_DELEGATED_AMOUNT_OFFSET = 121;
+
+const createRandomnessAccount = async (umi: Umi) => {
+  const [mint, token] = await createMintWithHolders(umi, {
+    holders: [{ owner: umi.identity.publicKey, amount: 0 }],
+  });
+
+  return {
+    token,
+    config: {
+      provider: SPL_TOKEN_PROGRAM_ID,
+      valueOffset: TOKEN_OWNER_OFFSET,
+      slotOffset: TOKEN_AMOUNT_OFFSET,
+      account: token,
+      discriminator: publicKeyBytes(mint.publicKey).slice(0, 8),
+      requestSlotOffset: TOKEN_DELEGATED_AMOUNT_OFFSET,
+    },
+    // Requests a new value at the current slot.
+    request: async () => {
+      const slot = BigInt(await umi.rpc.getSlot());
+      await transactionBuilder()
+        .add(
+          approveTokenDelegate(umi, {
+            source: token,
+            delegate: generateSigner(umi).publicKey,
+            amount: slot,
+          })
+        )
+        .sendAndConfirm(umi);
+      return slot;
+    },
+    // Produces the value of the request made at the given slot.
+    fulfill: async (requestSlot: bigint) => {
+      await transactionBuilder()
+        .add(
+          mintTokensTo(umi, {
+            mint: mint.publicKey,
+            token,
+            amount: requestSlot,
+          })
+        )
+        .sendAndConfirm(umi);
+    },
+  };
+};
+
+const createWithRandomnessProvider = async (umi: Umi) => {
+  const randomness = await createRandomnessAccount(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      setRandomnessConfig(umi, {
+        gumballMachine,
+        ...randomness.config,
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  return { gumballMachine, randomness };
+};
+
+const drawWithRandomness = async (
+  umi: Umi,
+  gumballMachine: PublicKey,
+  randomness: PublicKey
+) =>
+  transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        randomness,
+      })
+    )
+    .sendAndConfirm(umi);
+
+test('it can set the randomness provider of a gumball machine', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we set a randomness provider.
+  const randomness = await createRandomnessAccount(umi);
+  await transactionBuilder()
+    .add(
+      setRandomnessConfig(umi, {
+        gumballMachine,
+        ...randomness.config,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the randomness config was updated.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    randomnessConfig: <RandomnessConfig>{
+      provider: SPL_TOKEN_PROGRAM_ID,
+      valueOffset: TOKEN_OWNER_OFFSET,
+      slotOffset: TOKEN_AMOUNT_OFFSET,
+    },
+    randomnessRequestConfig: <RandomnessRequestConfig>{
+      account: randomness.token,
+      requestSlotOffset: TOKEN_DELEGATED_AMOUNT_OFFSET,
+    },
+  });
+  t.deepEqual(
+    gumballMachineAccount.randomnessRequestConfig.discriminator,
+    randomness.config.discriminator
+  );
+});
+
+test('it cannot set the randomness provider once the sale has started', async (t) => {
+  // Given a gumball machine that started its sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to set a randomness provider.
+  const randomness = await createRandomnessAccount(umi);
+  const promise = transactionBuilder()
+    .add(
+      setRandomnessConfig(umi, {
+        gumballMachine,
+        ...randomness.config,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('it cannot draw without the randomness account of the provider', async (t) => {
+  // Given a gumball machine that uses a randomness provider.
+  const umi = await createUmi();
+  const { gumballMachine } = await createWithRandomnessProvider(umi);
+
+  // When we draw without a randomness account.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingRandomnessAccount/ });
+});
+
+test('it cannot draw with another randomness account', async (t) => {
+  // Given a gumball machine that uses a randomness provider.
+  const umi = await createUmi();
+  const { gumballMachine } = await createWithRandomnessProvider(umi);
+
+  // And another account of the provider with an open request.
+  const otherRandomness = await createRandomnessAccount(umi);
+  await otherRandomness.request();
+
+  // When we draw with that account.
+  const promise = drawWithRandomness(
+    umi,
+    gumballMachine,
+    otherRandomness.token
+  );
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidRandomnessAccount/ });
+});
+
+test('it cannot draw with a randomness account of another layout', async (t) => {
+  // Given a gumball machine expecting another discriminator.
+  const umi = await createUmi();
+  const randomness = await createRandomnessAccount(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+  await transactionBuilder()
+    .add(
+      setRandomnessConfig(umi, {
+        gumballMachine,
+        ...randomness.config,
+        discriminator: new Uint8Array(8),
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+  await randomness.request();
+
+  // When we draw with the randomness account.
+  const promise = drawWithRandomness(umi, gumballMachine, randomness.token);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidRandomnessAccount/ });
+});
+
+test('it cannot draw once the randomness request was fulfilled', async (t) => {
+  // Given a gumball machine that uses a randomness provider.
+  const umi = await createUmi();
+  const { gumballMachine, randomness } = await createWithRandomnessProvider(
+    umi
+  );
+
+  // And a request whose value is already known.
+  await randomness.fulfill(await randomness.request());
+
+  // When we draw with the randomness account.
+  const promise = drawWithRandomness(umi, gumballMachine, randomness.token);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RandomnessRequestFulfilled/ });
+});
+
+test('it reveals a draw with the value of the randomness provider', async (t) => {
+  // Given a gumball machine that uses a randomness provider.
+  const umi = await createUmi();
+  const { gumballMachine, randomness } = await createWithRandomnessProvider(
+    umi
+  );
+
+  // And an open request on the randomness account.
+  const requestSlot = await randomness.request();
+
+  // When we draw with the randomness account.
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+        randomness: randomness.token,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the pending draw is bound to the request.
+  const pendingDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 0,
+  });
+  t.like(pendingDraw, <PendingDraw>{
+    randomness: randomness.token,
+    randomnessRequestSlot: requestSlot,
+  });
+
+  // And once the provider produced the value of the request.
+  await randomness.fulfill(requestSlot);
+
+  // The draw can be revealed.
+  await assertItemBought(t, umi, { gumballMachine, buyer: buyer.publicKey });
+});
+
+test('it cannot reveal a draw with the value of a later request', async (t) => {
+  // Given a gumball machine that uses a randomness provider.
+  const umi = await createUmi();
+  const { gumballMachine, randomness } = await createWithRandomnessProvider(
+    umi
+  );
+
+  // And a draw bound to an open request.
+  await randomness.request();
+  await drawWithRandomness(umi, gumballMachine, randomness.token);
+  const pendingDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 0,
+  });
+  while ((await umi.rpc.getSlot()) <= pendingDraw.targetSlot) {
+    await new Promise((resolve) => {
+      setTimeout(resolve, 400);
+    });
+  }
+
+  // And a new request that was fulfilled.
+  await randomness.fulfill(await randomness.request());
+
+  // When we try to reveal the draw.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      revealDraw(umi, {
+        gumballMachine,
+        pendingDraw: pendingDraw.publicKey,
+        payer: pendingDraw.payer,
+        randomness: randomness.token,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RandomnessRequestReplaced/ });
+});
+
+test('anyone can cancel a draw that can no longer be revealed after the grace period', async (t) => {
+  // Given a gumball machine that uses a randomness provider and can be force closed
+  // 1 second after the sale ends.
+  const umi = await createUmi();
+  const { gumballMachine, randomness } = await createWithRandomnessProvider(
+    umi
+  );
+  await setForceCloseGracePeriod(umi, {
+    gumballMachine,
+    gracePeriod: 1,
+  }).sendAndConfirm(umi);
+
+  // And a draw bound to a request that was replaced before it was fulfilled.
+  await randomness.request();
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+        randomness: randomness.token,
+      })
+    )
+    .sendAndConfirm(umi);
+  const pendingDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 0,
+  });
+  await randomness.fulfill(await randomness.request());
+
+  // And the sale ended more than the grace period ago.
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+
+  // When someone else cancels the draw.
+  const cranker = await generateSignerWithSol(umi);
+  await transactionBuilder()
+    .add(
+      cancelPendingDraw(umi, {
+        gumballMachine,
+        pendingDraw: pendingDraw.publicKey,
+        payer: pendingDraw.payer,
+        buyer: buyer.publicKey,
+        feePayer: cranker,
+        randomness: randomness.token,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the pending draw account is closed.
+  t.false(await umi.rpc.accountExists(pendingDraw.publicKey));
+
+  // And the reserved item is back in the pool, so it can be settled to its seller.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <GumballMachine>{
+    itemsRedeemed: 0n,
+    itemsPending: 0,
+    drawsRevealed: 1n,
+  });
+});
+
+test('it cannot cancel a draw that can still be revealed', async (t) => {
+  // Given a gumball machine that uses a randomness provider and can be force closed
+  // 1 second after the sale ends.
+  const umi = await createUmi();
+  const { gumballMachine, randomness } = await createWithRandomnessProvider(
+    umi
+  );
+  await setForceCloseGracePeriod(umi, {
+    gumballMachine,
+    gracePeriod: 1,
+  }).sendAndConfirm(umi);
+
+  // And a draw whose request was fulfilled.
+  const requestSlot = await randomness.request();
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+        randomness: randomness.token,
+      })
+    )
+    .sendAndConfirm(umi);
+  const pendingDraw = await fetchPendingDrawFromSeeds(umi, {
+    gumballMachine,
+    drawNumber: 0,
+  });
+  await randomness.fulfill(requestSlot);
+
+  // And the sale ended more than the grace period ago.
+  await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
+  await new Promise((resolve) => setTimeout(resolve, 2000));
+
+  // When we try to cancel the draw.
+  const promise = transactionBuilder()
+    .add(
+      cancelPendingDraw(umi, {
+        gumballMachine,
+        pendingDraw: pendingDraw.publicKey,
+        payer: pendingDraw.payer,
+        buyer: buyer.publicKey,
+        randomness: randomness.token,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /DrawCanBeRevealed/ });
+});
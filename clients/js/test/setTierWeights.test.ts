@@ -0,0 +1,183 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  setItemTiers,
+  setTierWeights,
+  startSale,
+  TokenStandard,
+} from '../src';
+import { create, createNft, createUmi, revealPendingDraw } from './_setup';
+
+test('every tier has the same weight by default', async (t) => {
+  // Given a new gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+
+  // Then every tier has a weight of one.
+  const gumballMachineAccount = await fetchGumballMachine(
+    umi,
+    gumballMachineSigner.publicKey
+  );
+  t.deepEqual(
+    gumballMachineAccount.tierConfig.weights,
+    [1, 1, 1, 1, 1, 1, 1, 1]
+  );
+});
+
+test('it can set the weight of each tier', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we update the tier weights.
+  await transactionBuilder()
+    .add(
+      setTierWeights(umi, {
+        gumballMachine,
+        weights: [100, 50, 25, 10, 5, 2, 1, 1],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the weights were updated.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.deepEqual(
+    gumballMachineAccount.tierConfig.weights,
+    [100, 50, 25, 10, 5, 2, 1, 1]
+  );
+});
+
+test('it cannot set a tier weight to zero', async (t) => {
+  // Given a gumball machine.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to give a tier no weight.
+  const promise = transactionBuilder()
+    .add(
+      setTierWeights(umi, {
+        gumballMachine,
+        weights: [1, 1, 1, 1, 1, 1, 1, 0],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidTier/ });
+});
+
+test('it cannot set tier weights once the sale has started', async (t) => {
+  // Given a gumball machine that started its sale.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    startSale: true,
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // When we try to update the tier weights.
+  const promise = transactionBuilder()
+    .add(
+      setTierWeights(umi, {
+        gumballMachine,
+        weights: [2, 2, 2, 2, 2, 2, 2, 2],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
+
+test('it draws items from heavier tiers first', async (t) => {
+  // Given a gumball machine with a common and a rare item.
+  const umi = await createUmi();
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+      {
+        id: (await createNft(umi)).publicKey,
+        tokenStandard: TokenStandard.NonFungible,
+      },
+    ],
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  // And the second item is in a tier that is far more likely to be drawn.
+  await transactionBuilder()
+    .add(
+      setItemTiers(umi, {
+        gumballMachine,
+        startIndex: 1,
+        endIndex: 1,
+        tier: 1,
+      })
+    )
+    .add(
+      setTierWeights(umi, {
+        gumballMachine,
+        weights: [1, 65535, 1, 1, 1, 1, 1, 1],
+      })
+    )
+    .add(startSale(umi, { gumballMachine }))
+    .sendAndConfirm(umi);
+
+  // When we draw a single item.
+  const buyer = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      draw(umi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
+        buyer,
+      })
+    )
+    .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
+
+  // Then the item of the heavier tier was drawn.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.is(gumballMachineAccount.items[1].buyer, buyer.publicKey);
+  t.is(gumballMachineAccount.items[0].buyer, undefined);
+  t.deepEqual(
+    gumballMachineAccount.tierConfig.itemsAvailable,
+    [1, 0, 0, 0, 0, 0, 0, 0]
+  );
+});
@@ -22,8 +22,10 @@ import test from 'ava';
 import {
   claimCoreAsset,
   draw,
+  drawMany,
   endSale,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
   GumballMachine,
@@ -31,7 +33,12 @@ import {
   settleCoreAssetSale,
   TokenStandard,
 } from '../src';
-import { create, createCoreAsset, createUmi } from './_setup';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can settle a core asset sale', async (t) => {
   // Given a gumball machine with some guards.
@@ -65,6 +72,7 @@ test('it can settle a core asset sale', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -73,6 +81,7 @@ test('it can settle a core asset sale', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -212,6 +221,7 @@ test('it splits proceeds for a primary asset sale with multiple creators after c
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -220,6 +230,7 @@ test('it splits proceeds for a primary asset sale with multiple creators after c
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -345,6 +356,7 @@ test('it splits proceeds for a primary asset sale with multiple creators before
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -353,6 +365,7 @@ test('it splits proceeds for a primary asset sale with multiple creators before
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const secondCreatorPreBalance = await umi.rpc.getBalance(secondCreator);
@@ -465,6 +478,7 @@ test('it splits proceeds for a secondary asset sale with multiple creators after
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -473,6 +487,7 @@ test('it splits proceeds for a secondary asset sale with multiple creators after
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -621,6 +636,7 @@ test('it splits proceeds for a secondary asset sale with multiple creators befor
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -629,6 +645,7 @@ test('it splits proceeds for a secondary asset sale with multiple creators befor
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const firstCreatorPreBalance = await umi.rpc.getBalance(
@@ -827,6 +844,7 @@ test('it can settle a core asset that was not sold with proceeds from another sa
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -835,6 +853,7 @@ test('it can settle a core asset that was not sold with proceeds from another sa
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
 
@@ -946,6 +965,7 @@ test('it cannot settle a core asset to the wrong buyer', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -954,6 +974,7 @@ test('it cannot settle a core asset to the wrong buyer', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale for the unbought nft
   const promise = transactionBuilder()
@@ -1010,26 +1031,19 @@ test('it cannot settle a core asset sale twice', async (t) => {
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
     .add(
-      draw(umi, {
-        gumballMachine,
-        payer,
-        buyer,
-        mintArgs: {
-          solPayment: some(true),
-        },
-      })
-    )
-    .add(
-      draw(umi, {
+      drawMany(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
+        quantity: 2,
         mintArgs: {
           solPayment: some(true),
         },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale
   await transactionBuilder()
@@ -1094,12 +1108,14 @@ test('it can settle a core asset sale where buyer is the seller', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           solPayment: some(true),
         },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -1201,6 +1217,7 @@ test('it can settle a core asset sale for claimed core asset', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1209,6 +1226,7 @@ test('it can settle a core asset sale for claimed core asset', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -1333,6 +1351,7 @@ test('it can settle a core asset sale after asset has been claimed and burnt', a
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1341,6 +1360,7 @@ test('it can settle a core asset sale after asset has been claimed and burnt', a
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
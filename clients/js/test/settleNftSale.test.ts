@@ -32,6 +32,7 @@ import {
   draw,
   endSale,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
   GumballMachine,
@@ -40,7 +41,13 @@ import {
   settleNftSale,
   TokenStandard,
 } from '../src';
-import { create, createNft, createProgrammableNft, createUmi } from './_setup';
+import {
+  create,
+  createNft,
+  createProgrammableNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can settle an nft sale', async (t) => {
   // Given a gumball machine with some guards.
@@ -75,6 +82,7 @@ test('it can settle an nft sale', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -83,6 +91,7 @@ test('it can settle an nft sale', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -218,6 +227,7 @@ test('it can settle an pnft sale', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -226,6 +236,7 @@ test('it can settle an pnft sale', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -364,6 +375,7 @@ test('it splits proceeds for a primary nft sale with multiple creators after cla
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -372,6 +384,7 @@ test('it splits proceeds for a primary nft sale with multiple creators after cla
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -486,6 +499,7 @@ test('it only splits royalty portion of proceeds for a primary nft sale with dis
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -494,6 +508,7 @@ test('it only splits royalty portion of proceeds for a primary nft sale with dis
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -606,6 +621,7 @@ test('it splits proceeds for a primary nft sale with multiple creators before cl
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -614,6 +630,7 @@ test('it splits proceeds for a primary nft sale with multiple creators before cl
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const secondCreatorPreBalance = await umi.rpc.getBalance(secondCreator);
@@ -714,6 +731,7 @@ test('it splits proceeds for a secondary nft sale with multiple creators after c
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -722,6 +740,7 @@ test('it splits proceeds for a secondary nft sale with multiple creators after c
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -858,6 +877,7 @@ test('it splits proceeds for a secondary nft sale with multiple creators before
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -866,6 +886,7 @@ test('it splits proceeds for a secondary nft sale with multiple creators before
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const firstCreatorPreBalance = await umi.rpc.getBalance(
@@ -976,6 +997,7 @@ test('it can settle an nft sale as a third party', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -984,6 +1006,7 @@ test('it can settle an nft sale as a third party', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -1111,6 +1134,7 @@ test('it can settle a pnft sale as a third party', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1119,6 +1143,7 @@ test('it can settle a pnft sale as a third party', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -1430,6 +1455,7 @@ test('it can settle an nft that was not sold with proceeds from another sale', a
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1438,6 +1464,7 @@ test('it can settle an nft that was not sold with proceeds from another sale', a
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
 
@@ -1566,6 +1593,7 @@ test('it can settle an nft that was not sold with proceeds from another sale wit
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1574,6 +1602,7 @@ test('it can settle an nft that was not sold with proceeds from another sale wit
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
 
@@ -1696,6 +1725,7 @@ test('it cannot settle an nft to the wrong buyer', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1704,6 +1734,7 @@ test('it cannot settle an nft to the wrong buyer', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale for the unsold nft
   const promise = transactionBuilder()
@@ -1754,12 +1785,14 @@ test('it can settle an nft sale where buyer is the seller', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         mintArgs: {
           solPayment: some(true),
         },
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -1869,6 +1902,7 @@ test('it can settle an nft sale for claimed nft', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1877,6 +1911,7 @@ test('it can settle an nft sale for claimed nft', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -2015,6 +2050,7 @@ test('it can settle an nft sale with a marketplace config', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -2023,6 +2059,7 @@ test('it can settle an nft sale with a marketplace config', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -2109,6 +2146,7 @@ test('it omits sending proceeds for a creator if the amount is too small to keep
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -2117,6 +2155,7 @@ test('it omits sending proceeds for a creator if the amount is too small to keep
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale
   await transactionBuilder()
@@ -2183,6 +2222,7 @@ test('it can settle an nft sale with disableRoyalties', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -2191,6 +2231,7 @@ test('it can settle an nft sale with disableRoyalties', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const creatorPreBalance = await umi.rpc.getBalance(
     creatorUmi.identity.publicKey
@@ -2279,6 +2320,7 @@ test('it can settle an nft sale after nft has been claimed and burnt', async (t)
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -2287,6 +2329,7 @@ test('it can settle an nft sale after nft has been claimed and burnt', async (t)
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then claim and burn the nft
   await transactionBuilder()
@@ -2422,6 +2465,7 @@ test('it cannot settle an nft with the wrong metadata account', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -2430,6 +2474,7 @@ test('it cannot settle an nft with the wrong metadata account', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale for the unsold nft
   const promise = transactionBuilder()
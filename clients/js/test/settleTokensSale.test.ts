@@ -24,6 +24,7 @@ import {
   draw,
   endSale,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
   GumballMachine,
@@ -31,7 +32,12 @@ import {
   settleTokensSale,
   TokenStandard,
 } from '../src';
-import { create, createMintWithHolders, createUmi } from './_setup';
+import {
+  create,
+  createMintWithHolders,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it can settle a token sale', async (t) => {
   // Given a gumball machine with some guards.
@@ -68,6 +74,7 @@ test('it can settle a token sale', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -76,6 +83,7 @@ test('it can settle a token sale', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -217,6 +225,7 @@ test('it can settle a token sale after claim', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -225,6 +234,7 @@ test('it can settle a token sale after claim', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await claimTokens(buyerUmi, {
     gumballMachine,
@@ -348,6 +358,7 @@ test('it can settle a tokens sale as a third party', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -356,6 +367,7 @@ test('it can settle a tokens sale as a third party', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
@@ -597,6 +609,7 @@ test('it can settle a tokens sale that was not sold with proceeds from another s
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -605,6 +618,7 @@ test('it can settle a tokens sale that was not sold with proceeds from another s
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
 
@@ -733,6 +747,7 @@ test('it cannot settle a tokens sale to the wrong buyer', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -741,6 +756,7 @@ test('it cannot settle a tokens sale to the wrong buyer', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale for the unsold tokens
   const promise = transactionBuilder()
@@ -804,6 +820,7 @@ test('it can settle a tokens sale with a marketplace config', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -812,6 +829,7 @@ test('it can settle a tokens sale with a marketplace config', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   const sellerPreBalance = await umi.rpc.getBalance(umi.identity.publicKey);
   const authorityPdaPreBalance = await umi.rpc.getBalance(
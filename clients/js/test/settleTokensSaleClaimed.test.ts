@@ -25,6 +25,7 @@ import {
   draw,
   endSale,
   fetchGumballMachine,
+  fetchNextPendingDrawPda,
   findGumballGuardPda,
   findGumballMachineAuthorityPda,
   findSellerHistoryPda,
@@ -36,7 +37,13 @@ import {
   startSale,
   TokenStandard,
 } from '../src';
-import { create, createMintWithHolders, createNft, createUmi } from './_setup';
+import {
+  create,
+  createMintWithHolders,
+  createNft,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
 
 test('it cannot settle an unclaimed token sale', async (t) => {
   // Given a gumball machine with some guards.
@@ -73,6 +80,7 @@ test('it cannot settle an unclaimed token sale', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -81,6 +89,7 @@ test('it cannot settle an unclaimed token sale', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Then settle the sale
   const promise = transactionBuilder()
@@ -135,6 +144,7 @@ test('it can settle a token sale after claim', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -143,6 +153,7 @@ test('it can settle a token sale after claim', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await claimTokens(buyerUmi, {
     gumballMachine,
@@ -262,6 +273,7 @@ test('it can settle a claimed tokens sale as a third party', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -270,6 +282,7 @@ test('it can settle a claimed tokens sale as a third party', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await claimTokens(buyerUmi, {
     gumballMachine,
@@ -517,6 +530,7 @@ test('it can settle a tokens item that was not sold with proceeds from another s
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -525,6 +539,7 @@ test('it can settle a tokens item that was not sold with proceeds from another s
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   let gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
 
@@ -663,6 +678,7 @@ test('it can settle a claimed tokens item with a marketplace config', async (t)
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -671,6 +687,7 @@ test('it can settle a claimed tokens item with a marketplace config', async (t)
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await claimTokens(buyerUmi, {
     gumballMachine,
@@ -797,6 +814,7 @@ test('it cannot settle an nft sale', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -805,6 +823,7 @@ test('it cannot settle an nft sale', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await transactionBuilder()
     .add(setComputeUnitLimit(umi, { units: 600_000 }))
@@ -874,6 +893,7 @@ test('it cannot settle an already settled tokens item', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -882,6 +902,7 @@ test('it cannot settle an already settled tokens item', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await endSale(umi, { gumballMachine }).sendAndConfirm(umi);
 
@@ -1103,6 +1124,7 @@ test('it can settle with curator fees', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1111,6 +1133,7 @@ test('it can settle with curator fees', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   await claimTokens(buyerUmi, {
     gumballMachine,
@@ -1253,6 +1276,7 @@ test('it can settle multiple items drawn and claimed', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1261,6 +1285,7 @@ test('it can settle multiple items drawn and claimed', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Draw second item
   await transactionBuilder()
@@ -1268,6 +1293,7 @@ test('it can settle multiple items drawn and claimed', async (t) => {
     .add(
       draw(umi, {
         gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(umi, gumballMachine),
         payer,
         buyer,
         mintArgs: {
@@ -1276,6 +1302,7 @@ test('it can settle multiple items drawn and claimed', async (t) => {
       })
     )
     .sendAndConfirm(umi);
+  await revealPendingDraw(umi, { gumballMachine, buyer: buyer.publicKey });
 
   // Claim both items
   await claimTokens(buyerUmi, {
@@ -0,0 +1,245 @@
+import { AssetV1, fetchAssetV1 } from '@metaplex-foundation/mpl-core';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import { transactionBuilder } from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  claimCoreAsset,
+  draw,
+  fetchGumballMachine,
+  fetchNextPendingDrawPda,
+  findGumballMachineAuthorityPda,
+  GumballMachine,
+  TokenStandard,
+  transferPrize,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  revealPendingDraw,
+} from './_setup';
+
+test('it can transfer a drawn item to a new buyer', async (t) => {
+  // Given a gumball machine with a drawn core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When the buyer transfers the item to a new buyer.
+  const newBuyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(
+      transferPrize(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        newBuyer: newBuyerUmi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then the item belongs to the new buyer.
+  const gumballMachineAccount = await fetchGumballMachine(umi, gumballMachine);
+  t.like(gumballMachineAccount, <Partial<GumballMachine>>{
+    items: [
+      {
+        index: 0,
+        isDrawn: true,
+        isClaimed: false,
+        buyer: newBuyerUmi.identity.publicKey,
+      },
+    ],
+  });
+
+  // And the new buyer can claim it.
+  await transactionBuilder()
+    .add(
+      claimCoreAsset(newBuyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        asset: asset.publicKey,
+      })
+    )
+    .sendAndConfirm(newBuyerUmi);
+
+  const coreAsset = await fetchAssetV1(umi, asset.publicKey);
+  t.like(coreAsset, <AssetV1>{
+    owner: newBuyerUmi.identity.publicKey,
+  });
+});
+
+test('it cannot transfer an item drawn by another buyer', async (t) => {
+  // Given a gumball machine with a drawn core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When another wallet tries to transfer the item to itself.
+  const otherUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(
+      transferPrize(otherUmi, {
+        gumballMachine,
+        index: 0,
+        newBuyer: otherUmi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(otherUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+});
+
+test('it cannot transfer an item to the authority PDA', async (t) => {
+  // Given a gumball machine with a drawn core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+
+  // When the buyer tries to transfer the item to the authority PDA, which
+  // holds the items represented by a receipt.
+  const promise = transactionBuilder()
+    .add(
+      transferPrize(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        newBuyer: findGumballMachineAuthorityPda(umi, { gumballMachine })[0],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidBuyer/ });
+});
+
+test('it cannot transfer a claimed item', async (t) => {
+  // Given a gumball machine with a claimed core asset.
+  const umi = await createUmi();
+  const asset = await createCoreAsset(umi);
+  const gumballMachineSigner = await create(umi, {
+    items: [
+      {
+        id: asset.publicKey,
+        tokenStandard: TokenStandard.Core,
+      },
+    ],
+    startSale: true,
+    guards: {},
+  });
+  const gumballMachine = gumballMachineSigner.publicKey;
+
+  const buyerUmi = await createUmi();
+  await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 600_000 }))
+    .add(
+      draw(buyerUmi, {
+        gumballMachine,
+        pendingDraw: await fetchNextPendingDrawPda(buyerUmi, gumballMachine),
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+  await revealPendingDraw(umi, {
+    gumballMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  await transactionBuilder()
+    .add(
+      claimCoreAsset(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        seller: umi.identity.publicKey,
+        asset: asset.publicKey,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // When the buyer tries to transfer the item.
+  const newBuyerUmi = await createUmi();
+  const promise = transactionBuilder()
+    .add(
+      transferPrize(buyerUmi, {
+        gumballMachine,
+        index: 0,
+        newBuyer: newBuyerUmi.identity.publicKey,
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /ItemAlreadyClaimed/ });
+});
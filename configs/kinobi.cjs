@@ -75,6 +75,17 @@ kinobi.update(
         ),
       ],
     },
+    pendingDraw: {
+      seeds: [
+        k.stringConstantSeed("pending_draw"),
+        gumballMachineSeed,
+        k.variableSeed(
+          "drawNumber",
+          k.numberTypeNode("u64"),
+          "The number of the draw on the Gumball Machine"
+        ),
+      ],
+    },
     mintCounter: {
       size: 2,
       discriminator: k.sizeAccountDiscriminator(),
@@ -251,6 +262,18 @@ const defaultsToJellybeanProgram = () =>
     "mallowJellybean",
     "J3LLYcm8V5hJRzCKENRPW3yGdQ6xU8Nie8jr3mU88eqq"
   );
+const defaultsToMplBubblegumProgram = () =>
+  k.programDefault(
+    "mplBubblegum",
+    "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+  );
+const defaultsToSplAccountCompressionProgram = () =>
+  k.programDefault(
+    "splAccountCompression",
+    "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+  );
+const defaultsToSplNoopProgram = () =>
+  k.programDefault("splNoop", "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
 const defaultsToSysvarInstructions = () =>
   k.publicKeyDefault("Sysvar1nstructions1111111111111111111111111");
 
@@ -382,6 +405,21 @@ kinobi.update(
       account: "associatedTokenProgram",
       ignoreIfOptional: true,
     },
+    {
+      ...defaultsToMplBubblegumProgram(),
+      account: "bubblegumProgram",
+      ignoreIfOptional: true,
+    },
+    {
+      ...defaultsToSplAccountCompressionProgram(),
+      account: "compressionProgram",
+      ignoreIfOptional: true,
+    },
+    {
+      ...defaultsToSplNoopProgram(),
+      account: "logWrapper",
+      ignoreIfOptional: true,
+    },
   ])
 );
 
@@ -411,6 +449,41 @@ const sellerWithMetadataPnftDefault = () => {
   };
 };
 
+const addItemRequestNftDefault = () => {
+  return {
+    tokenAccount: {
+      defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+        ifTrue: defaultsToAssociatedTokenPda("asset", "seller"),
+      }),
+    },
+    authorityPdaTokenAccount: {
+      defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+        ifTrue: defaultsToAssociatedTokenPda("asset", "authorityPda"),
+      }),
+    },
+    edition: {
+      defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+        ifTrue: defaultsToMasterEditionPda("asset"),
+      }),
+    },
+    metadata: {
+      defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+        ifTrue: defaultsToMetadataPda("asset"),
+      }),
+    },
+    sellerTokenRecord: {
+      defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+        ifTrue: defaultsToTokenRecordPda("asset", "tokenAccount"),
+      }),
+    },
+    instructions: {
+      defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+        ifTrue: defaultsToSysvarInstructions(),
+      }),
+    },
+  };
+};
+
 const claimPnftDefault = () => {
   return {
     ...sellerPnftDefault(),
@@ -483,6 +556,12 @@ kinobi.update(
         seller: { defaultsTo: k.identityDefault() },
       },
     },
+    "mallowGumball.addCoreAssets": {
+      name: "baseAddCoreAssets",
+      accounts: {
+        seller: { defaultsTo: k.identityDefault() },
+      },
+    },
     "mallowGumball.requestAddCoreAsset": {
       name: "requestAddCoreAsset",
       accounts: {
@@ -502,6 +581,32 @@ kinobi.update(
         seller: { defaultsTo: k.identityDefault() },
       },
     },
+    "mallowGumball.addCompressedNft": {
+      name: "addCompressedNft",
+      accounts: {
+        seller: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.removeCompressedNft": {
+      name: "removeCompressedNft",
+      accounts: {
+        authority: { defaultsTo: k.identityDefault() },
+        seller: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.addSolPrize": {
+      name: "addSolPrize",
+      accounts: {
+        seller: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.removeSolPrize": {
+      name: "removeSolPrize",
+      accounts: {
+        authority: { defaultsTo: k.identityDefault() },
+        seller: { defaultsTo: k.identityDefault() },
+      },
+    },
     "mallowGumball.addTokens": {
       name: "addTokens",
       accounts: {
@@ -522,12 +627,157 @@ kinobi.update(
         seller: { defaultsTo: k.identityDefault() },
       },
     },
+    "mallowGumball.removeNftsSpan": {
+      name: "baseRemoveNftsSpan",
+      accounts: {
+        authority: { defaultsTo: k.identityDefault() },
+        instructions: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToSysvarInstructions(),
+          }),
+        },
+      },
+    },
+    "mallowGumball.removeCoreAssetsSpan": {
+      name: "baseRemoveCoreAssetsSpan",
+      accounts: {
+        authority: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.closeExpiredAddItemRequest": {
+      name: "baseCloseExpiredAddItemRequest",
+      accounts: {
+        ...addItemRequestNftDefault(),
+      },
+    },
+    "mallowGumball.rejectAddItem": {
+      name: "baseRejectAddItem",
+      accounts: {
+        authority: { defaultsTo: k.identityDefault() },
+        ...addItemRequestNftDefault(),
+      },
+    },
+    "mallowGumball.forceCloseGumballMachine": {
+      name: "baseForceCloseGumballMachine",
+      accounts: {
+        authority: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.reclaimUnsoldItem": {
+      name: "baseReclaimUnsoldItem",
+      accounts: {
+        seller: { defaultsTo: k.identityDefault() },
+        tokenAccount: {
+          defaultsTo: k.conditionalDefault("account", "tokenProgram", {
+            ifTrue: defaultsToAssociatedTokenPda("asset", "seller"),
+          }),
+        },
+        authorityPdaTokenAccount: {
+          defaultsTo: k.conditionalDefault("account", "tokenProgram", {
+            ifTrue: defaultsToAssociatedTokenPda("asset", "authorityPda"),
+          }),
+        },
+        edition: {
+          defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+            ifTrue: defaultsToMasterEditionPda("asset"),
+          }),
+        },
+        metadata: {
+          defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+            ifTrue: defaultsToMetadataPda("asset"),
+          }),
+        },
+        sellerTokenRecord: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToTokenRecordPda("asset", "tokenAccount"),
+          }),
+        },
+        authorityPdaTokenRecord: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToTokenRecordPda(
+              "asset",
+              "authorityPdaTokenAccount"
+            ),
+          }),
+        },
+        instructions: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToSysvarInstructions(),
+          }),
+        },
+      },
+    },
+    "mallowGumball.claimWithReceipt": {
+      name: "baseClaimWithReceipt",
+      accounts: {
+        owner: { defaultsTo: k.identityDefault() },
+        ownerTokenAccount: {
+          defaultsTo: k.conditionalDefault("account", "tokenProgram", {
+            ifTrue: defaultsToAssociatedTokenPda("asset", "owner"),
+          }),
+        },
+        tokenAccount: {
+          defaultsTo: k.conditionalDefault("account", "tokenProgram", {
+            ifTrue: defaultsToAssociatedTokenPda("asset", "seller"),
+          }),
+        },
+        authorityPdaTokenAccount: {
+          defaultsTo: k.conditionalDefault("account", "tokenProgram", {
+            ifTrue: defaultsToAssociatedTokenPda("asset", "authorityPda"),
+          }),
+        },
+        edition: {
+          defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+            ifTrue: defaultsToMasterEditionPda("asset"),
+          }),
+        },
+        metadata: {
+          defaultsTo: k.conditionalDefault("account", "tokenMetadataProgram", {
+            ifTrue: defaultsToMetadataPda("asset"),
+          }),
+        },
+        sellerTokenRecord: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToTokenRecordPda("asset", "tokenAccount"),
+          }),
+        },
+        authorityPdaTokenRecord: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToTokenRecordPda(
+              "asset",
+              "authorityPdaTokenAccount"
+            ),
+          }),
+        },
+        ownerTokenRecord: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToTokenRecordPda("asset", "ownerTokenAccount"),
+          }),
+        },
+        instructions: {
+          defaultsTo: k.conditionalDefault("account", "authRulesProgram", {
+            ifTrue: defaultsToSysvarInstructions(),
+          }),
+        },
+      },
+    },
+    "mallowGumball.transferPrize": {
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
     "mallowGumball.draw": {
       name: "drawFromGumballMachine",
       accounts: {
         buyer: { defaultsTo: k.identityDefault() },
       },
     },
+    "mallowGumball.drawMany": {
+      name: "drawManyFromGumballMachine",
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
     "gumballGuard.draw": {
       internal: true,
       args: {
@@ -540,6 +790,54 @@ kinobi.update(
         buyer: { defaultsTo: k.identityDefault() },
       },
     },
+    "gumballGuard.drawMany": {
+      internal: true,
+      args: {
+        label: { name: "group" },
+      },
+      accounts: {
+        gumballGuard: {
+          defaultsTo: defaultsToGumballGuardPda("gumballMachine"),
+        },
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.reroll": {
+      name: "rerollFromGumballMachine",
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "gumballGuard.reroll": {
+      internal: true,
+      args: {
+        label: { name: "group" },
+      },
+      accounts: {
+        gumballGuard: {
+          defaultsTo: defaultsToGumballGuardPda("gumballMachine"),
+        },
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.buySpecific": {
+      name: "buySpecificFromGumballMachine",
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "gumballGuard.buySpecific": {
+      internal: true,
+      args: {
+        label: { name: "group" },
+      },
+      accounts: {
+        gumballGuard: {
+          defaultsTo: defaultsToGumballGuardPda("gumballMachine"),
+        },
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
     "gumballGuard.drawJellybean": {
       internal: true,
       args: {
@@ -568,6 +866,18 @@ kinobi.update(
         buyer: { defaultsTo: k.identityDefault() },
       },
     },
+    "mallowGumball.claimCompressedNft": {
+      name: "claimCompressedNft",
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
+    "mallowGumball.claimSolPrize": {
+      name: "claimSolPrize",
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+      },
+    },
     "mallowGumball.claimTokens": {
       name: "claimTokens",
       accounts: {
@@ -674,6 +984,27 @@ kinobi.update(
         },
       },
     },
+    "mallowGumball.settleCompressedNftSale": {
+      name: "settleCompressedNftSale",
+      accounts: {
+        buyer: { defaultsTo: k.identityDefault() },
+        authorityPdaPaymentAccount: {
+          defaultsTo: k.conditionalDefault("account", "paymentMint", {
+            ifTrue: defaultsToAssociatedTokenPda("paymentMint", "authorityPda"),
+          }),
+        },
+        authorityPaymentAccount: {
+          defaultsTo: k.conditionalDefault("account", "paymentMint", {
+            ifTrue: defaultsToAssociatedTokenPda("paymentMint", "authority"),
+          }),
+        },
+        sellerPaymentAccount: {
+          defaultsTo: k.conditionalDefault("account", "paymentMint", {
+            ifTrue: defaultsToAssociatedTokenPda("paymentMint", "seller"),
+          }),
+        },
+      },
+    },
     "mallowGumball.settleTokensSale": {
       name: "settleTokensSale",
       accounts: {
@@ -733,6 +1064,22 @@ kinobi.update(
         },
       },
     },
+    "gumballGuard.pauseSale": {
+      name: "pauseGumballGuardSale",
+      accounts: {
+        gumballGuard: {
+          defaultsTo: defaultsToGumballGuardPda("gumballMachine"),
+        },
+      },
+    },
+    "gumballGuard.resumeSale": {
+      name: "resumeGumballGuardSale",
+      accounts: {
+        gumballGuard: {
+          defaultsTo: defaultsToGumballGuardPda("gumballMachine"),
+        },
+      },
+    },
     "mallowGumball.manageBuyBackFunds": {
       name: "manageBuyBackFunds",
       accounts: {
@@ -748,6 +1095,22 @@ kinobi.update(
         },
       },
     },
+    "mallowGumball.cancelPendingDraw": {
+      name: "cancelPendingDraw",
+      accounts: {
+        feePayer: { defaultsTo: k.payerDefault() },
+        authorityPdaPaymentAccount: {
+          defaultsTo: k.conditionalDefault("account", "paymentMint", {
+            ifTrue: defaultsToAssociatedTokenPda("paymentMint", "authorityPda"),
+          }),
+        },
+        buyerPaymentAccount: {
+          defaultsTo: k.conditionalDefault("account", "paymentMint", {
+            ifTrue: defaultsToAssociatedTokenPda("paymentMint", "buyer"),
+          }),
+        },
+      },
+    },
   })
 );
 
@@ -756,6 +1119,7 @@ kinobi.update(new k.FlattenInstructionArgsStructVisitor());
 const addItemDefaultArgs = k.vStruct({
   sellerProofPath: k.vNone(),
   index: k.vNone(),
+  weight: k.vNone(),
 });
 
 const nftDefaultArgs = {
@@ -771,6 +1135,7 @@ kinobi.update(
     addItemArgs: {
       sellerProofPath: k.vNone(),
       index: k.vNone(),
+      weight: k.vNone(),
     },
     addNftInstructionData: { args: addItemDefaultArgs, ...nftDefaultArgs },
     removeNftInstructionData: nftDefaultArgs,
@@ -782,9 +1147,14 @@ kinobi.update(
     },
     settleNftSaleInstructionData: nftDefaultArgs,
     requestAddNftInstructionData: { sellerProofPath: k.vNone() },
+    approveAddItemInstructionData: { weight: k.vNone() },
+    baseRejectAddItemInstructionData: { reason: k.vNone() },
+    baseClaimWithReceiptInstructionData: { compressedNft: k.vNone() },
     cancelAddNftRequestInstructionData: { sellerProofPath: k.vNone() },
     addCoreAssetInstructionData: { args: addItemDefaultArgs },
     addTokensInstructionData: { args: addItemDefaultArgs },
+    baseAddCoreAssetsInstructionData: { args: addItemDefaultArgs },
+    addSolPrizeInstructionData: { args: addItemDefaultArgs },
     initializeGumballMachineInstructionData: {
       feeConfig: k.vNone(),
       disablePrimarySplit: k.vScalar(false),
@@ -172,4 +172,25 @@ pub enum GumballGuardError {
 
     #[msg("Invalid machine")]
     InvalidMachine,
+
+    #[msg("Invalid quantity")]
+    InvalidQuantity,
+
+    #[msg("Reroll fee guard must be enabled to reroll a prize")]
+    RerollFeeNotEnabled,
+
+    #[msg("Guard group can only be used to reroll a prize")]
+    RerollOnlyGroup,
+
+    #[msg("Premium price guard must be enabled to buy a specific item")]
+    PremiumPriceNotEnabled,
+
+    #[msg("Invalid premium price")]
+    InvalidPremiumPrice,
+
+    #[msg("Premium price cannot be charged with an NFT payment or NFT burn guard")]
+    PremiumPriceNotSupported,
+
+    #[msg("Reroll fee and flat premium price guards require a single payment guard")]
+    MultiplePaymentGuards,
 }
@@ -8,8 +8,8 @@ use utils::{assert_keys_equal, assert_owned_by};
 /// List of accounts required:
 ///
 ///   0. `[writable]` Allocation tracker PDA. The PDA is derived
-///                   using the seed `["allocation", allocation id,
-///                   gumball guard pubkey, gumball machine pubkey]`.
+///      using the seed `["allocation", allocation id,
+///      gumball guard pubkey, gumball machine pubkey]`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct Allocation {
     /// Unique identifier of the allocation.
@@ -172,7 +172,7 @@ impl Condition for Allocation {
         let account_data = allocation.try_borrow_data()?;
         let mint_tracker = AllocationTracker::try_from_slice(&account_data)?;
 
-        if mint_tracker.count >= self.limit {
+        if (mint_tracker.count as u64) + (ctx.quantity as u64) > self.limit as u64 {
             return err!(GumballGuardError::AllocationLimitReached);
         }
 
@@ -190,7 +190,10 @@ impl Condition for Allocation {
         let mut account_data = allocation.try_borrow_mut_data()?;
         let mut mint_tracker = AllocationTracker::try_from_slice(&account_data)?;
 
-        mint_tracker.count += 1;
+        mint_tracker.count = mint_tracker
+            .count
+            .checked_add(ctx.quantity as u32)
+            .ok_or(GumballGuardError::NumericalOverflowError)?;
         // saves the changes back to the pda
         let data = &mut mint_tracker.try_to_vec().unwrap();
         account_data[0..data.len()].copy_from_slice(data);
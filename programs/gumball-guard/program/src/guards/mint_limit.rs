@@ -11,8 +11,8 @@ use crate::state::GuardType;
 /// List of accounts required:
 ///
 ///   0. `[writable]` Mint counter PDA. The PDA is derived
-///                   using the seed `["mint_limit", mint guard id, payer key,
-///                   gumball guard pubkey, gumball machine pubkey]`.
+///      using the seed `["mint_limit", mint guard id, payer key,
+///      gumball guard pubkey, gumball machine pubkey]`.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct MintLimit {
     /// Unique identifier of the mint limit.
@@ -87,11 +87,11 @@ impl Condition for MintLimit {
             let account_data = counter.data.borrow();
             let mint_counter = MintCounter::try_from_slice(&account_data)?;
 
-            if mint_counter.count >= self.limit {
+            if (mint_counter.count as u64) + (ctx.quantity as u64) > self.limit as u64 {
                 return err!(GumballGuardError::AllowedMintLimitReached);
             }
-        } else if self.limit < 1 {
-            // sanity check: if the limit is set to less than 1 we cannot proceed
+        } else if (ctx.quantity as u64) > self.limit as u64 {
+            // sanity check: if the limit is set to less than the quantity we cannot proceed
             return err!(GumballGuardError::AllowedMintLimitReached);
         }
 
@@ -151,7 +151,10 @@ impl Condition for MintLimit {
 
         let mut account_data = counter.try_borrow_mut_data()?;
         let mut mint_counter = MintCounter::try_from_slice(&account_data)?;
-        mint_counter.count += 1;
+        mint_counter.count = mint_counter
+            .count
+            .checked_add(ctx.quantity)
+            .ok_or(GumballGuardError::NumericalOverflowError)?;
         // saves the changes back to the pda
         let data = &mut mint_counter.try_to_vec().unwrap();
         account_data[0..data.len()].copy_from_slice(data);
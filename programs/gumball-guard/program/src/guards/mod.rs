@@ -20,8 +20,10 @@ pub use mint_limit::{MintCounter, MintLimit};
 pub use nft_burn::NftBurn;
 pub use nft_gate::NftGate;
 pub use nft_payment::NftPayment;
+pub use premium_price::{PremiumPrice, PremiumPriceType};
 pub use program_gate::ProgramGate;
 pub use redeemed_amount::RedeemedAmount;
+pub use reroll_fee::RerollFee;
 pub use sol_payment::SolPayment;
 pub use start_date::StartDate;
 pub use third_party_signer::ThirdPartySigner;
@@ -40,8 +42,10 @@ mod mint_limit;
 mod nft_burn;
 mod nft_gate;
 mod nft_payment;
+mod premium_price;
 mod program_gate;
 mod redeemed_amount;
+mod reroll_fee;
 mod sol_payment;
 mod start_date;
 mod third_party_signer;
@@ -178,6 +182,39 @@ pub struct EvaluationContext<'b, 'c: 'info, 'info> {
 
     /// The type of machine that is being used to mint the NFT.
     pub machine_type: MachineType,
+
+    /// Number of items being drawn in this transaction.
+    pub quantity: u16,
+
+    /// Index of the drawn item given back when the transaction is a reroll.
+    pub reroll_index: Option<u32>,
+
+    /// Index of the item being bought when the transaction is a specific purchase.
+    pub buy_index: Option<u32>,
+
+    /// Premium price of the active guard set, only set for specific purchases.
+    pub premium_price: Option<PremiumPrice>,
+
+    /// Reroll fee of the active guard set, only set for rerolls.
+    pub reroll_fee: Option<RerollFee>,
+}
+
+impl EvaluationContext<'_, '_, '_> {
+    /// Returns the total amount owed for the draw given the price of a single item.
+    ///
+    /// A reroll fee or flat premium price is a single amount, so guard sets using them
+    /// have at most one payment guard (checked when the guards are set).
+    pub fn total_price(&self, price: u64) -> Result<u64> {
+        let price = match (&self.premium_price, &self.reroll_fee) {
+            (Some(premium_price), _) => premium_price.price_of(price)?,
+            (None, Some(reroll_fee)) => reroll_fee.amount,
+            (None, None) => price,
+        };
+
+        Ok(price
+            .checked_mul(self.quantity as u64)
+            .ok_or(GumballGuardError::NumericalOverflowError)?)
+    }
 }
 
 /// Utility function to try to get the account from the remaining accounts
@@ -41,6 +41,9 @@ impl Condition for NftBurn {
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        // a single NFT only covers a single draw
+        require!(ctx.quantity == 1, GumballGuardError::InvalidQuantity);
+
         let index = ctx.account_cursor;
         // validates that we received all required accounts
         let nft_account = try_get_account_info(ctx.accounts.remaining, index)?;
@@ -0,0 +1,108 @@
+use crate::state::GuardType;
+
+use super::*;
+
+/// Basis points of a 1x multiplier.
+const MULTIPLIER_BASE: u64 = 10_000;
+
+/// How the price of a specific item is calculated.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum PremiumPriceType {
+    /// The value is the price of the item (in the units of the payment guard).
+    Flat,
+    /// The value is a multiple of the draw price in basis points (e.g. 20000 = 2x).
+    Multiplier,
+}
+
+/// Guard that sets the price to buy a specific item instead of drawing one. Buying a
+/// specific item is only allowed when this guard is enabled.
+///
+/// The premium price replaces the price of the payment guards (sol payment, token
+/// payment and token2022 payment) and the amount of the token burn guard. A flat price
+/// is a single amount, so guard sets with a flat premium price can only have one of
+/// those guards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PremiumPrice {
+    pub price_type: PremiumPriceType,
+    pub value: u64,
+}
+
+impl PremiumPrice {
+    /// Returns the price of a specific item given the draw price.
+    pub fn price_of(&self, draw_price: u64) -> Result<u64> {
+        match self.price_type {
+            PremiumPriceType::Flat => Ok(self.value),
+            PremiumPriceType::Multiplier => Ok((draw_price as u128)
+                .checked_mul(self.value as u128)
+                .ok_or(GumballGuardError::NumericalOverflowError)?
+                .checked_div(MULTIPLIER_BASE as u128)
+                .and_then(|price| u64::try_from(price).ok())
+                .ok_or(GumballGuardError::NumericalOverflowError)?),
+        }
+    }
+}
+
+impl Guard for PremiumPrice {
+    fn size() -> usize {
+        1   // price type
+        + 8 // value
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::PremiumPrice)
+    }
+
+    fn verify(data: &GumballGuardData) -> Result<()> {
+        let mut premium_prices = vec![];
+
+        if let Some(premium_price) = &data.default.premium_price {
+            premium_prices.push(premium_price);
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(premium_price) = &group.guards.premium_price {
+                    premium_prices.push(premium_price);
+                }
+            }
+        }
+
+        for premium_price in premium_prices {
+            // a multiplier below 1x would make specific items cheaper than a draw
+            let minimum = match premium_price.price_type {
+                PremiumPriceType::Flat => 1,
+                PremiumPriceType::Multiplier => MULTIPLIER_BASE,
+            };
+
+            if premium_price.value < minimum {
+                return err!(GumballGuardError::InvalidPremiumPrice);
+            }
+        }
+
+        for guard_set in data.active_sets() {
+            if let Some(PremiumPrice {
+                price_type: PremiumPriceType::Flat,
+                ..
+            }) = guard_set.premium_price
+            {
+                if guard_set.payment_guard_count() > 1 {
+                    return err!(GumballGuardError::MultiplePaymentGuards);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for PremiumPrice {
+    fn validate<'info>(
+        &self,
+        _ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        // the premium price is applied by the payment guards
+        Ok(())
+    }
+}
@@ -41,7 +41,11 @@ impl Condition for RedeemedAmount {
             }
         };
 
-        if items_redeemed >= self.maximum {
+        let items_after_draw = items_redeemed
+            .checked_add(ctx.quantity as u64)
+            .ok_or(GumballGuardError::NumericalOverflowError)?;
+
+        if items_after_draw > self.maximum {
             return err!(GumballGuardError::MaximumRedeemedAmount);
         }
 
@@ -0,0 +1,46 @@
+use crate::state::GuardType;
+
+use super::*;
+
+/// Guard that sets the fee to reroll an unclaimed prize. Rerolling is only allowed when
+/// this guard is enabled, and groups with this guard can only be used to reroll.
+///
+/// The fee replaces the price of the payment guards (sol payment, token payment and
+/// token2022 payment) and the amount of the token burn guard. Since the fee is a single
+/// amount, guard sets with a reroll fee can only have one of those guards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RerollFee {
+    pub amount: u64,
+}
+
+impl Guard for RerollFee {
+    fn size() -> usize {
+        8 // amount
+    }
+
+    fn mask() -> u64 {
+        GuardType::as_mask(GuardType::RerollFee)
+    }
+
+    fn verify(data: &GumballGuardData) -> Result<()> {
+        for guard_set in data.active_sets() {
+            if guard_set.reroll_fee.is_some() && guard_set.payment_guard_count() > 1 {
+                return err!(GumballGuardError::MultiplePaymentGuards);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for RerollFee {
+    fn validate<'info>(
+        &self,
+        _ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        // the reroll fee is applied by the payment guards
+        Ok(())
+    }
+}
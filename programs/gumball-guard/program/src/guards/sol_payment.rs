@@ -41,10 +41,11 @@ impl Condition for SolPayment {
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
-        if ctx.accounts.payer.lamports() < self.lamports {
+        let lamports = ctx.total_price(self.lamports)?;
+        if ctx.accounts.payer.lamports() < lamports {
             msg!(
                 "Require {} lamports, accounts has {} lamports",
-                self.lamports,
+                lamports,
                 ctx.accounts.payer.lamports(),
             );
             return err!(GumballGuardError::NotEnoughSOL);
@@ -64,13 +65,14 @@ impl Condition for SolPayment {
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let lamports = ctx.total_price(self.lamports)?;
         match ctx.machine_type {
             MachineType::Gumball => self.pre_actions_gumball(ctx, _guard_set, _mint_args)?,
             MachineType::Jellybean => self.pre_actions_jellybean(ctx, _guard_set, _mint_args)?,
         }
 
         emit!(PaymentEvent {
-            amount: self.lamports,
+            amount: lamports,
             mint: native_mint::id(),
         });
 
@@ -79,7 +81,7 @@ impl Condition for SolPayment {
 }
 
 impl SolPayment {
-    fn validate_gumball<'info>(
+    fn validate_gumball(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
@@ -122,12 +124,13 @@ impl SolPayment {
         Ok(())
     }
 
-    fn pre_actions_gumball<'info>(
+    fn pre_actions_gumball(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let lamports = ctx.total_price(self.lamports)?;
         let lamports_destination_index = ctx.indices["lamports_destination"];
         let destination = try_get_account_info(ctx.accounts.remaining, lamports_destination_index)?;
         let gumball_machine = try_from!(Account::<GumballMachine>, ctx.accounts.machine)?;
@@ -143,7 +146,7 @@ impl SolPayment {
             0
         };
 
-        let marketplace_fee = get_bps_of(self.lamports, marketplace_fee_bps)?;
+        let marketplace_fee = get_bps_of(lamports, marketplace_fee_bps)?;
         msg!("Marketplace fee: {}", marketplace_fee);
 
         if marketplace_fee > 0 {
@@ -164,8 +167,7 @@ impl SolPayment {
             )?;
         }
 
-        let price_less_fees = self
-            .lamports
+        let price_less_fees = lamports
             .checked_sub(marketplace_fee)
             .ok_or(GumballGuardError::NumericalOverflowError)?;
 
@@ -182,12 +184,12 @@ impl SolPayment {
             ],
         )?;
 
-        cpi_increment_total_revenue(ctx, self.lamports)?;
+        cpi_increment_total_revenue(ctx, lamports)?;
 
         Ok(())
     }
 
-    fn validate_jellybean<'info>(
+    fn validate_jellybean(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
@@ -212,12 +214,13 @@ impl SolPayment {
         Ok(())
     }
 
-    fn pre_actions_jellybean<'info>(
+    fn pre_actions_jellybean(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let lamports = ctx.total_price(self.lamports)?;
         let jellybean_machine = try_from!(Account::<JellybeanMachine>, ctx.accounts.machine)?;
 
         let fee_accounts_start = ctx.indices["fee_accounts"];
@@ -232,17 +235,16 @@ impl SolPayment {
             remaining_accounts,
             None,
             &ctx.accounts.system_program,
-            self.lamports,
+            lamports,
         )?;
 
-        let remaining_lamports = self
-            .lamports
+        let remaining_lamports = lamports
             .checked_sub(amount_transferred)
             .ok_or(GumballGuardError::NumericalOverflowError)?;
 
         // Any remaining dust goes to first fee account
         transfer_sol(
-            &mut ctx.accounts.payer,
+            &ctx.accounts.payer,
             &ctx.accounts.remaining[fee_accounts_start],
             &ctx.accounts.system_program,
             None,
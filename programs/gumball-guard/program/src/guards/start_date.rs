@@ -50,6 +50,7 @@ impl Condition for StartDate {
                 let gumball_machine = try_from!(Account::<GumballMachine>, ctx.accounts.machine)?;
                 if gumball_machine.state != GumballState::SaleLive
                     && gumball_machine.state != GumballState::SaleEnded
+                    && gumball_machine.state != GumballState::Paused
                 {
                     cpi_start_sale(ctx)?;
                 }
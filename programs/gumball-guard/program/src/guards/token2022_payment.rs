@@ -47,6 +47,7 @@ impl Condition for Token2022Payment {
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         require!(
             ctx.machine_type == MachineType::Gumball,
             GumballGuardError::GuardNotSupported
@@ -77,7 +78,7 @@ impl Condition for Token2022Payment {
                 let expected_ata = get_associated_token_address_with_program_id(
                     &fee_config.fee_account,
                     &self.mint,
-                    &spl_token_2022_program.key,
+                    spl_token_2022_program.key,
                 );
 
                 assert_keys_equal(
@@ -117,7 +118,7 @@ impl Condition for Token2022Payment {
             "Invalid payer token account mint",
         )?;
 
-        if token_account.base.amount < self.amount {
+        if token_account.base.amount < amount {
             return err!(GumballGuardError::NotEnoughTokens);
         }
 
@@ -143,6 +144,7 @@ impl Condition for Token2022Payment {
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         let gumball_machine = try_from!(Account::<GumballMachine>, ctx.accounts.machine)?;
 
         let index = ctx.indices["token2022_payment_index"];
@@ -166,7 +168,7 @@ impl Condition for Token2022Payment {
             0
         };
 
-        let marketplace_fee = get_bps_of(self.amount, marketplace_fee_bps)?;
+        let marketplace_fee = get_bps_of(amount, marketplace_fee_bps)?;
         msg!("Marketplace fee: {}", marketplace_fee);
 
         if marketplace_fee > 0 {
@@ -193,8 +195,7 @@ impl Condition for Token2022Payment {
             )?;
         }
 
-        let price_less_fees = self
-            .amount
+        let price_less_fees = amount
             .checked_sub(marketplace_fee)
             .ok_or(GumballGuardError::NumericalOverflowError)?;
 
@@ -218,10 +219,10 @@ impl Condition for Token2022Payment {
             ],
         )?;
 
-        cpi_increment_total_revenue(ctx, self.amount)?;
+        cpi_increment_total_revenue(ctx, amount)?;
 
         emit!(PaymentEvent {
-            amount: self.amount,
+            amount,
             mint: self.mint,
         });
 
@@ -43,7 +43,7 @@ impl Condition for TokenBurn {
 
         let account = assert_is_ata(token_gate_account, &ctx.accounts.buyer.key(), &self.mint)?;
 
-        if account.amount >= self.amount {
+        if account.amount >= ctx.total_price(self.amount)? {
             let token_gate_mint =
                 try_get_account_info(ctx.accounts.remaining, token_gate_index + 1)?;
             // consumes the remaning account
@@ -74,7 +74,7 @@ impl Condition for TokenBurn {
         spl_token_burn(TokenBurnParams {
             mint: token_gate_mint.to_account_info(),
             source: token_gate_account.to_account_info(),
-            amount: self.amount,
+            amount: ctx.total_price(self.amount)?,
             authority: ctx.accounts.buyer.to_account_info(),
             authority_signer_seeds: None,
             token_program: ctx.accounts.spl_token_program.to_account_info(),
@@ -58,13 +58,14 @@ impl Condition for TokenPayment {
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         match ctx.machine_type {
             MachineType::Gumball => self.pre_actions_gumball(ctx, _guard_set, _mint_args)?,
             MachineType::Jellybean => self.pre_actions_jellybean(ctx, _guard_set, _mint_args)?,
         }
 
         emit!(PaymentEvent {
-            amount: self.amount,
+            amount,
             mint: self.mint,
         });
 
@@ -73,12 +74,13 @@ impl Condition for TokenPayment {
 }
 
 impl TokenPayment {
-    fn validate_gumball<'info>(
+    fn validate_gumball(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         let gumball_machine = try_from!(Account::<GumballMachine>, ctx.accounts.machine)?;
 
         require!(
@@ -113,7 +115,7 @@ impl TokenPayment {
         let token_account =
             assert_is_token_account(token_account_info, ctx.accounts.payer.key(), self.mint)?;
 
-        if token_account.amount < self.amount {
+        if token_account.amount < amount {
             return err!(GumballGuardError::NotEnoughTokens);
         }
 
@@ -123,12 +125,13 @@ impl TokenPayment {
         Ok(())
     }
 
-    fn pre_actions_gumball<'info>(
+    fn pre_actions_gumball(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         let index = ctx.indices["token_payment_index"];
         // the accounts have already been validated
         let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
@@ -146,7 +149,7 @@ impl TokenPayment {
             0
         };
 
-        let marketplace_fee = get_bps_of(self.amount, marketplace_fee_bps)?;
+        let marketplace_fee = get_bps_of(amount, marketplace_fee_bps)?;
         msg!("Marketplace fee: {}", marketplace_fee);
 
         if marketplace_fee > 0 {
@@ -162,8 +165,7 @@ impl TokenPayment {
             })?;
         }
 
-        let price_less_fees = self
-            .amount
+        let price_less_fees = amount
             .checked_sub(marketplace_fee)
             .ok_or(GumballGuardError::NumericalOverflowError)?;
 
@@ -176,17 +178,18 @@ impl TokenPayment {
             amount: price_less_fees,
         })?;
 
-        cpi_increment_total_revenue(ctx, self.amount)?;
+        cpi_increment_total_revenue(ctx, amount)?;
 
         Ok(())
     }
 
-    fn validate_jellybean<'info>(
+    fn validate_jellybean(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         ctx.indices
             .insert("source_token_account", ctx.account_cursor);
 
@@ -198,7 +201,7 @@ impl TokenPayment {
             self.mint,
         )?;
 
-        if token_account.amount < self.amount {
+        if token_account.amount < amount {
             return err!(GumballGuardError::NotEnoughTokens);
         }
 
@@ -219,12 +222,13 @@ impl TokenPayment {
         Ok(())
     }
 
-    fn pre_actions_jellybean<'info>(
+    fn pre_actions_jellybean(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        let amount = ctx.total_price(self.amount)?;
         let jellybean_machine = try_from!(Account::<JellybeanMachine>, ctx.accounts.machine)?;
 
         let source_token_account_index = ctx.indices["source_token_account"];
@@ -237,17 +241,16 @@ impl TokenPayment {
 
         let amount_transferred = pay_fee_accounts(
             &mut ctx.accounts.payer,
-            Some(&source_token_account_info),
+            Some(source_token_account_info),
             Some(self.mint),
             &jellybean_machine.fee_accounts,
             remaining_accounts,
             Some(&ctx.accounts.spl_token_program),
             &ctx.accounts.system_program,
-            self.amount,
+            amount,
         )?;
 
-        let remaining_tokens = self
-            .amount
+        let remaining_tokens = amount
             .checked_sub(amount_transferred)
             .ok_or(GumballGuardError::NumericalOverflowError)?;
 
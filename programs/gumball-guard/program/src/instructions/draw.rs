@@ -4,6 +4,7 @@ use anchor_lang::{prelude::*, solana_program::sysvar};
 use mallow_gumball::GumballMachine;
 
 use crate::{
+    errors::GumballGuardError,
     guards::{EvaluationContext, MachineType},
     processors::process_draw,
     state::{GumballGuard, SEED},
@@ -16,6 +17,17 @@ pub fn draw<'c: 'info, 'info>(
     mint_args: Vec<u8>,
     label: Option<String>,
 ) -> Result<()> {
+    draw_many(ctx, 1, mint_args, label)
+}
+
+pub fn draw_many<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Draw<'info>>,
+    quantity: u16,
+    mint_args: Vec<u8>,
+    label: Option<String>,
+) -> Result<()> {
+    require!(quantity > 0, GumballGuardError::InvalidQuantity);
+
     let accounts = DrawAccounts {
         gumball_guard: &ctx.accounts.gumball_guard,
         machine: ctx.accounts.gumball_machine.to_account_info(),
@@ -27,8 +39,14 @@ pub fn draw<'c: 'info, 'info>(
         system_program: ctx.accounts.system_program.to_account_info(),
         sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
         token_metadata_program: Some(ctx.accounts.token_metadata_program.to_account_info()),
+        pending_draw: Some(ctx.accounts.pending_draw.to_account_info()),
+        randomness: ctx
+            .accounts
+            .randomness
+            .as_ref()
+            .map(|a| a.to_account_info()),
         remaining: ctx.remaining_accounts,
-        event_authority: ctx.accounts.gumball_event_authority.to_account_info(),
+        event_authority: None,
         authority_pda: None,
         unclaimed_prizes: None,
         print_fee_account: None,
@@ -42,6 +60,11 @@ pub fn draw<'c: 'info, 'info>(
         args_cursor: 0,
         indices: BTreeMap::new(),
         machine_type: MachineType::Gumball,
+        quantity,
+        reroll_index: None,
+        buy_index: None,
+        premium_price: None,
+        reroll_fee: None,
     };
 
     process_draw(&mut ctx, mint_args, label)
@@ -96,6 +119,14 @@ pub struct Draw<'info> {
     #[account(address = sysvar::slot_hashes::id())]
     recent_slothashes: UncheckedAccount<'info>,
 
-    /// CHECK: safe due to check in gumball machine
-    gumball_event_authority: UncheckedAccount<'info>,
+    /// Pending draw account.
+    ///
+    /// CHECK: account checked in CPI
+    #[account(mut)]
+    pending_draw: UncheckedAccount<'info>,
+
+    /// Randomness account of the gumball machine's randomness provider.
+    ///
+    /// CHECK: account checked in CPI
+    randomness: Option<UncheckedAccount<'info>>,
 }
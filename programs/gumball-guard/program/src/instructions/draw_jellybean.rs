@@ -28,8 +28,10 @@ pub fn draw_jellybean<'c: 'info, 'info>(
         system_program: ctx.accounts.system_program.to_account_info(),
         sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
         remaining: ctx.remaining_accounts,
-        event_authority: ctx.accounts.jellybean_event_authority.to_account_info(),
+        event_authority: Some(ctx.accounts.jellybean_event_authority.to_account_info()),
         token_metadata_program: None,
+        pending_draw: None,
+        randomness: None,
         authority_pda: Some(
             ctx.accounts
                 .jellybean_machine_authority_pda
@@ -51,6 +53,11 @@ pub fn draw_jellybean<'c: 'info, 'info>(
         args_cursor: 0,
         indices: BTreeMap::new(),
         machine_type: MachineType::Jellybean,
+        quantity: 1,
+        reroll_index: None,
+        buy_index: None,
+        premium_price: None,
+        reroll_fee: None,
     };
 
     process_draw(&mut ctx, mint_args, label)
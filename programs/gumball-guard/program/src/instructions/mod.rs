@@ -1,8 +1,12 @@
 use crate::state::GumballGuard;
 use anchor_lang::prelude::*;
+pub use buy_specific::*;
 pub use draw::*;
 pub use draw_jellybean::*;
 pub use initialize::*;
+pub use pause_sale::*;
+pub use reroll::*;
+pub use resume_sale::*;
 pub use route::*;
 pub use set_authority::*;
 pub use unwrap::*;
@@ -10,9 +14,13 @@ pub use update::*;
 pub use withdraw::*;
 pub use wrap::*;
 
+pub mod buy_specific;
 pub mod draw;
 pub mod draw_jellybean;
 pub mod initialize;
+pub mod pause_sale;
+pub mod reroll;
+pub mod resume_sale;
 pub mod route;
 pub mod set_authority;
 pub mod unwrap;
@@ -32,10 +40,12 @@ pub(crate) struct DrawAccounts<'b, 'c, 'info> {
     pub(crate) sysvar_instructions: AccountInfo<'info>,
     pub(crate) recent_slothashes: AccountInfo<'info>,
     pub(crate) remaining: &'c [AccountInfo<'info>],
-    pub(crate) event_authority: AccountInfo<'info>,
     /// Only required for Gumball.
     pub(crate) token_metadata_program: Option<AccountInfo<'info>>,
+    pub(crate) pending_draw: Option<AccountInfo<'info>>,
+    pub(crate) randomness: Option<AccountInfo<'info>>,
     /// Only required for Jellybean.
+    pub(crate) event_authority: Option<AccountInfo<'info>>,
     pub(crate) authority_pda: Option<AccountInfo<'info>>,
     pub(crate) unclaimed_prizes: Option<AccountInfo<'info>>,
     pub(crate) print_fee_account: Option<AccountInfo<'info>>,
@@ -0,0 +1,43 @@
+use crate::state::{GumballGuard, SEED};
+use anchor_lang::prelude::*;
+use mallow_gumball::{
+    cpi::{accounts::ResumeSale as ResumeSaleAccounts, resume_sale as cpi_resume_sale},
+    GumballMachine,
+};
+
+pub fn resume_sale(ctx: Context<ResumeSale>) -> Result<()> {
+    let gumball_guard = &ctx.accounts.gumball_guard;
+    // PDA signer for the transaction
+    let seeds = [SEED, &gumball_guard.base.to_bytes(), &[gumball_guard.bump]];
+    let signer = [&seeds[..]];
+
+    let resume_ix = ResumeSaleAccounts {
+        gumball_machine: ctx.accounts.gumball_machine.to_account_info(),
+        authority: gumball_guard.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.gumball_machine_program.to_account_info(),
+        resume_ix,
+        &signer,
+    );
+    // gumball machine resume_sale CPI
+    cpi_resume_sale(cpi_ctx)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResumeSale<'info> {
+    #[account(
+        has_one = authority,
+        constraint = gumball_guard.key() == gumball_machine.mint_authority
+    )]
+    pub gumball_guard: Account<'info, GumballGuard>,
+    // gumball guard authority
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub gumball_machine: Account<'info, GumballMachine>,
+    /// CHECK: account constraints checked in account trait
+    #[account(address = mallow_gumball::id())]
+    pub gumball_machine_program: AccountInfo<'info>,
+}
@@ -53,7 +53,7 @@ pub fn route<'c: 'info, 'info>(
 
     let route_context = RouteContext {
         gumball_guard: gumball_guard_account,
-        machine_mint_authority: machine_mint_authority,
+        machine_mint_authority,
         guard_set,
     };
 
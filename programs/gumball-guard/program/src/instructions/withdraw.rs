@@ -46,7 +46,7 @@ pub fn withdraw<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>) -> Resu
     let signer = [&seeds[..]];
 
     // TODO: Make this less expensive
-    if let Ok(_) = try_from!(Account::<GumballMachine>, machine) {
+    if try_from!(Account::<GumballMachine>, machine).is_ok() {
         withdraw_cpi(
             CpiContext::new_with_signer(
                 machine_program,
@@ -55,20 +55,20 @@ pub fn withdraw<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>) -> Resu
                     authority,
                     mint_authority: gumball_guard.to_account_info(),
                     authority_pda: ctx.accounts.authority_pda.to_account_info(),
-                    authority_pda_payment_account: if let Some(authority_pda_payment_account) =
-                        &ctx.accounts.authority_pda_payment_account
-                    {
-                        Some(authority_pda_payment_account.to_account_info())
-                    } else {
-                        None
-                    },
+                    authority_pda_payment_account: ctx
+                        .accounts
+                        .authority_pda_payment_account
+                        .as_ref()
+                        .map(|authority_pda_payment_account| {
+                            authority_pda_payment_account.to_account_info()
+                        }),
                     token_program: ctx.accounts.token_program.to_account_info(),
                 },
                 &signer,
             )
             .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
         )?;
-    } else if let Ok(_) = try_from!(Account::<JellybeanMachine>, machine) {
+    } else if try_from!(Account::<JellybeanMachine>, machine).is_ok() {
         WithdrawCpiBuilder::new(&machine_program)
             .jellybean_machine(machine)
             .authority(&authority)
@@ -1,4 +1,4 @@
-#![allow(clippy::result_large_err)]
+#![allow(clippy::result_large_err, clippy::too_many_arguments)]
 
 use anchor_lang::prelude::*;
 
@@ -32,6 +32,16 @@ pub mod gumball_guard {
         instructions::draw(ctx, mint_args, label)
     }
 
+    /// Draw multiple prizes from a gumball machine wrapped in the gumball guard.
+    pub fn draw_many<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Draw<'info>>,
+        quantity: u16,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    ) -> Result<()> {
+        instructions::draw_many(ctx, quantity, mint_args, label)
+    }
+
     /// Draw a prize from a gumball machine wrapped in the gumball guard.
     pub fn draw_jellybean<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, DrawJellybean<'info>>,
@@ -41,6 +51,40 @@ pub mod gumball_guard {
         instructions::draw_jellybean(ctx, mint_args, label)
     }
 
+    /// Buy a specific prize from a gumball machine wrapped in the gumball guard, at the
+    /// price set by the premium price guard of the group. Groups with the nft payment or
+    /// nft burn guard cannot be used to buy specific prizes.
+    pub fn buy_specific<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BuySpecific<'info>>,
+        index: u32,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    ) -> Result<()> {
+        instructions::buy_specific(ctx, index, mint_args, label)
+    }
+
+    /// Give back an unclaimed prize and draw a new one from a gumball machine wrapped
+    /// in the gumball guard. The group must have the reroll fee guard, which sets the
+    /// price charged by the payment guards; groups with it can only be used to reroll.
+    pub fn reroll<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Reroll<'info>>,
+        index: u32,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    ) -> Result<()> {
+        instructions::reroll(ctx, index, mint_args, label)
+    }
+
+    /// Pause the sale of a gumball machine wrapped in the gumball guard.
+    pub fn pause_sale(ctx: Context<PauseSale>) -> Result<()> {
+        instructions::pause_sale(ctx)
+    }
+
+    /// Resume the sale of a gumball machine wrapped in the gumball guard.
+    pub fn resume_sale(ctx: Context<ResumeSale>) -> Result<()> {
+        instructions::resume_sale(ctx)
+    }
+
     /// Route the transaction to a guard instruction.
     pub fn route<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, Route<'info>>,
@@ -1,8 +1,9 @@
-use anchor_lang::{prelude::*, Discriminator};
+use anchor_lang::{prelude::*, Discriminator, InstructionData};
 use mallow_jellybean_sdk::instructions::DrawCpiBuilder;
 use solana_program::{instruction::Instruction, program::invoke_signed};
 
 use crate::{
+    errors::GumballGuardError,
     guards::{EvaluationContext, MachineType},
     state::{GuardSet, GumballGuardData, DATA_OFFSET, SEED},
 };
@@ -26,6 +27,47 @@ pub fn process_draw(
     };
     drop(account_data);
 
+    // specific items can only be bought at the premium price, which payment guards
+    // use instead of their own price
+    if ctx.buy_index.is_some() {
+        // nft payment and nft burn charge a single NFT regardless of the price
+        if guard_set.nft_payment.is_some() || guard_set.nft_burn.is_some() {
+            return process_error(
+                ctx,
+                &guard_set,
+                error!(GumballGuardError::PremiumPriceNotSupported),
+            );
+        }
+
+        match &guard_set.premium_price {
+            Some(premium_price) => ctx.premium_price = Some(premium_price.clone()),
+            None => {
+                return process_error(
+                    ctx,
+                    &guard_set,
+                    error!(GumballGuardError::PremiumPriceNotEnabled),
+                )
+            }
+        }
+    }
+
+    // rerolls are only allowed at the reroll fee, which payment guards use instead of
+    // their own price, and groups with a reroll fee cannot be used for anything else
+    match (ctx.reroll_index, &guard_set.reroll_fee) {
+        (Some(_), Some(reroll_fee)) => ctx.reroll_fee = Some(reroll_fee.clone()),
+        (Some(_), None) => {
+            return process_error(
+                ctx,
+                &guard_set,
+                error!(GumballGuardError::RerollFeeNotEnabled),
+            )
+        }
+        (None, Some(_)) => {
+            return process_error(ctx, &guard_set, error!(GumballGuardError::RerollOnlyGroup))
+        }
+        (None, None) => {}
+    }
+
     let conditions = guard_set.enabled_conditions();
 
     // validates enabled guards (any error at this point is subject to bot tax)
@@ -71,30 +113,48 @@ fn cpi_draw(ctx: &EvaluationContext) -> Result<()> {
 
     match ctx.machine_type {
         MachineType::Gumball => {
+            if let Some(index) = ctx.reroll_index {
+                return cpi_reroll(ctx, index, &signer);
+            }
+
+            if let Some(index) = ctx.buy_index {
+                return cpi_buy_specific(ctx, index, &signer);
+            }
+
             // gumball machine mint instruction accounts
             let mint_accounts = Box::new(mallow_gumball::cpi::accounts::Draw {
                 gumball_machine: ctx.accounts.machine.to_account_info(),
+                pending_draw: ctx.accounts.pending_draw.clone().unwrap(),
                 mint_authority: gumball_guard.to_account_info(),
                 payer: ctx.accounts.payer.clone(),
                 buyer: ctx.accounts.buyer.clone(),
                 system_program: ctx.accounts.system_program.clone(),
-                recent_slothashes: ctx.accounts.recent_slothashes.clone(),
-                event_authority: ctx.accounts.event_authority.clone(),
-                program: ctx.accounts._machine_program.clone(),
+                randomness: ctx.accounts.randomness.clone(),
             });
 
-            let mint_infos = mint_accounts.to_account_infos();
+            let mut mint_infos = mint_accounts.to_account_infos();
+            // missing optional accounts are passed as the gumball machine program
+            mint_infos.push(ctx.accounts._machine_program.clone());
             let mint_metas = mint_accounts.to_account_metas(None);
 
             let mint_ix = Instruction {
                 program_id: mallow_gumball::ID,
                 accounts: mint_metas,
-                data: mallow_gumball::instruction::Draw::DISCRIMINATOR.to_vec(),
+                data: if ctx.quantity == 1 {
+                    mallow_gumball::instruction::Draw::DISCRIMINATOR.to_vec()
+                } else {
+                    mallow_gumball::instruction::DrawMany {
+                        quantity: ctx.quantity,
+                    }
+                    .data()
+                },
             };
 
             invoke_signed(&mint_ix, &mint_infos, &signer)?;
         }
         MachineType::Jellybean => {
+            require!(ctx.quantity == 1, GumballGuardError::InvalidQuantity);
+
             DrawCpiBuilder::new(&ctx.accounts._machine_program)
                 .jellybean_machine(&ctx.accounts.machine)
                 .authority_pda(ctx.accounts.authority_pda.as_ref().unwrap())
@@ -105,7 +165,7 @@ fn cpi_draw(ctx: &EvaluationContext) -> Result<()> {
                 .system_program(&ctx.accounts.system_program)
                 .rent(ctx.accounts.rent.as_ref().unwrap())
                 .recent_slothashes(&ctx.accounts.recent_slothashes)
-                .event_authority(&ctx.accounts.event_authority)
+                .event_authority(ctx.accounts.event_authority.as_ref().unwrap())
                 .program(&ctx.accounts._machine_program)
                 .print_fee_account(ctx.accounts.print_fee_account.as_ref())
                 .invoke_signed(&signer)?;
@@ -114,3 +174,55 @@ fn cpi_draw(ctx: &EvaluationContext) -> Result<()> {
 
     Ok(())
 }
+
+fn cpi_reroll(ctx: &EvaluationContext, index: u32, signer: &[&[&[u8]]]) -> Result<()> {
+    // gumball machine reroll instruction accounts
+    let reroll_accounts = Box::new(mallow_gumball::cpi::accounts::Reroll {
+        gumball_machine: ctx.accounts.machine.to_account_info(),
+        pending_draw: ctx.accounts.pending_draw.clone().unwrap(),
+        mint_authority: ctx.accounts.gumball_guard.to_account_info(),
+        payer: ctx.accounts.payer.clone(),
+        buyer: ctx.accounts.buyer.clone(),
+        system_program: ctx.accounts.system_program.clone(),
+        randomness: ctx.accounts.randomness.clone(),
+    });
+
+    let mut reroll_infos = reroll_accounts.to_account_infos();
+    // missing optional accounts are passed as the gumball machine program
+    reroll_infos.push(ctx.accounts._machine_program.clone());
+    let reroll_metas = reroll_accounts.to_account_metas(None);
+
+    let reroll_ix = Instruction {
+        program_id: mallow_gumball::ID,
+        accounts: reroll_metas,
+        data: mallow_gumball::instruction::Reroll { index }.data(),
+    };
+
+    invoke_signed(&reroll_ix, &reroll_infos, signer)?;
+
+    Ok(())
+}
+
+fn cpi_buy_specific(ctx: &EvaluationContext, index: u32, signer: &[&[&[u8]]]) -> Result<()> {
+    // gumball machine buy specific instruction accounts
+    let buy_accounts = Box::new(mallow_gumball::cpi::accounts::BuySpecific {
+        gumball_machine: ctx.accounts.machine.to_account_info(),
+        mint_authority: ctx.accounts.gumball_guard.to_account_info(),
+        buyer: ctx.accounts.buyer.clone(),
+        event_authority: ctx.accounts.event_authority.clone().unwrap(),
+        program: ctx.accounts._machine_program.clone(),
+    });
+
+    let buy_infos = buy_accounts.to_account_infos();
+    let buy_metas = buy_accounts.to_account_metas(None);
+
+    let buy_ix = Instruction {
+        program_id: mallow_gumball::ID,
+        accounts: buy_metas,
+        data: mallow_gumball::instruction::BuySpecific { index }.data(),
+    };
+
+    invoke_signed(&buy_ix, &buy_infos, signer)?;
+
+    Ok(())
+}
@@ -55,6 +55,8 @@ pub struct GumballGuard {
     // 19) program gate
     // 20) allocation
     // 21) token2022 payment
+    // 22) premium price
+    // 23) reroll fee
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -112,6 +114,10 @@ pub struct GuardSet {
     pub allocation: Option<Allocation>,
     /// Token2022 payment guard (set the price for the mint in spl-token-2022 amount).
     pub token2022_payment: Option<Token2022Payment>,
+    /// Premium price guard (set the price to buy a specific item).
+    pub premium_price: Option<PremiumPrice>,
+    /// Reroll fee guard (set the fee to reroll an unclaimed prize).
+    pub reroll_fee: Option<RerollFee>,
 }
 
 /// Available guard types.
@@ -136,6 +142,23 @@ pub enum GuardType {
     ProgramGate,
     Allocation,
     Token2022Payment,
+    PremiumPrice,
+    RerollFee,
+}
+
+impl GuardSet {
+    /// Returns the number of guards that charge the price of the draw.
+    pub fn payment_guard_count(&self) -> usize {
+        [
+            self.sol_payment.is_some(),
+            self.token_payment.is_some(),
+            self.token2022_payment.is_some(),
+            self.token_burn.is_some(),
+        ]
+        .iter()
+        .filter(|enabled| **enabled)
+        .count()
+    }
 }
 
 impl GuardType {
@@ -270,6 +293,22 @@ impl GumballGuardData {
         size
     }
 
+    /// Returns the guard sets that can be active: each group merged with the default
+    /// guard set, or the default guard set when there are no groups.
+    pub fn active_sets(&self) -> Vec<GuardSet> {
+        match &self.groups {
+            Some(groups) if !groups.is_empty() => groups
+                .iter()
+                .map(|group| {
+                    let mut guard_set = self.default.clone();
+                    guard_set.merge(group.guards.clone());
+                    guard_set
+                })
+                .collect(),
+            _ => vec![self.default.clone()],
+        }
+    }
+
     pub fn verify(&self) -> Result<()> {
         // set of unique labels
         let mut labels = HashSet::new();
@@ -65,7 +65,7 @@ pub fn assert_is_token_account(
 }
 
 pub fn assert_derivation(program_id: &Pubkey, account: &AccountInfo, path: &[&[u8]]) -> Result<u8> {
-    let (key, bump) = Pubkey::find_program_address(&path, program_id);
+    let (key, bump) = Pubkey::find_program_address(path, program_id);
     if key != *account.key {
         return err!(GumballGuardError::InvalidPDA);
     }
@@ -197,7 +197,7 @@ pub fn pay_fee_accounts<'a>(
 
             transfer_sol(
                 payer,
-                &mut current_fee_account.to_account_info(),
+                &current_fee_account.to_account_info(),
                 system_program,
                 None,
                 fee_amount,
@@ -227,7 +227,10 @@ pub fn pay_fee_accounts<'a>(
 
 #[macro_export]
 macro_rules! try_from {
-    ($ty: ty, $acc: expr) => {
-        <$ty>::try_from(unsafe { std::mem::transmute::<_, &AccountInfo<'_>>($acc.as_ref()) })
-    };
+    ($ty: ty, $acc: expr) => {{
+        let account_info: &AccountInfo<'_> = $acc.as_ref();
+        <$ty>::try_from(unsafe {
+            std::mem::transmute::<&AccountInfo<'_>, &AccountInfo<'_>>(account_info)
+        })
+    }};
 }
@@ -4,6 +4,11 @@ use solana_program::{pubkey, pubkey::Pubkey};
 pub const MPL_TOKEN_AUTH_RULES_PROGRAM: Pubkey =
     pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
 
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+pub const SPL_NOOP_PROGRAM: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
 // Seed used to derive the authority PDA address.
 pub const AUTHORITY_SEED: &str = "gumball_machine";
 
@@ -13,6 +18,20 @@ pub const SELLER_HISTORY_SEED: &str = "seller_history";
 // Seed used to derive the add item request PDA address.
 pub const ADD_ITEM_REQUEST_SEED: &str = "add_item_request";
 
+// Seed used to derive the pending draw PDA address.
+pub const PENDING_DRAW_SEED: &str = "pending_draw";
+
+// Number of slots between committing a draw and the slot whose hash is used to reveal it.
+// Leader schedules rotate every 4 slots, so the target slot is never produced by the
+// leader that processed the commit.
+pub const DRAW_REVEAL_SLOT_DELAY: u64 = 4;
+
+// Attributes of a draw receipt: the gumball machine, the index of the item and the slot
+// of the draw that selected it.
+pub const RECEIPT_MACHINE_ATTRIBUTE: &str = "machine";
+pub const RECEIPT_INDEX_ATTRIBUTE: &str = "index";
+pub const RECEIPT_SLOT_ATTRIBUTE: &str = "slot";
+
 pub const GUMBALL_SETTINGS_BYTE_INDEX: usize = 8 // discriminator
     + 1                                       // version
     + 32                                      // authority
@@ -43,3 +62,15 @@ pub const CONFIG_LINE_SIZE: usize = 32// mint
     +1; // token standard
 
 pub const CONFIG_LINE_V2_SIZE: usize = CONFIG_LINE_SIZE + 8; // amount
+
+pub const CONFIG_LINE_V3_SIZE: usize = CONFIG_LINE_V2_SIZE + 1; // tier
+
+pub const CONFIG_LINE_V4_SIZE: usize = CONFIG_LINE_V3_SIZE + 4; // proceeds weight
+
+pub const CONFIG_LINE_V5_SIZE: usize = CONFIG_LINE_V4_SIZE + 8; // draw slot
+
+pub const CONFIG_LINE_V6_SIZE: usize = CONFIG_LINE_V5_SIZE + 8; // price
+
+// Maximum number of tiers items can be assigned to. The IDL parser cannot resolve constants in
+// array lengths, so the tier arrays of `TierConfig` and `set_tier_weights` spell it out.
+pub const MAX_TIERS: usize = 8;
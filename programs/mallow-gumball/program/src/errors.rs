@@ -208,4 +208,109 @@ pub enum GumballError {
 
     #[msg("Missing item index")]
     MissingItemIndex,
+
+    #[msg("Invalid quantity")]
+    InvalidQuantity,
+
+    #[msg("Draw cannot be revealed yet")]
+    RevealNotReady,
+
+    #[msg("Items are pending reveal")]
+    ItemsPendingReveal,
+
+    #[msg("Invalid tier")]
+    InvalidTier,
+
+    #[msg("Missing randomness account")]
+    MissingRandomnessAccount,
+
+    #[msg("Invalid randomness account")]
+    InvalidRandomnessAccount,
+
+    #[msg("Account size increase is too large, resize in smaller steps")]
+    AccountSizeIncreaseTooLarge,
+
+    #[msg("Invalid proceeds weight")]
+    InvalidProceedsWeight,
+
+    #[msg("Invalid token program")]
+    InvalidTokenProgram,
+
+    #[msg("Non-transferable tokens cannot be added")]
+    NonTransferableMint,
+
+    #[msg("Tokens with a transfer hook or permanent delegate cannot be added")]
+    UnsupportedMintExtension,
+
+    #[msg("Compressed nft args are required")]
+    MissingCompressedNftArgs,
+
+    #[msg("Duplicate item")]
+    DuplicateItem,
+
+    #[msg("Missing account")]
+    MissingAccount,
+
+    #[msg("Invalid add item request expiry")]
+    InvalidAddItemRequestExpiry,
+
+    #[msg("Add item request has not expired")]
+    AddItemRequestNotExpired,
+
+    #[msg("Invalid force close grace period")]
+    InvalidForceCloseGracePeriod,
+
+    #[msg("Gumball machine cannot be force closed yet")]
+    ForceCloseNotAvailable,
+
+    #[msg("Invalid claim deadline")]
+    InvalidClaimDeadline,
+
+    #[msg("Claim deadline has passed")]
+    ClaimDeadlinePassed,
+
+    #[msg("Invalid instant delivery account")]
+    InvalidInstantDeliveryAccount,
+
+    #[msg("Receipt collection is not set")]
+    ReceiptsNotEnabled,
+
+    #[msg("Invalid receipt collection")]
+    InvalidReceiptCollection,
+
+    #[msg("Invalid receipt")]
+    InvalidReceipt,
+
+    #[msg("Item is held by a receipt")]
+    ItemHeldByReceipt,
+
+    #[msg("Receipts require a claim deadline")]
+    ClaimDeadlineRequired,
+
+    #[msg("Earlier draws must be revealed first")]
+    RevealOutOfOrder,
+
+    #[msg("The randomness request was already fulfilled")]
+    RandomnessRequestFulfilled,
+
+    #[msg("The randomness request of the draw was replaced")]
+    RandomnessRequestReplaced,
+
+    #[msg("SOL prizes must be at least the rent-exempt minimum")]
+    SolPrizeTooSmall,
+
+    #[msg("Transfer would use the lamports of SOL prizes")]
+    SolPrizesNotCovered,
+
+    #[msg("The draw can still be revealed")]
+    DrawCanBeRevealed,
+
+    #[msg("Compressed NFTs cannot be delivered instantly")]
+    InstantDeliveryNotSupported,
+
+    #[msg("Invalid receipt account")]
+    InvalidReceiptAccount,
+
+    #[msg("Receipts cannot be used with instant delivery")]
+    ReceiptsWithInstantDelivery,
 }
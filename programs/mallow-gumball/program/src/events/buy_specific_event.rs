@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct BuySpecificEvent {
+    pub authority: Pubkey,
+    pub buyer: Pubkey,
+    pub index: u32,
+}
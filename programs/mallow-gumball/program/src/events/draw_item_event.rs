@@ -6,4 +6,10 @@ pub struct DrawItemEvent {
     pub authority: Pubkey,
     pub buyer: Pubkey,
     pub index: u32,
+    /// Tier of the drawn item.
+    pub tier: u8,
+    /// Draw weight of the item's tier.
+    pub weight: u16,
+    /// Sum of the weights of every item available when the item was drawn.
+    pub total_weight: u64,
 }
@@ -1,9 +1,19 @@
+pub mod buy_specific_event;
 pub mod claim_item_event;
 pub mod draw_item_event;
+pub mod mint_receipt_event;
+pub mod reject_add_item_event;
+pub mod reroll_item_event;
 pub mod sell_item_event;
 pub mod settle_item_sale_event;
+pub mod transfer_prize_event;
 
+pub use buy_specific_event::*;
 pub use claim_item_event::*;
 pub use draw_item_event::*;
+pub use mint_receipt_event::*;
+pub use reject_add_item_event::*;
+pub use reroll_item_event::*;
 pub use sell_item_event::*;
 pub use settle_item_sale_event::*;
+pub use transfer_prize_event::*;
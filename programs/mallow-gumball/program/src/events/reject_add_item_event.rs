@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RejectAddItemEvent {
+    pub gumball_machine: Pubkey,
+    pub authority: Pubkey,
+    pub seller: Pubkey,
+    pub asset: Pubkey,
+    /// Optional curator defined code explaining the rejection.
+    pub reason: Option<u8>,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RerollItemEvent {
+    pub authority: Pubkey,
+    pub buyer: Pubkey,
+    pub rerolled_index: u32,
+    pub index: u32,
+}
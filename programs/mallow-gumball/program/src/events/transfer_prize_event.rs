@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct TransferPrizeEvent {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub new_buyer: Pubkey,
+}
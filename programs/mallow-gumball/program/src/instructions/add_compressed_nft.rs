@@ -0,0 +1,150 @@
+use crate::{
+    assert_can_add_item,
+    constants::{
+        AUTHORITY_SEED, SELLER_HISTORY_SEED, SPL_ACCOUNT_COMPRESSION_PROGRAM, SPL_NOOP_PROGRAM,
+    },
+    state::GumballMachine,
+    transfer_compressed_nft, ConfigLineV2Input, GumballError, SellerHistory, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+
+use super::AddItemArgs;
+
+/// Current state of a compressed NFT leaf, required to transfer it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompressedNftArgs {
+    /// Root of the merkle tree the proof was generated for.
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    /// Nonce of the leaf, used to derive the asset id.
+    pub nonce: u64,
+    /// Index of the leaf in the merkle tree.
+    pub index: u32,
+}
+
+/// Add compressed nft to a gumball machine.
+#[derive(Accounts)]
+pub struct AddCompressedNft<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_add_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Seller history account.
+    #[account(
+		init_if_needed,
+		seeds = [
+			SELLER_HISTORY_SEED.as_bytes(),
+			gumball_machine.key().as_ref(),
+            seller.key().as_ref(),
+		],
+		bump,
+		space = SellerHistory::SPACE,
+		payer = seller
+	)]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Seller of the nft
+    #[account(mut)]
+    seller: Signer<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_NOOP_PROGRAM)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_compressed_nft<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddCompressedNft<'info>>,
+    args: AddItemArgs,
+    compressed_nft: CompressedNftArgs,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let merkle_tree = &ctx.accounts.merkle_tree.to_account_info();
+    let tree_config = &ctx.accounts.tree_config.to_account_info();
+    let bubblegum_program = &ctx.accounts.bubblegum_program.to_account_info();
+    let compression_program = &ctx.accounts.compression_program.to_account_info();
+    let log_wrapper = &ctx.accounts.log_wrapper.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+
+    seller_history.gumball_machine = gumball_machine.key();
+    seller_history.seller = seller.key();
+
+    // Validate the seller
+    assert_can_add_item(gumball_machine, seller_history, 1, &args)?;
+
+    // compressed NFTs cannot be delivered when the draw is revealed
+    let account_info = gumball_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+    require!(
+        !gumball_machine.is_instant_delivery(&account_data)?,
+        GumballError::InstantDeliveryNotSupported
+    );
+    drop(account_data);
+
+    seller_history.item_count += 1;
+
+    crate::processors::add_item(
+        gumball_machine,
+        ConfigLineV2Input {
+            mint: get_asset_id(merkle_tree.key, compressed_nft.nonce),
+            seller: seller.key(),
+            amount: 1,
+        },
+        TokenStandard::CompressedNonFungible,
+        1,
+        args.index,
+        args.weight.unwrap_or(1),
+    )?;
+
+    // The gumball machine takes custody of the leaf
+    transfer_compressed_nft(
+        seller,
+        authority_pda,
+        merkle_tree,
+        tree_config,
+        bubblegum_program,
+        compression_program,
+        log_wrapper,
+        system_program,
+        ctx.remaining_accounts,
+        &compressed_nft,
+        None,
+    )?;
+
+    Ok(())
+}
@@ -96,6 +96,7 @@ pub fn add_core_asset(ctx: Context<AddCoreAsset>, args: AddItemArgs) -> Result<(
         TokenStandard::Core,
         1,
         args.index,
+        args.weight.unwrap_or(1),
     )?;
 
     let auth_seeds = [
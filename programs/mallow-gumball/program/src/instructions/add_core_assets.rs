@@ -0,0 +1,142 @@
+use crate::{
+    approve_and_freeze_core_asset, assert_can_add_item,
+    constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
+    state::GumballMachine,
+    ConfigLineV2Input, GumballError, SellerHistory, TokenStandard,
+};
+use anchor_lang::prelude::*;
+
+use super::AddItemArgs;
+
+/// Add multiple core assets to a gumball machine.
+#[derive(Accounts)]
+pub struct AddCoreAssets<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_add_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Seller history account.
+    #[account(
+		init_if_needed,
+		seeds = [
+			SELLER_HISTORY_SEED.as_bytes(),
+			gumball_machine.key().as_ref(),
+            seller.key().as_ref(),
+		],
+		bump,
+		space = SellerHistory::SPACE,
+		payer = seller
+	)]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(), 
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Seller of the assets.
+    #[account(mut)]
+    seller: Signer<'info>,
+
+    /// Collection shared by the assets if they are part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_core_assets<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddCoreAssets<'info>>,
+    args: AddItemArgs,
+) -> Result<()> {
+    let assets = ctx.remaining_accounts;
+    let quantity: u16 = assets
+        .len()
+        .try_into()
+        .map_err(|_| GumballError::InvalidQuantity)?;
+    // Re-adding an item is only supported one at a time
+    require!(
+        quantity > 0 && args.index.is_none(),
+        GumballError::InvalidQuantity
+    );
+
+    let mut asset_keys: Vec<&Pubkey> = assets.iter().map(|asset| asset.key).collect();
+    asset_keys.sort_unstable();
+    require!(
+        asset_keys.windows(2).all(|keys| keys[0] != keys[1]),
+        GumballError::DuplicateItem
+    );
+
+    let gumball_machine_key = ctx.accounts.gumball_machine.key();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+
+    seller_history.gumball_machine = gumball_machine.key();
+    seller_history.seller = seller.key();
+
+    // Validate the seller once for the whole batch
+    assert_can_add_item(gumball_machine, seller_history, quantity, &args)?;
+
+    seller_history.item_count = seller_history
+        .item_count
+        .checked_add(quantity.into())
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    let collection_info = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let collection = collection_info.as_ref();
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    for asset_info in assets {
+        crate::processors::add_item(
+            gumball_machine,
+            ConfigLineV2Input {
+                mint: asset_info.key(),
+                seller: seller.key(),
+                amount: 1,
+            },
+            TokenStandard::Core,
+            1,
+            None,
+            args.weight.unwrap_or(1),
+        )?;
+
+        approve_and_freeze_core_asset(
+            seller,
+            asset_info,
+            collection,
+            authority_pda,
+            &auth_seeds,
+            mpl_core_program,
+            system_program,
+        )?;
+    }
+
+    Ok(())
+}
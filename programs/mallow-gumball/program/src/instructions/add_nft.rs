@@ -86,6 +86,8 @@ pub struct AddNft<'info> {
 pub struct AddItemArgs {
     pub seller_proof_path: Option<Vec<[u8; 32]>>,
     pub index: Option<u32>,
+    /// Proceeds weight of the item (defaults to 1), can only be set by the authority
+    pub weight: Option<u32>,
 }
 
 pub fn add_nft(ctx: Context<AddNft>, args: AddItemArgs) -> Result<()> {
@@ -123,9 +125,10 @@ pub fn add_nft(ctx: Context<AddNft>, args: AddItemArgs) -> Result<()> {
             seller: ctx.accounts.seller.key(),
             amount: 1,
         },
-        token_standard_from_mpl_token_standard(&metadata)?,
+        token_standard_from_mpl_token_standard(metadata)?,
         1,
         args.index,
+        args.weight.unwrap_or(1),
     )?;
 
     let auth_seeds = [
@@ -0,0 +1,118 @@
+use crate::{
+    assert_can_add_item,
+    constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
+    state::GumballMachine,
+    ConfigLineV2Input, GumballError, SellerHistory, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use utils::transfer_sol;
+
+use super::AddItemArgs;
+
+/// Add native SOL prizes to a gumball machine.
+#[derive(Accounts)]
+pub struct AddSolPrize<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_add_items() @ GumballError::InvalidState,
+        constraint = gumball_machine.version >= 18 @ GumballError::InvalidVersion,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Seller history account.
+    #[account(
+		init_if_needed,
+		seeds = [
+			SELLER_HISTORY_SEED.as_bytes(),
+			gumball_machine.key().as_ref(),
+            seller.key().as_ref(),
+		],
+		bump,
+		space = SellerHistory::SPACE,
+		payer = seller
+	)]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Seller of the prize
+    #[account(mut)]
+    seller: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_sol_prize(
+    ctx: Context<AddSolPrize>,
+    amount: u64,
+    quantity: u16,
+    args: AddItemArgs,
+) -> Result<()> {
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+
+    require!(amount > 0, GumballError::InvalidAmount);
+    // system transfers cannot leave an account below the rent-exempt minimum, so each
+    // prize can be paid to an empty account and the authority pda stays rent-exempt
+    // while it holds prizes
+    require!(
+        amount >= Rent::get()?.minimum_balance(0),
+        GumballError::SolPrizeTooSmall
+    );
+
+    seller_history.gumball_machine = gumball_machine.key();
+    seller_history.seller = seller.key();
+
+    // Validate the seller
+    assert_can_add_item(gumball_machine, seller_history, quantity, &args)?;
+
+    seller_history.item_count = seller_history
+        .item_count
+        .checked_add(quantity.into())
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    // SOL prizes are recorded with the native mint
+    crate::processors::add_item(
+        gumball_machine,
+        ConfigLineV2Input {
+            mint: native_mint::ID,
+            seller: seller.key(),
+            amount,
+        },
+        TokenStandard::Sol,
+        quantity,
+        args.index,
+        args.weight.unwrap_or(1),
+    )?;
+
+    let total_amount = amount
+        .checked_mul(quantity.into())
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    let account_info = gumball_machine.to_account_info();
+    let mut data = account_info.data.borrow_mut();
+    let sol_prizes_held = gumball_machine
+        .get_sol_prizes_held(&data)?
+        .checked_add(total_amount)
+        .ok_or(GumballError::NumericalOverflowError)?;
+    gumball_machine.set_sol_prizes_held(&mut data, sol_prizes_held)?;
+    drop(data);
+
+    transfer_sol(seller, authority_pda, system_program, None, total_amount)?;
+
+    Ok(())
+}
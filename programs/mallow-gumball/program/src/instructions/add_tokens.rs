@@ -1,15 +1,16 @@
 use crate::{
-    assert_can_add_item,
+    assert_can_add_item, assert_transferable_mint,
     constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
+    get_amount_with_transfer_fee, get_fungible_token_standard,
     state::GumballMachine,
-    ConfigLineV2Input, GumballError, SellerHistory, Token, TokenStandard,
+    ConfigLineV2Input, GumballError, SellerHistory,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
-use utils::transfer_spl;
+use utils::transfer_spl_checked;
 
 use super::AddItemArgs;
 
@@ -52,20 +53,21 @@ pub struct AddTokens<'info> {
     #[account(mut)]
     seller: Signer<'info>,
 
-    mint: Box<Account<'info, Mint>>,
+    /// Mint of the tokens (SPL Token or Token-2022)
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
         constraint = token_account.mint == mint.key(),
         constraint = token_account.owner == seller.key(),
     )]
-    token_account: Box<Account<'info, TokenAccount>>,
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// CHECK: Safe due to transfer checks
     #[account(mut)]
     authority_pda_token_account: UncheckedAccount<'info>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
@@ -88,6 +90,10 @@ pub fn add_tokens(
     let gumball_machine = &mut ctx.accounts.gumball_machine;
     let seller_history = &mut ctx.accounts.seller_history;
 
+    // Token-2022 mints are stored with their own token standard
+    let token_standard = get_fungible_token_standard(mint);
+    assert_transferable_mint(mint)?;
+
     seller_history.gumball_machine = gumball_machine.key();
     seller_history.seller = seller.key();
 
@@ -106,12 +112,19 @@ pub fn add_tokens(
             seller: ctx.accounts.seller.key(),
             amount,
         },
-        TokenStandard::Fungible,
+        token_standard,
         quantity,
         args.index,
+        args.weight.unwrap_or(1),
     )?;
 
-    transfer_spl(
+    let total_amount = amount
+        .checked_mul(quantity.into())
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    // The gumball machine must receive the full amount of the items, so the seller
+    // covers any Token-2022 transfer fee
+    transfer_spl_checked(
         seller,
         authority_pda,
         seller_token_account,
@@ -124,9 +137,8 @@ pub fn add_tokens(
         None,
         None,
         None,
-        amount
-            .checked_mul(quantity.into())
-            .ok_or(GumballError::NumericalOverflowError)?,
+        get_amount_with_transfer_fee(mint, total_amount)?,
+        ctx.accounts.mint.decimals,
     )?;
 
     Ok(())
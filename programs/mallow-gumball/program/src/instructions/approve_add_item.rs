@@ -11,6 +11,7 @@ pub struct ApproveAddItem<'info> {
     /// Gumball Machine account.
     #[account(
         mut,
+        has_one = authority @ GumballError::InvalidAuthority,
         constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
     )]
     gumball_machine: Box<Account<'info, GumballMachine>>,
@@ -55,7 +56,10 @@ pub struct ApproveAddItem<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn approve_add_item(ctx: Context<ApproveAddItem>) -> Result<()> {
+pub fn approve_add_item(ctx: Context<ApproveAddItem>, weight: Option<u32>) -> Result<()> {
+    let weight = weight.unwrap_or(1);
+    require!(weight > 0, GumballError::InvalidProceedsWeight);
+
     let gumball_machine = &mut ctx.accounts.gumball_machine;
 
     let add_item_request = &ctx.accounts.add_item_request;
@@ -70,6 +74,7 @@ pub fn approve_add_item(ctx: Context<ApproveAddItem>) -> Result<()> {
         add_item_request.token_standard,
         1,
         None,
+        weight,
     )?;
 
     Ok(())
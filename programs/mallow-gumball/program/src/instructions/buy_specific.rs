@@ -0,0 +1,113 @@
+use crate::{
+    events::BuySpecificEvent, get_config_count, instructions::set_buyer,
+    processors::take_item_from_tier, GumballError, GumballMachine, GumballState,
+};
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+
+/// Buys a specific item from the gumball machine.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BuySpecific<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = mint_authority,
+        constraint = gumball_machine.state == GumballState::SaleLive @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball machine mint authority (mint only allowed for the mint_authority).
+    mint_authority: Signer<'info>,
+
+    /// Buyer of the item.
+    ///
+    /// CHECK: account not written or read from
+    buyer: UncheckedAccount<'info>,
+}
+
+pub fn buy_specific<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuySpecific<'info>>,
+    index: u32,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let config_count = get_config_count(&account_data)? as u64;
+
+    if index as u64 >= config_count {
+        return err!(GumballError::IndexGreaterThanLength);
+    }
+
+    // items reserved by pending draws must remain available
+    if gumball_machine.items_redeemed >= config_count {
+        return err!(GumballError::GumballMachineEmpty);
+    }
+
+    // pending draws are selected from the available items when revealed, so taking one
+    // of them would change the outcome of a draw whose seed may already be known
+    require!(
+        gumball_machine.get_items_pending(&account_data)? == 0,
+        GumballError::ItemsPendingReveal
+    );
+
+    if gumball_machine.is_tiered() {
+        let tier = gumball_machine.get_item_tier(&account_data, index)?;
+        let mut tier_config = gumball_machine.get_tier_config(&account_data)?;
+        take_item_from_tier(
+            gumball_machine,
+            &mut account_data,
+            &mut tier_config,
+            tier,
+            index,
+        )?;
+        gumball_machine.set_tier_config(&mut account_data, &tier_config)?;
+    } else {
+        // available indices also hold the items reserved by pending draws
+        let items_pending = gumball_machine.get_items_pending(&account_data)? as u64;
+        let items_available =
+            (config_count - gumball_machine.items_redeemed + items_pending) as usize;
+        let indices_start = gumball_machine.get_mint_indices_position()?;
+
+        let position = (0..items_available)
+            .map(|i| indices_start + i * 4)
+            .find(|position| u32::from_le_bytes(*array_ref![account_data, *position, 4]) == index)
+            .ok_or(GumballError::ItemAlreadyDrawn)?;
+
+        // swap-remove with the last available index
+        let last_position = indices_start + (items_available - 1) * 4;
+        account_data.copy_within(last_position..last_position + 4, position);
+    }
+
+    gumball_machine.set_item_draw_slot(&mut account_data, index, Clock::get()?.slot)?;
+    let price = gumball_machine.take_draw_revenue(&mut account_data)?;
+    gumball_machine.set_item_price(&mut account_data, index, price)?;
+    drop(account_data);
+
+    set_buyer(gumball_machine, ctx.accounts.buyer.key(), index)?;
+
+    gumball_machine.items_redeemed = gumball_machine
+        .items_redeemed
+        .checked_add(1)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    // Sale has ended if this is the last item to be redeemed
+    if gumball_machine.items_redeemed == config_count {
+        gumball_machine.state = GumballState::SaleEnded;
+
+        if gumball_machine.version >= 10 {
+            let mut account_data = account_info.data.borrow_mut();
+            gumball_machine.set_sale_ended_at(&mut account_data, Clock::get()?.unix_timestamp)?;
+        }
+    }
+
+    msg!("Bought item at index: {}", index);
+
+    emit_cpi!(BuySpecificEvent {
+        authority: gumball_machine.authority.key(),
+        buyer: ctx.accounts.buyer.key(),
+        index,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{
     constants::{ADD_ITEM_REQUEST_SEED, AUTHORITY_SEED, SELLER_HISTORY_SEED},
-    thaw_and_revoke_core_asset, AddItemRequest, GumballError, SellerHistory,
+    processors::{self, CancelAddItemRequestParams},
+    AddItemRequest, GumballError, SellerHistory,
 };
 use anchor_lang::prelude::*;
 
@@ -72,6 +73,7 @@ pub fn cancel_add_core_asset_request(ctx: Context<CancelAddCoreAssetRequest>) ->
     let authority_pda = &ctx.accounts.authority_pda.to_account_info();
     let seller = &ctx.accounts.seller.to_account_info();
     let seller_history = &mut ctx.accounts.seller_history;
+    let gumball_machine = seller_history.gumball_machine;
 
     let collection_info = ctx
         .accounts
@@ -82,26 +84,33 @@ pub fn cancel_add_core_asset_request(ctx: Context<CancelAddCoreAssetRequest>) ->
 
     let auth_seeds = [
         AUTHORITY_SEED.as_bytes(),
-        seller_history.gumball_machine.as_ref(),
+        gumball_machine.as_ref(),
         &[ctx.bumps.authority_pda],
     ];
 
-    thaw_and_revoke_core_asset(
-        seller,
-        seller,
-        asset_info,
-        collection,
-        authority_pda,
-        &auth_seeds,
-        mpl_core_program,
-        system_program,
-    )?;
-
-    seller_history.item_count -= 1;
-
-    if seller_history.item_count == 0 {
-        seller_history.close(seller.to_account_info())?;
-    }
-
-    Ok(())
+    processors::cancel_add_item_request(
+        ctx.accounts.add_item_request.token_standard,
+        CancelAddItemRequestParams {
+            payer: seller,
+            seller,
+            asset: asset_info,
+            authority_pda,
+            auth_seeds: &auth_seeds,
+            system_program,
+            collection,
+            mpl_core_program: Some(mpl_core_program),
+            token_account: None,
+            authority_pda_token_account: None,
+            edition: None,
+            token_program: None,
+            associated_token_program: None,
+            token_metadata_program: None,
+            metadata: None,
+            seller_token_record: None,
+            auth_rules: None,
+            instructions: None,
+            auth_rules_program: None,
+        },
+        seller_history,
+    )
 }
@@ -2,7 +2,8 @@ use crate::{
     constants::{
         ADD_ITEM_REQUEST_SEED, AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, SELLER_HISTORY_SEED,
     },
-    thaw_and_revoke_nft_v2, AddItemRequest, AssociatedToken, GumballError, SellerHistory, Token,
+    processors::{self, CancelAddItemRequestParams},
+    AddItemRequest, AssociatedToken, GumballError, SellerHistory, Token,
 };
 use anchor_lang::prelude::*;
 
@@ -105,10 +106,11 @@ pub fn cancel_add_nft_request(ctx: Context<CancelAddNftRequest>) -> Result<()> {
     let mint = &ctx.accounts.mint.to_account_info();
     let seller = &ctx.accounts.seller.to_account_info();
     let seller_history = &mut ctx.accounts.seller_history;
+    let gumball_machine = seller_history.gumball_machine;
 
     let auth_seeds = [
         AUTHORITY_SEED.as_bytes(),
-        seller_history.gumball_machine.as_ref(),
+        gumball_machine.as_ref(),
         &[ctx.bumps.authority_pda],
     ];
 
@@ -119,31 +121,29 @@ pub fn cancel_add_nft_request(ctx: Context<CancelAddNftRequest>) -> Result<()> {
         .map(|acc| acc.to_account_info());
     let metadata_account_ref = metadata_account.as_ref();
 
-    thaw_and_revoke_nft_v2(
-        seller,
-        seller,
-        mint,
-        token_account,
-        edition,
-        authority_pda,
-        &auth_seeds,
-        token_metadata_program,
-        token_program,
-        metadata_account_ref,
-        ctx.accounts.seller_token_record.as_ref(),
-        ctx.accounts.auth_rules.as_ref(),
-        system_program,
-        ctx.accounts.instructions.as_ref(),
-        ctx.accounts.auth_rules_program.as_ref(),
-        associated_token_program,
-        authority_pda_token_account,
-    )?;
-
-    seller_history.item_count -= 1;
-
-    if seller_history.item_count == 0 {
-        seller_history.close(seller.to_account_info())?;
-    }
-
-    Ok(())
+    processors::cancel_add_item_request(
+        ctx.accounts.add_item_request.token_standard,
+        CancelAddItemRequestParams {
+            payer: seller,
+            seller,
+            asset: mint,
+            authority_pda,
+            auth_seeds: &auth_seeds,
+            system_program,
+            collection: None,
+            mpl_core_program: None,
+            token_account: Some(token_account),
+            authority_pda_token_account: Some(authority_pda_token_account),
+            edition: Some(edition),
+            token_program: Some(token_program),
+            associated_token_program: Some(associated_token_program),
+            token_metadata_program: Some(token_metadata_program),
+            metadata: metadata_account_ref,
+            seller_token_record: ctx.accounts.seller_token_record.as_ref(),
+            auth_rules: ctx.accounts.auth_rules.as_ref(),
+            instructions: ctx.accounts.instructions.as_ref(),
+            auth_rules_program: ctx.accounts.auth_rules_program.as_ref(),
+        },
+        seller_history,
+    )
 }
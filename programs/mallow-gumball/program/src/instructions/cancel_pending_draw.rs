@@ -0,0 +1,216 @@
+use crate::{
+    assert_force_close_available,
+    constants::{AUTHORITY_SEED, PENDING_DRAW_SEED},
+    processors::{assert_sol_prizes_covered, reverse_revenue},
+    utils::*,
+    AssociatedToken, GumballError, GumballMachine, GumballState, PendingDraw, Token,
+};
+use anchor_lang::{prelude::*, AccountsClose};
+use utils::{is_native_mint, transfer_from_pda};
+
+/// Cancels a pending draw that can no longer be revealed on an abandoned gumball machine.
+#[derive(Accounts)]
+pub struct CancelPendingDraw<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.version >= 17 @ GumballError::InvalidVersion,
+        constraint = gumball_machine.state == GumballState::SaleEnded @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Pending draw account.
+    #[account(
+        mut,
+        has_one = gumball_machine,
+        has_one = payer,
+        has_one = buyer,
+        seeds = [
+            PENDING_DRAW_SEED.as_bytes(),
+            gumball_machine.key().as_ref(),
+            &pending_draw.draw_number.to_le_bytes()
+        ],
+        bump
+    )]
+    pending_draw: Box<Account<'info, PendingDraw>>,
+
+    /// Payer of the pending draw account (receiver of the closed account rent).
+    ///
+    /// CHECK: Safe due to pending draw constraint
+    #[account(mut)]
+    payer: UncheckedAccount<'info>,
+
+    /// Buyer of the draw (receiver of the refund).
+    ///
+    /// CHECK: Safe due to pending draw constraint
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+
+    /// Payer of the buyer's payment account if it needs to be created.
+    #[account(mut)]
+    fee_payer: Signer<'info>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Payment account for authority pda if using token payment
+    /// CHECK: Safe due to ata check in processor
+    #[account(mut)]
+    authority_pda_payment_account: Option<UncheckedAccount<'info>>,
+
+    /// Buyer's token account if using token payment
+    /// CHECK: Safe due to ata check in processor
+    #[account(mut)]
+    buyer_payment_account: Option<UncheckedAccount<'info>>,
+
+    /// Payment mint if using non-native payment token
+    /// CHECK: Safe due to payment mint check in instruction
+    payment_mint: Option<UncheckedAccount<'info>>,
+
+    /// Randomness account of the provider used by the draw.
+    ///
+    /// CHECK: account checked in instruction
+    randomness: UncheckedAccount<'info>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+}
+
+pub fn cancel_pending_draw<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelPendingDraw<'info>>,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let pending_draw = &ctx.accounts.pending_draw;
+
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    assert_force_close_available(gumball_machine, &account_data)?;
+
+    // draws are revealed (or cancelled) in the order they were made
+    let draws_revealed = gumball_machine.get_draws_revealed(&account_data)?;
+    require!(
+        pending_draw.draw_number == draws_revealed,
+        GumballError::RevealOutOfOrder
+    );
+
+    // only a draw without a known outcome can be cancelled, otherwise the buyer could
+    // choose between the revealed items and a refund. Slot hash draws can always be
+    // revealed (with a new target slot if needed)
+    require!(
+        pending_draw.randomness != Pubkey::default()
+            && cmp_pubkeys(&ctx.accounts.randomness.key(), &pending_draw.randomness),
+        GumballError::InvalidRandomnessAccount
+    );
+    let request = get_randomness_request(
+        &ctx.accounts.randomness.to_account_info(),
+        &gumball_machine.get_randomness_config(&account_data)?,
+        &gumball_machine.get_randomness_request_config(&account_data)?,
+    )?;
+    require!(
+        request.request_slot != pending_draw.randomness_request_slot || !request.is_fulfilled(),
+        GumballError::DrawCanBeRevealed
+    );
+
+    // the reserved items go back to the pool: they were never removed from the available
+    // indices, so only the counters change
+    let items_pending = gumball_machine
+        .get_items_pending(&account_data)?
+        .checked_sub(pending_draw.quantity)
+        .ok_or(GumballError::InvalidQuantity)?;
+    let items_pending_position = gumball_machine.get_items_pending_position()?;
+    account_data[items_pending_position..items_pending_position + 2]
+        .copy_from_slice(&items_pending.to_le_bytes());
+    gumball_machine.items_redeemed -= pending_draw.quantity as u64;
+    gumball_machine.set_draws_revealed(
+        &mut account_data,
+        draws_revealed
+            .checked_add(1)
+            .ok_or(GumballError::NumericalOverflowError)?,
+    )?;
+
+    let refund = reverse_revenue(gumball_machine, &mut account_data, pending_draw.revenue)?;
+    drop(account_data);
+
+    if refund > 0 {
+        let payment_mint_info = ctx
+            .accounts
+            .payment_mint
+            .as_ref()
+            .map(|mint| mint.to_account_info());
+        if is_native_mint(gumball_machine.settings.payment_mint) {
+            require!(
+                payment_mint_info.is_none(),
+                GumballError::InvalidPaymentMint
+            );
+        } else {
+            require!(
+                payment_mint_info.is_some()
+                    && payment_mint_info.as_ref().unwrap().key()
+                        == gumball_machine.settings.payment_mint,
+                GumballError::InvalidPaymentMint
+            );
+        }
+
+        let authority_pda_payment_account_info = ctx
+            .accounts
+            .authority_pda_payment_account
+            .as_ref()
+            .map(|account| account.to_account_info());
+        let buyer_payment_account_info = ctx
+            .accounts
+            .buyer_payment_account
+            .as_ref()
+            .map(|account| account.to_account_info());
+
+        let gumball_machine_key = gumball_machine.key();
+        let auth_seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine_key.as_ref(),
+            &[ctx.bumps.authority_pda],
+        ];
+
+        transfer_from_pda(
+            &mut ctx.accounts.authority_pda.to_account_info(),
+            &mut ctx.accounts.buyer.to_account_info(),
+            authority_pda_payment_account_info.as_ref(),
+            buyer_payment_account_info.as_ref(),
+            payment_mint_info.as_ref(),
+            Some(&ctx.accounts.fee_payer.to_account_info()),
+            Some(&ctx.accounts.associated_token_program.to_account_info()),
+            Some(&ctx.accounts.token_program.to_account_info()),
+            &ctx.accounts.system_program.to_account_info(),
+            &auth_seeds,
+            None,
+            refund,
+        )?;
+
+        let account_data = account_info.data.borrow();
+        assert_sol_prizes_covered(
+            gumball_machine,
+            &account_data,
+            &ctx.accounts.authority_pda.to_account_info(),
+        )?;
+    }
+
+    msg!(
+        "Cancelled draw: {}, refunded: {}",
+        pending_draw.draw_number,
+        refund
+    );
+
+    ctx.accounts
+        .pending_draw
+        .close(ctx.accounts.payer.to_account_info())?;
+
+    Ok(())
+}
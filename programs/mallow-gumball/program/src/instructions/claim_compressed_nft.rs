@@ -0,0 +1,127 @@
+use crate::{
+    assert_config_line,
+    constants::{AUTHORITY_SEED, SPL_ACCOUNT_COMPRESSION_PROGRAM, SPL_NOOP_PROGRAM},
+    events::ClaimItemEvent,
+    processors,
+    state::GumballMachine,
+    ConfigLine, GumballError, GumballState, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+
+use super::CompressedNftArgs;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimCompressedNft<'info> {
+    /// Anyone can settle the sale
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Seller of the nft
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+
+    /// buyer of the nft
+    /// CHECK: Safe due to item check
+    buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_NOOP_PROGRAM)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn claim_compressed_nft<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimCompressedNft<'info>>,
+    index: u32,
+    compressed_nft: CompressedNftArgs,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let merkle_tree = &ctx.accounts.merkle_tree.to_account_info();
+    let asset_id = get_asset_id(merkle_tree.key, compressed_nft.nonce);
+
+    processors::assert_before_claim_deadline(gumball_machine)?;
+
+    assert_config_line(
+        gumball_machine,
+        index,
+        ConfigLine {
+            mint: asset_id,
+            seller: seller.key(),
+            buyer: buyer.key(),
+            token_standard: TokenStandard::CompressedNonFungible,
+        },
+        false,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    processors::claim_compressed_nft(
+        gumball_machine,
+        index,
+        authority_pda,
+        buyer,
+        merkle_tree,
+        &ctx.accounts.tree_config.to_account_info(),
+        &ctx.accounts.bubblegum_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        &compressed_nft,
+        &auth_seeds,
+    )?;
+
+    emit_cpi!(ClaimItemEvent {
+        mint: asset_id,
+        authority: gumball_machine.authority.key(),
+        seller: seller.key(),
+        buyer: buyer.key(),
+        amount: 1,
+    });
+
+    Ok(())
+}
@@ -14,7 +14,7 @@ pub struct ClaimCoreAsset<'info> {
     /// Gumball machine account.
     #[account(
         mut,
-        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded @ GumballError::InvalidState
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
     )]
     gumball_machine: Box<Account<'info, GumballMachine>>,
 
@@ -72,6 +72,8 @@ pub fn claim_core_asset<'info>(
         .map(|account| account.to_account_info());
     let collection = collection_info.as_ref();
 
+    processors::assert_before_claim_deadline(gumball_machine)?;
+
     assert_config_line(
         gumball_machine,
         index,
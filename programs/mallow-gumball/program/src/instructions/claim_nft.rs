@@ -21,7 +21,7 @@ pub struct ClaimNft<'info> {
     /// Gumball machine account.
     #[account(
         mut,
-        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded @ GumballError::InvalidState
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
     )]
     gumball_machine: Box<Account<'info, GumballMachine>>,
 
@@ -119,6 +119,8 @@ pub fn claim_nft<'info>(
     let metadata_info = &ctx.accounts.metadata.to_account_info();
     let metadata = &Metadata::try_from(metadata_info)?;
 
+    processors::assert_before_claim_deadline(gumball_machine)?;
+
     assert_config_line(
         gumball_machine,
         index,
@@ -126,7 +128,7 @@ pub fn claim_nft<'info>(
             mint: mint.key(),
             seller: seller.key(),
             buyer: buyer.key(),
-            token_standard: token_standard_from_mpl_token_standard(&metadata)?,
+            token_standard: token_standard_from_mpl_token_standard(metadata)?,
         },
         false,
     )?;
@@ -0,0 +1,94 @@
+use crate::{
+    assert_config_line, constants::AUTHORITY_SEED, events::ClaimItemEvent, processors,
+    state::GumballMachine, ConfigLine, GumballError, GumballState, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+/// Claims a native SOL prize
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimSolPrize<'info> {
+    /// Anyone can claim the prize
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Seller of the prize
+    /// CHECK: Safe due to item check
+    seller: UncheckedAccount<'info>,
+
+    /// buyer of the prize
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn claim_sol_prize<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimSolPrize<'info>>,
+    index: u32,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+
+    processors::assert_before_claim_deadline(gumball_machine)?;
+
+    assert_config_line(
+        gumball_machine,
+        index,
+        ConfigLine {
+            mint: native_mint::ID,
+            seller: seller.key(),
+            buyer: buyer.key(),
+            token_standard: TokenStandard::Sol,
+        },
+        false,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let amount = processors::claim_sol_prize(
+        gumball_machine,
+        index,
+        authority_pda,
+        buyer,
+        system_program,
+        &auth_seeds,
+    )?;
+
+    emit_cpi!(ClaimItemEvent {
+        mint: native_mint::ID,
+        authority: gumball_machine.authority.key(),
+        seller: seller.key(),
+        buyer: buyer.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use crate::{
-    assert_config_line, constants::AUTHORITY_SEED, events::ClaimItemEvent, processors,
-    state::GumballMachine, AssociatedToken, ConfigLine, GumballError, GumballState, Token,
-    TokenStandard,
+    assert_config_line, constants::AUTHORITY_SEED, events::ClaimItemEvent,
+    get_fungible_token_standard, processors, state::GumballMachine, AssociatedToken, ConfigLine,
+    GumballError, GumballState,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 /// Settles a legacy NFT sale
 #[event_cpi]
@@ -18,7 +18,7 @@ pub struct ClaimTokens<'info> {
     #[account(
         mut,
         has_one = authority,
-        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded @ GumballError::InvalidState
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
     )]
     gumball_machine: Box<Account<'info, GumballMachine>>,
 
@@ -47,12 +47,13 @@ pub struct ClaimTokens<'info> {
     /// CHECK: Safe due to item check
     buyer: UncheckedAccount<'info>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
 
-    mint: Box<Account<'info, Mint>>,
+    /// Mint of the tokens (SPL Token or Token-2022)
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK: Safe due to transfer checks
     #[account(mut)]
@@ -63,7 +64,7 @@ pub struct ClaimTokens<'info> {
         constraint = authority_pda_token_account.mint == mint.key(),
         constraint = authority_pda_token_account.owner == authority_pda.key(),
     )]
-    authority_pda_token_account: Box<Account<'info, TokenAccount>>,
+    authority_pda_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 }
 
 pub fn claim_tokens<'info>(
@@ -83,6 +84,8 @@ pub fn claim_tokens<'info>(
     let mint = &ctx.accounts.mint.to_account_info();
     let authority = &ctx.accounts.authority.to_account_info();
 
+    processors::assert_before_claim_deadline(gumball_machine)?;
+
     assert_config_line(
         gumball_machine,
         index,
@@ -90,7 +93,7 @@ pub fn claim_tokens<'info>(
             mint: mint.key(),
             seller: seller.key(),
             buyer: buyer.key(),
-            token_standard: TokenStandard::Fungible,
+            token_standard: get_fungible_token_standard(mint),
         },
         false,
     )?;
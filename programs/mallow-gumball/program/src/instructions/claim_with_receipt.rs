@@ -0,0 +1,412 @@
+use crate::{
+    assert_config_line,
+    constants::{
+        AUTHORITY_SEED, GUMBALL_MACHINE_SIZE, MPL_TOKEN_AUTH_RULES_PROGRAM,
+        RECEIPT_INDEX_ATTRIBUTE, RECEIPT_MACHINE_ATTRIBUTE, SPL_ACCOUNT_COMPRESSION_PROGRAM,
+        SPL_NOOP_PROGRAM,
+    },
+    events::ClaimItemEvent,
+    get_fungible_token_program, get_item_token_standard,
+    instructions::CompressedNftArgs,
+    processors,
+    state::GumballMachine,
+    try_from, AssociatedToken, ConfigLine, GumballError, GumballState, Token, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface::TokenAccount};
+use mpl_core::{instructions::BurnV1CpiBuilder, types::UpdateAuthority, Asset};
+use mpl_token_metadata::accounts::Metadata;
+use utils::core::attributes::get_attribute_value;
+
+/// Burns a draw receipt and delivers the item to the owner of the receipt.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimWithReceipt<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the gumball machine, receives the rent of closed token accounts
+    /// CHECK: Safe due to gumball machine constraint
+    #[account(mut)]
+    authority: UncheckedAccount<'info>,
+
+    /// Seller of the item
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+
+    /// Owner of the receipt, receives the item
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    /// Receipt asset, burnt once the item is claimed
+    /// CHECK: Safe due to receipt checks
+    #[account(mut)]
+    receipt: UncheckedAccount<'info>,
+
+    /// Receipt collection
+    /// CHECK: Safe due to gumball machine receipt collection check
+    #[account(mut)]
+    receipt_collection: UncheckedAccount<'info>,
+
+    /// Asset (mint for NFTs and tokens, native mint for SOL prizes)
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    /// OPTIONAL CORE ASSET ACCOUNTS - only required if claiming a Core asset
+
+    /// Core asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL TOKEN ACCOUNTS - only required if claiming a NFT, PNFT or tokens
+
+    /// Owner's token account
+    /// CHECK: Safe due to ata check in transfer
+    #[account(mut)]
+    owner_token_account: Option<UncheckedAccount<'info>>,
+    /// Seller's token account (NFT)
+    /// CHECK: Safe due to thaw/transfer
+    #[account(mut)]
+    token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to transfer
+    #[account(mut)]
+    authority_pda_token_account: Option<UncheckedAccount<'info>>,
+    token_program: Option<Program<'info, Token>>,
+    associated_token_program: Option<Program<'info, AssociatedToken>>,
+    /// Token-2022 program, only required if the item is a Token-2022 token
+    token_2022_program: Option<Program<'info, Token2022>>,
+
+    /// OPTIONAL NFT ACCOUNTS - only required if claiming a NFT or PNFT
+
+    /// CHECK: Safe due to thaw/send
+    #[account(mut)]
+    edition: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to constraint
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL PNFT ACCOUNTS - only required if claiming a PNFT
+
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub seller_token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub authority_pda_token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub owner_token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    pub auth_rules: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    pub auth_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL COMPRESSED NFT ACCOUNTS - only required if claiming a compressed NFT
+
+    /// CHECK: Safe due to bubblegum transfer
+    #[account(mut)]
+    merkle_tree: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to bubblegum transfer
+    tree_config: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM)]
+    compression_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_NOOP_PROGRAM)]
+    log_wrapper: Option<UncheckedAccount<'info>>,
+}
+
+pub fn claim_with_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimWithReceipt<'info>>,
+    index: u32,
+    compressed_nft: Option<CompressedNftArgs>,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let authority = &ctx.accounts.authority.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let owner = &ctx.accounts.owner.to_account_info();
+    let receipt = &ctx.accounts.receipt.to_account_info();
+    let receipt_collection = &ctx.accounts.receipt_collection.to_account_info();
+    let asset = &ctx.accounts.asset.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let token_standard = get_item_token_standard(gumball_machine, index)?;
+
+    // Items with a receipt have the authority PDA as buyer. The claim deadline does not
+    // apply to them, the item is held until the receipt is burnt
+    assert_config_line(
+        gumball_machine,
+        index,
+        ConfigLine {
+            mint: asset.key(),
+            seller: seller.key(),
+            buyer: authority_pda.key(),
+            token_standard,
+        },
+        false,
+    )?;
+
+    let account_info = gumball_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+    require!(
+        gumball_machine.get_receipt_collection(&account_data)? == Some(receipt_collection.key()),
+        GumballError::InvalidReceiptCollection
+    );
+    drop(account_data);
+
+    // Only the gumball machine can mint into the receipt collection, so the attributes
+    // identify the item
+    let receipt_asset = Box::<Asset>::try_from(receipt)?;
+    require!(
+        receipt_asset.base.owner == owner.key()
+            && receipt_asset.base.update_authority
+                == UpdateAuthority::Collection(receipt_collection.key())
+            && get_attribute_value(&receipt_asset, RECEIPT_MACHINE_ATTRIBUTE)
+                == Some(gumball_machine.key().to_string())
+            && get_attribute_value(&receipt_asset, RECEIPT_INDEX_ATTRIBUTE)
+                == Some(index.to_string()),
+        GumballError::InvalidReceipt
+    );
+
+    BurnV1CpiBuilder::new(mpl_core_program)
+        .asset(receipt)
+        .collection(Some(receipt_collection))
+        .payer(owner)
+        .authority(Some(owner))
+        .system_program(Some(system_program))
+        .invoke()?;
+
+    // The owner of the receipt becomes the buyer of the item
+    let mut account_data = account_info.data.borrow_mut();
+    let config_line_position =
+        GUMBALL_MACHINE_SIZE + 4 + (index as usize) * gumball_machine.get_config_line_size();
+    let buyer_position = config_line_position + 64;
+    account_data[buyer_position..buyer_position + 32].copy_from_slice(&owner.key().to_bytes());
+    drop(account_data);
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        account_info.key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let amount = match token_standard {
+        TokenStandard::Core => {
+            let collection_info = ctx
+                .accounts
+                .collection
+                .as_ref()
+                .map(|account| account.to_account_info());
+
+            processors::claim_core_asset(
+                gumball_machine,
+                index,
+                authority_pda,
+                owner,
+                owner,
+                seller,
+                asset,
+                collection_info.as_ref(),
+                mpl_core_program,
+                system_program,
+                &auth_seeds,
+            )?;
+
+            1
+        }
+        TokenStandard::NonFungible | TokenStandard::ProgrammableNonFungible => {
+            let metadata_info = &ctx.accounts.metadata.as_ref().unwrap().to_account_info();
+            let metadata = Metadata::try_from(metadata_info)?;
+
+            processors::claim_nft_v2(
+                gumball_machine,
+                index,
+                authority_pda,
+                owner,
+                owner,
+                &ctx.accounts
+                    .owner_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                seller,
+                &ctx.accounts
+                    .token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts
+                    .authority_pda_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                asset,
+                &ctx.accounts.edition.as_ref().unwrap().to_account_info(),
+                &metadata,
+                metadata_info,
+                &ctx.accounts
+                    .token_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts
+                    .associated_token_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts
+                    .token_metadata_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                system_program,
+                &auth_seeds,
+                ctx.accounts.seller_token_record.as_ref(),
+                ctx.accounts.authority_pda_token_record.as_ref(),
+                ctx.accounts.owner_token_record.as_ref(),
+                ctx.accounts.auth_rules.as_ref(),
+                ctx.accounts.instructions.as_ref(),
+                ctx.accounts.auth_rules_program.as_ref(),
+            )?;
+
+            1
+        }
+        TokenStandard::Fungible | TokenStandard::Token2022Fungible => {
+            let authority_pda_token_account = &mut Box::new(try_from!(
+                InterfaceAccount::<TokenAccount>,
+                ctx.accounts.authority_pda_token_account.as_ref().unwrap()
+            )?);
+            let token_program = &ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .unwrap()
+                .to_account_info();
+            let token_2022_program = ctx
+                .accounts
+                .token_2022_program
+                .as_ref()
+                .map(|program| program.to_account_info());
+            let prize_token_program = get_fungible_token_program(
+                token_standard,
+                token_program,
+                token_2022_program.as_ref(),
+            )?;
+
+            processors::claim_tokens(
+                gumball_machine,
+                index,
+                authority,
+                authority_pda,
+                owner,
+                owner,
+                &ctx.accounts
+                    .owner_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                authority_pda_token_account,
+                asset,
+                prize_token_program,
+                &ctx.accounts
+                    .associated_token_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                system_program,
+                &auth_seeds,
+            )?
+        }
+        TokenStandard::Sol => processors::claim_sol_prize(
+            gumball_machine,
+            index,
+            authority_pda,
+            owner,
+            system_program,
+            &auth_seeds,
+        )?,
+        TokenStandard::CompressedNonFungible => {
+            let compressed_nft = compressed_nft
+                .as_ref()
+                .ok_or(GumballError::MissingCompressedNftArgs)?;
+            let merkle_tree = &ctx.accounts.merkle_tree.as_ref().unwrap().to_account_info();
+            require!(
+                mpl_bubblegum::utils::get_asset_id(merkle_tree.key, compressed_nft.nonce)
+                    == asset.key(),
+                GumballError::InvalidMint
+            );
+
+            processors::claim_compressed_nft(
+                gumball_machine,
+                index,
+                authority_pda,
+                owner,
+                merkle_tree,
+                &ctx.accounts.tree_config.as_ref().unwrap().to_account_info(),
+                &ctx.accounts
+                    .bubblegum_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts
+                    .compression_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts.log_wrapper.as_ref().unwrap().to_account_info(),
+                system_program,
+                ctx.remaining_accounts,
+                compressed_nft,
+                &auth_seeds,
+            )?;
+
+            1
+        }
+    };
+
+    emit_cpi!(ClaimItemEvent {
+        mint: asset.key(),
+        authority: gumball_machine.authority.key(),
+        seller: seller.key(),
+        buyer: owner.key(),
+        amount,
+    });
+
+    Ok(())
+}
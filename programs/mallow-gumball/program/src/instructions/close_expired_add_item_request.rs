@@ -0,0 +1,204 @@
+use crate::{
+    constants::{
+        ADD_ITEM_REQUEST_SEED, AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, SELLER_HISTORY_SEED,
+    },
+    processors::{self, CancelAddItemRequestParams},
+    AddItemRequest, AssociatedToken, GumballError, SellerHistory, Token,
+};
+use anchor_lang::prelude::*;
+
+/// Close an expired request to add an item to a gumball machine.
+#[derive(Accounts)]
+pub struct CloseExpiredAddItemRequest<'info> {
+    /// Seller history account.
+    #[account(
+		mut,
+		seeds = [
+			SELLER_HISTORY_SEED.as_bytes(),
+			add_item_request.gumball_machine.as_ref(),
+            seller.key().as_ref(),
+		],
+		bump,
+        has_one = seller,
+	)]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// Add item request account.
+    #[account(
+        mut,
+        close = seller,
+        seeds = [
+            ADD_ITEM_REQUEST_SEED.as_bytes(),
+            asset.key().as_ref()
+        ],
+        bump,
+        has_one = seller @ GumballError::InvalidSeller,
+        has_one = asset @ GumballError::InvalidMint,
+    )]
+    add_item_request: Box<Account<'info, AddItemRequest>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            add_item_request.gumball_machine.as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Payer of the transaction, anyone can close an expired request.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Safe due to add_item_request constraint
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+
+    /// Asset (or mint for NFTs) of the request.
+    /// CHECK: Safe due to add_item_request constraint
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    /// OPTIONAL CORE ASSET ACCOUNTS - only required if closing a Core asset
+
+    /// Core asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL NFT ACCOUNTS - only required if closing a NFT or PNFT
+
+    /// CHECK: Safe due to thaw/revoke
+    #[account(mut)]
+    token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to thaw/revoke
+    #[account(mut)]
+    authority_pda_token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to thaw/revoke
+    edition: Option<UncheckedAccount<'info>>,
+    token_program: Option<Program<'info, Token>>,
+    associated_token_program: Option<Program<'info, AssociatedToken>>,
+    /// CHECK: Safe due to constraint
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL PNFT ACCOUNTS - only required if closing a PNFT
+
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub seller_token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    pub auth_rules: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    pub auth_rules_program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn close_expired_add_item_request(ctx: Context<CloseExpiredAddItemRequest>) -> Result<()> {
+    let payer = &ctx.accounts.payer.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let asset = &ctx.accounts.asset.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let add_item_request = &ctx.accounts.add_item_request;
+
+    let expires_at = AddItemRequest::get_expires_at(&add_item_request.to_account_info())
+        .ok_or(GumballError::AddItemRequestNotExpired)?;
+    require!(
+        Clock::get()?.unix_timestamp >= expires_at,
+        GumballError::AddItemRequestNotExpired
+    );
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        add_item_request.gumball_machine.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let mpl_core_program = ctx
+        .accounts
+        .mpl_core_program
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let token_account = ctx
+        .accounts
+        .token_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let authority_pda_token_account = ctx
+        .accounts
+        .authority_pda_token_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let edition = ctx
+        .accounts
+        .edition
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|program| program.to_account_info());
+    let associated_token_program = ctx
+        .accounts
+        .associated_token_program
+        .as_ref()
+        .map(|program| program.to_account_info());
+    let token_metadata_program = ctx
+        .accounts
+        .token_metadata_program
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let metadata = ctx
+        .accounts
+        .metadata
+        .as_ref()
+        .map(|account| account.to_account_info());
+
+    processors::cancel_add_item_request(
+        add_item_request.token_standard,
+        CancelAddItemRequestParams {
+            payer,
+            seller,
+            asset,
+            authority_pda,
+            auth_seeds: &auth_seeds,
+            system_program,
+            collection: collection.as_ref(),
+            mpl_core_program: mpl_core_program.as_ref(),
+            token_account: token_account.as_ref(),
+            authority_pda_token_account: authority_pda_token_account.as_ref(),
+            edition: edition.as_ref(),
+            token_program: token_program.as_ref(),
+            associated_token_program: associated_token_program.as_ref(),
+            token_metadata_program: token_metadata_program.as_ref(),
+            metadata: metadata.as_ref(),
+            seller_token_record: ctx.accounts.seller_token_record.as_ref(),
+            auth_rules: ctx.accounts.auth_rules.as_ref(),
+            instructions: ctx.accounts.instructions.as_ref(),
+            auth_rules_program: ctx.accounts.auth_rules_program.as_ref(),
+        },
+        &mut ctx.accounts.seller_history,
+    )?;
+
+    Ok(())
+}
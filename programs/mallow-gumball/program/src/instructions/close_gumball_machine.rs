@@ -3,7 +3,7 @@ use crate::{
     GumballError, GumballMachine, Token,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 use utils::{assert_is_ata, is_native_mint};
 
 /// Withdraw the rent SOL from the gumball machine account.
@@ -48,16 +48,41 @@ pub struct CloseGumballMachine<'info> {
 pub fn close_gumball_machine<'info>(
     ctx: Context<'_, '_, '_, 'info, CloseGumballMachine<'info>>,
 ) -> Result<()> {
-    let account_info = ctx.accounts.gumball_machine.to_account_info();
+    let authority_pda_payment_account = ctx
+        .accounts
+        .authority_pda_payment_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+
+    process_close_gumball_machine(
+        &ctx.accounts.gumball_machine,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.authority_pda.to_account_info(),
+        authority_pda_payment_account.as_ref(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.bumps.authority_pda,
+    )
+}
+
+/// Checks that every item has been settled and withdraws the remaining payment tokens of
+/// the authority PDA before the gumball machine account is closed.
+pub(crate) fn process_close_gumball_machine<'info>(
+    gumball_machine: &Account<'info, GumballMachine>,
+    authority: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    authority_pda_payment_account: Option<&AccountInfo<'info>>,
+    token_program: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    authority_pda_bump: u8,
+) -> Result<()> {
+    let account_info = gumball_machine.to_account_info();
     let account_data = account_info.data.borrow();
 
-    if ctx.accounts.gumball_machine.version >= 4 {
+    if gumball_machine.version >= 4 {
         // Make sure user has withdrawn all buy back funds
         require!(
-            ctx.accounts
-                .gumball_machine
-                .get_buy_back_funds_available(&account_data)?
-                == 0,
+            gumball_machine.get_buy_back_funds_available(&account_data)? == 0,
             GumballError::BuyBackFundsNotZero
         );
     }
@@ -70,29 +95,21 @@ pub fn close_gumball_machine<'info>(
 
     // Ensure all items have been settled/claimed
     require!(
-        config_count == ctx.accounts.gumball_machine.items_settled,
+        config_count == gumball_machine.items_settled,
         GumballError::NotAllSettled
     );
 
-    let token_program = &ctx.accounts.token_program.to_account_info();
-    let authority = &ctx.accounts.authority.to_account_info();
-    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
-    let payment_mint = ctx.accounts.gumball_machine.settings.payment_mint;
+    let payment_mint = gumball_machine.settings.payment_mint;
 
     let auth_seeds = [
         AUTHORITY_SEED.as_bytes(),
-        ctx.accounts.gumball_machine.to_account_info().key.as_ref(),
-        &[ctx.bumps.authority_pda],
+        gumball_machine.to_account_info().key.as_ref(),
+        &[authority_pda_bump],
     ];
 
     // Close payment account if using payment token
     if !is_native_mint(payment_mint) {
-        let authority_pda_payment_account = &ctx
-            .accounts
-            .authority_pda_payment_account
-            .as_ref()
-            .unwrap()
-            .to_account_info();
+        let authority_pda_payment_account = authority_pda_payment_account.unwrap();
 
         if !authority_pda_payment_account.data_is_empty() {
             assert_is_ata(
@@ -103,10 +120,10 @@ pub fn close_gumball_machine<'info>(
 
             // Transfer remaining balance to authority if there's any
             let token_account = &mut Box::new(try_from!(
-                Account::<'info, TokenAccount>,
+                InterfaceAccount::<'info, TokenAccount>,
                 authority_pda_payment_account
             )?);
-            let iter = &mut ctx.remaining_accounts.iter();
+            let iter = &mut remaining_accounts.iter();
             let mint = next_account_info(iter)?;
             let to_token_account = next_account_info(iter)?;
             let ata_program = next_account_info(iter)?;
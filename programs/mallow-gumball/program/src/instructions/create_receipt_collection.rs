@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateCollectionV2CpiBuilder;
+
+use crate::{constants::AUTHORITY_SEED, state::GumballMachine, GumballError};
+
+/// Creates the Core collection of the draw receipts, with the authority PDA as its
+/// update authority. Every revealed draw then mints a receipt for each drawn item.
+#[derive(Accounts)]
+pub struct CreateReceiptCollection<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version >= 14 @ GumballError::InvalidVersion,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    /// Pays for the collection account.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Receipt collection account.
+    #[account(mut)]
+    receipt_collection: Signer<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
+
+pub fn create_receipt_collection(
+    ctx: Context<CreateReceiptCollection>,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+
+    {
+        let account_data = account_info.data.borrow();
+        require!(
+            gumball_machine
+                .get_receipt_collection(&account_data)?
+                .is_none(),
+            GumballError::InvalidReceiptCollection
+        );
+
+        // delivered items have nothing left for a receipt to hold
+        require!(
+            !gumball_machine.is_instant_delivery(&account_data)?,
+            GumballError::ReceiptsWithInstantDelivery
+        );
+    }
+
+    CreateCollectionV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .collection(&ctx.accounts.receipt_collection.to_account_info())
+        .update_authority(Some(&ctx.accounts.authority_pda.to_account_info()))
+        .payer(&ctx.accounts.authority.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .name(name)
+        .uri(uri)
+        .invoke()?;
+
+    let mut account_data = account_info.data.borrow_mut();
+    gumball_machine
+        .set_receipt_collection(&mut account_data, ctx.accounts.receipt_collection.key())?;
+
+    Ok(())
+}
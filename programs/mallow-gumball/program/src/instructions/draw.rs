@@ -1,23 +1,36 @@
 use crate::{
-    constants::GUMBALL_MACHINE_SIZE, events::DrawItemEvent, utils::*, GumballError, GumballMachine,
-    GumballState,
+    constants::{DRAW_REVEAL_SLOT_DELAY, GUMBALL_MACHINE_SIZE, PENDING_DRAW_SEED},
+    utils::*,
+    GumballError, GumballMachine, GumballState, PendingDraw,
 };
 use anchor_lang::prelude::*;
 use arrayref::array_ref;
-use solana_program::sysvar;
 
 /// Draws an item from the gumball machine.
-#[event_cpi]
 #[derive(Accounts)]
 pub struct Draw<'info> {
     /// Gumball machine account.
     #[account(
-        mut, 
+        mut,
         has_one = mint_authority,
         constraint = gumball_machine.state == GumballState::SaleLive @ GumballError::InvalidState
     )]
     gumball_machine: Box<Account<'info, GumballMachine>>,
 
+    /// Pending draw account, closed once the draw is revealed.
+    #[account(
+        init,
+        seeds = [
+            PENDING_DRAW_SEED.as_bytes(),
+            gumball_machine.key().as_ref(),
+            &get_next_draw_number(&gumball_machine)?.to_le_bytes()
+        ],
+        bump,
+        space = PendingDraw::SPACE,
+        payer = payer
+    )]
+    pending_draw: Box<Account<'info, PendingDraw>>,
+
     /// Gumball machine mint authority (mint only allowed for the mint_authority).
     mint_authority: Signer<'info>,
 
@@ -33,91 +46,160 @@ pub struct Draw<'info> {
     /// System program.
     system_program: Program<'info, System>,
 
-    /// SlotHashes sysvar cluster data.
+    /// Randomness account of the provider (required when the gumball machine uses a
+    /// randomness provider).
     ///
-    /// CHECK: account constraints checked in account trait
-    #[account(address = sysvar::slot_hashes::id())]
-    recent_slothashes: UncheckedAccount<'info>,
+    /// CHECK: account checked in instruction
+    randomness: Option<UncheckedAccount<'info>>,
 }
 
-/// Accounts to mint an NFT.
-pub(crate) struct DrawAccounts<'info> {
-    pub buyer: AccountInfo<'info>,
-    pub recent_slothashes: AccountInfo<'info>,
+pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
+    draw_many(ctx, 1)
 }
 
-pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
-    let accounts = DrawAccounts {
-        buyer: ctx.accounts.buyer.to_account_info(),
-        recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
-    };
+pub fn draw_many<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>, quantity: u16) -> Result<()> {
+    let (randomness, randomness_request_slot) = get_draw_randomness(
+        &ctx.accounts.gumball_machine,
+        ctx.accounts.randomness.as_ref(),
+    )?;
 
-    let index = process_draw(&mut ctx.accounts.gumball_machine, accounts)?;
+    let (target_slot, draw_number, revenue) =
+        process_commit_draw(&mut ctx.accounts.gumball_machine, quantity)?;
 
-    msg!("Drew item at index: {}", index);
+    let pending_draw = &mut ctx.accounts.pending_draw;
+    pending_draw.gumball_machine = ctx.accounts.gumball_machine.key();
+    pending_draw.buyer = ctx.accounts.buyer.key();
+    pending_draw.payer = ctx.accounts.payer.key();
+    pending_draw.target_slot = target_slot;
+    pending_draw.quantity = quantity;
+    pending_draw.randomness = randomness;
+    pending_draw.rerolled_index = None;
+    pending_draw.draw_number = draw_number;
+    pending_draw.randomness_request_slot = randomness_request_slot;
+    pending_draw.revenue = revenue;
 
-    emit_cpi!(DrawItemEvent {
-        authority: ctx.accounts.gumball_machine.authority.key(),
-        buyer: ctx.accounts.buyer.key(),
-        index,
-    });
+    msg!(
+        "Reserved {} item(s), reveal after slot: {}",
+        quantity,
+        target_slot
+    );
 
     Ok(())
 }
 
-/// Mint a new NFT.
+/// Returns the number of the next draw, used as the seed of its pending draw account so a
+/// buyer can have several draws pending.
+pub(crate) fn get_next_draw_number(gumball_machine: &Account<'_, GumballMachine>) -> Result<u64> {
+    let account_info = gumball_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+    gumball_machine.get_draws_committed(&account_data)
+}
+
+/// Returns the randomness account and request a draw is bound to, so a different value
+/// cannot be picked when revealing (default pubkey when slot hashes are used).
 ///
-/// The index minted depends on the configuration of the gumball machine: it could be
-/// a psuedo-randomly selected one or sequential. In both cases, after minted a
-/// specific index, the gumball machine does not allow to mint the same index again.
-pub(crate) fn process_draw(
+/// The request must not be fulfilled yet, otherwise its value would be known before the
+/// items are reserved.
+pub(crate) fn get_draw_randomness(
+    gumball_machine: &Account<'_, GumballMachine>,
+    randomness: Option<&UncheckedAccount<'_>>,
+) -> Result<(Pubkey, u64)> {
+    let (randomness_config, randomness_request_config) = {
+        let account_info = gumball_machine.to_account_info();
+        let account_data = account_info.data.borrow();
+        (
+            gumball_machine.get_randomness_config(&account_data)?,
+            gumball_machine.get_randomness_request_config(&account_data)?,
+        )
+    };
+
+    if !randomness_config.is_enabled() {
+        return Ok((Pubkey::default(), 0));
+    }
+
+    // the randomness account is only stored from version 16
+    require!(gumball_machine.version >= 16, GumballError::InvalidVersion);
+
+    let randomness = randomness.ok_or(GumballError::MissingRandomnessAccount)?;
+    let request = get_randomness_request(
+        &randomness.to_account_info(),
+        &randomness_config,
+        &randomness_request_config,
+    )?;
+
+    require!(
+        !request.is_fulfilled(),
+        GumballError::RandomnessRequestFulfilled
+    );
+
+    Ok((randomness.key(), request.request_slot))
+}
+
+/// Reserves `quantity` items for a buyer.
+///
+/// The items are counted as redeemed straight away, but they are only selected once
+/// the draw is revealed using the hash of the returned target slot. Returns the target
+/// slot, the number of the draw and the revenue paid for it.
+pub(crate) fn process_commit_draw(
     gumball_machine: &mut Box<Account<'_, GumballMachine>>,
-    accounts: DrawAccounts,
-) -> Result<u32> {
+    quantity: u16,
+) -> Result<(u64, u64, u64)> {
     let account_info = gumball_machine.to_account_info();
-    let account_data = account_info.data.borrow();
+    let mut account_data = account_info.data.borrow_mut();
     let config_count = get_config_count(&account_data)? as u64;
-    drop(account_data);
 
     // are there items to be minted?
     if gumball_machine.items_redeemed >= config_count {
         return err!(GumballError::GumballMachineEmpty);
     }
 
-    // (2) selecting an item to mint
-    let recent_slothashes = &accounts.recent_slothashes;
-    let data = recent_slothashes.data.borrow();
-    let most_recent = array_ref![data, 12, 8];
+    require!(
+        quantity > 0 && quantity as u64 <= config_count - gumball_machine.items_redeemed,
+        GumballError::InvalidQuantity
+    );
 
-    let clock = Clock::get()?;
-    // seed for the random number is a combination of the slot_hash - timestamp
-    let seed = u64::from_le_bytes(*most_recent).saturating_sub(clock.unix_timestamp as u64);
-
-    let index: usize = seed
-        .checked_rem(config_count - gumball_machine.items_redeemed)
-        .ok_or(GumballError::NumericalOverflowError)? as usize;
-
-    let mint_index = set_config_line_buyer(
-        gumball_machine,
-        accounts.buyer.key(),
-        index,
-        gumball_machine.items_redeemed,
+    let items_pending_position = gumball_machine.get_items_pending_position()?;
+    let items_pending = gumball_machine
+        .get_items_pending(&account_data)?
+        .checked_add(quantity)
+        .ok_or(GumballError::NumericalOverflowError)?;
+    account_data[items_pending_position..items_pending_position + 2]
+        .copy_from_slice(&items_pending.to_le_bytes());
+
+    let draw_number = gumball_machine.get_draws_committed(&account_data)?;
+    gumball_machine.set_draws_committed(
+        &mut account_data,
+        draw_number
+            .checked_add(1)
+            .ok_or(GumballError::NumericalOverflowError)?,
     )?;
 
+    let revenue = gumball_machine.take_draw_revenue(&mut account_data)?;
+
+    drop(account_data);
+
     gumball_machine.items_redeemed = gumball_machine
         .items_redeemed
-        .checked_add(1)
+        .checked_add(quantity as u64)
         .ok_or(GumballError::NumericalOverflowError)?;
 
     // Sale has ended if this is the last item to be redeemed
     if gumball_machine.items_redeemed == config_count {
         gumball_machine.state = GumballState::SaleEnded;
+
+        if gumball_machine.version >= 10 {
+            let mut account_data = account_info.data.borrow_mut();
+            gumball_machine.set_sale_ended_at(&mut account_data, Clock::get()?.unix_timestamp)?;
+        }
     }
 
-    // release the data borrow
-    drop(data);
+    let clock = Clock::get()?;
+    let target_slot = clock
+        .slot
+        .checked_add(DRAW_REVEAL_SLOT_DELAY)
+        .ok_or(GumballError::NumericalOverflowError)?;
 
-    Ok(mint_index)
+    Ok((target_slot, draw_number, revenue))
 }
 
 /// Selects and returns the information of a config line.
@@ -145,9 +227,25 @@ pub fn set_config_line_buyer(
     // in a constant time O(1) no matter how big the indices array is
     account_data[mint_byte_position..mint_byte_position + 4]
         .copy_from_slice(&u32::to_le_bytes(last_value));
+    drop(account_data);
+
+    set_buyer(gumball_machine, buyer, mint_index as u32)?;
+
+    Ok(mint_index as u32)
+}
+
+/// Sets the buyer of a config line that has not been drawn yet.
+pub fn set_buyer(
+    gumball_machine: &Account<'_, GumballMachine>,
+    buyer: Pubkey,
+    mint_index: u32,
+) -> Result<()> {
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let mint_index = mint_index as usize;
 
     // (2) retrieve the config line at the mint_index position
-    let buyer_position = GUMBALL_MACHINE_SIZE + 4 + mint_index * gumball_machine.get_config_line_size() 
+    let buyer_position = GUMBALL_MACHINE_SIZE + 4 + mint_index * gumball_machine.get_config_line_size()
         + 32 // mint
         + 32; // seller
 
@@ -160,5 +258,5 @@ pub fn set_config_line_buyer(
     );
     account_data[buyer_position..buyer_position + 32].copy_from_slice(&buyer.to_bytes());
 
-    Ok(mint_index as u32)
+    Ok(())
 }
@@ -19,7 +19,14 @@ pub struct EndSale<'info> {
 }
 
 pub fn end_sale(ctx: Context<EndSale>) -> Result<()> {
-    ctx.accounts.gumball_machine.state = GumballState::SaleEnded;
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    gumball_machine.state = GumballState::SaleEnded;
+
+    if gumball_machine.version >= 10 {
+        let account_info = gumball_machine.to_account_info();
+        let mut account_data = account_info.data.borrow_mut();
+        gumball_machine.set_sale_ended_at(&mut account_data, Clock::get()?.unix_timestamp)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,89 @@
+use crate::{
+    constants::AUTHORITY_SEED, process_close_gumball_machine, GumballError, GumballMachine,
+    GumballState, Token,
+};
+use anchor_lang::prelude::*;
+
+/// Withdraw the rent SOL from an abandoned gumball machine account without the mint authority.
+#[derive(Accounts)]
+pub struct ForceCloseGumballMachine<'info> {
+    /// Gumball Machine acccount.
+    #[account(
+        mut,
+        close = authority,
+        has_one = authority @ GumballError::InvalidAuthority,
+        constraint = gumball_machine.version >= 10 @ GumballError::InvalidVersion,
+        constraint = gumball_machine.state == GumballState::SaleEnded @ GumballError::InvalidState
+    )]
+    gumball_machine: Account<'info, GumballMachine>,
+
+    /// Authority of the gumball machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Payment account for authority pda if using token payment
+    /// CHECK: Safe due to ata check in processor
+    #[account(mut)]
+    authority_pda_payment_account: Option<UncheckedAccount<'info>>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn force_close_gumball_machine<'info>(
+    ctx: Context<'_, '_, '_, 'info, ForceCloseGumballMachine<'info>>,
+) -> Result<()> {
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+
+    assert_force_close_available(gumball_machine, &account_data)?;
+
+    drop(account_data);
+
+    let authority_pda_payment_account = ctx
+        .accounts
+        .authority_pda_payment_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+
+    process_close_gumball_machine(
+        gumball_machine,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.authority_pda.to_account_info(),
+        authority_pda_payment_account.as_ref(),
+        &ctx.accounts.token_program.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.bumps.authority_pda,
+    )
+}
+
+/// Checks that the force close grace period has passed since the sale ended.
+pub fn assert_force_close_available(gumball_machine: &GumballMachine, data: &[u8]) -> Result<()> {
+    let grace_period = gumball_machine.get_force_close_grace_period(data)?;
+    let sale_ended_at = gumball_machine.get_sale_ended_at(data)?;
+    require!(
+        grace_period > 0 && sale_ended_at > 0,
+        GumballError::ForceCloseNotAvailable
+    );
+
+    let closable_at = sale_ended_at
+        .checked_add(grace_period)
+        .ok_or(GumballError::NumericalOverflowError)?;
+    require!(
+        Clock::get()?.unix_timestamp >= closable_at,
+        GumballError::ForceCloseNotAvailable
+    );
+
+    Ok(())
+}
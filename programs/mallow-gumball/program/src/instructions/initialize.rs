@@ -1,7 +1,7 @@
 use crate::{
     constants::{AUTHORITY_SEED, GUMBALL_MACHINE_SIZE},
     state::GumballMachine,
-    BuyBackConfig, FeeConfig, GumballError, GumballSettings, GumballState,
+    BuyBackConfig, FeeConfig, GumballError, GumballSettings, GumballState, TierConfig,
 };
 use anchor_lang::{prelude::*, Discriminator};
 use mpl_token_metadata::MAX_URI_LENGTH;
@@ -105,11 +105,7 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
     account_data[disable_royalties_position] = if disable_royalties { 1 } else { 0 };
 
     let buy_back_config_position = gumball_machine.get_buy_back_config_position()?;
-    let final_buy_back_config = if let Some(buy_back_config) = buy_back_config {
-        buy_back_config
-    } else {
-        BuyBackConfig::default()
-    };
+    let final_buy_back_config = buy_back_config.unwrap_or_default();
     account_data[buy_back_config_position..buy_back_config_position + BuyBackConfig::INIT_SPACE]
         .copy_from_slice(&final_buy_back_config.try_to_vec().unwrap());
 
@@ -118,5 +114,8 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
     account_data[buy_back_funds_available_position..buy_back_funds_available_position + 8]
         .copy_from_slice(&u64::MIN.to_le_bytes());
 
+    // every tier starts with the same draw weight
+    gumball_machine.set_tier_config(&mut account_data, &TierConfig::new())?;
+
     Ok(())
 }
@@ -1,4 +1,7 @@
-use crate::{constants::AUTHORITY_SEED, GumballError, GumballMachine, Token};
+use crate::{
+    constants::AUTHORITY_SEED, processors::assert_sol_prizes_covered, GumballError, GumballMachine,
+    Token,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use utils::{transfer, transfer_from_pda};
@@ -116,6 +119,8 @@ pub fn manage_buy_back_funds<'info>(
             None,
             amount,
         )?;
+
+        assert_sol_prizes_covered(&ctx.accounts.gumball_machine, &account_data, authority_pda)?;
     } else {
         require!(buy_back_config.enabled, GumballError::BuyBackNotEnabled);
 
@@ -0,0 +1,269 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use utils::get_bps_of;
+
+use crate::{
+    constants::{
+        CONFIG_LINE_SIZE, CONFIG_LINE_V2_SIZE, CONFIG_LINE_V3_SIZE, CONFIG_LINE_V4_SIZE,
+        CONFIG_LINE_V5_SIZE, CONFIG_LINE_V6_SIZE, GUMBALL_MACHINE_SIZE,
+    },
+    get_bit_byte_info, get_config_count,
+    state::GumballMachine,
+    GumballError, GumballState, TierConfig, TokenStandard,
+};
+
+/// Migrates a gumball machine to the current version.
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version < GumballMachine::CURRENT_VERSION @ GumballError::InvalidVersion
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    /// Pays for the additional rent.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let version = gumball_machine.version;
+
+    // Version 0 takes the marketplace fee on claim while later versions take it on draw
+    if version == 0 && gumball_machine.total_revenue > 0 {
+        msg!("Cannot migrate a version 0 gumball machine after items have been sold");
+        return err!(GumballError::InvalidState);
+    }
+
+    // pending draws are revealed with the layout and draw numbers of the version they
+    // were made with
+    require!(
+        gumball_machine.get_items_pending(&account_info.data.borrow())? == 0,
+        GumballError::ItemsPendingReveal
+    );
+
+    let old_machine: GumballMachine = (***gumball_machine).clone();
+    let mut new_machine = old_machine.clone();
+    new_machine.version = GumballMachine::CURRENT_VERSION;
+
+    let item_capacity = old_machine.settings.item_capacity;
+    let old_size = GumballMachine::get_size(item_capacity, version);
+    let new_size = GumballMachine::get_size(item_capacity, new_machine.version);
+
+    // the account can only grow by a limited amount on each instruction, so large
+    // machines are resized over multiple calls before their data is migrated
+    if account_info.data_len() < new_size {
+        let size = new_size.min(account_info.data_len() + MAX_PERMITTED_DATA_INCREASE);
+        let rent = Rent::get()?.minimum_balance(size);
+        let lamports = account_info.lamports();
+
+        if rent > lamports {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                rent - lamports,
+            )?;
+        }
+
+        account_info.realloc(size, false)?;
+
+        if size < new_size {
+            msg!(
+                "Account resized to {} of {} bytes, migrate again to continue",
+                size,
+                new_size
+            );
+            return Ok(());
+        }
+    }
+
+    let mut data = account_info.data.borrow_mut();
+    let config_count = get_config_count(&data)? as usize;
+
+    // every section grows or moves to a higher position, so they are moved starting
+    // from the last one
+
+    // (1) tail: version 3+ flags, buy back config and later fields
+    let old_tail_position = old_machine.get_disable_royalties_position()?;
+    let new_tail_position = new_machine.get_disable_royalties_position()?;
+    let old_tail_size = old_size - old_tail_position;
+    data.copy_within(old_tail_position..old_size, new_tail_position);
+    data[new_tail_position + old_tail_size..new_size].fill(0);
+
+    if version < 3 {
+        data[new_tail_position] = 0; // disable_royalties
+    }
+
+    // (2) mint indices, claimed and settled bit masks
+    let mask_size = (item_capacity / 8 + 1) as usize;
+    let indices_size = item_capacity as usize * 4;
+    for (from, to, size) in [
+        (
+            old_machine.get_mint_indices_position()?,
+            new_machine.get_mint_indices_position()?,
+            indices_size,
+        ),
+        (
+            old_machine.get_settled_items_bit_mask_position()?,
+            new_machine.get_settled_items_bit_mask_position()?,
+            mask_size,
+        ),
+        (
+            old_machine.get_claimed_items_bit_mask_position(),
+            new_machine.get_claimed_items_bit_mask_position(),
+            mask_size,
+        ),
+    ] {
+        data.copy_within(from..from + size, to);
+    }
+
+    // (3) config lines
+    let old_line_size = old_machine.get_config_line_size();
+    let new_line_size = new_machine.get_config_line_size();
+    // the price of items drawn before version 17 is unknown, so they get the average
+    let average_price = old_machine
+        .total_revenue
+        .checked_div(old_machine.items_redeemed)
+        .unwrap_or(0);
+    for index in (0..config_count).rev() {
+        let from = GUMBALL_MACHINE_SIZE + 4 + index * old_line_size;
+        let to = GUMBALL_MACHINE_SIZE + 4 + index * new_line_size;
+        data.copy_within(from..from + old_line_size, to);
+
+        if version < 2 {
+            // items added before version 2 are single assets
+            data[to + CONFIG_LINE_SIZE..to + CONFIG_LINE_V2_SIZE]
+                .copy_from_slice(&1u64.to_le_bytes());
+        }
+
+        if version < 6 {
+            // every item starts in the first tier
+            data[to + CONFIG_LINE_V2_SIZE] = 0;
+        }
+
+        if version < 8 {
+            // every item has the same proceeds weight
+            data[to + CONFIG_LINE_V3_SIZE..to + CONFIG_LINE_V3_SIZE + 4]
+                .copy_from_slice(&1u32.to_le_bytes());
+        }
+
+        if version < 14 {
+            // the draw slot of items drawn before version 14 is unknown
+            data[to + CONFIG_LINE_V4_SIZE..to + CONFIG_LINE_V5_SIZE].fill(0);
+        }
+
+        if version < 17 {
+            let buyer_position = to + 32 + 32;
+            let price = if data[buyer_position..buyer_position + 32] == Pubkey::default().to_bytes()
+            {
+                0
+            } else {
+                average_price
+            };
+            data[to + CONFIG_LINE_V5_SIZE..to + CONFIG_LINE_V6_SIZE]
+                .copy_from_slice(&price.to_le_bytes());
+        }
+    }
+
+    // (4) tier config: the mint indices already form the first tier, with the
+    // unassigned items at the start
+    if version < 6 {
+        let mut tier_config = TierConfig::new();
+        tier_config.item_counts[0] = config_count as u32;
+        tier_config.items_available[0] = (config_count as u64)
+            .checked_sub(old_machine.items_redeemed)
+            .ok_or(GumballError::NumericalOverflowError)?
+            as u32;
+        new_machine.set_tier_config(&mut data, &tier_config)?;
+    }
+
+    // (5) proceeds weights: settled items have a weight of 1
+    if version < 8 {
+        new_machine.set_total_weight(&mut data, config_count as u64)?;
+        new_machine.set_total_weight_settled(&mut data, old_machine.items_settled)?;
+    }
+
+    // (6) proceeds settled: before version 5 items could only be settled once the sale
+    // ended, so every settled item received the same share of the final revenue
+    if version < 5 && old_machine.items_settled > 0 {
+        let fees_taken = match old_machine.marketplace_fee_config {
+            Some(fee_config) if version > 0 => {
+                get_bps_of(old_machine.total_revenue, fee_config.fee_bps)?
+            }
+            _ => 0,
+        };
+        let proceeds_per_item = old_machine
+            .total_revenue
+            .checked_sub(fees_taken)
+            .ok_or(GumballError::NumericalOverflowError)?
+            .checked_div(config_count as u64)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        let total_proceeds_settled = proceeds_per_item
+            .checked_mul(old_machine.items_settled)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        new_machine.set_total_proceeds_settled(&mut data, total_proceeds_settled)?;
+    }
+
+    // (7) sale end: the grace period of ended sales starts at the migration
+    if version < 10 && old_machine.state == GumballState::SaleEnded {
+        new_machine.set_sale_ended_at(&mut data, Clock::get()?.unix_timestamp)?;
+    }
+
+    // (8) revenue drawn: the revenue of every past draw was assigned to its items
+    if version < 17 {
+        new_machine.set_total_revenue_drawn(&mut data, old_machine.total_revenue)?;
+    }
+
+    // (9) SOL prizes held: the lamports of every unclaimed SOL prize
+    if version < 18 {
+        let claimed_mask_start = new_machine.get_claimed_items_bit_mask_position();
+        let mut sol_prizes_held: u64 = 0;
+        for index in 0..config_count {
+            let line = GUMBALL_MACHINE_SIZE + 4 + index * new_line_size;
+            let (byte_position, _bit, mask) = get_bit_byte_info(claimed_mask_start, index)?;
+            if data[line + 32 + 32 + 32] != TokenStandard::Sol as u8
+                || data[byte_position] & mask == mask
+            {
+                continue;
+            }
+
+            let amount = u64::from_le_bytes(
+                data[line + CONFIG_LINE_SIZE..line + CONFIG_LINE_V2_SIZE]
+                    .try_into()
+                    .unwrap(),
+            );
+            sol_prizes_held = sol_prizes_held
+                .checked_add(amount)
+                .ok_or(GumballError::NumericalOverflowError)?;
+        }
+        new_machine.set_sol_prizes_held(&mut data, sol_prizes_held)?;
+    }
+
+    drop(data);
+
+    msg!(
+        "Migrated gumball machine from version {} to {}",
+        version,
+        new_machine.version
+    );
+
+    gumball_machine.version = new_machine.version;
+
+    Ok(())
+}
@@ -1,59 +1,125 @@
+pub mod add_compressed_nft;
 pub mod add_core_asset;
+pub mod add_core_assets;
 pub mod add_nft;
+pub mod add_sol_prize;
 pub mod add_tokens;
 pub mod approve_add_item;
+pub mod buy_specific;
 pub mod cancel_add_core_asset_request;
 pub mod cancel_add_nft_request;
+pub mod cancel_pending_draw;
+pub mod claim_compressed_nft;
 pub mod claim_core_asset;
 pub mod claim_nft;
+pub mod claim_sol_prize;
 pub mod claim_tokens;
+pub mod claim_with_receipt;
+pub mod close_expired_add_item_request;
 pub mod close_gumball_machine;
+pub mod create_receipt_collection;
 pub mod draw;
 pub mod end_sale;
+pub mod force_close_gumball_machine;
 pub mod increment_total_revenue;
 pub mod initialize;
 pub mod manage_buy_back_funds;
+pub mod migrate;
+pub mod pause_sale;
+pub mod reclaim_unsold_item;
+pub mod reject_add_item;
+pub mod remove_compressed_nft;
 pub mod remove_core_asset;
+pub mod remove_core_assets_span;
 pub mod remove_nft;
+pub mod remove_nfts_span;
+pub mod remove_sol_prize;
 pub mod remove_tokens;
 pub mod request_add_core_asset;
 pub mod request_add_nft;
+pub mod reroll;
+pub mod resize_capacity;
+pub mod resume_sale;
+pub mod reveal_draw;
 pub mod sell_item;
+pub mod set_add_item_request_expiry;
 pub mod set_authority;
+pub mod set_claim_deadline;
+pub mod set_force_close_grace_period;
+pub mod set_instant_delivery;
+pub mod set_item_tiers;
+pub mod set_item_weights;
 pub mod set_mint_authority;
+pub mod set_randomness_config;
+pub mod set_tier_weights;
+pub mod settle_compressed_nft_sale;
 pub mod settle_core_asset_sale;
 pub mod settle_nft_sale;
 pub mod settle_tokens_sale;
 pub mod settle_tokens_sale_claimed;
 pub mod start_sale;
+pub mod transfer_prize;
 pub mod update_settings;
 
+pub use add_compressed_nft::*;
 pub use add_core_asset::*;
+pub use add_core_assets::*;
 pub use add_nft::*;
+pub use add_sol_prize::*;
 pub use add_tokens::*;
 pub use approve_add_item::*;
+pub use buy_specific::*;
 pub use cancel_add_core_asset_request::*;
 pub use cancel_add_nft_request::*;
+pub use cancel_pending_draw::*;
+pub use claim_compressed_nft::*;
 pub use claim_core_asset::*;
 pub use claim_nft::*;
+pub use claim_sol_prize::*;
 pub use claim_tokens::*;
+pub use claim_with_receipt::*;
+pub use close_expired_add_item_request::*;
 pub use close_gumball_machine::*;
+pub use create_receipt_collection::*;
 pub use draw::*;
 pub use end_sale::*;
+pub use force_close_gumball_machine::*;
 pub use increment_total_revenue::*;
 pub use initialize::*;
 pub use manage_buy_back_funds::*;
+pub use migrate::*;
+pub use pause_sale::*;
+pub use reclaim_unsold_item::*;
+pub use reject_add_item::*;
+pub use remove_compressed_nft::*;
 pub use remove_core_asset::*;
+pub use remove_core_assets_span::*;
 pub use remove_nft::*;
+pub use remove_nfts_span::*;
+pub use remove_sol_prize::*;
 pub use remove_tokens::*;
 pub use request_add_core_asset::*;
 pub use request_add_nft::*;
+pub use reroll::*;
+pub use resize_capacity::*;
+pub use resume_sale::*;
+pub use reveal_draw::*;
 pub use sell_item::*;
+pub use set_add_item_request_expiry::*;
 pub use set_authority::*;
+pub use set_claim_deadline::*;
+pub use set_force_close_grace_period::*;
+pub use set_instant_delivery::*;
+pub use set_item_tiers::*;
+pub use set_item_weights::*;
 pub use set_mint_authority::*;
+pub use set_randomness_config::*;
+pub use set_tier_weights::*;
+pub use settle_compressed_nft_sale::*;
 pub use settle_core_asset_sale::*;
 pub use settle_nft_sale::*;
 pub use settle_tokens_sale::*;
 pub use settle_tokens_sale_claimed::*;
 pub use start_sale::*;
+pub use transfer_prize::*;
 pub use update_settings::*;
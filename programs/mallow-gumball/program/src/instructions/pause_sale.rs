@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, GumballError, GumballState};
+
+/// Pauses a live sale, preventing items from being drawn or added.
+#[derive(Accounts)]
+pub struct PauseSale<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = authority.key() == gumball_machine.authority || authority.key() == gumball_machine.mint_authority @ GumballError::InvalidAuthority,
+        constraint = gumball_machine.state == GumballState::SaleLive @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This can be the mint authority or the authority.
+    authority: Signer<'info>,
+}
+
+pub fn pause_sale(ctx: Context<PauseSale>) -> Result<()> {
+    ctx.accounts.gumball_machine.state = GumballState::Paused;
+
+    Ok(())
+}
@@ -0,0 +1,311 @@
+use crate::{
+    assert_config_line,
+    constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, SELLER_HISTORY_SEED},
+    events::SettleItemSaleEvent,
+    get_fungible_token_program, get_item_token_standard,
+    processors::{self, settle_without_proceeds},
+    state::GumballMachine,
+    try_from, AssociatedToken, ConfigLine, GumballError, GumballState, SellerHistory, Token,
+    TokenStandard,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{token_2022::Token2022, token_interface::TokenAccount};
+use mpl_token_metadata::accounts::Metadata;
+
+/// Returns an unsold item to its seller after the sale has ended.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimUnsoldItem<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority @ GumballError::InvalidAuthority,
+        constraint = gumball_machine.state == GumballState::SaleEnded @ GumballError::InvalidState,
+        // before version 5 the revenue is split over every item, so the share of a reclaimed
+        // item would never be paid out
+        constraint = gumball_machine.version >= 5 @ GumballError::InvalidVersion
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the gumball machine, receives the rent of closed token accounts
+    /// CHECK: Safe due to gumball machine constraint
+    #[account(mut)]
+    authority: UncheckedAccount<'info>,
+
+    /// Seller of the item
+    #[account(mut)]
+    seller: Signer<'info>,
+
+    /// Seller history account.
+    #[account(
+        mut,
+        seeds = [
+            SELLER_HISTORY_SEED.as_bytes(),
+            gumball_machine.key().as_ref(),
+            seller.key().as_ref()
+        ],
+        bump
+    )]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// Asset (mint for NFTs and tokens, native mint for SOL prizes)
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+
+    /// OPTIONAL CORE ASSET ACCOUNTS - only required if reclaiming a Core asset
+
+    /// Core asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL TOKEN ACCOUNTS - only required if reclaiming a NFT, PNFT or tokens
+
+    /// Seller's token account
+    /// CHECK: Safe due to ata check in transfer
+    #[account(mut)]
+    token_account: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to transfer
+    #[account(mut)]
+    authority_pda_token_account: Option<UncheckedAccount<'info>>,
+    token_program: Option<Program<'info, Token>>,
+    associated_token_program: Option<Program<'info, AssociatedToken>>,
+    /// Token-2022 program, only required if the item is a Token-2022 token
+    token_2022_program: Option<Program<'info, Token2022>>,
+
+    /// OPTIONAL NFT ACCOUNTS - only required if reclaiming a NFT or PNFT
+
+    /// CHECK: Safe due to thaw/send
+    #[account(mut)]
+    edition: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    metadata: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to constraint
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL PNFT ACCOUNTS - only required if reclaiming a PNFT
+
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub seller_token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    #[account(mut)]
+    pub authority_pda_token_record: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to token metadata program check
+    pub auth_rules: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    pub auth_rules_program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn reclaim_unsold_item<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimUnsoldItem<'info>>,
+    index: u32,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let authority = &ctx.accounts.authority.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let asset = &ctx.accounts.asset.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let token_standard = get_item_token_standard(gumball_machine, index)?;
+
+    // Only unsold items can be reclaimed
+    assert_config_line(
+        gumball_machine,
+        index,
+        ConfigLine {
+            mint: asset.key(),
+            seller: seller.key(),
+            buyer: Pubkey::default(),
+            token_standard,
+        },
+        false,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let amount = match token_standard {
+        TokenStandard::Core => {
+            let collection_info = ctx
+                .accounts
+                .collection
+                .as_ref()
+                .map(|account| account.to_account_info());
+
+            processors::claim_core_asset(
+                gumball_machine,
+                index,
+                authority_pda,
+                seller,
+                seller,
+                seller,
+                asset,
+                collection_info.as_ref(),
+                &ctx.accounts
+                    .mpl_core_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                system_program,
+                &auth_seeds,
+            )?;
+
+            1
+        }
+        TokenStandard::NonFungible | TokenStandard::ProgrammableNonFungible => {
+            let metadata_info = &ctx.accounts.metadata.as_ref().unwrap().to_account_info();
+            let metadata = Metadata::try_from(metadata_info)?;
+            let token_account = &ctx
+                .accounts
+                .token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info();
+
+            processors::claim_nft_v2(
+                gumball_machine,
+                index,
+                authority_pda,
+                seller,
+                seller,
+                token_account,
+                seller,
+                token_account,
+                &ctx.accounts
+                    .authority_pda_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                asset,
+                &ctx.accounts.edition.as_ref().unwrap().to_account_info(),
+                &metadata,
+                metadata_info,
+                &ctx.accounts
+                    .token_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts
+                    .associated_token_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                &ctx.accounts
+                    .token_metadata_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                system_program,
+                &auth_seeds,
+                ctx.accounts.seller_token_record.as_ref(),
+                ctx.accounts.authority_pda_token_record.as_ref(),
+                ctx.accounts.seller_token_record.as_ref(),
+                ctx.accounts.auth_rules.as_ref(),
+                ctx.accounts.instructions.as_ref(),
+                ctx.accounts.auth_rules_program.as_ref(),
+            )?;
+
+            1
+        }
+        TokenStandard::Fungible | TokenStandard::Token2022Fungible => {
+            let authority_pda_token_account = &mut Box::new(try_from!(
+                InterfaceAccount::<TokenAccount>,
+                ctx.accounts.authority_pda_token_account.as_ref().unwrap()
+            )?);
+            let token_program = &ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .unwrap()
+                .to_account_info();
+            let token_2022_program = ctx
+                .accounts
+                .token_2022_program
+                .as_ref()
+                .map(|program| program.to_account_info());
+            let prize_token_program = get_fungible_token_program(
+                token_standard,
+                token_program,
+                token_2022_program.as_ref(),
+            )?;
+
+            processors::claim_tokens(
+                gumball_machine,
+                index,
+                authority,
+                authority_pda,
+                seller,
+                seller,
+                &ctx.accounts
+                    .token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                authority_pda_token_account,
+                asset,
+                prize_token_program,
+                &ctx.accounts
+                    .associated_token_program
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                system_program,
+                &auth_seeds,
+            )?
+        }
+        TokenStandard::Sol => processors::claim_sol_prize(
+            gumball_machine,
+            index,
+            authority_pda,
+            seller,
+            system_program,
+            &auth_seeds,
+        )?,
+        // Compressed NFTs need their proof to be returned, use settle_compressed_nft_sale
+        _ => return err!(GumballError::InvalidTokenStandard),
+    };
+
+    settle_without_proceeds(gumball_machine, index, seller_history, seller)?;
+
+    emit_cpi!(SettleItemSaleEvent {
+        mint: asset.key(),
+        authority: gumball_machine.authority.key(),
+        seller: seller.key(),
+        buyer: Pubkey::default(),
+        total_proceeds: 0,
+        payment_mint: gumball_machine.settings.payment_mint,
+        fee_config: gumball_machine.marketplace_fee_config,
+        curator_fee_bps: gumball_machine.settings.curator_fee_bps,
+        amount
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use crate::{
+    constants::{
+        AUTHORITY_SEED, SELLER_HISTORY_SEED, SPL_ACCOUNT_COMPRESSION_PROGRAM, SPL_NOOP_PROGRAM,
+    },
+    processors,
+    state::GumballMachine,
+    transfer_compressed_nft, GumballError, SellerHistory, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+
+use super::CompressedNftArgs;
+
+/// Remove compressed nft from a gumball machine.
+#[derive(Accounts)]
+pub struct RemoveCompressedNft<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Account<'info, GumballMachine>,
+
+    /// Seller history account.
+    #[account(
+		mut,
+		seeds = [
+			SELLER_HISTORY_SEED.as_bytes(),
+			gumball_machine.key().as_ref(),
+            seller.key().as_ref(),
+		],
+		bump,
+        has_one = gumball_machine,
+        has_one = seller,
+	)]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority allowed to remove the nft (must be the gumball machine auth or the seller of the nft)
+    authority: Signer<'info>,
+
+    /// CHECK: Safe due to item seller check
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_NOOP_PROGRAM)]
+    log_wrapper: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn remove_compressed_nft<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveCompressedNft<'info>>,
+    index: u32,
+    compressed_nft: CompressedNftArgs,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let merkle_tree = &ctx.accounts.merkle_tree.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+
+    processors::remove_multiple_items_span(
+        gumball_machine,
+        authority.key(),
+        get_asset_id(merkle_tree.key, compressed_nft.nonce),
+        seller.key(),
+        &[TokenStandard::CompressedNonFungible],
+        1,
+        index,
+        index,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        ctx.accounts.gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    // Return the leaf to the seller
+    transfer_compressed_nft(
+        authority_pda,
+        seller,
+        merkle_tree,
+        &ctx.accounts.tree_config.to_account_info(),
+        &ctx.accounts.bubblegum_program.to_account_info(),
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.remaining_accounts,
+        &compressed_nft,
+        Some(&auth_seeds),
+    )?;
+
+    seller_history.item_count -= 1;
+
+    if seller_history.item_count == 0 {
+        seller_history.close(seller.to_account_info())?;
+    }
+
+    Ok(())
+}
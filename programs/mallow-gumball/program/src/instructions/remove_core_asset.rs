@@ -2,7 +2,7 @@ use crate::{
     constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
     processors,
     state::GumballMachine,
-    thaw_and_revoke_core_asset, GumballError, SellerHistory,
+    thaw_and_revoke_core_asset, GumballError, SellerHistory, TokenStandard,
 };
 use anchor_lang::prelude::*;
 
@@ -79,6 +79,7 @@ pub fn remove_core_asset(ctx: Context<RemoveCoreAsset>, index: u32) -> Result<()
         authority.key(),
         asset_info.key(),
         seller.key(),
+        &[TokenStandard::Core],
         1,
         index,
         index,
@@ -0,0 +1,109 @@
+use crate::{
+    constants::AUTHORITY_SEED, decrement_seller_histories, processors, state::GumballMachine,
+    thaw_and_revoke_core_asset, GumballError, TokenStandard,
+};
+use anchor_lang::prelude::*;
+
+/// Accounts passed through the remaining accounts for each removed asset.
+const ACCOUNTS_PER_ASSET: usize = 4;
+
+/// Remove a span of core assets from a gumball machine.
+#[derive(Accounts)]
+pub struct RemoveCoreAssetsSpan<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Account<'info, GumballMachine>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority allowed to remove the assets (must be the gumball machine auth or the seller of every asset)
+    authority: Signer<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn remove_core_assets_span<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveCoreAssetsSpan<'info>>,
+    start_index: u32,
+    end_index: u32,
+) -> Result<()> {
+    require!(start_index <= end_index, GumballError::InvalidInputLength);
+    // Each asset needs its seller, seller history, asset and collection accounts
+    require!(
+        ctx.remaining_accounts.len()
+            == ((end_index - start_index) as usize + 1) * ACCOUNTS_PER_ASSET,
+        GumballError::InvalidInputLength
+    );
+    let item_accounts: Vec<&'info [AccountInfo<'info>]> =
+        ctx.remaining_accounts.chunks(ACCOUNTS_PER_ASSET).collect();
+
+    let authority = &ctx.accounts.authority.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+
+    let items: Vec<(Pubkey, Pubkey)> = item_accounts
+        .iter()
+        .map(|accounts| (accounts[2].key(), accounts[0].key()))
+        .collect();
+
+    processors::remove_unique_items_span(
+        gumball_machine,
+        authority.key(),
+        &items,
+        &[TokenStandard::Core],
+        start_index,
+        end_index,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        ctx.accounts.gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    for accounts in item_accounts.iter() {
+        // The MPL Core program is passed in place of a missing collection
+        let collection = if accounts[3].key() == mpl_core::ID {
+            None
+        } else {
+            Some(&accounts[3])
+        };
+
+        thaw_and_revoke_core_asset(
+            authority,
+            &accounts[0],
+            &accounts[2],
+            collection,
+            authority_pda,
+            &auth_seeds,
+            mpl_core_program,
+            system_program,
+        )?;
+    }
+
+    let removed_items: Vec<(&'info AccountInfo<'info>, &'info AccountInfo<'info>)> = item_accounts
+        .iter()
+        .map(|&accounts| (&accounts[1], &accounts[0]))
+        .collect();
+    decrement_seller_histories(ctx.accounts.gumball_machine.key(), &removed_items)?;
+
+    Ok(())
+}
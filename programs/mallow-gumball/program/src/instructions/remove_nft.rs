@@ -2,7 +2,7 @@ use crate::{
     constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, SELLER_HISTORY_SEED},
     processors,
     state::GumballMachine,
-    thaw_and_revoke_nft_v2, AssociatedToken, GumballError, SellerHistory, Token,
+    thaw_and_revoke_nft_v2, AssociatedToken, GumballError, SellerHistory, Token, TokenStandard,
 };
 use anchor_lang::prelude::*;
 
@@ -107,6 +107,10 @@ pub fn remove_nft(ctx: Context<RemoveNft>, index: u32) -> Result<()> {
         authority.key(),
         mint.key(),
         seller.key(),
+        &[
+            TokenStandard::NonFungible,
+            TokenStandard::ProgrammableNonFungible,
+        ],
         1,
         index,
         index,
@@ -0,0 +1,134 @@
+use crate::{
+    constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM},
+    decrement_seller_histories, processors,
+    state::GumballMachine,
+    thaw_and_revoke_nft_v2, AssociatedToken, GumballError, Token, TokenStandard,
+};
+use anchor_lang::prelude::*;
+
+/// Accounts passed through the remaining accounts for each removed nft.
+const ACCOUNTS_PER_NFT: usize = 8;
+
+/// Remove a span of nfts from a gumball machine.
+#[derive(Accounts)]
+pub struct RemoveNftsSpan<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Account<'info, GumballMachine>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes(), gumball_machine.to_account_info().key.as_ref()],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority allowed to remove the nfts (must be the gumball machine auth or the seller of every nft)
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Safe due to constraint
+    #[account(address = mpl_token_metadata::ID)]
+    token_metadata_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+
+    /// OPTIONAL PNFT ACCOUNTS
+    /// CHECK: Safe due to token metadata program check
+    pub auth_rules: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address check
+    #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
+    pub auth_rules_program: Option<UncheckedAccount<'info>>,
+}
+
+pub fn remove_nfts_span<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveNftsSpan<'info>>,
+    start_index: u32,
+    end_index: u32,
+) -> Result<()> {
+    require!(start_index <= end_index, GumballError::InvalidInputLength);
+    // Each nft needs its seller, seller history, mint, seller token account, authority pda
+    // token account, edition, metadata and seller token record accounts
+    require!(
+        ctx.remaining_accounts.len() == ((end_index - start_index) as usize + 1) * ACCOUNTS_PER_NFT,
+        GumballError::InvalidInputLength
+    );
+    let item_accounts: Vec<&'info [AccountInfo<'info>]> =
+        ctx.remaining_accounts.chunks(ACCOUNTS_PER_NFT).collect();
+
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let token_program = &ctx.accounts.token_program.to_account_info();
+    let associated_token_program = &ctx.accounts.associated_token_program.to_account_info();
+    let token_metadata_program = &ctx.accounts.token_metadata_program.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let authority = &ctx.accounts.authority.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+
+    let items: Vec<(Pubkey, Pubkey)> = item_accounts
+        .iter()
+        .map(|accounts| (accounts[2].key(), accounts[0].key()))
+        .collect();
+
+    processors::remove_unique_items_span(
+        gumball_machine,
+        authority.key(),
+        &items,
+        &[
+            TokenStandard::NonFungible,
+            TokenStandard::ProgrammableNonFungible,
+        ],
+        start_index,
+        end_index,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        ctx.accounts.gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    for &accounts in item_accounts.iter() {
+        // Only used for pNFTs
+        let seller_token_record = UncheckedAccount::try_from(&accounts[7]);
+
+        thaw_and_revoke_nft_v2(
+            authority,
+            &accounts[0],
+            &accounts[2],
+            &accounts[3],
+            &accounts[5],
+            authority_pda,
+            &auth_seeds,
+            token_metadata_program,
+            token_program,
+            Some(&accounts[6]),
+            Some(&seller_token_record),
+            ctx.accounts.auth_rules.as_ref(),
+            system_program,
+            ctx.accounts.instructions.as_ref(),
+            ctx.accounts.auth_rules_program.as_ref(),
+            associated_token_program,
+            &accounts[4],
+        )?;
+    }
+
+    let removed_items: Vec<(&'info AccountInfo<'info>, &'info AccountInfo<'info>)> = item_accounts
+        .iter()
+        .map(|&accounts| (&accounts[1], &accounts[0]))
+        .collect();
+    decrement_seller_histories(ctx.accounts.gumball_machine.key(), &removed_items)?;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::{
+    constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
+    processors,
+    state::GumballMachine,
+    GumballError, SellerHistory, TokenStandard,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use utils::transfer_sol;
+
+/// Remove native SOL prizes from a gumball machine.
+#[derive(Accounts)]
+pub struct RemoveSolPrize<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Seller history account.
+    #[account(
+		mut,
+		seeds = [
+			SELLER_HISTORY_SEED.as_bytes(),
+			gumball_machine.key().as_ref(),
+            seller.key().as_ref(),
+		],
+		bump,
+        has_one = gumball_machine,
+        has_one = seller,
+	)]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [AUTHORITY_SEED.as_bytes(), gumball_machine.key().as_ref()],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority allowed to remove the prizes (must be the gumball machine auth or the seller of the prizes)
+    authority: Signer<'info>,
+
+    /// CHECK: Safe due to item seller check
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn remove_sol_prize(
+    ctx: Context<RemoveSolPrize>,
+    amount: u64,
+    start_index: u32,
+    end_index: u32,
+) -> Result<()> {
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let authority = &ctx.accounts.authority.to_account_info();
+    let seller = &ctx.accounts.seller.to_account_info();
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+
+    processors::remove_multiple_items_span(
+        gumball_machine,
+        authority.key(),
+        native_mint::ID,
+        seller.key(),
+        &[TokenStandard::Sol],
+        amount,
+        start_index,
+        end_index,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        ctx.accounts.gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    // computed in u64 since the span can cover every u32 index
+    let prize_count = (end_index - start_index) as u64 + 1;
+    let total_amount = amount
+        .checked_mul(prize_count)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    let account_info = ctx.accounts.gumball_machine.to_account_info();
+    processors::release_sol_prize(
+        &ctx.accounts.gumball_machine,
+        &mut account_info.data.borrow_mut(),
+        total_amount,
+    )?;
+    transfer_sol(
+        authority_pda,
+        seller,
+        system_program,
+        Some(&auth_seeds),
+        total_amount,
+    )?;
+
+    seller_history.item_count = seller_history
+        .item_count
+        .checked_sub(prize_count)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    if seller_history.item_count == 0 {
+        seller_history.close(seller.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -2,10 +2,10 @@ use crate::{
     constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
     processors,
     state::GumballMachine,
-    transfer_and_close_if_empty, AssociatedToken, GumballError, SellerHistory, Token,
+    transfer_and_close_if_empty, AssociatedToken, GumballError, SellerHistory, TokenStandard,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 /// Add nft to a gumball machine.
 #[derive(Accounts)]
@@ -47,28 +47,33 @@ pub struct RemoveTokens<'info> {
     #[account(mut)]
     seller: UncheckedAccount<'info>,
 
-    mint: Box<Account<'info, Mint>>,
+    /// Mint of the tokens (SPL Token or Token-2022)
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
         constraint = token_account.mint == mint.key(),
         constraint = token_account.owner == seller.key(),
     )]
-    token_account: Box<Account<'info, TokenAccount>>,
+    token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
         constraint = authority_pda_token_account.mint == mint.key(),
         constraint = authority_pda_token_account.owner == authority_pda.key(),
     )]
-    authority_pda_token_account: Box<Account<'info, TokenAccount>>,
+    authority_pda_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    token_program: Program<'info, Token>,
+    token_program: Interface<'info, TokenInterface>,
     associated_token_program: Program<'info, AssociatedToken>,
     system_program: Program<'info, System>,
     rent: Sysvar<'info, Rent>,
 }
 
+/// SOL prize lines also record the native mint, so they are removed with remove_sol_prize.
+const FUNGIBLE_TOKEN_STANDARDS: &[TokenStandard] =
+    &[TokenStandard::Fungible, TokenStandard::Token2022Fungible];
+
 // DEPRECATED: Use remove_tokens_span instead
 pub fn remove_tokens(ctx: Context<RemoveTokens>, indices: Vec<u8>, amount: u64) -> Result<()> {
     let system_program = &ctx.accounts.system_program.to_account_info();
@@ -88,6 +93,7 @@ pub fn remove_tokens(ctx: Context<RemoveTokens>, indices: Vec<u8>, amount: u64)
         authority.key(),
         mint.key(),
         seller.key(),
+        FUNGIBLE_TOKEN_STANDARDS,
         &indices,
         amount,
     )?;
@@ -150,6 +156,7 @@ pub fn remove_tokens_span(
         authority.key(),
         mint.key(),
         seller.key(),
+        FUNGIBLE_TOKEN_STANDARDS,
         amount,
         start_index,
         end_index,
@@ -125,6 +125,10 @@ pub fn request_add_nft(ctx: Context<RequestAddNft>) -> Result<()> {
         token_standard_from_mpl_token_standard(&metadata)?,
     )?;
 
+    let expiry = gumball_machine
+        .get_add_item_request_expiry(&gumball_machine.to_account_info().data.borrow())?;
+    AddItemRequest::set_timestamps(&add_item_request.to_account_info(), expiry)?;
+
     seller_history.gumball_machine = gumball_machine.key();
     seller_history.seller = seller.key();
 
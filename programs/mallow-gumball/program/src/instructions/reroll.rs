@@ -0,0 +1,122 @@
+use crate::{
+    constants::{GUMBALL_MACHINE_SIZE, PENDING_DRAW_SEED},
+    get_config_count,
+    instructions::{get_draw_randomness, get_next_draw_number, process_commit_draw},
+    processors::{is_item_claimed, return_drawn_item},
+    GumballError, GumballMachine, GumballState, PendingDraw,
+};
+use anchor_lang::prelude::*;
+
+/// Gives back an unclaimed drawn item and draws a new one in its place.
+#[derive(Accounts)]
+pub struct Reroll<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = mint_authority,
+        constraint = gumball_machine.state == GumballState::SaleLive @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Pending draw account, closed once the draw is revealed.
+    #[account(
+        init,
+        seeds = [
+            PENDING_DRAW_SEED.as_bytes(),
+            gumball_machine.key().as_ref(),
+            &get_next_draw_number(&gumball_machine)?.to_le_bytes()
+        ],
+        bump,
+        space = PendingDraw::SPACE,
+        payer = payer
+    )]
+    pending_draw: Box<Account<'info, PendingDraw>>,
+
+    /// Gumball machine mint authority (mint only allowed for the mint_authority).
+    mint_authority: Signer<'info>,
+
+    /// Payer for the transaction and account allocation (rent).
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Buyer of the item being rerolled.
+    buyer: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+
+    /// Randomness account of the provider (required when the gumball machine uses a
+    /// randomness provider).
+    ///
+    /// CHECK: account checked in instruction
+    randomness: Option<UncheckedAccount<'info>>,
+}
+
+pub fn reroll<'info>(ctx: Context<'_, '_, '_, 'info, Reroll<'info>>, index: u32) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let buyer = ctx.accounts.buyer.key();
+
+    require!(
+        !is_item_claimed(gumball_machine, index)?,
+        GumballError::ItemAlreadyClaimed
+    );
+
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let count = get_config_count(&account_data)?;
+
+    // a returned item would change the items pending draws are selected from
+    require!(
+        gumball_machine.get_items_pending(&account_data)? == 0,
+        GumballError::ItemsPendingReveal
+    );
+
+    if index >= count as u32 {
+        return err!(GumballError::IndexGreaterThanLength);
+    }
+
+    let config_line_position =
+        GUMBALL_MACHINE_SIZE + 4 + (index as usize) * gumball_machine.get_config_line_size();
+    let buyer_position = config_line_position + 32 + 32;
+    let current_buyer =
+        Pubkey::try_from(&account_data[buyer_position..buyer_position + 32]).unwrap();
+    require!(current_buyer == buyer, GumballError::InvalidBuyer);
+
+    // The item goes back to the pool, the new draw is covered by the original payment
+    let price = gumball_machine.get_item_price(&account_data, index)?;
+    return_drawn_item(
+        gumball_machine,
+        &mut account_data,
+        index,
+        config_line_position,
+    )?;
+    drop(account_data);
+
+    gumball_machine.items_redeemed -= 1;
+
+    let (randomness, randomness_request_slot) =
+        get_draw_randomness(gumball_machine, ctx.accounts.randomness.as_ref())?;
+    let (target_slot, draw_number, reroll_fee) = process_commit_draw(gumball_machine, 1)?;
+
+    let pending_draw = &mut ctx.accounts.pending_draw;
+    pending_draw.gumball_machine = gumball_machine.key();
+    pending_draw.buyer = buyer;
+    pending_draw.payer = ctx.accounts.payer.key();
+    pending_draw.target_slot = target_slot;
+    pending_draw.quantity = 1;
+    pending_draw.randomness = randomness;
+    pending_draw.rerolled_index = Some(index);
+    pending_draw.draw_number = draw_number;
+    pending_draw.randomness_request_slot = randomness_request_slot;
+    pending_draw.revenue = price
+        .checked_add(reroll_fee)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    msg!(
+        "Rerolled item at index: {}, reveal after slot: {}",
+        index,
+        target_slot
+    );
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+use crate::{get_config_count, state::GumballMachine, GumballError};
+
+/// Resizes the item capacity of a gumball machine.
+#[derive(Accounts)]
+pub struct ResizeCapacity<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    /// Pays for (or receives) the rent difference.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// System program.
+    system_program: Program<'info, System>,
+}
+
+pub fn resize_capacity(ctx: Context<ResizeCapacity>, item_capacity: u64) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let config_count = get_config_count(&account_info.data.borrow())? as u64;
+
+    if item_capacity == gumball_machine.settings.item_capacity || item_capacity < config_count {
+        msg!("Item capacity must differ from the current one and fit every item loaded");
+        return err!(GumballError::InvalidSettingUpdate);
+    }
+
+    let old_machine: GumballMachine = (***gumball_machine).clone();
+    let mut new_machine = old_machine.clone();
+    new_machine.settings.item_capacity = item_capacity;
+
+    let old_capacity = old_machine.settings.item_capacity;
+    let old_size = GumballMachine::get_size(old_capacity, old_machine.version);
+    let new_size = GumballMachine::get_size(item_capacity, old_machine.version);
+    let is_growing = item_capacity > old_capacity;
+
+    // sections of the hidden data that follow the config lines, as
+    // (old position, new position, bytes to keep, new section size)
+    let old_mask_size = (old_capacity / 8 + 1) as usize;
+    let new_mask_size = (item_capacity / 8 + 1) as usize;
+    let mask_size = old_mask_size.min(new_mask_size);
+    let old_tail_position = old_machine.get_disable_royalties_position()?;
+    let tail_size = old_size - old_tail_position;
+
+    let sections = [
+        (
+            old_machine.get_claimed_items_bit_mask_position(),
+            new_machine.get_claimed_items_bit_mask_position(),
+            mask_size,
+            new_mask_size,
+        ),
+        (
+            old_machine.get_settled_items_bit_mask_position()?,
+            new_machine.get_settled_items_bit_mask_position()?,
+            mask_size,
+            new_mask_size,
+        ),
+        (
+            old_machine.get_mint_indices_position()?,
+            new_machine.get_mint_indices_position()?,
+            config_count as usize * 4,
+            item_capacity as usize * 4,
+        ),
+        (
+            old_tail_position,
+            new_machine.get_disable_royalties_position()?,
+            tail_size,
+            tail_size,
+        ),
+    ];
+
+    let move_section = |data: &mut [u8], (from, to, length, size): (usize, usize, usize, usize)| {
+        data.copy_within(from..from + length, to);
+        data[to + length..to + size].fill(0);
+    };
+
+    if is_growing {
+        // the account can only grow by a limited amount on each instruction, so large
+        // increases are resized over multiple calls before the data is moved
+        if account_info.data_len() < new_size {
+            let size = new_size.min(account_info.data_len() + MAX_PERMITTED_DATA_INCREASE);
+            let rent = Rent::get()?.minimum_balance(size);
+            let lamports = account_info.lamports();
+
+            if rent > lamports {
+                transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.authority.to_account_info(),
+                            to: account_info.clone(),
+                        },
+                    ),
+                    rent - lamports,
+                )?;
+            }
+
+            account_info.realloc(size, false)?;
+
+            if size < new_size {
+                msg!(
+                    "Account resized to {} of {} bytes, resize again to continue",
+                    size,
+                    new_size
+                );
+                return Ok(());
+            }
+        } else {
+            // a previous step may have grown the account for a larger capacity
+            account_info.realloc(new_size, false)?;
+        }
+
+        // sections move to higher positions, so the last one is moved first
+        let mut data = account_info.data.borrow_mut();
+        for section in sections.into_iter().rev() {
+            move_section(&mut data, section);
+        }
+
+        // clears the space of the new config lines
+        data[sections[0].0..sections[0].1].fill(0);
+    } else {
+        // sections move to lower positions, so the first one is moved first
+        let mut data = account_info.data.borrow_mut();
+        for section in sections {
+            move_section(&mut data, section);
+        }
+        drop(data);
+
+        account_info.realloc(new_size, false)?;
+
+        let rent = Rent::get()?.minimum_balance(new_size);
+        let excess_lamports = account_info.lamports().saturating_sub(rent);
+        let authority_info = ctx.accounts.authority.to_account_info();
+
+        **account_info.try_borrow_mut_lamports()? -= excess_lamports;
+        **authority_info.try_borrow_mut_lamports()? += excess_lamports;
+    }
+
+    gumball_machine.settings.item_capacity = item_capacity;
+
+    Ok(())
+}
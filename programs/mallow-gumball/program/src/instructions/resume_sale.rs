@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, GumballError, GumballState};
+
+/// Resumes a paused sale.
+#[derive(Accounts)]
+pub struct ResumeSale<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = authority.key() == gumball_machine.authority || authority.key() == gumball_machine.mint_authority @ GumballError::InvalidAuthority,
+        constraint = gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This can be the mint authority or the authority.
+    authority: Signer<'info>,
+}
+
+pub fn resume_sale(ctx: Context<ResumeSale>) -> Result<()> {
+    ctx.accounts.gumball_machine.state = GumballState::SaleLive;
+
+    Ok(())
+}
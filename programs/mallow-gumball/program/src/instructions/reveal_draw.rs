@@ -0,0 +1,738 @@
+use crate::{
+    assert_config_line,
+    constants::{
+        AUTHORITY_SEED, DRAW_REVEAL_SLOT_DELAY, GUMBALL_MACHINE_SIZE, MPL_TOKEN_AUTH_RULES_PROGRAM,
+        PENDING_DRAW_SEED, RECEIPT_INDEX_ATTRIBUTE, RECEIPT_MACHINE_ATTRIBUTE,
+        RECEIPT_SLOT_ATTRIBUTE,
+    },
+    events::{ClaimItemEvent, DrawItemEvent, MintReceiptEvent, RerollItemEvent},
+    instructions::{set_buyer, set_config_line_buyer},
+    processors::{self, take_weighted_item},
+    try_from,
+    utils::*,
+    AssociatedToken, ConfigLine, GumballError, GumballMachine, GumballState, PendingDraw, Token,
+    TokenStandard,
+};
+use anchor_lang::{prelude::*, AccountsClose};
+use anchor_spl::{token_2022::Token2022, token_interface::TokenAccount};
+use mpl_core::{
+    instructions::CreateV2CpiBuilder,
+    types::{Attribute, Attributes, Plugin, PluginAuthorityPair},
+    Collection,
+};
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::{keccak, system_program, sysvar};
+use utils::random_below;
+
+/// Reveals a pending draw, assigning the reserved items to the buyer.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevealDraw<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Pending draw account.
+    #[account(
+        mut,
+        has_one = gumball_machine,
+        has_one = payer,
+        seeds = [
+            PENDING_DRAW_SEED.as_bytes(),
+            gumball_machine.key().as_ref(),
+            &pending_draw.draw_number.to_le_bytes()
+        ],
+        bump
+    )]
+    pending_draw: Box<Account<'info, PendingDraw>>,
+
+    /// Payer of the pending draw account (receiver of the closed account rent).
+    ///
+    /// CHECK: Safe due to pending draw constraint
+    #[account(mut)]
+    payer: UncheckedAccount<'info>,
+
+    /// SlotHashes sysvar cluster data.
+    ///
+    /// CHECK: account constraints checked in account trait
+    #[account(address = sysvar::slot_hashes::id())]
+    recent_slothashes: UncheckedAccount<'info>,
+
+    /// Randomness account of the provider (required when the draw uses a randomness
+    /// provider).
+    ///
+    /// CHECK: account checked in instruction
+    randomness: Option<UncheckedAccount<'info>>,
+}
+
+pub fn reveal_draw<'info>(ctx: Context<'_, '_, 'info, 'info, RevealDraw<'info>>) -> Result<()> {
+    let pending_draw = &mut ctx.accounts.pending_draw;
+    let clock = Clock::get()?;
+
+    // draws are revealed in the order they were made, otherwise the outcome of a draw
+    // would depend on which of the pending draws is revealed first
+    let draws_revealed = {
+        let account_info = ctx.accounts.gumball_machine.to_account_info();
+        let account_data = account_info.data.borrow();
+        ctx.accounts
+            .gumball_machine
+            .get_draws_revealed(&account_data)?
+    };
+    require!(
+        pending_draw.draw_number == draws_revealed,
+        GumballError::RevealOutOfOrder
+    );
+
+    require!(
+        clock.slot > pending_draw.target_slot,
+        GumballError::RevealNotReady
+    );
+
+    let random_value = if pending_draw.randomness != Pubkey::default() {
+        let randomness = ctx
+            .accounts
+            .randomness
+            .as_ref()
+            .ok_or(GumballError::MissingRandomnessAccount)?;
+
+        if !cmp_pubkeys(&randomness.key(), &pending_draw.randomness) {
+            return err!(GumballError::InvalidRandomnessAccount);
+        }
+
+        let account_info = ctx.accounts.gumball_machine.to_account_info();
+        let account_data = account_info.data.borrow();
+        let request = get_randomness_request(
+            &randomness.to_account_info(),
+            &ctx.accounts
+                .gumball_machine
+                .get_randomness_config(&account_data)?,
+            &ctx.accounts
+                .gumball_machine
+                .get_randomness_request_config(&account_data)?,
+        )?;
+
+        // only the value of the request the draw is bound to can be used, a new request
+        // would give a second chance at the outcome
+        require!(
+            request.request_slot == pending_draw.randomness_request_slot,
+            GumballError::RandomnessRequestReplaced
+        );
+        require!(request.is_fulfilled(), GumballError::RevealNotReady);
+
+        request.value
+    } else {
+        match get_slot_hash(
+            &ctx.accounts.recent_slothashes.to_account_info(),
+            pending_draw.target_slot,
+        )? {
+            Some(slot_hash) => slot_hash,
+            None => {
+                // the target slot is no longer available, so a new one is picked: the
+                // outcome of the expired slot cannot be kept and the new slot hash is not
+                // known yet
+                pending_draw.target_slot = clock
+                    .slot
+                    .checked_add(DRAW_REVEAL_SLOT_DELAY)
+                    .ok_or(GumballError::NumericalOverflowError)?;
+
+                msg!(
+                    "Target slot expired, reveal after slot: {}",
+                    pending_draw.target_slot
+                );
+
+                return Ok(());
+            }
+        }
+    };
+
+    // every draw gets its own seed, even when several draws share the same target slot
+    let seed = keccak::hashv(&[&random_value, pending_draw.key().as_ref()]).0;
+
+    let rerolled_index = pending_draw.rerolled_index;
+
+    let drawn_items = process_reveal_draw(
+        &mut ctx.accounts.gumball_machine,
+        pending_draw.buyer,
+        pending_draw.quantity,
+        pending_draw.target_slot,
+        pending_draw.revenue,
+        &seed,
+    )?;
+
+    let (instant_delivery, receipt_collection) = {
+        let account_info = ctx.accounts.gumball_machine.to_account_info();
+        let mut account_data = account_info.data.borrow_mut();
+        ctx.accounts.gumball_machine.set_draws_revealed(
+            &mut account_data,
+            draws_revealed
+                .checked_add(1)
+                .ok_or(GumballError::NumericalOverflowError)?,
+        )?;
+        (
+            ctx.accounts
+                .gumball_machine
+                .is_instant_delivery(&account_data)?,
+            ctx.accounts
+                .gumball_machine
+                .get_receipt_collection(&account_data)?,
+        )
+    };
+
+    // the items are only known once the draw is revealed, so they are delivered (or their
+    // receipts minted) here and the accounts are required
+    let indices: Vec<u32> = drawn_items.iter().map(|item| item.index).collect();
+    let mut claimed_items = vec![];
+    let mut minted_receipts = vec![];
+
+    if instant_delivery {
+        claimed_items = deliver_drawn_items(
+            &mut ctx.accounts.gumball_machine,
+            ctx.accounts.pending_draw.buyer,
+            &indices,
+            ctx.remaining_accounts,
+        )?;
+    } else if let Some(receipt_collection) = receipt_collection {
+        minted_receipts = mint_receipts(
+            &mut ctx.accounts.gumball_machine,
+            ctx.accounts.pending_draw.buyer,
+            receipt_collection,
+            &indices,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    for drawn_item in drawn_items {
+        msg!("Drew item at index: {}", drawn_item.index);
+
+        emit_cpi!(DrawItemEvent {
+            authority: ctx.accounts.gumball_machine.authority.key(),
+            buyer: ctx.accounts.pending_draw.buyer,
+            index: drawn_item.index,
+            tier: drawn_item.tier,
+            weight: drawn_item.weight,
+            total_weight: drawn_item.total_weight,
+        });
+
+        if let Some(rerolled_index) = rerolled_index {
+            emit_cpi!(RerollItemEvent {
+                authority: ctx.accounts.gumball_machine.authority.key(),
+                buyer: ctx.accounts.pending_draw.buyer,
+                rerolled_index,
+                index: drawn_item.index,
+            });
+        }
+    }
+
+    for claimed_item in claimed_items {
+        emit_cpi!(claimed_item);
+    }
+
+    for minted_receipt in minted_receipts {
+        emit_cpi!(minted_receipt);
+    }
+
+    ctx.accounts
+        .pending_draw
+        .close(ctx.accounts.payer.to_account_info())?;
+
+    Ok(())
+}
+
+/// Item selected when revealing a draw.
+pub(crate) struct DrawnItem {
+    pub index: u32,
+    pub tier: u8,
+    pub weight: u16,
+    pub total_weight: u64,
+}
+
+/// Selects the reserved items of a pending draw.
+///
+/// The available indices array also holds the reserved (pending) items, so each
+/// selection is made from every item that has not been assigned to a buyer yet. After
+/// an index is selected, it is swap-removed from the array, so the same index cannot
+/// be drawn again. On tiered machines, the odds of each item are proportional to the
+/// weight of its tier.
+///
+/// Values are derived from the seed (slot hash or provider randomness hashed with the
+/// pending draw address) with `random_below`, which avoids the modulo bias of reducing
+/// the seed directly. The draw slot and the price (the revenue of the draw split between
+/// its items, the last item taking the remainder) are stored on the config line of each
+/// selected item.
+pub(crate) fn process_reveal_draw(
+    gumball_machine: &mut Box<Account<'_, GumballMachine>>,
+    buyer: Pubkey,
+    quantity: u16,
+    draw_slot: u64,
+    revenue: u64,
+    seed: &[u8; 32],
+) -> Result<Vec<DrawnItem>> {
+    let account_info = gumball_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+    let config_count = get_config_count(&account_data)? as u64;
+    let mut items_pending = gumball_machine.get_items_pending(&account_data)?;
+    let mut tier_config = gumball_machine.get_tier_config(&account_data)?;
+    drop(account_data);
+
+    require!(items_pending >= quantity, GumballError::InvalidQuantity);
+
+    let mut drawn_items = Vec::with_capacity(quantity as usize);
+
+    for draw_number in 0..quantity as u64 {
+        // number of items that already have a buyer
+        let items_assigned = gumball_machine.items_redeemed - items_pending as u64;
+
+        let drawn_item = if gumball_machine.is_tiered() {
+            let total_weight = tier_config.total_weight();
+            // the draw number is used as the nonce so each item in a batch gets a
+            // different selection
+            let value = random_below(seed, draw_number, total_weight)?;
+
+            let mut account_data = account_info.data.borrow_mut();
+            let (index, tier) =
+                take_weighted_item(gumball_machine, &mut account_data, &mut tier_config, value)?;
+            drop(account_data);
+
+            set_buyer(gumball_machine, buyer, index)?;
+
+            DrawnItem {
+                index,
+                tier,
+                weight: tier_config.weights[tier as usize],
+                total_weight,
+            }
+        } else {
+            let items_available = config_count - items_assigned;
+            let index = random_below(seed, draw_number, items_available)? as usize;
+
+            let index = set_config_line_buyer(gumball_machine, buyer, index, items_assigned)?;
+
+            DrawnItem {
+                index,
+                tier: 0,
+                weight: 1,
+                total_weight: items_available,
+            }
+        };
+
+        let price = if draw_number == quantity as u64 - 1 {
+            revenue - (revenue / quantity as u64) * draw_number
+        } else {
+            revenue / quantity as u64
+        };
+
+        let mut account_data = account_info.data.borrow_mut();
+        gumball_machine.set_item_draw_slot(&mut account_data, drawn_item.index, draw_slot)?;
+        gumball_machine.set_item_price(&mut account_data, drawn_item.index, price)?;
+        drop(account_data);
+
+        items_pending -= 1;
+        drawn_items.push(drawn_item);
+    }
+
+    let items_pending_position = gumball_machine.get_items_pending_position()?;
+    let mut account_data = account_info.data.borrow_mut();
+    account_data[items_pending_position..items_pending_position + 2]
+        .copy_from_slice(&items_pending.to_le_bytes());
+
+    if gumball_machine.is_tiered() {
+        gumball_machine.set_tier_config(&mut account_data, &tier_config)?;
+    }
+
+    Ok(drawn_items)
+}
+
+/// Number of delivery accounts shared by every drawn item.
+const DELIVERY_ACCOUNTS: usize = 12;
+
+/// Returns the number of delivery accounts of a drawn item.
+fn delivery_item_accounts(token_standard: TokenStandard) -> Result<usize> {
+    match token_standard {
+        TokenStandard::Sol => Ok(2),
+        TokenStandard::Core => Ok(3),
+        TokenStandard::Fungible | TokenStandard::Token2022Fungible => Ok(4),
+        TokenStandard::NonFungible => Ok(7),
+        TokenStandard::ProgrammableNonFungible => Ok(11),
+        // the proof of a compressed NFT is not known until the draw is revealed
+        TokenStandard::CompressedNonFungible => err!(GumballError::InstantDeliveryNotSupported),
+    }
+}
+
+/// Transfers the revealed items to the buyer, marking them as claimed.
+///
+/// The remaining accounts hold the shared accounts followed by the accounts of each
+/// drawn item, in draw order:
+///
+///   0. `[signer, writable]` Payer of the delivery
+///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+///   2. `[writable]` Gumball Machine authority (receiver of closed token accounts rent)
+///   3. `[writable]` Buyer
+///   4. `[]` System program
+///   5. `[]` MPL Core program
+///   6. `[]` Token program
+///   7. `[]` Token-2022 program
+///   8. `[]` Associated Token program
+///   9. `[]` Token Metadata program
+///   10. `[]` Instructions sysvar
+///   11. `[]` Token Auth Rules program
+///
+/// For each drawn item, depending on its token standard:
+///
+///   0. `[writable]` Asset (mint for tokens and NFTs, native mint for SOL prizes)
+///   1. `[writable]` Seller
+///   - Core: 2. `[writable]` Collection (the gumball program when there is none)
+///   - Tokens: 2. `[writable]` Buyer token account, 3. `[writable]` Authority PDA token
+///     account
+///   - NFTs: 2. `[writable]` Seller token account, 3. `[writable]` Buyer token account,
+///     4. `[writable]` Authority PDA token account, 5. `[writable]` Edition,
+///     6. `[writable]` Metadata
+///   - pNFTs: the NFT accounts followed by 7. `[writable]` Seller token record,
+///     8. `[writable]` Authority PDA token record, 9. `[writable]` Buyer token record,
+///     10. `[]` Auth rules (the gumball program when there are none)
+///
+/// Compressed NFTs cannot be delivered, so machines with instant delivery cannot hold
+/// them.
+fn deliver_drawn_items<'info>(
+    gumball_machine: &mut Box<Account<'info, GumballMachine>>,
+    buyer: Pubkey,
+    indices: &[u32],
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<ClaimItemEvent>> {
+    require!(
+        remaining_accounts.len() >= DELIVERY_ACCOUNTS,
+        GumballError::InvalidInstantDeliveryAccount
+    );
+
+    let payer = &remaining_accounts[0];
+    let authority_pda = &remaining_accounts[1];
+    let authority = &remaining_accounts[2];
+    let buyer_info = &remaining_accounts[3];
+    let system_program = &remaining_accounts[4];
+    let mpl_core_program = &remaining_accounts[5];
+    let token_program = &remaining_accounts[6];
+    let token_2022_program = &remaining_accounts[7];
+    let associated_token_program = &remaining_accounts[8];
+    let token_metadata_program = &remaining_accounts[9];
+    let sysvar_instructions = UncheckedAccount::try_from(&remaining_accounts[10]);
+    let auth_rules_program = UncheckedAccount::try_from(&remaining_accounts[11]);
+
+    let gumball_machine_key = gumball_machine.key();
+    let (authority_pda_key, authority_pda_bump) = Pubkey::find_program_address(
+        &[AUTHORITY_SEED.as_bytes(), gumball_machine_key.as_ref()],
+        &crate::ID,
+    );
+
+    for (account, expected) in [
+        (authority_pda, authority_pda_key),
+        (authority, gumball_machine.authority),
+        (buyer_info, buyer),
+        (system_program, system_program::ID),
+        (mpl_core_program, mpl_core::ID),
+        (token_program, Token::id()),
+        (token_2022_program, Token2022::id()),
+        (associated_token_program, AssociatedToken::id()),
+        (token_metadata_program, mpl_token_metadata::ID),
+        (&remaining_accounts[10], sysvar::instructions::ID),
+        (&remaining_accounts[11], MPL_TOKEN_AUTH_RULES_PROGRAM),
+    ] {
+        require!(
+            cmp_pubkeys(account.key, &expected),
+            GumballError::InvalidInstantDeliveryAccount
+        );
+    }
+
+    require!(payer.is_signer, GumballError::InvalidInstantDeliveryAccount);
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine_key.as_ref(),
+        &[authority_pda_bump],
+    ];
+
+    let mut claimed_items = Vec::with_capacity(indices.len());
+    let mut cursor = DELIVERY_ACCOUNTS;
+
+    for index in indices {
+        let token_standard = get_item_token_standard(gumball_machine, *index)?;
+        let item_account_count = delivery_item_accounts(token_standard)?;
+        require!(
+            remaining_accounts.len() >= cursor + item_account_count,
+            GumballError::InvalidInstantDeliveryAccount
+        );
+        let item_accounts = &remaining_accounts[cursor..cursor + item_account_count];
+        cursor += item_account_count;
+
+        let asset = &item_accounts[0];
+        let seller = &item_accounts[1];
+
+        assert_config_line(
+            gumball_machine,
+            *index,
+            ConfigLine {
+                mint: asset.key(),
+                seller: seller.key(),
+                buyer,
+                token_standard,
+            },
+            false,
+        )?;
+
+        let amount = match token_standard {
+            TokenStandard::Core => {
+                let collection = if cmp_pubkeys(item_accounts[2].key, &crate::ID) {
+                    None
+                } else {
+                    Some(&item_accounts[2])
+                };
+
+                processors::claim_core_asset(
+                    gumball_machine,
+                    *index,
+                    authority_pda,
+                    payer,
+                    buyer_info,
+                    seller,
+                    asset,
+                    collection,
+                    mpl_core_program,
+                    system_program,
+                    &auth_seeds,
+                )?;
+
+                1
+            }
+            TokenStandard::Fungible | TokenStandard::Token2022Fungible => {
+                let authority_pda_token_account = &mut Box::new(try_from!(
+                    InterfaceAccount::<TokenAccount>,
+                    &item_accounts[3]
+                )?);
+                require!(
+                    authority_pda_token_account.mint == asset.key()
+                        && authority_pda_token_account.owner == authority_pda_key,
+                    GumballError::InvalidInstantDeliveryAccount
+                );
+
+                processors::claim_tokens(
+                    gumball_machine,
+                    *index,
+                    authority,
+                    authority_pda,
+                    payer,
+                    buyer_info,
+                    &item_accounts[2],
+                    authority_pda_token_account,
+                    asset,
+                    get_fungible_token_program(
+                        token_standard,
+                        token_program,
+                        Some(token_2022_program),
+                    )?,
+                    associated_token_program,
+                    system_program,
+                    &auth_seeds,
+                )?
+            }
+            TokenStandard::NonFungible | TokenStandard::ProgrammableNonFungible => {
+                let metadata_info = &item_accounts[6];
+                let metadata = &Metadata::try_from(metadata_info)?;
+
+                // only pNFTs have token records and auth rules
+                let (seller_token_record, authority_pda_token_record, buyer_token_record) =
+                    if token_standard == TokenStandard::ProgrammableNonFungible {
+                        (
+                            Some(UncheckedAccount::try_from(&item_accounts[7])),
+                            Some(UncheckedAccount::try_from(&item_accounts[8])),
+                            Some(UncheckedAccount::try_from(&item_accounts[9])),
+                        )
+                    } else {
+                        (None, None, None)
+                    };
+                let auth_rules = item_accounts
+                    .get(10)
+                    .filter(|auth_rules| !cmp_pubkeys(auth_rules.key, &crate::ID))
+                    .map(UncheckedAccount::try_from);
+
+                processors::claim_nft_v2(
+                    gumball_machine,
+                    *index,
+                    authority_pda,
+                    payer,
+                    buyer_info,
+                    &item_accounts[3],
+                    seller,
+                    &item_accounts[2],
+                    &item_accounts[4],
+                    asset,
+                    &item_accounts[5],
+                    metadata,
+                    metadata_info,
+                    token_program,
+                    associated_token_program,
+                    token_metadata_program,
+                    system_program,
+                    &auth_seeds,
+                    seller_token_record.as_ref(),
+                    authority_pda_token_record.as_ref(),
+                    buyer_token_record.as_ref(),
+                    auth_rules.as_ref(),
+                    Some(&sysvar_instructions),
+                    Some(&auth_rules_program),
+                )?;
+
+                1
+            }
+            TokenStandard::Sol => processors::claim_sol_prize(
+                gumball_machine,
+                *index,
+                authority_pda,
+                buyer_info,
+                system_program,
+                &auth_seeds,
+            )?,
+            TokenStandard::CompressedNonFungible => {
+                return err!(GumballError::InstantDeliveryNotSupported)
+            }
+        };
+
+        msg!("Delivered item at index: {}", index);
+
+        claimed_items.push(ClaimItemEvent {
+            mint: asset.key(),
+            authority: gumball_machine.authority,
+            seller: seller.key(),
+            buyer,
+            amount,
+        });
+    }
+
+    Ok(claimed_items)
+}
+
+/// Number of receipt accounts shared by every drawn item.
+const RECEIPT_ACCOUNTS: usize = 6;
+
+/// Mints a receipt Core asset for each revealed item, with the gumball machine, index and
+/// draw slot as attributes. The receipts hold the items: the authority PDA becomes their
+/// buyer, so they can only be claimed by burning the receipt with `claim_with_receipt`.
+///
+/// The remaining accounts hold the shared accounts followed by a new receipt account for
+/// each drawn item, in draw order:
+///
+///   0. `[signer, writable]` Payer of the receipts
+///   1. `[]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+///   2. `[]` Buyer (owner of the receipts)
+///   3. `[writable]` Receipt collection
+///   4. `[]` MPL Core program
+///   5. `[]` System program
+///   6. `[signer, writable]` Receipt asset of each drawn item
+fn mint_receipts<'info>(
+    gumball_machine: &mut Box<Account<'info, GumballMachine>>,
+    buyer: Pubkey,
+    receipt_collection: Pubkey,
+    indices: &[u32],
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<MintReceiptEvent>> {
+    require!(
+        remaining_accounts.len() >= RECEIPT_ACCOUNTS + indices.len(),
+        GumballError::InvalidReceiptAccount
+    );
+
+    let payer = &remaining_accounts[0];
+    let authority_pda = &remaining_accounts[1];
+    let buyer_info = &remaining_accounts[2];
+    let receipt_collection_info = &remaining_accounts[3];
+    let mpl_core_program = &remaining_accounts[4];
+    let system_program = &remaining_accounts[5];
+
+    let gumball_machine_key = gumball_machine.key();
+    let (authority_pda_key, authority_pda_bump) = Pubkey::find_program_address(
+        &[AUTHORITY_SEED.as_bytes(), gumball_machine_key.as_ref()],
+        &crate::ID,
+    );
+
+    for (account, expected) in [
+        (authority_pda, authority_pda_key),
+        (buyer_info, buyer),
+        (receipt_collection_info, receipt_collection),
+        (mpl_core_program, mpl_core::ID),
+        (system_program, system_program::ID),
+    ] {
+        require!(
+            cmp_pubkeys(account.key, &expected),
+            GumballError::InvalidReceiptAccount
+        );
+    }
+
+    require!(payer.is_signer, GumballError::InvalidReceiptAccount);
+
+    let collection = Box::<Collection>::try_from(receipt_collection_info)?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine_key.as_ref(),
+        &[authority_pda_bump],
+    ];
+
+    let account_info = gumball_machine.to_account_info();
+    let mut minted_receipts = Vec::with_capacity(indices.len());
+
+    for (index, receipt) in indices.iter().zip(&remaining_accounts[RECEIPT_ACCOUNTS..]) {
+        require!(receipt.is_signer, GumballError::InvalidReceiptAccount);
+
+        // the receipt becomes the only way to claim the item
+        let mut account_data = account_info.data.borrow_mut();
+        let buyer_position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (*index as usize) * gumball_machine.get_config_line_size()
+            + 64;
+        account_data[buyer_position..buyer_position + 32]
+            .copy_from_slice(&authority_pda_key.to_bytes());
+
+        let draw_slot = gumball_machine.get_item_draw_slot(&account_data, *index)?;
+        drop(account_data);
+
+        CreateV2CpiBuilder::new(mpl_core_program)
+            .asset(receipt)
+            .collection(Some(receipt_collection_info))
+            .authority(Some(authority_pda))
+            .payer(payer)
+            .owner(Some(buyer_info))
+            .system_program(system_program)
+            .name(format!("{} #{}", collection.base.name, index))
+            .uri(collection.base.uri.clone())
+            .plugins(vec![PluginAuthorityPair {
+                plugin: Plugin::Attributes(Attributes {
+                    attribute_list: vec![
+                        Attribute {
+                            key: RECEIPT_MACHINE_ATTRIBUTE.to_string(),
+                            value: gumball_machine_key.to_string(),
+                        },
+                        Attribute {
+                            key: RECEIPT_INDEX_ATTRIBUTE.to_string(),
+                            value: index.to_string(),
+                        },
+                        Attribute {
+                            key: RECEIPT_SLOT_ATTRIBUTE.to_string(),
+                            value: draw_slot.to_string(),
+                        },
+                    ],
+                }),
+                authority: None,
+            }])
+            .invoke_signed(&[&auth_seeds])?;
+
+        msg!("Minted receipt for item at index: {}", index);
+
+        minted_receipts.push(MintReceiptEvent {
+            receipt: receipt.key(),
+            authority: gumball_machine.authority,
+            buyer,
+            index: *index,
+        });
+    }
+
+    Ok(minted_receipts)
+}
@@ -1,21 +1,29 @@
 use crate::{
     assert_config_line_values,
-    constants::{AUTHORITY_SEED, GUMBALL_MACHINE_SIZE, MPL_TOKEN_AUTH_RULES_PROGRAM},
+    constants::{
+        AUTHORITY_SEED, GUMBALL_MACHINE_SIZE, MPL_TOKEN_AUTH_RULES_PROGRAM,
+        SPL_ACCOUNT_COMPRESSION_PROGRAM, SPL_NOOP_PROGRAM,
+    },
     events::SellItemEvent,
-    get_bit_byte_info, get_config_count,
-    processors::transfer_nft_with_revoke,
+    get_bit_byte_info, get_config_count, get_fungible_token_program,
+    processors::{
+        assert_sol_prizes_covered, release_sol_prize, return_drawn_item, reverse_revenue,
+        transfer_nft_with_revoke,
+    },
     state::GumballMachine,
-    transfer_and_close_if_empty, try_from, AssociatedToken, GumballError, GumballState, Token,
-    TokenStandard,
+    transfer_and_close_if_empty, transfer_compressed_nft, try_from, AssociatedToken, GumballError,
+    GumballState, Token, TokenStandard,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::{token_2022::Token2022, token_interface::TokenAccount};
 use mpl_core::{
     instructions::{TransferV1CpiBuilder, UpdatePluginV1CpiBuilder},
     types::{FreezeDelegate, Plugin},
 };
 use mpl_token_metadata::accounts::Metadata;
-use utils::{get_bps_of, is_native_mint, transfer_from_pda};
+use utils::{get_bps_of, is_native_mint, transfer_from_pda, transfer_sol};
+
+use super::CompressedNftArgs;
 
 /// Settles a legacy NFT sale
 #[event_cpi]
@@ -148,6 +156,26 @@ pub struct SellItem<'info> {
     /// CHECK: Safe due to address check
     #[account(address = MPL_TOKEN_AUTH_RULES_PROGRAM)]
     pub auth_rules_program: Option<UncheckedAccount<'info>>,
+
+    /// OPTIONAL TOKEN-2022 ACCOUNTS - only required if selling Token-2022 tokens
+    token_2022_program: Option<Program<'info, Token2022>>,
+
+    /// OPTIONAL COMPRESSED NFT ACCOUNTS - only required if selling compressed NFT
+
+    /// CHECK: Safe due to bubblegum transfer
+    #[account(mut)]
+    merkle_tree: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to bubblegum transfer
+    tree_config: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM)]
+    compression_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_NOOP_PROGRAM)]
+    log_wrapper: Option<UncheckedAccount<'info>>,
 }
 
 pub fn sell_item<'info>(
@@ -155,6 +183,7 @@ pub fn sell_item<'info>(
     index: u32,
     amount: u64,
     buy_price: u64,
+    compressed_nft: Option<CompressedNftArgs>,
 ) -> Result<()> {
     let gumball_machine = &mut ctx.accounts.gumball_machine;
     require!(gumball_machine.version >= 4, GumballError::InvalidVersion);
@@ -227,13 +256,26 @@ pub fn sell_item<'info>(
     let is_claimed = current_value & mask == mask;
     require!(!is_claimed, GumballError::ItemAlreadyClaimed);
 
-    if buy_back_config.to_gumball_machine {
-        // TODO: Implement buy back to gumball machine logic
-        require!(false, GumballError::NotImplemented);
+    // Revenue of the item's draw returned to the buy back funds
+    let reclaimed_revenue = if buy_back_config.to_gumball_machine {
+        // Item can only be drawn again while the sale is live
+        require!(
+            gumball_machine.state == GumballState::SaleLive,
+            GumballError::InvalidState
+        );
+        require!(gumball_machine.version >= 5, GumballError::InvalidVersion);
+
+        return_item_to_gumball_machine(
+            gumball_machine,
+            &mut gumball_data,
+            index,
+            config_line_position,
+        )?
     } else {
         // Mark item as claimed if buying back to creator
         gumball_data[byte_position] |= mask;
-    }
+        0
+    };
 
     let auth_seeds = [
         AUTHORITY_SEED.as_bytes(),
@@ -243,11 +285,21 @@ pub fn sell_item<'info>(
 
     if !buy_back_config.to_gumball_machine {
         match config_line.token_standard {
-            TokenStandard::Fungible => {
+            TokenStandard::Fungible | TokenStandard::Token2022Fungible => {
                 let authority_pda_token_account = &mut Box::new(try_from!(
-                    Account::<TokenAccount>,
+                    InterfaceAccount::<TokenAccount>,
                     ctx.accounts.authority_pda_token_account.as_ref().unwrap()
                 )?);
+                let token_2022_program = ctx
+                    .accounts
+                    .token_2022_program
+                    .as_ref()
+                    .map(|a| a.to_account_info());
+                let token_program = get_fungible_token_program(
+                    config_line.token_standard,
+                    token_program,
+                    token_2022_program.as_ref(),
+                )?;
 
                 transfer_and_close_if_empty(
                     payer,
@@ -298,6 +350,48 @@ pub fn sell_item<'info>(
                     .system_program(Some(system_program))
                     .invoke_signed(&[&auth_seeds])?;
             }
+            TokenStandard::Sol => {
+                release_sol_prize(gumball_machine, &mut gumball_data, amount)?;
+                transfer_sol(
+                    authority_pda,
+                    buyer,
+                    system_program,
+                    Some(&auth_seeds),
+                    amount,
+                )?;
+            }
+            TokenStandard::CompressedNonFungible => {
+                let compressed_nft = compressed_nft
+                    .as_ref()
+                    .ok_or(GumballError::MissingCompressedNftArgs)?;
+                let merkle_tree = &ctx.accounts.merkle_tree.as_ref().unwrap().to_account_info();
+                require!(
+                    mpl_bubblegum::utils::get_asset_id(merkle_tree.key, compressed_nft.nonce)
+                        == mint.key(),
+                    GumballError::InvalidMint
+                );
+                transfer_compressed_nft(
+                    authority_pda,
+                    buyer,
+                    merkle_tree,
+                    &ctx.accounts.tree_config.as_ref().unwrap().to_account_info(),
+                    &ctx.accounts
+                        .bubblegum_program
+                        .as_ref()
+                        .unwrap()
+                        .to_account_info(),
+                    &ctx.accounts
+                        .compression_program
+                        .as_ref()
+                        .unwrap()
+                        .to_account_info(),
+                    &ctx.accounts.log_wrapper.as_ref().unwrap().to_account_info(),
+                    system_program,
+                    ctx.remaining_accounts,
+                    compressed_nft,
+                    Some(&auth_seeds),
+                )?;
+            }
             TokenStandard::NonFungible | TokenStandard::ProgrammableNonFungible => {
                 let metadata_info = &ctx.accounts.metadata.as_ref().unwrap().to_account_info();
                 let metadata = &Metadata::try_from(metadata_info)?;
@@ -437,6 +531,8 @@ pub fn sell_item<'info>(
     let buy_back_funds_available_position =
         gumball_machine.get_buy_back_funds_available_position()?;
     let new_buy_back_funds_available = buy_back_funds_available
+        .checked_add(reclaimed_revenue)
+        .ok_or(GumballError::NumericalOverflowError)?
         .checked_sub(buy_price)
         .ok_or(GumballError::NumericalOverflowError)?
         .checked_sub(marketplace_fee)
@@ -449,6 +545,8 @@ pub fn sell_item<'info>(
         new_buy_back_funds_available
     );
 
+    assert_sol_prizes_covered(gumball_machine, &gumball_data, authority_pda)?;
+
     drop(gumball_data);
 
     emit_cpi!(SellItemEvent {
@@ -462,3 +560,51 @@ pub fn sell_item<'info>(
 
     Ok(())
 }
+
+/// Puts a bought back item back into the pool of drawable items.
+///
+/// The item will be drawn (and paid for) again, so the price paid for its previous draw
+/// (stored on its config line) is removed from the total revenue. Otherwise the proceeds
+/// of every item would include the revenue of two draws for a single config line.
+///
+/// Part of that price may already have been paid out to items settled since the draw, so
+/// their share (by weight or count) is removed from `total_proceeds_settled` and the rest
+/// (net of the marketplace fee already taken on draw) is returned so it can be added back
+/// to the buy back funds that paid for the item.
+fn return_item_to_gumball_machine(
+    gumball_machine: &mut Box<Account<'_, GumballMachine>>,
+    gumball_data: &mut [u8],
+    index: u32,
+    config_line_position: usize,
+) -> Result<u64> {
+    // pending draws are selected from the available items when revealed
+    require!(
+        gumball_machine.get_items_pending(gumball_data)? == 0,
+        GumballError::ItemsPendingReveal
+    );
+
+    // the proceeds of a settled item were already paid out to its seller
+    let bit_mask_start = gumball_machine.get_settled_items_bit_mask_position()?;
+    let (byte_position, _bit, mask) = get_bit_byte_info(bit_mask_start, index as usize)?;
+    require!(
+        gumball_data[byte_position] & mask != mask,
+        GumballError::ItemAlreadySettled
+    );
+
+    // Version 17+ stores the price of each drawn item, older versions use the average
+    let price = if gumball_machine.version >= 17 {
+        gumball_machine.get_item_price(gumball_data, index)?
+    } else {
+        gumball_machine
+            .total_revenue
+            .checked_div(gumball_machine.items_redeemed)
+            .ok_or(GumballError::NumericalOverflowError)?
+    };
+
+    return_drawn_item(gumball_machine, gumball_data, index, config_line_position)?;
+
+    let refund = reverse_revenue(gumball_machine, gumball_data, price)?;
+    gumball_machine.items_redeemed -= 1;
+
+    Ok(refund)
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, GumballError};
+
+/// Sets how long add item requests stay open before anyone can close them.
+#[derive(Accounts)]
+pub struct SetAddItemRequestExpiry<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version >= 9 @ GumballError::InvalidVersion,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_add_item_request_expiry(
+    ctx: Context<SetAddItemRequestExpiry>,
+    expiry: i64,
+) -> Result<()> {
+    require!(expiry >= 0, GumballError::InvalidAddItemRequestExpiry);
+
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    gumball_machine.set_add_item_request_expiry(&mut account_data, expiry)?;
+
+    Ok(())
+}
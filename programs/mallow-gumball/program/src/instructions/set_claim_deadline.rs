@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, ClaimDeadlineConfig, GumballError};
+
+/// Sets the deadline to claim drawn items and where unclaimed items are sent after it.
+#[derive(Accounts)]
+pub struct SetClaimDeadline<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version >= 11 @ GumballError::InvalidVersion,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_claim_deadline(
+    ctx: Context<SetClaimDeadline>,
+    claim_deadline_config: ClaimDeadlineConfig,
+) -> Result<()> {
+    require!(
+        claim_deadline_config.claim_period >= 0,
+        GumballError::InvalidClaimDeadline
+    );
+
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    let position = gumball_machine.get_claim_deadline_config_position()?;
+    account_data[position..position + ClaimDeadlineConfig::INIT_SPACE]
+        .copy_from_slice(&claim_deadline_config.try_to_vec().unwrap());
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, GumballError};
+
+/// Sets how long after the sale ends the gumball machine can be closed without the mint authority.
+/// Once the sale has started the grace period can only be increased.
+#[derive(Accounts)]
+pub struct SetForceCloseGracePeriod<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version >= 10 @ GumballError::InvalidVersion,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_force_close_grace_period(
+    ctx: Context<SetForceCloseGracePeriod>,
+    grace_period: i64,
+) -> Result<()> {
+    require!(
+        grace_period >= 0,
+        GumballError::InvalidForceCloseGracePeriod
+    );
+
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    // Buyers and sellers rely on the grace period once the sale has started, so it can only be extended
+    if !gumball_machine.can_edit_items() {
+        require!(
+            grace_period >= gumball_machine.get_force_close_grace_period(&account_data)?,
+            GumballError::InvalidForceCloseGracePeriod
+        );
+    }
+
+    gumball_machine.set_force_close_grace_period(&mut account_data, grace_period)?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    get_config_count, get_item_token_standard, state::GumballMachine, GumballError, TokenStandard,
+};
+
+/// Sets whether drawn items are transferred to the buyer when the draw is revealed.
+#[derive(Accounts)]
+pub struct SetInstantDelivery<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version >= 12 @ GumballError::InvalidVersion,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_instant_delivery(
+    ctx: Context<SetInstantDelivery>,
+    instant_delivery: bool,
+) -> Result<()> {
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+
+    // compressed NFTs cannot be delivered when the draw is revealed, and delivered items
+    // have nothing left for a receipt to hold
+    if instant_delivery {
+        require!(
+            gumball_machine
+                .get_receipt_collection(&account_info.data.borrow())?
+                .is_none(),
+            GumballError::ReceiptsWithInstantDelivery
+        );
+
+        let count = get_config_count(&account_info.data.borrow())?;
+        for index in 0..count as u32 {
+            require!(
+                get_item_token_standard(gumball_machine, index)?
+                    != TokenStandard::CompressedNonFungible,
+                GumballError::InstantDeliveryNotSupported
+            );
+        }
+    }
+
+    let mut account_data = account_info.data.borrow_mut();
+
+    gumball_machine.set_instant_delivery(&mut account_data, instant_delivery)?;
+
+    Ok(())
+}
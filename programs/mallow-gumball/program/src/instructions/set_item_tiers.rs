@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONFIG_LINE_V2_SIZE, GUMBALL_MACHINE_SIZE},
+    get_config_count,
+    processors::{insert_item_into_tier, remove_item_from_tier},
+    state::GumballMachine,
+    GumballError,
+};
+
+/// Sets the tier of a range of items.
+#[derive(Accounts)]
+pub struct SetItemTiers<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.is_tiered() @ GumballError::InvalidVersion,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_item_tiers(
+    ctx: Context<SetItemTiers>,
+    start_index: u32,
+    end_index: u32,
+    tier: u8,
+) -> Result<()> {
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let config_count = get_config_count(&account_data)? as u32;
+
+    require!(
+        start_index <= end_index && end_index < config_count,
+        GumballError::IndexGreaterThanLength
+    );
+
+    let mut tier_config = gumball_machine.get_tier_config(&account_data)?;
+    let config_line_size = gumball_machine.get_config_line_size();
+
+    for index in start_index..=end_index {
+        let current_tier = gumball_machine.get_item_tier(&account_data, index)?;
+        if current_tier == tier {
+            continue;
+        }
+
+        remove_item_from_tier(
+            gumball_machine,
+            &mut account_data,
+            &mut tier_config,
+            current_tier,
+            index,
+        )?;
+        insert_item_into_tier(
+            gumball_machine,
+            &mut account_data,
+            &mut tier_config,
+            tier,
+            index,
+        )?;
+
+        let tier_position =
+            GUMBALL_MACHINE_SIZE + 4 + (index as usize) * config_line_size + CONFIG_LINE_V2_SIZE;
+        account_data[tier_position] = tier;
+    }
+
+    gumball_machine.set_tier_config(&mut account_data, &tier_config)?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONFIG_LINE_V3_SIZE, GUMBALL_MACHINE_SIZE},
+    get_config_count,
+    state::GumballMachine,
+    GumballError,
+};
+
+/// Sets the proceeds weight of a range of items.
+#[derive(Accounts)]
+pub struct SetItemWeights<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.is_value_weighted() @ GumballError::InvalidVersion,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_item_weights(
+    ctx: Context<SetItemWeights>,
+    start_index: u32,
+    end_index: u32,
+    weight: u32,
+) -> Result<()> {
+    require!(weight > 0, GumballError::InvalidProceedsWeight);
+
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let config_count = get_config_count(&account_data)? as u32;
+
+    require!(
+        start_index <= end_index && end_index < config_count,
+        GumballError::IndexGreaterThanLength
+    );
+
+    let mut total_weight = gumball_machine.get_total_weight(&account_data)?;
+    let config_line_size = gumball_machine.get_config_line_size();
+
+    for index in start_index..=end_index {
+        let current_weight = gumball_machine.get_item_weight(&account_data, index)?;
+
+        total_weight = total_weight
+            .checked_sub(current_weight as u64)
+            .and_then(|total_weight| total_weight.checked_add(weight as u64))
+            .ok_or(GumballError::NumericalOverflowError)?;
+
+        let weight_position =
+            GUMBALL_MACHINE_SIZE + 4 + (index as usize) * config_line_size + CONFIG_LINE_V3_SIZE;
+        account_data[weight_position..weight_position + 4].copy_from_slice(&weight.to_le_bytes());
+    }
+
+    gumball_machine.set_total_weight(&mut account_data, total_weight)?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, GumballError, RandomnessConfig, RandomnessRequestConfig};
+
+/// Sets the randomness provider used to draw items.
+#[derive(Accounts)]
+pub struct SetRandomnessConfig<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.version >= 7 @ GumballError::InvalidVersion,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_randomness_config(
+    ctx: Context<SetRandomnessConfig>,
+    randomness_config: RandomnessConfig,
+    randomness_request_config: RandomnessRequestConfig,
+) -> Result<()> {
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    if randomness_config.is_enabled() {
+        // the randomness account and its requests are only stored from version 16
+        require!(gumball_machine.version >= 16, GumballError::InvalidVersion);
+        require!(
+            randomness_request_config.account != Pubkey::default(),
+            GumballError::InvalidRandomnessAccount
+        );
+    }
+
+    let position = gumball_machine.get_randomness_config_position()?;
+    account_data[position..position + RandomnessConfig::INIT_SPACE]
+        .copy_from_slice(&randomness_config.try_to_vec().unwrap());
+
+    if gumball_machine.version >= 16 {
+        let position = gumball_machine.get_randomness_request_config_position()?;
+        account_data[position..position + RandomnessRequestConfig::INIT_SPACE]
+            .copy_from_slice(&randomness_request_config.try_to_vec().unwrap());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::GumballMachine, GumballError};
+
+/// Sets the draw weight of each tier.
+#[derive(Accounts)]
+pub struct SetTierWeights<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = gumball_machine.is_tiered() @ GumballError::InvalidVersion,
+        constraint = gumball_machine.can_edit_items() @ GumballError::InvalidState,
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the gumball machine.
+    authority: Signer<'info>,
+}
+
+pub fn set_tier_weights(ctx: Context<SetTierWeights>, weights: [u16; 8]) -> Result<()> {
+    // a tier without weight would never be drawn
+    require!(
+        weights.iter().all(|weight| *weight > 0),
+        GumballError::InvalidTier
+    );
+
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    let mut tier_config = gumball_machine.get_tier_config(&account_data)?;
+    tier_config.weights = weights;
+    gumball_machine.set_tier_config(&mut account_data, &tier_config)?;
+
+    Ok(())
+}
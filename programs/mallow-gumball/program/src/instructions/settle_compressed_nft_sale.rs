@@ -0,0 +1,328 @@
+use crate::{
+    assert_config_line,
+    constants::{
+        AUTHORITY_SEED, SELLER_HISTORY_SEED, SPL_ACCOUNT_COMPRESSION_PROGRAM, SPL_NOOP_PROGRAM,
+    },
+    events::SettleItemSaleEvent,
+    processors::{
+        self, claim_proceeds, get_unclaimed_item_receiver, is_item_claimed, ItemReceiver,
+    },
+    state::GumballMachine,
+    verify_compressed_nft_leaf, AssociatedToken, ConfigLine, GumballError, SellerHistory, Token,
+    TokenStandard,
+};
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+use mpl_token_metadata::types::Creator;
+use utils::{get_verified_compressed_royalty_info, hash_compressed_nft_leaf, RoyaltyInfo};
+
+use super::CompressedNftArgs;
+
+/// Arguments required to settle the sale of a compressed nft.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SettleCompressedNftSaleArgs {
+    /// Current state of the leaf.
+    pub compressed_nft: CompressedNftArgs,
+    /// Current owner of the leaf, or the default pubkey if it has been burnt.
+    pub leaf_owner: Pubkey,
+    /// Current delegate of the leaf.
+    pub leaf_delegate: Pubkey,
+    /// Metadata hash used to verify the data hash.
+    pub metadata_hash: [u8; 32],
+    pub seller_fee_basis_points: u16,
+    /// Creators used to verify the creator hash.
+    pub creators: Vec<Creator>,
+    /// Number of proof nodes at the start of the remaining accounts.
+    pub proof_length: u8,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleCompressedNftSale<'info> {
+    /// Anyone can settle the sale
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Gumball machine account.
+    #[account(
+        mut,
+        has_one = authority @ GumballError::InvalidAuthority,
+        constraint = gumball_machine.can_settle_items() @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(), 
+            gumball_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Payment account for authority pda if using token payment
+    #[account(mut)]
+    authority_pda_payment_account: Option<UncheckedAccount<'info>>,
+
+    /// Seller of the nft
+    /// CHECK: Safe due to gumball machine constraint
+    #[account(mut)]
+    authority: UncheckedAccount<'info>,
+
+    /// Payment account for authority if using token payment
+    #[account(mut)]
+    authority_payment_account: Option<UncheckedAccount<'info>>,
+
+    /// Seller of the nft
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+
+    /// Payment account for seller if using token payment
+    #[account(mut)]
+    seller_payment_account: Option<UncheckedAccount<'info>>,
+
+    /// Seller history account.
+    #[account(
+        mut,
+        seeds = [
+            SELLER_HISTORY_SEED.as_bytes(),
+            gumball_machine.key().as_ref(),
+            seller.key().as_ref()
+        ],
+        bump
+    )]
+    seller_history: Box<Account<'info, SellerHistory>>,
+
+    /// buyer of the nft
+    /// CHECK: Safe due to item check
+    buyer: UncheckedAccount<'info>,
+
+    /// Fee account for marketplace fee if using fee config
+    #[account(mut)]
+    fee_account: Option<UncheckedAccount<'info>>,
+
+    /// Payment account for marketplace fee if using token payment
+    #[account(mut)]
+    fee_payment_account: Option<UncheckedAccount<'info>>,
+
+    /// Payment mint if using non-native payment token
+    payment_mint: Option<UncheckedAccount<'info>>,
+
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    #[account(mut)]
+    merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to bubblegum transfer
+    tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_bubblegum::ID)]
+    bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM)]
+    compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = SPL_NOOP_PROGRAM)]
+    log_wrapper: UncheckedAccount<'info>,
+}
+
+pub fn settle_compressed_nft_sale<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleCompressedNftSale<'info>>,
+    index: u32,
+    args: SettleCompressedNftSaleArgs,
+) -> Result<()> {
+    let gumball_machine = &mut ctx.accounts.gumball_machine;
+    let seller_history = &mut ctx.accounts.seller_history;
+    let payer = &ctx.accounts.payer.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let authority_pda = &mut ctx.accounts.authority_pda.to_account_info();
+    let authority = &mut ctx.accounts.authority.to_account_info();
+    let seller = &mut ctx.accounts.seller.to_account_info();
+    let token_program = &ctx.accounts.token_program.to_account_info();
+    let associated_token_program = &ctx.accounts.associated_token_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let merkle_tree = &ctx.accounts.merkle_tree.to_account_info();
+    let tree_config = &ctx.accounts.tree_config.to_account_info();
+    let bubblegum_program = &ctx.accounts.bubblegum_program.to_account_info();
+    let compression_program = &ctx.accounts.compression_program.to_account_info();
+    let log_wrapper = &ctx.accounts.log_wrapper.to_account_info();
+    let compressed_nft = &args.compressed_nft;
+    let asset_id = get_asset_id(merkle_tree.key, compressed_nft.nonce);
+    let is_burnt = args.leaf_owner == Pubkey::default();
+
+    let proof_length = args.proof_length as usize;
+    if ctx.remaining_accounts.len() < proof_length {
+        return err!(GumballError::InvalidProofPath);
+    }
+    let (proof, royalty_accounts) = ctx.remaining_accounts.split_at(proof_length);
+
+    assert_config_line(
+        gumball_machine,
+        index,
+        ConfigLine {
+            mint: asset_id,
+            seller: seller.key(),
+            buyer: buyer.key(),
+            token_standard: TokenStandard::CompressedNonFungible,
+        },
+        false,
+    )?;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        gumball_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let mut amount = 0;
+    if !is_item_claimed(gumball_machine, index)? {
+        amount = 1;
+
+        let receiver: &AccountInfo<'info> =
+            match get_unclaimed_item_receiver(gumball_machine, buyer.key(), authority_pda.key())? {
+                ItemReceiver::Buyer => buyer,
+                ItemReceiver::Seller => seller,
+                ItemReceiver::Authority => authority,
+            };
+
+        // The transfer authenticates the data and creator hashes
+        processors::claim_compressed_nft(
+            gumball_machine,
+            index,
+            authority_pda,
+            receiver,
+            merkle_tree,
+            tree_config,
+            bubblegum_program,
+            compression_program,
+            log_wrapper,
+            system_program,
+            proof,
+            compressed_nft,
+            &auth_seeds,
+        )?;
+    } else {
+        // Burnt leaves are replaced with an empty node
+        let leaf = if is_burnt {
+            [0; 32]
+        } else {
+            hash_compressed_nft_leaf(
+                &asset_id,
+                &args.leaf_owner,
+                &args.leaf_delegate,
+                compressed_nft.nonce,
+                &compressed_nft.data_hash,
+                &compressed_nft.creator_hash,
+            )
+        };
+
+        verify_compressed_nft_leaf(
+            merkle_tree,
+            compression_program,
+            proof,
+            compressed_nft.root,
+            leaf,
+            compressed_nft.index,
+        )?;
+    }
+
+    let royalty_info = if is_burnt {
+        RoyaltyInfo::default()
+    } else {
+        get_verified_compressed_royalty_info(
+            &compressed_nft.data_hash,
+            &compressed_nft.creator_hash,
+            &args.metadata_hash,
+            args.seller_fee_basis_points,
+            &args.creators,
+        )?
+    };
+
+    let payment_mint_info = ctx
+        .accounts
+        .payment_mint
+        .as_ref()
+        .map(|mint| mint.to_account_info());
+    let payment_mint = payment_mint_info.as_ref();
+
+    let authority_pda_payment_account_info = ctx
+        .accounts
+        .authority_pda_payment_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let authority_pda_payment_account = authority_pda_payment_account_info.as_ref();
+
+    let authority_payment_account_info = ctx
+        .accounts
+        .authority_payment_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let authority_payment_account = authority_payment_account_info.as_ref();
+
+    let seller_payment_account_info = ctx
+        .accounts
+        .seller_payment_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let seller_payment_account = seller_payment_account_info.as_ref();
+
+    let mut fee_account_info = ctx
+        .accounts
+        .fee_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let fee_account = fee_account_info.as_mut();
+
+    let fee_payment_account_info = ctx
+        .accounts
+        .fee_payment_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let fee_payment_account = fee_payment_account_info.as_ref();
+
+    let total_proceeds = claim_proceeds(
+        gumball_machine,
+        index,
+        seller_history,
+        payer,
+        authority_pda,
+        authority_pda_payment_account,
+        authority,
+        authority_payment_account,
+        seller,
+        seller_payment_account,
+        fee_account,
+        fee_payment_account,
+        payment_mint,
+        &royalty_info,
+        royalty_accounts,
+        associated_token_program,
+        token_program,
+        system_program,
+        &auth_seeds,
+    )?;
+
+    emit_cpi!(SettleItemSaleEvent {
+        mint: asset_id,
+        authority: gumball_machine.authority.key(),
+        seller: seller.key(),
+        buyer: buyer.key(),
+        total_proceeds,
+        payment_mint: gumball_machine.settings.payment_mint,
+        fee_config: gumball_machine.marketplace_fee_config,
+        curator_fee_bps: gumball_machine.settings.curator_fee_bps,
+        amount
+    });
+
+    Ok(())
+}
@@ -2,7 +2,9 @@ use crate::{
     assert_config_line,
     constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
     events::SettleItemSaleEvent,
-    processors::{self, claim_proceeds, is_item_claimed},
+    processors::{
+        self, claim_proceeds, get_unclaimed_item_receiver, is_item_claimed, ItemReceiver,
+    },
     state::GumballMachine,
     AssociatedToken, ConfigLine, GumballError, SellerHistory, Token, TokenStandard,
 };
@@ -196,16 +198,19 @@ pub fn settle_core_asset_sale<'info>(
     if !is_item_claimed(gumball_machine, index)? {
         amount = 1;
 
+        let receiver: &AccountInfo<'info> =
+            match get_unclaimed_item_receiver(gumball_machine, buyer.key(), authority_pda.key())? {
+                ItemReceiver::Buyer => buyer,
+                ItemReceiver::Seller => seller,
+                ItemReceiver::Authority => authority,
+            };
+
         processors::claim_core_asset(
             gumball_machine,
             index,
             authority_pda,
             payer,
-            if buyer.key() == Pubkey::default() {
-                seller
-            } else {
-                buyer
-            },
+            receiver,
             seller,
             asset,
             collection,
@@ -230,7 +235,7 @@ pub fn settle_core_asset_sale<'info>(
         fee_payment_account,
         payment_mint,
         &royalty_info,
-        &ctx.remaining_accounts,
+        ctx.remaining_accounts,
         associated_token_program,
         token_program,
         system_program,
@@ -2,7 +2,9 @@ use crate::{
     assert_config_line,
     constants::{AUTHORITY_SEED, MPL_TOKEN_AUTH_RULES_PROGRAM, SELLER_HISTORY_SEED},
     events::SettleItemSaleEvent,
-    processors::{self, claim_proceeds, is_item_claimed},
+    processors::{
+        self, claim_proceeds, get_unclaimed_item_receiver, is_item_claimed, ItemReceiver,
+    },
     state::GumballMachine,
     token_standard_from_mpl_token_standard, AssociatedToken, ConfigLine, GumballError,
     SellerHistory, Token, TokenStandard,
@@ -99,7 +101,7 @@ pub struct SettleNftSale<'info> {
     #[account(mut)]
     token_account: UncheckedAccount<'info>,
 
-    /// Nft token account for buyer
+    /// Nft token account for buyer (or the authority when unclaimed items are sent to it)
     /// CHECK: Safe due to ata check in transfer
     #[account(mut)]
     buyer_token_account: UncheckedAccount<'info>,
@@ -154,6 +156,7 @@ pub fn settle_nft_sale<'info>(
     let authority = &mut ctx.accounts.authority.to_account_info();
     let seller = &mut ctx.accounts.seller.to_account_info();
     let seller_for_to = &ctx.accounts.seller.to_account_info();
+    let authority_for_to = &ctx.accounts.authority.to_account_info();
     let token_metadata_program = &ctx.accounts.token_metadata_program.to_account_info();
     let token_account = &ctx.accounts.token_account.to_account_info();
     let token_program = &ctx.accounts.token_program.to_account_info();
@@ -262,21 +265,33 @@ pub fn settle_nft_sale<'info>(
     if !is_item_claimed(gumball_machine, index)? {
         amount = 1;
 
+        let (to, to_token_account, to_token_record) =
+            match get_unclaimed_item_receiver(gumball_machine, buyer.key(), authority_pda.key())? {
+                ItemReceiver::Buyer => (
+                    buyer,
+                    buyer_token_account,
+                    ctx.accounts.buyer_token_record.as_ref(),
+                ),
+                // Unclaimed items sent to the authority use the buyer token accounts
+                ItemReceiver::Authority => (
+                    authority_for_to,
+                    buyer_token_account,
+                    ctx.accounts.buyer_token_record.as_ref(),
+                ),
+                ItemReceiver::Seller => (
+                    seller_for_to,
+                    token_account,
+                    ctx.accounts.seller_token_record.as_ref(),
+                ),
+            };
+
         processors::claim_nft_v2(
             gumball_machine,
             index,
             authority_pda,
             payer,
-            if buyer.key() == Pubkey::default() {
-                seller_for_to
-            } else {
-                buyer
-            },
-            if buyer.key() == Pubkey::default() {
-                token_account
-            } else {
-                buyer_token_account
-            },
+            to,
+            to_token_account,
             seller,
             token_account,
             authority_pda_token_account,
@@ -292,11 +307,7 @@ pub fn settle_nft_sale<'info>(
             &auth_seeds,
             ctx.accounts.seller_token_record.as_ref(),
             ctx.accounts.authority_pda_token_record.as_ref(),
-            if buyer.key() == Pubkey::default() {
-                ctx.accounts.seller_token_record.as_ref()
-            } else {
-                ctx.accounts.buyer_token_record.as_ref()
-            },
+            to_token_record,
             ctx.accounts.auth_rules.as_ref(),
             ctx.accounts.instructions.as_ref(),
             ctx.accounts.auth_rules_program.as_ref(),
@@ -318,7 +329,7 @@ pub fn settle_nft_sale<'info>(
         fee_payment_account,
         payment_mint,
         &royalty_info,
-        &ctx.remaining_accounts,
+        ctx.remaining_accounts,
         associated_token_program,
         token_program,
         system_program,
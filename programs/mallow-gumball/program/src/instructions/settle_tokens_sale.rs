@@ -2,12 +2,18 @@ use crate::{
     assert_config_line,
     constants::{AUTHORITY_SEED, SELLER_HISTORY_SEED},
     events::SettleItemSaleEvent,
-    processors::{self, claim_proceeds, is_item_claimed},
+    get_fungible_token_program, get_fungible_token_standard, get_item_token_standard,
+    processors::{
+        self, claim_proceeds, get_unclaimed_item_receiver, is_item_claimed, ItemReceiver,
+    },
     state::GumballMachine,
     try_from, AssociatedToken, ConfigLine, GumballError, SellerHistory, Token, TokenStandard,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
 use utils::RoyaltyInfo;
 
 /// Settles a legacy NFT sale
@@ -73,6 +79,7 @@ pub struct SettleTokensSale<'info> {
 
     /// buyer of the item
     /// CHECK: Safe due to item check
+    #[account(mut)]
     buyer: UncheckedAccount<'info>,
 
     /// Fee account for marketplace fee if using fee config
@@ -92,15 +99,20 @@ pub struct SettleTokensSale<'info> {
     rent: Sysvar<'info, Rent>,
 
     /// CHECK: Safe due to item check
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Receiver token account, unused for native SOL prizes
     /// CHECK: Safe due to transfer check
     #[account(mut)]
     receiver_token_account: UncheckedAccount<'info>,
 
+    /// Authority PDA token account, unused for native SOL prizes
     /// CHECK: Safe due to transfer check
     #[account(mut)]
     authority_pda_token_account: UncheckedAccount<'info>,
+
+    /// Token-2022 program, only required if the item is a Token-2022 token
+    token_2022_program: Option<Program<'info, Token2022>>,
 }
 
 pub fn settle_tokens_sale<'info>(
@@ -119,6 +131,11 @@ pub fn settle_tokens_sale<'info>(
     let associated_token_program = &ctx.accounts.associated_token_program.to_account_info();
     let system_program = &ctx.accounts.system_program.to_account_info();
     let mint = &ctx.accounts.mint.to_account_info();
+    // Native SOL prizes are recorded with the native mint
+    let token_standard = match get_item_token_standard(gumball_machine, index)? {
+        TokenStandard::Sol => TokenStandard::Sol,
+        _ => get_fungible_token_standard(mint),
+    };
 
     assert_config_line(
         gumball_machine,
@@ -127,7 +144,7 @@ pub fn settle_tokens_sale<'info>(
             mint: mint.key(),
             seller: seller.key(),
             buyer: buyer.key(),
-            token_standard: TokenStandard::Fungible,
+            token_standard,
         },
         false,
     )?;
@@ -182,32 +199,56 @@ pub fn settle_tokens_sale<'info>(
 
     let mut amount = 0;
     if !is_item_claimed(gumball_machine, index)? {
-        let authority_pda_token_account = &mut Box::new(try_from!(
-            Account::<TokenAccount>,
-            ctx.accounts.authority_pda_token_account
-        )?);
-        amount = processors::claim_tokens(
-            gumball_machine,
-            index,
-            authority,
-            authority_pda,
-            payer,
-            if buyer.key() == Pubkey::default() {
-                seller
-            } else {
-                buyer
-            },
-            receiver_token_account,
-            authority_pda_token_account,
-            mint,
-            token_program,
-            associated_token_program,
-            system_program,
-            &auth_seeds,
-        )?;
+        let receiver: &AccountInfo<'info> =
+            match get_unclaimed_item_receiver(gumball_machine, buyer.key(), authority_pda.key())? {
+                ItemReceiver::Buyer => buyer,
+                ItemReceiver::Seller => seller,
+                ItemReceiver::Authority => authority,
+            };
+
+        if token_standard == TokenStandard::Sol {
+            amount = processors::claim_sol_prize(
+                gumball_machine,
+                index,
+                authority_pda,
+                receiver,
+                system_program,
+                &auth_seeds,
+            )?;
+        } else {
+            let authority_pda_token_account = &mut Box::new(try_from!(
+                InterfaceAccount::<TokenAccount>,
+                ctx.accounts.authority_pda_token_account
+            )?);
+            let token_2022_program = ctx
+                .accounts
+                .token_2022_program
+                .as_ref()
+                .map(|program| program.to_account_info());
+            let prize_token_program = get_fungible_token_program(
+                token_standard,
+                token_program,
+                token_2022_program.as_ref(),
+            )?;
+            amount = processors::claim_tokens(
+                gumball_machine,
+                index,
+                authority,
+                authority_pda,
+                payer,
+                receiver,
+                receiver_token_account,
+                authority_pda_token_account,
+                mint,
+                prize_token_program,
+                associated_token_program,
+                system_program,
+                &auth_seeds,
+            )?;
+        }
     }
 
-    // No royalties for tokens
+    // No royalties for tokens or SOL
     let royalty_info = RoyaltyInfo {
         is_primary_sale: false,
         seller_fee_basis_points: 0,
@@ -229,7 +270,7 @@ pub fn settle_tokens_sale<'info>(
         fee_payment_account,
         payment_mint,
         &royalty_info,
-        &ctx.remaining_accounts,
+        ctx.remaining_accounts,
         associated_token_program,
         token_program,
         system_program,
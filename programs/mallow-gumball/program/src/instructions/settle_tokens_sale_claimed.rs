@@ -1,14 +1,16 @@
 use crate::{
     constants::{AUTHORITY_SEED, GUMBALL_MACHINE_SIZE, SELLER_HISTORY_SEED},
     events::SettleItemSaleEvent,
-    get_config_count,
+    get_config_count, get_fungible_token_program, get_fungible_token_standard,
     processors::{get_total_proceeds, transfer_proceeds},
     state::GumballMachine,
     transfer_and_close_if_empty, try_from, AssociatedToken, GumballError, SellerHistory, Token,
-    TokenStandard,
 };
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
 use arrayref::array_ref;
 use utils::RoyaltyInfo;
 
@@ -82,7 +84,7 @@ pub struct SettleTokensSaleClaimed<'info> {
     rent: Sysvar<'info, Rent>,
 
     /// CHECK: Safe due to item check
-    mint: Box<Account<'info, Mint>>,
+    mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK: Safe due to transfer check
     #[account(mut)]
@@ -91,6 +93,9 @@ pub struct SettleTokensSaleClaimed<'info> {
     /// CHECK: Safe due to transfer check
     #[account(mut)]
     authority_pda_token_account: UncheckedAccount<'info>,
+
+    /// Token-2022 program, only required if the items are Token-2022 tokens
+    token_2022_program: Option<Program<'info, Token2022>>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -122,6 +127,7 @@ pub fn settle_tokens_sale_claimed<'info>(
     let system_program = &ctx.accounts.system_program.to_account_info();
     let mint = &ctx.accounts.mint.to_account_info();
     let mint_key = mint.key();
+    let expected_token_standard = get_fungible_token_standard(mint);
     let seller_key = seller.key();
 
     // Set up payment accounts
@@ -170,6 +176,7 @@ pub fn settle_tokens_sale_claimed<'info>(
 
     // Batch process all items in the span
     let mut total_unsold_tokens = 0_u64;
+    let mut total_weight = 0_u64;
     let total_items_settled = args.end_index - args.start_index + 1;
     let claimed_bit_mask_start = gumball_machine.get_claimed_items_bit_mask_position();
     let settled_bit_mask_start = gumball_machine.get_settled_items_bit_mask_position()?;
@@ -203,7 +210,7 @@ pub fn settle_tokens_sale_claimed<'info>(
         let token_standard =
             u8::from_le_bytes(*array_ref![account_data, config_line_position + 96, 1]);
         require!(
-            TokenStandard::Fungible as u8 == token_standard,
+            expected_token_standard as u8 == token_standard,
             GumballError::InvalidTokenStandard
         );
 
@@ -241,16 +248,21 @@ pub fn settle_tokens_sale_claimed<'info>(
 
         // Mark as settled
         account_data[settled_byte_position] |= settled_mask;
+
+        total_weight = total_weight
+            .checked_add(gumball_machine.get_item_weight(&account_data, idx)? as u64)
+            .ok_or(GumballError::NumericalOverflowError)?;
     }
 
     let mut total_proceeds_settled = gumball_machine.get_total_proceeds_settled(&account_data)?;
 
-    let (mut total_proceeds, _) =
-        get_total_proceeds(gumball_machine, total_proceeds_settled, config_count)?;
-
-    total_proceeds = total_proceeds
-        .checked_mul(total_items_settled as u64)
-        .ok_or(GumballError::NumericalOverflowError)?;
+    let (total_proceeds, _) = get_total_proceeds(
+        gumball_machine,
+        &account_data,
+        total_proceeds_settled,
+        config_count,
+        total_weight,
+    )?;
 
     if gumball_machine.version >= 5 {
         total_proceeds_settled = total_proceeds_settled
@@ -263,6 +275,14 @@ pub fn settle_tokens_sale_claimed<'info>(
             .copy_from_slice(&total_proceeds_settled.to_le_bytes());
     }
 
+    if gumball_machine.is_value_weighted() {
+        let total_weight_settled = gumball_machine
+            .get_total_weight_settled(&account_data)?
+            .checked_add(total_weight)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        gumball_machine.set_total_weight_settled(&mut account_data, total_weight_settled)?;
+    }
+
     // Done with the data borrow
     drop(account_data);
 
@@ -270,9 +290,19 @@ pub fn settle_tokens_sale_claimed<'info>(
     if total_unsold_tokens > 0 {
         // Convert the authority_pda_token_account to TokenAccount type
         let authority_pda_token_account = &mut Box::new(try_from!(
-            Account::<TokenAccount>,
+            InterfaceAccount::<TokenAccount>,
             ctx.accounts.authority_pda_token_account
         )?);
+        let token_2022_program = ctx
+            .accounts
+            .token_2022_program
+            .as_ref()
+            .map(|program| program.to_account_info());
+        let prize_token_program = get_fungible_token_program(
+            expected_token_standard,
+            token_program,
+            token_2022_program.as_ref(),
+        )?;
 
         transfer_and_close_if_empty(
             payer,
@@ -281,7 +311,7 @@ pub fn settle_tokens_sale_claimed<'info>(
             seller,
             seller_token_account,
             mint,
-            token_program,
+            prize_token_program,
             associated_token_program,
             system_program,
             seller,
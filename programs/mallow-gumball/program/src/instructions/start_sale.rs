@@ -9,7 +9,7 @@ pub struct StartSale<'info> {
     #[account(
         mut, 
         constraint = authority.key() == gumball_machine.authority || authority.key() == gumball_machine.mint_authority @ GumballError::InvalidAuthority,
-        constraint = gumball_machine.state != GumballState::SaleLive && gumball_machine.state != GumballState::SaleEnded @ GumballError::InvalidState
+        constraint = gumball_machine.state != GumballState::SaleLive && gumball_machine.state != GumballState::SaleEnded && gumball_machine.state != GumballState::Paused @ GumballError::InvalidState
     )]
     gumball_machine: Box<Account<'info, GumballMachine>>,
 
@@ -0,0 +1,82 @@
+use crate::{
+    constants::{AUTHORITY_SEED, GUMBALL_MACHINE_SIZE},
+    events::TransferPrizeEvent,
+    get_config_count, processors,
+    state::GumballMachine,
+    GumballError, GumballState,
+};
+use anchor_lang::prelude::*;
+
+/// Transfers the right to claim a drawn item to a new buyer.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferPrize<'info> {
+    /// Gumball machine account.
+    #[account(
+        mut,
+        constraint = gumball_machine.state == GumballState::SaleLive || gumball_machine.state == GumballState::SaleEnded || gumball_machine.state == GumballState::Paused @ GumballError::InvalidState
+    )]
+    gumball_machine: Box<Account<'info, GumballMachine>>,
+
+    /// Buyer of the item.
+    buyer: Signer<'info>,
+}
+
+pub fn transfer_prize(ctx: Context<TransferPrize>, index: u32, new_buyer: Pubkey) -> Result<()> {
+    let gumball_machine = &ctx.accounts.gumball_machine;
+    let buyer = &ctx.accounts.buyer;
+
+    require!(
+        new_buyer != Pubkey::default() && new_buyer != buyer.key(),
+        GumballError::InvalidBuyer
+    );
+
+    // items held by the authority PDA are represented by a receipt
+    let (authority_pda, _) = Pubkey::find_program_address(
+        &[AUTHORITY_SEED.as_bytes(), gumball_machine.key().as_ref()],
+        &crate::ID,
+    );
+    require!(new_buyer != authority_pda, GumballError::InvalidBuyer);
+
+    processors::assert_before_claim_deadline(gumball_machine)?;
+
+    require!(
+        !processors::is_item_claimed(gumball_machine, index)?,
+        GumballError::ItemAlreadyClaimed
+    );
+
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+    let count = get_config_count(&account_data)?;
+
+    if index >= count as u32 {
+        return err!(GumballError::IndexGreaterThanLength);
+    }
+
+    let config_line_position =
+        GUMBALL_MACHINE_SIZE + 4 + (index as usize) * gumball_machine.get_config_line_size();
+    let mint =
+        Pubkey::try_from(&account_data[config_line_position..config_line_position + 32]).unwrap();
+    let seller =
+        Pubkey::try_from(&account_data[config_line_position + 32..config_line_position + 64])
+            .unwrap();
+
+    let buyer_position = config_line_position + 64;
+    let current_buyer =
+        Pubkey::try_from(&account_data[buyer_position..buyer_position + 32]).unwrap();
+    require!(current_buyer == buyer.key(), GumballError::InvalidBuyer);
+
+    account_data[buyer_position..buyer_position + 32].copy_from_slice(&new_buyer.to_bytes());
+
+    drop(account_data);
+
+    emit_cpi!(TransferPrizeEvent {
+        mint,
+        authority: gumball_machine.authority,
+        seller,
+        buyer: buyer.key(),
+        new_buyer,
+    });
+
+    Ok(())
+}
@@ -39,9 +39,9 @@ pub fn update_settings(ctx: Context<UpdateSettings>, args: UpdateArgs) -> Result
 
     // uri and sellers_merkle_root can always be changed
 
-    // TODO: Allow decreasing capacity
+    // capacity changes require the account to be resized
     if settings.item_capacity != gumball_machine.settings.item_capacity {
-        msg!("Cannot update item capacity");
+        msg!("Cannot update item capacity, use resize_capacity instead");
         return err!(GumballError::InvalidSettingUpdate);
     }
 
@@ -1,4 +1,4 @@
-#![allow(clippy::result_large_err)]
+#![allow(clippy::result_large_err, clippy::too_many_arguments)]
 
 use anchor_lang::prelude::*;
 
@@ -44,6 +44,187 @@ pub mod mallow_gumball {
         instructions::update_settings(ctx, args)
     }
 
+    /// Sets the tier of the items in the range `[start_index, end_index]`.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_item_tiers(
+        ctx: Context<SetItemTiers>,
+        start_index: u32,
+        end_index: u32,
+        tier: u8,
+    ) -> Result<()> {
+        instructions::set_item_tiers(ctx, start_index, end_index, tier)
+    }
+
+    /// Sets the proceeds weight of the items in the range `[start_index, end_index]`.
+    /// Sale proceeds are split between sellers proportionally to the weight of their items.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_item_weights(
+        ctx: Context<SetItemWeights>,
+        start_index: u32,
+        end_index: u32,
+        weight: u32,
+    ) -> Result<()> {
+        instructions::set_item_weights(ctx, start_index, end_index, weight)
+    }
+
+    /// Sets the draw weight of each tier. The odds of drawing an item are proportional
+    /// to the weight of its tier.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_tier_weights(ctx: Context<SetTierWeights>, weights: [u16; 8]) -> Result<()> {
+        instructions::set_tier_weights(ctx, weights)
+    }
+
+    /// Sets the randomness provider used to draw items. When no provider is set, draws
+    /// are revealed using slot hashes.
+    ///
+    /// Every draw uses the configured randomness account, which must have an open request
+    /// when the draw is made. The draw is bound to that request and is only revealed with
+    /// its value; if the request is replaced, the draw cannot be revealed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_randomness_config(
+        ctx: Context<SetRandomnessConfig>,
+        randomness_config: RandomnessConfig,
+        randomness_request_config: RandomnessRequestConfig,
+    ) -> Result<()> {
+        instructions::set_randomness_config(ctx, randomness_config, randomness_request_config)
+    }
+
+    /// Sets how many seconds add item requests stay open before anyone can close them.
+    /// An expiry of 0 means requests never expire.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_add_item_request_expiry(
+        ctx: Context<SetAddItemRequestExpiry>,
+        expiry: i64,
+    ) -> Result<()> {
+        instructions::set_add_item_request_expiry(ctx, expiry)
+    }
+
+    /// Sets how many seconds after the sale ends the gumball machine can be force closed
+    /// without the mint authority. A grace period of 0 disables force closing. Once the sale
+    /// has started the grace period can only be increased.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_force_close_grace_period(
+        ctx: Context<SetForceCloseGracePeriod>,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::set_force_close_grace_period(ctx, grace_period)
+    }
+
+    /// Sets the deadline to claim drawn items, counted from the end of the sale. Once it has
+    /// passed, buyers can no longer claim their items and settling an unclaimed item sends it
+    /// to the configured destination instead, while the seller is paid as usual. Items held
+    /// by a receipt do not expire.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_claim_deadline(
+        ctx: Context<SetClaimDeadline>,
+        claim_deadline_config: ClaimDeadlineConfig,
+    ) -> Result<()> {
+        instructions::set_claim_deadline(ctx, claim_deadline_config)
+    }
+
+    /// Sets whether drawn items are transferred to the buyer when the draw is revealed,
+    /// instead of being claimed in a separate transaction. Items are only selected on
+    /// reveal, so the reveal transaction (sent by the buyer's client or a reveal crank)
+    /// passes the delivery accounts of the drawn items. Compressed NFTs cannot be delivered
+    /// this way, so instant delivery cannot be enabled on a machine that holds them, or on a
+    /// machine with receipts.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_instant_delivery(
+        ctx: Context<SetInstantDelivery>,
+        instant_delivery: bool,
+    ) -> Result<()> {
+        instructions::set_instant_delivery(ctx, instant_delivery)
+    }
+
+    /// Creates the Core collection of the draw receipts, with the authority PDA as its
+    /// update authority. Receipts use the name and URI of the collection.
+    ///
+    /// Once the collection exists, every revealed draw mints a receipt for each drawn item,
+    /// with the gumball machine, index and draw slot as attributes. The receipt becomes the
+    /// only way to claim the item, so it can be traded until it is burnt with
+    /// `claim_with_receipt`. Items held by a receipt cannot be settled until it is burnt.
+    /// Receipts cannot be used with instant delivery.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   2. `[signer, writable]` Gumball Machine authority (payer of the collection)
+    ///   3. `[signer, writable]` Receipt collection account
+    ///   4. `[]` MPL Core program
+    ///   5. `[]` System program
+    pub fn create_receipt_collection(
+        ctx: Context<CreateReceiptCollection>,
+        name: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_receipt_collection(ctx, name, uri)
+    }
+
+    /// Resizes the item capacity of the gumball machine, moving the data stored after
+    /// the config lines to its new position. The capacity can be decreased down to the
+    /// number of items loaded.
+    ///
+    /// The account can only grow by 10KiB per instruction, so a larger increase is done
+    /// over several calls with the same capacity: each call grows the account and the
+    /// data is moved by the last one.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer, writable]` Gumball Machine authority
+    ///   2. `[]` System program
+    pub fn resize_capacity(ctx: Context<ResizeCapacity>, item_capacity: u64) -> Result<()> {
+        instructions::resize_capacity(ctx, item_capacity)
+    }
+
+    /// Migrates the gumball machine account to the current version, upgrading config
+    /// lines and adding the fields of newer versions with default values.
+    ///
+    /// Accounts that need to grow by more than 10kb are resized over multiple calls,
+    /// the data is only migrated once the account reaches its final size.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer, writable]` Gumball Machine authority
+    ///   2. `[]` System program
+    pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate(ctx)
+    }
+
     /// Add legacy NFTs to the gumball machine.
     ///
     /// # Accounts
@@ -83,7 +264,29 @@ pub mod mallow_gumball {
         instructions::add_core_asset(ctx, args)
     }
 
+    /// Add multiple core assets to the gumball machine.
+    /// Validates the seller once and freezes each asset with the authority PDA as delegate.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[signer, writable]` Seller
+    ///   4. `[writable, optional]` Collection account shared by the assets
+    ///   5. `[]` MPL Core program
+    ///   6. `[]` System program
+    ///   Remaining accounts: Asset accounts (writable)
+    pub fn add_core_assets<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddCoreAssets<'info>>,
+        args: AddItemArgs,
+    ) -> Result<()> {
+        instructions::add_core_assets(ctx, args)
+    }
+
     /// Add fungible tokens to the gumball machine.
+    /// Token-2022 mints are supported, the seller pays any transfer fee so the gumball machine
+    /// receives the full amount. Non-transferable mints are rejected.
     ///
     /// # Accounts
     ///
@@ -94,7 +297,7 @@ pub mod mallow_gumball {
     ///   4. `[]` Mint account
     ///   5. `[writable]` Seller's token account
     ///   6. `[writable]` Gumball machine's token account
-    ///   7. `[]` Token program
+    ///   7. `[]` Token program (SPL Token or Token-2022)
     ///   8. `[]` Associated Token program
     ///   9. `[]` System program
     ///   10. `[]` Rent sysvar
@@ -107,6 +310,51 @@ pub mod mallow_gumball {
         instructions::add_tokens(ctx, amount, quantity, args)
     }
 
+    /// Add compressed nft to the gumball machine.
+    /// Transfers the leaf to the authority PDA, the config line records the asset id.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[signer, writable]` Seller
+    ///   4. `[writable]` Merkle tree account
+    ///   5. `[]` Tree config account
+    ///   6. `[]` Bubblegum program
+    ///   7. `[]` SPL Account Compression program
+    ///   8. `[]` SPL Noop program
+    ///   9. `[]` System program
+    ///   Remaining accounts: Merkle proof nodes
+    pub fn add_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddCompressedNft<'info>>,
+        args: AddItemArgs,
+        compressed_nft: CompressedNftArgs,
+    ) -> Result<()> {
+        instructions::add_compressed_nft(ctx, args, compressed_nft)
+    }
+
+    /// Add native SOL prizes to the gumball machine.
+    /// Transfers `amount * quantity` lamports to the authority PDA, the config lines record the native mint.
+    /// Each prize must be at least the rent-exempt minimum, and the lamports of SOL prizes are
+    /// tracked apart from the sale revenue so payouts of revenue cannot use them.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[signer, writable]` Seller
+    ///   4. `[]` System program
+    pub fn add_sol_prize(
+        ctx: Context<AddSolPrize>,
+        amount: u64,
+        quantity: u16,
+        args: AddItemArgs,
+    ) -> Result<()> {
+        instructions::add_sol_prize(ctx, amount, quantity, args)
+    }
+
     /// Request to add a NFT to the gumball machine.
     /// Freezes the seller's NFT and creates a request account.
     ///
@@ -194,8 +442,39 @@ pub mod mallow_gumball {
         instructions::cancel_add_core_asset_request(ctx)
     }
 
+    /// Close an expired request to add an item to the gumball machine. Can be called by anyone.
+    /// Thaws and revokes delegate from the seller's item, closes the request account and
+    /// refunds rent to the seller.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Seller history account (PDA, seeds: ["seller_history", add_item_request.gumball_machine, seller]).
+    ///   1. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", add_item_request.gumball_machine]).
+    ///   3. `[signer, writable]` Payer.
+    ///   4. `[writable]` Seller of the item.
+    ///   5. `[writable]` Asset account (or mint for NFTs).
+    ///   6. `[]` System program.
+    ///   7. `[writable, optional]` Collection account (Core asset).
+    ///   8. `[optional]` MPL Core program (Core asset).
+    ///   9. `[writable, optional]` Seller's token account (NFT).
+    ///   10. `[writable, optional]` Authority PDA's token account (NFT).
+    ///   11. `[optional]` Edition account (NFT).
+    ///   12. `[optional]` Token program (NFT).
+    ///   13. `[optional]` Associated Token program (NFT).
+    ///   14. `[optional]` Token Metadata program (NFT).
+    ///   15. `[writable, optional]` Metadata account (pNFT).
+    ///   16. `[writable, optional]` Seller token record (pNFT).
+    ///   17. `[optional]` Auth rules account (pNFT).
+    ///   18. `[optional]` Instructions sysvar (pNFT).
+    ///   19. `[optional]` Auth rules program (pNFT).
+    pub fn close_expired_add_item_request(ctx: Context<CloseExpiredAddItemRequest>) -> Result<()> {
+        instructions::close_expired_add_item_request(ctx)
+    }
+
     /// Approve adding an item to the gumball machine.
     /// Moves the item from the request to the gumball machine's config lines.
+    /// The authority can optionally set the proceeds weight of the item (defaults to 1).
     ///
     /// # Accounts
     ///
@@ -206,8 +485,38 @@ pub mod mallow_gumball {
     ///   4. `[writable]` Seller account (receiver of closed request account rent).
     ///   5. `[]` Asset/Mint account (checked via add_item_request constraint).
     ///   6. `[]` System program.
-    pub fn approve_add_item(ctx: Context<ApproveAddItem>) -> Result<()> {
-        instructions::approve_add_item(ctx)
+    pub fn approve_add_item(ctx: Context<ApproveAddItem>, weight: Option<u32>) -> Result<()> {
+        instructions::approve_add_item(ctx, weight)
+    }
+
+    /// Reject a request to add an item to the gumball machine.
+    /// Thaws and revokes the NFT or Core asset and closes the request account (rent to seller).
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Gumball Machine account.
+    ///   1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).
+    ///   2. `[writable]` Add item request account (PDA, seeds: ["add_item_request", asset]). Will be closed.
+    ///   3. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).
+    ///   4. `[signer, writable]` Authority of the gumball machine.
+    ///   5. `[writable]` Seller account (receiver of closed request account rent).
+    ///   6. `[writable]` Asset/Mint account (checked via add_item_request constraint).
+    ///   7. `[]` System program.
+    ///   8. `[writable, optional]` Collection account (Core asset).
+    ///   9. `[optional]` MPL Core program (Core asset).
+    ///   10. `[writable, optional]` Seller's token account (NFT).
+    ///   11. `[writable, optional]` Authority PDA's token account (NFT).
+    ///   12. `[optional]` Edition account (NFT).
+    ///   13. `[optional]` Token program (NFT).
+    ///   14. `[optional]` Associated Token program (NFT).
+    ///   15. `[optional]` Token Metadata program (NFT).
+    ///   16. `[writable, optional]` Metadata account (pNFT).
+    ///   17. `[writable, optional]` Seller token record (pNFT).
+    ///   18. `[optional]` Auth rules account (pNFT).
+    ///   19. `[optional]` Instructions sysvar (pNFT).
+    ///   20. `[optional]` Auth rules program (pNFT).
+    pub fn reject_add_item(ctx: Context<RejectAddItem>, reason: Option<u8>) -> Result<()> {
+        instructions::reject_add_item(ctx, reason)
     }
 
     /// Remove legacy NFT from the gumball machine.
@@ -239,6 +548,34 @@ pub mod mallow_gumball {
         instructions::remove_nft(ctx, index)
     }
 
+    /// Remove a span of legacy NFTs from the gumball machine.
+    /// Thaws and returns each NFT to its seller and compacts the config lines.
+    /// The signer can be the Gumball Machine authority or the seller of every item in the span.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account.
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).
+    ///   2. `[signer, writable]` Authority allowed to remove (gumball machine authority or item seller).
+    ///   3. `[]` Token program.
+    ///   4. `[]` Associated Token program.
+    ///   5. `[]` Token Metadata program.
+    ///   6. `[]` System program.
+    ///   7. `[]` Rent sysvar.
+    ///   8. `[optional]` Auth rules account (pNFT).
+    ///   9. `[optional]` Instructions sysvar (pNFT).
+    ///   10. `[optional]` Auth rules program (pNFT).
+    ///   Remaining accounts: For each item in the span, the seller (writable), seller history (writable),
+    ///   mint, seller's token account (writable), authority PDA's token account (writable), edition,
+    ///   metadata (writable) and seller token record (writable, pNFT only)
+    pub fn remove_nfts_span<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveNftsSpan<'info>>,
+        start_index: u32,
+        end_index: u32,
+    ) -> Result<()> {
+        instructions::remove_nfts_span(ctx, start_index, end_index)
+    }
+
     /// Remove Core asset from the gumball machine.
     /// Thaws and revokes delegate from the seller's asset and removes it from the config lines.
     /// The signer can be the Gumball Machine authority or the seller of the specific item.
@@ -258,6 +595,52 @@ pub mod mallow_gumball {
         instructions::remove_core_asset(ctx, index)
     }
 
+    /// Remove a span of Core assets from the gumball machine.
+    /// Thaws and returns each asset to its seller and compacts the config lines.
+    /// The signer can be the Gumball Machine authority or the seller of every item in the span.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account.
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).
+    ///   2. `[signer]` Authority allowed to remove (gumball machine authority or item seller).
+    ///   3. `[]` MPL Core program.
+    ///   4. `[]` System program.
+    ///   Remaining accounts: For each item in the span, the seller (writable), seller history (writable),
+    ///   asset (writable) and collection (writable, MPL Core program if the asset has none)
+    pub fn remove_core_assets_span<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveCoreAssetsSpan<'info>>,
+        start_index: u32,
+        end_index: u32,
+    ) -> Result<()> {
+        instructions::remove_core_assets_span(ctx, start_index, end_index)
+    }
+
+    /// Remove compressed nft from the gumball machine.
+    /// The signer can be the Gumball Machine authority or the seller of the specific item.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account.
+    ///   1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller]).
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine]).
+    ///   3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).
+    ///   4. `[writable]` Seller account (owner of the compressed nft).
+    ///   5. `[writable]` Merkle tree account.
+    ///   6. `[]` Tree config account.
+    ///   7. `[]` Bubblegum program.
+    ///   8. `[]` SPL Account Compression program.
+    ///   9. `[]` SPL Noop program.
+    ///   10. `[]` System program.
+    ///   Remaining accounts: Merkle proof nodes
+    pub fn remove_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveCompressedNft<'info>>,
+        index: u32,
+        compressed_nft: CompressedNftArgs,
+    ) -> Result<()> {
+        instructions::remove_compressed_nft(ctx, index, compressed_nft)
+    }
+
     /// Remove fungible tokens from the gumball machine.
     /// The signer can be the Gumball Machine authority or the seller of the specific item.
     ///
@@ -271,7 +654,7 @@ pub mod mallow_gumball {
     ///   5. `[]` Mint account
     ///   6. `[writable]` Seller's token account
     ///   7. `[writable]` Gumball machine's token account
-    ///   8. `[]` Token program
+    ///   8. `[]` Token program (SPL Token or Token-2022)
     ///   9. `[]` Associated Token program
     ///   10. `[]` System program
     ///   11. `[]` Rent sysvar
@@ -293,7 +676,7 @@ pub mod mallow_gumball {
     ///   5. `[]` Mint account
     ///   6. `[writable]` Seller's token account
     ///   7. `[writable]` Gumball machine's token account
-    ///   8. `[]` Token program
+    ///   8. `[]` Token program (SPL Token or Token-2022)
     ///   9. `[]` Associated Token program
     ///   10. `[]` System program
     ///   11. `[]` Rent sysvar
@@ -306,6 +689,26 @@ pub mod mallow_gumball {
         instructions::remove_tokens_span(ctx, amount, start_index, end_index)
     }
 
+    /// Remove native SOL prizes from the gumball machine, returning their lamports to the seller.
+    /// The signer can be the Gumball Machine authority or the seller of the prizes.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[signer]` Authority allowed to remove (gumball machine authority or item seller).
+    ///   4. `[writable]` Seller account
+    ///   5. `[]` System program
+    pub fn remove_sol_prize(
+        ctx: Context<RemoveSolPrize>,
+        amount: u64,
+        start_index: u32,
+        end_index: u32,
+    ) -> Result<()> {
+        instructions::remove_sol_prize(ctx, amount, start_index, end_index)
+    }
+
     /// Allows minting to begin.
     ///
     /// # Accounts
@@ -316,6 +719,27 @@ pub mod mallow_gumball {
         instructions::start_sale(ctx)
     }
 
+    /// Pauses a live sale. Items cannot be drawn, sold back or added while paused, but
+    /// drawn items can still be claimed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority (authority or mint_authority)
+    pub fn pause_sale(ctx: Context<PauseSale>) -> Result<()> {
+        instructions::pause_sale(ctx)
+    }
+
+    /// Resumes a paused sale.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority (authority or mint_authority)
+    pub fn resume_sale(ctx: Context<ResumeSale>) -> Result<()> {
+        instructions::resume_sale(ctx)
+    }
+
     /// Disables minting and allows sales to be settled.
     ///
     /// # Accounts
@@ -328,19 +752,132 @@ pub mod mallow_gumball {
 
     /// Draw for a random item from the gumball machine.
     /// Only the gumball machine mint authority is allowed to draw.
+    /// The item is reserved for the buyer and selected once the draw is revealed.
+    ///
+    /// The pending draw account is derived from the number of draws made on the gumball
+    /// machine (`draw_number`, little-endian u64), so a buyer can have several draws pending.
     ///
     /// # Accounts
     ///
     ///   0. `[writable]` Gumball Machine account
-    ///   1. `[signer]` Gumball Machine mint authority
-    ///   2. `[signer, writable]` Payer
-    ///   3. `[]` Buyer account
-    ///   4. `[]` System program
-    ///   5. `[]` SlotHashes sysvar cluster data
+    ///   1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])
+    ///   2. `[signer]` Gumball Machine mint authority
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[]` Buyer account
+    ///   5. `[]` System program
+    ///   6. `[optional]` Randomness account (required when a randomness provider is set)
     pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
         instructions::draw(ctx)
     }
 
+    /// Draw multiple random items from the gumball machine for the same buyer.
+    /// Only the gumball machine mint authority is allowed to draw.
+    /// The items are reserved for the buyer and selected once the draw is revealed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])
+    ///   2. `[signer]` Gumball Machine mint authority
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[]` Buyer account
+    ///   5. `[]` System program
+    ///   6. `[optional]` Randomness account (required when a randomness provider is set)
+    pub fn draw_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, Draw<'info>>,
+        quantity: u16,
+    ) -> Result<()> {
+        instructions::draw_many(ctx, quantity)
+    }
+
+    /// Assigns a specific available item to the buyer, skipping the random draw. Only
+    /// the mint authority (gumball guard) can buy specific items, where the payment is
+    /// added to the total revenue. Items cannot be bought while draws are pending reveal.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine mint authority
+    ///   2. `[]` Buyer account
+    pub fn buy_specific<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuySpecific<'info>>,
+        index: u32,
+    ) -> Result<()> {
+        instructions::buy_specific(ctx, index)
+    }
+
+    /// Gives back an unclaimed item drawn by the buyer and reserves a new item in its
+    /// place, to be revealed like a regular draw. Only the mint authority (gumball
+    /// guard) can reroll, so a fee can be charged through a guard group. Items cannot be
+    /// rerolled while draws are pending reveal.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number])
+    ///   2. `[signer]` Gumball Machine mint authority
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[signer]` Buyer of the rerolled item
+    ///   5. `[]` System program
+    ///   6. `[optional]` Randomness account (required when a randomness provider is set)
+    pub fn reroll<'info>(ctx: Context<'_, '_, '_, 'info, Reroll<'info>>, index: u32) -> Result<()> {
+        instructions::reroll(ctx, index)
+    }
+
+    /// Reveals a pending draw using the hash of its target slot and assigns the
+    /// reserved items to the buyer. Anyone can reveal a draw, but draws are revealed in
+    /// the order they were made.
+    ///
+    /// The seed of the draw is the slot hash (or provider value) hashed with the pending
+    /// draw address. Once the target slot is no longer in the SlotHashes sysvar (~512 slots),
+    /// the draw is moved to a new target slot and revealed again later.
+    ///
+    /// When instant delivery is enabled, the drawn items are also transferred to the buyer,
+    /// so the delivery accounts of every drawn item must be passed as remaining accounts.
+    /// When receipts are enabled instead, a receipt is minted to the buyer for every drawn
+    /// item, so the receipt accounts must be passed as remaining accounts.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number]). Will be closed.
+    ///   2. `[writable]` Payer of the pending draw account (receiver of closed account rent)
+    ///   3. `[]` SlotHashes sysvar cluster data
+    ///   4. `[optional]` Randomness account (required when the draw uses a randomness provider)
+    pub fn reveal_draw<'info>(ctx: Context<'_, '_, 'info, 'info, RevealDraw<'info>>) -> Result<()> {
+        instructions::reveal_draw(ctx)
+    }
+
+    /// Cancels the next pending draw of an abandoned gumball machine once the force close
+    /// grace period has passed, so that its items can be settled and the machine closed.
+    /// Anyone can cancel a draw, but only draws made with a randomness provider whose
+    /// request was replaced or never fulfilled: those can no longer be revealed.
+    ///
+    /// The reserved items are returned to the pool and the price of the draw (net of the
+    /// marketplace fee and of the share already paid out to settled items) is refunded to
+    /// the buyer.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Pending draw account (PDA, seeds: ["pending_draw", gumball_machine, draw_number]). Will be closed.
+    ///   2. `[writable]` Payer of the pending draw account (receiver of closed account rent)
+    ///   3. `[writable]` Buyer of the draw (receiver of the refund)
+    ///   4. `[signer, writable]` Payer of the buyer's payment account
+    ///   5. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   6. `[writable, optional]` Authority PDA payment account
+    ///   7. `[writable, optional]` Buyer payment account
+    ///   8. `[optional]` Payment mint
+    ///   9. `[]` Randomness account of the draw
+    ///   10. `[]` Token program
+    ///   11. `[]` Associated Token program
+    ///   12. `[]` System program
+    pub fn cancel_pending_draw<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelPendingDraw<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_pending_draw(ctx)
+    }
+
     /// Increments total revenue earned by the gumball machine.
     ///
     /// Only the gumball machine mint authority is allowed to increment revenue. This is
@@ -394,13 +931,82 @@ pub mod mallow_gumball {
     ///   26. `[optional]` Auth rules account (for pNFT)
     ///   27. `[optional]` Instructions sysvar (for pNFT)
     ///   28. `[optional]` Auth rules program (for pNFT)
+    ///   29. `[optional]` Token-2022 program (for Token-2022 tokens)
+    ///   30. `[writable, optional]` Merkle tree account (for compressed NFT)
+    ///   31. `[optional]` Tree config account (for compressed NFT)
+    ///   32. `[optional]` Bubblegum program (for compressed NFT)
+    ///   33. `[optional]` SPL Account Compression program (for compressed NFT)
+    ///   34. `[optional]` SPL Noop program (for compressed NFT)
+    ///   Remaining accounts: Merkle proof nodes (for compressed NFT)
     pub fn sell_item<'info>(
         ctx: Context<'_, '_, '_, 'info, SellItem<'info>>,
         index: u32,
         amount: u64,
         buy_price: u64,
+        compressed_nft: Option<CompressedNftArgs>,
     ) -> Result<()> {
-        instructions::sell_item(ctx, index, amount, buy_price)
+        instructions::sell_item(ctx, index, amount, buy_price, compressed_nft)
+    }
+
+    /// Burns a draw receipt and delivers the item to the owner of the receipt. Receipts do
+    /// not expire: the item can be claimed after the claim deadline.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account (must be in SaleLive, SaleEnded or Paused state)
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   2. `[writable]` Authority account
+    ///   3. `[writable]` Seller account
+    ///   4. `[signer, writable]` Owner of the receipt
+    ///   5. `[writable]` Receipt asset account
+    ///   6. `[writable]` Receipt collection account
+    ///   7. `[writable]` Asset account (mint for NFTs and tokens, native mint for SOL prizes)
+    ///   8. `[]` MPL Core program
+    ///   9. `[]` System program
+    ///   10. `[writable, optional]` Collection account (Core asset)
+    ///   11. `[writable, optional]` Owner's token account (NFT and tokens)
+    ///   12. `[writable, optional]` Seller's token account (NFT)
+    ///   13. `[writable, optional]` Authority PDA's token account (NFT and tokens)
+    ///   14. `[optional]` Token program (NFT and tokens)
+    ///   15. `[optional]` Associated Token program (NFT and tokens)
+    ///   16. `[optional]` Token-2022 program (Token-2022 tokens)
+    ///   17. `[writable, optional]` Edition account (NFT)
+    ///   18. `[writable, optional]` Metadata account (NFT)
+    ///   19. `[optional]` Token Metadata program (NFT)
+    ///   20. `[writable, optional]` Seller token record (pNFT)
+    ///   21. `[writable, optional]` Authority PDA token record (pNFT)
+    ///   22. `[writable, optional]` Owner token record (pNFT)
+    ///   23. `[optional]` Auth rules account (pNFT)
+    ///   24. `[optional]` Instructions sysvar (pNFT)
+    ///   25. `[optional]` Auth rules program (pNFT)
+    ///   26. `[writable, optional]` Merkle tree account (compressed NFT)
+    ///   27. `[optional]` Tree config account (compressed NFT)
+    ///   28. `[optional]` Bubblegum program (compressed NFT)
+    ///   29. `[optional]` SPL Account Compression program (compressed NFT)
+    ///   30. `[optional]` SPL Noop program (compressed NFT)
+    ///   Remaining accounts: Merkle proof nodes (compressed NFT)
+    pub fn claim_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimWithReceipt<'info>>,
+        index: u32,
+        compressed_nft: Option<CompressedNftArgs>,
+    ) -> Result<()> {
+        instructions::claim_with_receipt(ctx, index, compressed_nft)
+    }
+
+    /// Transfers the right to claim a drawn item to a new buyer, who can then claim it
+    /// or sell it back like the original buyer. The new buyer cannot be the authority PDA,
+    /// which holds the items represented by a receipt.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account (must be in SaleLive, SaleEnded or Paused state)
+    ///   1. `[signer]` Buyer of the item
+    pub fn transfer_prize(
+        ctx: Context<TransferPrize>,
+        index: u32,
+        new_buyer: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_prize(ctx, index, new_buyer)
     }
 
     /// Claims a Core asset from the gumball machine for a specific buyer.
@@ -467,7 +1073,7 @@ pub mod mallow_gumball {
     ///   3. `[writable]` Gumball Machine authority
     ///   4. `[writable]` Seller account
     ///   5. `[]` Buyer account
-    ///   6. `[]` Token program
+    ///   6. `[]` Token program (SPL Token or Token-2022)
     ///   7. `[]` Associated Token program
     ///   8. `[]` System program
     ///   9. `[]` Rent sysvar
@@ -481,8 +1087,53 @@ pub mod mallow_gumball {
         instructions::claim_tokens(ctx, index)
     }
 
+    /// Claims a compressed NFT from the gumball machine for a specific buyer.
+    /// Transfers the leaf from the PDA to the buyer.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[signer, writable]` Payer (anyone can claim the item)
+    ///   1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[writable]` Seller account
+    ///   4. `[]` Buyer account
+    ///   5. `[writable]` Merkle tree account
+    ///   6. `[]` Tree config account
+    ///   7. `[]` Bubblegum program
+    ///   8. `[]` SPL Account Compression program
+    ///   9. `[]` SPL Noop program
+    ///   10. `[]` System program
+    ///   Remaining accounts: Merkle proof nodes
+    pub fn claim_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimCompressedNft<'info>>,
+        index: u32,
+        compressed_nft: CompressedNftArgs,
+    ) -> Result<()> {
+        instructions::claim_compressed_nft(ctx, index, compressed_nft)
+    }
+
+    /// Claims a native SOL prize from the gumball machine for a specific buyer.
+    /// Transfers the lamports from the PDA to the buyer.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[signer, writable]` Payer (anyone can claim the prize)
+    ///   1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[]` Seller account
+    ///   4. `[writable]` Buyer account
+    ///   5. `[]` System program
+    pub fn claim_sol_prize<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimSolPrize<'info>>,
+        index: u32,
+    ) -> Result<()> {
+        instructions::claim_sol_prize(ctx, index)
+    }
+
     /// Settles a Core asset sale
     /// If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).
+    /// Once the claim deadline has passed, unclaimed items are sent to its destination instead.
+    /// Items held by a receipt cannot be settled until the receipt is burnt.
     /// Distributes proceeds according to royalties and fee configuration.
     ///
     /// # Accounts
@@ -517,6 +1168,8 @@ pub mod mallow_gumball {
 
     /// Settles a legacy NFT sale
     /// If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).
+    /// Once the claim deadline has passed, unclaimed items are sent to its destination instead.
+    /// Items held by a receipt cannot be settled until the receipt is burnt.
     /// Distributes proceeds according to royalties and fee configuration. Marks primary sale happened if applicable.
     ///
     /// # Accounts
@@ -539,14 +1192,14 @@ pub mod mallow_gumball {
     ///   15. `[]` System program
     ///   16. `[]` Rent sysvar
     ///   17. `[]` Mint account
-    ///   18. `[writable]` Buyer's token account
+    ///   18. `[writable]` Buyer's token account (authority's if the unclaimed item is sent to it)
     ///   19. `[writable]` Authority PDA's token account
     ///   20. `[writable]` Metadata account
     ///   21. `[writable]` Edition account
     ///   22. `[]` Token Metadata program
     ///   23. `[writable, optional]` Seller token record (pNFT)
     ///   24. `[writable, optional]` Authority PDA token record (pNFT)
-    ///   25. `[writable, optional]` Buyer token record (pNFT)
+    ///   25. `[writable, optional]` Buyer token record (pNFT, authority's if the unclaimed item is sent to it)
     ///   26. `[optional]` Auth rules account (pNFT)
     ///   27. `[optional]` Instructions sysvar (pNFT)
     ///   28. `[optional]` Auth rules program (pNFT)
@@ -560,6 +1213,9 @@ pub mod mallow_gumball {
 
     /// Settles a fungible tokens sale
     /// If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).
+    /// Once the claim deadline has passed, unclaimed items are sent to its destination instead.
+    /// Items held by a receipt cannot be settled until the receipt is burnt.
+    /// Native SOL prizes are settled with the native mint and paid out in lamports, the token accounts are unused.
     /// Distributes proceeds according to fee configuration.
     ///
     /// # Accounts
@@ -573,7 +1229,7 @@ pub mod mallow_gumball {
     ///   6. `[writable]` Seller account
     ///   7. `[writable, optional]` Seller payment account
     ///   8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
-    ///   9. `[]` Buyer account
+    ///   9. `[writable]` Buyer account
     ///   10. `[writable, optional]` Fee account
     ///   11. `[writable, optional]` Fee payment account
     ///   12. `[optional]` Payment mint
@@ -582,8 +1238,9 @@ pub mod mallow_gumball {
     ///   15. `[]` System program
     ///   16. `[]` Rent sysvar
     ///   17. `[]` Mint account
-    ///   18. `[writable]` Receiver's token account (buyer or seller if buyer is default)
+    ///   18. `[writable]` Receiver's token account (buyer, seller if buyer is default or the claim deadline destination)
     ///   19. `[writable]` Authority PDA's token account
+    ///   20. `[optional]` Token-2022 program (for Token-2022 tokens)
     ///   Remaining accounts: Fee recipients
     pub fn settle_tokens_sale<'info>(
         ctx: Context<'_, '_, '_, 'info, SettleTokensSale<'info>>,
@@ -615,6 +1272,7 @@ pub mod mallow_gumball {
     ///   14. `[]` Mint account
     ///   15. `[writable]` Seller's token account (for receiving unsold tokens)
     ///   16. `[writable]` Authority PDA's token account
+    ///   17. `[optional]` Token-2022 program (for Token-2022 tokens)
     ///   Remaining accounts: Fee recipients
     pub fn settle_tokens_sale_claimed<'info>(
         ctx: Context<'_, '_, '_, 'info, SettleTokensSaleClaimed<'info>>,
@@ -623,6 +1281,83 @@ pub mod mallow_gumball {
         instructions::settle_tokens_sale_claimed(ctx, args)
     }
 
+    /// Returns an unsold item to its seller after the sale has ended, without waiting for the
+    /// authority to settle it. The item is settled with zero proceeds, its share of the revenue
+    /// is left to the remaining items. Compressed NFTs must be settled with `settle_compressed_nft_sale`.
+    ///
+    /// Gumball machines older than version 5 must be migrated first.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account (must be in SaleEnded state)
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   2. `[writable]` Authority account
+    ///   3. `[signer, writable]` Seller account
+    ///   4. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
+    ///   5. `[writable]` Asset account (mint for NFTs and tokens, native mint for SOL prizes)
+    ///   6. `[]` System program
+    ///   7. `[writable, optional]` Collection account (Core asset)
+    ///   8. `[optional]` MPL Core program (Core asset)
+    ///   9. `[writable, optional]` Seller's token account (NFT and tokens)
+    ///   10. `[writable, optional]` Authority PDA's token account (NFT and tokens)
+    ///   11. `[optional]` Token program (NFT and tokens)
+    ///   12. `[optional]` Associated Token program (NFT and tokens)
+    ///   13. `[optional]` Token-2022 program (Token-2022 tokens)
+    ///   14. `[writable, optional]` Edition account (NFT)
+    ///   15. `[writable, optional]` Metadata account (NFT)
+    ///   16. `[optional]` Token Metadata program (NFT)
+    ///   17. `[writable, optional]` Seller token record (pNFT)
+    ///   18. `[writable, optional]` Authority PDA token record (pNFT)
+    ///   19. `[optional]` Auth rules account (pNFT)
+    ///   20. `[optional]` Instructions sysvar (pNFT)
+    ///   21. `[optional]` Auth rules program (pNFT)
+    pub fn reclaim_unsold_item<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimUnsoldItem<'info>>,
+        index: u32,
+    ) -> Result<()> {
+        instructions::reclaim_unsold_item(ctx, index)
+    }
+
+    /// Settles a compressed NFT sale
+    /// If the item hasn't been claimed yet, it claims it for the seller (or buyer if specified).
+    /// Once the claim deadline has passed, unclaimed items are sent to its destination instead.
+    /// Items held by a receipt cannot be settled until the receipt is burnt.
+    /// Otherwise the current leaf is verified against the merkle tree, a burnt leaf pays no royalties.
+    /// Distributes proceeds according to royalties and fee configuration.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[signer, writable]` Payer (anyone can settle the sale)
+    ///   1. `[writable]` Gumball Machine account (must be in SaleEnded state)
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[writable, optional]` Authority PDA payment account
+    ///   4. `[writable]` Authority account
+    ///   5. `[writable, optional]` Authority payment account
+    ///   6. `[writable]` Seller account
+    ///   7. `[writable, optional]` Seller payment account
+    ///   8. `[writable]` Seller history account (PDA, seeds: ["seller_history", gumball_machine, seller])
+    ///   9. `[]` Buyer account
+    ///   10. `[writable, optional]` Fee account
+    ///   11. `[writable, optional]` Fee payment account
+    ///   12. `[optional]` Payment mint
+    ///   13. `[]` Token program
+    ///   14. `[]` Associated Token program
+    ///   15. `[]` System program
+    ///   16. `[]` Rent sysvar
+    ///   17. `[writable]` Merkle tree account
+    ///   18. `[]` Tree config account
+    ///   19. `[]` Bubblegum program
+    ///   20. `[]` SPL Account Compression program
+    ///   21. `[]` SPL Noop program
+    ///   Remaining accounts: Merkle proof nodes (proof_length), then royalty recipients
+    pub fn settle_compressed_nft_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleCompressedNftSale<'info>>,
+        index: u32,
+        args: SettleCompressedNftSaleArgs,
+    ) -> Result<()> {
+        instructions::settle_compressed_nft_sale(ctx, index, args)
+    }
+
     /// Set a new authority of the gumball machine.
     ///
     /// # Accounts
@@ -668,6 +1403,33 @@ pub mod mallow_gumball {
         instructions::close_gumball_machine(ctx)
     }
 
+    /// Withdraw the rent lamports of an abandoned gumball machine without the mint authority's
+    /// signature. Only available once the force close grace period has passed since the sale
+    /// ended and every item has been settled.
+    ///
+    /// Every step before it is permissionless once the grace period has passed: pending draws
+    /// that can no longer be revealed are cancelled with `cancel_pending_draw`, and the
+    /// `settle_*_sale` instructions return unclaimed prizes to their buyers and unsold items
+    /// to their sellers.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account (will be closed)
+    ///   1. `[signer, writable]` Gumball Machine authority
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", gumball_machine])
+    ///   3. `[writable, optional]` Authority PDA payment account
+    ///   4. `[]` Token program
+    ///   Remaining accounts (if closing non-native payment account):
+    ///     - `[]` Payment Mint
+    ///     - `[writable]` Authority's token account for payment mint
+    ///     - `[]` Associated Token program
+    ///     - `[]` System program
+    pub fn force_close_gumball_machine<'info>(
+        ctx: Context<'_, '_, '_, 'info, ForceCloseGumballMachine<'info>>,
+    ) -> Result<()> {
+        instructions::force_close_gumball_machine(ctx)
+    }
+
     /// Manage the buy back funds of the gumball machine.
     ///
     /// # Accounts
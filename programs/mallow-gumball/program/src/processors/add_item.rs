@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::GUMBALL_MACHINE_SIZE, get_bit_byte_info, get_config_count, ConfigLineV2Input,
-    GumballError, GumballMachine, GumballState, TokenStandard,
+    constants::GUMBALL_MACHINE_SIZE,
+    get_bit_byte_info, get_config_count,
+    processors::{insert_item_into_tier, restore_item_to_tier},
+    ConfigLineV2Input, GumballError, GumballMachine, GumballState, TokenStandard,
 };
 
 pub fn add_item(
@@ -12,6 +14,8 @@ pub fn add_item(
     quantity: u16,
     // Populate to indicate re-adding an item
     re_add_index: Option<u32>,
+    // Proceeds weight of new items (re-added items keep their weight)
+    weight: u32,
 ) -> Result<()> {
     let is_re_add = re_add_index.is_some();
     if is_re_add {
@@ -52,6 +56,11 @@ pub fn add_item(
     let indices_start = gumball_machine.get_mint_indices_position()?;
     let claimed_items_bit_mask_start = gumball_machine.get_claimed_items_bit_mask_position();
     let settled_items_bit_mask_start = gumball_machine.get_settled_items_bit_mask_position()?;
+    // pending items are still part of the available indices until revealed
+    let items_pending = gumball_machine.get_items_pending(&data)? as usize;
+    let mut tier_config = gumball_machine.get_tier_config(&data)?;
+    // weight of the re-added items, which are no longer settled
+    let mut re_added_weight = 0u64;
 
     for i in 0..quantity {
         let mint_slice: &mut [u8] = &mut data[position..position + 32];
@@ -85,15 +94,49 @@ pub fn add_item(
         // add the new index to the mint indices vec
         let item_index = index + i as u32;
 
-        let index_position = if is_re_add {
-            // Mint indices will be as long as config count - redeemed items and we need to add to the end
-            indices_start
-                + ((config_count - (gumball_machine.items_redeemed as usize) + (i as usize)) * 4)
+        if gumball_machine.is_tiered() {
+            // new items start in the first tier, re-added items keep their tier
+            if is_re_add {
+                let tier = data[position];
+                restore_item_to_tier(
+                    gumball_machine,
+                    &mut data,
+                    &mut tier_config,
+                    tier,
+                    item_index,
+                )?;
+            } else {
+                insert_item_into_tier(gumball_machine, &mut data, &mut tier_config, 0, item_index)?;
+            }
         } else {
-            indices_start + (item_index as usize) * 4
-        };
+            let index_position = if is_re_add {
+                // Mint indices will be as long as config count - redeemed items + pending items and we need to add to the end
+                indices_start
+                    + ((config_count - (gumball_machine.items_redeemed as usize)
+                        + items_pending
+                        + (i as usize))
+                        * 4)
+            } else {
+                indices_start + (item_index as usize) * 4
+            };
+
+            data[index_position..index_position + 4].copy_from_slice(&u32::to_le_bytes(item_index));
+        }
+
+        if gumball_machine.is_tiered() {
+            // skip tier (+1)
+            position += 1;
+        }
 
-        data[index_position..index_position + 4].copy_from_slice(&u32::to_le_bytes(item_index));
+        if gumball_machine.is_value_weighted() {
+            if is_re_add {
+                re_added_weight +=
+                    u32::from_le_bytes(data[position..position + 4].try_into().unwrap()) as u64;
+            } else {
+                data[position..position + 4].copy_from_slice(&weight.to_le_bytes());
+            }
+            position += 4;
+        }
 
         // Make sure item is claimed and settled, and reset to false
         if is_re_add {
@@ -115,6 +158,31 @@ pub fn add_item(
         }
     }
 
+    if gumball_machine.is_tiered() {
+        gumball_machine.set_tier_config(&mut data, &tier_config)?;
+    }
+
+    if gumball_machine.is_value_weighted() {
+        if is_re_add {
+            let total_weight_settled = gumball_machine
+                .get_total_weight_settled(&data)?
+                .checked_sub(re_added_weight)
+                .ok_or(GumballError::NumericalOverflowError)?;
+            gumball_machine.set_total_weight_settled(&mut data, total_weight_settled)?;
+        } else {
+            let total_weight = (weight as u64)
+                .checked_mul(quantity.into())
+                .and_then(|added_weight| {
+                    gumball_machine
+                        .get_total_weight(&data)
+                        .ok()?
+                        .checked_add(added_weight)
+                })
+                .ok_or(GumballError::NumericalOverflowError)?;
+            gumball_machine.set_total_weight(&mut data, total_weight)?;
+        }
+    }
+
     if is_re_add {
         gumball_machine.items_redeemed = gumball_machine
             .items_redeemed
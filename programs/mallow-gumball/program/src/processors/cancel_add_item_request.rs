@@ -0,0 +1,94 @@
+use crate::{
+    thaw_and_revoke_core_asset, thaw_and_revoke_nft_v2, GumballError, SellerHistory, TokenStandard,
+};
+use anchor_lang::prelude::*;
+
+/// Accounts used to return the item of an add item request to its seller. Only the
+/// accounts of the token standard of the request are required.
+pub struct CancelAddItemRequestParams<'a: 'b, 'b> {
+    pub payer: &'b AccountInfo<'a>,
+    pub seller: &'b AccountInfo<'a>,
+    pub asset: &'b AccountInfo<'a>,
+    pub authority_pda: &'b AccountInfo<'a>,
+    pub auth_seeds: &'b [&'b [u8]],
+    pub system_program: &'b AccountInfo<'a>,
+    // Core assets
+    pub collection: Option<&'b AccountInfo<'a>>,
+    pub mpl_core_program: Option<&'b AccountInfo<'a>>,
+    // NFTs
+    pub token_account: Option<&'b AccountInfo<'a>>,
+    pub authority_pda_token_account: Option<&'b AccountInfo<'a>>,
+    pub edition: Option<&'b AccountInfo<'a>>,
+    pub token_program: Option<&'b AccountInfo<'a>>,
+    pub associated_token_program: Option<&'b AccountInfo<'a>>,
+    pub token_metadata_program: Option<&'b AccountInfo<'a>>,
+    // pNFTs
+    pub metadata: Option<&'b AccountInfo<'a>>,
+    pub seller_token_record: Option<&'b UncheckedAccount<'a>>,
+    pub auth_rules: Option<&'b UncheckedAccount<'a>>,
+    pub instructions: Option<&'b UncheckedAccount<'a>>,
+    pub auth_rules_program: Option<&'b UncheckedAccount<'a>>,
+}
+
+/// Thaws the item of an add item request and revokes the authority PDA as its delegate,
+/// then releases the seller's slot in the gumball machine.
+pub fn cancel_add_item_request<'a: 'b, 'b>(
+    token_standard: TokenStandard,
+    params: CancelAddItemRequestParams<'a, 'b>,
+    seller_history: &mut Account<'a, SellerHistory>,
+) -> Result<()> {
+    match token_standard {
+        TokenStandard::Core => {
+            thaw_and_revoke_core_asset(
+                params.payer,
+                params.seller,
+                params.asset,
+                params.collection,
+                params.authority_pda,
+                params.auth_seeds,
+                params
+                    .mpl_core_program
+                    .ok_or(GumballError::MissingAccount)?,
+                params.system_program,
+            )?;
+        }
+        TokenStandard::NonFungible | TokenStandard::ProgrammableNonFungible => {
+            thaw_and_revoke_nft_v2(
+                params.payer,
+                params.seller,
+                params.asset,
+                params
+                    .token_account
+                    .ok_or(GumballError::MissingTokenAccount)?,
+                params.edition.ok_or(GumballError::MissingAccount)?,
+                params.authority_pda,
+                params.auth_seeds,
+                params
+                    .token_metadata_program
+                    .ok_or(GumballError::MissingAccount)?,
+                params.token_program.ok_or(GumballError::MissingAccount)?,
+                params.metadata,
+                params.seller_token_record,
+                params.auth_rules,
+                params.system_program,
+                params.instructions,
+                params.auth_rules_program,
+                params
+                    .associated_token_program
+                    .ok_or(GumballError::MissingSplAtaProgram)?,
+                params
+                    .authority_pda_token_account
+                    .ok_or(GumballError::MissingTokenAccount)?,
+            )?;
+        }
+        _ => return err!(GumballError::InvalidTokenStandard),
+    }
+
+    seller_history.item_count -= 1;
+
+    if seller_history.item_count == 0 {
+        seller_history.close(params.seller.to_account_info())?;
+    }
+
+    Ok(())
+}
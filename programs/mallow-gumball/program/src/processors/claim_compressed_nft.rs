@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{instructions::CompressedNftArgs, transfer_compressed_nft, GumballMachine};
+
+use super::{assert_not_held_by_receipt, claim_item};
+
+pub fn claim_compressed_nft<'a>(
+    gumball_machine: &mut Box<Account<'a, GumballMachine>>,
+    index: u32,
+    authority_pda: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    merkle_tree: &AccountInfo<'a>,
+    tree_config: &AccountInfo<'a>,
+    bubblegum_program: &AccountInfo<'a>,
+    compression_program: &AccountInfo<'a>,
+    log_wrapper: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    proof: &[AccountInfo<'a>],
+    compressed_nft: &CompressedNftArgs,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    assert_not_held_by_receipt(authority_pda, to)?;
+    claim_item(gumball_machine, index)?;
+
+    transfer_compressed_nft(
+        authority_pda,
+        to,
+        merkle_tree,
+        tree_config,
+        bubblegum_program,
+        compression_program,
+        log_wrapper,
+        system_program,
+        proof,
+        compressed_nft,
+        Some(auth_seeds),
+    )?;
+
+    Ok(())
+}
@@ -7,9 +7,9 @@ use utils::transfer_sol;
 
 use crate::GumballMachine;
 
-use super::claim_item;
+use super::{assert_not_held_by_receipt, claim_item};
 
-pub fn claim_core_asset<'a, 'b>(
+pub fn claim_core_asset<'a>(
     gumball_machine: &mut Box<Account<'a, GumballMachine>>,
     index: u32,
     authority_pda: &AccountInfo<'a>,
@@ -22,6 +22,7 @@ pub fn claim_core_asset<'a, 'b>(
     system_program: &AccountInfo<'a>,
     auth_seeds: &[&[u8]],
 ) -> Result<()> {
+    assert_not_held_by_receipt(authority_pda, to)?;
     claim_item(gumball_machine, index)?;
 
     UpdatePluginV1CpiBuilder::new(mpl_core_program)
@@ -31,7 +32,7 @@ pub fn claim_core_asset<'a, 'b>(
         .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
         .authority(Some(authority_pda))
         .system_program(system_program)
-        .invoke_signed(&[&auth_seeds])?;
+        .invoke_signed(&[auth_seeds])?;
 
     TransferV1CpiBuilder::new(mpl_core_program)
         .asset(asset)
@@ -40,7 +41,7 @@ pub fn claim_core_asset<'a, 'b>(
         .authority(Some(authority_pda))
         .new_owner(to)
         .system_program(Some(system_program))
-        .invoke_signed(&[&auth_seeds])?;
+        .invoke_signed(&[auth_seeds])?;
 
     if payer.key == to.key {
         // Clean up plugins and send rent back to seller
@@ -63,7 +64,7 @@ pub fn claim_core_asset<'a, 'b>(
             .invoke()?;
 
         let post_lamports = payer.lamports();
-        let rent_amount = post_lamports.checked_sub(pre_lamports).unwrap_or(0);
+        let rent_amount = post_lamports.saturating_sub(pre_lamports);
 
         if rent_amount > 0 {
             transfer_sol(to, from, system_program, None, rent_amount)?;
@@ -1,10 +1,10 @@
 use crate::{
     constants::{CONFIG_LINE_SIZE, GUMBALL_MACHINE_SIZE},
-    get_bit_byte_info, GumballError, GumballMachine,
+    get_bit_byte_info, GumballError, GumballMachine, UnclaimedItemDestination,
 };
 use anchor_lang::prelude::*;
 
-pub fn is_item_claimed(gumball_machine: &Box<Account<GumballMachine>>, index: u32) -> Result<bool> {
+pub fn is_item_claimed(gumball_machine: &Account<GumballMachine>, index: u32) -> Result<bool> {
     let account_info = gumball_machine.to_account_info();
     let data = account_info.data.borrow();
 
@@ -67,3 +67,67 @@ pub fn claim_item(gumball_machine: &mut Box<Account<GumballMachine>>, index: u32
 
     Ok(item_amount)
 }
+
+/// Receiver of an item that is claimed while its sale is settled.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ItemReceiver {
+    Buyer,
+    Seller,
+    Authority,
+}
+
+/// Returns the receiver of an unclaimed item when its sale is settled: the seller for unsold
+/// items, the claim deadline destination once the deadline has passed and the buyer otherwise.
+/// Items held by a receipt cannot be settled until the receipt is burnt.
+pub fn get_unclaimed_item_receiver(
+    gumball_machine: &Account<GumballMachine>,
+    buyer: Pubkey,
+    authority_pda: Pubkey,
+) -> Result<ItemReceiver> {
+    if buyer == Pubkey::default() {
+        return Ok(ItemReceiver::Seller);
+    }
+
+    require!(buyer != authority_pda, GumballError::ItemHeldByReceipt);
+
+    let account_info = gumball_machine.to_account_info();
+    let data = account_info.data.borrow();
+
+    if !gumball_machine.is_claim_deadline_passed(&data)? {
+        return Ok(ItemReceiver::Buyer);
+    }
+
+    let receiver = match gumball_machine
+        .get_claim_deadline_config(&data)?
+        .destination
+    {
+        UnclaimedItemDestination::Seller => ItemReceiver::Seller,
+        UnclaimedItemDestination::Authority => ItemReceiver::Authority,
+    };
+
+    Ok(receiver)
+}
+
+/// Drawn items can only be claimed by their buyers before the claim deadline.
+pub fn assert_before_claim_deadline(gumball_machine: &Account<GumballMachine>) -> Result<()> {
+    let account_info = gumball_machine.to_account_info();
+    let data = account_info.data.borrow();
+
+    require!(
+        !gumball_machine.is_claim_deadline_passed(&data)?,
+        GumballError::ClaimDeadlinePassed
+    );
+
+    Ok(())
+}
+
+/// Items with a receipt have the authority PDA as buyer, they can only be claimed by
+/// burning the receipt.
+pub fn assert_not_held_by_receipt(authority_pda: &AccountInfo, to: &AccountInfo) -> Result<()> {
+    require!(
+        authority_pda.key() != to.key(),
+        GumballError::ItemHeldByReceipt
+    );
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
-use crate::{processors::claim_item, thaw_nft, GumballError, GumballMachine};
+use crate::{
+    processors::{assert_not_held_by_receipt, claim_item},
+    thaw_nft, GumballError, GumballMachine,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{close_account, CloseAccount};
 use mpl_token_metadata::accounts::Metadata;
 use utils::transfer_nft;
 
-pub fn claim_nft_v2<'a, 'b>(
+pub fn claim_nft_v2<'a>(
     gumball_machine: &mut Box<Account<'a, GumballMachine>>,
     index: u32,
     authority_pda: &AccountInfo<'a>,
@@ -30,6 +33,7 @@ pub fn claim_nft_v2<'a, 'b>(
     sysvar_instructions: Option<&UncheckedAccount<'a>>,
     auth_rules_program: Option<&UncheckedAccount<'a>>,
 ) -> Result<()> {
+    assert_not_held_by_receipt(authority_pda, to)?;
     claim_item(gumball_machine, index)?;
 
     transfer_nft_with_revoke(
@@ -61,7 +65,7 @@ pub fn claim_nft_v2<'a, 'b>(
     Ok(())
 }
 
-pub fn transfer_nft_with_revoke<'a, 'b>(
+pub fn transfer_nft_with_revoke<'a>(
     authority_pda: &AccountInfo<'a>,
     payer: &AccountInfo<'a>,
     to: &AccountInfo<'a>,
@@ -93,7 +97,7 @@ pub fn transfer_nft_with_revoke<'a, 'b>(
         from_token_account,
         edition,
         authority_pda,
-        &auth_seeds,
+        auth_seeds,
         token_metadata_program,
         token_program,
         Some(metadata_info),
@@ -127,7 +131,7 @@ pub fn transfer_nft_with_revoke<'a, 'b>(
             token_metadata_program,
             system_program,
             authority_pda,
-            Some(&auth_seeds),
+            Some(auth_seeds),
             None,
             seller_token_record,
             authority_pda_token_record,
@@ -154,7 +158,7 @@ pub fn transfer_nft_with_revoke<'a, 'b>(
         token_metadata_program,
         system_program,
         authority_pda,
-        Some(&auth_seeds),
+        Some(auth_seeds),
         None,
         authority_pda_token_record,
         to_token_record,
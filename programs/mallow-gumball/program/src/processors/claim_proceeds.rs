@@ -1,12 +1,13 @@
 use crate::{
-    get_bit_byte_info, get_config_count, state::GumballMachine, GumballError, SellerHistory,
+    constants::GUMBALL_MACHINE_SIZE, get_bit_byte_info, get_config_count,
+    processors::assert_sol_prizes_covered, state::GumballMachine, GumballError, SellerHistory,
 };
 use anchor_lang::prelude::*;
 use utils::{
     assert_keys_equal, get_bps_of, is_native_mint, transfer, transfer_from_pda, RoyaltyInfo,
 };
 
-pub fn claim_proceeds<'a, 'b>(
+pub fn claim_proceeds<'a>(
     gumball_machine: &mut Box<Account<'a, GumballMachine>>,
     index: u32,
     seller_history: &mut Box<Account<'a, SellerHistory>>,
@@ -21,7 +22,7 @@ pub fn claim_proceeds<'a, 'b>(
     fee_payment_account: Option<&AccountInfo<'a>>,
     payment_mint: Option<&AccountInfo<'a>>,
     royalty_info: &RoyaltyInfo,
-    remaining_accounts: &'b [AccountInfo<'a>],
+    remaining_accounts: &[AccountInfo<'a>],
     associated_token_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
@@ -50,6 +51,20 @@ pub fn claim_proceeds<'a, 'b>(
 
     account_data[byte_position] |= mask;
 
+    // unsold items could still be selected by a pending draw
+    let buyer_position = GUMBALL_MACHINE_SIZE
+        + 4
+        + (index as usize) * gumball_machine.get_config_line_size()
+        + 32 // mint
+        + 32; // seller
+    let buyer = Pubkey::try_from(&account_data[buyer_position..buyer_position + 32]).unwrap();
+    if buyer == Pubkey::default() {
+        require!(
+            gumball_machine.get_items_pending(&account_data)? == 0,
+            GumballError::ItemsPendingReveal
+        );
+    }
+
     let disable_primary_split_position = gumball_machine.get_disable_primary_split_position()?;
     let disable_royalties_position = gumball_machine.get_disable_royalties_position()?;
     let (disable_primary_split, disable_royalties) = if gumball_machine.version >= 3 {
@@ -74,12 +89,19 @@ pub fn claim_proceeds<'a, 'b>(
         disable_royalties
     );
 
+    let item_weight = gumball_machine.get_item_weight(&account_data, index)? as u64;
+
     let (total_proceeds, marketplace_fee_bps) = if gumball_machine.version >= 5 {
         let mut total_proceeds_settled =
             gumball_machine.get_total_proceeds_settled(&account_data)?;
 
-        let (total_proceeds, marketplace_fee_bps) =
-            get_total_proceeds(gumball_machine, total_proceeds_settled, config_count)?;
+        let (total_proceeds, marketplace_fee_bps) = get_total_proceeds(
+            gumball_machine,
+            &account_data,
+            total_proceeds_settled,
+            config_count,
+            item_weight,
+        )?;
 
         total_proceeds_settled = total_proceeds_settled
             .checked_add(total_proceeds)
@@ -90,9 +112,17 @@ pub fn claim_proceeds<'a, 'b>(
         account_data[total_proceeds_settled_position..total_proceeds_settled_position + 8]
             .copy_from_slice(&total_proceeds_settled.to_le_bytes());
 
+        if gumball_machine.is_value_weighted() {
+            let total_weight_settled = gumball_machine
+                .get_total_weight_settled(&account_data)?
+                .checked_add(item_weight)
+                .ok_or(GumballError::NumericalOverflowError)?;
+            gumball_machine.set_total_weight_settled(&mut account_data, total_weight_settled)?;
+        }
+
         (total_proceeds, marketplace_fee_bps)
     } else {
-        get_total_proceeds(gumball_machine, 0, config_count)?
+        get_total_proceeds(gumball_machine, &account_data, 0, config_count, item_weight)?
     };
 
     drop(account_data);
@@ -131,10 +161,65 @@ pub fn claim_proceeds<'a, 'b>(
     Ok(total_proceeds)
 }
 
+/// Settles an unsold item without paying out any proceeds. Its share of the revenue
+/// is left to the items that have not been settled yet.
+///
+/// Only version 5+ machines split the revenue over the unsettled items.
+pub fn settle_without_proceeds<'a>(
+    gumball_machine: &mut Box<Account<'a, GumballMachine>>,
+    index: u32,
+    seller_history: &mut Box<Account<'a, SellerHistory>>,
+    seller: &AccountInfo<'a>,
+) -> Result<()> {
+    require!(gumball_machine.version >= 5, GumballError::InvalidVersion);
+
+    let account_info = gumball_machine.to_account_info();
+    let mut account_data = account_info.data.borrow_mut();
+
+    // bit-mask
+    let bit_mask_start = gumball_machine.get_settled_items_bit_mask_position()?;
+    let (byte_position, _, mask) = get_bit_byte_info(bit_mask_start, index as usize)?;
+    let is_settled = account_data[byte_position] & mask == mask;
+    require!(!is_settled, GumballError::ItemAlreadySettled);
+
+    account_data[byte_position] |= mask;
+
+    // unsold items could still be selected by a pending draw
+    require!(
+        gumball_machine.get_items_pending(&account_data)? == 0,
+        GumballError::ItemsPendingReveal
+    );
+
+    if gumball_machine.is_value_weighted() {
+        let item_weight = gumball_machine.get_item_weight(&account_data, index)? as u64;
+        let total_weight_settled = gumball_machine
+            .get_total_weight_settled(&account_data)?
+            .checked_add(item_weight)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        gumball_machine.set_total_weight_settled(&mut account_data, total_weight_settled)?;
+    }
+
+    drop(account_data);
+
+    seller_history.item_count -= 1;
+    if seller_history.item_count == 0 {
+        seller_history.close(seller.to_account_info())?;
+    }
+
+    gumball_machine.items_settled += 1;
+
+    Ok(())
+}
+
+/// Returns the proceeds of items with a total proceeds weight of `item_weight`.
+///
+/// Before version 8 every item has a weight of 1, so the proceeds are split equally.
 pub fn get_total_proceeds<'a>(
-    gumball_machine: &Box<Account<'a, GumballMachine>>,
+    gumball_machine: &Account<'a, GumballMachine>,
+    data: &[u8],
     total_proceeds_settled: u64,
     config_count: u64,
+    item_weight: u64,
 ) -> Result<(u64, u16)> {
     let marketplace_fee_bps = if let Some(fee_config) = gumball_machine.marketplace_fee_config {
         fee_config.fee_bps
@@ -149,29 +234,48 @@ pub fn get_total_proceeds<'a>(
         0
     };
 
-    // Version 5+ can have re-added items so total proceeds settled and items settled should be removed
-    let count = if gumball_machine.version >= 5 {
-        config_count - gumball_machine.items_settled
-    } else {
-        config_count
-    };
-
-    // Proceeds are calculated as total revenue divided by total number of items in the gumball machine
-    // (This also accounts for items that have been settled)
-    let total_proceeds = gumball_machine
+    // Revenue that has not been paid out yet
+    let remaining_revenue = gumball_machine
         .total_revenue
         .checked_sub(fees_taken)
         .ok_or(GumballError::NumericalOverflowError)?
         .checked_sub(total_proceeds_settled)
-        .ok_or(GumballError::NumericalOverflowError)?
-        .checked_div(count)
         .ok_or(GumballError::NumericalOverflowError)?;
 
+    let total_proceeds = if gumball_machine.is_value_weighted() {
+        // Proceeds are split by the weight of each item that has not been settled yet
+        let remaining_weight = gumball_machine
+            .get_total_weight(data)?
+            .checked_sub(gumball_machine.get_total_weight_settled(data)?)
+            .ok_or(GumballError::NumericalOverflowError)?;
+
+        (remaining_revenue as u128)
+            .checked_mul(item_weight as u128)
+            .ok_or(GumballError::NumericalOverflowError)?
+            .checked_div(remaining_weight as u128)
+            .ok_or(GumballError::NumericalOverflowError)? as u64
+    } else {
+        // Version 5+ can have re-added items so total proceeds settled and items settled should be removed
+        let count = if gumball_machine.version >= 5 {
+            config_count - gumball_machine.items_settled
+        } else {
+            config_count
+        };
+
+        // Proceeds are calculated as total revenue divided by total number of items in the gumball machine
+        // (This also accounts for items that have been settled)
+        remaining_revenue
+            .checked_div(count)
+            .ok_or(GumballError::NumericalOverflowError)?
+            .checked_mul(item_weight)
+            .ok_or(GumballError::NumericalOverflowError)?
+    };
+
     Ok((total_proceeds, marketplace_fee_bps))
 }
 
-pub fn transfer_proceeds<'a, 'b>(
-    gumball_machine: &Box<Account<'a, GumballMachine>>,
+pub fn transfer_proceeds<'a>(
+    gumball_machine: &Account<'a, GumballMachine>,
     total_proceeds: u64,
     marketplace_fee_bps: u16,
     authority_pda: &mut AccountInfo<'a>,
@@ -191,7 +295,7 @@ pub fn transfer_proceeds<'a, 'b>(
     royalty_info: &RoyaltyInfo,
     disable_primary_split: bool,
     disable_royalties: bool,
-    remaining_accounts: &'b [AccountInfo<'a>],
+    remaining_accounts: &[AccountInfo<'a>],
 ) -> Result<()> {
     if total_proceeds > 0 {
         msg!("Total proceeds: {}", total_proceeds);
@@ -215,7 +319,7 @@ pub fn transfer_proceeds<'a, 'b>(
                 Some(associated_token_program),
                 Some(token_program),
                 system_program,
-                &auth_seeds,
+                auth_seeds,
                 None,
                 marketplace_fee,
             )?;
@@ -235,7 +339,7 @@ pub fn transfer_proceeds<'a, 'b>(
                 Some(associated_token_program),
                 Some(token_program),
                 system_program,
-                &auth_seeds,
+                auth_seeds,
                 None,
                 curator_fee,
             )?;
@@ -270,7 +374,7 @@ pub fn transfer_proceeds<'a, 'b>(
                 associated_token_program,
                 token_program,
                 system_program,
-                Some(&auth_seeds),
+                Some(auth_seeds),
                 total_royalty,
             )?
         } else {
@@ -293,24 +397,30 @@ pub fn transfer_proceeds<'a, 'b>(
                 Some(associated_token_program),
                 Some(token_program),
                 system_program,
-                &auth_seeds,
+                auth_seeds,
                 None,
                 seller_proceeds,
             )?;
         }
     }
 
+    assert_sol_prizes_covered(
+        gumball_machine,
+        &gumball_machine.to_account_info().data.borrow(),
+        authority_pda,
+    )?;
+
     Ok(())
 }
 
 /// Pays creator fees to the creators in the metadata and returns total paid
-pub fn pay_creator_royalties<'a, 'b>(
+pub fn pay_creator_royalties<'a>(
     payer: &mut AccountInfo<'a>,
     payment_mint: Option<&AccountInfo<'a>>,
     payer_token_account: Option<&AccountInfo<'a>>,
     fee_payer: Option<&AccountInfo<'a>>,
     verified_royalty_info: &RoyaltyInfo,
-    remaining_accounts: &'b [AccountInfo<'a>],
+    remaining_accounts: &[AccountInfo<'a>],
     ata_program: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
@@ -374,7 +484,7 @@ pub fn pay_creator_royalties<'a, 'b>(
             continue;
         }
 
-        if auth_seeds.is_some() {
+        if let Some(auth_seeds) = auth_seeds {
             transfer_from_pda(
                 payer,
                 &mut current_creator_info.to_account_info(),
@@ -385,7 +495,7 @@ pub fn pay_creator_royalties<'a, 'b>(
                 Some(ata_program),
                 Some(token_program),
                 system_program,
-                auth_seeds.unwrap(),
+                auth_seeds,
                 None,
                 creator_fee,
             )?;
@@ -0,0 +1,58 @@
+use crate::{
+    processors::{assert_not_held_by_receipt, claim_item},
+    GumballError, GumballMachine,
+};
+use anchor_lang::prelude::*;
+use utils::transfer_sol;
+
+pub fn claim_sol_prize<'a>(
+    gumball_machine: &mut Box<Account<'a, GumballMachine>>,
+    index: u32,
+    authority_pda: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    auth_seeds: &[&[u8]],
+) -> Result<u64> {
+    assert_not_held_by_receipt(authority_pda, to)?;
+    let amount = claim_item(gumball_machine, index)?;
+
+    require!(
+        to.key() != Pubkey::default(),
+        GumballError::InvalidAuthority
+    );
+
+    let account_info = gumball_machine.to_account_info();
+    release_sol_prize(gumball_machine, &mut account_info.data.borrow_mut(), amount)?;
+    transfer_sol(authority_pda, to, system_program, Some(auth_seeds), amount)?;
+
+    Ok(amount)
+}
+
+/// Removes the lamports of SOL prizes leaving the authority pda from the lamports it
+/// holds for prizes.
+pub fn release_sol_prize(
+    gumball_machine: &GumballMachine,
+    gumball_data: &mut [u8],
+    amount: u64,
+) -> Result<()> {
+    let sol_prizes_held = gumball_machine
+        .get_sol_prizes_held(gumball_data)?
+        .checked_sub(amount)
+        .ok_or(GumballError::NumericalOverflowError)?;
+    gumball_machine.set_sol_prizes_held(gumball_data, sol_prizes_held)
+}
+
+/// Sale revenue and buy back funds in SOL share the authority pda with SOL prizes, so
+/// after paying them out the pda must still hold the lamports of every SOL prize.
+pub fn assert_sol_prizes_covered(
+    gumball_machine: &GumballMachine,
+    gumball_data: &[u8],
+    authority_pda: &AccountInfo,
+) -> Result<()> {
+    require!(
+        authority_pda.lamports() >= gumball_machine.get_sol_prizes_held(gumball_data)?,
+        GumballError::SolPrizesNotCovered
+    );
+
+    Ok(())
+}
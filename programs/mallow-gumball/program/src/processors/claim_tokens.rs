@@ -1,8 +1,11 @@
-use crate::{processors::claim_item, transfer_and_close_if_empty, GumballError, GumballMachine};
+use crate::{
+    processors::{assert_not_held_by_receipt, claim_item},
+    transfer_and_close_if_empty, GumballError, GumballMachine,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
-pub fn claim_tokens<'a, 'b>(
+pub fn claim_tokens<'a>(
     gumball_machine: &mut Box<Account<'a, GumballMachine>>,
     index: u32,
     authority: &AccountInfo<'a>,
@@ -10,13 +13,14 @@ pub fn claim_tokens<'a, 'b>(
     payer: &AccountInfo<'a>,
     to: &AccountInfo<'a>,
     to_token_account: &AccountInfo<'a>,
-    authority_pda_token_account: &mut Box<Account<'a, TokenAccount>>,
+    authority_pda_token_account: &mut Box<InterfaceAccount<'a, TokenAccount>>,
     mint: &AccountInfo<'a>,
     token_program: &AccountInfo<'a>,
     associated_token_program: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     auth_seeds: &[&[u8]],
 ) -> Result<u64> {
+    assert_not_held_by_receipt(authority_pda, to)?;
     let amount = claim_item(gumball_machine, index)?;
 
     require!(
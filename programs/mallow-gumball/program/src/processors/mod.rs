@@ -1,15 +1,25 @@
 pub mod add_item;
+pub mod cancel_add_item_request;
+pub mod claim_compressed_nft;
 pub mod claim_core_asset;
 pub mod claim_item;
 pub mod claim_nft;
 pub mod claim_proceeds;
+pub mod claim_sol_prize;
 pub mod claim_tokens;
 pub mod remove_item;
+pub mod return_item;
+pub mod tiers;
 
 pub use add_item::*;
+pub use cancel_add_item_request::*;
+pub use claim_compressed_nft::*;
 pub use claim_core_asset::*;
 pub use claim_item::*;
 pub use claim_nft::*;
 pub use claim_proceeds::*;
+pub use claim_sol_prize::*;
 pub use claim_tokens::*;
 pub use remove_item::*;
+pub use return_item::*;
+pub use tiers::*;
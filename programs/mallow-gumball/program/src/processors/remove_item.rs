@@ -1,6 +1,8 @@
 use crate::{
     constants::{CONFIG_LINE_SIZE, GUMBALL_MACHINE_SIZE},
-    get_config_count, GumballError, GumballMachine,
+    get_config_count,
+    processors::rebuild_tier_indices,
+    GumballError, GumballMachine, TokenStandard,
 };
 use anchor_lang::prelude::*;
 
@@ -10,6 +12,7 @@ pub fn remove_multiple_items(
     authority: Pubkey,
     mint: Pubkey,
     expected_seller: Pubkey,
+    token_standards: &[TokenStandard],
     indices: &[u8],
     amount: u64,
 ) -> Result<()> {
@@ -19,6 +22,8 @@ pub fn remove_multiple_items(
     let mut data = account_info.data.borrow_mut();
     let mut count = get_config_count(&data)? as u32;
     let config_line_size = gumball_machine.get_config_line_size();
+    // proceeds weight of the removed items
+    let mut removed_weight = 0u64;
 
     // Validate all indices are within bounds and unique
     let mut sorted_indices: Vec<u32> = indices.iter().map(|&x| x as u32).collect();
@@ -58,6 +63,7 @@ pub fn remove_multiple_items(
         let item_mint =
             Pubkey::try_from(&data[config_line_position..config_line_position + 32]).unwrap();
         require!(mint == item_mint, GumballError::InvalidMint);
+        assert_token_standard(&data, config_line_position, token_standards)?;
 
         // Verify amount for version 2+
         if gumball_machine.version >= 2 {
@@ -70,6 +76,8 @@ pub fn remove_multiple_items(
             require!(amount == item_amount, GumballError::InvalidAmount);
         }
 
+        removed_weight += gumball_machine.get_item_weight(&data, index as u32)? as u64;
+
         // Find the last non-removed config line
         let mut last_valid_index = count - 1;
         while sorted_indices.binary_search(&(last_valid_index)).is_ok()
@@ -112,6 +120,20 @@ pub fn remove_multiple_items(
             .ok_or(GumballError::NumericalOverflowError)?;
     }
 
+    if gumball_machine.is_tiered() {
+        let mut tier_config = gumball_machine.get_tier_config(&data)?;
+        rebuild_tier_indices(gumball_machine, &mut data, &mut tier_config, count as usize)?;
+        gumball_machine.set_tier_config(&mut data, &tier_config)?;
+    }
+
+    if gumball_machine.is_value_weighted() {
+        let total_weight = gumball_machine
+            .get_total_weight(&data)?
+            .checked_sub(removed_weight)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        gumball_machine.set_total_weight(&mut data, total_weight)?;
+    }
+
     msg!("Items removed: new count={}", count);
 
     // Update final count
@@ -126,6 +148,7 @@ pub fn remove_multiple_items_span(
     authority: Pubkey,
     mint: Pubkey,
     expected_seller: Pubkey,
+    token_standards: &[TokenStandard],
     amount: u64,
     start_index: u32,
     end_index: u32,
@@ -134,8 +157,10 @@ pub fn remove_multiple_items_span(
 
     let account_info = gumball_machine.to_account_info();
     let mut data = account_info.data.borrow_mut();
-    let mut count = get_config_count(&data)? as u32;
+    let count = get_config_count(&data)? as u32;
     let config_line_size = gumball_machine.get_config_line_size();
+    // proceeds weight of the removed items
+    let mut removed_weight = 0u64;
 
     // Validate indices are within bounds
     require!(end_index < count, GumballError::IndexGreaterThanLength);
@@ -170,6 +195,7 @@ pub fn remove_multiple_items_span(
         let item_mint =
             Pubkey::try_from(&data[config_line_position..config_line_position + 32]).unwrap();
         require!(mint == item_mint, GumballError::InvalidMint);
+        assert_token_standard(&data, config_line_position, token_standards)?;
 
         // Verify amount for version 2+
         if gumball_machine.version >= 2 {
@@ -181,9 +207,98 @@ pub fn remove_multiple_items_span(
             );
             require!(amount == item_amount, GumballError::InvalidAmount);
         }
+
+        removed_weight += gumball_machine.get_item_weight(&data, index)? as u64;
     }
 
-    // After verification, perform the actual removal
+    remove_verified_span(
+        gumball_machine,
+        &mut data,
+        count,
+        start_index,
+        end_index,
+        removed_weight,
+    )?;
+
+    drop(data);
+
+    Ok(())
+}
+
+/// Removes a span of unique items (NFTs or Core assets), `items` holds the expected mint
+/// and seller of each item in the span.
+pub fn remove_unique_items_span(
+    gumball_machine: &mut Account<GumballMachine>,
+    authority: Pubkey,
+    items: &[(Pubkey, Pubkey)],
+    token_standards: &[TokenStandard],
+    start_index: u32,
+    end_index: u32,
+) -> Result<()> {
+    require!(start_index <= end_index, GumballError::InvalidInputLength);
+    require!(
+        items.len() == (end_index - start_index) as usize + 1,
+        GumballError::InvalidInputLength
+    );
+
+    let account_info = gumball_machine.to_account_info();
+    let mut data = account_info.data.borrow_mut();
+    let count = get_config_count(&data)? as u32;
+    let config_line_size = gumball_machine.get_config_line_size();
+    // proceeds weight of the removed items
+    let mut removed_weight = 0u64;
+
+    // Validate indices are within bounds
+    require!(end_index < count, GumballError::IndexGreaterThanLength);
+
+    // First, verify all items in the span
+    for (index, (mint, expected_seller)) in (start_index..=end_index).zip(items.iter()) {
+        let config_line_position = GUMBALL_MACHINE_SIZE + 4 + (index as usize) * config_line_size;
+
+        // Verify seller and authority
+        let seller =
+            Pubkey::try_from(&data[config_line_position + 32..config_line_position + 64]).unwrap();
+        require!(
+            authority == gumball_machine.authority || seller == authority,
+            GumballError::InvalidAuthority
+        );
+        require!(*expected_seller == seller, GumballError::InvalidSeller);
+
+        // Verify mint
+        let item_mint =
+            Pubkey::try_from(&data[config_line_position..config_line_position + 32]).unwrap();
+        require!(*mint == item_mint, GumballError::InvalidMint);
+        assert_token_standard(&data, config_line_position, token_standards)?;
+
+        removed_weight += gumball_machine.get_item_weight(&data, index)? as u64;
+    }
+
+    remove_verified_span(
+        gumball_machine,
+        &mut data,
+        count,
+        start_index,
+        end_index,
+        removed_weight,
+    )?;
+
+    drop(data);
+
+    Ok(())
+}
+
+/// Compacts the config lines and mint indices after removing a verified span of items.
+fn remove_verified_span(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    mut count: u32,
+    start_index: u32,
+    end_index: u32,
+    removed_weight: u64,
+) -> Result<()> {
+    let config_line_size = gumball_machine.get_config_line_size();
+    let items_to_remove = end_index - start_index + 1;
+
     if end_index < count - 1 {
         // Only move data if we're not removing the last items in the list
         let items_after_removal = count
@@ -221,6 +336,20 @@ pub fn remove_multiple_items_span(
         .checked_sub(items_to_remove)
         .ok_or(GumballError::NumericalOverflowError)?;
 
+    if gumball_machine.is_tiered() {
+        let mut tier_config = gumball_machine.get_tier_config(data)?;
+        rebuild_tier_indices(gumball_machine, data, &mut tier_config, count as usize)?;
+        gumball_machine.set_tier_config(data, &tier_config)?;
+    }
+
+    if gumball_machine.is_value_weighted() {
+        let total_weight = gumball_machine
+            .get_total_weight(data)?
+            .checked_sub(removed_weight)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        gumball_machine.set_total_weight(data, total_weight)?;
+    }
+
     msg!(
         "Items removed: span from {} to {}, new count={}",
         start_index,
@@ -229,10 +358,24 @@ pub fn remove_multiple_items_span(
     );
 
     // Update final count
-    data[GUMBALL_MACHINE_SIZE..GUMBALL_MACHINE_SIZE + 4]
-        .copy_from_slice(&(count as u32).to_le_bytes());
+    data[GUMBALL_MACHINE_SIZE..GUMBALL_MACHINE_SIZE + 4].copy_from_slice(&count.to_le_bytes());
 
-    drop(data);
+    Ok(())
+}
 
+/// Verifies that the item of a config line has one of the expected token standards, since
+/// fungible tokens and SOL prizes can share a mint.
+fn assert_token_standard(
+    data: &[u8],
+    config_line_position: usize,
+    token_standards: &[TokenStandard],
+) -> Result<()> {
+    let token_standard =
+        TokenStandard::try_from_slice(&data[config_line_position + 96..config_line_position + 97])
+            .map_err(|_| GumballError::InvalidTokenStandard)?;
+    require!(
+        token_standards.contains(&token_standard),
+        GumballError::InvalidTokenStandard
+    );
     Ok(())
 }
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use utils::get_bps_of;
+
+use crate::{get_config_count, processors::restore_item_to_tier, GumballError, GumballMachine};
+
+/// Clears the buyer and price of a drawn item and adds its index back to the available mint indices.
+///
+/// The caller is responsible for decrementing `items_redeemed` afterwards.
+pub fn return_drawn_item(
+    gumball_machine: &GumballMachine,
+    gumball_data: &mut [u8],
+    index: u32,
+    config_line_position: usize,
+) -> Result<()> {
+    let config_count = get_config_count(gumball_data)? as u64;
+    let items_pending = gumball_machine.get_items_pending(gumball_data)? as u64;
+
+    // Clear the buyer on the config line
+    let buyer_position = config_line_position + 32 + 32;
+    gumball_data[buyer_position..buyer_position + 32]
+        .copy_from_slice(&Pubkey::default().to_bytes());
+    gumball_machine.set_item_price(gumball_data, index, 0)?;
+
+    // Add the index back to the available mint indices
+    if gumball_machine.is_tiered() {
+        let tier = gumball_machine.get_item_tier(gumball_data, index)?;
+        let mut tier_config = gumball_machine.get_tier_config(gumball_data)?;
+        restore_item_to_tier(gumball_machine, gumball_data, &mut tier_config, tier, index)?;
+        gumball_machine.set_tier_config(gumball_data, &tier_config)?;
+    } else {
+        // Mint indices will be as long as config count - redeemed items + pending items and we need to add to the end
+        let index_position = gumball_machine.get_mint_indices_position()?
+            + ((config_count - gumball_machine.items_redeemed + items_pending) * 4) as usize;
+        gumball_data[index_position..index_position + 4].copy_from_slice(&index.to_le_bytes());
+    }
+
+    Ok(())
+}
+
+/// Removes the revenue of a drawn item (or draw) from the gumball machine and returns the
+/// amount that can be paid back: the revenue without its marketplace fees and without
+/// the share already paid out to settled items.
+pub fn reverse_revenue(
+    gumball_machine: &mut GumballMachine,
+    gumball_data: &mut [u8],
+    revenue: u64,
+) -> Result<u64> {
+    let total_revenue = gumball_machine
+        .total_revenue
+        .checked_sub(revenue)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    // Marketplace fees are taken on draw and calculated from the total revenue
+    let marketplace_fee_bps = gumball_machine
+        .marketplace_fee_config
+        .map(|fee_config| fee_config.fee_bps)
+        .unwrap_or(0);
+    let fees_taken = get_bps_of(gumball_machine.total_revenue, marketplace_fee_bps)?
+        - get_bps_of(total_revenue, marketplace_fee_bps)?;
+    let net_revenue = revenue
+        .checked_sub(fees_taken)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    // Share of the revenue already paid out to settled items
+    let (settled, total) = if gumball_machine.is_value_weighted() {
+        (
+            gumball_machine.get_total_weight_settled(gumball_data)?,
+            gumball_machine.get_total_weight(gumball_data)?,
+        )
+    } else {
+        (
+            gumball_machine.items_settled,
+            get_config_count(gumball_data)? as u64,
+        )
+    };
+    let total_proceeds_settled = gumball_machine.get_total_proceeds_settled(gumball_data)?;
+    let distributed = ((net_revenue as u128)
+        .checked_mul(settled as u128)
+        .ok_or(GumballError::NumericalOverflowError)?
+        .checked_div(total as u128)
+        .ok_or(GumballError::NumericalOverflowError)? as u64)
+        .min(total_proceeds_settled);
+
+    gumball_machine
+        .set_total_proceeds_settled(gumball_data, total_proceeds_settled - distributed)?;
+    let total_revenue_drawn = gumball_machine.get_total_revenue_drawn(gumball_data)?;
+    gumball_machine
+        .set_total_revenue_drawn(gumball_data, total_revenue_drawn.saturating_sub(revenue))?;
+
+    gumball_machine.total_revenue = total_revenue;
+
+    Ok(net_revenue - distributed)
+}
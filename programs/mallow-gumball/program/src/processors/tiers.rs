@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use arrayref::array_ref;
+
+use crate::{
+    constants::{CONFIG_LINE_V2_SIZE, GUMBALL_MACHINE_SIZE, MAX_TIERS},
+    GumballError, GumballMachine, TierConfig,
+};
+
+/// Adds an item to the end of a tier, shifting the mint indices of the following
+/// tiers by one position.
+///
+/// Only valid before the sale starts, while every item of a tier is still available.
+pub fn insert_item_into_tier(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    tier_config: &mut TierConfig,
+    tier: u8,
+    item_index: u32,
+) -> Result<()> {
+    let tier = tier as usize;
+    require!(tier < MAX_TIERS, GumballError::InvalidTier);
+
+    let indices_start = gumball_machine.get_mint_indices_position()?;
+    // first free position after the last tier
+    let mut free_position = indices_start + tier_config.tier_start(MAX_TIERS) * 4;
+
+    for next_tier in (tier + 1..MAX_TIERS).rev() {
+        let count = tier_config.item_counts[next_tier] as usize;
+        if count == 0 {
+            continue;
+        }
+
+        // moves the first index of the tier to the free position after it
+        let tier_start = free_position - count * 4;
+        data.copy_within(tier_start..tier_start + 4, free_position);
+        free_position = tier_start;
+    }
+
+    data[free_position..free_position + 4].copy_from_slice(&item_index.to_le_bytes());
+
+    tier_config.item_counts[tier] = tier_config.item_counts[tier]
+        .checked_add(1)
+        .ok_or(GumballError::NumericalOverflowError)?;
+    tier_config.items_available[tier] = tier_config.items_available[tier]
+        .checked_add(1)
+        .ok_or(GumballError::NumericalOverflowError)?;
+
+    Ok(())
+}
+
+/// Removes an item from a tier, shifting the mint indices of the following tiers
+/// back by one position.
+///
+/// Only valid before the sale starts, while every item of a tier is still available.
+pub fn remove_item_from_tier(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    tier_config: &mut TierConfig,
+    tier: u8,
+    item_index: u32,
+) -> Result<()> {
+    let tier = tier as usize;
+    require!(tier < MAX_TIERS, GumballError::InvalidTier);
+
+    let indices_start = gumball_machine.get_mint_indices_position()?;
+    let tier_start = indices_start + tier_config.tier_start(tier) * 4;
+    let count = tier_config.item_counts[tier] as usize;
+
+    let position = (0..count)
+        .map(|i| tier_start + i * 4)
+        .find(|position| u32::from_le_bytes(*array_ref![data, *position, 4]) == item_index)
+        .ok_or(GumballError::IndexGreaterThanLength)?;
+
+    // swap-remove within the tier
+    let mut free_position = tier_start + (count - 1) * 4;
+    data.copy_within(free_position..free_position + 4, position);
+
+    for next_tier in tier + 1..MAX_TIERS {
+        let count = tier_config.item_counts[next_tier] as usize;
+        if count == 0 {
+            continue;
+        }
+
+        // moves the last index of the tier to the free position before it
+        let tier_end = free_position + count * 4;
+        data.copy_within(tier_end..tier_end + 4, free_position);
+        free_position = tier_end;
+    }
+
+    data[free_position..free_position + 4].copy_from_slice(&u32::MIN.to_le_bytes());
+
+    tier_config.item_counts[tier] -= 1;
+    tier_config.items_available[tier] -= 1;
+
+    Ok(())
+}
+
+/// Rebuilds the mint indices from the tier of each config line.
+///
+/// Only valid before the sale starts, used when config lines are moved around.
+pub fn rebuild_tier_indices(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    tier_config: &mut TierConfig,
+    config_count: usize,
+) -> Result<()> {
+    let config_line_size = gumball_machine.get_config_line_size();
+    let tier_position =
+        |index: usize| GUMBALL_MACHINE_SIZE + 4 + index * config_line_size + CONFIG_LINE_V2_SIZE;
+
+    let mut item_counts = [0u32; MAX_TIERS];
+    for index in 0..config_count {
+        item_counts[data[tier_position(index)] as usize] += 1;
+    }
+
+    tier_config.item_counts = item_counts;
+    tier_config.items_available = item_counts;
+
+    let indices_start = gumball_machine.get_mint_indices_position()?;
+    let mut next_positions = [0usize; MAX_TIERS];
+    for (tier, next_position) in next_positions.iter_mut().enumerate() {
+        *next_position = indices_start + tier_config.tier_start(tier) * 4;
+    }
+
+    for index in 0..config_count {
+        let tier = data[tier_position(index)] as usize;
+        let position = next_positions[tier];
+        data[position..position + 4].copy_from_slice(&(index as u32).to_le_bytes());
+        next_positions[tier] += 4;
+    }
+
+    Ok(())
+}
+
+/// Puts a drawn item back in its tier, making it available again.
+pub fn restore_item_to_tier(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    tier_config: &mut TierConfig,
+    tier: u8,
+    item_index: u32,
+) -> Result<()> {
+    let tier = tier as usize;
+    require!(
+        tier_config.items_available[tier] < tier_config.item_counts[tier],
+        GumballError::IndexGreaterThanLength
+    );
+
+    let indices_start = gumball_machine.get_mint_indices_position()?;
+    let position = indices_start
+        + (tier_config.tier_start(tier) + tier_config.items_available[tier] as usize) * 4;
+    data[position..position + 4].copy_from_slice(&item_index.to_le_bytes());
+
+    tier_config.items_available[tier] += 1;
+
+    Ok(())
+}
+
+/// Removes a specific available item from its tier.
+pub fn take_item_from_tier(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    tier_config: &mut TierConfig,
+    tier: u8,
+    item_index: u32,
+) -> Result<()> {
+    let tier = tier as usize;
+    require!(tier < MAX_TIERS, GumballError::InvalidTier);
+
+    let indices_start = gumball_machine.get_mint_indices_position()?;
+    let tier_start = indices_start + tier_config.tier_start(tier) * 4;
+    let available = tier_config.items_available[tier] as usize;
+
+    let position = (0..available)
+        .map(|i| tier_start + i * 4)
+        .find(|position| u32::from_le_bytes(*array_ref![data, *position, 4]) == item_index)
+        .ok_or(GumballError::ItemAlreadyDrawn)?;
+
+    // swap-remove with the last available index of the tier
+    let last_position = tier_start + (available - 1) * 4;
+    data.copy_within(last_position..last_position + 4, position);
+
+    tier_config.items_available[tier] -= 1;
+
+    Ok(())
+}
+
+/// Selects an available item given a random value in the range `[0, total_weight)`,
+/// removing it from its tier.
+///
+/// Returns the index of the config line and its tier.
+pub fn take_weighted_item(
+    gumball_machine: &GumballMachine,
+    data: &mut [u8],
+    tier_config: &mut TierConfig,
+    mut value: u64,
+) -> Result<(u32, u8)> {
+    for tier in 0..MAX_TIERS {
+        let weight = tier_config.weights[tier] as u64;
+        let available = tier_config.items_available[tier] as u64;
+        let tier_weight = weight * available;
+
+        if value >= tier_weight {
+            value -= tier_weight;
+            continue;
+        }
+
+        // every item within the tier has the same odds
+        let indices_start = gumball_machine.get_mint_indices_position()?;
+        let tier_start = indices_start + tier_config.tier_start(tier) * 4;
+        let position = tier_start + (value / weight) as usize * 4;
+        let item_index = u32::from_le_bytes(*array_ref![data, position, 4]);
+
+        // swap-remove with the last available index of the tier
+        let last_position = tier_start + (available as usize - 1) * 4;
+        data.copy_within(last_position..last_position + 4, position);
+
+        tier_config.items_available[tier] -= 1;
+
+        return Ok((item_index, tier as u8));
+    }
+
+    err!(GumballError::GumballMachineEmpty)
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::TokenStandard;
+use crate::{GumballError, TokenStandard};
 
 /// Add item request state.
 #[account]
@@ -14,11 +14,17 @@ pub struct AddItemRequest {
     pub asset: Pubkey,
     /// Token standard.
     pub token_standard: TokenStandard,
+    // hidden data section, requests created before expiries were added don't have it:
+    //
+    // - (i64) created_at
+    // - (i64) expires_at (0 = never)
 }
 
 impl AddItemRequest {
     // Additional padding for future proofing
-    pub const SPACE: usize = 8 + 32 + 32 + 32 + 1;
+    pub const SPACE: usize = Self::TIMESTAMPS_POSITION + 8 + 8;
+
+    const TIMESTAMPS_POSITION: usize = 8 + 32 + 32 + 32 + 1;
 
     pub fn init(
         &mut self,
@@ -33,4 +39,38 @@ impl AddItemRequest {
         self.token_standard = token_standard;
         Ok(())
     }
+
+    /// Stores the creation time and the expiry of the request, an expiry of 0 never expires.
+    pub fn set_timestamps(account_info: &AccountInfo, expiry: i64) -> Result<()> {
+        let created_at = Clock::get()?.unix_timestamp;
+        let expires_at = if expiry > 0 {
+            created_at
+                .checked_add(expiry)
+                .ok_or(GumballError::NumericalOverflowError)?
+        } else {
+            0
+        };
+
+        let mut data = account_info.data.borrow_mut();
+        let position = Self::TIMESTAMPS_POSITION;
+        data[position..position + 8].copy_from_slice(&created_at.to_le_bytes());
+        data[position + 8..position + 16].copy_from_slice(&expires_at.to_le_bytes());
+        Ok(())
+    }
+
+    /// Expiry of the request, if it has one.
+    pub fn get_expires_at(account_info: &AccountInfo) -> Option<i64> {
+        let data = account_info.data.borrow();
+        let position = Self::TIMESTAMPS_POSITION + 8;
+        if data.len() < position + 8 {
+            return None;
+        }
+
+        let expires_at = i64::from_le_bytes(data[position..position + 8].try_into().unwrap());
+        if expires_at == 0 {
+            None
+        } else {
+            Some(expires_at)
+        }
+    }
 }
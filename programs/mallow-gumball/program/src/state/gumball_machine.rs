@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{CONFIG_LINE_SIZE, CONFIG_LINE_V2_SIZE, GUMBALL_MACHINE_SIZE},
+    constants::{
+        CONFIG_LINE_SIZE, CONFIG_LINE_V2_SIZE, CONFIG_LINE_V3_SIZE, CONFIG_LINE_V4_SIZE,
+        CONFIG_LINE_V5_SIZE, CONFIG_LINE_V6_SIZE, GUMBALL_MACHINE_SIZE, MAX_TIERS,
+    },
     GumballError,
 };
 
@@ -38,7 +41,8 @@ pub struct GumballMachine {
     //
     // - version 3:
     // - (boolean) disable_royalties
-    // - ([u8; 3]) unused
+    // - (u16) items_pending (drawn items waiting to be revealed, available for all versions)
+    // - ([u8; 1]) unused
     // - (boolean) disable_primary_split
     //
     // - version 4:
@@ -47,32 +51,100 @@ pub struct GumballMachine {
     //
     // - version 5:
     // - (u64) total_proceeds_settled
+    //
+    // - version 6:
+    // - (TierConfig) tier_config
+    //   (mint indices are grouped by tier, each tier's available items first)
+    //
+    // - version 7:
+    // - (RandomnessConfig) randomness_config
+    //
+    // - version 8:
+    // - (u64) total_weight (sum of the proceeds weight of every config line)
+    // - (u64) total_weight_settled
+    //
+    // - version 9:
+    // - (i64) add_item_request_expiry (seconds before an add item request expires, 0 = never)
+    //
+    // - version 10:
+    // - (i64) sale_ended_at (unix timestamp, 0 = sale not ended)
+    // - (i64) force_close_grace_period (seconds after the sale ends before the machine can be force closed, 0 = disabled)
+    //
+    // - version 11:
+    // - (ClaimDeadlineConfig) claim_deadline_config
+    //
+    // - version 12:
+    // - (bool) instant_delivery (items are transferred to the buyer when the draw is revealed)
+    //
+    // - version 13:
+    // - (Pubkey) receipt_collection (Core collection of the draw receipts, default pubkey = no receipts)
+    //
+    // - version 14:
+    // - config lines hold the draw slot (slot of the draw that assigned the item to its buyer)
+    //
+    // - version 15:
+    // - (u64) draws_committed (number of draws made, used as the seed of the next pending draw)
+    // - (u64) draws_revealed (number of draws revealed, draws are revealed in the order they were made)
+    //
+    // - version 16:
+    // - (RandomnessRequestConfig) randomness_request_config (randomness account and layout of its requests)
+    //
+    // - version 17:
+    // - config lines hold the price (revenue of the draw that assigned the item to its buyer)
+    // - (u64) total_revenue_drawn (revenue already assigned to a draw)
+    //
+    // - version 18:
+    // - (u64) sol_prizes_held (lamports of the SOL prizes held by the authority pda)
 }
 
 impl GumballMachine {
-    pub const CURRENT_VERSION: u8 = 5;
+    pub const CURRENT_VERSION: u8 = 18;
 
     /// Gets the size of the gumball machine given the number of items.
     pub fn get_size(item_count: u64, version: u8) -> usize {
         GUMBALL_MACHINE_SIZE
             + 4 // number of items inserted
-            + ((if version >= 2 { CONFIG_LINE_V2_SIZE } else { CONFIG_LINE_SIZE }) * item_count as usize) // config lines
+            + (Self::get_config_line_size_for_version(version) * item_count as usize) // config lines
             + (item_count as usize / 8) + 1 // bit mask tracking claimed items
             + (item_count as usize / 8) + 1 // bit mask tracking settled items
             + 4 + (4 * item_count as usize) // mint indices
             + if version >= 3 { 1 } else { 0 } // disable_primary_split
             + if version >= 4 { BuyBackConfig::INIT_SPACE + 8 } else { 0 } // buy_back_config
             + if version >= 5 { 8 } else { 0 } // total_proceeds_settled
+            + if version >= 6 { TierConfig::INIT_SPACE } else { 0 } // tier_config
+            + if version >= 7 { RandomnessConfig::INIT_SPACE } else { 0 } // randomness_config
+            + if version >= 8 { 8 + 8 } else { 0 } // total_weight + total_weight_settled
+            + if version >= 9 { 8 } else { 0 } // add_item_request_expiry
+            + if version >= 10 { 8 + 8 } else { 0 } // sale_ended_at + force_close_grace_period
+            + if version >= 11 { ClaimDeadlineConfig::INIT_SPACE } else { 0 } // claim_deadline_config
+            + if version >= 12 { 1 } else { 0 } // instant_delivery
+            + if version >= 13 { 32 } else { 0 } // receipt_collection
+            + if version >= 15 { 8 + 8 } else { 0 } // draws_committed + draws_revealed
+            + if version >= 16 { RandomnessRequestConfig::INIT_SPACE } else { 0 }
+            + if version >= 17 { 8 } else { 0 } // total_revenue_drawn
+            + if version >= 18 { 8 } else { 0 } // sol_prizes_held
     }
 
-    pub fn get_config_line_size(&self) -> usize {
-        if self.version < 2 {
+    pub fn get_config_line_size_for_version(version: u8) -> usize {
+        if version < 2 {
             CONFIG_LINE_SIZE
-        } else {
+        } else if version < 6 {
             CONFIG_LINE_V2_SIZE
+        } else if version < 8 {
+            CONFIG_LINE_V3_SIZE
+        } else if version < 14 {
+            CONFIG_LINE_V4_SIZE
+        } else if version < 17 {
+            CONFIG_LINE_V5_SIZE
+        } else {
+            CONFIG_LINE_V6_SIZE
         }
     }
 
+    pub fn get_config_line_size(&self) -> usize {
+        Self::get_config_line_size_for_version(self.version)
+    }
+
     pub fn get_claimed_items_bit_mask_position(&self) -> usize {
         GUMBALL_MACHINE_SIZE
             + 4
@@ -101,6 +173,19 @@ impl GumballMachine {
         Ok(position)
     }
 
+    pub fn get_items_pending_position(&self) -> Result<usize> {
+        // NOTE: stored in the unused bytes after disable royalties, which exist for every version
+        let position = self.get_disable_royalties_position()? + 1;
+        Ok(position)
+    }
+
+    pub fn get_items_pending(&self, data: &[u8]) -> Result<u16> {
+        let position = self.get_items_pending_position()?;
+        Ok(u16::from_le_bytes(
+            data[position..position + 2].try_into().unwrap(),
+        ))
+    }
+
     pub fn get_disable_primary_split_position(&self) -> Result<usize> {
         // NOTE: +1 for disable royalties bool, + 3 unused bytes and can be used for future purposes
         let position = self.get_disable_royalties_position()? + 4;
@@ -151,6 +236,484 @@ impl GumballMachine {
         ))
     }
 
+    pub fn set_total_proceeds_settled(
+        &self,
+        data: &mut [u8],
+        total_proceeds_settled: u64,
+    ) -> Result<()> {
+        let position = self.get_total_proceeds_settled_position()?;
+        data[position..position + 8].copy_from_slice(&total_proceeds_settled.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_tier_config_position(&self) -> Result<usize> {
+        let position = self.get_total_proceeds_settled_position()? + 8;
+        Ok(position)
+    }
+
+    pub fn get_tier_config(&self, data: &[u8]) -> Result<TierConfig> {
+        if self.version < 6 {
+            return Ok(TierConfig::default());
+        }
+
+        let position = self.get_tier_config_position()?;
+        let tier_config =
+            TierConfig::try_from_slice(&data[position..position + TierConfig::INIT_SPACE])?;
+        Ok(tier_config)
+    }
+
+    pub fn set_tier_config(&self, data: &mut [u8], tier_config: &TierConfig) -> Result<()> {
+        let position = self.get_tier_config_position()?;
+        data[position..position + TierConfig::INIT_SPACE]
+            .copy_from_slice(&tier_config.try_to_vec().unwrap());
+        Ok(())
+    }
+
+    pub fn get_randomness_config_position(&self) -> Result<usize> {
+        let position = self.get_tier_config_position()? + TierConfig::INIT_SPACE;
+        Ok(position)
+    }
+
+    pub fn get_randomness_config(&self, data: &[u8]) -> Result<RandomnessConfig> {
+        if self.version < 7 {
+            return Ok(RandomnessConfig::default());
+        }
+
+        let position = self.get_randomness_config_position()?;
+        let randomness_config = RandomnessConfig::try_from_slice(
+            &data[position..position + RandomnessConfig::INIT_SPACE],
+        )?;
+        Ok(randomness_config)
+    }
+
+    pub fn get_total_weight_position(&self) -> Result<usize> {
+        let position = self.get_randomness_config_position()? + RandomnessConfig::INIT_SPACE;
+        Ok(position)
+    }
+
+    pub fn get_total_weight(&self, data: &[u8]) -> Result<u64> {
+        let position = self.get_total_weight_position()?;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_total_weight(&self, data: &mut [u8], total_weight: u64) -> Result<()> {
+        let position = self.get_total_weight_position()?;
+        data[position..position + 8].copy_from_slice(&total_weight.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_total_weight_settled_position(&self) -> Result<usize> {
+        let position = self.get_total_weight_position()? + 8;
+        Ok(position)
+    }
+
+    pub fn get_total_weight_settled(&self, data: &[u8]) -> Result<u64> {
+        let position = self.get_total_weight_settled_position()?;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_total_weight_settled(
+        &self,
+        data: &mut [u8],
+        total_weight_settled: u64,
+    ) -> Result<()> {
+        let position = self.get_total_weight_settled_position()?;
+        data[position..position + 8].copy_from_slice(&total_weight_settled.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_add_item_request_expiry_position(&self) -> Result<usize> {
+        let position = self.get_total_weight_settled_position()? + 8;
+        Ok(position)
+    }
+
+    /// Seconds before an add item request expires, requests never expire before version 9.
+    pub fn get_add_item_request_expiry(&self, data: &[u8]) -> Result<i64> {
+        if self.version < 9 {
+            return Ok(0);
+        }
+
+        let position = self.get_add_item_request_expiry_position()?;
+        Ok(i64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_add_item_request_expiry(&self, data: &mut [u8], expiry: i64) -> Result<()> {
+        let position = self.get_add_item_request_expiry_position()?;
+        data[position..position + 8].copy_from_slice(&expiry.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_sale_ended_at_position(&self) -> Result<usize> {
+        let position = self.get_add_item_request_expiry_position()? + 8;
+        Ok(position)
+    }
+
+    /// Time the sale ended at, it is not recorded before version 10.
+    pub fn get_sale_ended_at(&self, data: &[u8]) -> Result<i64> {
+        if self.version < 10 {
+            return Ok(0);
+        }
+
+        let position = self.get_sale_ended_at_position()?;
+        Ok(i64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_sale_ended_at(&self, data: &mut [u8], sale_ended_at: i64) -> Result<()> {
+        let position = self.get_sale_ended_at_position()?;
+        data[position..position + 8].copy_from_slice(&sale_ended_at.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_force_close_grace_period_position(&self) -> Result<usize> {
+        let position = self.get_sale_ended_at_position()? + 8;
+        Ok(position)
+    }
+
+    /// Seconds after the sale ends before the machine can be force closed, force closing
+    /// is disabled before version 10.
+    pub fn get_force_close_grace_period(&self, data: &[u8]) -> Result<i64> {
+        if self.version < 10 {
+            return Ok(0);
+        }
+
+        let position = self.get_force_close_grace_period_position()?;
+        Ok(i64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_force_close_grace_period(&self, data: &mut [u8], grace_period: i64) -> Result<()> {
+        let position = self.get_force_close_grace_period_position()?;
+        data[position..position + 8].copy_from_slice(&grace_period.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_claim_deadline_config_position(&self) -> Result<usize> {
+        let position = self.get_force_close_grace_period_position()? + 8;
+        Ok(position)
+    }
+
+    /// Claim deadline of the drawn items, there is no deadline before version 11.
+    pub fn get_claim_deadline_config(&self, data: &[u8]) -> Result<ClaimDeadlineConfig> {
+        if self.version < 11 {
+            return Ok(ClaimDeadlineConfig::default());
+        }
+
+        let position = self.get_claim_deadline_config_position()?;
+        let claim_deadline_config = ClaimDeadlineConfig::try_from_slice(
+            &data[position..position + ClaimDeadlineConfig::INIT_SPACE],
+        )?;
+        Ok(claim_deadline_config)
+    }
+
+    pub fn get_instant_delivery_position(&self) -> Result<usize> {
+        let position = self.get_claim_deadline_config_position()? + ClaimDeadlineConfig::INIT_SPACE;
+        Ok(position)
+    }
+
+    /// Whether drawn items are transferred to the buyer when the draw is revealed, items
+    /// are always claimed separately before version 12.
+    pub fn is_instant_delivery(&self, data: &[u8]) -> Result<bool> {
+        if self.version < 12 {
+            return Ok(false);
+        }
+
+        let position = self.get_instant_delivery_position()?;
+        Ok(data[position] == 1)
+    }
+
+    pub fn set_instant_delivery(&self, data: &mut [u8], instant_delivery: bool) -> Result<()> {
+        let position = self.get_instant_delivery_position()?;
+        data[position] = instant_delivery as u8;
+        Ok(())
+    }
+
+    pub fn get_receipt_collection_position(&self) -> Result<usize> {
+        let position = self.get_instant_delivery_position()? + 1;
+        Ok(position)
+    }
+
+    /// Core collection of the draw receipts, receipts are not available before version 13.
+    pub fn get_receipt_collection(&self, data: &[u8]) -> Result<Option<Pubkey>> {
+        if self.version < 13 {
+            return Ok(None);
+        }
+
+        let position = self.get_receipt_collection_position()?;
+        let receipt_collection = Pubkey::try_from(&data[position..position + 32]).unwrap();
+        if receipt_collection == Pubkey::default() {
+            return Ok(None);
+        }
+
+        Ok(Some(receipt_collection))
+    }
+
+    pub fn set_receipt_collection(
+        &self,
+        data: &mut [u8],
+        receipt_collection: Pubkey,
+    ) -> Result<()> {
+        let position = self.get_receipt_collection_position()?;
+        data[position..position + 32].copy_from_slice(&receipt_collection.to_bytes());
+        Ok(())
+    }
+
+    pub fn get_draws_committed_position(&self) -> Result<usize> {
+        let position = self.get_receipt_collection_position()? + 32;
+        Ok(position)
+    }
+
+    /// Number of draws made, draws are not counted before version 15 so every pending
+    /// draw uses the same number (only one draw can be pending at a time).
+    pub fn get_draws_committed(&self, data: &[u8]) -> Result<u64> {
+        if self.version < 15 {
+            return Ok(0);
+        }
+
+        let position = self.get_draws_committed_position()?;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_draws_committed(&self, data: &mut [u8], draws_committed: u64) -> Result<()> {
+        if self.version < 15 {
+            return Ok(());
+        }
+
+        let position = self.get_draws_committed_position()?;
+        data[position..position + 8].copy_from_slice(&draws_committed.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_draws_revealed_position(&self) -> Result<usize> {
+        let position = self.get_draws_committed_position()? + 8;
+        Ok(position)
+    }
+
+    /// Number of draws revealed, draws are not counted before version 15.
+    pub fn get_draws_revealed(&self, data: &[u8]) -> Result<u64> {
+        if self.version < 15 {
+            return Ok(0);
+        }
+
+        let position = self.get_draws_revealed_position()?;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_draws_revealed(&self, data: &mut [u8], draws_revealed: u64) -> Result<()> {
+        if self.version < 15 {
+            return Ok(());
+        }
+
+        let position = self.get_draws_revealed_position()?;
+        data[position..position + 8].copy_from_slice(&draws_revealed.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_randomness_request_config_position(&self) -> Result<usize> {
+        let position = self.get_draws_revealed_position()? + 8;
+        Ok(position)
+    }
+
+    pub fn get_randomness_request_config(&self, data: &[u8]) -> Result<RandomnessRequestConfig> {
+        if self.version < 16 {
+            return Ok(RandomnessRequestConfig::default());
+        }
+
+        let position = self.get_randomness_request_config_position()?;
+        let randomness_request_config = RandomnessRequestConfig::try_from_slice(
+            &data[position..position + RandomnessRequestConfig::INIT_SPACE],
+        )?;
+        Ok(randomness_request_config)
+    }
+
+    pub fn get_total_revenue_drawn_position(&self) -> Result<usize> {
+        let position =
+            self.get_randomness_request_config_position()? + RandomnessRequestConfig::INIT_SPACE;
+        Ok(position)
+    }
+
+    pub fn get_total_revenue_drawn(&self, data: &[u8]) -> Result<u64> {
+        if self.version < 17 {
+            return Ok(0);
+        }
+
+        let position = self.get_total_revenue_drawn_position()?;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_total_revenue_drawn(&self, data: &mut [u8], total_revenue_drawn: u64) -> Result<()> {
+        if self.version < 17 {
+            return Ok(());
+        }
+
+        let position = self.get_total_revenue_drawn_position()?;
+        data[position..position + 8].copy_from_slice(&total_revenue_drawn.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn get_sol_prizes_held_position(&self) -> Result<usize> {
+        let position = self.get_total_revenue_drawn_position()? + 8;
+        Ok(position)
+    }
+
+    pub fn get_sol_prizes_held(&self, data: &[u8]) -> Result<u64> {
+        if self.version < 18 {
+            return Ok(0);
+        }
+
+        let position = self.get_sol_prizes_held_position()?;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_sol_prizes_held(&self, data: &mut [u8], sol_prizes_held: u64) -> Result<()> {
+        if self.version < 18 {
+            return Ok(());
+        }
+
+        let position = self.get_sol_prizes_held_position()?;
+        data[position..position + 8].copy_from_slice(&sol_prizes_held.to_le_bytes());
+        Ok(())
+    }
+
+    /// Returns the revenue that was added since the last draw and assigns it to the
+    /// current one. Payment guards increment the revenue before the draw is made, so
+    /// this is the price paid for the draw. Draw revenue is not tracked before version
+    /// 17.
+    pub fn take_draw_revenue(&self, data: &mut [u8]) -> Result<u64> {
+        if self.version < 17 {
+            return Ok(0);
+        }
+
+        let draw_revenue = self
+            .total_revenue
+            .checked_sub(self.get_total_revenue_drawn(data)?)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        self.set_total_revenue_drawn(data, self.total_revenue)?;
+        Ok(draw_revenue)
+    }
+
+    /// Whether the claim deadline of the drawn items has passed.
+    pub fn is_claim_deadline_passed(&self, data: &[u8]) -> Result<bool> {
+        let claim_deadline_config = self.get_claim_deadline_config(data)?;
+        let sale_ended_at = self.get_sale_ended_at(data)?;
+        if !claim_deadline_config.is_enabled() || sale_ended_at == 0 {
+            return Ok(false);
+        }
+
+        let claim_deadline = sale_ended_at
+            .checked_add(claim_deadline_config.claim_period)
+            .ok_or(GumballError::NumericalOverflowError)?;
+        Ok(Clock::get()?.unix_timestamp >= claim_deadline)
+    }
+
+    /// Proceeds weight of an item, every item has the same weight before version 8.
+    pub fn get_item_weight(&self, data: &[u8], index: u32) -> Result<u32> {
+        if !self.is_value_weighted() {
+            return Ok(1);
+        }
+
+        let position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (index as usize) * self.get_config_line_size()
+            + CONFIG_LINE_V3_SIZE;
+        Ok(u32::from_le_bytes(
+            data[position..position + 4].try_into().unwrap(),
+        ))
+    }
+
+    pub fn get_item_tier(&self, data: &[u8], index: u32) -> Result<u8> {
+        if self.version < 6 {
+            return Ok(0);
+        }
+
+        let position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (index as usize) * self.get_config_line_size()
+            + CONFIG_LINE_V2_SIZE;
+        Ok(data[position])
+    }
+
+    /// Slot of the draw that assigned the item to its buyer, draw slots are not stored
+    /// before version 14.
+    pub fn get_item_draw_slot(&self, data: &[u8], index: u32) -> Result<u64> {
+        if self.version < 14 {
+            return Ok(0);
+        }
+
+        let position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (index as usize) * self.get_config_line_size()
+            + CONFIG_LINE_V4_SIZE;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_item_draw_slot(&self, data: &mut [u8], index: u32, draw_slot: u64) -> Result<()> {
+        if self.version < 14 {
+            return Ok(());
+        }
+
+        let position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (index as usize) * self.get_config_line_size()
+            + CONFIG_LINE_V4_SIZE;
+        data[position..position + 8].copy_from_slice(&draw_slot.to_le_bytes());
+        Ok(())
+    }
+
+    /// Price paid for an item by its buyer, prices are not stored before version 17.
+    pub fn get_item_price(&self, data: &[u8], index: u32) -> Result<u64> {
+        if self.version < 17 {
+            return Ok(0);
+        }
+
+        let position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (index as usize) * self.get_config_line_size()
+            + CONFIG_LINE_V5_SIZE;
+        Ok(u64::from_le_bytes(
+            data[position..position + 8].try_into().unwrap(),
+        ))
+    }
+
+    pub fn set_item_price(&self, data: &mut [u8], index: u32, price: u64) -> Result<()> {
+        if self.version < 17 {
+            return Ok(());
+        }
+
+        let position = GUMBALL_MACHINE_SIZE
+            + 4
+            + (index as usize) * self.get_config_line_size()
+            + CONFIG_LINE_V5_SIZE;
+        data[position..position + 8].copy_from_slice(&price.to_le_bytes());
+        Ok(())
+    }
+
+    pub fn is_tiered(&self) -> bool {
+        self.version >= 6
+    }
+
+    pub fn is_value_weighted(&self) -> bool {
+        self.version >= 8
+    }
+
     pub fn can_edit_items(&self) -> bool {
         self.state == GumballState::None || self.state == GumballState::DetailsFinalized
     }
@@ -182,7 +745,7 @@ pub struct FeeConfig {
 pub struct BuyBackConfig {
     /// Whether buying back prizes is enabled
     pub enabled: bool,
-    /// Whether buying back prizes should be added back to the gumball machine (not yet supported)
+    /// Whether buying back prizes should be added back to the gumball machine to be drawn again
     pub to_gumball_machine: bool,
     /// Authority that must sign when buying back prizes, to ensure pricing is correct
     pub oracle_signer: Pubkey,
@@ -196,6 +759,90 @@ pub struct BuyBackConfig {
     pub cutoff_pct: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, Default)]
+pub struct RandomnessConfig {
+    /// Program that owns the randomness accounts, the default pubkey means slot hashes are used
+    pub provider: Pubkey,
+    /// Offset of the 32 bytes random value in the randomness account data
+    pub value_offset: u32,
+    /// Offset of the slot (u64) at which the random value was produced in the randomness account data
+    pub slot_offset: u32,
+}
+
+impl RandomnessConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.provider != Pubkey::default()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, Default)]
+pub struct RandomnessRequestConfig {
+    /// Randomness account of the provider used by every draw
+    pub account: Pubkey,
+    /// Discriminator of the randomness account (first 8 bytes of its data)
+    pub discriminator: [u8; 8],
+    /// Offset of the slot (u64) at which the current value was requested in the randomness account data
+    pub request_slot_offset: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, Default)]
+pub struct ClaimDeadlineConfig {
+    /// Seconds after the sale ends that drawn items can be claimed, 0 means there is no deadline
+    pub claim_period: i64,
+    /// Receiver of the items that were not claimed before the deadline
+    pub destination: UnclaimedItemDestination,
+}
+
+impl ClaimDeadlineConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.claim_period > 0
+    }
+}
+
+#[derive(
+    Copy, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace, Default,
+)]
+pub enum UnclaimedItemDestination {
+    #[default]
+    Seller,
+    Authority,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, Default)]
+pub struct TierConfig {
+    /// Draw weight of each tier, the odds of drawing an item are proportional to its tier weight
+    pub weights: [u16; 8],
+    /// Number of items assigned to each tier
+    pub item_counts: [u32; 8],
+    /// Number of items in each tier that have not been drawn yet
+    pub items_available: [u32; 8],
+}
+
+impl TierConfig {
+    /// Tier config with every tier having the same odds.
+    pub fn new() -> Self {
+        Self {
+            weights: [1; MAX_TIERS],
+            ..Default::default()
+        }
+    }
+
+    /// Sum of the weights of every item that has not been drawn yet.
+    pub fn total_weight(&self) -> u64 {
+        (0..MAX_TIERS)
+            .map(|tier| self.weights[tier] as u64 * self.items_available[tier] as u64)
+            .sum()
+    }
+
+    /// Position of the first mint index of a tier, relative to the start of the mint indices.
+    pub fn tier_start(&self, tier: usize) -> usize {
+        self.item_counts[..tier]
+            .iter()
+            .map(|count| *count as usize)
+            .sum()
+    }
+}
+
 /// Config line struct for storing asset (NFT) data pre-mint.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug)]
 pub struct ConfigLineInput {
@@ -230,6 +877,9 @@ pub struct ConfigLine {
 }
 
 /// Config line struct for storing asset data.
+///
+/// Version 6 lines are followed by the tier (u8) of the item, and version 8 lines by
+/// the proceeds weight (u32) of the item.
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, InitSpace)]
 pub struct ConfigLineV2 {
     /// Mint account of the asset.
@@ -250,6 +900,9 @@ pub enum TokenStandard {
     Core,
     Fungible,
     ProgrammableNonFungible,
+    Token2022Fungible,
+    CompressedNonFungible,
+    Sol,
 }
 
 #[derive(Copy, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -258,6 +911,7 @@ pub enum GumballState {
     DetailsFinalized, // Sellers invited so only some details can be updated
     SaleLive, // Sale started, can now mint items. Cannot no longer update details or add items.
     SaleEnded, // Sale ended, can now settle items
+    Paused,   // Sale paused, items cannot be drawn or added but drawn items can still be claimed
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -1,7 +1,9 @@
 pub use add_item_request::*;
 pub use gumball_machine::*;
+pub use pending_draw::*;
 pub use seller_history::*;
 
 pub mod add_item_request;
 pub mod gumball_machine;
+pub mod pending_draw;
 pub mod seller_history;
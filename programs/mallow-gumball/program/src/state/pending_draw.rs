@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Pending draw state to reserve items for a buyer until the draw is revealed.
+#[account]
+#[derive(Debug)]
+pub struct PendingDraw {
+    /// Gumball machine the items were reserved on
+    pub gumball_machine: Pubkey,
+    /// Wallet that will receive the items once revealed
+    pub buyer: Pubkey,
+    /// Wallet that paid the rent for this account
+    pub payer: Pubkey,
+    /// Slot whose hash is used to select the items
+    pub target_slot: u64,
+    /// Number of items reserved
+    pub quantity: u16,
+    /// Randomness account of the provider used to select the items (default pubkey when slot hashes are used)
+    pub randomness: Pubkey,
+    /// Index of the item given back when the draw is a reroll
+    pub rerolled_index: Option<u32>,
+    /// Number of the draw on the gumball machine (seed of this account)
+    pub draw_number: u64,
+    /// Slot of the randomness request the draw is bound to (0 when slot hashes are used)
+    pub randomness_request_slot: u64,
+    /// Revenue paid for the draw, split between the drawn items when revealed
+    pub revenue: u64,
+}
+
+impl PendingDraw {
+    pub const SPACE: usize = 8 //discriminator
+    + 32 // gumball machine
+    + 32 // buyer
+    + 32 // payer
+    + 8 // target slot
+    + 2 // quantity
+    + 32 // randomness
+    + 1 + 4 // rerolled index
+    + 8 // draw number
+    + 8 // randomness request slot
+    + 8; // revenue
+}
@@ -1,14 +1,26 @@
 use crate::{
-    constants::GUMBALL_MACHINE_SIZE, instructions::AddItemArgs, ConfigLine, ConfigLineV2,
-    GumballError, GumballMachine, GumballState, SellerHistory, TokenStandard,
+    constants::GUMBALL_MACHINE_SIZE,
+    instructions::{AddItemArgs, CompressedNftArgs},
+    ConfigLine, ConfigLineV2, GumballError, GumballMachine, GumballState, RandomnessConfig,
+    RandomnessRequestConfig, SellerHistory, TokenStandard,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::token::approve;
-use anchor_spl::token::close_account;
 use anchor_spl::token::Approve;
-use anchor_spl::token::CloseAccount;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        non_transferable::NonTransferable,
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::TransferHook,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account as Token2022Account, Mint as Token2022Mint},
+};
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount};
 use arrayref::array_ref;
+use mpl_bubblegum::instructions::TransferCpiBuilder as BubblegumTransferCpiBuilder;
 use mpl_core::{
     accounts::BaseAssetV1,
     fetch_plugin,
@@ -35,10 +47,14 @@ use mpl_token_metadata::{
 };
 use solana_program::{
     account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
     program_memory::sol_memcmp,
     pubkey::{Pubkey, PUBKEY_BYTES},
 };
-use utils::{assert_keys_equal, get_auth_payload, transfer_spl, verify_proof};
+use utils::{
+    assert_keys_equal, get_auth_payload, transfer_spl, transfer_spl_checked, verify_proof,
+};
 
 /// Anchor wrapper for Token program.
 #[derive(Debug, Clone)]
@@ -69,6 +85,7 @@ pub fn assert_can_add_item(
     let AddItemArgs {
         seller_proof_path,
         index,
+        weight,
     } = args;
 
     // Having an index means we're re-adding an item
@@ -83,6 +100,18 @@ pub fn assert_can_add_item(
 
     let seller = seller_history.seller;
 
+    if let Some(weight) = weight {
+        // Only the curator can declare the weight of an item, re-added items keep their weight
+        require!(
+            seller == gumball_machine.authority,
+            GumballError::InvalidAuthority
+        );
+        require!(
+            *weight > 0 && index.is_none(),
+            GumballError::InvalidProceedsWeight
+        );
+    }
+
     if seller == gumball_machine.authority {
         return Ok(());
     }
@@ -128,7 +157,7 @@ pub fn assert_can_request_add_item(
 }
 
 pub fn assert_config_line(
-    gumball_machine: &Box<Account<GumballMachine>>,
+    gumball_machine: &Account<GumballMachine>,
     index: u32,
     config_line: ConfigLine,
     is_burnt: bool,
@@ -170,6 +199,30 @@ pub fn assert_config_line(
     Ok(())
 }
 
+/// Token standard recorded in the config line of an item.
+pub fn get_item_token_standard(
+    gumball_machine: &Account<GumballMachine>,
+    index: u32,
+) -> Result<TokenStandard> {
+    let account_info = gumball_machine.to_account_info();
+    let data = account_info.data.borrow();
+    let count = get_config_count(&data)?;
+
+    if index >= count as u32 {
+        return err!(GumballError::IndexGreaterThanLength);
+    }
+
+    let config_line_position =
+        GUMBALL_MACHINE_SIZE + 4 + (index as usize) * gumball_machine.get_config_line_size();
+    let token_standard =
+        TokenStandard::try_from_slice(&data[config_line_position + 96..config_line_position + 97])
+            .map_err(|_| GumballError::InvalidTokenStandard)?;
+
+    drop(data);
+
+    Ok(token_standard)
+}
+
 pub fn assert_config_line_values(
     gumball_machine_data: &[u8],
     config_line_position: usize,
@@ -196,6 +249,58 @@ pub fn assert_config_line_values(
     Ok(config_line)
 }
 
+/// Decrements the seller histories of removed items, closing the ones left without items.
+/// Expects a (seller history, seller) pair for every removed item.
+pub fn decrement_seller_histories<'info>(
+    gumball_machine: Pubkey,
+    removed_items: &[(&'info AccountInfo<'info>, &'info AccountInfo<'info>)],
+) -> Result<()> {
+    // Number of removed items for each seller history, keyed by its first occurrence
+    let mut removed_counts: Vec<(usize, u64)> = vec![];
+
+    for (i, (seller_history, seller)) in removed_items.iter().enumerate() {
+        match removed_counts
+            .iter_mut()
+            .find(|(first, _)| removed_items[*first].0.key == seller_history.key)
+        {
+            Some((first, count)) => {
+                require!(
+                    removed_items[*first].1.key == seller.key,
+                    GumballError::InvalidSeller
+                );
+                *count += 1;
+            }
+            None => removed_counts.push((i, 1)),
+        }
+    }
+
+    for (first, count) in removed_counts {
+        let (seller_history_info, seller) = removed_items[first];
+        let mut seller_history = Account::<SellerHistory>::try_from(seller_history_info)?;
+        require!(
+            seller_history.gumball_machine == gumball_machine,
+            GumballError::InvalidGumballMachine
+        );
+        require!(
+            seller_history.seller == seller.key(),
+            GumballError::InvalidSeller
+        );
+
+        seller_history.item_count = seller_history
+            .item_count
+            .checked_sub(count)
+            .ok_or(GumballError::NumericalOverflowError)?;
+
+        if seller_history.item_count == 0 {
+            seller_history.close(seller.to_account_info())?;
+        } else {
+            seller_history.exit(&crate::ID)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Return the current number of lines written to the account.
 pub fn get_config_count(data: &[u8]) -> Result<usize> {
     Ok(u32::from_le_bytes(*array_ref![data, GUMBALL_MACHINE_SIZE, 4]) as usize)
@@ -212,9 +317,7 @@ pub fn get_core_asset_update_authority<'info>(
     // Considered a primary sale if owner is the update authority (most likely creator)
     let asset = Box::<Asset>::try_from(asset_info)?;
     match asset.base.update_authority {
-        UpdateAuthority::Address(address) => {
-            return Ok((Some(address), asset));
-        }
+        UpdateAuthority::Address(address) => Ok((Some(address), asset)),
         UpdateAuthority::Collection(collection_key) => {
             if let Some(collection_info) = collection_info {
                 assert_keys_equal(
@@ -223,12 +326,12 @@ pub fn get_core_asset_update_authority<'info>(
                     "Invalid collection key",
                 )?;
                 let collection = Box::<Collection>::try_from(collection_info)?;
-                return Ok((Some(collection.base.update_authority), asset));
+                Ok((Some(collection.base.update_authority), asset))
             } else {
-                return Ok((None, asset));
+                Ok((None, asset))
             }
         }
-        UpdateAuthority::None => return Ok((None, asset)),
+        UpdateAuthority::None => Ok((None, asset)),
     }
 }
 
@@ -243,7 +346,96 @@ pub fn get_bit_byte_info(base_position: usize, position: usize) -> Result<(usize
         .ok_or(GumballError::NumericalOverflowError)?;
     let mask = u8::pow(2, bit as u32);
 
-    return Ok((byte_position, bit, mask));
+    Ok((byte_position, bit, mask))
+}
+
+/// Returns the hash of the first slot at or after `target_slot` from the SlotHashes sysvar.
+///
+/// Skipped slots have no entry, so the next produced slot is used instead. Returns `None`
+/// when the target slot is older than every entry in the sysvar.
+pub fn get_slot_hash(
+    recent_slothashes: &AccountInfo,
+    target_slot: u64,
+) -> Result<Option<[u8; 32]>> {
+    let data = recent_slothashes.data.borrow();
+    // entries are (slot, hash) pairs sorted from the most recent slot
+    let entries = u64::from_le_bytes(*array_ref![data, 0, 8]) as usize;
+    let mut slot_hash = None;
+
+    for i in 0..entries {
+        let position = 8 + i * 40;
+        let slot = u64::from_le_bytes(*array_ref![data, position, 8]);
+
+        if slot < target_slot {
+            // the target slot (or a later one) has not been produced yet
+            return match slot_hash {
+                Some(hash) => Ok(Some(hash)),
+                None => err!(GumballError::RevealNotReady),
+            };
+        }
+
+        slot_hash = Some(*array_ref![data, position + 8, 32]);
+
+        if slot == target_slot {
+            return Ok(slot_hash);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Current request of a randomness provider account.
+pub struct RandomnessRequest {
+    /// Slot at which the value was requested.
+    pub request_slot: u64,
+    /// Slot at which the value was produced.
+    pub value_slot: u64,
+    /// Random value, only meaningful once the request is fulfilled.
+    pub value: [u8; 32],
+}
+
+impl RandomnessRequest {
+    /// Whether the provider produced the value of the request.
+    pub fn is_fulfilled(&self) -> bool {
+        self.value_slot >= self.request_slot
+    }
+}
+
+/// Reads the current request of a randomness provider account.
+///
+/// The account must be the randomness account of the gumball machine, owned by the
+/// provider program and hold the configured discriminator, so an account with a known
+/// value cannot be passed instead.
+pub fn get_randomness_request(
+    randomness: &AccountInfo,
+    randomness_config: &RandomnessConfig,
+    randomness_request_config: &RandomnessRequestConfig,
+) -> Result<RandomnessRequest> {
+    if !cmp_pubkeys(randomness.key, &randomness_request_config.account)
+        || !cmp_pubkeys(randomness.owner, &randomness_config.provider)
+    {
+        return err!(GumballError::InvalidRandomnessAccount);
+    }
+
+    let data = randomness.data.borrow();
+    let value_offset = randomness_config.value_offset as usize;
+    let slot_offset = randomness_config.slot_offset as usize;
+    let request_slot_offset = randomness_request_config.request_slot_offset as usize;
+
+    if data.len() < 8
+        || data.len() < value_offset + 32
+        || data.len() < slot_offset + 8
+        || data.len() < request_slot_offset + 8
+        || data[..8] != randomness_request_config.discriminator
+    {
+        return err!(GumballError::InvalidRandomnessAccount);
+    }
+
+    Ok(RandomnessRequest {
+        request_slot: u64::from_le_bytes(*array_ref![data, request_slot_offset, 8]),
+        value_slot: u64::from_le_bytes(*array_ref![data, slot_offset, 8]),
+        value: *array_ref![data, value_offset, 32],
+    })
 }
 
 pub fn approve_and_freeze_core_asset<'a>(
@@ -258,8 +450,8 @@ pub fn approve_and_freeze_core_asset<'a>(
     let new_authority = new_authority_info.key();
 
     // Approve
-    if let Err(_) =
-        fetch_plugin::<BaseAssetV1, TransferDelegate>(asset_info, PluginType::TransferDelegate)
+    if fetch_plugin::<BaseAssetV1, TransferDelegate>(asset_info, PluginType::TransferDelegate)
+        .is_err()
     {
         AddPluginV1CpiBuilder::new(mpl_core_program)
             .asset(asset_info)
@@ -285,8 +477,8 @@ pub fn approve_and_freeze_core_asset<'a>(
     }
 
     // Freeze
-    if let Err(_) =
-        fetch_plugin::<BaseAssetV1, TransferDelegate>(asset_info, PluginType::FreezeDelegate)
+    if fetch_plugin::<BaseAssetV1, TransferDelegate>(asset_info, PluginType::FreezeDelegate)
+        .is_err()
     {
         AddPluginV1CpiBuilder::new(mpl_core_program)
             .asset(asset_info)
@@ -297,7 +489,7 @@ pub fn approve_and_freeze_core_asset<'a>(
                 address: new_authority,
             })
             .system_program(system_program)
-            .invoke_signed(&[&new_authority_seeds])?;
+            .invoke_signed(&[new_authority_seeds])?;
     } else {
         ApprovePluginAuthorityV1CpiBuilder::new(mpl_core_program)
             .asset(asset_info)
@@ -317,12 +509,93 @@ pub fn approve_and_freeze_core_asset<'a>(
             .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
             .authority(Some(new_authority_info))
             .system_program(system_program)
-            .invoke_signed(&[&new_authority_seeds])?;
+            .invoke_signed(&[new_authority_seeds])?;
     }
 
     Ok(())
 }
 
+/// Transfers a compressed NFT. The proof nodes of the leaf are passed in `proof`.
+pub fn transfer_compressed_nft<'a>(
+    leaf_owner: &AccountInfo<'a>,
+    new_leaf_owner: &AccountInfo<'a>,
+    merkle_tree: &AccountInfo<'a>,
+    tree_config: &AccountInfo<'a>,
+    bubblegum_program: &AccountInfo<'a>,
+    compression_program: &AccountInfo<'a>,
+    log_wrapper: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    proof: &[AccountInfo<'a>],
+    compressed_nft: &CompressedNftArgs,
+    signer_seeds: Option<&[&[u8]]>,
+) -> Result<()> {
+    let mut transfer_builder = BubblegumTransferCpiBuilder::new(bubblegum_program);
+    transfer_builder
+        .tree_config(tree_config)
+        // delegated leaves must be revoked before they can be added
+        .leaf_owner(leaf_owner, true)
+        .leaf_delegate(leaf_owner, false)
+        .new_leaf_owner(new_leaf_owner)
+        .merkle_tree(merkle_tree)
+        .log_wrapper(log_wrapper)
+        .compression_program(compression_program)
+        .system_program(system_program)
+        .root(compressed_nft.root)
+        .data_hash(compressed_nft.data_hash)
+        .creator_hash(compressed_nft.creator_hash)
+        .nonce(compressed_nft.nonce)
+        .index(compressed_nft.index);
+
+    for node in proof {
+        transfer_builder.add_remaining_account(node, false, false);
+    }
+
+    if let Some(signer_seeds) = signer_seeds {
+        transfer_builder.invoke_signed(&[signer_seeds])?;
+    } else {
+        transfer_builder.invoke()?;
+    }
+
+    Ok(())
+}
+
+/// Verifies that `leaf` is at `index` of the merkle tree using the account compression program.
+pub fn verify_compressed_nft_leaf<'a>(
+    merkle_tree: &AccountInfo<'a>,
+    compression_program: &AccountInfo<'a>,
+    proof: &[AccountInfo<'a>],
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+) -> Result<()> {
+    // sha256("global:verify_leaf")[..8]
+    const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    let mut account_infos = vec![merkle_tree.clone()];
+    for node in proof {
+        accounts.push(AccountMeta::new_readonly(node.key(), false));
+        account_infos.push(node.clone());
+    }
+    account_infos.push(compression_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: compression_program.key(),
+            accounts,
+            data,
+        },
+        &account_infos,
+    )?;
+
+    Ok(())
+}
+
 pub fn thaw_and_revoke_core_asset<'a>(
     payer: &AccountInfo<'a>,
     owner: &AccountInfo<'a>,
@@ -341,7 +614,7 @@ pub fn thaw_and_revoke_core_asset<'a>(
         .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
         .authority(Some(authority))
         .system_program(system_program)
-        .invoke_signed(&[&authority_seeds])?;
+        .invoke_signed(&[authority_seeds])?;
 
     // Can only remove plugins if the seller is the authority
     if owner.key() == payer.key() {
@@ -371,7 +644,7 @@ pub fn thaw_and_revoke_core_asset<'a>(
             .plugin_type(PluginType::FreezeDelegate)
             .authority(Some(authority))
             .system_program(system_program)
-            .invoke_signed(&[&authority_seeds])?;
+            .invoke_signed(&[authority_seeds])?;
 
         // Revoke
         RevokePluginAuthorityV1CpiBuilder::new(mpl_core_program)
@@ -381,7 +654,7 @@ pub fn thaw_and_revoke_core_asset<'a>(
             .plugin_type(PluginType::TransferDelegate)
             .authority(Some(authority))
             .system_program(system_program)
-            .invoke_signed(&[&authority_seeds])?;
+            .invoke_signed(&[authority_seeds])?;
     }
 
     Ok(())
@@ -500,7 +773,7 @@ pub fn approve_and_freeze_nft_v2<'a>(
                 token_program,
             },
         )
-        .invoke_signed(&[&new_authority_seeds])?;
+        .invoke_signed(&[new_authority_seeds])?;
     }
 
     Ok(())
@@ -719,7 +992,7 @@ pub fn thaw_nft<'a>(
                 token_program,
             },
         )
-        .invoke_signed(&[&authority_seeds])?;
+        .invoke_signed(&[authority_seeds])?;
     }
 
     Ok(())
@@ -728,7 +1001,7 @@ pub fn thaw_nft<'a>(
 pub fn transfer_and_close_if_empty<'a>(
     payer: &AccountInfo<'a>,
     authority: &AccountInfo<'a>,
-    token_account: &mut Box<Account<'a, TokenAccount>>,
+    token_account: &mut Box<InterfaceAccount<'a, TokenAccount>>,
     recipient: &AccountInfo<'a>,
     recipient_token_account: &AccountInfo<'a>,
     mint: &AccountInfo<'a>,
@@ -740,7 +1013,7 @@ pub fn transfer_and_close_if_empty<'a>(
     amount: u64,
 ) -> Result<()> {
     if amount > 0 {
-        transfer_spl(
+        transfer_spl_checked(
             authority,
             recipient,
             &token_account.to_account_info(),
@@ -751,15 +1024,17 @@ pub fn transfer_and_close_if_empty<'a>(
             token_program,
             system_program,
             Some(authority),
-            Some(&auth_seeds),
+            Some(auth_seeds),
             None,
             amount,
+            get_mint_decimals(mint)?,
         )?;
         token_account.reload()?;
     }
 
-    // Close the token account back to authority if token account is empty
-    if token_account.amount == 0 {
+    // Close the token account back to authority if token account is empty (Token-2022
+    // accounts holding withheld transfer fees cannot be closed)
+    if token_account.amount == 0 && !has_withheld_transfer_fees(&token_account.to_account_info())? {
         close_account(CpiContext::new_with_signer(
             token_program.to_account_info(),
             CloseAccount {
@@ -774,11 +1049,104 @@ pub fn transfer_and_close_if_empty<'a>(
     Ok(())
 }
 
+/// Token standard of a fungible item, based on the token program that owns the mint.
+pub fn get_fungible_token_standard(mint: &AccountInfo) -> TokenStandard {
+    if *mint.owner == spl_token_2022::ID {
+        TokenStandard::Token2022Fungible
+    } else {
+        TokenStandard::Fungible
+    }
+}
+
+/// Returns the token program of a fungible item. Instructions that also transfer
+/// payments take the Token-2022 program as a separate optional account.
+pub fn get_fungible_token_program<'a, 'b>(
+    token_standard: TokenStandard,
+    token_program: &'b AccountInfo<'a>,
+    token_2022_program: Option<&'b AccountInfo<'a>>,
+) -> Result<&'b AccountInfo<'a>> {
+    match token_standard {
+        TokenStandard::Fungible => Ok(token_program),
+        TokenStandard::Token2022Fungible => {
+            Ok(token_2022_program.ok_or(GumballError::InvalidTokenProgram)?)
+        }
+        _ => err!(GumballError::InvalidTokenStandard),
+    }
+}
+
+pub fn get_mint_decimals(mint: &AccountInfo) -> Result<u8> {
+    let data = mint.data.borrow();
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+    Ok(mint.base.decimals)
+}
+
+/// Rejects Token-2022 mints that cannot be transferred out of the gumball machine.
+///
+/// Transfer hooks need extra accounts that the claim, settle and remove instructions do not
+/// pass, and a permanent delegate could move the tokens held by the authority PDA.
+pub fn assert_transferable_mint(mint: &AccountInfo) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint.data.borrow();
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+    require!(
+        mint.get_extension::<NonTransferable>().is_err(),
+        GumballError::NonTransferableMint
+    );
+    require!(
+        mint.get_extension::<TransferHook>().is_err()
+            && mint.get_extension::<PermanentDelegate>().is_err(),
+        GumballError::UnsupportedMintExtension
+    );
+
+    Ok(())
+}
+
+/// Returns the amount to transfer so that `amount` is received after the
+/// Token-2022 transfer fee of the mint (if any).
+pub fn get_amount_with_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(amount);
+    }
+
+    let data = mint.data.borrow();
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+    let fee = if let Ok(transfer_fee_config) = mint.get_extension::<TransferFeeConfig>() {
+        transfer_fee_config
+            .calculate_inverse_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(GumballError::NumericalOverflowError)?
+    } else {
+        0
+    };
+
+    Ok(amount
+        .checked_add(fee)
+        .ok_or(GumballError::NumericalOverflowError)?)
+}
+
+fn has_withheld_transfer_fees(token_account: &AccountInfo) -> Result<bool> {
+    if *token_account.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let data = token_account.data.borrow();
+    let token_account = StateWithExtensions::<Token2022Account>::unpack(&data)?;
+    Ok(token_account
+        .get_extension::<TransferFeeAmount>()
+        .map(|transfer_fee_amount| u64::from(transfer_fee_amount.withheld_amount) > 0)
+        .unwrap_or(false))
+}
+
 #[macro_export]
 macro_rules! try_from {
-    ($ty: ty, $acc: expr) => {
-        <$ty>::try_from(unsafe { std::mem::transmute::<_, &AccountInfo<'_>>($acc.as_ref()) })
-    };
+    ($ty: ty, $acc: expr) => {{
+        let account_info: &AccountInfo<'_> = $acc.as_ref();
+        <$ty>::try_from(unsafe {
+            std::mem::transmute::<&AccountInfo<'_>, &AccountInfo<'_>>(account_info)
+        })
+    }};
 }
 
 #[cfg(test)]
@@ -797,4 +1165,104 @@ pub mod tests {
         let key2 = Pubkey::new_unique();
         assert!(!cmp_pubkeys(&key1, &key2));
     }
+
+    fn randomness_configs(
+        account: Pubkey,
+        provider: Pubkey,
+    ) -> (RandomnessConfig, RandomnessRequestConfig) {
+        (
+            RandomnessConfig {
+                provider,
+                value_offset: 24,
+                slot_offset: 16,
+            },
+            RandomnessRequestConfig {
+                account,
+                discriminator: [7; 8],
+                request_slot_offset: 8,
+            },
+        )
+    }
+
+    #[test]
+    fn read_randomness_request() {
+        let key = Pubkey::new_unique();
+        let provider = Pubkey::new_unique();
+        let (randomness_config, randomness_request_config) = randomness_configs(key, provider);
+        let mut lamports = 0;
+        let mut data = [0u8; 56];
+        data[..8].copy_from_slice(&[7; 8]);
+        data[8..16].copy_from_slice(&10u64.to_le_bytes());
+        data[16..24].copy_from_slice(&9u64.to_le_bytes());
+        data[24..56].copy_from_slice(&[1; 32]);
+        let randomness = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &provider,
+            false,
+            0,
+        );
+
+        let request =
+            get_randomness_request(&randomness, &randomness_config, &randomness_request_config)
+                .unwrap();
+        assert_eq!(request.request_slot, 10);
+        assert!(!request.is_fulfilled());
+        assert_eq!(request.value, [1; 32]);
+    }
+
+    #[test]
+    fn reject_randomness_account_with_another_discriminator() {
+        let key = Pubkey::new_unique();
+        let provider = Pubkey::new_unique();
+        let (randomness_config, randomness_request_config) = randomness_configs(key, provider);
+        let mut lamports = 0;
+        let mut data = [0u8; 56];
+        let randomness = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &provider,
+            false,
+            0,
+        );
+
+        assert!(get_randomness_request(
+            &randomness,
+            &randomness_config,
+            &randomness_request_config
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn reject_short_randomness_account() {
+        let key = Pubkey::new_unique();
+        let provider = Pubkey::new_unique();
+        let (randomness_config, randomness_request_config) = randomness_configs(key, provider);
+        let mut lamports = 0;
+        let mut data = [7u8; 40];
+        let randomness = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &provider,
+            false,
+            0,
+        );
+
+        assert!(get_randomness_request(
+            &randomness,
+            &randomness_config,
+            &randomness_request_config
+        )
+        .is_err());
+    }
 }
@@ -1,13 +1,18 @@
 use crate::error::Error;
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::associated_token::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
 use anchor_spl::token::spl_token::state::Account as SplAccount;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::StateWithExtensions, state::Account as Token2022Account,
+};
 use mpl_token_metadata::accounts::Metadata;
 use solana_program::program_pack::{IsInitialized, Pack};
 use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 pub fn is_native_mint(key: Pubkey) -> bool {
-    return key == anchor_spl::token::spl_token::native_mint::ID;
+    key == anchor_spl::token::spl_token::native_mint::ID
 }
 
 pub fn assert_keys_equal(key1: Pubkey, key2: Pubkey, error_message: &str) -> Result<()> {
@@ -32,6 +37,27 @@ pub fn assert_is_ata(ata: &AccountInfo, wallet: &Pubkey, mint: &Pubkey) -> Resul
     Ok(ata_account)
 }
 
+/// Asserts that the account is the associated token account of `wallet` for `mint`
+/// owned by `token_program` (supports both SPL Token and Token-2022 accounts).
+pub fn assert_is_ata_with_program(
+    ata: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    assert_owned_by(ata, token_program)?;
+    let data = ata.data.borrow();
+    let ata_account = StateWithExtensions::<Token2022Account>::unpack(&data)?;
+    assert_keys_equal(ata_account.base.owner, *wallet, "Invalid ATA owner")?;
+    assert_keys_equal(ata_account.base.mint, *mint, "Invalid ATA mint")?;
+    assert_keys_equal(
+        get_associated_token_address_with_program_id(wallet, mint, token_program),
+        *ata.key,
+        "Invalid ATA address",
+    )?;
+    Ok(())
+}
+
 pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
     if account.owner != owner {
         err!(Error::InvalidOwner)
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::types::Creator;
+use solana_program::keccak;
+
+use crate::RoyaltyInfo;
+
+/// Hash of a compressed NFT leaf (bubblegum `LeafSchema::V1`).
+pub fn hash_compressed_nft_leaf(
+    id: &Pubkey,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    nonce: u64,
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+) -> [u8; 32] {
+    keccak::hashv(&[
+        &[1], // leaf schema version
+        id.as_ref(),
+        owner.as_ref(),
+        delegate.as_ref(),
+        &nonce.to_le_bytes(),
+        data_hash,
+        creator_hash,
+    ])
+    .to_bytes()
+}
+
+/// Data hash of a compressed NFT, computed from the hash of its metadata args.
+pub fn hash_compressed_nft_data(
+    metadata_hash: &[u8; 32],
+    seller_fee_basis_points: u16,
+) -> [u8; 32] {
+    keccak::hashv(&[metadata_hash, &seller_fee_basis_points.to_le_bytes()]).to_bytes()
+}
+
+/// Creator hash of a compressed NFT.
+pub fn hash_compressed_nft_creators(creators: &[Creator]) -> [u8; 32] {
+    let creator_data = creators
+        .iter()
+        .map(|creator| {
+            [
+                creator.address.as_ref(),
+                &[creator.verified as u8],
+                &[creator.share],
+            ]
+            .concat()
+        })
+        .collect::<Vec<_>>();
+
+    keccak::hashv(
+        creator_data
+            .iter()
+            .map(|data| data.as_slice())
+            .collect::<Vec<_>>()
+            .as_ref(),
+    )
+    .to_bytes()
+}
+
+/// Returns the royalty info of a compressed NFT after checking it against the
+/// data and creator hashes of its leaf.
+///
+/// The primary sale flag is part of the metadata args, which are only committed to
+/// through their hash, so compressed NFTs are always treated as secondary sales.
+pub fn get_verified_compressed_royalty_info(
+    data_hash: &[u8; 32],
+    creator_hash: &[u8; 32],
+    metadata_hash: &[u8; 32],
+    seller_fee_basis_points: u16,
+    creators: &[Creator],
+) -> Result<RoyaltyInfo> {
+    if hash_compressed_nft_data(metadata_hash, seller_fee_basis_points) != *data_hash
+        || hash_compressed_nft_creators(creators) != *creator_hash
+    {
+        msg!("Compressed NFT royalty info does not match the leaf");
+        return err!(crate::error::Error::InvalidCompressedNftData);
+    }
+
+    Ok(RoyaltyInfo {
+        is_primary_sale: false,
+        seller_fee_basis_points,
+        creators: if creators.is_empty() {
+            None
+        } else {
+            Some(creators.to_vec())
+        },
+    })
+}
@@ -3,13 +3,9 @@ use anchor_lang::prelude::*;
 use mpl_core::types::UpdateAuthority;
 use mpl_core::{Asset, Collection};
 
-pub fn is_primary_sale<'info>(seller: Pubkey, update_authority: Option<Pubkey>) -> Result<bool> {
-    if update_authority.is_none() {
-        return Ok(false);
-    }
-
+pub fn is_primary_sale(seller: Pubkey, update_authority: Option<Pubkey>) -> Result<bool> {
     // Considered a primary sale if seller is the update authority (most likely creator)
-    Ok(seller == update_authority.unwrap())
+    Ok(update_authority == Some(seller))
 }
 
 pub fn get_update_authority<'info>(
@@ -19,9 +15,7 @@ pub fn get_update_authority<'info>(
     // Considered a primary sale if owner is the update authority (most likely creator)
     let asset = Box::<Asset>::try_from(asset_info)?;
     match asset.base.update_authority {
-        UpdateAuthority::Address(address) => {
-            return Ok(Some(address));
-        }
+        UpdateAuthority::Address(address) => Ok(Some(address)),
         UpdateAuthority::Collection(collection_key) => {
             if let Some(collection_info) = collection_info {
                 assert_keys_equal(
@@ -30,11 +24,24 @@ pub fn get_update_authority<'info>(
                     "Invalid collection key",
                 )?;
                 let collection = Box::<Collection>::try_from(collection_info)?;
-                return Ok(Some(collection.base.update_authority));
+                Ok(Some(collection.base.update_authority))
             } else {
-                return Ok(None);
+                Ok(None)
             }
         }
-        UpdateAuthority::None => return Ok(None),
+        UpdateAuthority::None => Ok(None),
     }
 }
+
+/// Returns the value of an attribute of a Core asset.
+pub fn get_attribute_value(asset: &Asset, key: &str) -> Option<String> {
+    asset
+        .plugin_list
+        .attributes
+        .as_ref()?
+        .attributes
+        .attribute_list
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.clone())
+}
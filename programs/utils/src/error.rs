@@ -31,4 +31,8 @@ pub enum Error {
     ValidListing,
     #[msg("Invalid collection")]
     InvalidCollection,
+    #[msg("Invalid random range")]
+    InvalidRandomRange,
+    #[msg("Invalid compressed NFT data")]
+    InvalidCompressedNftData,
 }
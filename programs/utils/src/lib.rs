@@ -1,12 +1,18 @@
+#![allow(clippy::too_many_arguments)]
+
 mod checks;
+mod compression;
 pub mod core;
 pub mod error;
 mod math;
+mod random;
 mod royalties;
 mod transfer;
 
 pub use checks::*;
+pub use compression::*;
 pub use error::*;
 pub use math::*;
+pub use random::*;
 pub use royalties::*;
 pub use transfer::*;
@@ -0,0 +1,90 @@
+use crate::error::Error;
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+/// Returns a uniformly distributed random value in the range `[0, bound)`.
+///
+/// Candidate values are derived by hashing the `seed` together with the `nonce` and an
+/// attempt counter using `keccak`. Taking the remainder of a 64-bit value is biased towards
+/// lower values whenever `bound` does not divide 2^64, so candidates that fall in the
+/// incomplete range at the start of the `u64` space are rejected and the seed re-hashed.
+/// Each attempt is rejected with a probability lower than 1/2, so the expected number of
+/// hashes is below 2 for any bound.
+///
+/// The same `seed` can be used for multiple values (e.g. several items in the same draw)
+/// by using a different `nonce` for each of them.
+pub fn random_below(seed: &[u8; 32], nonce: u64, bound: u64) -> Result<u64> {
+    if bound == 0 {
+        return err!(Error::InvalidRandomRange);
+    }
+
+    // 2^64 % bound: values below it are the ones that would skew the distribution
+    let threshold = bound.wrapping_neg() % bound;
+    let nonce = nonce.to_le_bytes();
+    let mut attempt: u64 = 0;
+
+    loop {
+        let hash = keccak::hashv(&[seed, &nonce, &attempt.to_le_bytes()]).0;
+        let value = u64::from_le_bytes(hash[0..8].try_into().unwrap());
+
+        if value >= threshold {
+            return Ok(value % bound);
+        }
+
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: [u8; 32] = [7; 32];
+
+    #[test]
+    fn value_is_below_bound() {
+        // a bound just above 2^63 rejects almost half of the candidates
+        for bound in [1, 2, 3, 10, 1_000_003, (u64::MAX / 2) + 2, u64::MAX] {
+            for nonce in 0..100 {
+                assert!(random_below(&SEED, nonce, bound).unwrap() < bound);
+            }
+        }
+    }
+
+    #[test]
+    fn value_is_deterministic() {
+        assert_eq!(
+            random_below(&SEED, 5, 1_000).unwrap(),
+            random_below(&SEED, 5, 1_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn nonce_changes_value() {
+        let values: Vec<u64> = (0..10)
+            .map(|nonce| random_below(&SEED, nonce, u64::MAX).unwrap())
+            .collect();
+
+        for (i, value) in values.iter().enumerate() {
+            assert!(!values[i + 1..].contains(value));
+        }
+    }
+
+    #[test]
+    fn zero_bound_is_rejected() {
+        assert!(random_below(&SEED, 0, 0).is_err());
+    }
+
+    #[test]
+    fn values_are_evenly_distributed() {
+        let mut counts = [0u32; 3];
+
+        for nonce in 0..30_000 {
+            counts[random_below(&SEED, nonce, 3).unwrap() as usize] += 1;
+        }
+
+        for count in counts {
+            assert!((9_500..=10_500).contains(&count), "{counts:?}");
+        }
+    }
+}
@@ -27,20 +27,15 @@ pub fn get_verified_royalty_info<'a>(
     Ok(RoyaltyInfo {
         is_primary_sale,
         seller_fee_basis_points,
-        creators: if creators.is_some() {
+        creators: creators.map(|creators| {
             creators
-                .unwrap()
                 .iter()
-                .map(|c| {
-                    Some(Creator {
-                        address: c.address,
-                        verified: c.verified,
-                        share: c.share,
-                    })
+                .map(|c| Creator {
+                    address: c.address,
+                    verified: c.verified,
+                    share: c.share,
                 })
                 .collect()
-        } else {
-            None
-        },
+        }),
     })
 }
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
-use crate::{assert_is_ata, error::Error, is_native_mint};
+use crate::{assert_is_ata, assert_is_ata_with_program, error::Error, is_native_mint};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::{create, Create};
 use anchor_spl::token;
 use anchor_spl::token::Transfer;
+use anchor_spl::token_interface::{self, TransferChecked};
 use mpl_token_metadata::accounts::Metadata;
 use mpl_token_metadata::instructions::TransferV1CpiBuilder;
 use mpl_token_metadata::types::{Payload, PayloadType, ProgrammableConfig, TokenStandard};
@@ -26,20 +27,14 @@ pub fn transfer<'a>(
     fee_payer_seeds: Option<&[&[u8]]>,
     amount: u64,
 ) -> Result<()> {
-    if currency_mint.is_none() {
-        transfer_sol(from, to, system_program, signer_seeds, amount)?;
-    } else {
+    if let Some(currency_mint) = currency_mint {
         transfer_spl(
             from,
             to,
             from_currency_account.unwrap(),
             to_currency_account.unwrap(),
-            currency_mint.unwrap(),
-            if fee_payer.is_some() {
-                fee_payer.unwrap()
-            } else {
-                from
-            },
+            currency_mint,
+            fee_payer.unwrap_or(from),
             associated_token_program.unwrap(),
             token_program.unwrap(),
             system_program,
@@ -48,6 +43,8 @@ pub fn transfer<'a>(
             fee_payer_seeds,
             amount,
         )?;
+    } else {
+        transfer_sol(from, to, system_program, signer_seeds, amount)?;
     }
 
     Ok(())
@@ -68,31 +65,27 @@ pub fn transfer_from_pda<'a>(
     fee_payer_seeds: Option<&[&[u8]]>,
     amount: u64,
 ) -> Result<()> {
-    if currency_mint.is_none()
-        || currency_mint.unwrap().key() == Pubkey::default()
-        || is_native_mint(currency_mint.unwrap().key())
-    {
-        transfer_sol_from_pda(from, to, amount)?;
-    } else {
-        transfer_spl(
-            from,
-            to,
-            from_currency_account.unwrap(),
-            to_currency_account.unwrap(),
-            currency_mint.unwrap(),
-            if fee_payer.is_some() {
-                fee_payer.unwrap()
-            } else {
-                from
-            },
-            associated_token_program.unwrap(),
-            token_program.unwrap(),
-            system_program,
-            None,
-            Some(signer_seeds),
-            fee_payer_seeds,
-            amount,
-        )?;
+    match currency_mint {
+        Some(currency_mint)
+            if currency_mint.key() != Pubkey::default() && !is_native_mint(currency_mint.key()) =>
+        {
+            transfer_spl(
+                from,
+                to,
+                from_currency_account.unwrap(),
+                to_currency_account.unwrap(),
+                currency_mint,
+                fee_payer.unwrap_or(from),
+                associated_token_program.unwrap(),
+                token_program.unwrap(),
+                system_program,
+                None,
+                Some(signer_seeds),
+                fee_payer_seeds,
+                amount,
+            )?;
+        }
+        _ => transfer_sol_from_pda(from, to, amount)?,
     }
 
     Ok(())
@@ -129,8 +122,8 @@ pub fn transfer_sol<'a>(
 
     let transfer_accounts = &[from.clone(), to.clone(), system_program.clone()];
 
-    if signer_seeds.is_some() {
-        invoke_signed(transfer_ix, transfer_accounts, &[signer_seeds.unwrap()])?;
+    if let Some(signer_seeds) = signer_seeds {
+        invoke_signed(transfer_ix, transfer_accounts, &[signer_seeds])?;
     } else {
         invoke(transfer_ix, transfer_accounts)?;
     }
@@ -173,6 +166,62 @@ pub fn transfer_spl<'a>(
         Transfer {
             from: from_token_account.to_account_info(),
             to: to_token_account.to_account_info(),
+            authority: from_authority.unwrap_or(from).to_account_info(),
+        },
+    );
+
+    if let Some(signer_seeds) = signer_seeds {
+        token::transfer(transfer_cpi.with_signer(&[signer_seeds]), amount)?;
+    } else {
+        token::transfer(transfer_cpi, amount)?;
+    }
+
+    Ok(())
+}
+
+/// Transfers SPL tokens with `transfer_checked`, which is required by Token-2022 mints.
+/// The token program can be either SPL Token or Token-2022.
+pub fn transfer_spl_checked<'a>(
+    from: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    from_token_account: &AccountInfo<'a>,
+    to_token_account: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    ata_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    from_authority: Option<&AccountInfo<'a>>,
+    signer_seeds: Option<&[&[u8]]>,
+    fee_payer_seeds: Option<&[&[u8]]>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if to_token_account.data_is_empty() {
+        make_ata(
+            to_token_account.to_account_info(),
+            to.to_account_info(),
+            mint.to_account_info(),
+            fee_payer.to_account_info(),
+            ata_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            fee_payer_seeds,
+        )?;
+    } else {
+        assert_is_ata_with_program(to_token_account, to.key, mint.key, token_program.key)?;
+    }
+
+    let transfer_cpi = CpiContext::new(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: from_token_account.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to_token_account.to_account_info(),
             authority: if from_authority.is_some() {
                 from_authority.unwrap().to_account_info()
             } else {
@@ -182,9 +231,13 @@ pub fn transfer_spl<'a>(
     );
 
     if signer_seeds.is_none() {
-        token::transfer(transfer_cpi, amount)?;
+        token_interface::transfer_checked(transfer_cpi, amount, decimals)?;
     } else {
-        token::transfer(transfer_cpi.with_signer(&[signer_seeds.unwrap()]), amount)?;
+        token_interface::transfer_checked(
+            transfer_cpi.with_signer(&[signer_seeds.unwrap()]),
+            amount,
+            decimals,
+        )?;
     }
 
     Ok(())
@@ -237,8 +290,8 @@ pub fn make_ata<'a>(
         token_program: token_program.to_account_info(),
     };
 
-    if fee_payer_seeds.is_some() {
-        let seeds = &[fee_payer_seeds.unwrap()];
+    if let Some(fee_payer_seeds) = fee_payer_seeds {
+        let seeds = &[fee_payer_seeds];
         create(CpiContext::new_with_signer(
             ata_program.to_account_info(),
             accounts,